@@ -0,0 +1,311 @@
+// ============================================================
+//  advice.rs — Conseils tirés d'une analyse
+//
+//  Le texte des recommandations, sans rien de l'interface : le
+//  panneau de ui.rs le colore selon le thème, le rapport HTML
+//  (`report`) le reprend tel quel. Chaque ligne porte une
+//  tonalité (grave, à surveiller, indication, note…) plutôt
+//  qu'une couleur, et éventuellement une icône en tête.
+// ============================================================
+
+use crate::{
+    allpass::{self, AllpassFilter},
+    app::AppState,
+    dsp,
+    gain::GainControl,
+    placement::{self, DistanceFix},
+    reminders::Reminder,
+    seats::{self, Finding, Metric},
+    strings::{format_hz, format_num, format_signed, tr},
+};
+
+/// Écart moyen G/D dans une zone spectrale au-delà duquel elle est signalée (dB).
+pub const REGION_RECO_DB: f32 = 2.0;
+
+/// Tonalité d'une ligne (ou de son icône).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tone {
+    /// Au-delà du seuil grave du profil.
+    Severe,
+    /// Au-delà du seuil de signalement.
+    Warning,
+    /// Action suggérée, sans gravité.
+    Action,
+    /// Réglage réussi.
+    Good,
+    /// Texte principal d'un conseil.
+    Text,
+    /// Détail ou marche à suivre, en retrait.
+    Note,
+}
+
+/// Une ligne de conseil.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Advice {
+    /// Icône en tête et sa tonalité ; le texte est alors en `Tone::Text`.
+    pub icon: Option<(String, Tone)>,
+    pub text: String,
+    pub tone: Tone,
+}
+
+impl Advice {
+    fn flagged(icon: &str, tone: Tone, text: impl Into<String>) -> Self {
+        Advice { icon: Some((icon.to_string(), tone)), text: text.into(), tone: Tone::Text }
+    }
+
+    fn line(tone: Tone, text: impl Into<String>) -> Self {
+        Advice { icon: None, text: text.into(), tone }
+    }
+
+    fn note(text: impl Into<String>) -> Self {
+        Advice::line(Tone::Note, text)
+    }
+}
+
+/// Conseils du panneau de recommandations, dans l'ordre d'affichage,
+/// rappels de vérification compris.
+pub fn recommendations(state: &AppState) -> Vec<Advice> {
+    let s = tr();
+    if state.score.is_none() {
+        let mut lines = vec![Advice::note(s.reco_pending)];
+        lines.extend(reminders(state));
+        return lines;
+    }
+
+    let mut guides: Vec<Advice> = Vec::new();
+
+    // Captures inversées : tous les signes suivants seraient faux
+    if state.swap_suspected() {
+        guides.push(Advice::flagged("⇄", Tone::Severe, s.reco_swapped));
+        guides.push(Advice::note(s.reco_swapped_hint));
+    }
+
+    // Câblage + / − croisé : à corriger avant tout réglage de placement.
+    // Inversée des deux côtés, la polarité ne gêne pas l'image : simple note.
+    let polarity = state.speaker_polarity.filter(|(l, r)| l.is_reliable() && r.is_reliable());
+    if let Some((l, _)) = polarity.filter(|(l, r)| l.positive != r.positive) {
+        let side = if l.positive { s.right_word } else { s.left_word };
+        guides.push(Advice::flagged("⚠", Tone::Severe, (s.reco_reversed)(side)));
+        guides.push(Advice::note(s.reco_reversed_hint));
+    }
+
+    // Son direct plus faible qu'une réflexion : la distance repose sur la
+    // première arrivée, pas sur le pic
+    for (m, side) in [(&state.left, s.left_word), (&state.right, s.right_word)] {
+        if m.as_ref().is_some_and(|m| m.onset_candidates > 1) {
+            guides.push(Advice::flagged("⚠", Tone::Warning, (s.reco_weak_direct)(side)));
+            guides.push(Advice::note(s.reco_weak_direct_hint));
+        }
+    }
+
+    // Part la plus faible du score : déplacer les enceintes ou régler
+    if let Some(split) = state.score_breakdown().filter(|b| b.placement.min(b.electrical) < 85) {
+        let hint = if split.placement <= split.electrical { s.reco_bucket_placement } else { s.reco_bucket_electrical };
+        guides.push(Advice::flagged("▸", Tone::Action, hint));
+    }
+
+    // Seuils du profil `[score]`
+    let w = state.weights();
+    let severity = |severe: bool| if severe { Tone::Severe } else { Tone::Warning };
+    if w.delay_ms.exceeded(state.delay_ms) {
+        let sev = severity(w.delay_ms.severe(state.delay_ms));
+        // Distances absolues (boucle de référence) : le niveau après un
+        // déplacement peut être estimé
+        let distances = match (&state.left, &state.right) {
+            (Some(l), Some(r)) if l.latency_ms.is_some() => l.dist_m.zip(r.dist_m),
+            _ => None,
+        };
+        match state.placement.solve(state.delay_ms, state.level_diff_db, distances) {
+            DistanceFix::Move { right, closer, cm, level_after_db } => {
+                let icon = if state.delay_ms > 0.0 { "↗" } else { "↙" };
+                let action = match (right, closer) {
+                    (true, true) => s.reco_closer,
+                    (true, false) => s.reco_farther,
+                    (false, true) => s.reco_left_closer,
+                    (false, false) => s.reco_left_farther,
+                };
+                guides.push(Advice::flagged(icon, sev, action));
+                guides.push(Advice::note(if cm < 1.0 {
+                    format!("    Δ distance ≈ {} mm", format_num(cm * 10.0, 1))
+                } else {
+                    format!("    Δ distance ≈ {} cm", format_num(cm, 1))
+                }));
+                if let Some(db) = level_after_db.filter(|db| (db - state.level_diff_db).abs() >= 0.1) {
+                    guides.push(Advice::note((s.reco_level_after)(db)));
+                }
+            }
+            DistanceFix::Delay { right, ms } => {
+                let side = if right { s.right_word } else { s.left_word };
+                guides.push(Advice::flagged("⧗", sev, (s.reco_channel_delay)(side, ms, ms * placement::sound_cm_per_ms())));
+            }
+            DistanceFix::Blocked => {
+                guides.push(Advice::flagged("✕", sev, s.reco_distance_blocked));
+                guides.push(Advice::note(s.reco_distance_blocked_hint));
+            }
+        }
+    }
+
+    // Plus proche mais moins forte : le niveau contredit la distance (une
+    // enceinte plus proche devrait être plus forte). Le retard reste la
+    // mesure de distance ; l'écart de niveau vient de l'orientation ou d'un
+    // obstacle, on ne conseille donc pas de rapprocher l'autre enceinte.
+    let sign_mismatch = w.delay_ms.exceeded(state.delay_ms)
+        && w.level_db.exceeded(state.level_diff_db)
+        && state.delay_ms.signum() == state.level_diff_db.signum();
+
+    if sign_mismatch {
+        let closer = if state.delay_ms < 0.0 { s.right_word } else { s.left_word };
+        guides.push(Advice::flagged("⚠", severity(w.level_db.severe(state.level_diff_db)), (s.reco_sign_mismatch)(closer)));
+        guides.push(Advice::note((s.reco_level)(state.level_diff_db.abs())));
+        guides.push(Advice::note(s.reco_sign_mismatch_hint));
+    } else if w.level_db.exceeded(state.level_diff_db) {
+        let icon = if state.level_diff_db > 0.0 { "🔉" } else { "🔊" };
+        let action = if state.level_diff_db > 0.0 { s.reco_too_loud } else { s.reco_too_quiet };
+        guides.push(Advice::flagged(icon, severity(w.level_db.severe(state.level_diff_db)), action));
+        guides.push(Advice::note((s.reco_level)(state.level_diff_db.abs())));
+        // Même écart, dans les unités de l'organe déclaré dans `[gain]`
+        if let Some(adj) = state.gain.suggest(state.level_diff_db) {
+            let (louder, quieter) =
+                if adj.right_louder { (s.right_word, s.left_word) } else { (s.left_word, s.right_word) };
+            let mut text = match adj.control {
+                GainControl::Trim => (s.reco_gain_trim)(louder, adj.steps, adj.applied_db()),
+                GainControl::Balance => (s.reco_gain_balance)(quieter, adj.steps, adj.applied_db()),
+                GainControl::Detents => (s.reco_gain_detents)(louder, adj.steps, adj.applied_db()),
+            };
+            if adj.residual_db.abs() >= 0.05 {
+                text.push_str(&(s.reco_gain_residual)(adj.residual_db.abs()));
+            }
+            guides.push(Advice::line(Tone::Text, text));
+        }
+    }
+
+    if w.tilt_db.exceeded(state.freq_tilt) {
+        let icon = if state.freq_tilt > 0.0 { "◑" } else { "◐" };
+        let action = if state.freq_tilt > 0.0 { s.reco_too_bright } else { s.reco_too_dull };
+        guides.push(Advice::flagged(icon, severity(w.tilt_db.severe(state.freq_tilt)), action));
+    }
+
+    // Asymétrie de clarté : réflexions précoces plus fortes d'un côté
+    if let (Some(l), Some(r)) = (
+        state.left.as_ref().and_then(|m| m.clarity),
+        state.right.as_ref().and_then(|m| m.clarity),
+    ) {
+        let c80_diff = r.c80_db - l.c80_db;
+        if w.c80_db.exceeded(c80_diff) {
+            let side = if c80_diff < 0.0 { s.right_word } else { s.left_word };
+            guides.push(Advice::flagged("◎", severity(w.c80_db.severe(c80_diff)), (s.reco_clarity)(side)));
+            guides.push(Advice::note(format!("    Δ C80 = {} dB", format_num(c80_diff.abs(), 1))));
+        }
+    }
+
+    // Réflexion d'une même surface plus forte d'un côté : souvent ce qui
+    // sépare les courbes G et D
+    if let Some(report) = &state.reflections {
+        for asym in report.asymmetries() {
+            let diff = asym.diff_db();
+            let side = if diff > 0.0 { s.right_word } else { s.left_word };
+            let level = |db: Option<f32>| db.map_or("—".to_string(), |db| format!("{} dB", format_signed(db, 1)));
+            guides.push(Advice::flagged("⌁", Tone::Warning, (s.reco_reflection)(&asym.surfaces_label(), side, diff.abs())));
+            guides.push(Advice::note(format!(
+                "    {} {} · {} {}",
+                s.left_short,
+                level(asym.left_db),
+                s.right_short,
+                level(asym.right_db)
+            )));
+        }
+    }
+
+    // Retard qui dépend de la fréquence : le déplacement ne suffira pas
+    if let Some(report) = state.band_delay.as_ref().filter(|r| r.frequency_dependent()) {
+        guides.push(Advice::flagged("≋", Tone::Warning, (s.reco_dispersion)(report.spread_ms())));
+        // Correction de phase si le retard tient à l'enceinte elle-même
+        let phase = allpass::fit(report);
+        if !phase.is_empty() {
+            let sides = [(s.left_short, &phase.left), (s.right_short, &phase.right)];
+            let filters: Vec<String> = sides
+                .iter()
+                .flat_map(|(side, filters)| {
+                    filters.iter().map(move |f| match *f {
+                        AllpassFilter::FirstOrder { freq_hz } => {
+                            format!("{} {} {}", side, format_hz(freq_hz), s.allpass_first_order)
+                        }
+                        AllpassFilter::SecondOrder { freq_hz, q } => format!("{} {} Q {}", side, format_hz(freq_hz), format_num(q, 1)),
+                    })
+                })
+                .collect();
+            guides.push(Advice::note((s.reco_allpass)(&filters.join(", "))));
+        }
+    }
+
+    // Zone du spectre la plus déséquilibrée, nommée comme sur le graphique ([Y])
+    if let Some(diff) = &state.diff_db {
+        let means = dsp::region_means(diff);
+        if let Some((k, &db)) = means.iter().enumerate().max_by(|a, b| a.1.abs().total_cmp(&b.1.abs())) {
+            if db.abs() > REGION_RECO_DB {
+                let side = if db > 0.0 { s.right_word } else { s.left_word };
+                let tone = severity(db.abs() > 2.0 * REGION_RECO_DB);
+                guides.push(Advice::flagged("◧", tone, (s.reco_region)(s.region_names[k], side, db.abs())));
+            }
+        }
+    }
+
+    // Plusieurs places : un écart partout relève du placement, un écart à
+    // une seule place relève de la place
+    for finding in seats::stats(&state.seats).map(|st| st.findings).unwrap_or_default() {
+        let metric_name = |metric: Metric| match metric {
+            Metric::Delay => s.seat_delay,
+            Metric::Level => s.seat_level,
+            Metric::Region(k) => s.region_names[k],
+        };
+        let unit = |metric: Metric| if metric == Metric::Delay { "ms" } else { "dB" };
+        match finding {
+            Finding::Placement { metric, mean, sd } => {
+                guides.push(Advice::flagged("⚑", Tone::Warning, (s.reco_seat_placement)(metric_name(metric), state.seats.len())));
+                guides.push(Advice::note(format!("    {} ± {} {}", format_signed(mean, 2), format_num(sd, 2), unit(metric))));
+            }
+            Finding::SeatAnomaly { metric, seat, value } => {
+                guides.push(Advice::flagged("◌", Tone::Note, (s.reco_seat_anomaly)(metric_name(metric), &state.seats[seat].name)));
+                guides.push(Advice::note(format!("    {} {}", format_signed(value, 2), unit(metric))));
+            }
+        }
+    }
+
+    if guides.is_empty() {
+        guides.push(Advice::line(Tone::Text, ""));
+        guides.push(Advice::line(Tone::Good, s.reco_optimal));
+        guides.push(Advice::note(s.reco_symmetric));
+    }
+    if polarity.is_some_and(|(l, r)| !l.positive && !r.positive) {
+        guides.push(Advice::note(s.reco_reversed_both));
+    }
+    guides.extend(reminders(state));
+    guides
+}
+
+/// Vérifications à refaire (calibration micro, boucle), chacune suivie de
+/// la manière de la faire.
+fn reminders(state: &AppState) -> Vec<Advice> {
+    let s = tr();
+    let due = state.reminders();
+    let mut lines: Vec<Advice> = due
+        .iter()
+        .map(|reminder| {
+            let text = match *reminder {
+                Reminder::MicCalStale { days } => (s.reminder_mic_stale)(days),
+                Reminder::MicCalDeviceChanged => s.reminder_mic_device.to_string(),
+                Reminder::LoopbackUnmeasured => s.reminder_loopback_unmeasured.to_string(),
+                Reminder::LoopbackStale { days } => (s.reminder_loopback_stale)(days),
+                Reminder::LoopbackDeviceChanged => s.reminder_loopback_device.to_string(),
+            };
+            Advice::flagged("⏰", Tone::Warning, text)
+        })
+        .collect();
+    if due.iter().any(|r| r.is_mic_cal()) {
+        lines.push(Advice::note(s.reminder_mic_hint));
+    }
+    if due.iter().any(|r| !r.is_mic_cal()) {
+        lines.push(Advice::note(s.reminder_loopback_hint));
+    }
+    lines
+}
@@ -0,0 +1,189 @@
+// ============================================================
+//  allpass.rs — Correction de phase par filtres passe-tout
+//
+//  Quand le retard G/D varie d'une octave à l'autre (`banddelay`),
+//  l'écart n'est ni une distance (un seul retard le corrige) ni un
+//  écart d'amplitude (l'égaliseur en cloche n'y touche pas) : une
+//  enceinte est en retard de phase sur une partie du spectre. Un
+//  passe-tout ajoute du retard de groupe sans changer l'amplitude ;
+//  on en place sur l'enceinte EN AVANCE dans les octaves fautives,
+//  une fois le retard large bande compensé :
+//    - 1er ordre : retard de groupe 2·ω0 / (ω0² + ω²), plateau sous
+//      la coupure — un grave en retard de quelques dixièmes de ms ;
+//    - 2e ordre : pic de 4·Q / ω0 à la coupure — un retard plus
+//      grand que 1 / ω à la fréquence visée.
+//  Ajustement glouton sur les octaves mesurées : à chaque tour, le
+//  filtre d'une grille (fréquence × Q) qui réduit le plus l'écart
+//  quadratique restant — un passe-tout retarde aussi les octaves
+//  voisines, un excès compte autant qu'un manque. Au plus
+//  `MAX_FILTERS` par enceinte, arrêt sous `TOLERANCE_MS` ou sans
+//  gain notable. Sous l'octave mesurée la plus grave, le retard
+//  est supposé égal au sien : un passe-tout très grave, qui
+//  retarderait tout le sub-grave, est ainsi écarté. Vérifier en
+//  remesurant. Sorties : filtres `Biquad` de CamillaDSP, et la
+//  réponse impulsionnelle de la cascade (FIR pour un convolueur).
+// ============================================================
+
+use serde::Serialize;
+use std::f32::consts::PI;
+
+use crate::banddelay::{BandDelayReport, DISPERSION_LIMIT_MS};
+
+/// Nombre maximal de passe-tout par enceinte.
+pub const MAX_FILTERS: usize = 3;
+
+/// Écart de retard restant jugé négligeable (ms).
+pub const TOLERANCE_MS: f32 = DISPERSION_LIMIT_MS / 2.0;
+
+/// Longueur des FIR exportés (échantillons).
+pub const FIR_TAPS: usize = 16_384;
+
+/// Grille de recherche : pas en fréquence (1/6 d'octave) et Q des 2e ordre.
+const GRID_STEPS_PER_OCTAVE: f32 = 6.0;
+const GRID_Q: [f32; 8] = [0.3, 0.5, 0.7, 1.0, 1.5, 2.0, 3.0, 4.0];
+
+/// Réduction minimale de l'écart quadratique pour garder un filtre (ms²).
+const MIN_GAIN_MS2: f32 = 0.001;
+
+/// Filtre passe-tout (gain 1, phase seule).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum AllpassFilter {
+    FirstOrder { freq_hz: f32 },
+    SecondOrder { freq_hz: f32, q: f32 },
+}
+
+impl AllpassFilter {
+    pub fn freq_hz(&self) -> f32 {
+        match *self {
+            AllpassFilter::FirstOrder { freq_hz } | AllpassFilter::SecondOrder { freq_hz, .. } => freq_hz,
+        }
+    }
+
+    /// Retard de groupe (ms) à `freq_hz`, prototype analogique.
+    pub fn group_delay_ms(&self, freq_hz: f32) -> f32 {
+        let w = 2.0 * PI * freq_hz;
+        let seconds = match *self {
+            AllpassFilter::FirstOrder { freq_hz: f0 } => {
+                let w0 = 2.0 * PI * f0;
+                2.0 * w0 / (w0 * w0 + w * w)
+            }
+            AllpassFilter::SecondOrder { freq_hz: f0, q } => {
+                let w0 = 2.0 * PI * f0;
+                let b = w0 / q;
+                2.0 * b * (w0 * w0 + w * w) / ((w0 * w0 - w * w).powi(2) + (b * w).powi(2))
+            }
+        };
+        seconds * 1000.0
+    }
+
+    /// Coefficients numériques normalisés (b0, b1, b2, a1, a2) : transformée
+    /// bilinéaire pour le 1er ordre, biquad RBJ pour le 2e.
+    fn coefficients(&self, sample_rate: u32) -> [f32; 5] {
+        match *self {
+            AllpassFilter::FirstOrder { freq_hz } => {
+                let t = (PI * freq_hz / sample_rate as f32).tan();
+                let a = (t - 1.0) / (t + 1.0);
+                [a, 1.0, 0.0, a, 0.0]
+            }
+            AllpassFilter::SecondOrder { freq_hz, q } => {
+                let w0 = 2.0 * PI * freq_hz / sample_rate as f32;
+                let alpha = w0.sin() / (2.0 * q);
+                let a0 = 1.0 + alpha;
+                let c = -2.0 * w0.cos() / a0;
+                let r = (1.0 - alpha) / a0;
+                [r, c, 1.0, c, r]
+            }
+        }
+    }
+}
+
+/// Filtres essayés à chaque tour de l'ajustement, coupures entre `lo_hz` et
+/// `hi_hz`.
+fn candidates(lo_hz: f32, hi_hz: f32) -> Vec<AllpassFilter> {
+    let steps = ((hi_hz / lo_hz).log2() * GRID_STEPS_PER_OCTAVE).round() as usize;
+    (0..=steps)
+        .map(|i| lo_hz * 2f32.powf(i as f32 / GRID_STEPS_PER_OCTAVE))
+        .flat_map(|freq_hz| {
+            std::iter::once(AllpassFilter::FirstOrder { freq_hz })
+                .chain(GRID_Q.iter().map(move |&q| AllpassFilter::SecondOrder { freq_hz, q }))
+        })
+        .collect()
+}
+
+/// Passe-tout proposés pour chaque enceinte.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PhaseCorrection {
+    pub left: Vec<AllpassFilter>,
+    pub right: Vec<AllpassFilter>,
+}
+
+impl PhaseCorrection {
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.right.is_empty()
+    }
+}
+
+/// Réponse impulsionnelle de la cascade `filters` sur `taps` échantillons.
+pub fn impulse_response(filters: &[AllpassFilter], sample_rate: u32, taps: usize) -> Vec<f32> {
+    let mut signal = vec![0.0f32; taps];
+    if let Some(first) = signal.first_mut() {
+        *first = 1.0;
+    }
+    for filter in filters {
+        let [b0, b1, b2, a1, a2] = filter.coefficients(sample_rate);
+        let (mut x1, mut x2, mut y1, mut y2) = (0.0f32, 0.0f32, 0.0f32, 0.0f32);
+        for s in signal.iter_mut() {
+            let y = b0 * *s + b1 * x1 + b2 * x2 - a1 * y1 - a2 * y2;
+            (x2, x1, y2, y1) = (x1, *s, y1, y);
+            *s = y;
+        }
+    }
+    signal
+}
+
+/// Passe-tout ramenant le retard de chaque octave sur le retard large bande ;
+/// vide si le retard ne dépend pas de la fréquence.
+pub fn fit(report: &BandDelayReport) -> PhaseCorrection {
+    if !report.frequency_dependent() {
+        return PhaseCorrection::default();
+    }
+    // Retard D − G propre à chaque octave, le retard large bande compensé
+    let mut mismatch: Vec<(f32, f32)> = report
+        .bands
+        .iter()
+        .filter_map(|b| b.delay_ms.map(|d| (b.freq_hz, d - report.broadband_ms)))
+        .collect();
+    let (Some(&(lo, lowest)), Some(&(hi, _))) = (mismatch.first(), mismatch.last()) else {
+        return PhaseCorrection::default();
+    };
+    // Garde-fou une octave sous la plus grave
+    mismatch.insert(0, (lo / 2.0, lowest));
+    let candidates = candidates(lo / 2f32.sqrt(), hi * 2f32.sqrt());
+    // Droite en retard dans une octave → retarder la gauche, et inversement
+    let side = |sign: f32| {
+        let mut remaining: Vec<(f32, f32)> = mismatch.iter().map(|&(f, m)| (f, sign * m)).collect();
+        let error = |r: &[(f32, f32)], filter: Option<&AllpassFilter>| -> f32 {
+            r.iter().map(|&(f, m)| (m - filter.map_or(0.0, |p| p.group_delay_ms(f))).powi(2)).sum()
+        };
+        let mut filters = Vec::new();
+        while filters.len() < MAX_FILTERS && remaining.iter().any(|&(_, m)| m >= TOLERANCE_MS) {
+            let current = error(&remaining, None);
+            let Some((filter, err)) = candidates
+                .iter()
+                .map(|p| (*p, error(&remaining, Some(p))))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+            else {
+                break;
+            };
+            if current - err < MIN_GAIN_MS2 {
+                break;
+            }
+            for (f, r) in remaining.iter_mut() {
+                *r -= filter.group_delay_ms(*f);
+            }
+            filters.push(filter);
+        }
+        filters
+    };
+    PhaseCorrection { left: side(1.0), right: side(-1.0) }
+}
@@ -5,26 +5,84 @@
 //    Idle → Capturing → Analyzing → Results → Idle…
 // ============================================================
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton,
+        MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
+use serde::{Deserialize, Serialize};
 use std::{
     io,
+    path::{Path, PathBuf},
     sync::mpsc,
     thread,
     time::{Duration, Instant},
 };
 
 use crate::{
-    audio::{self, Channel},
+    assets::{self, AssetKind},
+    audio::{self, AudioError, Capture, CaptureLink, CaptureTiming, Channel, Loopback, Phase, Progress},
+    banddelay::{self, BandDelayReport},
+    baseline::{self, AcceptedAsymmetry},
+    bass::{self, BassReport},
+    calibration::MicCalibration,
+    channelmap::{self, ChannelMap, ChannelMapReport},
+    checklist::{Checklist, Evidence},
+    compression::{self, CompressionReport},
+    distortion::{self, RubBuzzReport},
+    drift,
+    debugdump,
+    distance::{self, DistanceReport},
     dsp::{self, *},
+    dualnoise::{self, DualMeasurement, DualState},
+    nudge,
+    enhancements::{self, Symptom},
+    environment::{self, Ambient, Environment, RoomEntry},
+    experiments::{self, ExperimentLog},
+    positions::{self, CandidatePosition},
+    export,
+    gain::GainParams,
+    keymap::KeyMap,
+    levelcal::{self, LevelCalibration, LevelParams},
+    linearity::{self, LinearityReport},
+    listening::{GridPosition, ListeningWindow, PointAnalysis, WindowSummary},
+    mask::ToleranceMask,
+    measurement::Measurement,
+    mmm::{self, MmmReport},
+    placement::PlacementParams,
+    multichannel::{self, PairResult},
+    multisub::{self, MultiSub},
+    noise::{self, NoiseFloor},
+    notes::{MeasurementNotes, NotesEditor},
+    overlay,
+    pipeline::{AnalysisOptions, Stage},
+    polarity::{self, PolarityReport, SpeakerPolarity},
+    quickcheck::{self, QuickCheckReport, Stimulus},
+    reflections::{self, ReflectionReport},
+    report,
+    reminders::{self, CheckLog, Reminder, ReminderParams},
+    roommodes::{RoomDimensions, RoomMode, MAX_MODE_HZ},
+    schedule::{self, StartSchedule},
+    scoring::{ScoreParams, ScoreWeights},
+    seats::{self, Seat},
+    session,
+    strings::{self, format_num, tr},
+    sub::{self, SubAlignment},
+    target::TargetCurve,
+    theme,
     ui,
+    watch::{self, WatchState},
 };
 
+/// Pas ↑ ↓ du curseur du spectre : une octave (`NUM_BANDS` bandes sur
+/// dix octaves).
+const CURSOR_OCTAVE: isize = (NUM_BANDS / 10) as isize;
+
 // ─── Types ────────────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -32,63 +90,475 @@ pub enum Step {
     Idle,
     CapturingLeft,
     CapturingRight,
+    /// Mesure enchaînée : sweep gauche puis droit dans une seule capture
+    CapturingPair,
+    /// Enceinte supplémentaire (centre, surround…)
+    CapturingChannel(Channel),
+    CapturingSub,
+    /// Caisson `n` de la session multi-caissons (`--subs`), à la place en cours
+    CapturingSubs(usize),
+    CapturingBass,
+    /// Silence capté pour le bruit de fond
+    CapturingNoise,
+    /// Salve de bruit rose du réglage de niveau, puis capture éventuelle
+    CalibratingLevel(Option<Channel>),
+    /// Test enchaîné gauche puis droite (polarité, rub & buzz…)
+    CapturingTest(StereoTest, Channel),
+    /// Sonde de câblage, micro contre l'enceinte donnée
+    ProbingChannels(Channel),
+    /// Suivi en direct : sweep court sur l'enceinte donnée
+    Watching(Channel),
+    /// Suivi simultané : bruits décorrélés sur les deux enceintes à la fois
+    CapturingDual,
     Analyzing,
     Results,
 }
 
+/// Tests de diagnostic joués successivement sur chaque enceinte.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StereoTest {
+    Polarity,
+    RubBuzz,
+    Compression,
+    Linearity,
+    QuickCheck(Stimulus),
+    /// Distances seules, chirp court
+    Distance,
+    /// Moyenne spatiale, micro en mouvement (bruit rose périodique)
+    Mmm,
+}
+
+impl StereoTest {
+    pub fn label(self) -> &'static str {
+        match self {
+            StereoTest::Polarity => tr().test_polarity,
+            StereoTest::RubBuzz => tr().test_rub_buzz,
+            StereoTest::Compression => tr().test_compression,
+            StereoTest::Linearity => tr().test_linearity,
+            StereoTest::QuickCheck(_) => tr().test_quick,
+            StereoTest::Distance => tr().test_distance,
+            StereoTest::Mmm => tr().test_mmm,
+        }
+    }
+
+    fn signal_fn(self) -> fn(u32) -> Vec<f32> {
+        match self {
+            StereoTest::Polarity => |rate| polarity::generate_test_signal(rate),
+            StereoTest::RubBuzz => |rate| distortion::generate_test_signal(rate),
+            StereoTest::Compression => |rate| compression::generate_test_signal(rate),
+            StereoTest::Linearity => |rate| linearity::generate_test_signal(rate),
+            StereoTest::QuickCheck(Stimulus::DualTone) => |rate| Stimulus::DualTone.generate(rate),
+            StereoTest::QuickCheck(Stimulus::WhiteNoise) => |rate| Stimulus::WhiteNoise.generate(rate),
+            StereoTest::Distance => |rate| distance::generate_test_signal(rate),
+            StereoTest::Mmm => |rate| mmm::generate_test_signal(rate),
+        }
+    }
+
+    fn sequence_secs(self) -> f32 {
+        match self {
+            StereoTest::Polarity => polarity::sequence_secs(),
+            StereoTest::RubBuzz => distortion::sequence_secs(),
+            StereoTest::Compression => compression::sequence_secs(),
+            StereoTest::Linearity => linearity::sequence_secs(),
+            StereoTest::QuickCheck(_) => quickcheck::CHECK_SECS,
+            StereoTest::Distance => distance::CHIRP_SECS,
+            StereoTest::Mmm => mmm::MMM_SECS,
+        }
+    }
+}
+
+impl Step {
+    /// Vrai pendant qu'un signal est joué et enregistré.
+    pub fn is_capturing(self) -> bool {
+        matches!(
+            self,
+            Step::CapturingLeft
+                | Step::CapturingRight
+                | Step::CapturingPair
+                | Step::CapturingChannel(_)
+                | Step::CapturingSub
+                | Step::CapturingSubs(_)
+                | Step::CapturingBass
+                | Step::CapturingNoise
+                | Step::CalibratingLevel(_)
+                | Step::CapturingTest(..)
+                | Step::ProbingChannels(_)
+                | Step::Watching(_)
+                | Step::CapturingDual
+        )
+    }
+
+    /// Vrai pour les captures au sweep de mesure (moyennables).
+    pub fn is_sweep_capture(self) -> bool {
+        matches!(
+            self,
+            Step::CapturingLeft
+                | Step::CapturingRight
+                | Step::CapturingChannel(_)
+                | Step::CapturingSub
+                | Step::CapturingSubs(_)
+        )
+    }
+}
+
+/// Vue du spectre : bandes affichées (zoom, défilement) et plancher de
+/// l'axe des dB.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpectrumView {
+    /// Première et dernière bande affichées (incluses).
+    pub bands: (usize, usize),
+    pub floor_db: f32,
+}
+
+/// Zoom maximal : une octave à l'écran.
+const VIEW_MIN_SPAN: usize = CURSOR_OCTAVE as usize;
+/// Plancher de l'axe des dB : bornes et pas de PgPréc / PgSuiv.
+const VIEW_FLOOR_RANGE_DB: (f32, f32) = (-100.0, -20.0);
+const VIEW_FLOOR_STEP_DB: f32 = 10.0;
+
+impl Default for SpectrumView {
+    fn default() -> Self {
+        SpectrumView { bands: (0, NUM_BANDS - 1), floor_db: -80.0 }
+    }
+}
+
+impl SpectrumView {
+    pub fn contains(&self, band: usize) -> bool {
+        (self.bands.0..=self.bands.1).contains(&band)
+    }
+
+    pub fn center(&self) -> usize {
+        (self.bands.0 + self.bands.1) / 2
+    }
+
+    /// Resserre (`zoom_in`) ou élargit la vue d'un facteur 1,5 autour de
+    /// `band`, qui garde sa place à l'écran.
+    pub fn zoom(&mut self, band: usize, zoom_in: bool) {
+        let (lo, hi) = self.bands;
+        let span = (hi - lo).max(1) as f32;
+        let new_span = if zoom_in { span / 1.5 } else { span * 1.5 }.round().clamp(VIEW_MIN_SPAN as f32, (NUM_BANDS - 1) as f32);
+        let anchor = band.clamp(lo, hi);
+        let ratio = (anchor - lo) as f32 / span;
+        let lo = (anchor as f32 - ratio * new_span).round().max(0.0) as usize;
+        self.place(lo, new_span as usize);
+    }
+
+    /// Décale la vue d'un quart de sa largeur (`step` négatif = vers le grave).
+    pub fn pan(&mut self, step: isize) {
+        let (lo, hi) = self.bands;
+        let span = hi - lo;
+        let shift = (span / 4).max(1) as isize * step;
+        self.place(lo.saturating_add_signed(shift), span);
+    }
+
+    /// Plancher de l'axe des dB −/+ `VIEW_FLOOR_STEP_DB` ; positif = vue resserrée.
+    pub fn scale_db(&mut self, step: i32) {
+        let (min, max) = VIEW_FLOOR_RANGE_DB;
+        self.floor_db = (self.floor_db + step as f32 * VIEW_FLOOR_STEP_DB).clamp(min, max);
+    }
+
+    /// Vue de `span` bandes à partir de `lo`, ramenée dans le spectre.
+    fn place(&mut self, lo: usize, span: usize) {
+        let lo = lo.min(NUM_BANDS - 1 - span);
+        self.bands = (lo, lo + span);
+    }
+}
+
+/// Domaine du panneau de résultats, dans l'ordre d'affichage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricDomain {
+    Score,
+    Clarity,
+    Reverb,
+    Distortion,
+}
+
+impl MetricDomain {
+    pub const ALL: [MetricDomain; 4] = [MetricDomain::Score, MetricDomain::Clarity, MetricDomain::Reverb, MetricDomain::Distortion];
+
+    /// Seuls la réverbération et la THD ont un détail par octave.
+    pub fn has_bands(self) -> bool {
+        matches!(self, MetricDomain::Reverb | MetricDomain::Distortion)
+    }
+}
+
+/// Niveau de détail d'un domaine : une ligne de résumé, les valeurs du
+/// domaine, puis le tableau par octave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fold {
+    Summary,
+    Domain,
+    Bands,
+}
+
+/// Arbre des métriques du panneau de résultats : domaine sélectionné
+/// ([F8]) et niveau de détail de chacun ([F9], clic sur l'en-tête). Le
+/// panneau garde la même hauteur quel que soit le nombre de métriques :
+/// il défile jusqu'au domaine sélectionné.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricTree {
+    pub focus: MetricDomain,
+    folds: [Fold; 4],
+}
+
+impl Default for MetricTree {
+    /// Score détaillé, autres domaines résumés.
+    fn default() -> Self {
+        MetricTree { focus: MetricDomain::Score, folds: [Fold::Domain, Fold::Summary, Fold::Summary, Fold::Summary] }
+    }
+}
+
+impl MetricTree {
+    pub fn fold(&self, domain: MetricDomain) -> Fold {
+        self.folds[domain as usize]
+    }
+
+    /// Domaine suivant parmi `shown` (ceux qui ont des valeurs).
+    pub fn next(&mut self, shown: &[MetricDomain]) {
+        let at = shown.iter().position(|&d| d == self.focus);
+        self.focus = shown.get(at.map_or(0, |i| (i + 1) % shown.len())).copied().unwrap_or(MetricDomain::Score);
+    }
+
+    /// Détaille `domain` d'un niveau ; après le plus détaillé, revient au
+    /// résumé.
+    pub fn unfold(&mut self, domain: MetricDomain) {
+        let fold = &mut self.folds[domain as usize];
+        *fold = match *fold {
+            Fold::Summary => Fold::Domain,
+            Fold::Domain if domain.has_bands() => Fold::Bands,
+            _ => Fold::Summary,
+        };
+    }
+}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryEntry {
     pub score: u32,
     pub delay_ms: f32,
     pub level_diff_db: f32,
     pub time: String,
+    /// Courbes G / D de l'analyse (moyennes en mode fenêtre), pour la
+    /// comparaison [&]. Vides dans les sessions plus anciennes.
+    #[serde(default)]
+    pub left_db: Vec<f32>,
+    #[serde(default)]
+    pub right_db: Vec<f32>,
+    /// Conditions de l'analyse (bruit de fond, température…), pour le
+    /// panneau des corrélations ["].
+    #[serde(default)]
+    pub environment: Environment,
+    /// Note, micro, enceintes et pièce saisis par [F12].
+    #[serde(default)]
+    pub notes: MeasurementNotes,
+}
+
+impl HistoryEntry {
+    pub fn has_curves(&self) -> bool {
+        !self.left_db.is_empty() && self.left_db.len() == self.right_db.len()
+    }
 }
 
 // Message envoyé par les threads audio vers la boucle principale
 pub enum AudioMsg {
-    Progress(f32),
-    Done(Vec<f32>, Vec<f32>), // (capture, test_signal)
+    /// Avancement et cycle en cours (rang, nombre de cycles moyennés).
+    Progress(Progress, (u32, u32)),
+    Done(Capture, Vec<f32>), // (capture, test_signal)
+    /// Capture au sweep déjà convertie en mesure (spectre, IR).
+    Measured(Box<Measurement>),
+    /// Mesure enchaînée découpée : (gauche, droite).
+    MeasuredPair(Box<(Measurement, Measurement)>),
+    /// Bruits simultanés G + D déjà analysés.
+    MeasuredDual(Box<DualMeasurement>),
     Error(String),
+    /// Capture arrêtée par [Échap].
+    Cancelled,
 }
 
 pub struct AppState {
     pub step: Step,
 
-    // Données brutes
-    pub left_samples: Option<Vec<f32>>,
-    pub right_samples: Option<Vec<f32>>,
+    // Mesures par enceinte (capture, spectre, IR et grandeurs dérivées)
+    pub left: Option<Measurement>,
+    pub right: Option<Measurement>,
+
+    // Enceintes supplémentaires (C, SL, SR…), référence des comparaisons
+    // et résultat de la comparaison de chaque enceinte à cette référence
+    pub others: Vec<Measurement>,
+    pub reference: Channel,
+    pub pairs: Vec<PairResult>,
 
-    // Signaux de test utilisés lors de la capture (pour déconvolution sweep)
-    pub left_test_signal: Option<Vec<f32>>,
-    pub right_test_signal: Option<Vec<f32>>,
+    // Caisson de basses : sortie utilisée, mesure et alignement sur les principales
+    pub sub_output: Channel,
+    pub sub: Option<Measurement>,
+    pub sub_alignment: Option<SubAlignment>,
+    // Plusieurs caissons (`--subs`) : mesures par place et réglage proposé
+    pub multisub: Option<MultiSub>,
 
-    // Distances absolues estimées enceinte→micro (sweep uniquement, inclut latence système)
-    pub left_dist_m: Option<f32>,
-    pub right_dist_m: Option<f32>,
+    // Bruit de fond de la pièce (capture de silence), comparé à chaque mesure
+    pub noise: Option<NoiseFloor>,
 
-    // Résultats DSP
-    pub left_db: Option<Vec<f32>>,
-    pub right_db: Option<Vec<f32>>,
+    // Résultats de l'analyse comparative
     pub diff_db: Option<Vec<f32>>,
 
     pub delay_ms: f32,
     pub level_diff_db: f32,
     pub freq_tilt: f32,
     pub score: Option<u32>,
-    pub progress: f32,
+    // Retard G/D par octave (retard variable = filtre / phase, pas distance)
+    pub band_delay: Option<BandDelayReport>,
+    // Signe du pic principal des IR gauche et droite (câblage + / − inversé)
+    pub speaker_polarity: Option<(SpeakerPolarity, SpeakerPolarity)>,
+    // Premières réflexions de chaque IR et surfaces probables
+    pub reflections: Option<ReflectionReport>,
+    pub progress: Progress,
+    // Cycle en cours (rang, nombre) des captures moyennées
+    pub cycle: (u32, u32),
 
     pub error: Option<String>,
+    // Message d'information (sauvegarde, export…) affiché dans la barre d'état
+    pub status: Option<String>,
     pub history: Vec<HistoryEntry>,
+    // Journal des changements physiques ([/]) et saisie en cours
+    pub experiments: ExperimentLog,
+    pub experiment_input: Option<String>,
+    // Comparaison [&] : indices A et B dans l'historique
+    pub overlay: Option<(usize, usize)>,
+    // Curseur du spectre (← → ↑ ↓, clic) : bande lue en clair
+    pub spectrum_cursor: Option<usize>,
+    // Zoom et défilement du spectre (Maj + flèches, molette, PgPréc / PgSuiv)
+    pub spectrum_view: SpectrumView,
+    // Domaines de métriques du panneau de résultats ([F8] / [F9])
+    pub metrics: MetricTree,
+    // Positions d'enceintes candidates ([#]), classées par note composite
+    pub positions: Vec<CandidatePosition>,
+    // Places d'écoute nommées ([:]) et saisie du nom en cours
+    pub seats: Vec<Seat>,
+    pub seat_input: Option<String>,
+    // Asymétrie acceptée ([%]) : référence de conformité et saisie de la raison
+    pub baseline: Option<AcceptedAsymmetry>,
+    pub baseline_input: Option<String>,
+    // Température / humidité saisies ([$]), reportées sur chaque analyse,
+    // saisie en cours et panneau des corrélations ["]
+    pub ambient: Ambient,
+    pub ambient_input: Option<String>,
+    pub show_environment: bool,
+    // Notes de la prochaine analyse (micro, enceintes, pièce reportés) et
+    // saisie [F12] en cours
+    pub notes: MeasurementNotes,
+    pub notes_input: Option<NotesEditor>,
 
     pub out_device: String,
     pub in_device: String,
 
-    // Délai pré-capture (secondes) — évite d'enregistrer la frappe clavier
+    /// Taux d'échantillonnage de la session (Hz) : signaux générés, captures
+    /// converties et analyses (`--sample-rate`, sinon négocié).
+    pub sample_rate: u32,
+    // Délai pré-capture (secondes, pas de 0,1 s) — évite d'enregistrer la frappe clavier
     pub pre_delay_secs: f32,
+    // Départ programmé de chaque capture (seconde de la minute) et sa saisie [\]
+    pub start_schedule: Option<StartSchedule>,
+    pub schedule_input: Option<String>,
+    // Sweep de mesure (durée, bande, amplitude) — `speaker-align.toml` puis touches
+    pub sweep: SweepParams,
+    // Niveau de capture visé et dernière calibration automatique de l'amplitude
+    pub level: LevelParams,
+    /// Organe de réglage du niveau (`[gain]`) : unités des conseils de niveau.
+    pub gain: GainParams,
+    /// Déplacements permis et délai par canal (`[placement]`) : remède au retard.
+    pub placement: PlacementParams,
+    /// Dimensions de la pièce (`[room]` ou [$]) et leurs modes propres,
+    /// marqués sur le spectre ; recalculés par `set_room_dimensions` et
+    /// quand la vitesse du son change.
+    pub room_dimensions: Option<RoomDimensions>,
+    pub room_modes: Vec<RoomMode>,
+    /// Profil de score (`[score]`) : poids du score, seuils des conseils.
+    pub scoring: ScoreParams,
+    /// Touches réassignées (`[keys]`).
+    pub keys: KeyMap,
+    /// Périodes de rappel (`[reminders]`) et journal des vérifications
+    /// (âge de la calibration micro et de la latence de boucle).
+    pub reminder_params: ReminderParams,
+    pub checks: CheckLog,
+    pub level_cal: Option<LevelCalibration>,
+    // Liste de vérification avant la première capture (`--checklist`)
+    pub checklist: Option<Checklist>,
+    // Périphérique occupé : réessayer avec la config par défaut du système
+    pub retry_default_config: bool,
+    // Boucle électrique de référence (distances absolues), depuis la configuration
+    pub loopback: Option<Loopback>,
+    // Options des étapes rapides du pipeline (plage d'analyse…)
+    pub analysis: AnalysisOptions,
+
+    // Calibration du micro appliquée aux spectres (None = micro supposé plat)
+    pub calibration: Option<MicCalibration>,
+    // Calibration lue au démarrage (--mic-cal), reproposée par [']
+    pub calibration_file: Option<MicCalibration>,
+
+    // Courbe cible : écart de chaque enceinte, part du score (None = aucune)
+    pub target: Option<TargetCurve>,
+    // Cible lue au démarrage (--target), reproposée par [F5]
+    pub target_file: Option<TargetCurve>,
+    /// Masque de tolérance de l'écart G/D ([Maj+F5]), embarqué.
+    pub mask: Option<ToleranceMask>,
+
+    // Bass management : coupure configurée sur l'AVR et dernier rapport
+    pub crossover_hz: f32,
+    pub bass_report: Option<BassReport>,
+
+    // Polarité par haut-parleur (woofer / tweeter) des deux enceintes
+    pub polarity: Option<PolarityReport>,
+    // Rub & buzz / souffle d'évent des deux enceintes
+    pub rub_buzz: Option<RubBuzzReport>,
+    // Compression dépendante du niveau des deux enceintes
+    pub compression: Option<CompressionReport>,
+    // Linéarité du gain (paliers de −30 à −10 dBFS)
+    pub linearity: Option<LinearityReport>,
+    // Sonde de câblage des canaux et correspondance appliquée aux captures
+    pub channel_probe: Option<ChannelMapReport>,
+    pub channel_map: ChannelMap,
+    // Vérification rapide (bi-ton / bruit blanc) et stimulus choisi
+    pub quick_check: Option<QuickCheckReport>,
+    pub quick_stimulus: Stimulus,
+    // Distances rapides [0] (chirp de 0,3 s)
+    pub quick_distance: Option<DistanceReport>,
+    // Moyenne spatiale micro en mouvement (tonalité G / D)
+    pub mmm: Option<MmmReport>,
+    // Signatures de traitements audio du système (améliorations Windows…)
+    pub enhancements: Vec<Symptom>,
+    // Suivi en direct G / D (Some = captures en boucle)
+    pub watch: Option<WatchState>,
+    // Bips de guidage avant chaque sweep du suivi [F2]
+    pub nudge: bool,
+    // Suivi simultané G + D aux bruits décorrélés (Some = captures en boucle)
+    pub dual: Option<DualState>,
+
+    // Fichier de session utilisé par [S] / [O]
+    pub session_path: PathBuf,
+    // Partage [@] : garder l'audio brut (--share-with-audio)
+    pub share_audio: bool,
+    // Dossier des intermédiaires DSP écrits à chaque analyse (--debug-dump)
+    pub debug_dir: Option<PathBuf>,
+
+    // Fenêtre d'écoute multi-positions (Some = mode actif) et position en cours
+    pub window: Option<ListeningWindow>,
+    pub window_pos: Option<GridPosition>,
+    pub window_summary: Option<WindowSummary>,
+    // Captures G / D effectuées à la position courante (pas encore analysées)
+    pub window_fresh: (bool, bool),
+
+    // Panneau réponse impulsionnelle affiché
+    pub show_ir: bool,
+    // Zones spectrales (sub-grave… brillance) ombrées sous le spectre
+    pub show_regions: bool,
+    // Historique en tendance (score, |Δt|, |ΔL|) plutôt qu'en liste
+    pub show_trend: bool,
+    // Mode expert (tous les panneaux) ; sinon mode simple : captures, verdict, conseils
+    pub expert: bool,
 
     // Canal de communication inter-thread
     pub audio_rx: Option<mpsc::Receiver<AudioMsg>>,
+    // Arrêt de la capture en cours ([Échap])
+    pub cancel_tx: Option<mpsc::Sender<()>>,
 }
 
 impl AppState {
@@ -96,65 +566,623 @@ impl AppState {
         let (out, inp) = audio::default_device_names();
         AppState {
             step: Step::Idle,
-            left_samples: None,
-            right_samples: None,
-            left_test_signal: None,
-            right_test_signal: None,
-            left_dist_m: None,
-            right_dist_m: None,
-            left_db: None,
-            right_db: None,
+            left: None,
+            right: None,
+            others: Vec::new(),
+            reference: Channel::LEFT,
+            pairs: Vec::new(),
+            sub_output: Channel::LFE,
+            sub: None,
+            sub_alignment: None,
+            multisub: None,
+            noise: None,
             diff_db: None,
             delay_ms: 0.0,
             level_diff_db: 0.0,
             freq_tilt: 0.0,
             score: None,
-            progress: 0.0,
+            band_delay: None,
+            speaker_polarity: None,
+            reflections: None,
+            progress: Progress::default(),
+            cycle: (1, 1),
             error: None,
+            status: None,
             history: Vec::new(),
+            experiments: ExperimentLog::default(),
+            experiment_input: None,
+            positions: Vec::new(),
+            seats: Vec::new(),
+            seat_input: None,
+            baseline: None,
+            baseline_input: None,
+            ambient: Ambient::default(),
+            ambient_input: None,
+            show_environment: false,
+            notes: MeasurementNotes::default(),
+            notes_input: None,
+            overlay: None,
+            spectrum_cursor: None,
+            spectrum_view: SpectrumView::default(),
+            metrics: MetricTree::default(),
             out_device: out,
             in_device: inp,
+            sample_rate: SAMPLE_RATE,
             pre_delay_secs: 1.0,
+            start_schedule: None,
+            schedule_input: None,
+            sweep: SweepParams::default(),
+            level: LevelParams::default(),
+            gain: GainParams::default(),
+            placement: PlacementParams::default(),
+            room_dimensions: None,
+            room_modes: Vec::new(),
+            scoring: ScoreParams::default(),
+            keys: KeyMap::default(),
+            reminder_params: ReminderParams::default(),
+            checks: CheckLog::default(),
+            level_cal: None,
+            checklist: None,
+            retry_default_config: false,
+            loopback: None,
+            analysis: AnalysisOptions::default(),
+            calibration: None,
+            calibration_file: None,
+            target: None,
+            target_file: None,
+            mask: None,
+            crossover_hz: bass::DEFAULT_CROSSOVER_HZ,
+            bass_report: None,
+            polarity: None,
+            rub_buzz: None,
+            compression: None,
+            linearity: None,
+            channel_probe: None,
+            channel_map: ChannelMap::default(),
+            quick_check: None,
+            quick_distance: None,
+            mmm: None,
+            quick_stimulus: Stimulus::default(),
+            enhancements: Vec::new(),
+            watch: None,
+            nudge: false,
+            dual: None,
+            session_path: PathBuf::from(session::DEFAULT_SESSION_PATH),
+            share_audio: false,
+            debug_dir: None,
+            window: None,
+            window_pos: None,
+            window_summary: None,
+            window_fresh: (false, false),
+            show_ir: false,
+            show_regions: false,
+            show_trend: false,
+            expert: true,
             audio_rx: None,
+            cancel_tx: None,
         }
     }
 
     /// Lance la capture pour le canal donné dans un thread séparé.
+    /// Avec `level.auto`, la première capture de la session est précédée
+    /// de la calibration du niveau.
     pub fn start_capture(&mut self, channel: Channel) {
+        if !self.checklist_cleared() {
+            return;
+        }
+        if self.level.auto && self.level_cal.is_none() {
+            self.start_level_calibration(Some(channel));
+            return;
+        }
+        let step = match channel {
+            Channel::LEFT => Step::CapturingLeft,
+            Channel::RIGHT => Step::CapturingRight,
+            other => Step::CapturingChannel(other),
+        };
+        let sweep = self.sweep;
+        self.spawn_capture(
+            move |rate| dsp::generate_sweep(rate, &sweep),
+            channel,
+            sweep.capture_secs(),
+            step,
+        );
+    }
+
+    /// Mesure enchaînée : sweep gauche, silence `gap_s`, sweep droit, dans
+    /// une seule capture découpée ensuite en deux mesures. Avec `level.auto`,
+    /// la calibration du niveau passe d'abord (relancer ensuite).
+    pub fn start_pair_capture(&mut self) {
+        if !self.checklist_cleared() {
+            return;
+        }
+        if self.level.auto && self.level_cal.is_none() {
+            self.start_level_calibration(None);
+            return;
+        }
         let (tx, rx) = mpsc::channel::<AudioMsg>();
         self.audio_rx = Some(rx);
-        self.progress = 0.0;
+        let (cancel_tx, cancel_rx) = mpsc::channel::<()>();
+        self.cancel_tx = Some(cancel_tx);
+        self.progress = Progress::default();
+        self.cycle = (1, 1);
         self.error = None;
+        self.status = None;
 
-        let pre_delay_secs = self.pre_delay_secs;
+        let sample_rate = self.sample_rate;
+        let sweep = self.sweep;
+        let timing = CaptureTiming {
+            sample_rate,
+            capture_secs: sweep.pair_capture_secs(),
+            pre_delay_secs: self.pre_delay_secs,
+            start_at: self.start_schedule,
+        };
+        let retry_default_config = self.retry_default_config;
+        let loopback = self.loopback;
+        let (calibration, options) = (self.calibration.clone(), self.analysis);
+        let pre_delay_samples = (self.pre_delay_secs * sample_rate as f32) as usize;
+        let channels = (self.channel_map.resolve(Channel::LEFT), self.channel_map.resolve(Channel::RIGHT));
+        let repeats = sweep.averages.max(1);
 
         thread::spawn(move || {
-            let signal = dsp::generate_sweep(SAMPLE_RATE, SWEEP_DURATION);
+            let signal = dsp::generate_sweep(sample_rate, &sweep);
+            // Segment = sweep + silence ; le second s'arrête au sweep
+            let segment_len = (sweep.pair_offset_secs() * sample_rate as f32).round() as usize;
+            let mut sequence = signal.clone();
+            sequence.resize(segment_len, 0.0);
+            sequence.extend_from_slice(&signal);
+            // Fenêtre de chaque côté : pause et capture d'un sweep seul
+            let window = ((timing.pre_delay_secs + sweep.capture_secs()) * sample_rate as f32).round() as usize;
+
+            let result = (0..repeats)
+                .map(|i| {
+                    let (cycle_tx, cycle_rx) = mpsc::channel::<Progress>();
+                    let tx = tx.clone();
+                    thread::spawn(move || {
+                        while let Ok(p) = cycle_rx.recv() {
+                            let _ = tx.send(AudioMsg::Progress(p, (i + 1, repeats)));
+                        }
+                    });
+                    let link = CaptureLink { progress: cycle_tx, cancel: Some(&cancel_rx) };
+                    // Départ programmé : premier cycle seulement
+                    let timing = CaptureTiming { start_at: timing.start_at.filter(|_| i == 0), ..timing };
+                    let capture = audio::play_pair_and_capture(&sequence, channels, segment_len, timing, retry_default_config, loopback, link)?;
+                    // Chaque moitié ramenée sur l'horloge de la sortie
+                    let left = drift::correct(capture.segment(0, window), &signal, &sweep, sample_rate)?;
+                    let right = drift::correct(capture.segment(segment_len, window), &signal, &sweep, sample_rate)?;
+                    Ok((left, right))
+                })
+                .collect::<Result<Vec<_>>>()
+                .and_then(|cycles| {
+                    if cycles.len() > 1 {
+                        let _ = tx.send(AudioMsg::Progress(Progress::new(Phase::Averaging, 0.0), (repeats, repeats)));
+                    }
+                    let (lefts, rights): (Vec<_>, Vec<_>) = cycles.into_iter().unzip();
+                    let side = |cycles: Vec<(Capture, Option<f32>)>| -> Result<(Capture, Option<f32>)> {
+                        let (captures, drifts): (Vec<Capture>, Vec<Option<f32>>) = cycles.into_iter().unzip();
+                        let capture = Capture::average(captures, &signal, &sweep, sample_rate).context(tr().err_no_capture)?;
+                        Ok((capture, drift::mean(&drifts)))
+                    };
+                    Ok((side(lefts)?, side(rights)?))
+                });
+            match result {
+                Ok(((left, left_drift), (right, right_drift))) => {
+                    let deconvolving = |fraction| AudioMsg::Progress(Progress::new(Phase::Deconvolving, fraction), (repeats, repeats));
+                    let _ = tx.send(deconvolving(0.0));
+                    let measure = |channel, capture, clock_drift_ppm| {
+                        let mut m = Measurement::from_capture(channel, capture, signal.clone(), sweep, sample_rate, calibration.as_ref(), options);
+                        m.clock_drift_ppm = clock_drift_ppm;
+                        m.analyze_ir(pre_delay_samples, options.onset_db);
+                        m
+                    };
+                    let left = measure(Channel::LEFT, left, left_drift);
+                    let _ = tx.send(deconvolving(0.5));
+                    let right = measure(Channel::RIGHT, right, right_drift);
+                    let _ = tx.send(AudioMsg::MeasuredPair(Box::new((left, right))));
+                }
+                Err(e) => {
+                    let _ = tx.send(failure(e, retry_default_config));
+                }
+            }
+        });
+        self.step = Step::CapturingPair;
+    }
+
+    /// Capture le caisson sur sa sortie (`sub_output`) pour l'aligner.
+    pub fn start_sub_capture(&mut self) {
+        if !self.checklist_cleared() {
+            return;
+        }
+        let sweep = self.sweep;
+        self.spawn_capture(
+            move |rate| dsp::generate_sweep(rate, &sweep),
+            self.sub_output,
+            sweep.capture_secs(),
+            Step::CapturingSub,
+        );
+    }
+
+    /// Mesure chaque caisson de la session multi-caissons à la place actuelle
+    /// du micro, l'un après l'autre.
+    pub fn start_multisub_seat(&mut self) {
+        let Some(multisub) = self.multisub.as_mut() else {
+            self.error = Some((tr().multisub_needs_subs)(multisub::MIN_SUBS, multisub::MAX_SUBS));
+            return;
+        };
+        // Retards relatifs entre captures : seule la boucle en donne l'origine
+        if self.loopback.is_none() {
+            self.error = Some(tr().multisub_needs_loopback.into());
+            return;
+        }
+        multisub.pending.clear();
+        if self.checklist_cleared() {
+            self.spawn_multisub(0);
+        }
+    }
+
+    /// Sweep sur le caisson `index` de la session multi-caissons.
+    fn spawn_multisub(&mut self, index: usize) {
+        let Some(&output) = self.multisub.as_ref().and_then(|m| m.outputs.get(index)) else { return };
+        let sweep = self.sweep;
+        self.spawn_capture(
+            move |rate| dsp::generate_sweep(rate, &sweep),
+            output,
+            sweep.capture_secs(),
+            Step::CapturingSubs(index),
+        );
+    }
+
+    /// Oublie la place en cours de mesure, sinon la dernière place complète,
+    /// pour la reprendre à [F3].
+    pub fn drop_multisub_seat(&mut self) {
+        let Some(multisub) = self.multisub.as_mut() else { return };
+        multisub.drop_last_seat();
+        self.status = Some((tr().multisub_dropped)(multisub.seats.len()));
+    }
+
+    /// Range la réponse d'un caisson ; lance le suivant, ou annonce le réglage
+    /// une fois la place complète. Une capture sans arrivée dans la boucle
+    /// abandonne la place.
+    fn store_multisub(&mut self, index: usize, m: &Measurement) {
+        self.step = Step::Idle;
+        let ir = m.ir.clone().unwrap_or_else(|| dsp::compute_impulse_response(&m.samples, &m.test_signal, &m.sweep, m.sample_rate));
+        let Some(multisub) = self.multisub.as_mut() else { return };
+        let Some(origin) = m.loopback_origin else {
+            multisub.pending.clear();
+            self.error = Some(tr().multisub_no_arrival.into());
+            return;
+        };
+        if !multisub.record(multisub::response(&ir, m.sample_rate, origin)) {
+            self.spawn_multisub(index + 1);
+            return;
+        }
+        self.status = Some(match &multisub.plan {
+            Some(plan) => (tr().multisub_planned)(multisub.seats.len(), plan.spread_before_db, plan.spread_after_db),
+            None => (tr().multisub_next_seat)(multisub.seats.len(), multisub::MIN_SEATS),
+        });
+    }
+
+    /// Vrai si les mesures peuvent démarrer ; sinon ouvre la liste de
+    /// vérification, à valider une fois par session.
+    fn checklist_cleared(&mut self) -> bool {
+        match self.checklist.as_mut() {
+            Some(checklist) if !checklist.acknowledged => {
+                checklist.open = true;
+                self.status = Some(tr().checklist_first.into());
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Ce que les mesures de la session disent des points de la liste.
+    pub fn checklist_evidence(&self) -> Evidence<'_> {
+        Evidence {
+            noise: self.noise.as_ref(),
+            target_dbfs: self.level.target_dbfs,
+            level_cal: self.level_cal.as_ref(),
+            linearity_done: self.linearity.as_ref().is_some_and(|r| r.left.is_some() || r.right.is_some()),
+            analyzed: self.score.is_some(),
+            enhancements: &self.enhancements,
+        }
+    }
+
+    /// Valide la liste si tous les points sont cochés ou vérifiés.
+    pub fn acknowledge_checklist(&mut self) {
+        let Some(checklist) = &self.checklist else { return };
+        if !checklist.is_complete(&self.checklist_evidence()) {
+            self.error = Some(tr().checklist_incomplete.into());
+            return;
+        }
+        if let Some(checklist) = self.checklist.as_mut() {
+            checklist.acknowledged = true;
+            checklist.open = false;
+        }
+        self.error = None;
+        self.status = Some(tr().checklist_done.into());
+    }
+
+    /// Joue la salve de bruit rose sur l'enceinte gauche pour régler
+    /// l'amplitude du sweep ; `then` est capturé ensuite.
+    pub fn start_level_calibration(&mut self, then: Option<Channel>) {
+        let sweep = self.sweep;
+        self.spawn_capture(
+            move |rate| levelcal::burst(rate, &sweep),
+            Channel::LEFT,
+            levelcal::BURST_SECS + 0.5,
+            Step::CalibratingLevel(then),
+        );
+    }
+
+    /// Capte quelques secondes de silence (aucun signal joué) pour mesurer
+    /// le bruit de fond.
+    pub fn start_noise_capture(&mut self) {
+        self.spawn_capture(
+            |rate| vec![0.0; (noise::NOISE_SECS * rate as f32) as usize],
+            Channel::LEFT,
+            noise::NOISE_SECS,
+            Step::CapturingNoise,
+        );
+    }
+
+    /// Lance la vérification du bass management : séquence de bruits filtrés
+    /// jouée sur l'enceinte gauche (principale).
+    pub fn start_bass_test(&mut self) {
+        self.bass_report = None;
+        let capture_secs = bass::segments(self.sample_rate)
+            .last()
+            .map(|(s, l)| (s + l) as f32 / self.sample_rate as f32)
+            .unwrap_or(0.0)
+            + 1.5;
+        self.spawn_capture(
+            bass::generate_test_signal,
+            Channel::LEFT,
+            capture_secs,
+            Step::CapturingBass,
+        );
+    }
+
+    /// Lance un test de diagnostic sur l'enceinte gauche ; la droite est
+    /// enchaînée automatiquement à la fin de la première capture.
+    pub fn start_stereo_test(&mut self, test: StereoTest) {
+        match test {
+            StereoTest::Polarity => self.polarity = Some(PolarityReport::default()),
+            StereoTest::RubBuzz => self.rub_buzz = Some(RubBuzzReport::default()),
+            StereoTest::Compression => self.compression = Some(CompressionReport::default()),
+            StereoTest::Linearity => self.linearity = Some(LinearityReport::default()),
+            StereoTest::QuickCheck(stimulus) => self.quick_check = Some(QuickCheckReport { stimulus, ..Default::default() }),
+            StereoTest::Distance => self.quick_distance = Some(DistanceReport::default()),
+            StereoTest::Mmm => self.mmm = Some(MmmReport::default()),
+        }
+        self.spawn_stereo_test(test, Channel::LEFT);
+    }
+
+    fn spawn_stereo_test(&mut self, test: StereoTest, channel: Channel) {
+        self.spawn_capture(
+            test.signal_fn(),
+            channel,
+            test.sequence_secs() + 1.0,
+            Step::CapturingTest(test, channel),
+        );
+        if test == StereoTest::Mmm {
+            let side = if channel == Channel::LEFT { tr().left_name } else { tr().right_name };
+            self.status = Some((tr().mmm_walk)(side, mmm::MMM_SECS));
+        }
+    }
+
+    /// Sonde de câblage : micro contre l'enceinte gauche, puis (second appui)
+    /// contre la droite. La correspondance n'est appliquée qu'une fois les
+    /// deux emplacements identifiés.
+    pub fn start_channel_probe(&mut self) {
+        let channel = match &self.channel_probe {
+            Some(report) if report.left.is_some() && report.right.is_none() => Channel::RIGHT,
+            _ => {
+                self.channel_probe = Some(ChannelMapReport::default());
+                Channel::LEFT
+            }
+        };
+        self.spawn_capture(
+            channelmap::generate_probe_signal,
+            channel,
+            channelmap::sequence_secs() + 1.0,
+            Step::ProbingChannels(channel),
+        );
+    }
+
+    /// Active / arrête le suivi en direct. À l'arrêt, la capture en cours
+    /// se termine mais n'est pas suivie d'une autre.
+    pub fn toggle_watch(&mut self) {
+        if self.watch.take().is_some() {
+            self.status = Some(tr().watch_stopped.into());
+            return;
+        }
+        self.watch = Some(WatchState::default());
+        self.spawn_watch_capture();
+    }
+
+    /// Sweep court sur l'enceinte attendue par le suivi, précédé des bips
+    /// de guidage s'ils sont activés (joués à part, hors capture).
+    fn spawn_watch_capture(&mut self) {
+        let sweep = watch::sweep(&self.sweep);
+        let Some(state) = self.watch.as_ref() else { return };
+        let channel = state.next;
+        let cue = match self.nudge {
+            true => nudge::feedback(state.latest(), self.sample_rate, sweep.amplitude * nudge::RELATIVE_LEVEL),
+            false => Vec::new(),
+        };
+        self.spawn_cued_capture(
+            cue,
+            move |rate| dsp::generate_sweep(rate, &sweep),
+            channel,
+            sweep.capture_secs(),
+            Step::Watching(channel),
+        );
+    }
+
+    /// [F2] : active / coupe les bips de guidage du suivi en direct.
+    pub fn toggle_nudge(&mut self) {
+        self.nudge = !self.nudge;
+        self.status = Some(if self.nudge { tr().nudge_on } else { tr().nudge_off }.into());
+    }
+
+    /// Active / arrête le suivi simultané G + D. À l'arrêt, la capture en
+    /// cours se termine mais n'est pas suivie d'une autre.
+    pub fn toggle_dual(&mut self) {
+        if self.dual.take().is_some() {
+            self.status = Some(tr().dual_stopped.into());
+            return;
+        }
+        self.dual = Some(DualState::default());
+        self.spawn_dual_capture();
+    }
+
+    /// Bruits décorrélés joués ensemble sur la gauche et la droite.
+    fn spawn_dual_capture(&mut self) {
+        self.spawn_capture(
+            dualnoise::generate_test_signal,
+            Channel::LEFT,
+            dualnoise::sequence_secs(self.sample_rate) + 0.5,
+            Step::CapturingDual,
+        );
+    }
+
+    /// Joue le signal produit par `make_signal` (au taux de la session) et
+    /// capture le micro dans un
+    /// thread séparé ; le résultat revient par `audio_rx`. `channel` désigne
+    /// l'enceinte physique : il passe par `channel_map` avant la lecture.
+    fn spawn_capture(
+        &mut self,
+        make_signal: impl FnOnce(u32) -> Vec<f32> + Send + 'static,
+        channel: Channel,
+        capture_secs: f32,
+        step: Step,
+    ) {
+        self.spawn_cued_capture(Vec::new(), make_signal, channel, capture_secs, step);
+    }
+
+    /// Comme `spawn_capture`, en jouant d'abord `cue` (bips de guidage) sur
+    /// la même enceinte, sans pause : la capture du sweep ne commence
+    /// qu'ensuite. Un échec de ce passage n'arrête que sur [Échap].
+    fn spawn_cued_capture(
+        &mut self,
+        cue: Vec<f32>,
+        make_signal: impl FnOnce(u32) -> Vec<f32> + Send + 'static,
+        channel: Channel,
+        capture_secs: f32,
+        step: Step,
+    ) {
+        let (tx, rx) = mpsc::channel::<AudioMsg>();
+        self.audio_rx = Some(rx);
+        let (cancel_tx, cancel_rx) = mpsc::channel::<()>();
+        self.cancel_tx = Some(cancel_tx);
+        self.progress = Progress::default();
+        self.cycle = (1, 1);
+        self.error = None;
+        self.status = None;
 
-            let (prog_tx, prog_rx) = mpsc::channel::<f32>();
+        let sample_rate = self.sample_rate;
+        // Les suivis en direct enchaînent leurs captures sans attendre
+        let start_at = if matches!(step, Step::Watching(_) | Step::CapturingDual) { None } else { self.start_schedule };
+        let timing = CaptureTiming { sample_rate, capture_secs, pre_delay_secs: self.pre_delay_secs, start_at };
+        let retry_default_config = self.retry_default_config;
+        let loopback = self.loopback;
+        let probe = matches!(step, Step::ProbingChannels(_));
+        let dual = matches!(step, Step::CapturingDual);
+        let pair = (self.channel_map.resolve(Channel::LEFT), self.channel_map.resolve(Channel::RIGHT));
+        let measured = step.is_sweep_capture();
+        let (calibration, options) = (self.calibration.clone(), self.analysis);
+        let pre_delay_samples = (self.pre_delay_secs * sample_rate as f32) as usize;
+        let logical = channel;
+        let channel = self.channel_map.resolve(channel);
+        // Captures au sweep : `averages` cycles lecture/capture, moyennés
+        let sweep = self.sweep;
+        let repeats = if step.is_sweep_capture() { sweep.averages.max(1) } else { 1 };
+        // Sweep analysé dans ce thread : mesure, ou sweep court du suivi
+        let analysed = match step {
+            Step::Watching(_) => Some(watch::sweep(&sweep)),
+            _ => measured.then_some(sweep),
+        };
 
-            // Thread de progression
-            let tx2 = tx.clone();
-            thread::spawn(move || {
-                while let Ok(p) = prog_rx.recv() {
-                    let _ = tx2.send(AudioMsg::Progress(p));
+        thread::spawn(move || {
+            if !cue.is_empty() {
+                let cue_timing = CaptureTiming { sample_rate, capture_secs: cue.len() as f32 / sample_rate as f32, pre_delay_secs: 0.0, start_at: None };
+                let (cue_tx, _) = mpsc::channel::<Progress>();
+                let link = CaptureLink { progress: cue_tx, cancel: Some(&cancel_rx) };
+                if let Err(e) = audio::play_and_capture(&cue, channel, cue_timing, retry_default_config, None, link) {
+                    if matches!(e.downcast_ref::<AudioError>(), Some(AudioError::Cancelled)) {
+                        let _ = tx.send(AudioMsg::Cancelled);
+                        return;
+                    }
                 }
-            });
+            }
+            let signal = make_signal(sample_rate);
 
-            match audio::play_and_capture(&signal, channel, CAPTURE_DURATION, pre_delay_secs, prog_tx) {
-                Ok(samples) => {
-                    let _ = tx.send(AudioMsg::Done(samples, signal));
+            let result = (0..repeats)
+                .map(|i| {
+                    // Progression du cycle, avec son rang parmi les cycles
+                    let (cycle_tx, cycle_rx) = mpsc::channel::<Progress>();
+                    let tx = tx.clone();
+                    thread::spawn(move || {
+                        while let Ok(p) = cycle_rx.recv() {
+                            let _ = tx.send(AudioMsg::Progress(p, (i + 1, repeats)));
+                        }
+                    });
+                    let link = CaptureLink { progress: cycle_tx, cancel: Some(&cancel_rx) };
+                    // Départ programmé : premier cycle seulement
+                    let timing = CaptureTiming { start_at: timing.start_at.filter(|_| i == 0), ..timing };
+                    let capture = if probe {
+                        let segment_len = channelmap::segment_len(sample_rate);
+                        audio::play_probe_and_capture(&signal, segment_len, timing, retry_default_config, loopback, link)?
+                    } else if dual {
+                        audio::play_dual_and_capture(&signal, pair, timing, retry_default_config, link)?
+                    } else {
+                        audio::play_and_capture(&signal, channel, timing, retry_default_config, loopback, link)?
+                    };
+                    // Chaque cycle au sweep ramené sur l'horloge de la sortie
+                    if measured {
+                        drift::correct(capture, &signal, &sweep, sample_rate)
+                    } else {
+                        Ok((capture, None))
+                    }
+                })
+                .collect::<Result<Vec<(Capture, Option<f32>)>>>()
+                .and_then(|cycles| {
+                    if cycles.len() > 1 {
+                        let _ = tx.send(AudioMsg::Progress(Progress::new(Phase::Averaging, 0.0), (repeats, repeats)));
+                    }
+                    let (captures, drifts): (Vec<Capture>, Vec<Option<f32>>) = cycles.into_iter().unzip();
+                    let capture = Capture::average(captures, &signal, &sweep, sample_rate).context(tr().err_no_capture)?;
+                    Ok((capture, drift::mean(&drifts)))
+                });
+            match (result, analysed) {
+                // Mesure au sweep (suivi compris) : spectre et déconvolution
+                // ici, pas dans l'interface
+                (Ok((capture, clock_drift_ppm)), Some(sweep)) => {
+                    let deconvolving = |fraction| AudioMsg::Progress(Progress::new(Phase::Deconvolving, fraction), (repeats, repeats));
+                    let _ = tx.send(deconvolving(0.0));
+                    let mut m = Measurement::from_capture(logical, capture, signal, sweep, sample_rate, calibration.as_ref(), options);
+                    m.clock_drift_ppm = clock_drift_ppm;
+                    let _ = tx.send(deconvolving(0.5));
+                    m.analyze_ir(pre_delay_samples, options.onset_db);
+                    let _ = tx.send(AudioMsg::Measured(Box::new(m)));
                 }
-                Err(e) => {
-                    let _ = tx.send(AudioMsg::Error(e.to_string()));
+                // Bruits simultanés : les deux déconvolutions ici aussi
+                (Ok((capture, _)), None) if dual => {
+                    let analysis = dualnoise::analyze(&capture.mono, &signal, sample_rate, pre_delay_samples, calibration.as_ref(), &options);
+                    let _ = tx.send(match analysis {
+                        Some(m) => AudioMsg::MeasuredDual(Box::new(m)),
+                        None => AudioMsg::Error(tr().dual_too_short.into()),
+                    });
+                }
+                (Ok((capture, _)), None) => {
+                    let _ = tx.send(AudioMsg::Done(capture, signal));
+                }
+                (Err(e), _) => {
+                    let _ = tx.send(failure(e, retry_default_config));
                 }
             }
         });
 
-        self.step = match channel {
-            Channel::Left => Step::CapturingLeft,
-            Channel::Right => Step::CapturingRight,
-        };
+        self.step = step;
     }
 
     /// Dépile les messages audio reçus du thread de capture.
@@ -166,129 +1194,1258 @@ impl AppState {
         };
 
         match msg {
-            Some(AudioMsg::Progress(p)) => self.progress = p,
-            Some(AudioMsg::Done(samples, test_signal)) => {
-                self.run_dsp(samples, test_signal);
+            Some(AudioMsg::Progress(p, cycle)) => {
+                self.progress = p;
+                self.cycle = cycle;
+            }
+            Some(AudioMsg::Done(capture, test_signal)) => {
+                self.run_dsp(capture, test_signal);
             }
+            Some(AudioMsg::Measured(m)) => self.store_measurement(*m),
+            Some(AudioMsg::MeasuredPair(pair)) => self.store_pair(*pair),
+            Some(AudioMsg::MeasuredDual(m)) => self.store_dual(*m),
             Some(AudioMsg::Error(e)) => {
                 self.error = Some(e);
                 self.step = Step::Idle;
                 self.audio_rx = None;
+                self.cancel_tx = None;
+                // Une erreur de périphérique se répéterait à chaque capture
+                self.watch = None;
+                self.dual = None;
+            }
+            Some(AudioMsg::Cancelled) => {
+                self.status = Some(tr().err_cancelled.into());
+                self.step = Step::Idle;
+                self.audio_rx = None;
+                self.cancel_tx = None;
+                // Le suivi relancerait aussitôt une capture
+                self.watch = None;
+                self.dual = None;
             }
             None => {}
         }
     }
 
-    /// Calcule le spectre après réception des échantillons.
-    fn run_dsp(&mut self, samples: Vec<f32>, test_signal: Vec<f32>) {
-        // Filtre passe-haut 30 Hz : supprime le bruit de ronflement ambiant
-        // (ventilateurs PC, vibrations bureau) sans affecter la plage utile
-        let filtered = dsp::highpass_filter(&samples, 30.0, SAMPLE_RATE);
-        let spectrum = dsp::compute_fft(&filtered);
-        let bands = dsp::spectrum_to_bands(&spectrum, SAMPLE_RATE, NUM_BANDS);
-        let bands_db = dsp::bands_to_db(&bands);
+    /// Une saisie de texte est ouverte ([/], [\], [$], [%], [:], [F12]).
+    pub fn is_typing(&self) -> bool {
+        self.experiment_input.is_some()
+            || self.notes_input.is_some()
+            || self.schedule_input.is_some()
+            || self.ambient_input.is_some()
+            || self.baseline_input.is_some()
+            || self.seat_input.is_some()
+    }
+
+    /// Demande l'arrêt de la capture en cours ; la confirmation revient par
+    /// `AudioMsg::Cancelled`.
+    pub fn cancel_capture(&mut self) {
+        if let Some(tx) = &self.cancel_tx {
+            let _ = tx.send(());
+            self.status = Some(tr().capture_stopping.into());
+        }
+    }
 
+    /// Range une mesure au sweep reçue du thread de capture.
+    fn store_measurement(&mut self, m: Measurement) {
+        self.audio_rx = None;
+        self.cancel_tx = None;
+        if let Step::Watching(_) = self.step {
+            return self.store_watch(m);
+        }
+        self.note_loopback(&m);
         match self.step {
             Step::CapturingLeft => {
-                self.left_samples = Some(filtered);
-                self.left_test_signal = Some(test_signal);
-                self.left_db = Some(bands_db);
-                self.step = Step::Idle;
+                self.left = Some(m);
+                self.window_fresh.0 = true;
             }
             Step::CapturingRight => {
-                self.right_samples = Some(filtered);
-                self.right_test_signal = Some(test_signal);
-                self.right_db = Some(bands_db);
-                self.step = Step::Idle;
+                self.right = Some(m);
+                self.window_fresh.1 = true;
+            }
+            Step::CapturingChannel(channel) => {
+                self.others.retain(|o| o.channel != channel);
+                self.others.push(m);
+                self.others.sort_by_key(|o| o.channel);
             }
+            Step::CapturingSub => {
+                self.sub = Some(m);
+                self.align_sub();
+            }
+            // Lance le caisson suivant, ou repasse au repos
+            Step::CapturingSubs(index) => return self.store_multisub(index, &m),
             _ => {}
         }
+        self.step = Step::Idle;
+    }
+
+    /// Range une capture du suivi en direct et lance la suivante.
+    fn store_watch(&mut self, m: Measurement) {
+        self.step = Step::Idle;
+        // Suivi arrêté pendant la capture : on l'ignore
+        let Some(state) = self.watch.as_mut() else { return };
+        state.record(m, &self.analysis);
+        self.spawn_watch_capture();
+    }
+
+    /// Range une lecture du suivi simultané G + D et lance la suivante.
+    fn store_dual(&mut self, m: DualMeasurement) {
         self.audio_rx = None;
+        self.cancel_tx = None;
+        self.step = Step::Idle;
+        // Suivi arrêté pendant la capture : on l'ignore
+        let Some(state) = self.dual.as_mut() else { return };
+        state.record(m);
+        self.spawn_dual_capture();
     }
 
-    /// Lance l'analyse comparative une fois les deux captures effectuées.
-    pub fn analyze(&mut self) {
-        let (left_s, right_s) = match (&self.left_samples, &self.right_samples) {
-            (Some(l), Some(r)) => (l.clone(), r.clone()),
-            _ => return,
+    /// Range les deux moitiés d'une mesure enchaînée.
+    fn store_pair(&mut self, (left, right): (Measurement, Measurement)) {
+        self.audio_rx = None;
+        self.cancel_tx = None;
+        self.note_loopback(&left);
+        self.note_loopback(&right);
+        self.left = Some(left);
+        self.right = Some(right);
+        self.window_fresh = (true, true);
+        self.step = Step::Idle;
+        self.status = Some(tr().chain_done.into());
+    }
+
+    /// Latence de boucle tout juste mesurée (localisée par le thread de
+    /// capture) : son âge repart de zéro (une mesure rechargée d'une
+    /// session ne compte pas).
+    fn note_loopback(&mut self, m: &Measurement) {
+        if m.loopback.is_none() {
+            return;
+        }
+        if let Some(latency_ms) = m.latency_ms {
+            self.checks.note_loopback(latency_ms, &self.out_device, &self.in_device, reminders::now_unix());
+            self.save_checks();
+        }
+    }
+
+    /// Traite une capture reçue hors mesure au sweep (niveau, tests, sonde…).
+    fn run_dsp(&mut self, capture: Capture, test_signal: Vec<f32>) {
+        self.audio_rx = None;
+        self.cancel_tx = None;
+        match self.step {
+            Step::CalibratingLevel(then) => {
+                self.step = Step::Idle;
+                let Some(cal) = levelcal::calibrate(&capture.mono, &test_signal, &self.sweep, self.level.target_dbfs, self.sample_rate) else {
+                    self.error = Some(tr().level_no_burst.into());
+                    return;
+                };
+                self.sweep.amplitude = cal.amplitude;
+                self.level_cal = Some(cal);
+                let mut status = (tr().level_set)(cal.burst_dbfs, self.sweep.amplitude_dbfs(), cal.expected_dbfs);
+                if cal.limited {
+                    status.push_str(tr().level_limited);
+                }
+                self.status = Some(status);
+                if let Some(channel) = then {
+                    self.start_capture(channel);
+                }
+            }
+            Step::CapturingNoise => {
+                self.noise = Some(NoiseFloor::from_capture(&capture.mono, self.sample_rate, self.calibration.as_ref(), self.analysis));
+                self.step = Step::Idle;
+            }
+            Step::CapturingBass => {
+                self.bass_report = bass::analyze(&capture.mono, &test_signal, self.sample_rate, self.crossover_hz);
+                if self.bass_report.is_none() {
+                    self.error = Some(tr().bass_not_detected.into());
+                }
+                self.step = Step::Idle;
+            }
+            Step::CapturingTest(test, channel) => {
+                let stored = match test {
+                    StereoTest::Polarity => polarity::analyze(&capture.mono, &test_signal, self.sample_rate).map(|bands| {
+                        let report = self.polarity.get_or_insert_with(PolarityReport::default);
+                        match channel {
+                            Channel::LEFT => report.left = Some(bands),
+                            _ => report.right = Some(bands),
+                        }
+                    }),
+                    StereoTest::RubBuzz => distortion::analyze(&capture.mono, &test_signal, self.sample_rate).map(|bursts| {
+                        let report = self.rub_buzz.get_or_insert_with(RubBuzzReport::default);
+                        match channel {
+                            Channel::LEFT => report.left = Some(bursts),
+                            _ => report.right = Some(bursts),
+                        }
+                    }),
+                    StereoTest::Compression => compression::analyze(&capture.mono, &test_signal, self.sample_rate).map(|c| {
+                        let report = self.compression.get_or_insert_with(CompressionReport::default);
+                        match channel {
+                            Channel::LEFT => report.left = Some(c),
+                            _ => report.right = Some(c),
+                        }
+                    }),
+                    StereoTest::Linearity => linearity::analyze(&capture.mono, &test_signal, self.sample_rate).map(|l| {
+                        let report = self.linearity.get_or_insert_with(LinearityReport::default);
+                        match channel {
+                            Channel::LEFT => report.left = Some(l),
+                            _ => report.right = Some(l),
+                        }
+                    }),
+                    StereoTest::QuickCheck(stimulus) => {
+                        quickcheck::analyze(&capture.mono, &test_signal, stimulus, self.sample_rate).map(|c| {
+                            let report = self.quick_check.get_or_insert_with(QuickCheckReport::default);
+                            match channel {
+                                Channel::LEFT => report.left = Some(c),
+                                _ => report.right = Some(c),
+                            }
+                        })
+                    }
+                    StereoTest::Distance => {
+                        let pre_delay = (self.pre_delay_secs * self.sample_rate as f32) as usize;
+                        distance::analyze(&capture, &test_signal, self.sample_rate, pre_delay, self.analysis.onset_db).map(|(d, absolute)| {
+                            let report = self.quick_distance.get_or_insert_with(DistanceReport::default);
+                            match channel {
+                                Channel::LEFT => {
+                                    report.left = Some(d);
+                                    report.absolute = absolute;
+                                }
+                                _ => {
+                                    report.right = Some(d);
+                                    report.absolute &= absolute;
+                                }
+                            }
+                        })
+                    }
+                    StereoTest::Mmm => {
+                        mmm::analyze(&capture.mono, self.sample_rate, self.calibration.as_ref(), self.analysis.smoothing).map(|avg| {
+                            let report = self.mmm.get_or_insert_with(MmmReport::default);
+                            match channel {
+                                Channel::LEFT => report.left = Some(avg),
+                                _ => report.right = Some(avg),
+                            }
+                        })
+                    }
+                };
+                if test == StereoTest::Linearity {
+                    self.detect_enhancements();
+                }
+                match (stored, channel) {
+                    (None, _) => {
+                        self.error = Some((tr().test_not_detected)(test.label()));
+                        self.step = Step::Idle;
+                    }
+                    // Enchaîne sur l'enceinte droite
+                    (Some(()), Channel::LEFT) => self.spawn_stereo_test(test, Channel::RIGHT),
+                    (Some(()), _) => self.step = Step::Idle,
+                }
+            }
+            Step::ProbingChannels(channel) => {
+                self.step = Step::Idle;
+                let Some(probe) = channelmap::analyze(&capture.mono, &test_signal, self.sample_rate) else {
+                    self.error = Some(tr().wiring_not_detected.into());
+                    return;
+                };
+                let report = self.channel_probe.get_or_insert_with(ChannelMapReport::default);
+                // Après la gauche, le panneau invite à déplacer le micro
+                match channel {
+                    Channel::LEFT => report.left = Some(probe),
+                    _ => {
+                        report.right = Some(probe);
+                        if let Some(map) = report.map() {
+                            self.channel_map = map;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Alignement caisson / principales à la coupure (enceinte GAUCHE comme
+    /// référence ; requiert les deux captures).
+    pub fn align_sub(&mut self) {
+        let (Some(sub), Some(mains)) = (&self.sub, &self.left) else {
+            self.sub_alignment = None;
+            return;
         };
+        let pre_delay_samples = (self.pre_delay_secs * self.sample_rate as f32) as usize;
+        self.sub_alignment = sub::align(sub, mains, self.crossover_hz, pre_delay_samples, self.analysis.onset_db);
+        if self.sub_alignment.is_none() {
+            self.error = Some(tr().sub_no_arrival.into());
+        }
+    }
+
+    /// Cherche dans les IR et le test de linéarité les signatures des
+    /// traitements audio du système, qui faussent toute comparaison.
+    pub fn detect_enhancements(&mut self) {
+        self.enhancements = enhancements::detect(self.left.as_ref(), self.right.as_ref(), self.linearity.as_ref());
+    }
+
+    /// Compare chaque enceinte capturée à la référence (`reference`).
+    pub fn compare_speakers(&mut self) {
+        let pre_delay_samples = (self.pre_delay_secs * self.sample_rate as f32) as usize;
+        // IR déjà calculée par le thread de capture, mais pas encore localisée
+        let all = self.left.iter_mut().chain(self.right.iter_mut()).chain(self.others.iter_mut());
+        for m in all.filter(|m| m.dist_m.is_none() && m.has_audio()) {
+            m.analyze_ir(pre_delay_samples, self.analysis.onset_db);
+        }
+        let all = self.left.iter().chain(self.right.iter()).chain(self.others.iter());
+        self.pairs = multichannel::compare_all(self.reference, all, &self.analysis, self.target_db(), self.mask_db(), &self.weights());
+    }
+
+    /// Canaux capturés, dans l'ordre des sorties.
+    pub fn captured_channels(&self) -> Vec<Channel> {
+        self.left.iter().chain(self.right.iter()).chain(self.others.iter()).map(|m| m.channel).collect()
+    }
+
+    /// Gain et retard de chaque enceinte comparée, relatifs à la référence ;
+    /// vide avant la comparaison.
+    pub fn channel_trims(&self) -> Vec<multichannel::ChannelTrim> {
+        if self.pairs.is_empty() {
+            return Vec::new();
+        }
+        let reference_dist = self
+            .left
+            .iter()
+            .chain(self.right.iter())
+            .chain(self.others.iter())
+            .find(|m| m.channel == self.reference)
+            .and_then(|m| m.dist_m);
+        multichannel::trims(self.reference, reference_dist, &self.pairs)
+    }
+
+    /// Passe la référence à l'enceinte capturée suivante et recompare.
+    pub fn cycle_reference(&mut self) {
+        let channels = self.captured_channels();
+        if channels.is_empty() {
+            return;
+        }
+        let next = channels.iter().position(|c| *c == self.reference).map(|i| i + 1).unwrap_or(0);
+        self.reference = channels[next % channels.len()];
+        self.compare_speakers();
+    }
+
+    /// Vrai si les deux enceintes sont capturées — et, en mode fenêtre
+    /// d'écoute, si elles l'ont été à la position courante.
+    pub fn can_analyze(&self) -> bool {
+        // Une session partagée sans audio se consulte, elle ne se réanalyse pas
+        let captured = [&self.left, &self.right].iter().all(|m| m.as_ref().is_some_and(Measurement::has_audio));
+        match self.window {
+            Some(_) => captured && self.window_fresh == (true, true),
+            None => captured,
+        }
+    }
 
-        let (left_db, right_db) = match (&self.left_db, &self.right_db) {
-            (Some(l), Some(r)) => (l.clone(), r.clone()),
-            _ => return,
+    /// Lance l'analyse comparative une fois les deux captures effectuées :
+    /// IR (une fois par capture), comparaison, point de la fenêtre d'écoute,
+    /// historique.
+    pub fn analyze(&mut self) {
+        let (Some(left), Some(right)) = (&mut self.left, &mut self.right) else {
+            return;
         };
 
         self.step = Step::Analyzing;
 
-        // Distances absolues (sweep uniquement — requiert le signal de référence).
+        // Distances absolues, EDT/C50/C80 et IACC depuis la réponse impulsionnelle.
         // On soustrait le pre_delay connu ; la latence système reste mais est
         // identique pour G et D, donc la différence est acoustiquement juste.
-        let pre_delay_samples = (self.pre_delay_secs * SAMPLE_RATE as f32) as usize;
-        self.left_dist_m = self.left_test_signal.as_deref()
-            .and_then(|sig| dsp::compute_speaker_distance(&left_s, sig, SAMPLE_RATE, pre_delay_samples));
-        self.right_dist_m = self.right_test_signal.as_deref()
-            .and_then(|sig| dsp::compute_speaker_distance(&right_s, sig, SAMPLE_RATE, pre_delay_samples));
-
-        // Délai inter-canal : différence de distances → annule pre_delay ET latence système
-        self.delay_ms = match (self.left_dist_m, self.right_dist_m) {
-            (Some(l), Some(r)) => (r - l) / 343.0 * 1000.0,
-            _ => 0.0,
-        };
+        let pre_delay_samples = (self.pre_delay_secs * self.sample_rate as f32) as usize;
+        left.analyze_ir(pre_delay_samples, self.analysis.onset_db);
+        right.analyze_ir(pre_delay_samples, self.analysis.onset_db);
 
-        // Différence de niveau (RMS)
-        let left_rms = dsp::compute_rms(&left_s);
-        let right_rms = dsp::compute_rms(&right_s);
-        self.level_diff_db = if left_rms > 0.0 && right_rms > 0.0 {
-            20.0 * (right_rms / left_rms).log10()
-        } else {
-            0.0
-        };
+        self.compare_pair();
 
-        // Différence spectrale
-        let diff: Vec<f32> = left_db
-            .iter()
-            .zip(right_db.iter())
-            .map(|(l, r)| r - l)
-            .collect();
-        self.diff_db = Some(diff);
+        // Fenêtre d'écoute : enregistre ce point, puis base les résultats
+        // (et donc les recommandations) sur la moyenne des positions mesurées
+        if let (Some(window), Some(left), Some(right)) = (self.window.as_mut(), &self.left, &self.right) {
+            if let Some(pos) = self.window_pos {
+                window.record(pos, PointAnalysis {
+                    left_db: left.bands_db.clone(),
+                    right_db: right.bands_db.clone(),
+                    delay_ms: self.delay_ms,
+                    level_diff_db: self.level_diff_db,
+                    freq_tilt: self.freq_tilt,
+                });
+            }
+            self.window_pos = window.next_position();
+            self.window_fresh = (false, false);
+        }
+        self.apply_window_summary();
 
-        // Inclinaison spectrale
-        self.freq_tilt = dsp::compute_freq_tilt(&left_db, &right_db);
+        // Caisson : réaligné sur l'IR gauche fraîchement calculée
+        if self.sub.is_some() {
+            self.align_sub();
+        }
 
-        // Score global
-        let s = dsp::compute_score(&left_db, &right_db, self.delay_ms, self.level_diff_db);
-        self.score = Some(s);
+        // Autres enceintes : comparaison à la référence choisie
+        self.compare_speakers();
 
-        // Historique
-        let now = chrono_now();
-        self.history.push(HistoryEntry {
-            score: s,
-            delay_ms: self.delay_ms,
-            level_diff_db: self.level_diff_db,
-            time: now,
-        });
+        self.detect_enhancements();
 
-        self.step = Step::Results;
+        // Historique
+        if let Some(score) = self.score {
+            let (left_db, right_db) = match &self.window_summary {
+                Some(w) => (w.left_db.clone(), w.right_db.clone()),
+                None => (
+                    self.left.as_ref().map(|m| m.bands_db.clone()).unwrap_or_default(),
+                    self.right.as_ref().map(|m| m.bands_db.clone()).unwrap_or_default(),
+                ),
+            };
+            self.history.push(HistoryEntry {
+                score,
+                delay_ms: self.delay_ms,
+                level_diff_db: self.level_diff_db,
+                time: chrono_now(),
+                left_db,
+                right_db,
+                environment: Environment::now(self.noise.as_ref().map(|n| n.broadband_dbfs), self.ambient),
+                notes: self.notes.clone(),
+            });
+            self.notes = self.notes.carried();
+            // Conformité à l'asymétrie acceptée
+            if let (Some(accepted), Some(entry)) = (self.baseline.as_mut(), self.history.last()) {
+                self.status = Some(if accepted.record_check(entry) { tr().baseline_compliant_check } else { tr().baseline_outside_check }.into());
+            }
+            // Changement noté avant cette mesure : son effet est connu
+            let after = self.history.last().cloned();
+            if let Some(effect) = after.and_then(|h| self.experiments.close(&h).and_then(|e| e.effect())) {
+                self.status = Some((tr().experiment_effect)(effect.score_delta, effect.delay_delta_ms, effect.level_delta_db));
+            }
+        }
+
+        if self.debug_dir.is_some() {
+            self.dump_debug_artifacts();
+        }
+
+        self.step = Step::Results;
+    }
+
+    /// Écrit spectres, corrélations et IR brutes de chaque mesure dans
+    /// `debug_dir`, pour joindre à un rapport de bug.
+    pub fn dump_debug_artifacts(&mut self) {
+        let Some(dir) = &self.debug_dir else { return };
+        let pre_delay_samples = (self.pre_delay_secs * self.sample_rate as f32) as usize;
+        let all = self.left.iter().chain(self.right.iter()).chain(self.others.iter()).chain(self.sub.iter());
+        match debugdump::dump(dir, all, pre_delay_samples, self.analysis.onset_db) {
+            Ok(paths) => self.status = Some((tr().debug_written)(paths.len(), &dir.display().to_string())),
+            Err(e) => self.error = Some(format!("{:#}", e)),
+        }
+    }
+
+    /// Relance le pipeline depuis `from` avec les intermédiaires en cache
+    /// (spectres, IR) : ni FFT de capture, ni déconvolution, ni historique.
+    pub fn recompute(&mut self, from: Stage) {
+        if from <= Stage::Spectrum {
+            let window = self.analysis.window;
+            let all = self.left.iter_mut().chain(self.right.iter_mut()).chain(self.others.iter_mut());
+            for m in all.chain(self.sub.iter_mut()) {
+                m.rebuild_spectrum(window);
+            }
+            if let Some(noise) = self.noise.as_mut() {
+                noise.rebuild_spectrum(window);
+            }
+        }
+        if from <= Stage::Bands {
+            let calibration = self.calibration.as_ref();
+            let all = self.left.iter_mut().chain(self.right.iter_mut()).chain(self.others.iter_mut());
+            for m in all.chain(self.sub.iter_mut()) {
+                m.rebuild_bands(calibration, self.analysis);
+            }
+            if let Some(noise) = self.noise.as_mut() {
+                noise.rebuild_bands(self.sample_rate, calibration, self.analysis);
+            }
+        }
+        // Distances relues dans les IR en cache, au seuil du front courant :
+        // aucune déconvolution ici, l'arrivée dans la boucle est en cache aussi
+        let pre_delay_samples = (self.pre_delay_secs * self.sample_rate as f32) as usize;
+        let all = self.left.iter_mut().chain(self.right.iter_mut()).chain(self.others.iter_mut());
+        for m in all.filter(|m| m.ir.is_some() && m.has_audio()) {
+            m.analyze_ir(pre_delay_samples, self.analysis.onset_db);
+        }
+        // Étape « comparaison » : seulement si une analyse a déjà eu lieu
+        if self.score.is_some() {
+            self.compare_pair();
+            self.apply_window_summary();
+        }
+        self.compare_speakers();
+    }
+
+    /// Passe à la plage d'analyse suivante et recalcule les scores.
+    pub fn cycle_analysis_range(&mut self) {
+        self.analysis = self.analysis.next_range();
+        self.recompute(Stage::Compare);
+        let (lo, hi) = self.analysis.range_hz;
+        self.status = Some((tr().range_set)(lo, hi));
+    }
+
+    /// Calibration micro suivante : celle de --mic-cal, puis les calibrations
+    /// embarquées, puis aucune ; spectres retracés depuis le cache.
+    pub fn cycle_calibration(&mut self) {
+        let mut choices: Vec<Option<MicCalibration>> = self.calibration_file.iter().cloned().map(Some).collect();
+        choices.extend(assets::list(AssetKind::Calibration).filter_map(|a| MicCalibration::builtin(a.name).ok()).map(Some));
+        choices.push(None);
+        let current = self.calibration.as_ref().map(|c| c.name.as_str());
+        let i = choices
+            .iter()
+            .position(|c| c.as_ref().map(|c| c.name.as_str()) == current)
+            .map_or(0, |i| (i + 1) % choices.len());
+        self.calibration = choices.swap_remove(i);
+        if let Some(cal) = &self.calibration {
+            if self.checks.note_mic_cal(cal, &self.in_device, reminders::now_unix()) {
+                self.save_checks();
+            }
+        }
+        self.recompute(Stage::Bands);
+        self.status = Some(match &self.calibration {
+            Some(cal) => (tr().calibration_set)(&cal.name),
+            None => tr().calibration_none.into(),
+        });
+    }
+
+    /// Courbe cible suivante : celle de --target, puis les cibles embarquées,
+    /// puis aucune ; scores recalculés.
+    pub fn cycle_target(&mut self) {
+        let mut choices: Vec<Option<TargetCurve>> = self.target_file.iter().cloned().map(Some).collect();
+        choices.extend(assets::list(AssetKind::TargetCurve).filter_map(|a| TargetCurve::builtin(a.name).ok()).map(Some));
+        choices.push(None);
+        let current = self.target.as_ref().map(|t| t.name.as_str());
+        let i = choices
+            .iter()
+            .position(|t| t.as_ref().map(|t| t.name.as_str()) == current)
+            .map_or(0, |i| (i + 1) % choices.len());
+        self.target = choices.swap_remove(i);
+        self.recompute(Stage::Compare);
+        self.status = Some(match &self.target {
+            Some(target) => (tr().target_set)(&target.name),
+            None => tr().target_none.into(),
+        });
+    }
+
+    /// Masque de tolérance suivant parmi les masques embarqués, puis aucun ;
+    /// scores recalculés.
+    pub fn cycle_mask(&mut self) {
+        let mut choices: Vec<Option<ToleranceMask>> = ToleranceMask::all().into_iter().map(Some).collect();
+        choices.push(None);
+        let current = self.mask.as_ref().map(|m| m.name.as_str());
+        let i = choices
+            .iter()
+            .position(|m| m.as_ref().map(|m| m.name.as_str()) == current)
+            .map_or(0, |i| (i + 1) % choices.len());
+        self.mask = choices.swap_remove(i);
+        self.recompute(Stage::Compare);
+        self.status = Some(match &self.mask {
+            Some(mask) => (tr().mask_set)(&mask.name),
+            None => tr().mask_none.into(),
+        });
+    }
+
+    /// Poids du score et seuils des conseils du profil `[score]`.
+    pub fn weights(&self) -> ScoreWeights {
+        self.scoring.weights()
+    }
+
+    /// Courbe cible sur les bandes du spectre.
+    pub fn target_db(&self) -> Option<&[f32]> {
+        self.target.as_ref().map(|t| t.bands_db.as_slice())
+    }
+
+    /// Écart G/D toléré (± dB) sur les bandes du spectre.
+    pub fn mask_db(&self) -> Option<&[f32]> {
+        self.mask.as_ref().map(|m| m.bands_db.as_slice())
+    }
+
+    /// Écart moyen de G et D à la cible sur la plage d'analyse (dB), courbes
+    /// moyennes en mode fenêtre d'écoute.
+    pub fn target_deviations(&self) -> Option<(f32, f32)> {
+        let target = self.target_db()?;
+        let (left, right) = match &self.window_summary {
+            Some(w) => (&w.left_db, &w.right_db),
+            None => (&self.left.as_ref()?.bands_db, &self.right.as_ref()?.bands_db),
+        };
+        let range = self.analysis.bands();
+        let deviation = |db: &[f32]| dsp::target_deviation(&db[range.clone()], &target[range.clone()]);
+        Some((deviation(left), deviation(right)))
+    }
+
+    /// Lit le journal des vérifications, y note la calibration chargée et
+    /// signale les vérifications à refaire.
+    pub fn load_checks(&mut self) {
+        match CheckLog::load(&self.reminder_params.log) {
+            Ok(checks) => self.checks = checks,
+            Err(e) => {
+                self.error = Some(format!("{:#}", e));
+                return;
+            }
+        }
+        if let Some(cal) = &self.calibration {
+            if self.checks.note_mic_cal(cal, &self.in_device, reminders::now_unix()) {
+                self.save_checks();
+            }
+        }
+        let due = self.reminders().len();
+        if due > 0 {
+            self.status = Some((tr().checks_due)(due));
+        }
+    }
+
+    fn save_checks(&mut self) {
+        if let Err(e) = self.checks.save(&self.reminder_params.log) {
+            self.error = Some((tr().checks_log_error)(&format!("{:#}", e)));
+        }
+    }
+
+    /// Vérifications à refaire (calibration micro, latence de boucle).
+    pub fn reminders(&self) -> Vec<Reminder> {
+        self.checks.reminders(
+            &self.reminder_params,
+            self.calibration.as_ref(),
+            self.loopback.is_some(),
+            (&self.out_device, &self.in_device),
+            reminders::now_unix(),
+        )
+    }
+
+    /// [_] : la calibration micro en cours a été contrôlée (bon fichier,
+    /// bon micro) ; son âge repart de zéro.
+    pub fn verify_calibration(&mut self) {
+        let Some(cal) = &self.calibration else {
+            self.error = Some(tr().calibration_nothing_to_verify.into());
+            return;
+        };
+        self.checks.verify_mic_cal(cal, &self.in_device, reminders::now_unix());
+        self.error = None;
+        self.status = Some((tr().calibration_verified)(&cal.name));
+        self.save_checks();
+    }
+
+    /// Passe à la fenêtre FFT suivante : spectres refaits depuis les captures.
+    pub fn cycle_window(&mut self) {
+        self.analysis = self.analysis.next_window();
+        self.recompute(Stage::Spectrum);
+        self.status = Some((tr().window_set)(tr().window_names[self.analysis.window.index()]));
+    }
+
+    /// Passe au seuil du front suivant : distances relues dans les IR en cache
+    /// (et courbes refaites si la fenêtre temporelle part du front).
+    pub fn cycle_onset(&mut self) {
+        self.analysis = self.analysis.next_onset();
+        self.recompute(if self.analysis.gate_ms > 0.0 { Stage::Bands } else { Stage::Compare });
+        self.status = Some((tr().onset_set)(self.analysis.onset_db));
+    }
+
+    /// Passe à la fenêtre temporelle suivante de l'IR (réponse quasi
+    /// anéchoïque) et retrace les spectres depuis le cache.
+    pub fn cycle_gate(&mut self) {
+        self.analysis = self.analysis.next_gate();
+        self.recompute(Stage::Bands);
+        let s = tr();
+        let measured = self.left.is_some() || self.right.is_some();
+        let applied = self.left.iter().chain(&self.right).any(Measurement::is_gated);
+        self.status = Some(match self.analysis.gate_ms {
+            0.0 => s.gate_off.to_string(),
+            ms if measured && !applied => (s.gate_unavailable)(ms),
+            ms => (s.gate_on)(ms, dsp::gate_low_hz(ms)),
+        });
+    }
+
+    /// Passe au lissage suivant et retrace les spectres depuis le cache.
+    pub fn cycle_smoothing(&mut self) {
+        self.analysis = self.analysis.next_smoothing();
+        self.recompute(Stage::Bands);
+        self.status = Some(match self.analysis.smoothing {
+            0 => tr().smoothing_off.to_string(),
+            n => (tr().smoothing_set)(n),
+        });
+    }
+
+    /// Paire stéréo : délai (différence de distances → annule pre_delay ET
+    /// latence système), niveau RMS, différence spectrale, inclinaison, score.
+    fn compare_pair(&mut self) {
+        let (Some(left), Some(right)) = (&self.left, &self.right) else {
+            return;
+        };
+        let pair = multichannel::compare(left, right, &self.analysis, self.target_db(), self.mask_db(), &self.weights());
+        self.delay_ms = pair.delay_ms;
+        self.level_diff_db = pair.level_diff_db;
+        self.diff_db = Some(pair.diff_db);
+        self.freq_tilt = pair.freq_tilt;
+        self.score = Some(pair.score);
+        self.band_delay = match (&left.ir, &right.ir) {
+            (Some(l), Some(r)) => banddelay::analyze(l, r, left.sample_rate),
+            _ => None,
+        };
+        self.speaker_polarity = match (&left.ir, &right.ir) {
+            (Some(l), Some(r)) => SpeakerPolarity::from_ir(l, left.sample_rate).zip(SpeakerPolarity::from_ir(r, right.sample_rate)),
+            _ => None,
+        };
+        self.reflections = self.find_reflections();
+    }
+
+    /// Premières réflexions des deux IR ; surfaces devinées avec la distance
+    /// absolue mesurée, sinon celle de `[placement]`, sinon une distance type.
+    fn find_reflections(&self) -> Option<ReflectionReport> {
+        let (left, right) = (self.left.as_ref()?, self.right.as_ref()?);
+        let onset_db = self.analysis.onset_db;
+        let side = |m: &Measurement, declared: Option<f32>| {
+            let direct_m = m.dist_m.filter(|_| m.latency_ms.is_some()).or(declared).unwrap_or(reflections::DEFAULT_LISTENING_M);
+            reflections::find(m.ir.as_ref()?, m.sample_rate, onset_db, direct_m)
+        };
+        Some(ReflectionReport { left: side(left, self.placement.left_m)?, right: side(right, self.placement.right_m)? })
+    }
+
+    /// Captures G / D probablement inversées (retard contraire aux distances
+    /// déclarées dans `[placement]`).
+    pub fn swap_suspected(&self) -> bool {
+        if self.score.is_none() {
+            return false;
+        }
+        let measured = match (&self.left, &self.right) {
+            (Some(l), Some(r)) if l.latency_ms.is_some() => l.dist_m.zip(r.dist_m),
+            _ => None,
+        };
+        self.placement.swap_suspected(self.delay_ms, measured)
+    }
+
+    /// Échange les captures gauche et droite (capture faite sous la mauvaise
+    /// touche) et refait la comparaison depuis le cache.
+    pub fn swap_captures(&mut self) {
+        if self.left.is_none() && self.right.is_none() {
+            return;
+        }
+        std::mem::swap(&mut self.left, &mut self.right);
+        if let Some(m) = self.left.as_mut() {
+            m.channel = Channel::LEFT;
+        }
+        if let Some(m) = self.right.as_mut() {
+            m.channel = Channel::RIGHT;
+        }
+        self.window_fresh = (self.window_fresh.1, self.window_fresh.0);
+        self.recompute(Stage::Compare);
+        if self.sub.is_some() {
+            self.align_sub();
+        }
+        self.detect_enhancements();
+        self.status = Some(tr().captures_swapped.into());
+    }
+
+    /// Score séparé en placement / réglage, sur la plage d'analyse et, en
+    /// mode fenêtre d'écoute, sur la moyenne des positions.
+    pub fn score_breakdown(&self) -> Option<ScoreBreakdown> {
+        self.score?;
+        let (left, right) = match &self.window_summary {
+            Some(w) => (&w.left_db, &w.right_db),
+            None => (&self.left.as_ref()?.bands_db, &self.right.as_ref()?.bands_db),
+        };
+        let c80_diff_db = match (
+            self.left.as_ref().and_then(|m| m.clarity),
+            self.right.as_ref().and_then(|m| m.clarity),
+        ) {
+            (Some(l), Some(r)) => Some(r.c80_db - l.c80_db),
+            _ => None,
+        };
+        let range = self.analysis.bands();
+        Some(dsp::compute_score_breakdown(
+            &left[range.clone()],
+            &right[range.clone()],
+            self.delay_ms,
+            self.level_diff_db,
+            c80_diff_db,
+            self.mask_db().map(|m| &m[range]),
+            &self.weights(),
+        ))
+    }
+
+    /// En mode fenêtre d'écoute, remplace les résultats de la paire par la
+    /// moyenne des positions mesurées.
+    fn apply_window_summary(&mut self) {
+        let Some(window) = &self.window else { return };
+        self.window_summary = window.summary();
+        if let Some(summary) = &self.window_summary {
+            self.delay_ms = summary.delay_ms;
+            self.level_diff_db = summary.level_diff_db;
+            self.freq_tilt = summary.freq_tilt;
+            self.diff_db = Some(summary.diff_db.clone());
+            self.score = Some(summary.score(self.analysis.bands(), self.target_db(), self.mask_db(), &self.weights()));
+        }
+    }
+
+    /// Active / désactive le mode fenêtre d'écoute (repart d'une grille vide).
+    pub fn toggle_window(&mut self) {
+        self.window_summary = None;
+        self.window_fresh = (false, false);
+        if self.window.is_some() {
+            self.window = None;
+            self.window_pos = None;
+        } else {
+            let window = ListeningWindow::default();
+            self.window_pos = window.next_position();
+            self.window = Some(window);
+        }
+    }
+
+    /// Ouvre la saisie d'un changement physique ; la dernière analyse sert de
+    /// mesure « avant ».
+    pub fn begin_experiment_note(&mut self) {
+        if self.history.is_empty() {
+            self.error = Some(tr().experiment_needs_before.into());
+            return;
+        }
+        self.error = None;
+        self.experiment_input = Some(String::new());
+    }
+
+    /// Valide la saisie : le changement attend la prochaine analyse.
+    pub fn commit_experiment_note(&mut self) {
+        let Some(description) = self.experiment_input.take() else { return };
+        let Some(before) = self.history.last().cloned() else { return };
+        if description.trim().is_empty() {
+            return;
+        }
+        self.experiments.start(&description, before);
+        self.status = Some((tr().experiment_noted)(description.trim()));
+    }
+
+    /// Ouvre la saisie du nom de la place où la dernière analyse a été faite.
+    pub fn begin_seat_name(&mut self) {
+        if self.history.is_empty() {
+            self.error = Some(tr().seat_needs_analysis.into());
+            return;
+        }
+        self.error = None;
+        self.seat_input = Some(String::new());
+    }
+
+    /// Valide la saisie : la dernière analyse est rangée sous ce nom,
+    /// en remplaçant une place du même nom.
+    pub fn commit_seat(&mut self) {
+        let Some(name) = self.seat_input.take() else { return };
+        let Some(last) = self.history.last() else { return };
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        seats::record(
+            &mut self.seats,
+            Seat {
+                name: name.clone(),
+                time: last.time.clone(),
+                score: last.score,
+                delay_ms: last.delay_ms,
+                level_diff_db: last.level_diff_db,
+                left_db: last.left_db.clone(),
+                right_db: last.right_db.clone(),
+            },
+        );
+        self.status = Some((tr().seat_recorded)(&name, self.seats.len()));
+    }
+
+    /// [+] / [-] : pause pré-capture par pas de 0,1 s, de 0 à 5 s (comptée
+    /// en dixièmes : pas de dérive d'arrondi au fil des appuis).
+    pub fn nudge_pre_delay(&mut self, steps: i32) {
+        let tenths = (self.pre_delay_secs * 10.0).round() as i32 + steps;
+        self.pre_delay_secs = tenths.clamp(0, 50) as f32 / 10.0;
+    }
+
+    /// [\] : ouvre la saisie du départ programmé des captures.
+    pub fn begin_schedule(&mut self) {
+        self.error = None;
+        self.schedule_input = Some(self.start_schedule.map_or_else(String::new, |s| s.label()));
+    }
+
+    /// Valide la saisie : chaque capture suivante attend la seconde donnée.
+    pub fn commit_schedule(&mut self) {
+        let Some(text) = self.schedule_input.take() else { return };
+        match StartSchedule::parse(&text) {
+            Ok(schedule) => {
+                self.start_schedule = schedule;
+                self.status = Some(match schedule {
+                    Some(s) => (tr().schedule_set)(&s.label()),
+                    None => tr().schedule_cleared.to_string(),
+                });
+            }
+            Err(e) => self.error = Some((tr().schedule_error)(&e.to_string())),
+        }
+    }
+
+    /// [$] : ouvre la saisie de la température, de l'humidité et des
+    /// dimensions de la pièce, pré-remplie des valeurs courantes.
+    pub fn begin_ambient(&mut self) {
+        self.error = None;
+        self.ambient_input = Some(RoomEntry { ambient: self.ambient, dimensions: self.room_dimensions }.to_text());
+    }
+
+    /// Valide la saisie : les conditions accompagnent les analyses suivantes.
+    pub fn commit_ambient(&mut self) {
+        let Some(text) = self.ambient_input.take() else { return };
+        let s = tr();
+        match RoomEntry::parse(&text) {
+            Ok(RoomEntry { ambient, dimensions }) => {
+                self.set_ambient(ambient);
+                self.set_room_dimensions(dimensions);
+                let mut status = match (ambient.temperature_c, ambient.humidity_pct) {
+                    (None, None) => s.ambient_cleared.to_string(),
+                    (t, h) => (s.ambient_noted)(
+                        &t.map_or("—".into(), |t| format!("{} °C", format_num(t, 1))),
+                        &h.map_or("—".into(), |h| format!("{:.0} %", h)),
+                        dsp::speed_of_sound(),
+                    ),
+                };
+                if let Some(d) = dimensions {
+                    status += &(s.room_dimensions_noted)(d.length_m, d.width_m, d.height_m, self.room_modes.len());
+                }
+                self.status = Some(status);
+            }
+            Err(e) => self.error = Some((s.ambient_error)(&e.to_string())),
+        }
+    }
+
+    /// Conditions courantes et vitesse du son qui en découle ; les distances
+    /// déjà mesurées sont relues dans les IR en cache si elle change, les
+    /// modes de la pièce recalculés.
+    pub fn set_ambient(&mut self, ambient: Ambient) {
+        self.ambient = ambient;
+        let c = ambient.speed_of_sound();
+        if c != dsp::speed_of_sound() {
+            dsp::set_speed_of_sound(c);
+            self.recompute(Stage::Compare);
+            self.set_room_dimensions(self.room_dimensions);
+        }
+    }
+
+    /// Dimensions de la pièce et modes propres qui en découlent.
+    pub fn set_room_dimensions(&mut self, dimensions: Option<RoomDimensions>) {
+        self.room_dimensions = dimensions;
+        self.room_modes = dimensions.map(|room| room.modes(MAX_MODE_HZ)).unwrap_or_default();
+    }
+
+    /// Notes de la dernière analyse, ou de la prochaine avant toute analyse.
+    pub fn current_notes(&self) -> &MeasurementNotes {
+        self.history.last().map_or(&self.notes, |h| &h.notes)
+    }
+
+    /// [F12] : ouvre la saisie des notes de la dernière analyse, ou de la
+    /// prochaine s'il n'y en a pas encore.
+    pub fn begin_notes(&mut self) {
+        self.error = None;
+        let entry = self.history.len().checked_sub(1);
+        let notes = entry.map_or_else(|| self.notes.clone(), |i| self.history[i].notes.clone());
+        self.notes_input = Some(NotesEditor { notes, entry, ..NotesEditor::default() });
+    }
+
+    /// Valide la saisie : notes de l'analyse visée ; micro, enceintes et
+    /// pièce passent aussi aux analyses suivantes.
+    pub fn commit_notes(&mut self) {
+        let Some(editor) = self.notes_input.take() else { return };
+        let notes = MeasurementNotes {
+            text: editor.notes.text.trim().to_string(),
+            mic_position: editor.notes.mic_position.trim().to_string(),
+            speakers: editor.notes.speakers.trim().to_string(),
+            room: editor.notes.room.trim().to_string(),
+        };
+        let latest = editor.entry.is_some_and(|i| i + 1 == self.history.len());
+        match editor.entry.and_then(|i| self.history.get_mut(i)) {
+            Some(entry) => {
+                // Une analyse plus ancienne ne change pas ce qui est reporté
+                if latest {
+                    self.notes = notes.carried();
+                }
+                self.status = Some((tr().notes_saved)(&entry.time));
+                entry.notes = notes;
+            }
+            None => {
+                self.notes = notes;
+                self.status = Some(tr().notes_saved_next.into());
+            }
+        }
+    }
+
+    /// Passe la saisie des notes sur l'analyse `index` de l'historique
+    /// (PgPréc / PgSuiv, clic sur l'historique) ; ce qui n'a pas été validé
+    /// sur la précédente est abandonné.
+    pub fn retarget_notes(&mut self, index: usize) {
+        let Some(entry) = self.history.get(index) else { return };
+        if let Some(editor) = self.notes_input.as_mut() {
+            *editor = NotesEditor { notes: entry.notes.clone(), field: editor.field, entry: Some(index) };
+        }
+    }
+
+    /// [%] : ouvre la saisie de la raison de l'asymétrie à accepter, ou
+    /// abandonne l'asymétrie déjà acceptée.
+    pub fn toggle_baseline(&mut self) {
+        if self.baseline.take().is_some() {
+            self.status = Some(tr().baseline_dropped.into());
+            return;
+        }
+        if self.history.is_empty() {
+            self.error = Some(tr().baseline_needs_analysis.into());
+            return;
+        }
+        self.error = None;
+        self.baseline_input = Some(String::new());
+    }
+
+    /// Valide la saisie : la dernière analyse devient la référence.
+    pub fn commit_baseline(&mut self) {
+        let Some(reason) = self.baseline_input.take() else { return };
+        let Some(last) = self.history.last() else { return };
+        if reason.trim().is_empty() {
+            return;
+        }
+        self.baseline = Some(AcceptedAsymmetry::from_history(&reason, last));
+        self.status = Some((tr().baseline_noted)(reason.trim()));
+    }
+
+    /// Active / désactive la comparaison de deux analyses de l'historique :
+    /// A = avant-dernière, B = dernière.
+    pub fn toggle_overlay(&mut self) {
+        if self.overlay.take().is_some() {
+            return;
+        }
+        if self.history.len() < 2 {
+            self.error = Some(tr().compare_needs_two.into());
+            return;
+        }
+        self.error = None;
+        self.overlay = Some((self.history.len() - 2, self.history.len() - 1));
+    }
+
+    /// Domaines affichés dans le panneau de résultats : réverbération et
+    /// THD une fois une IR (un sweep) analysée.
+    pub fn metric_domains(&self) -> Vec<MetricDomain> {
+        let any = |has: fn(&Measurement) -> bool| self.left.iter().chain(&self.right).any(has);
+        MetricDomain::ALL
+            .into_iter()
+            .filter(|d| match d {
+                MetricDomain::Reverb => any(|m| !m.rt60.is_empty()),
+                MetricDomain::Distortion => any(|m| !m.thd.is_empty()),
+                _ => true,
+            })
+            .collect()
+    }
+
+    /// Sélectionne le domaine suivant du panneau de résultats.
+    pub fn next_metric(&mut self) {
+        let shown = self.metric_domains();
+        self.metrics.next(&shown);
+    }
+
+    /// Écrit la comparaison A → B en Markdown pour un forum : celle affichée
+    /// par [&], sinon l'avant-dernière analyse et la dernière.
+    pub fn export_forum_diff(&mut self) {
+        if self.history.len() < 2 {
+            self.error = Some(tr().compare_needs_two.into());
+            return;
+        }
+        let (ia, ib) = self.overlay.unwrap_or((self.history.len() - 2, self.history.len() - 1));
+        let Some(text) = overlay::forum_markdown(&self.history[ia], ia, &self.history[ib], ib, self.analysis.bands()) else {
+            self.error = Some(tr().forum_no_curves.into());
+            return;
+        };
+        let path = tr().forum_file;
+        match std::fs::write(path, text) {
+            Ok(()) => {
+                self.error = None;
+                self.status = Some((tr().forum_written)(ia + 1, ib + 1, path));
+            }
+            Err(e) => self.error = Some((tr().write_failed)(path, &e.to_string())),
+        }
+    }
+
+    /// Écrit le rapport HTML de la dernière analyse (`report::REPORT_PATH`).
+    pub fn export_report(&mut self) {
+        let html = match report::to_html(self) {
+            Ok(Some(html)) => html,
+            Ok(None) => {
+                self.error = Some(tr().report_needs_analysis.into());
+                return;
+            }
+            Err(e) => {
+                self.error = Some((tr().report_charts_failed)(&e.to_string()));
+                return;
+            }
+        };
+        match std::fs::write(report::REPORT_PATH, html) {
+            Ok(()) => {
+                self.error = None;
+                self.status = Some((tr().report_written)(report::REPORT_PATH));
+            }
+            Err(e) => self.error = Some((tr().write_failed)(report::REPORT_PATH, &e.to_string())),
+        }
+    }
+
+    /// [Ins] : courbes G/D et écart D − G en image PNG (`report::PNG_PATH`).
+    pub fn export_png(&mut self) {
+        match report::write_png(&report::Response::from_state(self), Path::new(report::PNG_PATH)) {
+            Ok(true) => {
+                self.error = None;
+                self.status = Some((tr().png_written)(report::PNG_PATH));
+            }
+            Ok(false) => self.error = Some(tr().png_no_curves.into()),
+            Err(e) => self.error = Some((tr().write_failed)(report::PNG_PATH, &e.to_string())),
+        }
+    }
+
+    /// Compare l'analyse `index` de l'historique (A) à la dernière (B) ; la
+    /// dernière elle-même se compare à l'avant-dernière (clic sur l'historique).
+    pub fn compare_history(&mut self, index: usize) {
+        if self.history.len() < 2 {
+            self.error = Some(tr().compare_needs_two.into());
+            return;
+        }
+        let last = self.history.len() - 1;
+        self.error = None;
+        self.overlay = Some((index.min(last - 1), last));
+    }
+
+    /// Déplace le curseur du spectre de `step` bandes ; le premier appui le
+    /// place au milieu de la vue, qui défile pour le suivre.
+    pub fn move_spectrum_cursor(&mut self, step: isize) {
+        let band = match self.spectrum_cursor {
+            Some(band) => band.saturating_add_signed(step).min(NUM_BANDS - 1),
+            None => self.spectrum_view.center(),
+        };
+        self.spectrum_cursor = Some(band);
+        let view = &mut self.spectrum_view;
+        while !view.contains(band) {
+            view.pan(if band < view.bands.0 { -1 } else { 1 });
+        }
+    }
+
+    /// Zoom du spectre autour du curseur, ou du centre de la vue.
+    pub fn zoom_spectrum(&mut self, zoom_in: bool) {
+        let band = self.spectrum_cursor.filter(|&b| self.spectrum_view.contains(b)).unwrap_or(self.spectrum_view.center());
+        self.spectrum_view.zoom(band, zoom_in);
+    }
+
+    /// Déplace A (`which_b` faux) ou B dans l'historique, sans sortir des bornes.
+    pub fn move_overlay(&mut self, which_b: bool, step: isize) {
+        let last = self.history.len().saturating_sub(1);
+        if let Some((a, b)) = self.overlay.as_mut() {
+            let index = if which_b { b } else { a };
+            *index = index.saturating_add_signed(step).min(last);
+        }
+    }
+
+    /// Marque la dernière analyse comme position candidate.
+    pub fn mark_position(&mut self) {
+        let (Some(score), Some(left), Some(right)) = (self.score, &self.left, &self.right) else {
+            self.error = Some(tr().position_needs_analysis.into());
+            return;
+        };
+        let label = format!("P{}", self.positions.len() + 1);
+        let position = CandidatePosition::from_measurements(label.clone(), chrono_now(), score, left, right);
+        let composite = position.composite();
+        self.positions.push(position);
+        let rank = positions::ranking(&self.positions)
+            .iter()
+            .position(|&i| i == self.positions.len() - 1)
+            .unwrap_or(0);
+        self.error = None;
+        self.status = Some((tr().position_marked)(&label, composite, rank + 1, self.positions.len()));
+    }
+
+    /// Enregistre la session courante dans `session_path`.
+    pub fn save_session(&mut self) {
+        match session::save(self, &self.session_path) {
+            Ok(()) => {
+                self.error = None;
+                self.status = Some((tr().session_saved)(&self.session_path.display().to_string()));
+            }
+            Err(e) => self.error = Some(format!("{:#}", e)),
+        }
+    }
+
+    /// Recharge la session depuis `session_path`.
+    pub fn load_session(&mut self) {
+        let path = self.session_path.clone();
+        self.import_session(&path);
+    }
+
+    /// Charge une session enregistrée ou partagée (`--import`, [O]).
+    pub fn import_session(&mut self, path: &Path) {
+        match session::load(path) {
+            Ok(s) => {
+                self.reset();
+                self.overlay = None;
+                let migrated = s.migrated_from;
+                s.apply(self);
+                // Nouvelles captures au taux des mesures chargées : comparables
+                if let Some(m) = self.left.as_ref().or(self.right.as_ref()) {
+                    self.sample_rate = m.sample_rate;
+                }
+                self.error = None;
+                self.status = Some(match migrated {
+                    Some(v) => (tr().session_migrated)(&path.display().to_string(), v, session::SESSION_VERSION),
+                    None => (tr().session_loaded)(&path.display().to_string()),
+                });
+            }
+            Err(e) => self.error = Some(format!("{:#}", e)),
+        }
+    }
+
+    /// Écrit la session compressée à partager (`shared.spkalign`).
+    pub fn share_session(&mut self) {
+        let path = Path::new(session::SHARED_SESSION_PATH);
+        match session::save_shared(self, path, self.share_audio) {
+            Ok(size) => {
+                self.error = None;
+                self.status = Some((tr().session_shared)(&path.display().to_string(), size.div_ceil(1024), self.share_audio));
+            }
+            Err(e) => self.error = Some(format!("{:#}", e)),
+        }
+    }
+
+    /// Exporte les spectres (.frd REW + CSV) dans le répertoire courant.
+    pub fn export_response(&mut self) {
+        match export::export_all(self, Path::new(".")) {
+            Ok(paths) => {
+                let names: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+                self.error = None;
+                self.status = Some((tr().exported)(&names.join(", ")));
+            }
+            Err(e) => self.error = Some(format!("{:#}", e)),
+        }
     }
 
     /// Réinitialise les mesures (garde l'historique).
     pub fn reset(&mut self) {
-        self.left_samples = None;
-        self.right_samples = None;
-        self.left_test_signal = None;
-        self.right_test_signal = None;
-        self.left_dist_m = None;
-        self.right_dist_m = None;
-        self.left_db = None;
-        self.right_db = None;
+        self.left = None;
+        self.right = None;
+        self.others.clear();
+        self.pairs.clear();
         self.diff_db = None;
         self.delay_ms = 0.0;
         self.level_diff_db = 0.0;
         self.freq_tilt = 0.0;
         self.score = None;
-        self.progress = 0.0;
+        self.band_delay = None;
+        self.speaker_polarity = None;
+        self.reflections = None;
+        self.progress = Progress::default();
         self.error = None;
+        self.status = None;
         self.step = Step::Idle;
+
+        // La grille repart de zéro, le mode reste actif
+        if let Some(window) = self.window.as_mut() {
+            *window = ListeningWindow::default();
+            self.window_pos = window.next_position();
+            self.window_summary = None;
+            self.window_fresh = (false, false);
+        }
+    }
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Message d'échec d'une capture pour l'interface.
+fn failure(e: anyhow::Error, retry_default_config: bool) -> AudioMsg {
+    match e.downcast_ref::<AudioError>() {
+        Some(AudioError::Cancelled) => AudioMsg::Cancelled,
+        Some(AudioError::DeviceBusy { .. }) if !retry_default_config => {
+            AudioMsg::Error((tr().err_busy_hint)(&format!("{:#}", e)))
+        }
+        _ => AudioMsg::Error(format!("{:#}", e)),
     }
 }
 
@@ -310,7 +2467,7 @@ fn chrono_now() -> String {
 pub struct App;
 
 impl App {
-    pub fn run() -> Result<()> {
+    pub fn run(mut state: AppState) -> Result<()> {
         // Init terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -318,71 +2475,39 @@ impl App {
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
-        let mut state = AppState::new();
         let tick = Duration::from_millis(50);
         let mut last_tick = Instant::now();
+        let mut hits = ui::HitMap::default();
 
         loop {
             // Dépile les messages audio
             state.poll_audio();
 
             // Rendu
-            terminal.draw(|f| ui::draw(f, &state))?;
+            terminal.draw(|f| hits = ui::draw(f, &state))?;
 
-            // Gestion des événements clavier
+            // Gestion des événements clavier et souris
             let timeout = tick.checked_sub(last_tick.elapsed()).unwrap_or_default();
             if event::poll(timeout)? {
-                if let Event::Key(key) = event::read()? {
-                    if key.kind != KeyEventKind::Press {
-                        continue;
-                    }
-                    match (key.code, key.modifiers) {
-                        // Quitter
-                        (KeyCode::Char('q'), _)
-                        | (KeyCode::Char('c'), KeyModifiers::CONTROL) => break,
-
-                        // Capturer gauche
-                        (KeyCode::Char('l') | KeyCode::Char('L'), _)
-                            if state.step == Step::Idle =>
-                        {
-                            state.start_capture(Channel::Left);
-                        }
-
-                        // Capturer droite
-                        (KeyCode::Char('r') | KeyCode::Char('R'), _)
-                            if state.step == Step::Idle =>
-                        {
-                            state.start_capture(Channel::Right);
+                let running = match event::read()? {
+                    Event::Key(key) => Self::handle_key(&mut state, key),
+                    Event::Mouse(mouse) => match (mouse.kind, hits.at(mouse.column, mouse.row)) {
+                        (MouseEventKind::Down(MouseButton::Left), Some(click)) => Self::handle_click(&mut state, click),
+                        // Glisser fait suivre le curseur du spectre
+                        (MouseEventKind::Drag(MouseButton::Left), Some(click @ ui::Click::Band(_))) => {
+                            Self::handle_click(&mut state, click)
                         }
-
-                        // Analyser
-                        (KeyCode::Char('a') | KeyCode::Enter, _)
-                            if state.step == Step::Idle
-                                && state.left_db.is_some()
-                                && state.right_db.is_some() =>
-                        {
-                            state.analyze();
+                        // Molette sur le spectre : zoom autour de la bande pointée
+                        (MouseEventKind::ScrollUp | MouseEventKind::ScrollDown, Some(ui::Click::Band(band))) => {
+                            state.spectrum_view.zoom(band, mouse.kind == MouseEventKind::ScrollUp);
+                            true
                         }
-
-                        // Réinitialiser
-                        (KeyCode::Char('x') | KeyCode::Delete, _) => {
-                            state.reset();
-                        }
-
-                        // Augmenter le délai pré-capture (+0.5s, max 5.0s)
-                        (KeyCode::Char('+') | KeyCode::Char('='), _)
-                            if state.step == Step::Idle =>
-                        {
-                            state.pre_delay_secs = (state.pre_delay_secs + 0.5).min(5.0);
-                        }
-
-                        // Diminuer le délai pré-capture (-0.5s, min 0.0s)
-                        (KeyCode::Char('-'), _) if state.step == Step::Idle => {
-                            state.pre_delay_secs = (state.pre_delay_secs - 0.5).max(0.0);
-                        }
-
-                        _ => {}
-                    }
+                        _ => true,
+                    },
+                    _ => true,
+                };
+                if !running {
+                    break;
                 }
             }
 
@@ -401,4 +2526,557 @@ impl App {
         terminal.show_cursor()?;
         Ok(())
     }
+
+    /// Applique un clic sur une zone de `ui::HitMap` ; faux = quitter. Sans
+    /// effet pendant une saisie de texte (le clic ne tape rien), sauf sur
+    /// l'historique pendant la saisie des notes : l'analyse cliquée devient
+    /// celle annotée.
+    fn handle_click(state: &mut AppState, click: ui::Click) -> bool {
+        if let (Some(_), ui::Click::History(index)) = (&state.notes_input, click) {
+            state.retarget_notes(index);
+            return true;
+        }
+        if state.is_typing() {
+            return true;
+        }
+        match click {
+            ui::Click::Key(code) => Self::handle_key(state, KeyEvent::new(code, KeyModifiers::NONE)),
+            ui::Click::History(index) => {
+                state.compare_history(index);
+                true
+            }
+            ui::Click::Band(band) => {
+                state.spectrum_cursor = Some(band);
+                true
+            }
+            ui::Click::Metric(domain) => {
+                state.metrics.focus = domain;
+                state.metrics.unfold(domain);
+                true
+            }
+        }
+    }
+
+    /// Applique une touche à l'état ; faux = quitter. Sert aussi aux
+    /// scripts de touches (script.rs), sans terminal.
+    pub fn handle_key(state: &mut AppState, key: KeyEvent) -> bool {
+        if key.kind != KeyEventKind::Press {
+            return true;
+        }
+        // Saisie d'un changement ([/]) : le clavier sert au texte
+        if let Some(input) = state.experiment_input.as_mut() {
+            match key.code {
+                KeyCode::Enter => state.commit_experiment_note(),
+                KeyCode::Esc => state.experiment_input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) if input.chars().count() < experiments::MAX_DESCRIPTION_LEN => {
+                    input.push(c);
+                }
+                _ => {}
+            }
+            return true;
+        }
+        // Saisie des notes ([F12]) : Tab / ↑ ↓ changent de champ
+        if let Some(editor) = state.notes_input.as_mut() {
+            match key.code {
+                KeyCode::Enter => state.commit_notes(),
+                KeyCode::Esc => state.notes_input = None,
+                KeyCode::Tab | KeyCode::Down => editor.field = editor.field.next(),
+                KeyCode::BackTab | KeyCode::Up => editor.field = editor.field.prev(),
+                KeyCode::PageUp => {
+                    if let Some(i) = editor.entry.and_then(|i| i.checked_sub(1)) {
+                        state.retarget_notes(i);
+                    }
+                }
+                KeyCode::PageDown => {
+                    if let Some(i) = editor.entry {
+                        state.retarget_notes(i + 1);
+                    }
+                }
+                KeyCode::Backspace => editor.pop(),
+                KeyCode::Char(c) => editor.push(c),
+                _ => {}
+            }
+            return true;
+        }
+        // Saisie du départ programmé ([\])
+        if let Some(input) = state.schedule_input.as_mut() {
+            match key.code {
+                KeyCode::Enter => state.commit_schedule(),
+                KeyCode::Esc => state.schedule_input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) if input.chars().count() < schedule::MAX_INPUT_LEN => {
+                    input.push(c);
+                }
+                _ => {}
+            }
+            return true;
+        }
+        // Saisie des conditions et des dimensions de la pièce ([$])
+        if let Some(input) = state.ambient_input.as_mut() {
+            match key.code {
+                KeyCode::Enter => state.commit_ambient(),
+                KeyCode::Esc => state.ambient_input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) if input.chars().count() < environment::MAX_INPUT_LEN => {
+                    input.push(c);
+                }
+                _ => {}
+            }
+            return true;
+        }
+        // Saisie de la raison d'une asymétrie acceptée ([%])
+        if let Some(input) = state.baseline_input.as_mut() {
+            match key.code {
+                KeyCode::Enter => state.commit_baseline(),
+                KeyCode::Esc => state.baseline_input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) if input.chars().count() < baseline::MAX_REASON_LEN => {
+                    input.push(c);
+                }
+                _ => {}
+            }
+            return true;
+        }
+        // Saisie du nom d'une place d'écoute ([:])
+        if let Some(input) = state.seat_input.as_mut() {
+            match key.code {
+                KeyCode::Enter => state.commit_seat(),
+                KeyCode::Esc => state.seat_input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) if input.chars().count() < seats::MAX_NAME_LEN => {
+                    input.push(c);
+                }
+                _ => {}
+            }
+            return true;
+        }
+        // Liste de vérification ouverte : [1-4] cochent, Entrée valide,
+        // Échap ferme ; [H], [J], [G]… restent actives pour la vérifier
+        if let Some(checklist) = state.checklist.as_mut().filter(|c| c.open) {
+            match key.code {
+                KeyCode::Char(c @ '1'..='4') => {
+                    checklist.toggle(c as usize - '1' as usize);
+                    return true;
+                }
+                KeyCode::Enter => {
+                    state.acknowledge_checklist();
+                    return true;
+                }
+                KeyCode::Esc => {
+                    checklist.open = false;
+                    return true;
+                }
+                _ => {}
+            }
+        }
+        // Touches réassignées (`[keys]`) : ramenées à la touche d'origine
+        let Some(code) = state.keys.translate(key.code, key.modifiers) else {
+            return true;
+        };
+        let key = KeyEvent { code, ..key };
+        match (key.code, key.modifiers) {
+            // Arrêter la capture en cours
+            (KeyCode::Esc, _) if state.step.is_capturing() => state.cancel_capture(),
+
+            // Quitter
+            (KeyCode::Char('q'), _)
+            | (KeyCode::Char('c'), KeyModifiers::CONTROL) => return false,
+
+            // Capturer gauche
+            (KeyCode::Char('l') | KeyCode::Char('L'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_capture(Channel::LEFT);
+            }
+
+            // Capturer droite
+            (KeyCode::Char('r') | KeyCode::Char('R'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_capture(Channel::RIGHT);
+            }
+
+            // Mesure enchaînée : gauche puis droite en une capture
+            (KeyCode::Tab, _) if !state.step.is_capturing() => {
+                state.start_pair_capture();
+            }
+
+            // Capturer n'importe quelle sortie : 1 = FL, 2 = FR, 3 = C…
+            (KeyCode::Char(d @ '1'..='8'), _) if !state.step.is_capturing() => {
+                state.start_capture(Channel(d as u16 - '1' as u16));
+            }
+
+            // Enceinte de référence suivante (comparaisons multicanal)
+            (KeyCode::Char('f') | KeyCode::Char('F'), _) if !state.step.is_capturing() => {
+                state.cycle_reference();
+            }
+
+            // Capturer le caisson (sortie LFE ou --sub-channel)
+            (KeyCode::Char('u') | KeyCode::Char('U'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_sub_capture();
+            }
+
+            // Plusieurs caissons : reprendre la dernière place
+            (KeyCode::F(3), KeyModifiers::SHIFT) if !state.step.is_capturing() => {
+                state.drop_multisub_seat();
+            }
+
+            // Plusieurs caissons : chacun mesuré à la place actuelle
+            (KeyCode::F(3), _) if !state.step.is_capturing() => {
+                state.start_multisub_seat();
+            }
+
+            // Analyser
+            (KeyCode::Char('a') | KeyCode::Enter, _)
+                if !state.step.is_capturing() && state.can_analyze() =>
+            {
+                state.analyze();
+            }
+
+            // Réinitialiser
+            (KeyCode::Char('x') | KeyCode::Delete, _) => {
+                state.reset();
+            }
+
+            // Vue du spectre : Maj + ← → défile, Maj + ↑ ↓ zoome, PgPréc / PgSuiv
+            // resserrent / élargissent l'axe des dB, Début revient à la vue entière
+            (KeyCode::Left | KeyCode::Right, KeyModifiers::SHIFT) => {
+                state.spectrum_view.pan(if key.code == KeyCode::Left { -1 } else { 1 });
+            }
+            (KeyCode::Up | KeyCode::Down, KeyModifiers::SHIFT) => state.zoom_spectrum(key.code == KeyCode::Up),
+            (KeyCode::PageUp | KeyCode::PageDown, _) => {
+                state.spectrum_view.scale_db(if key.code == KeyCode::PageUp { 1 } else { -1 });
+            }
+            (KeyCode::Home, _) => state.spectrum_view = SpectrumView::default(),
+
+            // Comparaison de deux analyses : ← → choisissent A, ↑ ↓ choisissent B
+            (KeyCode::Char('&'), _) => state.toggle_overlay(),
+            (KeyCode::Left | KeyCode::Right, _) if state.overlay.is_some() => {
+                state.move_overlay(false, if key.code == KeyCode::Left { -1 } else { 1 });
+            }
+            (KeyCode::Up | KeyCode::Down, _) if state.overlay.is_some() => {
+                state.move_overlay(true, if key.code == KeyCode::Down { -1 } else { 1 });
+            }
+
+            // Curseur du spectre : ← → d'une bande, ↑ ↓ d'une octave, Échap le masque
+            (KeyCode::Left | KeyCode::Right, _) => {
+                state.move_spectrum_cursor(if key.code == KeyCode::Left { -1 } else { 1 });
+            }
+            (KeyCode::Up | KeyCode::Down, _) => {
+                state.move_spectrum_cursor(if key.code == KeyCode::Down { -CURSOR_OCTAVE } else { CURSOR_OCTAVE });
+            }
+            (KeyCode::Esc, _) => state.spectrum_cursor = None,
+
+            // Marquer la position mesurée comme candidate
+            (KeyCode::Char('#'), _) if !state.step.is_capturing() => {
+                state.mark_position();
+            }
+
+            // Ranger la dernière analyse sous un nom de place d'écoute
+            (KeyCode::Char(':'), _) if !state.step.is_capturing() => {
+                state.begin_seat_name();
+            }
+
+            // Calibration micro contrôlée : son âge repart de zéro
+            (KeyCode::Char('_'), _) => {
+                state.verify_calibration();
+            }
+
+            // Conditions de mesure (température, humidité)
+            (KeyCode::Char('$'), _) => {
+                state.begin_ambient();
+            }
+
+            // Panneau des corrélations score / environnement
+            (KeyCode::Char('"'), _) => {
+                state.show_environment = !state.show_environment;
+            }
+
+            // Accepter l'asymétrie mesurée (ou l'abandonner)
+            (KeyCode::Char('%'), _) if !state.step.is_capturing() => {
+                state.toggle_baseline();
+            }
+
+            // Noter un changement physique avant de remesurer
+            (KeyCode::Char('/'), _) if !state.step.is_capturing() => {
+                state.begin_experiment_note();
+            }
+
+            // Calibration du niveau : salve de bruit rose puis amplitude du sweep
+            (KeyCode::Char('j') | KeyCode::Char('J'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_level_calibration(None);
+            }
+
+            // Niveau de capture visé par la calibration : −/+ 1 dB
+            (KeyCode::Char(c @ ('{' | '}')), _) if state.step == Step::Idle => {
+                let step = if c == '{' { -1.0 } else { 1.0 };
+                state.level.target_dbfs = (state.level.target_dbfs + step)
+                    .clamp(levelcal::MIN_TARGET_DBFS, levelcal::MAX_TARGET_DBFS);
+            }
+
+            // Bruit de fond : quelques secondes de silence
+            (KeyCode::Char('h') | KeyCode::Char('H'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_noise_capture();
+            }
+
+            // Vérification du bass management
+            (KeyCode::Char('b') | KeyCode::Char('B'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_bass_test();
+            }
+
+            // Polarité par haut-parleur (gauche puis droite)
+            (KeyCode::Char('p') | KeyCode::Char('P'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_stereo_test(StereoTest::Polarity);
+            }
+
+            // Rub & buzz : salves graves à fort niveau (gauche puis droite)
+            (KeyCode::Char('z') | KeyCode::Char('Z'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_stereo_test(StereoTest::RubBuzz);
+            }
+
+            // Compression : même sweep à trois niveaux (gauche puis droite)
+            (KeyCode::Char('c') | KeyCode::Char('C'), KeyModifiers::NONE | KeyModifiers::SHIFT)
+                if !state.step.is_capturing() =>
+            {
+                state.start_stereo_test(StereoTest::Compression);
+            }
+
+            // Linéarité du gain : paliers de −30 à −10 dBFS (gauche puis droite)
+            (KeyCode::Char('g') | KeyCode::Char('G'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_stereo_test(StereoTest::Linearity);
+            }
+
+            // Sonde de câblage : micro contre la gauche, puis contre la droite
+            (KeyCode::Char('k') | KeyCode::Char('K'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_channel_probe();
+            }
+
+            // Plage d'analyse du score (recalcul instantané depuis le cache)
+            (KeyCode::Char('m') | KeyCode::Char('M'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.cycle_analysis_range();
+            }
+
+            // Calibration micro suivante (fichier, embarquées, aucune)
+            (KeyCode::Char('\''), _) if !state.step.is_capturing() => {
+                state.cycle_calibration();
+            }
+
+            // Échange des captures G / D (capture faite sous la mauvaise touche)
+            (KeyCode::Char('|'), _) if !state.step.is_capturing() => {
+                state.swap_captures();
+            }
+
+            // Fenêtre de la FFT (Hann, Blackman-Harris, flat-top, rectangulaire)
+            (KeyCode::Char('^'), _) if !state.step.is_capturing() => {
+                state.cycle_window();
+            }
+
+            // Fenêtre temporelle de l'IR : réponse quasi anéchoïque
+            (KeyCode::Char('9'), _) if !state.step.is_capturing() => {
+                state.cycle_gate();
+            }
+
+            // Lissage des spectres en fraction d'octave (recalcul depuis le cache)
+            (KeyCode::Char('~'), _) if !state.step.is_capturing() => {
+                state.cycle_smoothing();
+            }
+
+            // Vérification rapide : 1 s de bi-ton / bruit blanc (gauche puis droite)
+            (KeyCode::Char('v') | KeyCode::Char('V'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.start_stereo_test(StereoTest::QuickCheck(state.quick_stimulus));
+            }
+
+            // Distances rapides : chirp de 0,3 s (gauche puis droite), sans analyse
+            (KeyCode::Char('0'), _) if !state.step.is_capturing() => {
+                state.start_stereo_test(StereoTest::Distance);
+            }
+
+            // Moyenne spatiale : bruit rose périodique, micro en mouvement (gauche puis droite)
+            (KeyCode::Char(';'), _) if !state.step.is_capturing() => {
+                state.start_stereo_test(StereoTest::Mmm);
+            }
+
+            // Stimulus de la vérification rapide : bi-ton ↔ bruit blanc
+            (KeyCode::Char('n') | KeyCode::Char('N'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.quick_stimulus = state.quick_stimulus.toggle();
+                state.status = Some((tr().label_value)(tr().test_quick, state.quick_stimulus.label()));
+            }
+
+            // Suivi en direct G / D ; actif pendant sa propre capture pour l'arrêter
+            (KeyCode::Char('d') | KeyCode::Char('D'), _)
+                if !state.step.is_capturing() || state.watch.is_some() =>
+            {
+                state.toggle_watch();
+            }
+
+            // Bips de guidage du suivi en direct
+            (KeyCode::F(2), _) => {
+                state.toggle_nudge();
+            }
+
+            // Seuil du front de l'IR (sensibilité de la détection du son direct)
+            (KeyCode::F(4), _) => state.cycle_onset(),
+
+            // Courbe cible : fichier --target, cibles embarquées, aucune
+            (KeyCode::F(5), KeyModifiers::SHIFT) => state.cycle_mask(),
+            (KeyCode::F(5), _) => state.cycle_target(),
+
+            // Comparaison A → B en Markdown pour un forum
+            (KeyCode::F(6), _) => state.export_forum_diff(),
+
+            // Thème de couleurs suivant
+            (KeyCode::F(7), _) => {
+                let next = theme::theme_name().next();
+                theme::set_theme(next);
+                state.status = Some((tr().theme_set)(next.name()));
+            }
+
+            // Rapport HTML de la dernière analyse
+            (KeyCode::End, _) => state.export_report(),
+            (KeyCode::Insert, _) => state.export_png(),
+            (KeyCode::F(12), _) => state.begin_notes(),
+
+            // Panneau de résultats : domaine suivant, niveau de détail
+            (KeyCode::F(8), _) => state.next_metric(),
+            (KeyCode::F(9), _) => {
+                let focus = state.metrics.focus;
+                state.metrics.unfold(focus);
+            }
+
+            // Suivi simultané G + D (bruits décorrélés, marche / arrêt)
+            (KeyCode::Char('?'), _) if !state.step.is_capturing() || state.dual.is_some() => {
+                state.toggle_dual();
+            }
+
+            // Mode fenêtre d'écoute (grille de positions micro)
+            (KeyCode::Char('w') | KeyCode::Char('W'), _)
+                if state.step == Step::Idle =>
+            {
+                state.toggle_window();
+            }
+
+            // Afficher / masquer la réponse impulsionnelle
+            (KeyCode::Char('i') | KeyCode::Char('I'), _) => {
+                state.show_ir = !state.show_ir;
+            }
+
+            // Afficher / masquer les zones spectrales du graphique
+            (KeyCode::Char('y') | KeyCode::Char('Y'), _) => {
+                state.show_regions = !state.show_regions;
+            }
+
+            // Historique : liste des dernières analyses ↔ tendance de toutes
+            (KeyCode::Char('`'), _) => {
+                state.show_trend = !state.show_trend;
+            }
+
+            // Mode simple ↔ mode expert
+            (KeyCode::Char('!'), _) => {
+                state.expert = !state.expert;
+                state.status = Some(if state.expert { tr().mode_expert } else { tr().mode_basic }.into());
+            }
+
+            // Basculer la langue de l'interface
+            (KeyCode::Char('t') | KeyCode::Char('T'), _) => {
+                strings::set_lang(strings::lang().toggle());
+            }
+
+            // Enregistrer / ouvrir la session
+            (KeyCode::Char('s') | KeyCode::Char('S'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.save_session();
+            }
+            (KeyCode::Char('o') | KeyCode::Char('O'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.load_session();
+            }
+            // Session compressée à partager (sans audio brut par défaut)
+            (KeyCode::Char('@'), _) if !state.step.is_capturing() => {
+                state.share_session();
+            }
+
+            // Exporter la réponse en fréquence (.frd / .csv)
+            (KeyCode::Char('e') | KeyCode::Char('E'), _)
+                if !state.step.is_capturing() =>
+            {
+                state.export_response();
+            }
+
+            // Augmenter le délai pré-capture (+0.1s, max 5.0s)
+            (KeyCode::Char('+') | KeyCode::Char('='), _)
+                if state.step == Step::Idle =>
+            {
+                state.nudge_pre_delay(1);
+            }
+
+            // Diminuer le délai pré-capture (-0.1s, min 0.0s)
+            (KeyCode::Char('-'), _) if state.step == Step::Idle => {
+                state.nudge_pre_delay(-1);
+            }
+
+            // Départ programmé des captures (seconde de la minute)
+            (KeyCode::Char('\\'), _) if !state.step.is_capturing() => {
+                state.begin_schedule();
+            }
+
+            // Sweep : [ ] durée ±1 s, , . début ÷/× 2, < > fin ÷/× 2, ( ) amplitude ±1 dB
+            (KeyCode::Char(c @ ('[' | ']' | ',' | '.' | '<' | '>' | '(' | ')' | '*')), _)
+                if state.step == Step::Idle =>
+            {
+                let mut sweep = state.sweep;
+                match c {
+                    '[' => sweep.duration_s -= 1.0,
+                    ']' => sweep.duration_s += 1.0,
+                    ',' => sweep.f_start_hz /= 2.0,
+                    '.' => sweep.f_start_hz *= 2.0,
+                    '<' => sweep.f_stop_hz /= 2.0,
+                    '>' => sweep.f_stop_hz *= 2.0,
+                    '(' => sweep.amplitude /= 10f32.powf(1.0 / 20.0),
+                    // Moyennes : 1, 2, 4… puis retour à 1
+                    '*' if sweep.averages >= SweepParams::MAX_AVERAGES => sweep.averages = 1,
+                    '*' => sweep.averages *= 2,
+                    _ => sweep.amplitude *= 10f32.powf(1.0 / 20.0),
+                }
+                state.sweep = sweep.clamped();
+            }
+
+            _ => {}
+        }
+        true
+    }
 }
@@ -0,0 +1,122 @@
+// ============================================================
+//  assets.rs — Données embarquées dans le binaire
+//
+//  Courbes cibles, masques de tolérance et calibrations micro
+//  d'exemple sont compilés dans l'exécutable (`include_str!` des
+//  fichiers de assets/) : un seul fichier à copier, rien à
+//  installer, quel que soit le système. Chaque ressource a un type
+//  et un nom ; là où un fichier est attendu, `builtin:<nom>` désigne
+//  la ressource embarquée (`--mic-cal builtin:generic-electret`).
+//  `--list-assets` les énumère. Les textes de l'interface sont déjà
+//  compilés (strings.rs).
+//
+//  Toutes les ressources sont des paires « fréquence  dB » par
+//  ligne, au format des fichiers de calibration : un en-tête ou un
+//  commentaire est ignoré.
+// ============================================================
+
+use anyhow::{Context, Result};
+
+use crate::{calibration::MicCalibration, strings::tr};
+
+/// Préfixe désignant une ressource embarquée à la place d'un chemin.
+pub const BUILTIN_PREFIX: &str = "builtin:";
+
+/// Nature d'une ressource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetKind {
+    /// Réponse visée (dB relatifs par fréquence).
+    TargetCurve,
+    /// Écart G/D toléré (± dB par fréquence).
+    ToleranceMask,
+    /// Écart d'un micro à une réponse plate.
+    Calibration,
+}
+
+impl AssetKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            AssetKind::TargetCurve => "target",
+            AssetKind::ToleranceMask => "mask",
+            AssetKind::Calibration => "calibration",
+        }
+    }
+}
+
+/// Ressource embarquée.
+#[derive(Debug, Clone, Copy)]
+pub struct Asset {
+    pub kind: AssetKind,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub text: &'static str,
+}
+
+impl Asset {
+    /// Points (fréquence Hz, dB) triés par fréquence.
+    pub fn points(&self) -> Result<Vec<(f32, f32)>> {
+        MicCalibration::parse(self.text)
+            .map(|c| c.points)
+            .with_context(|| (tr().asset_invalid)(&format!("{}{}", BUILTIN_PREFIX, self.name)))
+    }
+}
+
+/// Registre des ressources, par type puis dans l'ordre de présentation.
+pub const ASSETS: &[Asset] = &[
+    Asset {
+        kind: AssetKind::TargetCurve,
+        name: "flat",
+        description: "Réponse plate",
+        text: include_str!("../assets/targets/flat.frd"),
+    },
+    Asset {
+        kind: AssetKind::TargetCurve,
+        name: "house-curve",
+        description: "Courbe de salon, −0,6 dB / octave",
+        text: include_str!("../assets/targets/house-curve.frd"),
+    },
+    Asset {
+        kind: AssetKind::TargetCurve,
+        name: "harman",
+        description: "Courbe de pièce type Harman : grave relevé, aigu descendant",
+        text: include_str!("../assets/targets/harman.frd"),
+    },
+    Asset {
+        kind: AssetKind::ToleranceMask,
+        name: "standard",
+        description: "Écart G/D toléré : ±2 dB au médium, plus large aux extrêmes",
+        text: include_str!("../assets/masks/standard.csv"),
+    },
+    Asset {
+        kind: AssetKind::ToleranceMask,
+        name: "strict",
+        description: "Écart G/D toléré : ±1 dB au médium",
+        text: include_str!("../assets/masks/strict.csv"),
+    },
+    Asset {
+        kind: AssetKind::Calibration,
+        name: "generic-electret",
+        description: "Capsule électret générique (à défaut du fichier du micro)",
+        text: include_str!("../assets/calibration/generic-electret.txt"),
+    },
+    Asset {
+        kind: AssetKind::Calibration,
+        name: "example-umik1",
+        description: "Exemple de fichier UMIK-1 (format seulement)",
+        text: include_str!("../assets/calibration/example-umik1.txt"),
+    },
+];
+
+/// Ressources d'un type.
+pub fn list(kind: AssetKind) -> impl Iterator<Item = &'static Asset> {
+    ASSETS.iter().filter(move |a| a.kind == kind)
+}
+
+pub fn find(kind: AssetKind, name: &str) -> Option<&'static Asset> {
+    list(kind).find(|a| a.name == name)
+}
+
+/// Nom de ressource d'un `builtin:<nom>`, `None` pour un chemin ordinaire.
+pub fn builtin_name(spec: &str) -> Option<&str> {
+    spec.strip_prefix(BUILTIN_PREFIX)
+}
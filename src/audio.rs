@@ -1,143 +1,1517 @@
 // ============================================================
 //  audio.rs — Lecture & capture audio via cpal
 //
-//  - Lecture d'un signal de test sur le canal gauche ou droit
+//  - Lecture d'un signal de test sur un canal de sortie (FL, FR, C, LFE…)
 //  - Enregistrement simultané depuis le microphone
 //  - Support : WASAPI (Windows), CoreAudio (macOS), ALSA (Linux)
+//  - Taux négocié avec chaque périphérique (`PREFERRED_RATES`) ;
+//    signal et capture convertis au besoin (resample.rs)
+//  - Moteur persistant : flux gardés ouverts entre les captures
+//    (délai d'ouverture payé une fois) ; capture calée sur
+//    l'horodatage de la première trame jouée, quelle que soit la
+//    phase des callbacks d'entrée et de sortie
 // ============================================================
 
 use anyhow::{Context, Result, bail};
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-use cpal::{SampleFormat, SampleRate, StreamConfig};
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use cpal::{BuildStreamError, FromSample, Sample, SampleFormat, SampleRate, SizedSample, StreamConfig, StreamInstant, SupportedStreamConfigRange};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex, MutexGuard, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
 
-use crate::dsp::SAMPLE_RATE;
+use crate::dsp::{self, SweepParams, FFT_SIZE, SAMPLE_RATE};
+use crate::mockaudio::{self, Fault, MockAudio};
+use crate::resample;
+use crate::schedule::StartSchedule;
+use crate::strings::tr;
 
+/// Fraction minimale de la durée de capture demandée qui doit réellement
+/// arriver : en dessous, le flux d'entrée a été interrompu (veille, changement
+/// de format, périphérique repris par une autre application).
+pub const MIN_CAPTURE_RATIO: f32 = 0.95;
+
+/// Niveau d'entrée considéré comme écrêté (pleine échelle à 0,01 dB près).
+pub const CLIP_LEVEL: f32 = 0.999;
+/// Échantillons écrêtés tolérés par piste (un pic isolé à pleine échelle ne
+/// fausse pas l'analyse ; une série d'échantillons bloqués, si).
+pub const MAX_CLIPPED_SAMPLES: usize = 2;
+/// Baisse de gain conseillée après un écrêtage : le niveau réel est inconnu.
+pub const CLIP_BACKOFF_DB: f32 = 6.0;
+/// Niveau RMS minimal de la tranche la plus forte de la capture (dBFS) :
+/// en dessous, le spectre est noyé dans le bruit de l'entrée.
+pub const MIN_RMS_DBFS: f32 = -55.0;
+/// Niveau RMS visé par la correction de gain conseillée (dBFS).
+pub const TARGET_RMS_DBFS: f32 = -25.0;
+/// Longueur des tranches sur lesquelles le RMS est mesuré (s).
+const LEVEL_WINDOW_SECS: f32 = 0.1;
+
+/// Formats d'échantillon acceptés, par ordre de préférence. Les entiers
+/// (interfaces sans mode flottant) sont convertis dans les callbacks,
+/// pleine échelle = ±1.0.
+pub const SAMPLE_FORMATS: [SampleFormat; 4] = [SampleFormat::F32, SampleFormat::I32, SampleFormat::I16, SampleFormat::U16];
+
+/// Taux d'échantillonnage essayés, par ordre de préférence, quand un
+/// périphérique refuse celui de la session.
+pub const PREFERRED_RATES: [u32; 5] = [SAMPLE_RATE, 44_100, 96_000, 88_200, 192_000];
+
+/// Canal de sortie, par son indice dans le flux entrelacé (FL = 0, FR = 1…).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Channel(pub u16);
+
+/// Noms usuels des canaux d'un layout 5.1 / 7.1 (ordre WAVE / WASAPI).
+/// L'ordre réel dépend du pilote : ALSA place par exemple C et LFE après les surrounds.
+pub const CHANNEL_NAMES: [&str; 8] = ["FL", "FR", "C", "LFE", "SL", "SR", "SBL", "SBR"];
+
+impl Channel {
+    pub const LEFT: Channel = Channel(0);
+    pub const RIGHT: Channel = Channel(1);
+    pub const LFE: Channel = Channel(3);
+
+    /// Indice du canal de sortie.
+    pub fn output_index(self) -> usize {
+        self.0 as usize
+    }
+
+    /// Nom court (« FL », « C »…) ou « CH9 » au-delà du 7.1.
+    pub fn name(self) -> String {
+        CHANNEL_NAMES
+            .get(self.output_index())
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| format!("CH{}", self.0 + 1))
+    }
+
+    /// Retrouve un canal par son nom (insensible à la casse) ou son indice.
+    pub fn from_name(name: &str) -> Option<Channel> {
+        let upper = name.trim().to_uppercase();
+        CHANNEL_NAMES
+            .iter()
+            .position(|n| *n == upper)
+            .map(|i| Channel(i as u16))
+            .or_else(|| upper.strip_prefix("CH").and_then(|n| n.parse::<u16>().ok()).filter(|n| *n > 0).map(|n| Channel(n - 1)))
+            .or_else(|| upper.parse::<u16>().ok().map(Channel))
+    }
+}
+
+/// Résultat d'une capture : mix mono + pistes individuelles de chaque entrée.
+/// Avec deux micros (ou un micro binaural), `inputs` contient les deux oreilles.
+/// La piste de boucle (`Loopback::input`) est tenue à part, hors du mix.
+#[derive(Debug, Clone)]
+pub struct Capture {
+    pub mono: Vec<f32>,
+    pub inputs: Vec<Vec<f32>>,
+    pub loopback: Option<Vec<f32>>,
+}
+
+impl Capture {
+    /// Toutes les pistes converties de `from` à `to` Hz, en une passe (même
+    /// retard de filtre pour chacune).
+    pub fn resampled(self, from: u32, to: u32) -> Result<Capture> {
+        if from == to {
+            return Ok(self);
+        }
+        self.map_tracks(|tracks| resample::resample_tracks(tracks, from, to))
+    }
+
+    /// Applique `f` à toutes les pistes ensemble (mix, entrées, boucle, dans
+    /// cet ordre), qui doit en rendre autant.
+    pub fn map_tracks(self, f: impl FnOnce(&[Vec<f32>]) -> Result<Vec<Vec<f32>>>) -> Result<Capture> {
+        let (n_inputs, has_loopback) = (self.inputs.len(), self.loopback.is_some());
+        let mut tracks = vec![self.mono];
+        tracks.extend(self.inputs);
+        tracks.extend(self.loopback);
+        let mut tracks = f(&tracks)?.into_iter();
+        let mono = tracks.next().unwrap_or_default();
+        let inputs = tracks.by_ref().take(n_inputs).collect();
+        let loopback = if has_loopback { tracks.next() } else { None };
+        Ok(Capture { mono, inputs, loopback })
+    }
+
+    /// Moyenne cohérente de plusieurs captures du même sweep : toutes les
+    /// pistes (mix, entrées, boucle) reçoivent le décalage mesuré sur le mix,
+    /// ce qui préserve les écarts entre elles.
+    pub fn average(captures: Vec<Capture>, sweep: &[f32], params: &SweepParams, sample_rate: u32) -> Option<Capture> {
+        if captures.len() <= 1 {
+            return captures.into_iter().next();
+        }
+        let monos: Vec<&[f32]> = captures.iter().map(|c| c.mono.as_slice()).collect();
+        let offsets = dsp::alignment_offsets(&monos, sweep, params, sample_rate);
+        let mono = dsp::average_aligned(&monos, &offsets);
+
+        let inputs = (0..captures[0].inputs.len())
+            .map(|k| {
+                let tracks: Vec<&[f32]> = captures.iter().filter_map(|c| c.inputs.get(k).map(|t| t.as_slice())).collect();
+                dsp::average_aligned(&tracks, &offsets)
+            })
+            .collect();
+        let loopbacks: Option<Vec<&[f32]>> = captures.iter().map(|c| c.loopback.as_deref()).collect();
+        let loopback = loopbacks.map(|tracks| dsp::average_aligned(&tracks, &offsets));
+
+        Some(Capture { mono, inputs, loopback })
+    }
+
+    /// Tranche `[start, start + len)` de chaque piste, complétée de silence
+    /// au-delà de la fin (découpage d'une mesure enchaînée).
+    pub fn segment(&self, start: usize, len: usize) -> Capture {
+        let slice = |track: &[f32]| {
+            let mut out: Vec<f32> = track.iter().skip(start).take(len).copied().collect();
+            out.resize(len, 0.0);
+            out
+        };
+        Capture {
+            mono: slice(&self.mono),
+            inputs: self.inputs.iter().map(|t| slice(t)).collect(),
+            loopback: self.loopback.as_deref().map(slice),
+        }
+    }
+}
+
+/// Boucle électrique de référence : une entrée de la carte son reliée
+/// directement à une sortie. Le signal y arrive avec la seule latence des
+/// buffers, ce qui date son émission réelle à chaque capture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Loopback {
+    /// Entrée qui reçoit la boucle (indice à partir de 0).
+    pub input: usize,
+    /// Sortie qui porte une copie du signal vers la boucle. Absente : la
+    /// boucle est prise sur la sortie de l'enceinte mesurée (câble en Y).
+    #[serde(default)]
+    pub output: Option<Channel>,
+}
+
+/// Erreurs audio identifiées, récupérables via `anyhow::Error::downcast_ref`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioError {
+    /// Le périphérique est tenu par une autre application (DAW, lecteur en
+    /// mode exclusif WASAPI, `EBUSY` ALSA).
+    DeviceBusy { device: String, detail: String },
+    /// L'ouverture des flux n'a pas abouti dans le délai imparti.
+    Timeout { secs: f32 },
+    /// Entrée saturée : `samples` échantillons à pleine échelle.
+    Clipped { samples: usize },
+    /// Capture trop faible : `rms_dbfs` sur sa tranche la plus forte.
+    TooQuiet { rms_dbfs: f32 },
+    /// Lecture et capture arrêtées à la demande de l'appelant.
+    Cancelled,
+}
+
+impl std::fmt::Display for AudioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AudioError::DeviceBusy { device, detail } => f.write_str(&(tr().err_device_busy)(device, detail)),
+            AudioError::Timeout { secs } => f.write_str(&(tr().err_timeout)(*secs)),
+            AudioError::Clipped { samples } => f.write_str(&(tr().err_clipped)(*samples, CLIP_BACKOFF_DB)),
+            AudioError::TooQuiet { rms_dbfs } => f.write_str(&(tr().err_too_quiet)(*rms_dbfs, TARGET_RMS_DBFS - rms_dbfs)),
+            AudioError::Cancelled => f.write_str(tr().err_cancelled),
+        }
+    }
+}
+
+impl std::error::Error for AudioError {}
+
+/// Délai maximal d'ouverture des flux (hors pause pré-capture).
+pub const DEVICE_OPEN_TIMEOUT_SECS: f32 = 5.0;
+/// Pause avant la nouvelle tentative avec la config par défaut.
+const RETRY_PAUSE: Duration = Duration::from_millis(500);
+/// Pas de la boucle de capture : progression et demande d'arrêt.
+const POLL_STEP: Duration = Duration::from_millis(50);
+/// Capacité de la file de capture (s de signal) : le thread audio la vide à
+/// chaque `POLL_STEP`, la marge couvre un thread retardé par le système.
+const RING_SECS: usize = 2;
+/// Entrée sans nouvelle trame pendant ce délai : flux interrompu, la capture
+/// s'arrête là (et `check_capture_length` la refuse si elle est trop courte).
+const STALL_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Étape d'une opération longue : capture (pause, lecture, queue), puis
+/// traitements faits dans le thread de capture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Phase {
+    /// Pause silencieuse avant la lecture (`pre_delay_secs`).
+    #[default]
+    PreDelay,
+    /// Signal de test en cours de lecture.
+    Playing,
+    /// Signal joué, enregistrement de la queue (réverbération, latence).
+    Tail,
+    /// Moyenne cohérente des captures répétées.
+    Averaging,
+    /// Déconvolution : réponse impulsionnelle et grandeurs qui en découlent.
+    Deconvolving,
+    /// Attente du départ programmé (`schedule`), flux ouverts et capture
+    /// armée, avant la pause.
+    Scheduled,
+}
+
+impl Phase {
+    pub fn label(self) -> &'static str {
+        match self {
+            Phase::PreDelay => tr().phase_pre_delay,
+            Phase::Playing => tr().phase_playing,
+            Phase::Tail => tr().phase_tail,
+            Phase::Averaging => tr().phase_averaging,
+            Phase::Deconvolving => tr().phase_deconvolving,
+            Phase::Scheduled => tr().phase_scheduled,
+        }
+    }
+}
+
+/// Avancement : étape en cours et fraction (0..1) de cette étape.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Progress {
+    pub phase: Phase,
+    pub fraction: f32,
+}
+
+impl Progress {
+    pub fn new(phase: Phase, fraction: f32) -> Self {
+        Progress { phase, fraction: fraction.clamp(0.0, 1.0) }
+    }
+}
+
+/// Taux et durées d'une capture.
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Channel {
-    Left,
-    Right,
+pub struct CaptureTiming {
+    /// Taux du signal fourni et de la capture rendue (Hz), quel que soit le
+    /// taux réellement ouvert sur chaque périphérique.
+    pub sample_rate: u32,
+    /// Durée enregistrée après la pause (s).
+    pub capture_secs: f32,
+    /// Pause silencieuse avant la lecture (s).
+    pub pre_delay_secs: f32,
+    /// Départ programmé, attendu flux ouverts et capture armée, avant la
+    /// pause.
+    pub start_at: Option<StartSchedule>,
+}
+
+impl CaptureTiming {
+    /// Trames de capture au taux `rate`.
+    fn frames_at(&self, rate: u32) -> usize {
+        (self.capture_secs * rate as f32).round() as usize
+    }
+
+    /// Attente maximale avant le départ programmé (s).
+    fn scheduled_secs(&self) -> f32 {
+        self.start_at.map_or(0.0, StartSchedule::remaining_secs)
+    }
+
+    /// Trames de la pause pré-capture au taux `rate`.
+    fn frames_before(&self, rate: u32) -> usize {
+        (self.pre_delay_secs.max(0.0) * rate as f32).round() as usize
+    }
+}
+
+/// Liaison avec l'appelant pendant une capture.
+pub struct CaptureLink<'a> {
+    /// Avancement par étape : pause, lecture, queue.
+    pub progress: mpsc::Sender<Progress>,
+    /// Un message reçu arrête lecture et capture (`AudioError::Cancelled`).
+    pub cancel: Option<&'a mpsc::Receiver<()>>,
+}
+
+impl CaptureLink<'_> {
+    /// Progression seule, sans arrêt possible.
+    pub fn progress_only(progress: mpsc::Sender<Progress>) -> Self {
+        CaptureLink { progress, cancel: None }
+    }
+
+    fn cancel_requested(&self) -> bool {
+        self.cancel.is_some_and(|rx| rx.try_recv().is_ok())
+    }
+}
+
+/// Signaux échangés avec le thread audio.
+struct StreamSignals {
+    progress: mpsc::Sender<Progress>,
+    /// Signalé dès que les deux flux sont construits.
+    opened: mpsc::Sender<()>,
+    /// Levé par l'appelant : les flux sont arrêtés au pas suivant.
+    stop: Arc<AtomicBool>,
 }
 
 /// Lance la lecture du signal `signal` sur le canal choisi,
-/// et capture simultanément le microphone pendant `capture_secs` secondes.
-/// `pre_delay_secs` : pause silencieuse avant le démarrage (évite d'enregistrer la frappe clavier).
-/// Retourne les échantillons capturés (mono f32, taux = SAMPLE_RATE).
+/// et capture simultanément le microphone pendant `timing.capture_secs` secondes.
+/// `channel` est un emplacement du flux entrelacé : pour viser une enceinte
+/// physique, le passer d'abord par `ChannelMap::resolve`.
+/// `timing.pre_delay_secs` : pause silencieuse avant le démarrage (évite d'enregistrer la frappe clavier).
+/// `retry_default_config` : si l'ouverture échoue, réessaie une fois avec la
+/// config par défaut du périphérique (format partagé du système).
+/// `loopback` : entrée reliée électriquement à la sortie ; sa piste est
+/// renvoyée dans `Capture::loopback` et exclue du mix mono.
+/// Retourne les échantillons capturés (mix mono f32 + pistes par entrée) au
+/// taux `timing.sample_rate`, celui de `signal` : un périphérique ouvert à un
+/// autre taux est converti (resample.rs).
+///
+/// Les flux vivent dans un thread dédié : un pilote bloqué à l'ouverture
+/// donne `AudioError::Timeout` au lieu de figer l'appelant. Un message sur
+/// `link.cancel` ferme les flux au pas suivant (50 ms, pause comprise) et
+/// donne `AudioError::Cancelled`.
 pub fn play_and_capture(
     signal: &[f32],
     channel: Channel,
-    capture_secs: f32,
-    pre_delay_secs: f32,
-    progress_tx: std::sync::mpsc::Sender<f32>,
-) -> Result<Vec<f32>> {
-    let host = cpal::default_host();
+    timing: CaptureTiming,
+    retry_default_config: bool,
+    loopback: Option<Loopback>,
+    link: CaptureLink,
+) -> Result<Capture> {
+    let wiring = Wiring { route: Route::Slot(channel), loopback };
+    spawn_streams(signal, wiring, timing, retry_default_config, link)
+}
 
-    // ── Sortie ──────────────────────────────────────────────────────────────
-    let output_device = host
-        .default_output_device()
-        .context("Aucune sortie audio disponible")?;
-
-    let out_config = find_stereo_config(&output_device, SampleRate(SAMPLE_RATE))
-        .context("Format de sortie stéréo 48 kHz introuvable")?;
-
-    // Prépare le buffer de lecture multicanal (interleaved, signal sur ch0 ou ch1, zéros ailleurs)
-    let num_out_channels = out_config.channels as usize;
-    let play_buf: Arc<Vec<f32>> = Arc::new(interleave_to_multichannel(signal, channel, num_out_channels));
-    let play_pos = Arc::new(Mutex::new(0usize));
-
-    let pb = Arc::clone(&play_buf);
-    let pp = Arc::clone(&play_pos);
-
-    let out_stream = output_device.build_output_stream(
-        &out_config,
-        move |data: &mut [f32], _| {
-            let mut pos = pp.lock().unwrap();
-            for frame in data.chunks_mut(num_out_channels) {
-                if *pos + num_out_channels <= pb.len() {
-                    frame.copy_from_slice(&pb[*pos..*pos + num_out_channels]);
-                    *pos += num_out_channels;
-                } else {
-                    for s in frame.iter_mut() {
-                        *s = 0.0;
+/// Sonde de câblage : `sequence` est découpée en segments de `segment_len`
+/// échantillons, le segment k étant joué sur l'emplacement k du flux de
+/// sortie (les segments au-delà du nombre de canaux restent muets).
+/// Même config de sortie qu'une capture G/D, donc même ordre de canaux.
+/// La boucle éventuelle est seulement écartée du mix (pas de copie du signal).
+pub fn play_probe_and_capture(
+    sequence: &[f32],
+    segment_len: usize,
+    timing: CaptureTiming,
+    retry_default_config: bool,
+    loopback: Option<Loopback>,
+    link: CaptureLink,
+) -> Result<Capture> {
+    let wiring = Wiring { route: Route::Probe(segment_len), loopback };
+    spawn_streams(sequence, wiring, timing, retry_default_config, link)
+}
+
+/// Mesure enchaînée G → D : `sequence` est faite de deux segments de
+/// `segment_len` échantillons (sweep puis silence), le premier joué sur
+/// `channels.0`, le second sur `channels.1`, dans une seule capture.
+pub fn play_pair_and_capture(
+    sequence: &[f32],
+    channels: (Channel, Channel),
+    segment_len: usize,
+    timing: CaptureTiming,
+    retry_default_config: bool,
+    loopback: Option<Loopback>,
+    link: CaptureLink,
+) -> Result<Capture> {
+    let wiring = Wiring { route: Route::Pair(channels.0, channels.1, segment_len), loopback };
+    spawn_streams(sequence, wiring, timing, retry_default_config, link)
+}
+
+/// Mesure simultanée : `sequence` est faite de deux moitiés de même longueur,
+/// la première jouée sur `channels.0`, la seconde en même temps sur
+/// `channels.1` (bruits décorrélés, `dualnoise`).
+pub fn play_dual_and_capture(
+    sequence: &[f32],
+    channels: (Channel, Channel),
+    timing: CaptureTiming,
+    retry_default_config: bool,
+    link: CaptureLink,
+) -> Result<Capture> {
+    let wiring = Wiring { route: Route::Dual(channels.0, channels.1), loopback: None };
+    spawn_streams(sequence, wiring, timing, retry_default_config, link)
+}
+
+/// Placement du signal mono dans le flux de sortie entrelacé.
+#[derive(Debug, Clone, Copy)]
+enum Route {
+    /// Tout le signal sur un seul emplacement.
+    Slot(Channel),
+    /// Segment k (longueur donnée) sur l'emplacement k.
+    Probe(usize),
+    /// Premier segment sur le premier emplacement, la suite sur le second.
+    Pair(Channel, Channel, usize),
+    /// Première moitié sur le premier emplacement, seconde moitié en même
+    /// temps sur le second.
+    Dual(Channel, Channel),
+}
+
+impl Route {
+    /// Emplacements de sortie requis (sonde : ceux que le flux offre).
+    fn slots(self) -> Vec<Channel> {
+        match self {
+            Route::Slot(channel) => vec![channel],
+            Route::Probe(_) => Vec::new(),
+            Route::Pair(first, second, _) | Route::Dual(first, second) => vec![first, second],
+        }
+    }
+
+    /// Trames jouées pour un signal de `len` échantillons.
+    fn played_len(self, len: usize) -> usize {
+        match self {
+            Route::Dual(..) => len / 2,
+            _ => len,
+        }
+    }
+}
+
+/// Placement du signal et boucle de référence éventuelle.
+#[derive(Debug, Clone, Copy)]
+struct Wiring {
+    route: Route,
+    loopback: Option<Loopback>,
+}
+
+impl Wiring {
+    /// Emplacement de la copie destinée à la boucle (captures G/D seulement).
+    fn loopback_output(&self) -> Option<Channel> {
+        match self.route {
+            Route::Slot(_) | Route::Pair(..) => self.loopback.and_then(|l| l.output),
+            Route::Probe(_) | Route::Dual(..) => None,
+        }
+    }
+
+    /// Même placement pour un signal converti de `from` à `to` Hz.
+    fn at_rate(self, from: u32, to: u32) -> Wiring {
+        let scale = |len: usize| (len as f64 * to as f64 / from as f64).round() as usize;
+        match self.route {
+            Route::Probe(len) => Wiring { route: Route::Probe(scale(len)), ..self },
+            Route::Pair(first, second, len) => Wiring { route: Route::Pair(first, second, scale(len)), ..self },
+            Route::Slot(_) | Route::Dual(..) => self,
+        }
+    }
+}
+
+/// Thread audio et délais d'attente, communs aux deux modes de lecture.
+fn spawn_streams(
+    signal: &[f32],
+    wiring: Wiring,
+    timing: CaptureTiming,
+    retry_default_config: bool,
+    link: CaptureLink,
+) -> Result<Capture> {
+    let (opened_tx, opened_rx) = mpsc::channel::<()>();
+    let (result_tx, result_rx) = mpsc::channel::<Result<Capture>>();
+    let stop = Arc::new(AtomicBool::new(false));
+    let signals = StreamSignals { progress: link.progress.clone(), opened: opened_tx, stop: Arc::clone(&stop) };
+    let signal = signal.to_vec();
+    thread::spawn(move || {
+        let result = run_streams(&signal, wiring, timing, retry_default_config, signals);
+        let _ = result_tx.send(result);
+    });
+
+    let timeout = AudioError::Timeout { secs: DEVICE_OPEN_TIMEOUT_SECS };
+    match opened_rx.recv_timeout(Duration::from_secs_f32(DEVICE_OPEN_TIMEOUT_SECS)) {
+        Ok(()) => {}
+        // Le thread s'est terminé sans ouvrir les flux : son erreur est disponible
+        Err(mpsc::RecvTimeoutError::Disconnected) => {}
+        Err(mpsc::RecvTimeoutError::Timeout) => return Err(timeout.into()),
+    }
+    // La capture dure départ programmé + pre_delay + capture_secs ; la fermeture
+    // des flux a la même marge
+    let busy_secs = timing.scheduled_secs() + timing.pre_delay_secs + timing.capture_secs;
+    let deadline = Instant::now() + Duration::from_secs_f32(busy_secs + DEVICE_OPEN_TIMEOUT_SECS);
+    loop {
+        // Arrêt coopératif : le thread audio ferme lui-même ses flux
+        if link.cancel_requested() {
+            stop.store(true, Ordering::Relaxed);
+        }
+        match result_rx.recv_timeout(POLL_STEP) {
+            Ok(result) => return result,
+            Err(mpsc::RecvTimeoutError::Timeout) if Instant::now() < deadline => {}
+            Err(_) => return Err(timeout.into()),
+        }
+    }
+}
+
+/// Flux ouverts, capture armée mais pas encore démarrée : périphériques
+/// cpal (moteur persistant ou flux propres) ou carte simulée (`mockaudio`),
+/// tous deux sur une `StreamSlot`. Les rendre (drop) tait aussitôt le
+/// haut-parleur.
+struct OpenStreams {
+    streams: Streams,
+    /// Trames remises à la sortie, tenues par son callback.
+    played: Arc<AtomicUsize>,
+    /// Trames interleaved poussées par le callback d'entrée.
+    ring: Arc<SampleRing>,
+    out_rate: u32,
+    in_rate: u32,
+    num_in_channels: usize,
+}
+
+enum Streams {
+    Device(DeviceLease),
+    Mock(MockStreams),
+}
+
+impl OpenStreams {
+    fn play(&mut self) {
+        match &mut self.streams {
+            Streams::Device(lease) => lease.slot.start(),
+            Streams::Mock(mock) => mock.play(),
+        }
+    }
+}
+
+/// Ouverture, lecture et capture proprement dites (thread audio).
+fn run_streams(
+    signal: &[f32],
+    wiring: Wiring,
+    timing: CaptureTiming,
+    retry_default_config: bool,
+    signals: StreamSignals,
+) -> Result<Capture> {
+    let s = tr();
+    let rate = timing.sample_rate;
+    let mut streams = match mockaudio::installed() {
+        Some(mock) => open_mock(&mock, signal, wiring, timing, retry_default_config)?,
+        None => open_devices(signal, wiring, timing, retry_default_config)?,
+    };
+    let (num_in_channels, in_rate, out_rate) = (streams.num_in_channels, streams.in_rate, streams.out_rate);
+    // Durées comptées en trames d'entrée ; les trames jouées y sont ramenées
+    let target_frames = timing.frames_at(in_rate);
+    let to_input = |frames: usize| (frames as f64 * in_rate as f64 / out_rate as f64) as usize;
+    let mut interleaved = Vec::with_capacity(target_frames * num_in_channels);
+    let _ = signals.opened.send(());
+    let stopped = || signals.stop.load(Ordering::Relaxed);
+
+    // ── Synchronisation ─────────────────────────────────────────────────────
+    // Départ programmé : flux ouverts et armés, seule l'horloge reste à attendre
+    if let Some(at) = timing.start_at {
+        if !at.wait(stopped, |fraction| {
+            let _ = signals.progress.send(Progress::new(Phase::Scheduled, fraction));
+        }) {
+            return Err(AudioError::Cancelled.into());
+        }
+    }
+    // Pause avant démarrage pour laisser le bruit de frappe se dissiper
+    let pause = Duration::from_secs_f32(timing.pre_delay_secs.max(0.0));
+    let pause_end = Instant::now() + pause;
+    while Instant::now() < pause_end && !stopped() {
+        std::thread::sleep(POLL_STEP.min(pause_end.saturating_duration_since(Instant::now())));
+        let left = pause_end.saturating_duration_since(Instant::now());
+        let _ = signals.progress.send(Progress::new(Phase::PreDelay, 1.0 - left.as_secs_f32() / pause.as_secs_f32()));
+    }
+    if stopped() {
+        return Err(AudioError::Cancelled.into());
+    }
+
+    streams.play();
+
+    // Progression d'après les trames des callbacks, pas l'horloge : un gros
+    // buffer ou un pilote qui cale se voient sur la jauge
+    let signal_frames = ((wiring.route.played_len(signal.len()) as f64 * in_rate as f64 / rate as f64) as usize).min(target_frames);
+    let (ring, played) = (Arc::clone(&streams.ring), Arc::clone(&streams.played));
+    let mut last_frames = (Instant::now(), 0usize);
+    while !stopped() {
+        std::thread::sleep(POLL_STEP);
+        ring.drain_into(&mut interleaved);
+        let frames = ring.pushed() / num_in_channels.max(1);
+        let done = frames.min(to_input(played.load(Ordering::Relaxed))).min(target_frames);
+        let progress = if done < signal_frames {
+            Progress::new(Phase::Playing, done as f32 / signal_frames as f32)
+        } else {
+            Progress::new(Phase::Tail, (done - signal_frames) as f32 / (target_frames - signal_frames).max(1) as f32)
+        };
+        let _ = signals.progress.send(progress);
+        if frames >= target_frames {
+            break;
+        }
+        if frames > last_frames.1 {
+            last_frames = (Instant::now(), frames);
+        } else if last_frames.0.elapsed() >= STALL_TIMEOUT {
+            break;
+        }
+    }
+
+    // Flux rendus avant tout retour : le haut-parleur se tait aussitôt
+    drop(streams);
+    if stopped() {
+        return Err(AudioError::Cancelled.into());
+    }
+    ring.drain_into(&mut interleaved);
+    if ring.overruns() > 0 {
+        bail!((s.err_overrun)(ring.overruns()));
+    }
+
+    if interleaved.is_empty() {
+        bail!(s.err_no_samples);
+    }
+
+    let loopback_input = wiring.loopback.map(|l| l.input);
+    if let Some(input) = loopback_input.filter(|&i| i >= num_in_channels) {
+        bail!((s.err_loopback_input)(input, num_in_channels));
+    }
+    let capture = deinterleave(&interleaved, num_in_channels, loopback_input).resampled(in_rate, rate)?;
+    check_capture_length(capture.mono.len(), timing.frames_at(rate), wiring.route.played_len(signal.len()))?;
+    check_capture_level(&capture, signal.iter().any(|&s| s != 0.0), rate)?;
+    Ok(capture)
+}
+
+/// Prépare une capture sur les périphériques par défaut (cpal) : flux du
+/// moteur persistant, ou flux propres à la capture (`set_keep_streams`).
+/// Le signal est armé mais muet jusqu'à `OpenStreams::play`.
+fn open_devices(signal: &[f32], wiring: Wiring, timing: CaptureTiming, retry_default_config: bool) -> Result<OpenStreams> {
+    let rate = timing.sample_rate;
+    let need = DeviceNeed {
+        rate,
+        out_channels: min_output_channels(wiring),
+        in_channels: wiring.loopback.map_or(1, |l| l.input as u16 + 1),
+        retry_default_config,
+    };
+    let (mut lease, format) = acquire_streams(need)?;
+    check_output_channels(wiring, format.out_channels)?;
+
+    // Signal au taux de la sortie ouverte
+    let played_signal = resample::resample(signal, rate, format.out_rate)?;
+    let buffer = output_buffer(&played_signal, wiring.at_rate(rate, format.out_rate), format.out_channels as usize)
+        .map_err(|e| classify_stream_error(&e, &format.out_name))?;
+    // Capture interleaved brute, vidée de la file au fil de l'eau ; le mix
+    // mono est fait après la capture
+    let num_in_channels = (format.in_channels as usize).max(1);
+    let played = Arc::new(AtomicUsize::new(0));
+    let ring = Arc::new(SampleRing::new(RING_SECS * format.in_rate as usize * num_in_channels));
+    let limit = timing.frames_at(format.in_rate) * num_in_channels;
+    lease.arm(OutputJob { buffer, played: Arc::clone(&played) }, InputJob::new(Arc::clone(&ring), limit))?;
+    Ok(OpenStreams {
+        streams: Streams::Device(lease),
+        played,
+        ring,
+        out_rate: format.out_rate,
+        in_rate: format.in_rate,
+        num_in_channels,
+    })
+}
+
+/// Canaux de sortie nécessaires aux emplacements de `wiring` (2 au moins).
+fn min_output_channels(wiring: Wiring) -> u16 {
+    let slots = wiring.route.slots().into_iter().chain(wiring.loopback_output());
+    slots.map(|c| c.output_index() as u16 + 1).max().unwrap_or(2).max(2)
+}
+
+/// Refuse une sortie de `channels` canaux qui n'a pas les emplacements de `wiring`.
+fn check_output_channels(wiring: Wiring, channels: u16) -> Result<()> {
+    let slots = wiring.route.slots().into_iter().chain(wiring.loopback_output());
+    if let Some(highest) = slots.map(|c| c.output_index()).max().filter(|&h| (channels as usize) <= h) {
+        bail!((tr().err_too_few_channels)(channels, highest));
+    }
+    Ok(())
+}
+
+// ─── Carte simulée (mockaudio) ──────────────────────────────────────────────
+
+/// Durée d'un callback simulé, en temps de l'horloge simulée.
+const MOCK_PERIOD: Duration = Duration::from_millis(10);
+
+/// `StreamSlot` de la carte simulée, partagée par les captures comme celle
+/// du moteur : une seconde capture armée en même temps est refusée.
+static MOCK_SLOT: OnceLock<Arc<StreamSlot>> = OnceLock::new();
+
+/// Callbacks simulés : un thread, lancé par `play`, qui passe à la
+/// `StreamSlot` un bloc de sortie puis un bloc de la capture précalculée,
+/// période par période, horodatés comme ceux de cpal.
+struct MockStreams {
+    slot: Arc<StreamSlot>,
+    recording: Arc<Vec<f32>>,
+    num_out_channels: usize,
+    num_in_channels: usize,
+    rate: u32,
+    /// Trames par callback.
+    period: usize,
+    /// Durée réelle d'un callback.
+    tick: Duration,
+    /// Retard des blocs d'entrée sur ceux de la sortie (trames, négatif :
+    /// en avance).
+    skew: isize,
+    /// Trames livrées avant que l'entrée ne se taise.
+    silent_after: usize,
+    drop_every: Option<usize>,
+    stop: Arc<AtomicBool>,
+    callbacks: Option<thread::JoinHandle<()>>,
+}
+
+impl MockStreams {
+    fn play(&mut self) {
+        self.slot.start();
+        let (slot, stop, recording) = (Arc::clone(&self.slot), Arc::clone(&self.stop), Arc::clone(&self.recording));
+        let (outs, ins, rate, period, tick) = (self.num_out_channels, self.num_in_channels, self.rate, self.period, self.tick);
+        let (skew, silent_after, drop_every) = (self.skew, self.silent_after, self.drop_every);
+        self.callbacks = Some(thread::spawn(move || {
+            let mut output = vec![0.0f32; period * outs];
+            let (mut pos, mut calls) = (0usize, 0usize);
+            let secs = |frame: isize| frame as f64 / rate as f64;
+            while !stop.load(Ordering::Relaxed) {
+                thread::sleep(tick);
+                // Horloge simulée : trames de sortie écoulées depuis `play`
+                let now = (calls * period) as isize;
+                slot.play_block(&mut output, outs, secs(now));
+                calls += 1;
+                let frames = period.min(silent_after.saturating_sub(pos));
+                let lost = drop_every.is_some_and(|every| calls % every == 0);
+                if frames > 0 && !lost {
+                    // Ce que le micro entend de `at` à `at + frames` : silence avant
+                    // la capture, plus rien après
+                    let at = now + skew;
+                    let samples = at * ins as isize..((at + frames as isize) * ins as isize).min(recording.len() as isize);
+                    let block: Vec<f32> = samples.map(|i| usize::try_from(i).map_or(0.0, |i| recording[i])).collect();
+                    if !block.is_empty() {
+                        slot.record_block(&block, ins, rate, secs(at));
+                    }
+                }
+                pos += frames;
+            }
+        }));
+    }
+}
+
+impl Drop for MockStreams {
+    /// Callbacks arrêtés avant de désarmer : aucun ne déborde sur la
+    /// capture suivante.
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(callbacks) = self.callbacks.take() {
+            let _ = callbacks.join();
+        }
+        self.slot.disarm();
+    }
+}
+
+/// Ouvre la carte simulée `mock` : mêmes erreurs que les périphériques réels
+/// selon ses pannes, puis une capture précalculée (pièce simulée) livrée
+/// par les callbacks de `MockStreams` à la `StreamSlot` partagée, armée.
+fn open_mock(mock: &MockAudio, signal: &[f32], wiring: Wiring, timing: CaptureTiming, retry_default_config: bool) -> Result<OpenStreams> {
+    let out_name = mockaudio::OUTPUT_NAME;
+    if mock.faults.contains(&Fault::Busy) {
+        return Err(classify_stream_error(&"Device or resource busy", out_name));
+    }
+    if mock.faults.contains(&Fault::Hang) {
+        // Au-delà du délai d'ouverture : l'appelant a déjà rendu `Timeout`
+        thread::sleep(Duration::from_secs_f32(2.0 * DEVICE_OPEN_TIMEOUT_SECS));
+        bail!((tr().err_open_failed)(out_name, "hang"));
+    }
+    let mut open_rate = mock.open_rate(timing.sample_rate);
+    if let Some(default_rate) = mock.rejected() {
+        let refused = BuildStreamError::StreamConfigNotSupported;
+        if !retry_default_config {
+            return Err(classify_stream_error(&refused, out_name));
+        }
+        thread::sleep(RETRY_PAUSE);
+        open_rate = default_rate;
+    }
+    check_output_channels(wiring, mock.output_channels)?;
+    let channels = mock.output_channels as usize;
+    let played_signal = resample::resample(signal, timing.sample_rate, open_rate)?;
+    let buffer = output_buffer(&played_signal, wiring.at_rate(timing.sample_rate, open_rate), channels)
+        .map_err(|e| classify_stream_error(&e, out_name))?;
+
+    let target_frames = timing.frames_at(open_rate);
+    let num_in_channels = mock.input_channels.max(1) as usize;
+    let period = ((MOCK_PERIOD.as_secs_f64() * open_rate as f64) as usize).max(1);
+    let recording = Arc::new(mock.record(&buffer, channels, open_rate, timing.frames_before(open_rate), target_frames));
+    let played = Arc::new(AtomicUsize::new(0));
+    let ring = Arc::new(SampleRing::new(RING_SECS * open_rate as usize * num_in_channels));
+    let slot = Arc::clone(MOCK_SLOT.get_or_init(Default::default));
+    slot.arm(OutputJob { buffer, played: Arc::clone(&played) }, InputJob::new(Arc::clone(&ring), target_frames * num_in_channels))?;
+    let streams = MockStreams {
+        slot,
+        recording,
+        num_out_channels: channels,
+        num_in_channels,
+        rate: open_rate,
+        period,
+        tick: MOCK_PERIOD.div_f32(mock.speed.max(0.1)),
+        skew: (mock.input_skew_ms / 1000.0 * open_rate as f32).round() as isize,
+        silent_after: mock.short_fraction().map_or(usize::MAX, |f| (f * target_frames as f32) as usize),
+        drop_every: mock.drop_every(),
+        stop: Arc::new(AtomicBool::new(false)),
+        callbacks: None,
+    };
+    Ok(OpenStreams {
+        streams: Streams::Mock(streams),
+        played,
+        ring,
+        out_rate: open_rate,
+        in_rate: open_rate,
+        num_in_channels,
+    })
+}
+
+/// Buffer de lecture multicanal (interleaved, zéros hors des emplacements
+/// joués) ; refusé si la sortie n'a pas les emplacements de `wiring`.
+fn output_buffer(signal: &[f32], wiring: Wiring, num_out_channels: usize) -> Result<Vec<f32>, BuildStreamError> {
+    let mut buf = match wiring.route {
+        Route::Slot(channel) if num_out_channels <= channel.output_index() => {
+            return Err(BuildStreamError::StreamConfigNotSupported);
+        }
+        Route::Slot(channel) => interleave_to_multichannel(signal, channel, num_out_channels),
+        Route::Probe(segment_len) => interleave_probe(signal, segment_len, num_out_channels),
+        Route::Pair(first, second, _) if num_out_channels <= first.output_index().max(second.output_index()) => {
+            return Err(BuildStreamError::StreamConfigNotSupported);
+        }
+        Route::Pair(first, second, segment_len) => interleave_pair(signal, (first, second), segment_len, num_out_channels),
+        Route::Dual(first, second) if num_out_channels <= first.output_index().max(second.output_index()) => {
+            return Err(BuildStreamError::StreamConfigNotSupported);
+        }
+        Route::Dual(first, second) => interleave_dual(signal, (first, second), num_out_channels),
+    };
+    // Copie du signal vers la boucle de référence
+    if let Some(out) = wiring.loopback_output() {
+        if num_out_channels <= out.output_index() {
+            return Err(BuildStreamError::StreamConfigNotSupported);
+        }
+        for (frame, &s) in buf.chunks_exact_mut(num_out_channels).zip(signal) {
+            frame[out.output_index()] = s;
+        }
+    }
+    Ok(buf)
+}
+
+// ─── Moteur persistant ──────────────────────────────────────────────────────
+//
+//  Ouvrir les flux coûte 100 à 500 ms selon le pilote, et ce délai varie
+//  d'une capture à l'autre (G puis D). Le moteur garde les flux ouverts
+//  et démarrés entre les captures : sortie en silence, entrée ignorée,
+//  tant qu'aucune capture n'est armée (`StreamSlot`). Les deux
+//  callbacks ne partent pas au même instant : la capture est calée
+//  sur l'horodatage de la première trame jouée. `cpal::Stream`
+//  ne change pas de thread : les flux vivent dans un thread dédié, qui
+//  les rouvre quand une capture demande plus de canaux, un autre taux,
+//  après une erreur de flux ou un changement de périphérique par défaut,
+//  et les ferme après `ENGINE_IDLE` sans capture.
+
+/// Flux du moteur fermés après ce délai sans capture (périphériques rendus
+/// au système).
+const ENGINE_IDLE: Duration = Duration::from_secs(120);
+
+/// Moteur actif par défaut ; `--release-devices` le désactive.
+static KEEP_STREAMS: AtomicBool = AtomicBool::new(true);
+
+/// File des demandes du thread moteur, lancé à la première capture.
+static ENGINE: Mutex<Option<mpsc::Sender<EngineRequest>>> = Mutex::new(None);
+
+/// Garde les flux ouverts entre les captures (`true`, défaut) ou les ouvre
+/// et les ferme à chaque capture, pour rendre les périphériques aux autres
+/// applications (`false` : le moteur en cours s'arrête).
+pub fn set_keep_streams(keep: bool) {
+    KEEP_STREAMS.store(keep, Ordering::Relaxed);
+    if !keep {
+        *lock(&ENGINE) = None;
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Ce qu'une capture demande aux flux.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DeviceNeed {
+    /// Taux de la session (les flux ont pu s'ouvrir à un autre).
+    rate: u32,
+    out_channels: u16,
+    in_channels: u16,
+    retry_default_config: bool,
+}
+
+/// Flux réellement ouverts.
+#[derive(Debug, Clone)]
+struct DeviceFormat {
+    out_rate: u32,
+    out_channels: u16,
+    in_rate: u32,
+    in_channels: u16,
+    out_name: String,
+    in_name: String,
+}
+
+/// Capture armée vue des callbacks, remise par deux `JobCell` : aucun
+/// verrou sur le chemin temps réel. Rien d'armé ou pas encore démarré, la
+/// sortie joue du silence et l'entrée est ignorée.
+///
+/// Après `start`, chaque callback reprend à sa propre limite de buffer :
+/// la sortie pose l'instant où sa première trame est jouée (`origin`),
+/// l'entrée écarte ce qui a été capté avant et complète de silence ce qui
+/// manque après. La trame 0 de la capture est celle du départ du signal,
+/// à une latence fixe près.
+#[derive(Default)]
+struct StreamSlot {
+    output: JobCell<OutputJob>,
+    input: JobCell<InputJob>,
+    /// Levé par `arm`, baissé par `disarm` : une seule capture à la fois.
+    armed: AtomicBool,
+    /// Levé par `start` : le signal part et l'entrée est enregistrée.
+    started: AtomicBool,
+    /// Levé par un callback d'erreur : flux à rouvrir.
+    failed: AtomicBool,
+    /// Instant de lecture de la première trame du signal (s, horloge des
+    /// flux, bits f64) ; NaN tant que la sortie ne l'a pas jouée.
+    origin: AtomicU64,
+    /// Zéro de l'horloge des flux : premier horodatage vu par un callback.
+    clock: OnceLock<StreamInstant>,
+}
+
+/// Buffer de lecture multicanal et trames remises au périphérique.
+struct OutputJob {
+    buffer: Vec<f32>,
+    played: Arc<AtomicUsize>,
+}
+
+/// File de capture, `limit` échantillons exactement.
+struct InputJob {
+    ring: Arc<SampleRing>,
+    limit: usize,
+    /// Premier bloc calé sur `StreamSlot::origin`.
+    aligned: AtomicBool,
+}
+
+impl InputJob {
+    fn new(ring: Arc<SampleRing>, limit: usize) -> Self {
+        InputJob { ring, limit, aligned: AtomicBool::new(false) }
+    }
+}
+
+/// Silence poussé dans la file pour caler le premier bloc d'entrée.
+const SILENCE: [f32; 256] = [0.0; 256];
+
+impl StreamSlot {
+    /// Arme une capture ; refusé si une autre l'est déjà (les flux du
+    /// moteur sont partagés).
+    fn arm(&self, output: OutputJob, input: InputJob) -> Result<()> {
+        if self.armed.compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire).is_err() {
+            bail!(tr().err_capture_armed);
+        }
+        self.started.store(false, Ordering::Release);
+        self.origin.store(f64::NAN.to_bits(), Ordering::Release);
+        self.input.put(input);
+        self.output.put(output);
+        Ok(())
+    }
+
+    fn start(&self) {
+        self.started.store(true, Ordering::Release);
+    }
+
+    /// Retire la capture armée, une fois les callbacks en cours sortis.
+    fn disarm(&self) {
+        self.started.store(false, Ordering::Release);
+        self.output.take();
+        self.input.take();
+        self.armed.store(false, Ordering::Release);
+    }
+
+    fn armed(&self) -> bool {
+        self.armed.load(Ordering::Acquire)
+    }
+
+    /// `at` en secondes depuis le zéro de l'horloge des flux.
+    fn seconds(&self, at: StreamInstant) -> f64 {
+        let zero = self.clock.get_or_init(|| at);
+        match at.duration_since(zero) {
+            Some(after) => after.as_secs_f64(),
+            None => zero.duration_since(&at).map_or(0.0, |before| -before.as_secs_f64()),
+        }
+    }
+
+    /// Callback de sortie : bloc de `channels` canaux dont la première trame
+    /// est jouée à `at` (s). Silence hors capture, sinon la suite du buffer
+    /// armé ; le premier bloc du signal pose `origin`.
+    fn play_block<T: Sample + FromSample<f32>>(&self, data: &mut [T], channels: usize, at: f64) {
+        let played = self.started.load(Ordering::Acquire)
+            && self
+                .output
+                .with(|job| {
+                    let first = job.played.fetch_add(data.len() / channels, Ordering::Relaxed);
+                    if first == 0 {
+                        self.origin.store(at.to_bits(), Ordering::Release);
+                    }
+                    for (i, frame) in data.chunks_mut(channels).enumerate() {
+                        let pos = (first + i) * channels;
+                        match job.buffer.get(pos..pos + channels) {
+                            Some(src) => frame.iter_mut().zip(src).for_each(|(d, &s)| *d = T::from_sample(s)),
+                            None => frame.fill(T::EQUILIBRIUM),
+                        }
+                    }
+                })
+                .is_some();
+        if !played {
+            data.fill(T::EQUILIBRIUM);
+        }
+    }
+
+    /// Callback d'entrée : bloc de `channels` canaux à `rate` Hz dont la
+    /// première trame a été captée à `at` (s). Le premier bloc retenu est
+    /// calé sur `origin` ; avant que la sortie n'ait joué, rien n'est gardé.
+    fn record_block<T: Sample>(&self, data: &[T], channels: usize, rate: u32, at: f64)
+    where
+        f32: FromSample<T>,
+    {
+        if !self.started.load(Ordering::Acquire) {
+            return;
+        }
+        self.input.with(|job| {
+            let mut data = data;
+            if !job.aligned.load(Ordering::Relaxed) {
+                let origin = f64::from_bits(self.origin.load(Ordering::Acquire));
+                if origin.is_nan() {
+                    return;
+                }
+                // Position du bloc dans la capture : négative, captée avant le départ
+                let start = ((at - origin) * rate as f64).round() as isize;
+                let skip = start.clamp(-((data.len() / channels) as isize), 0).unsigned_abs() * channels;
+                data = &data[skip..];
+                if data.is_empty() {
+                    return;
+                }
+                let mut pad = (start.max(0) as usize * channels).min(job.limit.saturating_sub(job.ring.pushed()));
+                while pad > 0 {
+                    let n = pad.min(SILENCE.len());
+                    job.ring.push::<f32>(&SILENCE[..n]);
+                    pad -= n;
+                }
+                job.aligned.store(true, Ordering::Relaxed);
+            }
+            let room = job.limit.saturating_sub(job.ring.pushed());
+            job.ring.push(&data[..data.len().min(room)]);
+        });
+    }
+}
+
+// ─── Remise sans verrou ─────────────────────────────────────────────────────
+
+/// Case d'un seul travail, remis par pointeur atomique : le callback lit
+/// sans verrou ni allocation (`with`), le thread de capture pose (`put`)
+/// et retire (`take`) le travail. `take` attend la sortie du callback en
+/// cours avant de libérer (un bloc au plus), jamais l'inverse.
+struct JobCell<T> {
+    job: AtomicPtr<T>,
+    /// Callbacks en train de lire `job`.
+    readers: AtomicUsize,
+    _owns: PhantomData<Box<T>>,
+}
+
+impl<T> Default for JobCell<T> {
+    fn default() -> Self {
+        JobCell { job: AtomicPtr::new(ptr::null_mut()), readers: AtomicUsize::new(0), _owns: PhantomData }
+    }
+}
+
+/// Lecteur compté jusqu'à sa sortie, même en cas de panique.
+struct Reading<'a>(&'a AtomicUsize);
+
+impl Drop for Reading<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl<T> JobCell<T> {
+    /// Pose `job` dans la case vide (sinon il est rendu aussitôt).
+    fn put(&self, job: T) {
+        let job = Box::into_raw(Box::new(job));
+        if self.job.compare_exchange(ptr::null_mut(), job, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+            // Sûreté : `job` vient de `Box::into_raw` et n'a pas été publié
+            drop(unsafe { Box::from_raw(job) });
+        }
+    }
+
+    /// Callback : `f` sur le travail posé, s'il y en a un.
+    fn with<R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        self.readers.fetch_add(1, Ordering::SeqCst);
+        let _reading = Reading(&self.readers);
+        let job = self.job.load(Ordering::SeqCst);
+        // Sûreté : un travail publié n'est libéré par `take` qu'une fois
+        // `readers` retombé à zéro, après l'avoir retiré de la case
+        (!job.is_null()).then(|| f(unsafe { &*job }))
+    }
+
+    /// Retire et libère le travail posé, après les lecteurs en cours.
+    fn take(&self) {
+        let job = self.job.swap(ptr::null_mut(), Ordering::SeqCst);
+        if job.is_null() {
+            return;
+        }
+        while self.readers.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+        // Sûreté : retiré de la case, plus aucun lecteur ne peut le voir
+        drop(unsafe { Box::from_raw(job) });
+    }
+}
+
+impl<T> Drop for JobCell<T> {
+    fn drop(&mut self) {
+        self.take();
+    }
+}
+
+/// Flux cpal ouverts et démarrés sur une `StreamSlot`.
+struct DeviceStreams {
+    _output: cpal::Stream,
+    _input: cpal::Stream,
+    slot: Arc<StreamSlot>,
+    need: DeviceNeed,
+    format: DeviceFormat,
+}
+
+impl DeviceStreams {
+    /// Flux réutilisables pour `need` : sains, au même taux demandé, assez
+    /// de canaux, toujours sur les périphériques par défaut.
+    fn covers(&self, need: DeviceNeed) -> bool {
+        let (out_name, in_name) = default_device_names();
+        !self.slot.failed.load(Ordering::Relaxed)
+            && self.need.rate == need.rate
+            && self.format.out_channels >= need.out_channels
+            && self.format.in_channels >= need.in_channels
+            && out_name == self.format.out_name
+            && in_name == self.format.in_name
+    }
+}
+
+/// Flux tenus par une capture : les siens (`owned`) ou ceux du moteur.
+/// Rendus (drop), ils se taisent aussitôt ; ceux du moteur restent ouverts.
+struct DeviceLease {
+    slot: Arc<StreamSlot>,
+    _owned: Option<DeviceStreams>,
+    /// Capture armée par ce bail : seul lui la désarme.
+    armed: bool,
+}
+
+impl DeviceLease {
+    fn arm(&mut self, output: OutputJob, input: InputJob) -> Result<()> {
+        self.slot.arm(output, input)?;
+        self.armed = true;
+        Ok(())
+    }
+}
+
+impl Drop for DeviceLease {
+    fn drop(&mut self) {
+        if self.armed {
+            self.slot.disarm();
+        }
+    }
+}
+
+type EngineReply = Result<(Arc<StreamSlot>, DeviceFormat)>;
+
+struct EngineRequest {
+    need: DeviceNeed,
+    reply: mpsc::Sender<EngineReply>,
+}
+
+/// Flux pour `need` : ceux du moteur, ou des flux propres à la capture si
+/// le moteur est désactivé. Un moteur qui ne répond pas dans le délai
+/// d'ouverture (pilote bloqué) est abandonné : la capture suivante en
+/// lance un autre.
+fn acquire_streams(need: DeviceNeed) -> Result<(DeviceLease, DeviceFormat)> {
+    if !KEEP_STREAMS.load(Ordering::Relaxed) {
+        let streams = open_device_streams(need)?;
+        let format = streams.format.clone();
+        return Ok((DeviceLease { slot: Arc::clone(&streams.slot), _owned: Some(streams), armed: false }, format));
+    }
+    let (reply_tx, reply_rx) = mpsc::channel();
+    let engine = lock(&ENGINE).get_or_insert_with(spawn_engine).clone();
+    let _ = engine.send(EngineRequest { need, reply: reply_tx });
+    match reply_rx.recv_timeout(Duration::from_secs_f32(DEVICE_OPEN_TIMEOUT_SECS)) {
+        Ok(reply) => reply.map(|(slot, format)| (DeviceLease { slot, _owned: None, armed: false }, format)),
+        Err(_) => {
+            *lock(&ENGINE) = None;
+            Err(AudioError::Timeout { secs: DEVICE_OPEN_TIMEOUT_SECS }.into())
+        }
+    }
+}
+
+fn spawn_engine() -> mpsc::Sender<EngineRequest> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || run_engine(rx));
+    tx
+}
+
+/// Thread moteur : sert les demandes jusqu'à l'abandon de sa file.
+fn run_engine(requests: mpsc::Receiver<EngineRequest>) {
+    let mut streams: Option<DeviceStreams> = None;
+    loop {
+        let request = match &streams {
+            Some(open) => match requests.recv_timeout(ENGINE_IDLE) {
+                Ok(request) => request,
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    if !open.slot.armed() {
+                        streams = None;
                     }
+                    continue;
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => return,
+            },
+            None => match requests.recv() {
+                Ok(request) => request,
+                Err(_) => return,
+            },
+        };
+        if !streams.as_ref().is_some_and(|open| open.covers(request.need)) {
+            // Anciens flux fermés d'abord : le périphérique est libre pour la réouverture
+            streams = None;
+            match open_device_streams(request.need) {
+                Ok(open) => streams = Some(open),
+                Err(e) => {
+                    let _ = request.reply.send(Err(e));
+                    continue;
                 }
             }
+        }
+        if let Some(open) = &streams {
+            let _ = request.reply.send(Ok((Arc::clone(&open.slot), open.format.clone())));
+        }
+    }
+}
+
+/// Ouvre et démarre les flux des périphériques par défaut, silencieux tant
+/// que rien n'est armé : config au taux demandé si possible, config par
+/// défaut en seconde tentative.
+fn open_device_streams(need: DeviceNeed) -> Result<DeviceStreams> {
+    let host = cpal::default_host();
+    let s = tr();
+    let rate = SampleRate(need.rate);
+    let slot = Arc::new(StreamSlot::default());
+
+    // ── Sortie ──────────────────────────────────────────────────────────────
+    let output_device = host.default_output_device().context(s.err_no_output)?;
+    let out_name = output_device.name().unwrap_or_else(|_| s.device_unknown.into());
+    let (out_config, out_format) = find_stereo_config(&output_device, rate, need.out_channels).context(s.err_output_format)?;
+    let (output, out_config) = match open_output(&output_device, &out_config, out_format, &slot) {
+        Ok(stream) => (stream, out_config),
+        Err(e) if need.retry_default_config => {
+            thread::sleep(RETRY_PAUSE);
+            let (fallback, format) = usable_default(output_device.default_output_config()?, &out_name)?;
+            let stream = open_output(&output_device, &fallback, format, &slot).map_err(|_| classify_stream_error(&e, &out_name))?;
+            (stream, fallback)
+        }
+        Err(e) => return Err(classify_stream_error(&e, &out_name)),
+    };
+
+    // ── Entrée ──────────────────────────────────────────────────────────────
+    let input_device = host.default_input_device().context(s.err_no_input)?;
+    let in_name = input_device.name().unwrap_or_else(|_| s.device_unknown.into());
+    let (in_config, in_format) = find_mono_input_config(&input_device, rate, need.in_channels).context(s.err_input_format)?;
+    let (input, in_config) = match open_input(&input_device, &in_config, in_format, &slot) {
+        Ok(stream) => (stream, in_config),
+        Err(e) if need.retry_default_config => {
+            thread::sleep(RETRY_PAUSE);
+            let (fallback, format) = usable_default(input_device.default_input_config()?, &in_name)?;
+            let stream = open_input(&input_device, &fallback, format, &slot).map_err(|_| classify_stream_error(&e, &in_name))?;
+            (stream, fallback)
+        }
+        Err(e) => return Err(classify_stream_error(&e, &in_name)),
+    };
+
+    output.play().map_err(|e| classify_stream_error(&e, &out_name))?;
+    input.play().map_err(|e| classify_stream_error(&e, &in_name))?;
+    let format = DeviceFormat {
+        out_rate: out_config.sample_rate.0,
+        out_channels: out_config.channels,
+        in_rate: in_config.sample_rate.0,
+        in_channels: in_config.channels,
+        out_name,
+        in_name,
+    };
+    Ok(DeviceStreams { _output: output, _input: input, slot, need, format })
+}
+
+/// Flux de sortie de `config` : joue la capture armée de `slot`.
+fn open_output(
+    device: &cpal::Device,
+    config: &StreamConfig,
+    format: SampleFormat,
+    slot: &Arc<StreamSlot>,
+) -> Result<cpal::Stream, BuildStreamError> {
+    let slot = Arc::clone(slot);
+    match format {
+        SampleFormat::F32 => build_output::<f32>(device, config, slot),
+        SampleFormat::I32 => build_output::<i32>(device, config, slot),
+        SampleFormat::I16 => build_output::<i16>(device, config, slot),
+        SampleFormat::U16 => build_output::<u16>(device, config, slot),
+        _ => Err(BuildStreamError::StreamConfigNotSupported),
+    }
+}
+
+/// Callback de sortie au format `T` (`StreamSlot::play_block`) : le buffer
+/// f32 est converti à la volée, le silence (hors capture, après le signal)
+/// est le point milieu du format (32768 en U16). `played` compte les trames
+/// remises depuis `start`.
+fn build_output<T: SizedSample + FromSample<f32>>(
+    device: &cpal::Device,
+    config: &StreamConfig,
+    slot: Arc<StreamSlot>,
+) -> Result<cpal::Stream, BuildStreamError> {
+    let num_out_channels = config.channels as usize;
+    let errors = Arc::clone(&slot);
+    device.build_output_stream(
+        config,
+        move |data: &mut [T], info: &cpal::OutputCallbackInfo| {
+            slot.play_block(data, num_out_channels, slot.seconds(info.timestamp().playback));
+        },
+        move |e| {
+            errors.failed.store(true, Ordering::Relaxed);
+            eprintln!("{}", (tr().err_output_stream)(&e.to_string()));
         },
-        |e| eprintln!("Erreur sortie audio : {}", e),
         None,
-    )?;
+    )
+}
 
-    // ── Entrée ──────────────────────────────────────────────────────────────
-    let input_device = host
-        .default_input_device()
-        .context("Aucun microphone disponible. Branchez un micro et réessayez.")?;
-
-    let in_config = find_mono_input_config(&input_device, SampleRate(SAMPLE_RATE))
-        .context("Format d'entrée mono 48 kHz introuvable")?;
-
-    let captured: Arc<Mutex<Vec<f32>>> = Arc::new(Mutex::new(Vec::new()));
-    let cap_clone = Arc::clone(&captured);
-
-    let in_stream = input_device.build_input_stream(
-        &in_config,
-        move |data: &[f32], _| {
-            let mut buf = cap_clone.lock().unwrap();
-            // Mix multicanal → mono
-            let channels = in_config.channels as usize;
-            for frame in data.chunks(channels) {
-                let mono = frame.iter().sum::<f32>() / channels as f32;
-                buf.push(mono);
-            }
+/// Flux d'entrée de `config` : pousse les échantillons interleaved dans la
+/// file de la capture armée de `slot`.
+fn open_input(
+    device: &cpal::Device,
+    config: &StreamConfig,
+    format: SampleFormat,
+    slot: &Arc<StreamSlot>,
+) -> Result<cpal::Stream, BuildStreamError> {
+    let slot = Arc::clone(slot);
+    match format {
+        SampleFormat::F32 => build_input::<f32>(device, config, slot),
+        SampleFormat::I32 => build_input::<i32>(device, config, slot),
+        SampleFormat::I16 => build_input::<i16>(device, config, slot),
+        SampleFormat::U16 => build_input::<u16>(device, config, slot),
+        _ => Err(BuildStreamError::StreamConfigNotSupported),
+    }
+}
+
+/// Callback d'entrée au format `T` (`StreamSlot::record_block`), converti
+/// en f32 à l'entrée de la file.
+fn build_input<T: SizedSample>(
+    device: &cpal::Device,
+    config: &StreamConfig,
+    slot: Arc<StreamSlot>,
+) -> Result<cpal::Stream, BuildStreamError>
+where
+    f32: FromSample<T>,
+{
+    let (num_in_channels, in_rate) = ((config.channels as usize).max(1), config.sample_rate.0);
+    let errors = Arc::clone(&slot);
+    device.build_input_stream(
+        config,
+        move |data: &[T], info: &cpal::InputCallbackInfo| {
+            slot.record_block(data, num_in_channels, in_rate, slot.seconds(info.timestamp().capture));
+        },
+        move |e| {
+            errors.failed.store(true, Ordering::Relaxed);
+            eprintln!("{}", (tr().err_input_stream)(&e.to_string()));
         },
-        |e| eprintln!("Erreur entrée audio : {}", e),
         None,
-    )?;
+    )
+}
 
-    // ── Synchronisation ─────────────────────────────────────────────────────
-    // Pause avant démarrage pour laisser le bruit de frappe se dissiper
-    if pre_delay_secs > 0.0 {
-        std::thread::sleep(Duration::from_secs_f32(pre_delay_secs));
+// ─── File circulaire sans verrou ────────────────────────────────────────────
+
+/// File un producteur (callback d'entrée) / un consommateur (thread audio),
+/// sans verrou : le callback temps réel ne bloque jamais. Les échantillons
+/// sont rangés en bits dans des `AtomicU32`, les positions sont des totaux
+/// croissants (`head` écrit par le producteur, `tail` par le consommateur).
+struct SampleRing {
+    slots: Vec<AtomicU32>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+    /// Échantillons perdus, file pleine.
+    overruns: AtomicUsize,
+}
+
+impl SampleRing {
+    fn new(capacity: usize) -> Self {
+        SampleRing {
+            slots: (0..capacity.max(1)).map(|_| AtomicU32::new(0)).collect(),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            overruns: AtomicUsize::new(0),
+        }
     }
 
-    out_stream.play()?;
-    in_stream.play()?;
+    /// Producteur : range ce qui tient (converti en f32), compte le reste
+    /// comme perdu.
+    fn push<T: Sample>(&self, data: &[T])
+    where
+        f32: FromSample<T>,
+    {
+        let head = self.head.load(Ordering::Relaxed);
+        let free = self.slots.len() - (head - self.tail.load(Ordering::Acquire));
+        let n = data.len().min(free);
+        for (i, &s) in data[..n].iter().enumerate() {
+            self.slots[(head + i) % self.slots.len()].store(f32::from_sample(s).to_bits(), Ordering::Relaxed);
+        }
+        self.head.store(head + n, Ordering::Release);
+        if n < data.len() {
+            self.overruns.fetch_add(data.len() - n, Ordering::Relaxed);
+        }
+    }
 
-    let total_ms = (capture_secs * 1000.0) as u64;
-    let step_ms = 50u64;
-    let mut elapsed = 0u64;
+    /// Consommateur : vide la file à la suite de `out`.
+    fn drain_into(&self, out: &mut Vec<f32>) {
+        let tail = self.tail.load(Ordering::Relaxed);
+        let head = self.head.load(Ordering::Acquire);
+        out.extend((tail..head).map(|i| f32::from_bits(self.slots[i % self.slots.len()].load(Ordering::Relaxed))));
+        self.tail.store(head, Ordering::Release);
+    }
 
-    while elapsed < total_ms {
-        std::thread::sleep(Duration::from_millis(step_ms));
-        elapsed += step_ms;
-        let _ = progress_tx.send(elapsed as f32 / total_ms as f32);
+    /// Échantillons rangés depuis le début.
+    fn pushed(&self) -> usize {
+        self.head.load(Ordering::Acquire)
     }
 
-    drop(out_stream);
-    drop(in_stream);
+    fn overruns(&self) -> usize {
+        self.overruns.load(Ordering::Relaxed)
+    }
+}
 
-    let samples = Arc::try_unwrap(captured)
-        .unwrap()
-        .into_inner()
-        .unwrap();
+/// Config par défaut du périphérique, si son format est géré
+/// (`SAMPLE_FORMATS`) ; tout taux convient, la conversion suit.
+fn usable_default(conf: cpal::SupportedStreamConfig, device: &str) -> Result<(StreamConfig, SampleFormat)> {
+    if format_rank(conf.sample_format()).is_none() {
+        bail!((tr().err_default_config)(device, &format!("{:?}", conf.sample_format()), conf.sample_rate().0));
+    }
+    Ok((conf.config(), conf.sample_format()))
+}
+
+/// Traduit une erreur cpal : « occupé » devient `AudioError::DeviceBusy`.
+fn classify_stream_error(e: &dyn std::fmt::Display, device: &str) -> anyhow::Error {
+    let detail = e.to_string();
+    let lower = detail.to_lowercase();
+    // ALSA : EBUSY ; WASAPI : AUDCLNT_E_DEVICE_IN_USE (0x8889000A) ; cpal : DeviceNotAvailable
+    let busy = ["busy", "in use", "8889000a", "not available", "no longer available"]
+        .iter()
+        .any(|k| lower.contains(k));
+    if busy {
+        AudioError::DeviceBusy { device: device.to_string(), detail }.into()
+    } else {
+        anyhow::anyhow!((tr().err_open_failed)(device, &detail))
+    }
+}
+
+/// Refuse une capture tronquée : moins de `MIN_CAPTURE_RATIO` des `expected`
+/// échantillons, ou trop courte pour contenir le signal de test ou une trame FFT
+/// (sans quoi l'analyse produirait une courbe plate à −100 dB sans explication).
+pub fn check_capture_length(received: usize, expected: usize, signal_len: usize) -> Result<()> {
+    let required = ((expected as f32 * MIN_CAPTURE_RATIO) as usize).max(signal_len).max(FFT_SIZE);
+    if received < required {
+        bail!((tr().err_truncated)(received, expected, 100.0 * received as f32 / expected.max(1) as f32, required));
+    }
+    Ok(())
+}
 
-    if samples.is_empty() {
-        bail!("Aucun échantillon capturé. Vérifiez que le microphone est actif.");
+/// Refuse une capture inexploitable à cause du gain micro : entrée saturée
+/// (harmoniques parasites dans tout le spectre) ou signal à peine au-dessus
+/// du bruit de l'entrée. La piste de boucle n'est pas vérifiée ; une capture
+/// de silence (`expect_signal` faux, bruit de fond) n'a pas de niveau minimal.
+pub fn check_capture_level(capture: &Capture, expect_signal: bool, sample_rate: u32) -> Result<()> {
+    let clipped = capture
+        .inputs
+        .iter()
+        .chain(std::iter::once(&capture.mono))
+        .map(|track| track.iter().filter(|s| s.abs() >= CLIP_LEVEL).count())
+        .max()
+        .unwrap_or(0);
+    if clipped > MAX_CLIPPED_SAMPLES {
+        return Err(AudioError::Clipped { samples: clipped }.into());
     }
 
-    Ok(samples)
+    // Tranche la plus forte : le silence de fin de capture ne compte pas
+    let window = ((LEVEL_WINDOW_SECS * sample_rate as f32) as usize).max(1);
+    let rms = capture.mono.chunks(window).map(dsp::compute_rms).fold(0.0f32, f32::max);
+    let rms_dbfs = 20.0 * rms.max(1e-10).log10();
+    if expect_signal && rms_dbfs < MIN_RMS_DBFS {
+        return Err(AudioError::TooQuiet { rms_dbfs }.into());
+    }
+    Ok(())
 }
 
 // ─── Utilitaires internes ─────────────────────────────────────────────────────
 
 /// Convertit un signal mono en buffer multicanal interleaved.
-/// Le signal est placé sur ch0 (Left) ou ch1 (Right) ; tous les autres canaux
-/// (centre, LFE, surround…) restent à zéro.
+/// Le signal est placé sur le seul canal `channel` ; tous les autres restent à zéro.
 fn interleave_to_multichannel(mono: &[f32], channel: Channel, num_channels: usize) -> Vec<f32> {
-    let ch_idx = match channel {
-        Channel::Left  => 0,
-        Channel::Right => 1.min(num_channels - 1),
-    };
+    let ch_idx = channel.output_index().min(num_channels - 1);
     let mut out = vec![0.0f32; mono.len() * num_channels];
     for (i, &s) in mono.iter().enumerate() {
         out[i * num_channels + ch_idx] = s;
@@ -145,90 +1519,203 @@ fn interleave_to_multichannel(mono: &[f32], channel: Channel, num_channels: usiz
     out
 }
 
-/// Cherche une config de sortie à 48 kHz — préfère la stéréo, accepte 5.1/7.1.
-/// Le signal sera toujours routé sur FL (ch0) et FR (ch1), les canaux
-/// supplémentaires étant mis à zéro, ce qui fonctionne sur tout layout surround.
+/// Buffer de sonde : l'échantillon i va sur l'emplacement i / `segment_len`,
+/// abandonné au-delà de `num_channels`.
+fn interleave_probe(mono: &[f32], segment_len: usize, num_channels: usize) -> Vec<f32> {
+    let mut out = vec![0.0f32; mono.len() * num_channels];
+    for (i, &s) in mono.iter().enumerate() {
+        let slot = i / segment_len.max(1);
+        if slot < num_channels {
+            out[i * num_channels + slot] = s;
+        }
+    }
+    out
+}
+
+/// Buffer de mesure enchaînée : les `segment_len` premiers échantillons sur
+/// `channels.0`, les suivants sur `channels.1`.
+fn interleave_pair(mono: &[f32], channels: (Channel, Channel), segment_len: usize, num_channels: usize) -> Vec<f32> {
+    let mut out = vec![0.0f32; mono.len() * num_channels];
+    for (i, &s) in mono.iter().enumerate() {
+        let slot = if i < segment_len { channels.0 } else { channels.1 };
+        out[i * num_channels + slot.output_index()] = s;
+    }
+    out
+}
+
+/// Buffer de mesure simultanée : première moitié de `mono` sur `channels.0`,
+/// seconde moitié sur `channels.1`, aux mêmes instants.
+fn interleave_dual(mono: &[f32], channels: (Channel, Channel), num_channels: usize) -> Vec<f32> {
+    let (first, second) = mono.split_at(mono.len() / 2);
+    let mut out = vec![0.0f32; first.len() * num_channels];
+    for (i, (&a, &b)) in first.iter().zip(second).enumerate() {
+        out[i * num_channels + channels.0.output_index()] = a;
+        out[i * num_channels + channels.1.output_index()] = b;
+    }
+    out
+}
+
+/// Sépare un buffer interleaved en pistes individuelles et calcule le mix mono.
+/// La piste `loopback` est mise à part et n'entre pas dans le mix.
+fn deinterleave(interleaved: &[f32], num_channels: usize, loopback: Option<usize>) -> Capture {
+    let frames = interleaved.len() / num_channels;
+    let mut inputs = vec![Vec::with_capacity(frames); num_channels];
+    for frame in interleaved.chunks_exact(num_channels) {
+        for (track, &s) in inputs.iter_mut().zip(frame) {
+            track.push(s);
+        }
+    }
+    let loopback = loopback.filter(|&i| i < num_channels).map(|i| inputs.remove(i));
+    let mono = (0..frames)
+        .map(|i| inputs.iter().map(|t| t[i]).sum::<f32>() / inputs.len().max(1) as f32)
+        .collect();
+    Capture { mono, inputs, loopback }
+}
+
+/// Cherche une config de sortie au taux `desired_rate`, à défaut à un autre
+/// taux de `PREFERRED_RATES` — préfère la stéréo, accepte 5.1/7.1.
+/// Le signal est routé sur un seul canal, les canaux supplémentaires étant mis
+/// à zéro, ce qui fonctionne sur tout layout surround. `min_channels` > 2
+/// impose un layout multicanal (caisson sur la sortie LFE). À layout égal, le
+/// format le mieux classé dans `SAMPLE_FORMATS` l'emporte.
 fn find_stereo_config(
     device: &cpal::Device,
     desired_rate: SampleRate,
-) -> Result<StreamConfig> {
-    // 1er choix : stéréo exacte F32 à 48 kHz
-    for supported in device.supported_output_configs()? {
-        if supported.channels() == 2
-            && supported.sample_format() == SampleFormat::F32
-            && supported.min_sample_rate() <= desired_rate
-            && supported.max_sample_rate() >= desired_rate
-        {
-            return Ok(StreamConfig {
-                channels: 2,
-                sample_rate: desired_rate,
-                buffer_size: cpal::BufferSize::Default,
-            });
+    min_channels: u16,
+) -> Result<(StreamConfig, SampleFormat)> {
+    let configs: Vec<SupportedStreamConfigRange> = device.supported_output_configs()?.collect();
+    for rate in rate_preference(desired_rate) {
+        let usable: Vec<&SupportedStreamConfigRange> = configs.iter().filter(|s| supports(s, rate)).collect();
+
+        // 1er choix : stéréo exacte à ce taux
+        if min_channels <= 2 {
+            if let Some(supported) = best_format(usable.iter().copied().filter(|s| s.channels() == 2)) {
+                let config = StreamConfig {
+                    channels: 2,
+                    sample_rate: rate,
+                    buffer_size: cpal::BufferSize::Default,
+                };
+                return Ok((config, supported.sample_format()));
+            }
         }
-    }
 
-    // 2e choix : n'importe quel layout (5.1, 7.1…) F32 à 48 kHz
-    // → on conserve le nombre de canaux natif pour éviter l'erreur WASAPI
-    for supported in device.supported_output_configs()? {
-        if supported.channels() >= 2
-            && supported.sample_format() == SampleFormat::F32
-            && supported.min_sample_rate() <= desired_rate
-            && supported.max_sample_rate() >= desired_rate
-        {
-            return Ok(StreamConfig {
+        // 2e choix : n'importe quel layout (5.1, 7.1…) à ce taux
+        // → on conserve le nombre de canaux natif pour éviter l'erreur WASAPI
+        if let Some(supported) = best_format(usable.iter().copied().filter(|s| s.channels() >= min_channels)) {
+            let config = StreamConfig {
                 channels: supported.channels(),
-                sample_rate: desired_rate,
+                sample_rate: rate,
                 buffer_size: cpal::BufferSize::Default,
-            });
+            };
+            return Ok((config, supported.sample_format()));
         }
     }
 
     // Fallback absolu : config par défaut du périphérique
     let conf = device.default_output_config()?;
-    Ok(StreamConfig {
+    let config = StreamConfig {
         channels: conf.channels(),
         sample_rate: conf.sample_rate(),
         buffer_size: cpal::BufferSize::Default,
-    })
+    };
+    Ok((config, conf.sample_format()))
 }
 
-/// Cherche une config mono (ou stéréo en fallback) à 48 kHz sur le micro.
+/// Cherche une config mono (ou stéréo en fallback) sur le micro, au taux
+/// `desired_rate` ou à défaut à un autre taux de `PREFERRED_RATES`.
+/// `min_channels` > 1 réserve des entrées supplémentaires (boucle de référence).
 fn find_mono_input_config(
     device: &cpal::Device,
     desired_rate: SampleRate,
-) -> Result<StreamConfig> {
-    for supported in device.supported_input_configs()? {
-        if supported.sample_format() == SampleFormat::F32
-            && supported.min_sample_rate() <= desired_rate
-            && supported.max_sample_rate() >= desired_rate
-        {
-            let channels = supported.channels().min(2);
-            return Ok(StreamConfig {
-                channels,
-                sample_rate: desired_rate,
+    min_channels: u16,
+) -> Result<(StreamConfig, SampleFormat)> {
+    let configs: Vec<SupportedStreamConfigRange> =
+        device.supported_input_configs()?.filter(|s| s.channels() >= min_channels).collect();
+    for rate in rate_preference(desired_rate) {
+        if let Some(supported) = best_format(configs.iter().filter(|s| supports(s, rate))) {
+            let config = StreamConfig {
+                channels: supported.channels().min(min_channels.max(2)),
+                sample_rate: rate,
                 buffer_size: cpal::BufferSize::Default,
-            });
+            };
+            return Ok((config, supported.sample_format()));
         }
     }
 
     let conf = device.default_input_config()?;
-    Ok(StreamConfig {
+    let config = StreamConfig {
         channels: conf.channels(),
         sample_rate: conf.sample_rate(),
         buffer_size: cpal::BufferSize::Default,
-    })
+    };
+    Ok((config, conf.sample_format()))
+}
+
+/// `desired` d'abord, puis les autres taux de `PREFERRED_RATES`.
+fn rate_preference(desired: SampleRate) -> impl Iterator<Item = SampleRate> {
+    std::iter::once(desired).chain(PREFERRED_RATES.into_iter().map(SampleRate).filter(move |&r| r != desired))
+}
+
+/// Taux de session proposé : le premier de `PREFERRED_RATES` accepté par la
+/// sortie et l'entrée par défaut (aucune conversion), sinon le premier
+/// accepté par la sortie (seule la capture est convertie) ; `SAMPLE_RATE`
+/// si aucun périphérique ne répond.
+pub fn negotiate_sample_rate() -> u32 {
+    // Carte simulée : le taux de référence, ses pannes de taux restent visibles
+    if mockaudio::installed().is_some() {
+        return SAMPLE_RATE;
+    }
+    let host = cpal::default_host();
+    let output: Vec<SupportedStreamConfigRange> = host
+        .default_output_device()
+        .and_then(|d| d.supported_output_configs().ok())
+        .map(|c| c.collect())
+        .unwrap_or_default();
+    let input: Vec<SupportedStreamConfigRange> = host
+        .default_input_device()
+        .and_then(|d| d.supported_input_configs().ok())
+        .map(|c| c.collect())
+        .unwrap_or_default();
+    let accepts = |configs: &[SupportedStreamConfigRange], rate: u32| configs.iter().any(|s| supports(s, SampleRate(rate)));
+    PREFERRED_RATES
+        .into_iter()
+        .find(|&r| accepts(&output, r) && accepts(&input, r))
+        .or_else(|| PREFERRED_RATES.into_iter().find(|&r| accepts(&output, r)))
+        .unwrap_or(SAMPLE_RATE)
+}
+
+/// Rang de `format` dans `SAMPLE_FORMATS`, `None` s'il n'est pas géré.
+fn format_rank(format: SampleFormat) -> Option<usize> {
+    SAMPLE_FORMATS.iter().position(|&f| f == format)
+}
+
+/// Plage de configs couvrant `rate` dans un format géré.
+fn supports(supported: &SupportedStreamConfigRange, rate: SampleRate) -> bool {
+    format_rank(supported.sample_format()).is_some()
+        && supported.min_sample_rate() <= rate
+        && supported.max_sample_rate() >= rate
+}
+
+/// Config au format le mieux classé ; la première en cas d'égalité.
+fn best_format<'a>(
+    configs: impl Iterator<Item = &'a SupportedStreamConfigRange>,
+) -> Option<&'a SupportedStreamConfigRange> {
+    configs.min_by_key(|s| format_rank(s.sample_format()))
 }
 
 /// Retourne le nom du périphérique d'entrée et de sortie par défaut.
 pub fn default_device_names() -> (String, String) {
+    if mockaudio::installed().is_some() {
+        return (mockaudio::OUTPUT_NAME.to_string(), mockaudio::INPUT_NAME.to_string());
+    }
     let host = cpal::default_host();
     let out = host
         .default_output_device()
-        .map(|d| d.name().unwrap_or_else(|_| "Inconnu".into()))
-        .unwrap_or_else(|| "Aucun".into());
+        .map(|d| d.name().unwrap_or_else(|_| tr().device_unknown.into()))
+        .unwrap_or_else(|| tr().device_none.into());
     let inp = host
         .default_input_device()
-        .map(|d| d.name().unwrap_or_else(|_| "Inconnu".into()))
-        .unwrap_or_else(|| "Aucun".into());
+        .map(|d| d.name().unwrap_or_else(|_| tr().device_unknown.into()))
+        .unwrap_or_else(|| tr().device_none.into());
     (out, inp)
 }
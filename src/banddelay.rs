@@ -0,0 +1,128 @@
+// ============================================================
+//  banddelay.rs — Retard G/D par octave
+//
+//  Le délai global vient du front du son direct : il suppose que
+//  tout le spectre arrive en même temps. Filtrer les deux IR par
+//  octave puis les corréler donne un retard par bande :
+//    - constant : écart de distance pur, déplacer l'enceinte ;
+//    - variable : filtre / phase différents (coupure du caisson,
+//      réglage de tweeter, polarité d'un haut-parleur), qu'aucun
+//      déplacement ne corrige.
+//
+//  En bande étroite la corrélation se répète à chaque période :
+//  chaque octave cherche son pic à moins d'une demi-période du
+//  retard large bande, ce qui lève l'ambiguïté.
+// ============================================================
+
+use serde::Serialize;
+
+use crate::dsp::{self, OCTAVE_CENTERS};
+
+/// Écart entre octaves au-delà duquel le retard dépend de la fréquence (ms).
+pub const DISPERSION_LIMIT_MS: f32 = 0.15;
+
+/// Retard maximal recherché entre les deux enceintes (ms).
+const MAX_LAG_MS: f32 = 5.0;
+/// Marge gardée avant le son direct le plus précoce (ms).
+const PRE_MS: f32 = 2.0;
+/// Durée minimale de la fenêtre d'analyse (ms) ; les octaves graves
+/// gardent au moins trois périodes.
+const WINDOW_MS: f32 = 10.0;
+/// Mise en régime des filtres avant la fenêtre (ms).
+const SETTLE_MS: f32 = 20.0;
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct BandDelay {
+    pub freq_hz: f32,
+    /// Retard de la droite sur la gauche dans cette octave (ms).
+    pub delay_ms: Option<f32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BandDelayReport {
+    /// Retard large bande de la même corrélation (ms), référence des octaves.
+    pub broadband_ms: f32,
+    pub bands: Vec<BandDelay>,
+}
+
+impl BandDelayReport {
+    /// Écart entre le plus grand et le plus petit retard par octave (ms).
+    pub fn spread_ms(&self) -> f32 {
+        let delays = self.bands.iter().filter_map(|b| b.delay_ms);
+        let (lo, hi) = delays.fold((f32::MAX, f32::MIN), |(lo, hi), d| (lo.min(d), hi.max(d)));
+        if lo > hi { 0.0 } else { hi - lo }
+    }
+
+    /// Vrai si le retard varie avec la fréquence : problème de filtre ou
+    /// de phase plutôt que de distance.
+    pub fn frequency_dependent(&self) -> bool {
+        self.spread_ms() > DISPERSION_LIMIT_MS
+    }
+}
+
+/// Retard (échantillons, sous-échantillon) de `b` sur `a` dans
+/// `center ± half_width`, par corrélation directe.
+fn xcorr_lag(a: &[f32], b: &[f32], center: isize, half_width: isize) -> Option<f32> {
+    let score = |lag: isize| -> f32 {
+        a.iter()
+            .enumerate()
+            .filter_map(|(i, &x)| {
+                let j = i as isize + lag;
+                (j >= 0 && (j as usize) < b.len()).then(|| x * b[j as usize])
+            })
+            .sum()
+    };
+    let lags: Vec<(isize, f32)> = (center - half_width..=center + half_width).map(|l| (l, score(l))).collect();
+    let k = (0..lags.len()).max_by(|&i, &j| lags[i].1.total_cmp(&lags[j].1))?;
+    if lags[k].1 <= 0.0 {
+        return None;
+    }
+    let delta = if k > 0 && k + 1 < lags.len() {
+        let (y0, y1, y2) = (lags[k - 1].1, lags[k].1, lags[k + 1].1);
+        let denom = y0 - 2.0 * y1 + y2;
+        if denom.abs() > 1e-12 { 0.5 * (y0 - y2) / denom } else { 0.0 }
+    } else {
+        0.0
+    };
+    Some(lags[k].0 as f32 + delta)
+}
+
+/// Retards par octave (125 Hz – 8 kHz) entre deux IR de même origine
+/// (même pre_delay, captures successives sur le même matériel).
+pub fn analyze(left_ir: &[f32], right_ir: &[f32], sample_rate: u32) -> Option<BandDelayReport> {
+    let ms = |t: f32| (t / 1000.0 * sample_rate as f32) as usize;
+    let peak = |ir: &[f32]| ir.iter().enumerate().max_by(|a, b| a.1.abs().total_cmp(&b.1.abs())).map(|(i, _)| i);
+    let first = peak(left_ir)?.min(peak(right_ir)?);
+    let len = left_ir.len().min(right_ir.len());
+
+    let start = first.saturating_sub(ms(PRE_MS));
+    let max_lag = ms(MAX_LAG_MS) as isize;
+    let window = |ir: &[f32], n: usize| ir[start..(start + n).min(len)].to_vec();
+
+    let n = ms(WINDOW_MS) + ms(MAX_LAG_MS);
+    let broadband = xcorr_lag(&window(left_ir, n), &window(right_ir, n + ms(MAX_LAG_MS)), 0, max_lag)?;
+
+    let bands = OCTAVE_CENTERS[1..]
+        .iter()
+        .map(|&fc| {
+            let period = sample_rate as f32 / fc;
+            let n = ms(WINDOW_MS).max((3.0 * period) as usize);
+            // Filtre sur une portion qui commence avant la fenêtre (mise en régime)
+            let from = start.saturating_sub(ms(SETTLE_MS));
+            let to = (start + n + ms(MAX_LAG_MS) * 2).min(len);
+            let q = 2f32.sqrt();
+            let filt = |ir: &[f32]| {
+                let band = dsp::bandpass_biquad(&ir[from..to], fc, q, sample_rate);
+                band[start - from..].to_vec()
+            };
+            let (l, r) = (filt(left_ir), filt(right_ir));
+            let l = &l[..n.min(l.len())];
+            let half = ((period / 2.0) as isize).min(max_lag).max(1);
+            let delay_ms = xcorr_lag(l, &r, broadband.round() as isize, half)
+                .map(|lag| lag / sample_rate as f32 * 1000.0);
+            BandDelay { freq_hz: fc, delay_ms }
+        })
+        .collect();
+
+    Some(BandDelayReport { broadband_ms: broadband / sample_rate as f32 * 1000.0, bands })
+}
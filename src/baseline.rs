@@ -0,0 +1,128 @@
+// ============================================================
+//  baseline.rs — Asymétrie acceptée (pièce asymétrique)
+//
+//  Dans une pièce en L, avec une porte d'un côté ou une enceinte
+//  dans un coin, la symétrie parfaite est hors d'atteinte : le
+//  score plafonne et les conseils tournent en rond. [%] accepte
+//  la dernière analyse comme référence, avec la raison notée
+//  (« porte ouverte à droite, enceinte D contre le mur »). Pour
+//  ce reste documenté :
+//    - la correction électrique complémentaire est calculée une
+//      fois pour toutes : retard de l'enceinte la plus proche,
+//      réglage de niveau (`gain`), filtres d'égalisation (`eq`),
+//      exportés par [E] (eq_baseline.txt, camilladsp_baseline.yml) ;
+//    - chaque analyse suivante est jugée conforme si son écart G/D
+//      ne dépasse pas l'écart accepté (retard, niveau, zones
+//      spectrales) au-delà d'une marge : une correction appliquée
+//      réduit l'écart et reste conforme, une dérive ne l'est plus.
+//  Les contrôles successifs sont gardés avec la référence, dans la
+//  session.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::HistoryEntry,
+    dsp::{self, SPECTRAL_REGIONS},
+    eq::{self, PeakingFilter},
+    gain::{GainAdjustment, GainParams},
+    seats::Metric,
+};
+
+/// Longueur maximale de la raison notée (caractères).
+pub const MAX_REASON_LEN: usize = 60;
+
+/// Dépassement toléré de l'écart accepté avant non-conformité.
+pub const DELAY_MARGIN_MS: f32 = 0.1;
+pub const LEVEL_MARGIN_DB: f32 = 0.5;
+pub const REGION_MARGIN_DB: f32 = 2.0;
+
+/// Retard sous lequel aucune correction de délai n'est proposée (ms).
+const DELAY_CORRECTION_MIN_MS: f32 = 0.1;
+
+/// Une analyse comparée à la référence.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceCheck {
+    pub time: String,
+    pub score: u32,
+    pub compliant: bool,
+}
+
+/// Écart courant au-delà de l'écart accepté.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Deviation {
+    pub metric: Metric,
+    pub accepted: f32,
+    pub current: f32,
+}
+
+/// Correction électrique du reste accepté.
+#[derive(Debug, Clone)]
+pub struct Correction {
+    /// Enceinte à retarder (`true` = droite) et retard (ms).
+    pub delay: Option<(bool, f32)>,
+    pub gain: Option<GainAdjustment>,
+    /// Filtres appliqués à l'enceinte droite.
+    pub eq: Vec<PeakingFilter>,
+}
+
+/// Analyse acceptée comme référence, avec sa raison.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcceptedAsymmetry {
+    pub reason: String,
+    pub time: String,
+    pub score: u32,
+    pub delay_ms: f32,
+    pub level_diff_db: f32,
+    /// D − G de chaque bande au moment de l'acceptation.
+    pub diff_db: Vec<f32>,
+    #[serde(default)]
+    pub checks: Vec<ComplianceCheck>,
+}
+
+impl AcceptedAsymmetry {
+    pub fn from_history(reason: &str, entry: &HistoryEntry) -> Self {
+        AcceptedAsymmetry {
+            reason: reason.trim().to_string(),
+            time: entry.time.clone(),
+            score: entry.score,
+            delay_ms: entry.delay_ms,
+            level_diff_db: entry.level_diff_db,
+            diff_db: entry.right_db.iter().zip(&entry.left_db).map(|(r, l)| r - l).collect(),
+            checks: Vec::new(),
+        }
+    }
+
+    pub fn correction(&self, gain: &GainParams, sample_rate: u32) -> Correction {
+        Correction {
+            // delay_ms > 0 : la droite arrive après la gauche → retarder la gauche
+            delay: (self.delay_ms.abs() > DELAY_CORRECTION_MIN_MS).then_some((self.delay_ms < 0.0, self.delay_ms.abs())),
+            gain: gain.suggest(self.level_diff_db),
+            eq: eq::fit(&self.diff_db, sample_rate),
+        }
+    }
+
+    /// Écarts de l'analyse courante qui dépassent l'écart accepté ; vide =
+    /// conforme.
+    pub fn deviations(&self, delay_ms: f32, level_diff_db: f32, diff_db: &[f32]) -> Vec<Deviation> {
+        let mut pairs = vec![
+            (Metric::Delay, self.delay_ms, delay_ms, DELAY_MARGIN_MS),
+            (Metric::Level, self.level_diff_db, level_diff_db, LEVEL_MARGIN_DB),
+        ];
+        let (accepted, current) = (dsp::region_means(&self.diff_db), dsp::region_means(diff_db));
+        pairs.extend((0..SPECTRAL_REGIONS.len()).map(|k| (Metric::Region(k), accepted[k], current[k], REGION_MARGIN_DB)));
+        pairs
+            .into_iter()
+            .filter(|(_, accepted, current, margin)| current.abs() > accepted.abs() + margin)
+            .map(|(metric, accepted, current, _)| Deviation { metric, accepted, current })
+            .collect()
+    }
+
+    /// Contrôle une nouvelle analyse et le garde.
+    pub fn record_check(&mut self, entry: &HistoryEntry) -> bool {
+        let diff: Vec<f32> = entry.right_db.iter().zip(&entry.left_db).map(|(r, l)| r - l).collect();
+        let compliant = self.deviations(entry.delay_ms, entry.level_diff_db, &diff).is_empty();
+        self.checks.push(ComplianceCheck { time: entry.time.clone(), score: entry.score, compliant });
+        compliant
+    }
+}
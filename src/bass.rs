@@ -0,0 +1,155 @@
+// ============================================================
+//  bass.rs — Vérification du bass management (AVR)
+//
+//  Une séquence de bruits filtrés par octave (31.5 → 250 Hz + une
+//  référence à 1 kHz) est jouée sur une enceinte principale, dans
+//  UNE seule capture pour que la latence système soit identique
+//  pour toutes les bandes. Pour chaque bande on mesure :
+//    - le niveau relatif à la référence 1 kHz
+//    - le retard d'arrivée relatif à la référence
+//  Une bande qui arrive avec un retard différent provient d'une autre
+//  source (le caisson) ; une bande très atténuée n'est pas reproduite.
+//  La transition caisson → principale donne la coupure effective.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::dsp;
+
+/// Bandes de test (centre, bas, haut) en Hz — la dernière sert de référence.
+pub const BASS_TEST_BANDS: [(f32, f32, f32); 5] = [
+    (31.5, 22.0, 44.0),
+    (63.0, 44.0, 88.0),
+    (125.0, 88.0, 177.0),
+    (250.0, 177.0, 354.0),
+    (1000.0, 707.0, 1414.0),
+];
+
+/// Durée de chaque salve de bruit et silence entre deux salves.
+pub const SEGMENT_SECS: f32 = 1.0;
+pub const GAP_SECS: f32 = 0.3;
+
+/// Écart de retard au-delà duquel une bande est attribuée au caisson.
+pub const SUB_DELAY_THRESHOLD_MS: f32 = 2.0;
+/// Atténuation au-delà de laquelle une bande est considérée non reproduite.
+pub const ABSENT_LEVEL_DB: f32 = -20.0;
+
+/// Coupure configurée par défaut sur la plupart des AVR (THX).
+pub const DEFAULT_CROSSOVER_HZ: f32 = 80.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BassRoute {
+    /// Reproduite par l'enceinte principale
+    Main,
+    /// Redirigée vers le caisson (retard d'arrivée différent)
+    Sub,
+    /// Non reproduite (niveau trop faible)
+    Absent,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BassBand {
+    pub center_hz: f32,
+    pub level_db: f32,
+    pub delay_ms: f32,
+    pub route: BassRoute,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BassReport {
+    pub bands: Vec<BassBand>,
+    /// Coupure effective estimée (transition caisson → principale).
+    pub crossover_hz: Option<f32>,
+    pub configured_hz: f32,
+}
+
+impl BassReport {
+    /// Vrai si la coupure mesurée est à moins d'une octave de la coupure configurée.
+    pub fn matches_configuration(&self) -> bool {
+        match self.crossover_hz {
+            Some(f) => (f / self.configured_hz).log2().abs() <= 1.0,
+            None => false,
+        }
+    }
+}
+
+/// Position (début, longueur) de chaque salve dans la séquence, en échantillons.
+pub fn segments(sample_rate: u32) -> Vec<(usize, usize)> {
+    let seg = (SEGMENT_SECS * sample_rate as f32) as usize;
+    let gap = (GAP_SECS * sample_rate as f32) as usize;
+    (0..BASS_TEST_BANDS.len()).map(|i| (i * (seg + gap), seg)).collect()
+}
+
+/// Séquence complète : une salve de bruit filtré par bande, séparées par un silence.
+pub fn generate_test_signal(sample_rate: u32) -> Vec<f32> {
+    let segs = segments(sample_rate);
+    let total = segs.last().map(|(s, l)| s + l).unwrap_or(0);
+    let mut out = vec![0.0f32; total];
+    for ((start, len), &(_, lo, hi)) in segs.iter().zip(BASS_TEST_BANDS.iter()) {
+        let burst = dsp::generate_band_noise(sample_rate, SEGMENT_SECS, lo, hi);
+        for (o, b) in out[*start..start + len].iter_mut().zip(burst) {
+            *o = b;
+        }
+    }
+    out
+}
+
+/// Analyse la capture de la séquence `signal` jouée sur une enceinte principale.
+pub fn analyze(capture: &[f32], signal: &[f32], sample_rate: u32, configured_hz: f32) -> Option<BassReport> {
+    let segs = segments(sample_rate);
+
+    // Retard et niveau absolus de chaque salve
+    let raw: Vec<(f32, f32)> = segs
+        .iter()
+        .map(|&(start, len)| {
+            let mut reference = vec![0.0f32; signal.len()];
+            reference[start..start + len].copy_from_slice(&signal[start..start + len]);
+            let lag = dsp::xcorr_delay(&reference, capture, capture.len())?;
+
+            let cap_start = (start as f32 + lag) as usize;
+            let cap_end = (cap_start + len).min(capture.len());
+            if cap_start >= cap_end {
+                return None;
+            }
+            let rms_cap = dsp::compute_rms(&capture[cap_start..cap_end]);
+            let rms_ref = dsp::compute_rms(&signal[start..start + len]);
+            let level_db = if rms_cap > 0.0 && rms_ref > 0.0 {
+                20.0 * (rms_cap / rms_ref).log10()
+            } else {
+                -100.0
+            };
+            Some((lag, level_db))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let &(ref_lag, ref_level) = raw.last()?;
+
+    let bands: Vec<BassBand> = raw[..raw.len() - 1]
+        .iter()
+        .zip(BASS_TEST_BANDS.iter())
+        .map(|(&(lag, level), &(center_hz, _, _))| {
+            let level_db = level - ref_level;
+            let delay_ms = (lag - ref_lag) / sample_rate as f32 * 1000.0;
+            let route = if level_db < ABSENT_LEVEL_DB {
+                BassRoute::Absent
+            } else if delay_ms.abs() > SUB_DELAY_THRESHOLD_MS {
+                BassRoute::Sub
+            } else {
+                BassRoute::Main
+            };
+            BassBand { center_hz, level_db, delay_ms, route }
+        })
+        .collect();
+
+    // Coupure : milieu géométrique entre la plus haute bande « caisson »
+    // et la bande suivante (ou la référence si toutes passent au caisson)
+    let crossover_hz = bands
+        .iter()
+        .rposition(|b| b.route == BassRoute::Sub)
+        .map(|i| {
+            let upper = bands.get(i + 1).map(|b| b.center_hz).unwrap_or(BASS_TEST_BANDS[BASS_TEST_BANDS.len() - 1].0);
+            (bands[i].center_hz * upper).sqrt()
+        });
+
+    Some(BassReport { bands, crossover_hz, configured_hz })
+}
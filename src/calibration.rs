@@ -0,0 +1,110 @@
+// ============================================================
+//  calibration.rs — Fichier de calibration micro
+//
+//  Les micros de mesure USB (miniDSP UMIK-1, Dayton iMM-6…) sont
+//  livrés avec un fichier texte « fréquence  dB [phase] » décrivant
+//  leur écart à une réponse plate. Sans correction, la bosse typique
+//  au-dessus de 10 kHz fausse fortement l'inclinaison spectrale.
+//
+//  Formats acceptés : .cal / .txt (UMIK-1) et .frd — lignes d'en-tête
+//  ("Sens Factor…", '*', '#', ';') et colonnes de phase ignorées.
+//  `builtin:<nom>` désigne une calibration embarquée (assets.rs).
+// ============================================================
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+use crate::{
+    assets::{self, AssetKind},
+    dsp,
+    strings::tr,
+};
+
+#[derive(Debug, Clone)]
+pub struct MicCalibration {
+    /// Points (fréquence Hz, écart dB) triés par fréquence croissante.
+    pub points: Vec<(f32, f32)>,
+    /// Nom affiché : fichier ou ressource embarquée.
+    pub name: String,
+}
+
+impl MicCalibration {
+    /// Lit un fichier de calibration sur disque, ou une calibration
+    /// embarquée (`builtin:<nom>`).
+    pub fn load(path: &Path) -> Result<Self> {
+        if let Some(name) = path.to_str().and_then(assets::builtin_name) {
+            return Self::builtin(name);
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| (tr().calibration_unreadable)(&path.display().to_string()))?;
+        let cal = Self::parse(&text).with_context(|| (tr().calibration_invalid)(&path.display().to_string()))?;
+        let name = path.file_name().map_or_else(|| path.display().to_string(), |n| n.to_string_lossy().into_owned());
+        Ok(MicCalibration { name, ..cal })
+    }
+
+    /// Calibration embarquée `name`.
+    pub fn builtin(name: &str) -> Result<Self> {
+        let asset = assets::find(AssetKind::Calibration, name).with_context(|| {
+            let known: Vec<&str> = assets::list(AssetKind::Calibration).map(|a| a.name).collect();
+            (tr().calibration_unknown)(name, &known.join(", "))
+        })?;
+        Ok(MicCalibration { points: asset.points()?, name: asset.name.to_string() })
+    }
+
+    /// Analyse le contenu texte : une paire fréquence / dB par ligne.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut points: Vec<(f32, f32)> = text
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with(['"', '*', '#', ';']))
+            .filter_map(|l| {
+                let mut cols = l
+                    .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+                    .filter(|c| !c.is_empty());
+                let freq = cols.next()?.parse::<f32>().ok()?;
+                let db = cols.next()?.parse::<f32>().ok()?;
+                (freq > 0.0 && db.is_finite()).then_some((freq, db))
+            })
+            .collect();
+
+        if points.len() < 2 {
+            bail!(tr().calibration_too_few_points);
+        }
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(MicCalibration { points, name: String::new() })
+    }
+
+    /// Écart du micro (dB) à la fréquence `freq`, interpolé linéairement en
+    /// log-fréquence ; maintenu constant hors de la plage du fichier.
+    pub fn correction_db(&self, freq: f32) -> f32 {
+        let first = self.points[0];
+        let last = self.points[self.points.len() - 1];
+        if freq <= first.0 {
+            return first.1;
+        }
+        if freq >= last.0 {
+            return last.1;
+        }
+        let i = self.points.partition_point(|p| p.0 <= freq);
+        let (f0, d0) = self.points[i - 1];
+        let (f1, d1) = self.points[i];
+        let t = (freq / f0).ln() / (f1 / f0).ln();
+        d0 + (d1 - d0) * t
+    }
+
+    /// Corrections (dB) interpolées sur la grille de `num_bands` bandes log.
+    pub fn band_corrections(&self, num_bands: usize) -> Vec<f32> {
+        (0..num_bands)
+            .map(|i| self.correction_db(dsp::band_center_freq(i, num_bands)))
+            .collect()
+    }
+
+    /// Retire la réponse du micro d'un spectre en bandes (amplitudes linéaires,
+    /// avant `bands_to_db`).
+    pub fn apply(&self, bands: &mut [f32]) {
+        let corrections = self.band_corrections(bands.len());
+        for (b, c) in bands.iter_mut().zip(corrections) {
+            *b /= 10f32.powf(c / 20.0);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// ============================================================
+//  channelmap.rs — Vérification du câblage des canaux
+//
+//  Le signal est placé dans le flux entrelacé en supposant
+//  l'ordre WAVE (emplacement 0 = FL, 1 = FR…). Certains pilotes
+//  multicanal (HDMI, ALSA surround, cartes USB en 7.1) ne suivent
+//  pas cet ordre : la « gauche » sort alors sur une autre enceinte
+//  et toute la comparaison G/D est faussée sans que rien ne le
+//  signale.
+//
+//  Sonde : une courte salve de bruit est jouée sur chaque
+//  emplacement à tour de rôle, micro posé contre une enceinte.
+//  L'emplacement le plus fort est celui qui l'alimente. Deux
+//  captures (micro contre la gauche, puis contre la droite)
+//  donnent la correspondance appliquée ensuite à toutes les
+//  captures.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::{audio::Channel, dsp};
+
+/// Nombre d'emplacements sondés (7.1 au plus). Les emplacements absents
+/// du flux de sortie restent muets.
+pub const PROBE_SLOTS: usize = 8;
+
+/// Durée d'une salve, silence qui la suit.
+const BURST_SECS: f32 = 0.2;
+const GAP_SECS: f32 = 0.3;
+
+/// Bande des salves (Hz) : rendue par tout haut-parleur, caisson exclu.
+const NOISE_LO_HZ: f32 = 500.0;
+const NOISE_HI_HZ: f32 = 4_000.0;
+
+/// Écart minimal entre l'emplacement le plus fort et le suivant (dB) :
+/// micro contre l'enceinte, sa voisine arrive bien plus faible.
+pub const MIN_MARGIN_DB: f32 = 6.0;
+
+/// Correspondance enceinte physique → emplacement du flux entrelacé.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelMap {
+    pub left: usize,
+    pub right: usize,
+}
+
+impl Default for ChannelMap {
+    /// Ordre WAVE : FL sur 0, FR sur 1.
+    fn default() -> Self {
+        ChannelMap { left: 0, right: 1 }
+    }
+}
+
+impl ChannelMap {
+    pub fn is_identity(&self) -> bool {
+        *self == ChannelMap::default()
+    }
+
+    /// Emplacement réel de `channel`. Les canaux déplacés par la
+    /// correspondance prennent la place laissée libre (permutation), les
+    /// autres gardent leur indice.
+    pub fn resolve(&self, channel: Channel) -> Channel {
+        let len = PROBE_SLOTS.max(self.left + 1).max(self.right + 1).max(channel.output_index() + 1);
+        let mut slots: Vec<usize> = (0..len).collect();
+        let at = |slots: &Vec<usize>, v: usize| slots.iter().position(|&s| s == v).unwrap_or(v);
+        let i = at(&slots, self.left);
+        slots.swap(Channel::LEFT.output_index(), i);
+        let i = at(&slots, self.right);
+        slots.swap(Channel::RIGHT.output_index(), i);
+        Channel(slots[channel.output_index()] as u16)
+    }
+}
+
+/// Sonde avec le micro contre une enceinte.
+#[derive(Debug, Clone)]
+pub struct SlotProbe {
+    /// Niveau capté pendant la salve de chaque emplacement (dB relatifs au plus fort).
+    pub levels_db: Vec<f32>,
+    /// Emplacement qui alimente l'enceinte ; `None` si aucun ne domine
+    /// de `MIN_MARGIN_DB`.
+    pub slot: Option<usize>,
+}
+
+/// Résultat de la sonde pour les deux enceintes.
+#[derive(Debug, Clone, Default)]
+pub struct ChannelMapReport {
+    pub left: Option<SlotProbe>,
+    pub right: Option<SlotProbe>,
+}
+
+impl ChannelMapReport {
+    /// Correspondance mesurée, si les deux emplacements sont nets et distincts.
+    pub fn map(&self) -> Option<ChannelMap> {
+        let left = self.left.as_ref()?.slot?;
+        let right = self.right.as_ref()?.slot?;
+        (left != right).then_some(ChannelMap { left, right })
+    }
+}
+
+/// Longueur d'un segment (salve + silence), en échantillons.
+pub fn segment_len(sample_rate: u32) -> usize {
+    ((BURST_SECS + GAP_SECS) * sample_rate as f32) as usize
+}
+
+/// Durée totale de la séquence, en secondes.
+pub fn sequence_secs() -> f32 {
+    PROBE_SLOTS as f32 * (BURST_SECS + GAP_SECS)
+}
+
+/// Séquence mono : une salve différente par segment. `audio` place le
+/// segment k sur l'emplacement k ; des salves distinctes évitent qu'une
+/// corrélation se cale sur le mauvais segment.
+pub fn generate_probe_signal(sample_rate: u32) -> Vec<f32> {
+    let seg = segment_len(sample_rate);
+    let mut out = vec![0.0f32; seg * PROBE_SLOTS];
+    for k in 0..PROBE_SLOTS {
+        let burst = dsp::generate_band_noise(sample_rate, BURST_SECS, NOISE_LO_HZ, NOISE_HI_HZ);
+        out[k * seg..k * seg + burst.len()].copy_from_slice(&burst);
+    }
+    out
+}
+
+/// Niveau de chaque salve dans la capture de `signal`.
+pub fn analyze(capture: &[f32], signal: &[f32], sample_rate: u32) -> Option<SlotProbe> {
+    let lag = dsp::xcorr_delay(signal, capture, capture.len())?.round() as usize;
+    let seg = segment_len(sample_rate);
+    let burst = (BURST_SECS * sample_rate as f32) as usize;
+
+    let rms: Vec<f32> = (0..PROBE_SLOTS)
+        .map(|k| {
+            let from = (k * seg + lag).min(capture.len());
+            let to = (from + burst).min(capture.len());
+            dsp::compute_rms(&capture[from..to])
+        })
+        .collect();
+    let loudest = (0..PROBE_SLOTS).max_by(|&a, &b| rms[a].total_cmp(&rms[b]))?;
+    let peak = rms[loudest];
+    if peak <= 0.0 {
+        return None;
+    }
+    let levels_db: Vec<f32> = rms.iter().map(|&r| 20.0 * (r.max(1e-9) / peak).log10()).collect();
+
+    let runner_up = levels_db
+        .iter()
+        .enumerate()
+        .filter(|&(k, _)| k != loudest)
+        .map(|(_, &db)| db)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let slot = (-runner_up >= MIN_MARGIN_DB).then_some(loudest);
+
+    Some(SlotProbe { levels_db, slot })
+}
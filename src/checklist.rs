@@ -0,0 +1,82 @@
+// ============================================================
+//  checklist.rs — Vérifications avant la première mesure
+//
+//  Une mesure faite micro posé sur la table, aspirateur en marche
+//  ou « son 3D » activé ne vaut rien, et le spectre ne le dit pas.
+//  Avec `--checklist`, la première capture de la session ouvre une
+//  liste à valider une fois. Les points que les mesures savent
+//  vérifier se cochent seuls :
+//    - pièce calme        ← bruit de fond [H] sous le niveau visé ;
+//    - volume réglé       ← calibration du niveau [J] atteinte ;
+//    - traitements coupés ← test de linéarité [G] sans pompage,
+//      ou analyse déjà faite sans symptôme.
+//  La hauteur du micro ne se vérifie qu'à la main. Un problème
+//  détecté reste signalé même si le point est coché à la main.
+// ============================================================
+
+use crate::{enhancements::Symptom, levelcal::LevelCalibration, noise::NoiseFloor};
+
+/// Marge minimale entre le niveau de capture visé et le bruit de fond (dB).
+pub const QUIET_MARGIN_DB: f32 = 30.0;
+
+/// Points de la liste, dans l'ordre d'affichage (touches 1 à 4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Item {
+    MicHeight,
+    Quiet,
+    Volume,
+    Enhancements,
+}
+
+pub const ITEMS: [Item; 4] = [Item::MicHeight, Item::Quiet, Item::Volume, Item::Enhancements];
+
+/// Ce que les mesures de la session disent des points de la liste.
+pub struct Evidence<'a> {
+    pub noise: Option<&'a NoiseFloor>,
+    pub target_dbfs: f32,
+    pub level_cal: Option<&'a LevelCalibration>,
+    pub linearity_done: bool,
+    pub analyzed: bool,
+    pub enhancements: &'a [Symptom],
+}
+
+impl Item {
+    /// `Some(true)` vérifié par une mesure, `Some(false)` problème détecté,
+    /// `None` rien ne permet d'en juger.
+    pub fn auto(self, e: &Evidence) -> Option<bool> {
+        match self {
+            Item::MicHeight => None,
+            Item::Quiet => e.noise.map(|n| n.broadband_dbfs <= e.target_dbfs - QUIET_MARGIN_DB),
+            Item::Volume => e.level_cal.map(|c| !c.limited),
+            Item::Enhancements if !e.enhancements.is_empty() => Some(false),
+            Item::Enhancements => (e.linearity_done || e.analyzed).then_some(true),
+        }
+    }
+}
+
+/// État de la liste pour la session en cours.
+#[derive(Debug, Clone, Default)]
+pub struct Checklist {
+    /// Points cochés à la main, dans l'ordre de `ITEMS`.
+    pub ticked: [bool; ITEMS.len()],
+    pub acknowledged: bool,
+    /// Panneau affiché (ouvert par la première capture).
+    pub open: bool,
+}
+
+impl Checklist {
+    pub fn toggle(&mut self, index: usize) {
+        if let Some(t) = self.ticked.get_mut(index) {
+            *t = !*t;
+        }
+    }
+
+    /// Point validé : coché à la main ou vérifié par une mesure.
+    pub fn is_checked(&self, index: usize, e: &Evidence) -> bool {
+        self.ticked[index] || ITEMS[index].auto(e) == Some(true)
+    }
+
+    pub fn is_complete(&self, e: &Evidence) -> bool {
+        (0..ITEMS.len()).all(|i| self.is_checked(i, e))
+    }
+}
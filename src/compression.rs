@@ -0,0 +1,120 @@
+// ============================================================
+//  compression.rs — Contrôle de compression / SPL maximal
+//
+//  Le même sweep est joué à plusieurs niveaux (−20, −10, 0 dB) dans
+//  une seule capture. Pour un système linéaire, la réponse normalisée
+//  (niveau capté − niveau joué) est identique à tous les niveaux ;
+//  un limiteur DSP intégré ou une voie d'ampli défaillante fait
+//  chuter le gain au niveau le plus fort. On rapporte, par octave,
+//  l'écart de gain entre le niveau le plus fort et le plus faible.
+// ============================================================
+
+use crate::dsp::{self, OCTAVE_CENTERS};
+
+/// Niveaux de lecture relatifs au sweep nominal (dB), du plus faible au plus fort.
+pub const LEVELS_DB: [f32; 3] = [-20.0, -10.0, 0.0];
+
+/// Durée de chaque sweep et silence entre deux sweeps.
+pub const SWEEP_SECS: f32 = 1.5;
+pub const GAP_SECS: f32 = 0.5;
+
+/// Perte de gain au-delà de laquelle une octave est considérée compressée.
+pub const COMPRESSION_LIMIT_DB: f32 = 1.5;
+
+/// Résultat pour une enceinte : compression par octave (dB, négatif = le gain
+/// chute au niveau le plus fort).
+#[derive(Debug, Clone)]
+pub struct ChannelCompression {
+    pub per_octave_db: Vec<f32>,
+}
+
+impl ChannelCompression {
+    /// Pire compression mesurée (dB, valeur la plus négative).
+    pub fn worst_db(&self) -> f32 {
+        self.per_octave_db.iter().cloned().fold(0.0, f32::min)
+    }
+}
+
+/// Résultat du test pour les deux enceintes.
+#[derive(Debug, Clone, Default)]
+pub struct CompressionReport {
+    pub left: Option<ChannelCompression>,
+    pub right: Option<ChannelCompression>,
+}
+
+impl CompressionReport {
+    /// Octaves compressées : (fréquence centrale, « G » / « D », compression dB).
+    pub fn compressed_octaves(&self) -> Vec<(f32, &'static str, f32)> {
+        [("G", &self.left), ("D", &self.right)]
+            .iter()
+            .filter_map(|(side, c)| c.as_ref().map(|c| (*side, c)))
+            .flat_map(|(side, c)| {
+                c.per_octave_db
+                    .iter()
+                    .zip(OCTAVE_CENTERS.iter())
+                    .filter(|(db, _)| **db < -COMPRESSION_LIMIT_DB)
+                    .map(move |(db, fc)| (*fc, side, *db))
+            })
+            .collect()
+    }
+}
+
+/// Position (début, longueur) de chaque sweep, en échantillons.
+pub fn segments(sample_rate: u32) -> Vec<(usize, usize)> {
+    let sweep = (SWEEP_SECS * sample_rate as f32) as usize;
+    let gap = (GAP_SECS * sample_rate as f32) as usize;
+    (0..LEVELS_DB.len()).map(|i| (i * (sweep + gap), sweep)).collect()
+}
+
+/// Durée totale de la séquence, en secondes.
+pub fn sequence_secs() -> f32 {
+    LEVELS_DB.len() as f32 * (SWEEP_SECS + GAP_SECS)
+}
+
+/// Séquence : le même sweep répété à chaque niveau de `LEVELS_DB`.
+pub fn generate_test_signal(sample_rate: u32) -> Vec<f32> {
+    let params = dsp::SweepParams { duration_s: SWEEP_SECS, ..Default::default() };
+    let sweep = dsp::generate_sweep(sample_rate, &params);
+    let segs = segments(sample_rate);
+    let total = segs.last().map(|(s, l)| s + l).unwrap_or(0);
+    let mut out = vec![0.0f32; total];
+    for (&(start, len), &level_db) in segs.iter().zip(LEVELS_DB.iter()) {
+        let gain = 10f32.powf(level_db / 20.0);
+        for (o, s) in out[start..start + len].iter_mut().zip(sweep.iter()) {
+            *o = s * gain;
+        }
+    }
+    out
+}
+
+/// Compression par octave à partir de la capture de `signal`.
+pub fn analyze(capture: &[f32], signal: &[f32], sample_rate: u32) -> Option<ChannelCompression> {
+    // Latence commune : le sweep le plus fort domine la corrélation
+    let lag = dsp::xcorr_delay(signal, capture, capture.len())?.round() as usize;
+    // Inclut la queue de réverbération du sweep dans le silence qui suit
+    let tail = (GAP_SECS * sample_rate as f32) as usize;
+
+    // Gain normalisé (capté − joué) de chaque octave, pour chaque niveau
+    let gains: Vec<Vec<f32>> = segments(sample_rate)
+        .iter()
+        .zip(LEVELS_DB.iter())
+        .map(|(&(start, len), &level_db)| {
+            let from = start + lag;
+            let to = (from + len + tail).min(capture.len());
+            if from >= to {
+                return None;
+            }
+            Some(
+                dsp::octave_levels_db(&capture[from..to], sample_rate)
+                    .iter()
+                    .map(|db| db - level_db)
+                    .collect(),
+            )
+        })
+        .collect::<Option<_>>()?;
+
+    let (low, high) = (gains.first()?, gains.last()?);
+    Some(ChannelCompression {
+        per_octave_db: high.iter().zip(low.iter()).map(|(h, l)| h - l).collect(),
+    })
+}
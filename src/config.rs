@@ -0,0 +1,150 @@
+// ============================================================
+//  config.rs — Fichier de configuration (TOML)
+//
+//  Valeurs de démarrage lues depuis `speaker-align.toml` (ou le
+//  chemin donné par `--config`). Fichier absent = valeurs par
+//  défaut ; chaque clé absente garde aussi sa valeur par défaut.
+//
+//    [sweep]
+//    duration_s = 6.0
+//    f_start_hz = 20.0
+//    f_stop_hz  = 20000.0
+//    amplitude  = 0.5
+//    gap_s      = 1.0  # silence G → D de la mesure enchaînée ([Tab])
+//
+//    [level]           # calibration du niveau ([J])
+//    target_dbfs = -20.0
+//    auto = false      # calibrer avant la première capture
+//
+//    [loopback]        # boucle électrique de référence (optionnelle)
+//    input  = 1        # entrée reliée à la sortie (indice à partir de 0)
+//    output = 2        # sortie copiant le signal ; absente = câble en Y
+//
+//    [monitor]         # surveillance (`speaker-align monitor`)
+//    at = "03:00"      # heure UTC de la mesure quotidienne
+//    min_score = 80    # alerte en dessous
+//    history = "monitor.jsonl"
+//    mqtt_broker = "192.168.1.10:1883"   # optionnel
+//    mqtt_topic = "speaker-align/alert"
+//
+//    [webhook]         # POST JSON en fin de mesure (headless, monitor)
+//    url = "https://hooks.example.com/…"
+//
+//    [gain]            # organe de réglage du niveau (conseils G/D)
+//    control = "trim"  # trim (ampli A/V), balance, detents (enceinte active)
+//    step_db = 0.5     # absent = pas usuel de l'organe
+//
+//    [placement]       # déplacements permis vers l'auditeur (conseils de retard)
+//    left  = "free"    # free, forward (avancer), back (reculer), fixed
+//    right = "back"
+//    channel_delay = true   # délai réglable par canal dans l'ampli / le DSP
+//    left_m  = 2.45    # distances au mètre ruban (inversion G/D suspectée)
+//    right_m = 2.80
+//
+//    [reminders]       # rappels de re-vérification (0 = jamais)
+//    mic_cal_days  = 180   # calibration micro
+//    loopback_days = 30    # latence de la boucle
+//    on_device_change = true
+//    log = "speaker-align-checks.json"
+//
+//    [score]           # profil de score et seuils des conseils
+//    profile = "nearfield-studio"   # home-hifi (défaut), home-theater
+//    time = 35         # poids optionnels (spectrum + level + time = 100)
+//
+//    [room]            # conditions de départ (vitesse du son, corrélations)
+//    temperature_c = 17.5
+//    humidity_pct  = 60
+//    length_m = 5.2    # dimensions : modes propres marqués sur le spectre
+//    width_m  = 3.8
+//    height_m = 2.5
+//
+//    [theme]           # couleurs : default, high-contrast, ansi16, mono
+//    name = "high-contrast"
+//    [theme.colors]    # teintes remplacées (voir theme.rs)
+//    orange = "#e69f00"
+//
+//    [keys]            # touches réassignées (voir keymap.rs)
+//    quit = "a"
+//    analyze = "q"
+// ============================================================
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::{fs, io::ErrorKind, path::Path};
+
+use crate::{
+    audio::Loopback, dsp::SweepParams, environment::RoomParams, gain::GainParams, keymap::KeyBindings, levelcal::LevelParams,
+    monitor::MonitorParams, placement::PlacementParams, reminders::ReminderParams, scoring::ScoreParams,
+    strings::tr, theme::ThemeParams, webhook::WebhookParams,
+};
+
+/// Chemin lu par défaut, dans le répertoire courant.
+pub const DEFAULT_CONFIG_PATH: &str = "speaker-align.toml";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub sweep: SweepParams,
+    pub level: LevelParams,
+    pub loopback: Option<Loopback>,
+    pub monitor: MonitorParams,
+    pub webhook: WebhookParams,
+    pub gain: GainParams,
+    pub placement: PlacementParams,
+    pub reminders: ReminderParams,
+    pub score: ScoreParams,
+    pub room: RoomParams,
+    pub theme: ThemeParams,
+    pub keys: KeyBindings,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Config> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => {
+                return Err(e).with_context(|| (tr().config_unreadable)(&path.display().to_string()));
+            }
+        };
+        let config: Config = toml::from_str(&text)
+            .with_context(|| (tr().config_invalid)(&path.display().to_string()))?;
+        config
+            .sweep
+            .validate()
+            .map_err(|e| anyhow!((tr().label_value)(&path.display().to_string(), &e)))?;
+        config
+            .level
+            .validate()
+            .map_err(|e| anyhow!((tr().label_value)(&path.display().to_string(), &e)))?;
+        config
+            .monitor
+            .validate()
+            .map_err(|e| anyhow!((tr().label_value)(&path.display().to_string(), &e)))?;
+        config
+            .webhook
+            .validate()
+            .map_err(|e| anyhow!((tr().label_value)(&path.display().to_string(), &e)))?;
+        config
+            .gain
+            .validate()
+            .map_err(|e| anyhow!((tr().label_value)(&path.display().to_string(), &e)))?;
+        config
+            .score
+            .validate()
+            .map_err(|e| anyhow!((tr().label_value)(&path.display().to_string(), &e)))?;
+        config
+            .room
+            .validate()
+            .map_err(|e| anyhow!((tr().label_value)(&path.display().to_string(), &e)))?;
+        config
+            .theme
+            .validate()
+            .map_err(|e| anyhow!((tr().label_value)(&path.display().to_string(), &e)))?;
+        config
+            .keys
+            .validate()
+            .map_err(|e| anyhow!((tr().label_value)(&path.display().to_string(), &e)))?;
+        Ok(config)
+    }
+}
@@ -0,0 +1,117 @@
+// ============================================================
+//  debugdump.rs — Intermédiaires DSP pour le diagnostic
+//
+//  Avec `--debug-dump DOSSIER`, chaque analyse écrit, pour chaque
+//  enceinte mesurée, les données qui mènent au délai affiché :
+//    <canal>_capture.npy   — capture filtrée (passe-haut)
+//    <canal>_spectrum.csv  — spectre FFT moyenné (fenêtre de Hann)
+//    <canal>_xcorr.npy     — corrélation croisée brute signal / capture
+//                            (indice = retard en échantillons)
+//    <canal>_ir.npy        — IR complète, avant toute recherche du front
+//    summary.csv           — front, fenêtre du son direct, pic et distance
+//                            retenus par enceinte
+//  Les .npy (float32, 1 dimension) s'ouvrent avec `numpy.load`.
+// ============================================================
+
+use anyhow::{Context, Result};
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{dsp::{self, FFT_SIZE}, measurement::Measurement, strings::tr};
+
+pub const SUMMARY_CSV: &str = "summary.csv";
+
+/// Contenu d'un fichier .npy (format 1.0) : tableau float32 little-endian.
+pub fn to_npy(data: &[f32]) -> Vec<u8> {
+    let mut header = format!("{{'descr': '<f4', 'fortran_order': False, 'shape': ({},), }}", data.len());
+    // Magie (6) + version (2) + longueur (2) + en-tête + '\n', aligné sur 64 octets
+    let unpadded = 10 + header.len() + 1;
+    header.push_str(&" ".repeat(unpadded.next_multiple_of(64) - unpadded));
+    header.push('\n');
+
+    let mut out = Vec::with_capacity(10 + header.len() + data.len() * 4);
+    out.extend_from_slice(b"\x93NUMPY\x01\x00");
+    out.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    out.extend_from_slice(header.as_bytes());
+    for v in data {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    out
+}
+
+/// Spectre moyenné en CSV : fréquence du bin, amplitude linéaire et dB.
+pub fn spectrum_csv(spectrum: &[f32], sample_rate: u32) -> String {
+    let mut out = String::from("frequency_hz,amplitude,db\n");
+    for (i, a) in spectrum.iter().enumerate() {
+        let freq = i as f32 * sample_rate as f32 / FFT_SIZE as f32;
+        let _ = writeln!(out, "{:.3},{:e},{:.2}", freq, a, 20.0 * a.max(1e-12).log10());
+    }
+    out
+}
+
+fn write(path: PathBuf, bytes: &[u8], written: &mut Vec<PathBuf>) -> Result<()> {
+    fs::write(&path, bytes).with_context(|| (tr().cannot_write)(&path.display().to_string()))?;
+    written.push(path);
+    Ok(())
+}
+
+/// Écrit les intermédiaires de chaque mesure dans `dir` (créé au besoin).
+/// Les IR absentes (mesure pas encore analysée) sont calculées pour l'export.
+pub fn dump<'a>(
+    dir: &Path,
+    measurements: impl IntoIterator<Item = &'a Measurement>,
+    pre_delay_samples: usize,
+    onset_db: f32,
+) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir).with_context(|| (tr().cannot_create)(&dir.display().to_string()))?;
+    let mut written = Vec::new();
+    let mut summary = String::from("channel,pre_delay_samples,ir_onset_sample,onset_candidates,ir_peak_sample,xcorr_peak_sample,dist_m,latency_ms,direct_end_sample,direct_window_ms\n");
+
+    for m in measurements {
+        let name = m.channel.name();
+        let sr = m.sample_rate;
+        let ir = match &m.ir {
+            Some(ir) => ir.clone(),
+            None => dsp::compute_impulse_response(&m.samples, &m.test_signal, &m.sweep, sr),
+        };
+        // Retards positifs seulement : au-delà, la corrélation est circulaire
+        let mut xcorr = dsp::cross_correlation(&m.test_signal, &m.samples).unwrap_or_default();
+        xcorr.truncate(m.samples.len());
+
+        write(dir.join(format!("{}_capture.npy", name)), &to_npy(&m.samples), &mut written)?;
+        write(dir.join(format!("{}_spectrum.csv", name)), spectrum_csv(&m.spectrum, sr).as_bytes(), &mut written)?;
+        write(dir.join(format!("{}_xcorr.npy", name)), &to_npy(&xcorr), &mut written)?;
+        write(dir.join(format!("{}_ir.npy", name)), &to_npy(&ir), &mut written)?;
+
+        let argmax = |x: &[f32]| {
+            x.iter()
+                .enumerate()
+                .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+                .map(|(i, _)| i.to_string())
+                .unwrap_or_default()
+        };
+        let opt = |v: Option<f32>| v.map(|v| format!("{:.4}", v)).unwrap_or_default();
+        let direct = dsp::direct_window(&ir, sr, onset_db);
+        let onset = dsp::ir_onset(&ir, sr, onset_db);
+        let _ = writeln!(
+            summary,
+            "{},{},{},{},{},{},{},{},{},{}",
+            name,
+            pre_delay_samples,
+            onset.map(|o| o.index.to_string()).unwrap_or_default(),
+            onset.map(|o| o.candidates.to_string()).unwrap_or_default(),
+            argmax(&ir),
+            argmax(&xcorr),
+            opt(m.dist_m),
+            opt(m.latency_ms),
+            direct.map(|w| w.end.to_string()).unwrap_or_default(),
+            opt(direct.map(|w| w.len_secs(sr) * 1000.0)),
+        );
+    }
+
+    write(dir.join(SUMMARY_CSV), summary.as_bytes(), &mut written)?;
+    Ok(written)
+}
@@ -0,0 +1,79 @@
+// ============================================================
+//  distance.rs — Distances rapides
+//
+//  Pour dégrossir le placement avant la mesure complète : un
+//  chirp de 0,3 s sur chaque enceinte (gauche puis droite,
+//  enchaînées) et seulement les distances micro → enceinte et
+//  le retard D − G, en deux ou trois secondes. Ni spectre, ni
+//  score, rien dans l'historique.
+//
+//  Même calcul que l'analyse : IR par déconvolution, puis front
+//  d'onde (`dsp::distance_from_ir`), ou origine donnée par la
+//  piste de boucle quand elle existe — distances alors
+//  absolues. Sans boucle, la latence du système s'ajoute aux
+//  deux distances ; seul l'écart est exact.
+// ============================================================
+
+use crate::{
+    audio::Capture,
+    dsp::{self, SweepParams},
+    measurement::HIGHPASS_HZ,
+};
+
+/// Durée du chirp (s).
+pub const CHIRP_SECS: f32 = 0.3;
+
+/// Bande du chirp (Hz) : pas de graves, trop peu d'énergie en 0,3 s ;
+/// les aigus font la netteté du front d'onde.
+const CHIRP_START_HZ: f32 = 200.0;
+const CHIRP_STOP_HZ: f32 = 16_000.0;
+
+/// Paramètres du chirp ; hors des bornes de `SweepParams::clamped`, qui
+/// valent pour le sweep de mesure.
+pub fn chirp() -> SweepParams {
+    SweepParams {
+        duration_s: CHIRP_SECS,
+        f_start_hz: CHIRP_START_HZ,
+        f_stop_hz: CHIRP_STOP_HZ,
+        averages: 1,
+        ..SweepParams::default()
+    }
+}
+
+pub fn generate_test_signal(sample_rate: u32) -> Vec<f32> {
+    dsp::generate_sweep(sample_rate, &chirp())
+}
+
+/// Distance micro → enceinte d'une capture du chirp (m), et vrai si
+/// l'origine vient de la piste de boucle ; `None` si le front d'onde n'est
+/// pas trouvé.
+pub fn analyze(capture: &Capture, test_signal: &[f32], sample_rate: u32, pre_delay_samples: usize, onset_db: f32) -> Option<(f32, bool)> {
+    let samples = dsp::highpass_filter(&capture.mono, HIGHPASS_HZ, sample_rate);
+    let ir = dsp::compute_impulse_response(&samples, test_signal, &chirp(), sample_rate);
+    match capture.loopback.as_ref().and_then(|lb| dsp::loopback_arrival(lb, test_signal, &chirp(), sample_rate)) {
+        Some(origin) => dsp::distance_from_origin(&ir, sample_rate, origin, onset_db).map(|d| (d, true)),
+        None => dsp::distance_from_ir(&ir, sample_rate, pre_delay_samples, onset_db).map(|d| (d, false)),
+    }
+}
+
+/// Résultat de [0] : distance de chaque enceinte (m).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DistanceReport {
+    pub left: Option<f32>,
+    pub right: Option<f32>,
+    /// Origine prise sur la piste de boucle des deux côtés : distances
+    /// absolues.
+    pub absolute: bool,
+}
+
+impl DistanceReport {
+    /// Retard de la droite sur la gauche (ms), comme `AppState::delay_ms`.
+    pub fn delay_ms(&self) -> Option<f32> {
+        Some((self.right? - self.left?) / dsp::speed_of_sound() * 1000.0)
+    }
+
+    /// Écart de distance (cm, positif = droite plus loin).
+    pub fn distance_cm(&self) -> Option<f32> {
+        Some((self.right? - self.left?) * 100.0)
+    }
+}
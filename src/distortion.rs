@@ -0,0 +1,142 @@
+// ============================================================
+//  distortion.rs — Test rub & buzz / souffle d'évent
+//
+//  Un woofer qui frotte (bobine décentrée, suspension fatiguée) ou
+//  un évent qui souffle produit, à fort niveau dans le grave, de
+//  l'énergie très au-dessus de la fondamentale : harmoniques d'ordre
+//  élevé et bruit large bande. Ce défaut d'une seule enceinte se
+//  confond facilement avec un « déséquilibre de placement ».
+//
+//  Salves sinusoïdales à fort niveau sur quelques fréquences graves ;
+//  pour chaque salve :
+//    - THD      : harmoniques 2 à 5 / fondamentale
+//    - résidu   : toute l'énergie entre 10 × f et 10 kHz / fondamentale
+//  Le résidu de G et D est comparé fréquence par fréquence.
+// ============================================================
+
+use std::f32::consts::PI;
+
+use crate::dsp::{self, Window, FFT_SIZE};
+
+/// Fréquences des salves (Hz).
+pub const BURST_FREQS: [f32; 5] = [40.0, 60.0, 80.0, 120.0, 200.0];
+
+/// Durée d'une salve, silence entre deux salves, amplitude crête.
+pub const BURST_SECS: f32 = 1.0;
+pub const GAP_SECS: f32 = 0.3;
+pub const BURST_LEVEL: f32 = 0.9;
+
+/// Résidu au-dessus duquel une salve est suspecte (dB / fondamentale).
+pub const RESIDUE_LIMIT_DB: f32 = -60.0;
+/// Écart de résidu G/D à partir duquel une enceinte est signalée.
+pub const ASYMMETRY_DB: f32 = 10.0;
+
+/// Limite haute de l'intégration du résidu.
+const RESIDUE_MAX_HZ: f32 = 10_000.0;
+
+/// Analyse d'une salve.
+#[derive(Debug, Clone, Copy)]
+pub struct BurstDistortion {
+    pub freq_hz: f32,
+    pub thd_db: f32,
+    pub residue_db: f32,
+}
+
+/// Résultat du test pour les deux enceintes.
+#[derive(Debug, Clone, Default)]
+pub struct RubBuzzReport {
+    pub left: Option<Vec<BurstDistortion>>,
+    pub right: Option<Vec<BurstDistortion>>,
+}
+
+impl RubBuzzReport {
+    /// Fréquences où une enceinte présente un résidu anormal par rapport à
+    /// l'autre : (fréquence, enceinte fautive « G » / « D », écart en dB).
+    pub fn suspects(&self) -> Vec<(f32, &'static str, f32)> {
+        let (Some(l), Some(r)) = (&self.left, &self.right) else {
+            return Vec::new();
+        };
+        l.iter()
+            .zip(r.iter())
+            .filter_map(|(a, b)| {
+                let diff = b.residue_db - a.residue_db;
+                let worst = a.residue_db.max(b.residue_db);
+                if diff.abs() < ASYMMETRY_DB || worst < RESIDUE_LIMIT_DB {
+                    return None;
+                }
+                Some((a.freq_hz, if diff > 0.0 { "D" } else { "G" }, diff.abs()))
+            })
+            .collect()
+    }
+}
+
+/// Position (début, longueur) de chaque salve, en échantillons.
+pub fn segments(sample_rate: u32) -> Vec<(usize, usize)> {
+    let burst = (BURST_SECS * sample_rate as f32) as usize;
+    let gap = (GAP_SECS * sample_rate as f32) as usize;
+    (0..BURST_FREQS.len()).map(|i| (i * (burst + gap), burst)).collect()
+}
+
+/// Durée totale de la séquence, en secondes.
+pub fn sequence_secs() -> f32 {
+    BURST_FREQS.len() as f32 * (BURST_SECS + GAP_SECS)
+}
+
+/// Séquence de salves sinusoïdales (fondus de 20 ms pour éviter les clics).
+pub fn generate_test_signal(sample_rate: u32) -> Vec<f32> {
+    let segs = segments(sample_rate);
+    let total = segs.last().map(|(s, l)| s + l).unwrap_or(0);
+    let mut out = vec![0.0f32; total];
+    let fade = (0.020 * sample_rate as f32) as usize;
+    for (&(start, len), &freq) in segs.iter().zip(BURST_FREQS.iter()) {
+        for (i, o) in out[start..start + len].iter_mut().enumerate() {
+            let env = (i as f32 / fade as f32).min(1.0) * ((len - 1 - i) as f32 / fade as f32).min(1.0);
+            *o = BURST_LEVEL * env * (2.0 * PI * freq * i as f32 / sample_rate as f32).sin();
+        }
+    }
+    out
+}
+
+/// THD et résidu de chaque salve à partir de la capture de `signal`.
+pub fn analyze(capture: &[f32], signal: &[f32], sample_rate: u32) -> Option<Vec<BurstDistortion>> {
+    // Latence commune à toutes les salves (une seule capture)
+    let lag = dsp::xcorr_delay(signal, capture, capture.len())?.round() as usize;
+    let freq_res = sample_rate as f32 / FFT_SIZE as f32;
+    // Ignore les 100 premières ms de chaque salve (attaque, fondu)
+    let settle = (0.1 * sample_rate as f32) as usize;
+
+    segments(sample_rate)
+        .iter()
+        .zip(BURST_FREQS.iter())
+        .map(|(&(start, len), &freq)| {
+            let from = start + lag + settle;
+            let to = (start + lag + len).min(capture.len());
+            if from >= to {
+                return None;
+            }
+            let power: Vec<f32> = dsp::compute_fft(&capture[from..to], Window::Hann).iter().map(|m| m * m).collect();
+
+            // Énergie autour d'une fréquence (lobe principal de Hann ±2 bins)
+            let around = |f: f32| -> f32 {
+                let k = (f / freq_res).round() as usize;
+                power.iter().skip(k.saturating_sub(2)).take(5).sum()
+            };
+            let fundamental = around(freq);
+            if fundamental <= 0.0 {
+                return None;
+            }
+            let harmonics: f32 = (2..=5).map(|h| around(freq * h as f32)).sum();
+
+            let k0 = (10.0 * freq / freq_res).round() as usize;
+            let k1 = ((RESIDUE_MAX_HZ / freq_res) as usize).min(power.len());
+            let residue: f32 = power.get(k0..k1).map(|p| p.iter().sum()).unwrap_or(0.0);
+
+            let to_db = |p: f32| if p > 0.0 { 10.0 * (p / fundamental).log10() } else { -100.0 };
+            Some(BurstDistortion {
+                freq_hz: freq,
+                thd_db: to_db(harmonics),
+                residue_db: to_db(residue),
+            })
+        })
+        .collect()
+}
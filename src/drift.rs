@@ -0,0 +1,143 @@
+// ============================================================
+//  drift.rs — Dérive d'horloge entre sortie et entrée
+//
+//  Micro et enceintes sur deux cartes différentes : chacune a son
+//  quartz, et leurs taux « 48 kHz » diffèrent de quelques dizaines
+//  de ppm. Sur une capture de 4 s, 50 ppm font déjà 10 échantillons
+//  à 48 kHz : la capture est une version étirée du signal joué, et
+//  retard comme distance dérivent d'une mesure à l'autre.
+//
+//  Estimation par étirement du sweep : le sweep est découpé en
+//  `SEGMENTS` tronçons, chacun retrouvé dans la capture par
+//  corrélation (pic sub-sample) ; la pente du retard en fonction de
+//  la position donne la dérive. Avec une boucle électrique, sa piste
+//  sert de référence dès `ELECTRICAL_MIN_HZ` (aucun effet acoustique) ;
+//  sinon seule la fin du sweep, au-dessus de `ACOUSTIC_MIN_HZ`, est
+//  utilisée — le retard de groupe des filtres et du grave varierait
+//  d'un tronçon à l'autre. Pics pris sur l'enveloppe de corrélation
+//  (un tronçon est à bande étroite). Un ajustement trop dispersé est
+//  écarté.
+//
+//  Correction : toutes les pistes de la capture (même horloge) sont
+//  rééchantillonnées du rapport inverse (resample::stretch_tracks),
+//  au-delà de `MIN_CORRECTION_SAMPLES` d'écart sur la capture.
+// ============================================================
+
+use anyhow::Result;
+
+use crate::{
+    audio::Capture,
+    dsp::{self, SweepParams, Window},
+    resample,
+};
+
+/// Nombre de tronçons du sweep retrouvés dans la capture.
+const SEGMENTS: usize = 4;
+
+/// Tronçon le plus court exploitable (échantillons).
+const MIN_SEGMENT_LEN: usize = 2_048;
+
+/// Fréquence du début des tronçons : avec la boucle, au-dessus des tronçons
+/// graves dont le lobe de corrélation, trop large, situe mal l'arrivée ; sans
+/// boucle, au-dessus des coupures de filtre.
+pub const ELECTRICAL_MIN_HZ: f32 = 500.0;
+pub const ACOUSTIC_MIN_HZ: f32 = 3_000.0;
+
+/// Dérive au-delà de laquelle l'estimation est jugée aberrante (ppm).
+pub const MAX_DRIFT_PPM: f32 = 1_000.0;
+
+/// Écart résiduel RMS maximal des tronçons autour de la droite (échantillons).
+const MAX_RESIDUAL_SAMPLES: f32 = 0.5;
+
+/// Écart cumulé sur la capture en deçà duquel on ne corrige pas (échantillons).
+pub const MIN_CORRECTION_SAMPLES: f32 = 1.0;
+
+/// Dérive de l'horloge d'entrée par rapport à celle de sortie (ppm) :
+/// positive, l'entrée compte plus d'échantillons que la sortie n'en joue.
+/// `None` si l'ajustement n'est pas fiable.
+pub fn estimate_ppm(track: &[f32], sweep: &[f32], params: &SweepParams, sample_rate: u32, electrical: bool) -> Option<f32> {
+    // Début de la portion utile : f(t) = f0 · k^(t / T)
+    let min_hz = if electrical { ELECTRICAL_MIN_HZ } else { ACOUSTIC_MIN_HZ }.max(params.f_start_hz);
+    if min_hz >= params.f_stop_hz {
+        return None;
+    }
+    let k = params.f_stop_hz / params.f_start_hz;
+    let start_secs = params.duration_s * (min_hz / params.f_start_hz).ln() / k.ln();
+    // Fondus de 50 ms aux deux bouts du sweep écartés
+    let fade = sample_rate as usize / 20;
+    let start = ((start_secs * sample_rate as f32) as usize).max(fade);
+    let end = sweep.len().saturating_sub(fade);
+    let seg_len = end.saturating_sub(start) / SEGMENTS;
+    if seg_len < MIN_SEGMENT_LEN {
+        return None;
+    }
+
+    // Retard global, puis chaque tronçon cherché autour de lui
+    let global = dsp::xcorr_delay(sweep, track, track.len())?.round() as usize;
+    let margin = ((MAX_DRIFT_PPM * 1e-6 * track.len() as f32) as usize).max(64);
+    // Tronçons pondérés (Hann) : lobes secondaires bas, une réflexion
+    // proche ne déplace pas le pic du son direct
+    let window = Window::Hann.coefficients(seg_len);
+    let points: Vec<(f32, f32)> = (0..SEGMENTS)
+        .filter_map(|i| {
+            let a = start + i * seg_len;
+            let segment: Vec<f32> = sweep[a..a + seg_len].iter().zip(&window).map(|(s, w)| s * w).collect();
+            let from = (a + global).checked_sub(margin)?;
+            let region = track.get(from..(a + global + seg_len + margin).min(track.len()))?;
+            let lag = segment_lag(&segment, region, 2 * margin)?;
+            Some(((a + seg_len / 2) as f32, from as f32 + lag - a as f32))
+        })
+        .collect();
+    if points.len() + 1 < SEGMENTS {
+        return None;
+    }
+
+    // Droite des moindres carrés : retard = b + pente · position
+    let n = points.len() as f32;
+    let mean_x = points.iter().map(|p| p.0).sum::<f32>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f32>() / n;
+    let sxx: f32 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    let sxy: f32 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    if sxx <= 0.0 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    let residual = (points.iter().map(|p| (p.1 - mean_y - slope * (p.0 - mean_x)).powi(2)).sum::<f32>() / n).sqrt();
+    let ppm = slope * 1e6;
+    (residual <= MAX_RESIDUAL_SAMPLES && ppm.abs() <= MAX_DRIFT_PPM).then_some(ppm)
+}
+
+/// Position sub-sample de `segment` dans `region` (pic de l'enveloppe de
+/// corrélation, polarité indifférente), retards 0…`max_lag`.
+fn segment_lag(segment: &[f32], region: &[f32], max_lag: usize) -> Option<f32> {
+    let envelope = dsp::correlation_envelope(segment, region)?;
+    let at = |i: usize| envelope.get(i).copied().unwrap_or(0.0);
+    let best = (0..=max_lag.min(envelope.len() - 1)).max_by(|&a, &b| at(a).total_cmp(&at(b)))?;
+    let delta = if best > 0 { dsp::parabolic_interp(at(best - 1), at(best), at(best + 1)) } else { 0.0 };
+    Some(best as f32 + delta)
+}
+
+/// Estime la dérive d'une capture au sweep (piste de boucle si présente,
+/// sinon le mix micro) et la corrige si elle est notable. Retourne la
+/// capture et la dérive corrigée (ppm), `None` si rien n'a été corrigé.
+pub fn correct(capture: Capture, sweep: &[f32], params: &SweepParams, sample_rate: u32) -> Result<(Capture, Option<f32>)> {
+    let ppm = match &capture.loopback {
+        Some(loopback) => estimate_ppm(loopback, sweep, params, sample_rate, true),
+        None => estimate_ppm(&capture.mono, sweep, params, sample_rate, false),
+    };
+    let Some(ppm) = ppm else { return Ok((capture, None)) };
+    if (ppm * 1e-6 * capture.mono.len() as f32).abs() < MIN_CORRECTION_SAMPLES {
+        return Ok((capture, None));
+    }
+
+    // Entrée trop rapide de (1 + ε) → capture ramenée au rythme de la sortie
+    let ratio = 1.0 / (1.0 + ppm as f64 * 1e-6);
+    let capture = capture.map_tracks(|tracks| resample::stretch_tracks(tracks, ratio))?;
+    Ok((capture, Some(ppm)))
+}
+
+/// Moyenne des dérives corrigées sur plusieurs cycles, `None` si aucune.
+pub fn mean(drifts: &[Option<f32>]) -> Option<f32> {
+    let corrected: Vec<f32> = drifts.iter().flatten().copied().collect();
+    (!corrected.is_empty()).then(|| corrected.iter().sum::<f32>() / corrected.len() as f32)
+}
@@ -1,27 +1,133 @@
 // ============================================================
 //  dsp.rs — Traitement du signal audio
 //
-//  - FFT via rustfft (O(n log n), fenêtre de Hann)
+//  - FFT via rustfft (O(n log n), fenêtre de Hann par défaut)
 //  - Découpage log en bandes (20 Hz – 20 kHz)
-//  - RMS, corrélation croisée pour le délai
+//  - RMS, corrélation croisée pour le délai (IR de Farina, GCC-PHAT)
 //  - Score global (fréquence + niveau + temps)
 // ============================================================
 
-use rustfft::{FftPlanner, num_complex::Complex};
-use std::f32::consts::PI;
+use rand::Rng;
+use rustfft::{Fft, FftPlanner, num_complex::Complex};
+use serde::{Deserialize, Serialize};
+use std::{
+    f32::consts::PI,
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+};
+
+use crate::{scoring::ScoreWeights, strings::tr};
 
 pub const SAMPLE_RATE: u32 = 48_000;
 pub const FFT_SIZE: usize = 8_192;
 pub const NUM_BANDS: usize = 128;
-pub const SWEEP_DURATION: f32 = 3.0;
-pub const CAPTURE_DURATION: f32 = 4.0;
+/// Capture prolongée au-delà du sweep : latence système + queue de réverbération.
+pub const CAPTURE_TAIL_SECS: f32 = 1.0;
 
 // ─── Génération du sweep sinusoïdal logarithmique ────────────────────────────
 
-pub fn generate_sweep(sample_rate: u32, duration: f32) -> Vec<f32> {
+/// Paramètres du sweep de mesure. Un sweep plus long améliore le rapport
+/// signal/bruit de la déconvolution (+3 dB par doublement de durée).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SweepParams {
+    pub duration_s: f32,
+    pub f_start_hz: f32,
+    pub f_stop_hz: f32,
+    /// Crête du sweep (pleine échelle = 1.0).
+    pub amplitude: f32,
+    /// Nombre de sweeps joués et moyennés par capture (+3 dB de rapport
+    /// signal/bruit par doublement).
+    pub averages: u32,
+    /// Silence entre le sweep gauche et le sweep droit d'une mesure
+    /// enchaînée [Tab] (s).
+    pub gap_s: f32,
+}
+
+impl Default for SweepParams {
+    fn default() -> Self {
+        SweepParams {
+            duration_s: 3.0,
+            f_start_hz: 20.0,
+            f_stop_hz: 20_000.0,
+            amplitude: 0.7,
+            averages: 1,
+            gap_s: 1.0,
+        }
+    }
+}
+
+impl SweepParams {
+    pub const MIN_DURATION_S: f32 = 1.0;
+    pub const MAX_DURATION_S: f32 = 30.0;
+    pub const MIN_START_HZ: f32 = 10.0;
+    pub const MAX_STOP_HZ: f32 = 20_000.0;
+    pub const MIN_AMPLITUDE: f32 = 0.05;
+    pub const MAX_AVERAGES: u32 = 16;
+    /// La queue captée après le sweep gauche ne doit pas atteindre le droit.
+    pub const MIN_GAP_S: f32 = CAPTURE_TAIL_SECS;
+    pub const MAX_GAP_S: f32 = 10.0;
+
+    /// Durée de capture nécessaire pour ce sweep.
+    pub fn capture_secs(&self) -> f32 {
+        self.duration_s + CAPTURE_TAIL_SECS
+    }
+
+    /// Début du sweep droit d'une mesure enchaînée, relatif au gauche (s).
+    pub fn pair_offset_secs(&self) -> f32 {
+        self.duration_s + self.gap_s
+    }
+
+    /// Durée de capture d'une mesure enchaînée G → D.
+    pub fn pair_capture_secs(&self) -> f32 {
+        self.pair_offset_secs() + self.capture_secs()
+    }
+
+    /// Crête en dBFS.
+    pub fn amplitude_dbfs(&self) -> f32 {
+        20.0 * self.amplitude.log10()
+    }
+
+    /// Ramène chaque paramètre dans sa plage valide (au moins une octave
+    /// entre début et fin).
+    pub fn clamped(self) -> Self {
+        let f_stop_hz = self.f_stop_hz.clamp(Self::MIN_START_HZ * 2.0, Self::MAX_STOP_HZ);
+        SweepParams {
+            duration_s: self.duration_s.clamp(Self::MIN_DURATION_S, Self::MAX_DURATION_S),
+            f_start_hz: self.f_start_hz.clamp(Self::MIN_START_HZ, f_stop_hz / 2.0),
+            f_stop_hz,
+            amplitude: self.amplitude.clamp(Self::MIN_AMPLITUDE, 1.0),
+            averages: self.averages.clamp(1, Self::MAX_AVERAGES),
+            gap_s: self.gap_s.clamp(Self::MIN_GAP_S, Self::MAX_GAP_S),
+        }
+    }
+
+    /// Erreur explicite si un paramètre est hors plage (fichier de configuration).
+    pub fn validate(&self) -> Result<(), String> {
+        if *self == self.clamped() {
+            Ok(())
+        } else {
+            Err((tr().sweep_out_of_range)(
+                Self::MIN_DURATION_S,
+                Self::MAX_DURATION_S,
+                Self::MIN_START_HZ,
+                Self::MAX_STOP_HZ,
+                Self::MIN_AMPLITUDE,
+                Self::MAX_AVERAGES,
+                Self::MIN_GAP_S,
+                Self::MAX_GAP_S
+            ))
+        }
+    }
+}
+
+pub fn generate_sweep(sample_rate: u32, params: &SweepParams) -> Vec<f32> {
+    let duration = params.duration_s;
     let len = (duration * sample_rate as f32) as usize;
-    let f0: f32 = 20.0;
-    let f1: f32 = 20_000.0;
+    let f0 = params.f_start_hz;
+    let f1 = params.f_stop_hz;
     let k = f1 / f0;
     let mut buf = Vec::with_capacity(len);
 
@@ -29,15 +135,221 @@ pub fn generate_sweep(sample_rate: u32, duration: f32) -> Vec<f32> {
         let t = i as f32 / sample_rate as f32;
         let phase = 2.0 * PI * f0 * duration / k.ln() * (k.powf(t / duration) - 1.0);
         let env = (t * 20.0).min(1.0) * ((duration - t) * 20.0).min(1.0);
-        buf.push(phase.sin() * 0.7 * env);
+        buf.push(phase.sin() * params.amplitude * env);
     }
     buf
 }
 
 
-// ─── FFT avec fenêtre de Hann, moyennée sur les segments ─────────────────────
+// ─── Bruit blanc et bi-ton (vérification rapide) ─────────────────────────────
+
+/// Bruit blanc pleine bande, crête 0,5, fondus de 10 ms.
+pub fn generate_white_noise(sample_rate: u32, duration: f32) -> Vec<f32> {
+    let mut rng = rand::thread_rng();
+    let noise: Vec<f32> = (0..(sample_rate as f32 * duration) as usize)
+        .map(|_| rng.gen_range(-1.0f32..1.0))
+        .collect();
+    with_fades(noise, sample_rate, 0.5)
+}
+
+/// Bruit rose (−3 dB / octave, filtre de Paul Kellet), crête `peak`, fondus
+/// de 10 ms.
+pub fn generate_pink_noise(sample_rate: u32, duration: f32, peak: f32) -> Vec<f32> {
+    let mut rng = rand::thread_rng();
+    let mut b = [0.0f32; 7];
+    let noise: Vec<f32> = (0..(sample_rate as f32 * duration) as usize)
+        .map(|_| {
+            let white = rng.gen_range(-1.0f32..1.0);
+            b[0] = 0.99886 * b[0] + white * 0.0555179;
+            b[1] = 0.99332 * b[1] + white * 0.0750759;
+            b[2] = 0.96900 * b[2] + white * 0.153852;
+            b[3] = 0.86650 * b[3] + white * 0.3104856;
+            b[4] = 0.55000 * b[4] + white * 0.5329522;
+            b[5] = -0.7616 * b[5] - white * 0.0168980;
+            let pink = b[..6].iter().sum::<f32>() + b[6] + white * 0.5362;
+            b[6] = white * 0.115926;
+            pink
+        })
+        .collect();
+    with_fades(noise, sample_rate, peak)
+}
+
+/// Somme de deux sinus d'égale amplitude (`f_lo` + `f_hi`), crête 0,5.
+pub fn generate_dual_tone(sample_rate: u32, duration: f32, f_lo: f32, f_hi: f32) -> Vec<f32> {
+    let tone: Vec<f32> = (0..(sample_rate as f32 * duration) as usize)
+        .map(|i| {
+            let t = i as f32 / sample_rate as f32;
+            (2.0 * PI * f_lo * t).sin() + (2.0 * PI * f_hi * t).sin()
+        })
+        .collect();
+    with_fades(tone, sample_rate, 0.5)
+}
 
-pub fn compute_fft(samples: &[f32]) -> Vec<f32> {
+/// Normalise à `peak` et applique des fondus de 10 ms (pas de clic).
+fn with_fades(mut samples: Vec<f32>, sample_rate: u32, peak: f32) -> Vec<f32> {
+    let len = samples.len();
+    let max = samples.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    let fade = ((0.010 * sample_rate as f32) as usize).max(1);
+    for (i, v) in samples.iter_mut().enumerate() {
+        let env = (i as f32 / fade as f32).min(1.0) * ((len - 1 - i) as f32 / fade as f32).min(1.0);
+        *v = if max > 0.0 { *v / max * peak * env } else { 0.0 };
+    }
+    samples
+}
+
+// ─── Bruit blanc filtré en bande ─────────────────────────────────────────────
+//
+// Bruit blanc uniforme, filtré par masquage des bins FFT hors [f_lo, f_hi],
+// normalisé à une crête de 0.5 avec fondus d'entrée/sortie de 10 ms.
+
+pub fn generate_band_noise(sample_rate: u32, duration: f32, f_lo: f32, f_hi: f32) -> Vec<f32> {
+    let len = (duration * sample_rate as f32) as usize;
+    if len == 0 {
+        return Vec::new();
+    }
+    let mut rng = rand::thread_rng();
+    let mut buf: Vec<Complex<f32>> = (0..len)
+        .map(|_| Complex::new(rng.gen_range(-1.0f32..1.0), 0.0))
+        .collect();
+
+    let mut planner = FftPlanner::<f32>::new();
+    planner.plan_fft_forward(len).process(&mut buf);
+    let freq_res = sample_rate as f32 / len as f32;
+    for (k, c) in buf.iter_mut().enumerate() {
+        // Bins négatifs repliés sur leur fréquence positive
+        let f = k.min(len - k) as f32 * freq_res;
+        if f < f_lo || f > f_hi {
+            *c = Complex::new(0.0, 0.0);
+        }
+    }
+    planner.plan_fft_inverse(len).process(&mut buf);
+
+    let mut out: Vec<f32> = buf.iter().map(|c| c.re).collect();
+    let peak = out.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    let fade = (0.010 * sample_rate as f32) as usize;
+    for (i, v) in out.iter_mut().enumerate() {
+        let env = (i as f32 / fade as f32).min(1.0) * ((len - 1 - i) as f32 / fade as f32).min(1.0);
+        *v = if peak > 0.0 { *v / peak * 0.5 * env } else { 0.0 };
+    }
+    out
+}
+
+// ─── Clic filtré en bande ────────────────────────────────────────────────────
+//
+// Impulsion à phase nulle limitée à [f_lo, f_hi] (masquage FFT), centrée dans
+// le buffer et fenêtrée (Hann) : crête POSITIVE au centre, ce qui permet de
+// lire la polarité d'un haut-parleur sur le signe de la réponse.
+
+pub fn generate_band_click(sample_rate: u32, duration: f32, f_lo: f32, f_hi: f32) -> Vec<f32> {
+    let len = (duration * sample_rate as f32) as usize;
+    if len < 2 {
+        return Vec::new();
+    }
+    let mut buf = vec![Complex::new(0.0f32, 0.0); len];
+    buf[len / 2] = Complex::new(1.0, 0.0);
+
+    let mut planner = FftPlanner::<f32>::new();
+    planner.plan_fft_forward(len).process(&mut buf);
+    let freq_res = sample_rate as f32 / len as f32;
+    for (k, c) in buf.iter_mut().enumerate() {
+        let f = k.min(len - k) as f32 * freq_res;
+        if f < f_lo || f > f_hi {
+            *c = Complex::new(0.0, 0.0);
+        }
+    }
+    planner.plan_fft_inverse(len).process(&mut buf);
+
+    let mut out: Vec<f32> = buf
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            let w = 0.5 - 0.5 * (2.0 * PI * i as f32 / (len - 1) as f32).cos();
+            c.re * w
+        })
+        .collect();
+    let peak = out.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    if peak > 0.0 {
+        out.iter_mut().for_each(|v| *v *= 0.8 / peak);
+    }
+    out
+}
+
+// ─── Fenêtres de pondération ─────────────────────────────────────────────────
+//
+// Fenêtres en somme de cosinus : w(i) = a0 − a1·cos(x) + a2·cos(2x) − …,
+// x = 2π·i / (n − 1). Chacune atténue l'amplitude d'un sinus de son gain
+// cohérent (moyenne des coefficients) : `compute_fft` le compense, si bien
+// qu'un sinus lit la même amplitude quelle que soit la fenêtre — à l'échelle
+// historique de la fenêtre de Hann, pour que les niveaux déjà enregistrés
+// (sessions, historique) restent comparables.
+//   - Hann : compromis résolution / fuite, le choix par défaut ;
+//   - Blackman-Harris (4 termes) : fuite à −92 dB, pour un spectre à forte
+//     dynamique (bruit de fond sous un grave puissant) ;
+//   - Flat-top : erreur d'amplitude < 0,02 dB même entre deux bins, pour
+//     lire le niveau d'un sinus ; lobe principal large (±5 bins) ;
+//   - Rectangulaire : aucune pondération, exacte seulement sur un signal
+//     périodique dont la période tient dans la trame (bruit rose
+//     périodique, moyenne synchrone), où toute fenêtre fausserait le bin.
+
+/// Fenêtre appliquée à chaque trame de `compute_fft`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Window {
+    #[default]
+    Hann,
+    BlackmanHarris,
+    FlatTop,
+    Rectangular,
+}
+
+impl Window {
+    /// Ordre de présentation (noms : `strings::window_names`).
+    pub const ALL: [Window; 4] = [Window::Hann, Window::BlackmanHarris, Window::FlatTop, Window::Rectangular];
+
+    pub fn index(self) -> usize {
+        Window::ALL.iter().position(|&w| w == self).unwrap_or(0)
+    }
+
+    /// Fenêtre suivante de `ALL`.
+    pub fn next(self) -> Window {
+        Window::ALL[(self.index() + 1) % Window::ALL.len()]
+    }
+
+    /// Coefficients a0, a1… de la somme de cosinus.
+    fn cosine_terms(self) -> &'static [f32] {
+        match self {
+            Window::Hann => &[0.5, 0.5],
+            Window::BlackmanHarris => &[0.358_75, 0.488_29, 0.141_28, 0.011_68],
+            Window::FlatTop => &[0.215_578_95, 0.416_631_58, 0.277_263_16, 0.083_578_95, 0.006_947_37],
+            Window::Rectangular => &[1.0],
+        }
+    }
+
+    /// Pondération de `n` échantillons.
+    pub fn coefficients(self, n: usize) -> Vec<f32> {
+        let terms = self.cosine_terms();
+        (0..n)
+            .map(|i| {
+                let x = 2.0 * PI * i as f32 / n.saturating_sub(1).max(1) as f32;
+                terms
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &a)| if k % 2 == 0 { a } else { -a } * (k as f32 * x).cos())
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+/// Gain cohérent de la fenêtre de Hann, échelle de référence des spectres.
+const REFERENCE_GAIN: f32 = 0.5;
+
+// ─── FFT fenêtrée, moyennée sur les segments ─────────────────────────────────
+//
+// Renvoie des zéros si `samples` est plus court que FFT_SIZE : les captures
+// doivent être validées en amont (`audio::check_capture_length`).
+
+pub fn compute_fft(samples: &[f32], window: Window) -> Vec<f32> {
     let n = FFT_SIZE;
     let half = n / 2;
     let num_segments = samples.len() / n;
@@ -49,10 +361,10 @@ pub fn compute_fft(samples: &[f32]) -> Vec<f32> {
     let mut planner = FftPlanner::<f32>::new();
     let fft = planner.plan_fft_forward(n);
 
-    // Fenêtre de Hann précalculée
-    let window: Vec<f32> = (0..n)
-        .map(|i| 0.5 * (1.0 - (2.0 * PI * i as f32 / (n - 1) as f32).cos()))
-        .collect();
+    // Pondération précalculée, ramenée au gain cohérent de Hann
+    let mut window = window.coefficients(n);
+    let gain = window.iter().sum::<f32>() / n as f32;
+    window.iter_mut().for_each(|w| *w *= REFERENCE_GAIN / gain);
 
     let mut spectrum = vec![0.0f32; half];
 
@@ -64,9 +376,8 @@ pub fn compute_fft(samples: &[f32]) -> Vec<f32> {
 
         fft.process(&mut buf);
 
-        for k in 0..half {
-            let mag = buf[k].norm() / n as f32;
-            spectrum[k] += mag;
+        for (acc, c) in spectrum.iter_mut().zip(buf.iter()) {
+            *acc += c.norm() / n as f32;
         }
     }
 
@@ -78,6 +389,142 @@ pub fn compute_fft(samples: &[f32]) -> Vec<f32> {
     spectrum
 }
 
+// ─── Bruit rose périodique et moyenne spectrale au fil de l'eau ──────────────
+//
+// Une période de FFT_SIZE échantillons est synthétisée dans le domaine
+// fréquentiel (amplitude exactement en 1/√f, phases aléatoires) puis répétée.
+// Toute tranche de FFT_SIZE échantillons du régime établi contient alors une
+// période entière : FFT sans fenêtre, sans fuite spectrale, et chaque bin
+// reçoit la même énergie à chaque période. `SpectrumAccumulator` ajoute la
+// puissance de chaque période complète à mesure que les échantillons
+// arrivent, sans garder les tranches.
+
+/// Bruit rose périodique (période `FFT_SIZE`), crête `peak`, fondus de 10 ms.
+pub fn generate_periodic_pink_noise(sample_rate: u32, duration: f32, peak: f32) -> Vec<f32> {
+    let n = FFT_SIZE;
+    let mut rng = rand::thread_rng();
+    let mut spectrum = vec![Complex::new(0.0f32, 0.0); n];
+    for k in 1..n / 2 {
+        let phase = rng.gen_range(0.0..2.0 * PI);
+        let bin = Complex::from_polar(1.0 / (k as f32).sqrt(), phase);
+        spectrum[k] = bin;
+        spectrum[n - k] = bin.conj();
+    }
+    FftPlanner::<f32>::new().plan_fft_inverse(n).process(&mut spectrum);
+    let period: Vec<f32> = spectrum.iter().map(|c| c.re).collect();
+    let len = (sample_rate as f32 * duration) as usize;
+    with_fades(period.iter().copied().cycle().take(len).collect(), sample_rate, peak)
+}
+
+/// Deux bruits roses périodiques (période `period`) décorrélés par
+/// construction : la gauche n'occupe que les bins pairs, la droite que les
+/// impairs. Joués ensemble, leurs fonctions de transfert se séparent
+/// exactement, bin par bin (`dualnoise`). Crête `peak` chacun, fondus de 10 ms.
+pub fn generate_interleaved_pink_pair(sample_rate: u32, period: usize, duration: f32, peak: f32) -> (Vec<f32>, Vec<f32>) {
+    let mut rng = rand::thread_rng();
+    let mut spectra = [vec![Complex::new(0.0f32, 0.0); period], vec![Complex::new(0.0f32, 0.0); period]];
+    for k in 1..period / 2 {
+        let phase = rng.gen_range(0.0..2.0 * PI);
+        let bin = Complex::from_polar(1.0 / (k as f32).sqrt(), phase);
+        let spectrum = &mut spectra[k % 2];
+        spectrum[k] = bin;
+        spectrum[period - k] = bin.conj();
+    }
+    let ifft = FftPlanner::<f32>::new().plan_fft_inverse(period);
+    let len = (sample_rate as f32 * duration) as usize;
+    let [left, right] = spectra.map(|mut spectrum| {
+        ifft.process(&mut spectrum);
+        let cycle: Vec<f32> = spectrum.iter().map(|c| c.re).cycle().take(len).collect();
+        with_fades(cycle, sample_rate, peak)
+    });
+    (left, right)
+}
+
+/// Moyenne en puissance des spectres de tranches consécutives de
+/// `FFT_SIZE` échantillons (`Window::Rectangular` : signal périodique).
+pub struct SpectrumAccumulator {
+    fft: Arc<dyn Fft<f32>>,
+    pending: Vec<f32>,
+    power: Vec<f64>,
+    blocks: usize,
+}
+
+impl Default for SpectrumAccumulator {
+    fn default() -> Self {
+        SpectrumAccumulator {
+            fft: FftPlanner::<f32>::new().plan_fft_forward(FFT_SIZE),
+            pending: Vec::with_capacity(FFT_SIZE),
+            power: vec![0.0; FFT_SIZE / 2],
+            blocks: 0,
+        }
+    }
+}
+
+impl SpectrumAccumulator {
+    /// Ajoute des échantillons ; chaque tranche complète est analysée aussitôt.
+    pub fn push(&mut self, samples: &[f32]) {
+        for chunk in samples.chunks(FFT_SIZE) {
+            let take = (FFT_SIZE - self.pending.len()).min(chunk.len());
+            self.pending.extend_from_slice(&chunk[..take]);
+            if self.pending.len() == FFT_SIZE {
+                self.add_block();
+            }
+            self.pending.extend_from_slice(&chunk[take..]);
+        }
+    }
+
+    fn add_block(&mut self) {
+        let mut buf: Vec<Complex<f32>> = self.pending.drain(..).map(|x| Complex::new(x, 0.0)).collect();
+        self.fft.process(&mut buf);
+        for (acc, c) in self.power.iter_mut().zip(&buf) {
+            *acc += c.norm_sqr() as f64;
+        }
+        self.blocks += 1;
+    }
+
+    /// Tranches complètes analysées.
+    pub fn blocks(&self) -> usize {
+        self.blocks
+    }
+
+    /// Spectre d'amplitudes moyen (moyenne quadratique, divisée par
+    /// `FFT_SIZE`) ; des zéros tant qu'aucune tranche n'est complète.
+    pub fn spectrum(&self) -> Vec<f32> {
+        let n = FFT_SIZE as f64;
+        self.power
+            .iter()
+            .map(|&p| if self.blocks > 0 { ((p / self.blocks as f64).sqrt() / n) as f32 } else { 0.0 })
+            .collect()
+    }
+}
+
+// ─── Lissage en fraction d'octave ────────────────────────────────────────────
+//
+// Chaque bin prend la moyenne en puissance des bins situés à moins d'un
+// demi-1/N d'octave de part et d'autre : largeur constante sur l'axe log,
+// le grave garde son détail et l'aigu perd son hachis. L'indice d'un bin
+// étant proportionnel à sa fréquence, la fenêtre se calcule sur les indices.
+
+/// Spectre d'amplitudes lissé au 1/`fraction` d'octave ; 0 = inchangé.
+pub fn smooth_spectrum(spectrum: &[f32], fraction: u32) -> Vec<f32> {
+    if fraction == 0 || spectrum.is_empty() {
+        return spectrum.to_vec();
+    }
+    let half = 2f32.powf(0.5 / fraction as f32);
+    // Sommes cumulées des puissances : moyenne de chaque fenêtre en O(1)
+    let mut cumul = vec![0.0f64; spectrum.len() + 1];
+    for (k, &v) in spectrum.iter().enumerate() {
+        cumul[k + 1] = cumul[k] + (v as f64).powi(2);
+    }
+    (0..spectrum.len())
+        .map(|k| {
+            let lo = ((k as f32 / half).round() as usize).min(k);
+            let hi = ((k as f32 * half).round() as usize).clamp(k, spectrum.len() - 1);
+            ((cumul[hi + 1] - cumul[lo]) / (hi + 1 - lo) as f64).sqrt() as f32
+        })
+        .collect()
+}
+
 // ─── Découpage du spectre en bandes logarithmiques ───────────────────────────
 
 pub fn spectrum_to_bands(spectrum: &[f32], sample_rate: u32, num_bands: usize) -> Vec<f32> {
@@ -86,18 +533,18 @@ pub fn spectrum_to_bands(spectrum: &[f32], sample_rate: u32, num_bands: usize) -
     let log_max = 20_000f32.log10();
     let mut bands = vec![0.0f32; num_bands];
 
-    for b in 0..num_bands {
+    for (b, band) in bands.iter_mut().enumerate() {
         let f0 = 10f32.powf(log_min + (log_max - log_min) * b as f32 / num_bands as f32);
         let f1 = 10f32.powf(log_min + (log_max - log_min) * (b + 1) as f32 / num_bands as f32);
         let k0 = ((f0 / freq_res) as usize).max(1);
         let k1 = ((f1 / freq_res).ceil() as usize).min(spectrum.len() - 1);
 
         let (mut sum, mut count) = (0.0f32, 0usize);
-        for k in k0..=k1 {
-            sum += spectrum[k];
+        for &v in spectrum.iter().take(k1 + 1).skip(k0) {
+            sum += v;
             count += 1;
         }
-        bands[b] = if count > 0 { sum / count as f32 } else { 0.0 };
+        *band = if count > 0 { sum / count as f32 } else { 0.0 };
     }
     bands
 }
@@ -111,6 +558,29 @@ pub fn bands_to_db(bands: &[f32]) -> Vec<f32> {
         .collect()
 }
 
+// ─── Niveaux par octave ──────────────────────────────────────────────────────
+//
+// Grille grossière utilisée par les tests de niveau (compression, linéarité) :
+// la résolution de 128 bandes y est inutile et plus sensible au bruit.
+
+pub const OCTAVE_CENTERS: [f32; 8] = [63.0, 125.0, 250.0, 500.0, 1_000.0, 2_000.0, 4_000.0, 8_000.0];
+
+/// Niveau (dB) de chaque octave de `OCTAVE_CENTERS` : puissance des bins FFT
+/// entre fc/√2 et fc·√2.
+pub fn octave_levels_db(samples: &[f32], sample_rate: u32) -> Vec<f32> {
+    let power: Vec<f32> = compute_fft(samples, Window::Hann).iter().map(|m| m * m).collect();
+    let freq_res = sample_rate as f32 / FFT_SIZE as f32;
+    OCTAVE_CENTERS
+        .iter()
+        .map(|&fc| {
+            let k0 = ((fc / 2f32.sqrt() / freq_res) as usize).max(1);
+            let k1 = ((fc * 2f32.sqrt() / freq_res) as usize).min(power.len());
+            let p: f32 = power.get(k0..k1).map(|p| p.iter().sum()).unwrap_or(0.0);
+            if p > 0.0 { 10.0 * p.log10() } else { -100.0 }
+        })
+        .collect()
+}
+
 // ─── Filtre passe-haut (IIR 1er ordre) ───────────────────────────────────────
 //
 // Élimine le bruit de ronflement ambiant (ventilateurs, vibrations sol/bureau)
@@ -144,7 +614,7 @@ pub fn compute_rms(samples: &[f32]) -> f32 {
 // Fit une parabole sur 3 points autour du pic pour obtenir une résolution
 // ~0.1 sample → ~0.7 mm à 48 kHz.
 
-fn parabolic_interp(y_minus: f32, y_center: f32, y_plus: f32) -> f32 {
+pub(crate) fn parabolic_interp(y_minus: f32, y_center: f32, y_plus: f32) -> f32 {
     let denom = y_minus - 2.0 * y_center + y_plus;
     if denom.abs() < 1e-12 {
         return 0.0;
@@ -153,20 +623,32 @@ fn parabolic_interp(y_minus: f32, y_center: f32, y_plus: f32) -> f32 {
 }
 
 
-// ─── Distance absolue d'une enceinte par déconvolution sweep ────────────────
-//
-// Retourne la distance estimée enceinte→micro en mètres.
-// La valeur inclut la latence système (buffer DAC+ADC), constante pour les
-// deux canaux → la DIFFÉRENCE gauche/droite est acoustiquement juste.
+// ─── Réponse impulsionnelle par déconvolution du sweep ──────────────────────
 //
-// Algorithme :
-//   1. IR = FFT(capture) * FFT(inverse_sweep)⁻¹ → réponse impulsionnelle
-//   2. Seuil = 10 % du maximum de l'IR sur une fenêtre réaliste
-//   3. Premier passage au-dessus du seuil = arrivée du son direct
-//   4. Interpolation parabolique sub-sample pour la précision
+// IR = FFT(capture) * FFT(inverse_sweep)⁻¹ (méthode de Farina).
+// L'indice 0 de l'IR retournée correspond au démarrage de la lecture du sweep ;
+// elle couvre capture.len() échantillons. Le signe est conservé (polarité).
 
-pub fn compute_speaker_distance(capture: &[f32], sweep: &[f32], sample_rate: u32, pre_delay_samples: usize) -> Option<f32> {
+pub fn compute_impulse_response(capture: &[f32], sweep: &[f32], params: &SweepParams, sample_rate: u32) -> Vec<f32> {
+    // La convolution linéaire de capture (N) avec inverse_sweep (M) produit son pic
+    // à l'indice (M-1) + pre_delay + latence_système + t_travel dans l'IR.
+    // On décale la fenêtre de (sweep_len-1) puis on garde tout capture.len()
+    // pour couvrir n'importe quel pre_delay ou latence système.
+    let full = deconvolve_sweep(capture, sweep, params, sample_rate);
+    let offset = sweep.len().saturating_sub(1);
+    let end = (offset + capture.len()).min(full.len());
+    full.get(offset..end).map(<[f32]>::to_vec).unwrap_or_default()
+}
+
+/// Convolution linéaire complète de `capture` par le filtre inverse du sweep
+/// (longueur capture + sweep − 1). Avant le pic de l'IR linéaire se trouvent
+/// les IR des harmoniques (`harmonics.rs`), que `compute_impulse_response`
+/// écarte.
+pub fn deconvolve_sweep(capture: &[f32], sweep: &[f32], params: &SweepParams, sample_rate: u32) -> Vec<f32> {
     let sweep_len = sweep.len();
+    if sweep_len == 0 || capture.is_empty() {
+        return Vec::new();
+    }
     let total_len = capture.len() + sweep_len;
     let fft_len = total_len.next_power_of_two();
 
@@ -175,8 +657,8 @@ pub fn compute_speaker_distance(capture: &[f32], sweep: &[f32], sample_rate: u32
     let fft_inv = planner.plan_fft_inverse(fft_len);
 
     // Filtre inverse du sweep log (time-reverse + compensation d'amplitude)
-    let f0: f32 = 20.0;
-    let f1: f32 = 20_000.0;
+    let f0 = params.f_start_hz;
+    let f1 = params.f_stop_hz;
     let duration = sweep_len as f32 / sample_rate as f32;
     let rate = (f1 / f0).ln() / duration;
 
@@ -209,90 +691,941 @@ pub fn compute_speaker_distance(capture: &[f32], sweep: &[f32], sample_rate: u32
     fft_inv.process(&mut ir_buf);
 
     let inv_n = 1.0 / fft_len as f32;
+    ir_buf[..total_len - 1].iter().map(|c| c.re * inv_n).collect()
+}
 
-    // La convolution linéaire de capture (N) avec inverse_sweep (M) produit son pic
-    // à l'indice (M-1) + pre_delay + latence_système + t_travel dans l'IR.
-    // On décale la fenêtre de (sweep_len-1) puis on cherche sur tout capture.len()
-    // pour couvrir n'importe quel pre_delay ou latence système.
-    let offset = sweep_len - 1;
+// ─── Réponse fenêtrée (quasi anéchoïque) ─────────────────────────────────────
+//
+// Les premières millisecondes de l'IR ne contiennent que le son direct :
+// leur spectre est celui de l'enceinte, sans la pièce. Une fenêtre de T ms
+// ne résout plus rien sous ~2,5 / T (500 Hz pour 5 ms) ; en dessous, le
+// spectre complet est gardé, avec un fondu d'une octave entre les deux.
+
+/// Marge gardée avant le front du son direct (ms).
+const GATE_PRE_MS: f32 = 0.5;
+/// Périodes contenues dans la fenêtre à la fréquence de raccord.
+const GATE_CYCLES: f32 = 2.5;
+/// Plancher de la division spectrale, relatif au maximum du signal joué.
+const GATE_REGULARIZATION: f32 = 1e-4;
+
+/// Fréquence sous laquelle une fenêtre de `gate_ms` ne résout plus la
+/// réponse (Hz).
+pub fn gate_low_hz(gate_ms: f32) -> f32 {
+    GATE_CYCLES * 1000.0 / gate_ms
+}
 
-    let search_start = offset;
-    let search_end = (offset + capture.len()).min(ir_buf.len());
+/// Module du spectre de `ir` fenêtrée sur `gate_ms` à partir de `onset`
+/// (`GATE_PRE_MS` avant, montée et descente en demi-Hann), sur les bins de
+/// `compute_fft`.
+fn gate_spectrum(ir: &[f32], onset: usize, sample_rate: u32, gate_ms: f32) -> Vec<f32> {
+    let samples = |ms: f32| (ms / 1000.0 * sample_rate as f32) as usize;
+    let pre = samples(GATE_PRE_MS).min(onset);
+    let start = onset - pre;
+    let len = (pre + samples(gate_ms)).min(ir.len().saturating_sub(start)).min(FFT_SIZE);
+    // Descente sur le dernier quart de la fenêtre
+    let fall = (len / 4).max(1);
+    let taper = |i: usize| {
+        if i < pre {
+            0.5 - 0.5 * (PI * i as f32 / pre as f32).cos()
+        } else if i + fall >= len {
+            0.5 + 0.5 * (PI * (i + fall - len) as f32 / fall as f32).cos()
+        } else {
+            1.0
+        }
+    };
+    let mut buf: Vec<Complex<f32>> = (0..FFT_SIZE)
+        .map(|i| Complex::new(if i < len { ir[start + i] * taper(i) } else { 0.0 }, 0.0))
+        .collect();
+    FftPlanner::<f32>::new().plan_fft_forward(FFT_SIZE).process(&mut buf);
+    buf[..FFT_SIZE / 2].iter().map(|c| c.norm()).collect()
+}
 
-    if search_start >= search_end {
-        return None;
+/// IR plate : division spectrale de la capture par le signal joué,
+/// régularisée là où il n'a pas d'énergie (hors de la bande du sweep). Mêmes
+/// indices que `compute_impulse_response`, sans la pente de son filtre
+/// inverse — qui, fenêtrée court, noierait les aigus sous la fuite du grave.
+fn flat_impulse_response(capture: &[f32], sweep: &[f32]) -> Vec<f32> {
+    let fft_len = (capture.len() + sweep.len()).next_power_of_two();
+    let mut planner = FftPlanner::<f32>::new();
+    let fft_fwd = planner.plan_fft_forward(fft_len);
+    let spectrum = |x: &[f32]| {
+        let mut buf: Vec<Complex<f32>> = x
+            .iter()
+            .map(|&v| Complex::new(v, 0.0))
+            .chain(std::iter::repeat_n(Complex::new(0.0, 0.0), fft_len - x.len()))
+            .collect();
+        fft_fwd.process(&mut buf);
+        buf
+    };
+    let (y, x) = (spectrum(capture), spectrum(sweep));
+    let floor = x.iter().map(|c| c.norm_sqr()).fold(0.0, f32::max) * GATE_REGULARIZATION;
+    let mut h: Vec<Complex<f32>> = y.iter().zip(&x).map(|(y, x)| y * x.conj() / (x.norm_sqr() + floor)).collect();
+    planner.plan_fft_inverse(fft_len).process(&mut h);
+    h[..capture.len()].iter().map(|c| c.re / fft_len as f32).collect()
+}
+
+/// Réponse du son direct seul : les `gate_ms` qui suivent le front de `ir`
+/// dans l'IR plate de `capture`, mise à la forme du spectre du signal joué
+/// tel que `compute_fft` (fenêtre `window`) le voit dans la capture — placé
+/// au même front, donc découpé dans les mêmes segments. `None` sans front.
+pub fn gated_response(
+    ir: &[f32],
+    capture: &[f32],
+    sweep: &[f32],
+    sample_rate: u32,
+    onset_db: f32,
+    gate_ms: f32,
+    window: Window,
+) -> Option<Vec<f32>> {
+    let onset = ir_onset(ir, sample_rate, onset_db)?.index;
+    let gated = gate_spectrum(&flat_impulse_response(capture, sweep), onset, sample_rate, gate_ms);
+    let mut placed = vec![0.0; capture.len()];
+    for (o, &s) in placed.iter_mut().skip(onset).zip(sweep) {
+        *o = s;
     }
+    Some(gated.iter().zip(compute_fft(&placed, window)).map(|(g, x)| g * x).collect())
+}
 
-    // Extrait la fenêtre [offset .. offset+travel_max] et normalise
-    let ir: Vec<f32> = ir_buf[search_start..search_end]
+/// Spectre affiché avec une fenêtre de `gate_ms` : `spectrum` (spectre de la
+/// capture) sous `gate_low_hz`, au-dessus la réponse fenêtrée `gated`
+/// (`gated_response`) recalée sur la puissance de `spectrum` au-dessus du
+/// raccord.
+pub fn splice_gated(spectrum: &[f32], shaped: &[f32], sample_rate: u32, gate_ms: f32) -> Vec<f32> {
+    let bin_hz = sample_rate as f32 / FFT_SIZE as f32;
+    let low = gate_low_hz(gate_ms);
+    let above = |k: &usize| *k as f32 * bin_hz >= low;
+    let power = |v: &[f32]| (0..v.len()).filter(above).map(|k| v[k] * v[k]).sum::<f32>();
+    let (captured, gated_power) = (power(spectrum), power(shaped));
+    let scale = if gated_power > 0.0 { (captured / gated_power).sqrt() } else { 0.0 };
+    spectrum
         .iter()
-        .map(|c| (c.re * inv_n).abs())
+        .zip(shaped)
+        .enumerate()
+        .map(|(k, (&full, &g))| {
+            // 0 sous le raccord, 1 une octave au-dessus
+            let mix = ((k as f32 * bin_hz / low).max(f32::MIN_POSITIVE).log2()).clamp(0.0, 1.0);
+            full * (1.0 - mix) + g * scale * mix
+        })
+        .collect()
+}
+
+// ─── Moyenne cohérente de plusieurs captures ────────────────────────────────
+//
+// Chaque sweep arrive avec une latence système légèrement différente : les
+// captures sont d'abord recalées sur le pic de leur IR, puis moyennées
+// échantillon par échantillon. La déconvolution étant linéaire, l'IR de la
+// moyenne est la moyenne cohérente des IR : le bruit décorrélé baisse de
+// 3 dB par doublement, la réponse de l'enceinte reste intacte.
+
+/// Décalage (échantillons) de chaque capture par rapport à la première,
+/// mesuré sur le pic de son IR.
+pub fn alignment_offsets(captures: &[&[f32]], sweep: &[f32], params: &SweepParams, sample_rate: u32) -> Vec<isize> {
+    let peaks: Vec<isize> = captures
+        .iter()
+        .map(|c| {
+            let ir = compute_impulse_response(c, sweep, params, sample_rate);
+            ir.iter()
+                .enumerate()
+                .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+                .map_or(0, |(i, _)| i as isize)
+        })
         .collect();
+    let first = peaks.first().copied().unwrap_or(0);
+    peaks.iter().map(|p| p - first).collect()
+}
+
+/// Moyenne de `tracks` décalées de `offsets` (échantillon t de la sortie =
+/// échantillon t + offset de chaque piste). Longueur de la première piste ;
+/// les bords ne moyennent que les pistes disponibles.
+pub fn average_aligned(tracks: &[&[f32]], offsets: &[isize]) -> Vec<f32> {
+    let len = tracks.first().map_or(0, |t| t.len());
+    (0..len as isize)
+        .map(|t| {
+            let (sum, n) = tracks.iter().zip(offsets).fold((0.0f32, 0usize), |(sum, n), (track, &off)| {
+                match usize::try_from(t + off).ok().and_then(|i| track.get(i)) {
+                    Some(v) => (sum + v, n + 1),
+                    None => (sum, n),
+                }
+            });
+            if n > 0 { sum / n as f32 } else { 0.0 }
+        })
+        .collect()
+}
+
+// ─── Distance absolue d'une enceinte par déconvolution sweep ────────────────
+//
+// Retourne la distance estimée enceinte→micro en mètres.
+// Sans boucle, la valeur inclut la latence système (buffer DAC+ADC), constante
+// pour les deux canaux → la DIFFÉRENCE gauche/droite est acoustiquement juste.
+// Avec une piste de boucle électrique (`loopback`), l'origine des temps est
+// l'arrivée du signal dans la boucle : la latence disparaît et la distance
+// est absolue.
+//
+// Algorithme :
+//   1. IR = FFT(capture) * FFT(inverse_sweep)⁻¹ → réponse impulsionnelle
+//   2. Énergie lissée de l'IR ; seuil à `threshold_db` au-dessus de son
+//      bruit de fond (médiane)
+//   3. Première arrivée au-dessus du seuil dans les `ONSET_MAX_LEAD_SECS`
+//      qui précèdent le pic le plus fort, reculée au pied de sa montée
+//      (`ir_onset`) = arrivée du son direct
+//   4. Fenêtre du son direct : jusqu'au premier creux marqué de
+//      l'enveloppe après le pic (`direct_window`), avant les réflexions
+//   5. Pic dans cette fenêtre, interpolation parabolique sub-sample
+
+pub fn compute_speaker_distance(
+    capture: &[f32],
+    sweep: &[f32],
+    params: &SweepParams,
+    sample_rate: u32,
+    pre_delay_samples: usize,
+    loopback: Option<&[f32]>,
+    onset_db: f32,
+) -> Option<f32> {
+    let ir = compute_impulse_response(capture, sweep, params, sample_rate);
+    match loopback {
+        Some(loopback) => {
+            let origin = loopback_arrival(loopback, sweep, params, sample_rate)?;
+            distance_from_origin(&ir, sample_rate, origin, onset_db)
+        }
+        None => distance_from_ir(&ir, sample_rate, pre_delay_samples, onset_db),
+    }
+}
 
-    let max_val = ir.iter().cloned().fold(0.0f32, f32::max);
-    if max_val < 1e-9 {
+/// Arrivée du signal dans la piste de boucle (échantillons, sub-sample, même
+/// repère que l'IR de la capture). Le chemin électrique ne donne qu'un pic
+/// net : le maximum absolu suffit, sans recherche de front.
+pub fn loopback_arrival(loopback: &[f32], sweep: &[f32], params: &SweepParams, sample_rate: u32) -> Option<f32> {
+    let ir = compute_impulse_response(loopback, sweep, params, sample_rate);
+    let (peak_idx, peak) = ir
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))?;
+    if peak.abs() < 1e-9 {
         return None;
     }
+    let delta = if peak_idx > 0 && peak_idx < ir.len() - 1 {
+        parabolic_interp(ir[peak_idx - 1].abs(), peak.abs(), ir[peak_idx + 1].abs())
+    } else {
+        0.0
+    };
+    Some(peak_idx as f32 + delta)
+}
 
-    let threshold = max_val * 0.10;
+/// Seuil du front par défaut, au-dessus du bruit de fond de l'IR (dB).
+pub const ONSET_THRESHOLD_DB: f32 = 20.0;
+
+/// Lissage de l'énergie de l'IR (s) : assez court pour dater le front.
+const ONSET_SMOOTH_SECS: f32 = 0.000_1;
+
+/// Le son direct précède le pic le plus fort de 20 ms au plus (7 m de trajet
+/// de plus pour une réflexion) : les pré-échos plus anciens — harmoniques
+/// d'un sweep court — ne sont pas des fronts.
+const ONSET_MAX_LEAD_SECS: f32 = 0.02;
+
+/// Deux arrivées sont distinctes si l'énergie repasse sous le seuil au
+/// moins 0,5 ms entre elles ; le front recule d'autant au plus.
+const ONSET_GAP_SECS: f32 = 0.000_5;
+
+/// Bornes du seuil sous le pic d'énergie (dB) : au plus 20 dB sous lui, les
+/// oscillations de la déconvolution avant le front restent dessous ;
+/// au moins 6 dB, le pic reste une arrivée sur une IR très bruitée.
+const ONSET_MAX_DEPTH_DB: f32 = 20.0;
+const ONSET_MIN_DEPTH_DB: f32 = 6.0;
+
+/// Front du son direct retenu dans l'IR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Onset {
+    pub index: usize,
+    /// Arrivées distinctes jusqu'au pic le plus fort, retenue comprise :
+    /// plus d'une, une réflexion dépasse le son direct.
+    pub candidates: usize,
+}
 
-    // Premier passage au-dessus du seuil = front du son direct
-    let onset = ir.iter().position(|&v| v >= threshold)?;
+/// Front du son direct. Un seuil relatif au pic (10 % autrefois) prend une
+/// réflexion plus forte que le son direct — enceinte tournée vers un mur,
+/// rebond sur le bureau — pour le front, ou manque un son direct faible.
+/// Ici : énergie lissée de la dérivée de l'IR — le grave, lent, s'y efface
+/// devant le front, raide —, seuil à `threshold_db` au-dessus du bruit de
+/// fond, et première arrivée qui le franchit peu avant le pic d'énergie le
+/// plus fort. Le front recule ensuite au pied de la montée, tant que
+/// l'énergie croît (`ONSET_GAP_SECS` au plus).
+pub fn ir_onset(ir: &[f32], sample_rate: u32, threshold_db: f32) -> Option<Onset> {
+    let rate = sample_rate as f32;
+    let slope: Vec<f32> = std::iter::once(0.0).chain(ir.windows(2).map(|w| w[1] - w[0])).collect();
+    let energy = smoothed_energy(&slope, ((ONSET_SMOOTH_SECS * rate) as usize).max(1));
+    let (peak, &peak_energy) = energy.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1))?;
+    if peak_energy < 1e-18 {
+        return None;
+    }
+    // Bruit de fond : médiane de l'énergie, l'IR étant surtout bruit et
+    // décroissance de la pièce
+    let mut sorted = energy.clone();
+    let mid = sorted.len() / 2;
+    let floor = *sorted.select_nth_unstable_by(mid, f32::total_cmp).1;
+    let below_peak = |db: f32| peak_energy * 10f32.powf(-db / 10.0);
+    let threshold = (floor * 10f32.powf(threshold_db / 10.0)).clamp(below_peak(ONSET_MAX_DEPTH_DB), below_peak(ONSET_MIN_DEPTH_DB));
+
+    // Arrivées : passages au-dessus du seuil, séparés d'un creux assez long
+    let start = peak.saturating_sub((ONSET_MAX_LEAD_SECS * rate) as usize);
+    let gap = ((ONSET_GAP_SECS * rate) as usize).max(1);
+    let mut arrivals: Vec<usize> = Vec::new();
+    let mut below = gap;
+    for (i, &e) in energy.iter().enumerate().take(peak + 1).skip(start) {
+        if e < threshold {
+            below += 1;
+            continue;
+        }
+        if below >= gap {
+            arrivals.push(i);
+        }
+        below = 0;
+    }
+    let first = *arrivals.first()?;
+    let mut index = first;
+    while index > start.max(first.saturating_sub(gap)) && energy[index - 1] < energy[index] && energy[index - 1] > floor {
+        index -= 1;
+    }
+    Some(Onset { index, candidates: arrivals.len() })
+}
 
-    // Pic local dans les 50 samples suivant le front (son direct, avant les réflexions)
-    let window_end = (onset + 50).min(ir.len() - 1);
-    let peak_idx = (onset..=window_end)
-        .max_by(|&a, &b| ir[a].partial_cmp(&ir[b]).unwrap())?;
+/// Énergie moyenne de `x` sur `width` échantillons centrés.
+fn smoothed_energy(x: &[f32], width: usize) -> Vec<f32> {
+    let mut prefix = vec![0.0f64; x.len() + 1];
+    for (i, &v) in x.iter().enumerate() {
+        prefix[i + 1] = prefix[i] + (v as f64) * (v as f64);
+    }
+    let half = width / 2;
+    (0..x.len())
+        .map(|i| {
+            let (lo, hi) = (i.saturating_sub(half), (i + half + 1).min(x.len()));
+            ((prefix[hi] - prefix[lo]) / (hi - lo) as f64) as f32
+        })
+        .collect()
+}
 
-    // peak_idx est l'indice DANS la fenêtre décalée.
-    // Il vaut : pre_delay_samples + latence_système + t_acoustique.
+/// Fenêtre du son direct : au moins 0,2 ms après le front, au plus 3 ms
+/// (un mètre de trajet de plus qu'une réflexion sur le bureau ou le mur).
+const DIRECT_MIN_SECS: f32 = 0.000_2;
+const DIRECT_MAX_SECS: f32 = 0.003;
+
+/// Creux marqué : l'enveloppe retombe sous cette fraction du pic direct.
+const DIRECT_DIP_RATIO: f32 = 0.5;
+
+/// Son direct dans l'IR (indices) : du front jusqu'au premier creux marqué
+/// de l'enveloppe après le pic direct, frontière avec les réflexions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectWindow {
+    pub onset: usize,
+    /// Dernier indice retenu (inclus).
+    pub end: usize,
+}
+
+impl DirectWindow {
+    pub fn len_secs(&self, sample_rate: u32) -> f32 {
+        (self.end - self.onset) as f32 / sample_rate as f32
+    }
+}
+
+/// Cherche la fenêtre du son direct. Une fenêtre fixe après le front avale,
+/// dans une petite pièce, la réflexion du bureau ou du mur proche — parfois
+/// plus forte que le son direct — ou coupe le pic d'un tweeter lent ;
+/// l'enveloppe (module du signal analytique, sans les passages à zéro de
+/// l'IR) montre où le son direct s'éteint avant que la réflexion arrive.
+pub fn direct_window(ir: &[f32], sample_rate: u32, onset_db: f32) -> Option<DirectWindow> {
+    let onset = ir_onset(ir, sample_rate, onset_db)?.index;
+    let rate = sample_rate as f32;
+    let max_len = ((DIRECT_MAX_SECS * rate) as usize).min(ir.len() - onset);
+    let min_len = ((DIRECT_MIN_SECS * rate) as usize).max(1);
+    if max_len <= min_len {
+        return Some(DirectWindow { onset, end: ir.len() - 1 });
+    }
+    let env = envelope(&ir[onset..onset + max_len]);
+    let mut peak = 0.0f32;
+    for i in 0..max_len - 1 {
+        peak = peak.max(env[i]);
+        let dip = i >= min_len && env[i] < DIRECT_DIP_RATIO * peak && env[i] <= env[i + 1];
+        if dip {
+            return Some(DirectWindow { onset, end: onset + i });
+        }
+    }
+    Some(DirectWindow { onset, end: onset + max_len - 1 })
+}
+
+/// Enveloppe de `x` : module du signal analytique (transformée de Hilbert par FFT).
+pub fn envelope(x: &[f32]) -> Vec<f32> {
+    let n = (2 * x.len()).next_power_of_two();
+    let mut buf: Vec<Complex<f32>> = x
+        .iter()
+        .map(|&v| Complex::new(v, 0.0))
+        .chain(std::iter::repeat_n(Complex::new(0.0, 0.0), n - x.len()))
+        .collect();
+    let mut planner = FftPlanner::<f32>::new();
+    planner.plan_fft_forward(n).process(&mut buf);
+    for (k, c) in buf.iter_mut().enumerate() {
+        match k {
+            0 => {}
+            k if k < n / 2 => *c *= 2.0,
+            k if k == n / 2 => {}
+            _ => *c = Complex::new(0.0, 0.0),
+        }
+    }
+    planner.plan_fft_inverse(n).process(&mut buf);
+    buf[..x.len()].iter().map(|c| c.norm() / n as f32).collect()
+}
+
+// ─── Vitesse du son ───────────────────────────────────────────────────────────
+
+/// Vitesse du son sans température connue (m/s, air sec vers 20 °C).
+pub const DEFAULT_SPEED_OF_SOUND: f32 = 343.0;
+
+/// Vitesse courante (bits d'un f32) ; 0 = `DEFAULT_SPEED_OF_SOUND`.
+static SPEED_OF_SOUND: AtomicU32 = AtomicU32::new(0);
+
+/// Vitesse du son utilisée pour toutes les conversions temps ↔ distance
+/// (m/s), globale comme la langue.
+pub fn speed_of_sound() -> f32 {
+    match SPEED_OF_SOUND.load(Ordering::Relaxed) {
+        0 => DEFAULT_SPEED_OF_SOUND,
+        bits => f32::from_bits(bits),
+    }
+}
+
+pub fn set_speed_of_sound(c: f32) {
+    SPEED_OF_SOUND.store(c.to_bits(), Ordering::Relaxed);
+}
+
+/// Vitesse du son dans l'air à `temperature_c` : c = 331,3·√(1 + T/273,15),
+/// plus 0,0124 m/s par point d'humidité relative quand elle est connue
+/// (approximation de Cramer, ≈ +0,6 m/s à 50 %).
+pub fn speed_of_sound_at(temperature_c: f32, humidity_pct: Option<f32>) -> f32 {
+    331.3 * (1.0 + temperature_c / 273.15).sqrt() + humidity_pct.map_or(0.0, |h| 0.0124 * h)
+}
+
+/// Distance enceinte→micro (m) à partir d'une IR déjà calculée
+/// par `compute_impulse_response` ; `onset_db` : seuil de `ir_onset`.
+pub fn distance_from_ir(ir: &[f32], sample_rate: u32, pre_delay_samples: usize, onset_db: f32) -> Option<f32> {
+    // L'indice du pic vaut : pre_delay_samples + latence_système + t_acoustique.
     // On soustrait le pre_delay (connu) ; la latence système reste mais est
     // identique pour G et D → la DIFFÉRENCE est acoustiquement juste.
+    distance_from_origin(ir, sample_rate, pre_delay_samples as f32, onset_db)
+}
+
+/// Distance (m) parcourue entre `origin` (échantillons dans le repère de l'IR)
+/// et le pic du son direct.
+pub fn distance_from_origin(ir: &[f32], sample_rate: u32, origin: f32, onset_db: f32) -> Option<f32> {
+    // Du front au premier creux de l'enveloppe : son direct, avant les réflexions
+    let window = direct_window(ir, sample_rate, onset_db)?;
+    let ir: Vec<f32> = ir.iter().map(|v| v.abs()).collect();
+
+    let peak_idx = (window.onset..=window.end)
+        .max_by(|&a, &b| ir[a].total_cmp(&ir[b]))?;
+
+    // peak_idx est l'indice DANS la fenêtre décalée
     let delta = if peak_idx > 0 && peak_idx < ir.len() - 1 {
         parabolic_interp(ir[peak_idx - 1], ir[peak_idx], ir[peak_idx + 1])
     } else {
         0.0
     };
 
-    let net = (peak_idx as f32 + delta) - pre_delay_samples as f32;
+    let net = (peak_idx as f32 + delta) - origin;
     if net < 0.0 {
         return None;
     }
     let time_s = net / sample_rate as f32;
-    Some(time_s * 343.0) // distance en mètres (inclut encore la latence système)
+    Some(time_s * speed_of_sound()) // distance en mètres
+}
+
+// ─── GCC-PHAT (corrélation croisée généralisée, pondération de phase) ───────
+//
+// Estime le retard de `b` par rapport à `a` (secondes, positif = `b` en retard).
+// La pondération PHAT blanchit le spectre croisé : le pic de corrélation reste
+// étroit même en présence de réverbération ou d'un spectre très coloré.
+// La recherche est limitée à ±`max_delay_s`.
+
+pub fn gcc_phat(a: &[f32], b: &[f32], sample_rate: u32, max_delay_s: f32) -> Option<f32> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let fft_len = (a.len() + b.len()).next_power_of_two();
+
+    let mut planner = FftPlanner::<f32>::new();
+    let fft_fwd = planner.plan_fft_forward(fft_len);
+    let fft_inv = planner.plan_fft_inverse(fft_len);
+
+    let to_buf = |x: &[f32]| -> Vec<Complex<f32>> {
+        x.iter()
+            .map(|&v| Complex::new(v, 0.0))
+            .chain(std::iter::repeat_n(Complex::new(0.0, 0.0), fft_len - x.len()))
+            .collect()
+    };
+    let mut fa = to_buf(a);
+    let mut fb = to_buf(b);
+    fft_fwd.process(&mut fa);
+    fft_fwd.process(&mut fb);
+
+    let mut cross: Vec<Complex<f32>> = fa
+        .iter()
+        .zip(fb.iter())
+        .map(|(x, y)| {
+            let c = y * x.conj();
+            let mag = c.norm();
+            if mag > 1e-12 { c / mag } else { Complex::new(0.0, 0.0) }
+        })
+        .collect();
+    fft_inv.process(&mut cross);
+
+    // Indices circulaires : 0..max_lag (b en retard) et fft_len-max_lag.. (b en avance)
+    let max_lag = ((max_delay_s * sample_rate as f32) as usize).min(fft_len / 2 - 1);
+    let corr = |lag: isize| -> f32 {
+        let idx = lag.rem_euclid(fft_len as isize) as usize;
+        cross[idx].re
+    };
+
+    let best = (-(max_lag as isize)..=max_lag as isize)
+        .max_by(|&x, &y| corr(x).total_cmp(&corr(y)))?;
+
+    let delta = parabolic_interp(corr(best - 1), corr(best), corr(best + 1));
+    Some((best as f32 + delta) / sample_rate as f32)
+}
+
+// ─── Retard par corrélation croisée ──────────────────────────────────────────
+//
+// Retourne le décalage (en échantillons, sub-sample) pour lequel `capture`
+// ressemble le plus à `reference`, en ne cherchant que des retards positifs
+// ≤ `max_lag`. Contrairement à GCC-PHAT, aucune pondération : adapté aux
+// signaux à bande étroite (le blanchiment PHAT amplifierait le bruit hors bande).
+
+pub fn xcorr_delay(reference: &[f32], capture: &[f32], max_lag: usize) -> Option<f32> {
+    let cross = cross_correlation(reference, capture)?;
+
+    let max_lag = max_lag.min(cross.len() - 2);
+    let best = (0..=max_lag).max_by(|&a, &b| cross[a].total_cmp(&cross[b]))?;
+    if cross[best] <= 0.0 {
+        return None;
+    }
+    let delta = if best > 0 {
+        parabolic_interp(cross[best - 1], cross[best], cross[best + 1])
+    } else {
+        0.0
+    };
+    Some(best as f32 + delta)
+}
+
+/// Pic de corrélation de plus grande amplitude (retards positifs ≤ `max_lag`),
+/// signe conservé : un pic négatif signale une polarité inversée.
+/// Retourne (retard en échantillons, valeur du pic, plus grand pic de signe opposé).
+pub fn xcorr_signed_peak(reference: &[f32], capture: &[f32], max_lag: usize) -> Option<(usize, f32, f32)> {
+    let cross = cross_correlation(reference, capture)?;
+
+    let max_lag = max_lag.min(cross.len() - 1);
+    let best = (0..=max_lag).max_by(|&a, &b| cross[a].abs().total_cmp(&cross[b].abs()))?;
+    let peak = cross[best];
+    let opposite = cross[..=max_lag]
+        .iter()
+        .filter(|v| v.signum() != peak.signum())
+        .fold(0.0f32, |m, v| m.max(v.abs()));
+    Some((best, peak, opposite))
+}
+
+/// Corrélation croisée linéaire de `capture` par `reference` (indice = retard).
+pub fn cross_correlation(reference: &[f32], capture: &[f32]) -> Option<Vec<f32>> {
+    Some(correlate(reference, capture, false)?.iter().map(|c| c.re).collect())
+}
+
+/// Enveloppe de la corrélation croisée (module du signal analytique) : un
+/// seul lobe par arrivée, sans les oscillations de la porteuse qui rendent
+/// ambigu le pic d'un signal à bande étroite.
+pub fn correlation_envelope(reference: &[f32], capture: &[f32]) -> Option<Vec<f32>> {
+    Some(correlate(reference, capture, true)?.iter().map(|c| c.norm()).collect())
+}
+
+/// Corrélation complexe ; `analytic` ne garde que les fréquences positives.
+fn correlate(reference: &[f32], capture: &[f32], analytic: bool) -> Option<Vec<Complex<f32>>> {
+    if reference.is_empty() || capture.is_empty() {
+        return None;
+    }
+    let fft_len = (reference.len() + capture.len()).next_power_of_two();
+
+    let mut planner = FftPlanner::<f32>::new();
+    let fft_fwd = planner.plan_fft_forward(fft_len);
+    let fft_inv = planner.plan_fft_inverse(fft_len);
+
+    let to_buf = |x: &[f32]| -> Vec<Complex<f32>> {
+        x.iter()
+            .map(|&v| Complex::new(v, 0.0))
+            .chain(std::iter::repeat_n(Complex::new(0.0, 0.0), fft_len - x.len()))
+            .collect()
+    };
+    let mut fr = to_buf(reference);
+    let mut fc = to_buf(capture);
+    fft_fwd.process(&mut fr);
+    fft_fwd.process(&mut fc);
+
+    let mut cross: Vec<Complex<f32>> = fr.iter().zip(fc.iter()).map(|(r, c)| c * r.conj()).collect();
+    if analytic {
+        for (k, c) in cross.iter_mut().enumerate().skip(1) {
+            *c = if k < fft_len / 2 { *c * 2.0 } else if k == fft_len / 2 { *c } else { Complex::new(0.0, 0.0) };
+        }
+    }
+    fft_inv.process(&mut cross);
+    Some(cross)
+}
+
+// ─── EDT et clarté (C50 / C80) ───────────────────────────────────────────────
+//
+// Calculés sur l'IR à partir de l'arrivée du son direct (pic de |IR|) :
+//   - EDT : temps de décroissance 0 → -10 dB de l'intégrale de Schroeder, × 6
+//   - C50 : énergie 0–50 ms / énergie au-delà (parole)
+//   - C80 : énergie 0–80 ms / énergie au-delà (musique)
+// La queue est tronquée à 1 s pour limiter l'influence du bruit de fond.
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClarityMetrics {
+    pub edt_s: f32,
+    pub c50_db: f32,
+    pub c80_db: f32,
+}
+
+pub fn compute_clarity(ir: &[f32], sample_rate: u32) -> Option<ClarityMetrics> {
+    let peak_idx = ir
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+        .map(|(i, _)| i)?;
+
+    let tail_len = sample_rate as usize; // 1 s
+    let end = (peak_idx + tail_len).min(ir.len());
+    let energy: Vec<f32> = ir[peak_idx..end].iter().map(|v| v * v).collect();
+    let total: f32 = energy.iter().sum();
+    if total < 1e-20 {
+        return None;
+    }
+
+    let clarity = |ms: f32| -> f32 {
+        let split = ((ms / 1000.0 * sample_rate as f32) as usize).min(energy.len());
+        let early: f32 = energy[..split].iter().sum();
+        let late: f32 = energy[split..].iter().sum();
+        if late > 0.0 && early > 0.0 {
+            10.0 * (early / late).log10()
+        } else {
+            0.0
+        }
+    };
+
+    // Intégrale de Schroeder (backward) normalisée, en dB
+    let mut acc = 0.0f32;
+    let mut schroeder = vec![0.0f32; energy.len()];
+    for (i, &e) in energy.iter().enumerate().rev() {
+        acc += e;
+        schroeder[i] = acc;
+    }
+    let t_10 = schroeder
+        .iter()
+        .position(|&v| 10.0 * (v / total).log10() <= -10.0)
+        .unwrap_or(energy.len());
+    let edt_s = 6.0 * t_10 as f32 / sample_rate as f32;
+
+    Some(ClarityMetrics {
+        edt_s,
+        c50_db: clarity(50.0),
+        c80_db: clarity(80.0),
+    })
+}
+
+
+// ─── Temps de réverbération par octave (Schroeder) ──────────────────────────
+//
+// L'IR est filtrée par octave (deux passe-bande RBJ en cascade), puis on
+// intègre son énergie à rebours (Schroeder) après soustraction du bruit de
+// fond, en tronquant là où la décroissance rejoint ce bruit. Les temps sont
+// extrapolés à −60 dB par régression linéaire sur la courbe intégrée :
+//   EDT : 0 → −10 dB    T20 : −5 → −25 dB    T30 : −5 → −35 dB
+// Un temps vaut None si la dynamique de l'octave n'atteint pas sa borne basse.
+
+/// Fenêtre analysée après le pic de l'IR ; son dernier quart sert d'estimation
+/// du bruit de fond.
+const DECAY_WINDOW_SECS: f32 = 1.5;
+/// Blocs de lissage de l'enveloppe pour la détection de la troncature.
+const DECAY_BLOCK_SECS: f32 = 0.01;
+/// Marge au-dessus du bruit de fond où l'intégration s'arrête (dB).
+const DECAY_NOISE_MARGIN_DB: f64 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DecayTimes {
+    pub edt_s: Option<f32>,
+    pub t20_s: Option<f32>,
+    pub t30_s: Option<f32>,
+}
+
+/// Passe-bande biquad (RBJ, gain 0 dB au centre).
+pub fn bandpass_biquad(samples: &[f32], fc: f32, q: f32, sample_rate: u32) -> Vec<f32> {
+    let w0 = 2.0 * PI * fc / sample_rate as f32;
+    let alpha = w0.sin() / (2.0 * q);
+    let a0 = 1.0 + alpha;
+    let (b0, b2) = (alpha / a0, -alpha / a0);
+    let (a1, a2) = (-2.0 * w0.cos() / a0, (1.0 - alpha) / a0);
+
+    let (mut x1, mut x2, mut y1, mut y2) = (0.0f32, 0.0f32, 0.0f32, 0.0f32);
+    samples
+        .iter()
+        .map(|&x| {
+            let y = b0 * x + b2 * x2 - a1 * y1 - a2 * y2;
+            x2 = x1;
+            x1 = x;
+            y2 = y1;
+            y1 = y;
+            y
+        })
+        .collect()
+}
+
+/// EDT / T20 / T30 de chaque octave de `OCTAVE_CENTERS`.
+pub fn compute_rt60(ir: &[f32], sample_rate: u32) -> Vec<DecayTimes> {
+    OCTAVE_CENTERS
+        .iter()
+        .map(|&fc| {
+            let q = 2f32.sqrt();
+            let band = bandpass_biquad(&bandpass_biquad(ir, fc, q, sample_rate), fc, q, sample_rate);
+            band_decay(&band, sample_rate).unwrap_or(DecayTimes { edt_s: None, t20_s: None, t30_s: None })
+        })
+        .collect()
+}
+
+fn band_decay(band: &[f32], sample_rate: u32) -> Option<DecayTimes> {
+    let peak_idx = band
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+        .map(|(i, _)| i)?;
+    let end = (peak_idx + (DECAY_WINDOW_SECS * sample_rate as f32) as usize).min(band.len());
+    let energy: Vec<f64> = band[peak_idx..end].iter().map(|&v| (v as f64) * (v as f64)).collect();
+    if energy.len() < 8 {
+        return None;
+    }
+
+    // Bruit de fond : énergie moyenne du dernier quart de la fenêtre
+    let tail = &energy[energy.len() * 3 / 4..];
+    let noise = tail.iter().sum::<f64>() / tail.len() as f64;
+
+    // Troncature : premier bloc dont l'énergie moyenne rejoint le bruit
+    let block = ((DECAY_BLOCK_SECS * sample_rate as f32) as usize).max(1);
+    let limit = noise * 10f64.powf(DECAY_NOISE_MARGIN_DB / 10.0);
+    let cut = energy
+        .chunks(block)
+        .position(|c| c.iter().sum::<f64>() / c.len() as f64 <= limit)
+        .map(|b| b * block)
+        .unwrap_or(energy.len());
+
+    // Intégrale de Schroeder compensée du bruit
+    let mut acc = 0.0f64;
+    let mut schroeder = vec![0.0f64; cut];
+    for i in (0..cut).rev() {
+        acc += (energy[i] - noise).max(0.0);
+        schroeder[i] = acc;
+    }
+    if acc <= 0.0 {
+        return None;
+    }
+    let curve: Vec<f64> = schroeder.iter().map(|&s| 10.0 * (s / acc).max(1e-30).log10()).collect();
+
+    // Régression sur [hi, lo] dB puis extrapolation à −60 dB
+    let fit = |hi: f64, lo: f64| -> Option<f32> {
+        let i0 = curve.iter().position(|&l| l <= hi)?;
+        let i1 = curve.iter().position(|&l| l <= lo)?;
+        if i1 <= i0 + 1 {
+            return None;
+        }
+        let n = (i1 - i0) as f64;
+        let t = |i: usize| i as f64 / sample_rate as f64;
+        let mean_t = (i0..i1).map(t).sum::<f64>() / n;
+        let mean_l = curve[i0..i1].iter().sum::<f64>() / n;
+        let (mut cov, mut var) = (0.0f64, 0.0f64);
+        for (i, &l) in curve.iter().enumerate().take(i1).skip(i0) {
+            cov += (t(i) - mean_t) * (l - mean_l);
+            var += (t(i) - mean_t) * (t(i) - mean_t);
+        }
+        let slope = cov / var;
+        (slope < 0.0).then(|| (-60.0 / slope) as f32)
+    };
+
+    Some(DecayTimes {
+        edt_s: fit(0.0, -10.0),
+        t20_s: fit(-5.0, -25.0),
+        t30_s: fit(-5.0, -35.0),
+    })
 }
 
+// ─── IACC (corrélation croisée inter-aurale) ────────────────────────────────
+//
+// Calculé sur les IR des deux oreilles (deux micros espacés d'une tête,
+// ou deux positions de capture) : maximum de la corrélation normalisée
+// pour un décalage |τ| ≤ 1 ms, sur la fenêtre précoce 0–80 ms (IACC_E).
+// 1.0 = signaux identiques (image étroite), → 0 = image large / diffuse.
+
+pub fn compute_iacc(left_ear: &[f32], right_ear: &[f32], sample_rate: u32) -> Option<f32> {
+    let peak = |ir: &[f32]| -> Option<usize> {
+        ir.iter()
+            .enumerate()
+            .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+            .map(|(i, _)| i)
+    };
+    let start = peak(left_ear)?.min(peak(right_ear)?);
+    let win = (0.080 * sample_rate as f32) as usize;
+    let max_lag = (0.001 * sample_rate as f32) as isize;
+
+    let len = left_ear.len().min(right_ear.len());
+    let end = (start + win).min(len);
+    if end <= start {
+        return None;
+    }
+    let l = &left_ear[start..end];
+    let r = &right_ear[start..end];
+
+    let energy_l: f32 = l.iter().map(|v| v * v).sum();
+    let energy_r: f32 = r.iter().map(|v| v * v).sum();
+    let norm = (energy_l * energy_r).sqrt();
+    if norm < 1e-20 {
+        return None;
+    }
+
+    let n = l.len() as isize;
+    let best = (-max_lag..=max_lag)
+        .map(|lag| {
+            let mut acc = 0.0f32;
+            for i in 0..n {
+                let j = i + lag;
+                if j >= 0 && j < n {
+                    acc += l[i as usize] * r[j as usize];
+                }
+            }
+            (acc / norm).abs()
+        })
+        .fold(0.0f32, f32::max);
+
+    Some(best.min(1.0))
+}
 
 // ─── Score global (0–100) ─────────────────────────────────────────────────────
 
+/// Part de la conformité à la courbe cible dans le score quand une cible est
+/// chargée (pts) ; les autres parts sont réduites d'autant.
+const TARGET_POINTS: f32 = 20.0;
+
+/// Écart de C80 (dB) qui annule la part « réflexions » du placement.
+const C80_ZERO_DB: f32 = 6.25;
+
+/// Score global, pondéré par `weights` (profil `[score]`). Avec une courbe
+/// cible (`target_db`, mêmes bandes que les mesures), l'écart moyen de G et
+/// D à la cible en prend `TARGET_POINTS` ; avec un masque de tolérance
+/// (`mask_db`, ± dB par bande), seul l'écart G/D qui le dépasse compte.
 pub fn compute_score(
     left_db: &[f32],
     right_db: &[f32],
     delay_ms: f32,
     level_diff_db: f32,
+    target_db: Option<&[f32]>,
+    mask_db: Option<&[f32]>,
+    weights: &ScoreWeights,
 ) -> u32 {
-    // Similarité spectrale → 0-50 pts
+    let (freq_score, level_score, time_score) = score_parts(left_db, right_db, delay_ms, level_diff_db, mask_db, weights);
+    let total = freq_score + level_score + time_score;
+    let score = match target_db {
+        Some(target) => {
+            // Conformité → 0-20 pts, 2 pts par dB d'écart moyen
+            let deviation = (target_deviation(left_db, target) + target_deviation(right_db, target)) / 2.0;
+            total * (100.0 - TARGET_POINTS) / 100.0 + (TARGET_POINTS - deviation * 2.0).max(0.0)
+        }
+        None => total,
+    };
+    score.round() as u32
+}
+
+/// Écart de `bands_db` à `target_db` par bande (dB), niveaux moyens alignés :
+/// seule la forme de la réponse compte.
+pub fn target_offsets(bands_db: &[f32], target_db: &[f32]) -> Vec<f32> {
+    let diff: Vec<f32> = bands_db.iter().zip(target_db).map(|(b, t)| b - t).collect();
+    let mean = diff.iter().sum::<f32>() / diff.len().max(1) as f32;
+    diff.iter().map(|d| d - mean).collect()
+}
+
+/// Écart moyen (valeur absolue, dB) de `bands_db` à `target_db`.
+pub fn target_deviation(bands_db: &[f32], target_db: &[f32]) -> f32 {
+    let offsets = target_offsets(bands_db, target_db);
+    offsets.iter().map(|d| d.abs()).sum::<f32>() / offsets.len().max(1) as f32
+}
+
+/// Points du score global : spectre, niveau, temps (0–50, 0–25, 0–25 avec
+/// le profil par défaut), chacun décroissant linéairement jusqu'à l'écart
+/// qui l'annule.
+fn score_parts(
+    left_db: &[f32],
+    right_db: &[f32],
+    delay_ms: f32,
+    level_diff_db: f32,
+    mask_db: Option<&[f32]>,
+    w: &ScoreWeights,
+) -> (f32, f32, f32) {
+    let points = |max: f32, error: f32, zero: f32| max * (1.0 - error / zero).max(0.0);
+
+    // Similarité spectrale, hors de l'écart toléré par le masque
+    let tolerance = |i: usize| mask_db.and_then(|m| m.get(i)).copied().unwrap_or(0.0);
     let freq_error: f32 = left_db
         .iter()
         .zip(right_db.iter())
-        .map(|(l, r)| (l - r).abs())
+        .enumerate()
+        .map(|(i, (l, r))| ((l - r).abs() - tolerance(i)).max(0.0))
         .sum::<f32>()
         / left_db.len() as f32;
-    let freq_score = (50.0 - freq_error * 2.0).max(0.0);
+    let freq_score = points(w.spectrum, freq_error, w.spectrum_zero_db);
+
+    // Équilibre de niveau
+    let level_score = points(w.level, level_diff_db.abs(), w.level_zero_db);
 
-    // Équilibre de niveau → 0-25 pts
-    let level_score = (25.0 - level_diff_db.abs() * 5.0).max(0.0);
+    // Alignement temporel
+    let time_score = points(w.time, delay_ms.abs(), w.time_zero_ms);
 
-    // Alignement temporel → 0-25 pts
-    let time_score = (25.0 - delay_ms.abs() * 10.0).max(0.0);
+    (freq_score, level_score, time_score)
+}
+
+/// Score séparé selon le remède (0–100 chacun).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    /// Retard et réflexions précoces : se corrige en déplaçant les enceintes.
+    pub placement: u32,
+    /// Niveau et accord spectral : se corrige au réglage de niveau ou à
+    /// l'égaliseur, sans toucher aux meubles.
+    pub electrical: u32,
+}
 
-    (freq_score + level_score + time_score).round() as u32
+/// Sépare le score global en placement / réglage. `c80_diff_db` (C80
+/// droite − gauche) chiffre la symétrie des réflexions précoces ; sans IR,
+/// le placement ne repose que sur le retard.
+pub fn compute_score_breakdown(
+    left_db: &[f32],
+    right_db: &[f32],
+    delay_ms: f32,
+    level_diff_db: f32,
+    c80_diff_db: Option<f32>,
+    mask_db: Option<&[f32]>,
+    weights: &ScoreWeights,
+) -> ScoreBreakdown {
+    let (freq_score, level_score, time_score) = score_parts(left_db, right_db, delay_ms, level_diff_db, mask_db, weights);
+    // Symétrie des réflexions → autant de points que le temps, nuls à
+    // `C80_ZERO_DB` d'écart de C80
+    let placement = match c80_diff_db {
+        Some(diff) => (time_score + weights.time * (1.0 - diff.abs() / C80_ZERO_DB).max(0.0)) * 50.0 / weights.time,
+        None => time_score * 100.0 / weights.time,
+    };
+    ScoreBreakdown {
+        placement: placement.round() as u32,
+        electrical: ((freq_score + level_score) * 100.0 / (weights.spectrum + weights.level)).round() as u32,
+    }
 }
 
 // ─── Inclinaison spectrale ────────────────────────────────────────────────────
 
 pub fn compute_freq_tilt(left_db: &[f32], right_db: &[f32]) -> f32 {
-    let mid = NUM_BANDS / 2;
+    let mid = left_db.len().min(right_db.len()) / 2;
 
     let avg = |slice: &[f32]| -> f32 {
         slice.iter().sum::<f32>() / slice.len() as f32
@@ -314,6 +1647,34 @@ pub fn band_center_freq(index: usize, num_bands: usize) -> f32 {
     10f32.powf(log_min + (log_max - log_min) * (index as f32 + 0.5) / num_bands as f32)
 }
 
+// ─── Zones spectrales ────────────────────────────────────────────────────────
+
+/// Zones usuelles du spectre (Hz) : sub-grave, grave, médium, présence,
+/// brillance. Noms dans `strings::Strings::region_names`.
+pub const SPECTRAL_REGIONS: [(f32, f32); 5] = [
+    (20.0, 60.0),
+    (60.0, 250.0),
+    (250.0, 4_000.0),
+    (4_000.0, 6_000.0),
+    (6_000.0, 20_000.0),
+];
+
+/// Moyenne de `bands_db` dans chaque zone de `SPECTRAL_REGIONS` (bandes dont
+/// le centre y tombe ; 0 pour une zone sans bande).
+pub fn region_means(bands_db: &[f32]) -> Vec<f32> {
+    let n = bands_db.len();
+    SPECTRAL_REGIONS
+        .iter()
+        .map(|&(lo, hi)| {
+            let inside: Vec<f32> = (0..n)
+                .filter(|&i| (lo..hi).contains(&band_center_freq(i, n)))
+                .map(|i| bands_db[i])
+                .collect();
+            if inside.is_empty() { 0.0 } else { inside.iter().sum::<f32>() / inside.len() as f32 }
+        })
+        .collect()
+}
+
 pub fn freq_label(index: usize, num_bands: usize) -> String {
     let f = band_center_freq(index, num_bands);
     if f >= 1000.0 {
@@ -0,0 +1,214 @@
+// ============================================================
+//  dualnoise.rs — Mesure simultanée G + D (bruits décorrélés)
+//
+//  Les deux enceintes jouent en même temps deux bruits roses
+//  périodiques indépendants : l'un n'occupe que les bins pairs
+//  de la période, l'autre que les impairs
+//  (`dsp::generate_interleaved_pink_pair`). Le micro capte leur
+//  somme ; la corrélation croisée de la capture avec chaque
+//  référence — dans le domaine fréquentiel, Y·X*/|X|² — rend la
+//  fonction de transfert de chaque enceinte, l'autre n'ayant
+//  aucune énergie sur ces bins. La résolution est moitié moindre
+//  qu'une mesure seule, mais G et D sont mesurés au même instant :
+//  l'équilibre se suit en direct ([?]) sans sweeps successifs.
+//
+//  La première période (latence, établissement dans la pièce)
+//  est ignorée ; les suivantes sont moyennées en complexe avant
+//  la division (le bruit de fond baisse de 3 dB par doublement).
+//  L'IR de chaque côté, repliée sur une demi-période, date son
+//  arrivée : la différence des deux donne le retard D − G.
+// ============================================================
+
+use rustfft::{FftPlanner, num_complex::Complex};
+
+use crate::{
+    calibration::MicCalibration,
+    dsp::{self, FFT_SIZE, NUM_BANDS},
+    pipeline::AnalysisOptions,
+};
+
+/// Période des deux bruits (échantillons) : 1,4 s à 48 kHz, IR de 0,7 s
+/// par côté après séparation.
+pub const PERIOD: usize = 65_536;
+
+/// Périodes moyennées par capture.
+pub const PERIODS: usize = 2;
+
+/// Périodes ignorées en tête de capture (latence, régime établi).
+const SETTLE_PERIODS: usize = 1;
+
+/// Crête du bruit de chaque enceinte (pleine échelle = 1.0).
+pub const DUAL_LEVEL: f32 = 0.35;
+
+/// Nombre de lectures gardées pour la tendance.
+pub const HISTORY_LEN: usize = 12;
+
+/// Écart de niveau sous lequel les enceintes sont jugées équilibrées (dB).
+pub const BALANCED_DB: f32 = 0.5;
+
+/// Durée jouée : périodes ignorées, moyennées, et une de plus pour couvrir
+/// la latence du système.
+pub fn sequence_secs(sample_rate: u32) -> f32 {
+    ((SETTLE_PERIODS + PERIODS + 1) * PERIOD) as f32 / sample_rate as f32
+}
+
+/// Signal de lecture : bruit gauche puis bruit droit, bout à bout (même
+/// longueur), joués ensemble par `audio::play_dual_and_capture`.
+pub fn generate_test_signal(sample_rate: u32) -> Vec<f32> {
+    let (mut left, right) = dsp::generate_interleaved_pink_pair(sample_rate, PERIOD, sequence_secs(sample_rate), DUAL_LEVEL);
+    left.extend(right);
+    left
+}
+
+/// Une lecture du suivi simultané.
+#[derive(Debug, Clone, Copy)]
+pub struct DualReading {
+    /// D − G sur la plage d'analyse (dB).
+    pub level_diff_db: f32,
+    /// Retard de la droite sur la gauche (ms).
+    pub delay_ms: f32,
+}
+
+impl DualReading {
+    /// Écart de distance correspondant (cm, positif = droite plus loin).
+    pub fn distance_cm(&self) -> f32 {
+        self.delay_ms / 1000.0 * dsp::speed_of_sound() * 100.0
+    }
+}
+
+/// Réponses séparées d'une capture.
+#[derive(Debug, Clone)]
+pub struct DualMeasurement {
+    pub reading: DualReading,
+    /// Bandes log (dB), micro corrigé et lissage appliqué.
+    pub left_db: Vec<f32>,
+    pub right_db: Vec<f32>,
+}
+
+/// Sépare la capture `capture` en fonctions de transfert gauche / droite.
+/// `reference` est le signal joué (`generate_test_signal`), `pre_delay`
+/// le début de la lecture dans la capture (échantillons). `None` si la
+/// capture est trop courte.
+pub fn analyze(
+    capture: &[f32],
+    reference: &[f32],
+    sample_rate: u32,
+    pre_delay: usize,
+    calibration: Option<&MicCalibration>,
+    options: &AnalysisOptions,
+) -> Option<DualMeasurement> {
+    let (ref_left, ref_right) = reference.split_at(reference.len() / 2);
+    // Une période entière du régime établi de chaque référence ; la
+    // capture est découpée à la même phase
+    let period = SETTLE_PERIODS * PERIOD..(SETTLE_PERIODS + 1) * PERIOD;
+    let fft = FftPlanner::<f32>::new().plan_fft_forward(PERIOD);
+    let spectrum = |samples: &[f32]| {
+        let mut buf: Vec<Complex<f32>> = samples.iter().map(|&x| Complex::new(x, 0.0)).collect();
+        fft.process(&mut buf);
+        buf
+    };
+    let x_left = spectrum(ref_left.get(period.clone())?);
+    let x_right = spectrum(ref_right.get(period.clone())?);
+
+    // Moyenne complexe des périodes captées
+    let start = pre_delay + period.start;
+    let mut y = vec![Complex::new(0.0f32, 0.0); PERIOD];
+    for j in 0..PERIODS {
+        let from = start + j * PERIOD;
+        for (acc, c) in y.iter_mut().zip(spectrum(capture.get(from..from + PERIOD)?)) {
+            *acc += c / PERIODS as f32;
+        }
+    }
+
+    let left = transfer(&y, &x_left, 0);
+    let right = transfer(&y, &x_right, 1);
+    let bands_db = |h: &[Complex<f32>]| {
+        let spectrum = dsp::smooth_spectrum(&magnitude(h), options.smoothing);
+        let mut bands = dsp::spectrum_to_bands(&spectrum, sample_rate, NUM_BANDS);
+        if let Some(cal) = calibration {
+            cal.apply(&mut bands);
+        }
+        dsp::bands_to_db(&bands)
+    };
+    let (left_db, right_db) = (bands_db(&left), bands_db(&right));
+
+    // Niveau : moyenne en puissance des bandes de la plage d'analyse
+    let range = options.bands();
+    let level = |db: &[f32]| {
+        let power = db[range.clone()].iter().map(|d| 10f32.powf(d / 10.0)).sum::<f32>() / range.len() as f32;
+        10.0 * power.max(1e-12).log10()
+    };
+    let arrival = |h: &[Complex<f32>]| arrival_samples(h) / sample_rate as f32 * 1000.0;
+    let reading = DualReading { level_diff_db: level(&right_db) - level(&left_db), delay_ms: arrival(&right) - arrival(&left) };
+    Some(DualMeasurement { reading, left_db, right_db })
+}
+
+/// Fonction de transfert sur les bins de parité `parity` (les autres à zéro),
+/// complétée par symétrie hermitienne.
+fn transfer(y: &[Complex<f32>], x: &[Complex<f32>], parity: usize) -> Vec<Complex<f32>> {
+    let n = y.len();
+    let mut h = vec![Complex::new(0.0f32, 0.0); n];
+    for k in (1..n / 2).filter(|k| k % 2 == parity) {
+        let power = x[k].norm_sqr();
+        if power > 0.0 {
+            h[k] = y[k] * x[k].conj() / power;
+            h[n - k] = h[k].conj();
+        }
+    }
+    h
+}
+
+/// Amplitudes ramenées à la résolution de `FFT_SIZE` (moyenne en puissance
+/// des bins non nuls de chaque groupe), pour `dsp::spectrum_to_bands`.
+fn magnitude(h: &[Complex<f32>]) -> Vec<f32> {
+    let group = (h.len() / FFT_SIZE).max(1);
+    h[..h.len() / 2]
+        .chunks(group)
+        .map(|bins| {
+            let filled: Vec<f32> = bins.iter().map(|c| c.norm_sqr()).filter(|&p| p > 0.0).collect();
+            (filled.iter().sum::<f32>() / filled.len().max(1) as f32).sqrt()
+        })
+        .collect()
+}
+
+/// Arrivée du pic de l'IR (échantillons, interpolation parabolique). Un
+/// bin sur deux étant vide, l'IR se répète à chaque demi-période : le pic
+/// est cherché dans la première.
+fn arrival_samples(h: &[Complex<f32>]) -> f32 {
+    let mut ir = h.to_vec();
+    FftPlanner::<f32>::new().plan_fft_inverse(ir.len()).process(&mut ir);
+    let half: Vec<f32> = ir[..ir.len() / 2].iter().map(|c| c.re.abs()).collect();
+    let Some((peak, _)) = half.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)) else { return 0.0 };
+    match (peak.checked_sub(1).and_then(|i| half.get(i)), half.get(peak + 1)) {
+        (Some(&a), Some(&c)) => {
+            let b = half[peak];
+            let denom = a - 2.0 * b + c;
+            if denom.abs() > f32::EPSILON { peak as f32 + 0.5 * (a - c) / denom } else { peak as f32 }
+        }
+        _ => peak as f32,
+    }
+}
+
+/// Suivi simultané en cours : lectures successives et dernière mesure.
+#[derive(Debug, Clone, Default)]
+pub struct DualState {
+    pub readings: Vec<DualReading>,
+    pub latest: Option<DualMeasurement>,
+}
+
+impl DualState {
+    pub fn record(&mut self, m: DualMeasurement) {
+        self.readings.push(m.reading);
+        if self.readings.len() > HISTORY_LEN {
+            self.readings.remove(0);
+        }
+        self.latest = Some(m);
+    }
+
+    /// D − G moyen de chaque zone de `dsp::SPECTRAL_REGIONS` (dB).
+    pub fn region_diffs(&self) -> Option<Vec<f32>> {
+        let m = self.latest.as_ref()?;
+        let diff: Vec<f32> = m.right_db.iter().zip(&m.left_db).map(|(r, l)| r - l).collect();
+        Some(dsp::region_means(&diff))
+    }
+}
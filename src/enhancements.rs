@@ -0,0 +1,102 @@
+// ============================================================
+//  enhancements.rs — Détection des « améliorations » audio du système
+//
+//  Les traitements du système (Windows : Améliorations audio,
+//  égalisation de sonie, son spatial / virtual surround ; pilotes
+//  Realtek, Dolby, DTS ; effets PipeWire/PulseAudio) modifient le
+//  signal avant les enceintes et faussent les comparaisons de
+//  niveau et de spectre. Ils ne sont pas interrogeables de façon
+//  portable : on les déduit de leurs signatures dans les mesures.
+//
+//    - Pompage : un AGC / égalisation de sonie fait dériver le
+//      niveau pendant un palier stationnaire du test de linéarité,
+//      ou écrase la pente entrée → sortie.
+//    - Diaphonie virtuelle : le son spatial renvoie une copie
+//      filtrée (souvent inversée) du canal, qui apparaît dans l'IR
+//      comme une seconde arrivée nette moins d'une milliseconde
+//      après le son direct — trop tôt pour une réflexion.
+// ============================================================
+
+use serde::Serialize;
+
+use crate::{linearity::LinearityReport, measurement::Measurement};
+
+/// Dérive de niveau dans un palier au-delà de laquelle on suspecte un AGC (dB).
+pub const PUMPING_LIMIT_DB: f32 = 1.5;
+/// Pente entrée → sortie en dessous de laquelle le gain est régulé.
+pub const AGC_SLOPE_LIMIT: f32 = 0.8;
+
+/// Fenêtre de recherche de la seconde arrivée après le pic direct (ms).
+const CROSSFEED_MIN_MS: f32 = 0.1;
+const CROSSFEED_MAX_MS: f32 = 1.0;
+/// Niveau minimal de la seconde arrivée par rapport au pic direct (dB).
+const CROSSFEED_LEVEL_DB: f32 = -9.0;
+/// Creux requis entre les deux arrivées (fraction de la seconde) : écarte
+/// la traînée du woofer, qui prolonge le pic sans s'en détacher.
+const CROSSFEED_DIP: f32 = 0.3;
+
+/// Signature d'un traitement du système.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum Symptom {
+    /// Niveau qui dérive de `drift_db` pendant un palier stationnaire.
+    Pumping { side: &'static str, drift_db: f32 },
+    /// Pente entrée → sortie écrasée (`slope` < `AGC_SLOPE_LIMIT`).
+    GainRiding { side: &'static str, slope: f32 },
+    /// Seconde arrivée à `delay_ms` du son direct, `level_db` sous lui.
+    Crossfeed { side: &'static str, delay_ms: f32, level_db: f32, inverted: bool },
+}
+
+/// Seconde arrivée anormalement proche du son direct dans l'IR.
+fn crossfeed(ir: &[f32], sample_rate: u32) -> Option<(f32, f32, bool)> {
+    let (peak_idx, peak) = ir
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+        .map(|(i, &v)| (i, v))?;
+    if peak == 0.0 {
+        return None;
+    }
+    let ms = |t: f32| (t / 1000.0 * sample_rate as f32) as usize;
+    let from = peak_idx + ms(CROSSFEED_MIN_MS);
+    let to = (peak_idx + ms(CROSSFEED_MAX_MS)).min(ir.len());
+    if from >= to {
+        return None;
+    }
+    let (idx, value) = (from..to)
+        .map(|i| (i, ir[i]))
+        .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))?;
+    let level_db = 20.0 * (value.abs() / peak.abs()).log10();
+    let dip = ir[peak_idx + 1..idx].iter().fold(f32::MAX, |m, v| m.min(v.abs()));
+    if level_db < CROSSFEED_LEVEL_DB || dip > value.abs() * CROSSFEED_DIP {
+        return None;
+    }
+    let delay_ms = (idx - peak_idx) as f32 / sample_rate as f32 * 1000.0;
+    Some((delay_ms, level_db, value.signum() != peak.signum()))
+}
+
+/// Symptômes relevés dans les mesures disponibles (IR analysées, test de
+/// linéarité). `side` vaut « G » ou « D », comme dans les autres rapports.
+pub fn detect(left: Option<&Measurement>, right: Option<&Measurement>, linearity: Option<&LinearityReport>) -> Vec<Symptom> {
+    let mut symptoms = Vec::new();
+
+    for (side, m) in [("G", left), ("D", right)] {
+        let Some(m) = m else { continue };
+        let Some(ir) = &m.ir else { continue };
+        if let Some((delay_ms, level_db, inverted)) = crossfeed(ir, m.sample_rate) {
+            symptoms.push(Symptom::Crossfeed { side, delay_ms, level_db, inverted });
+        }
+    }
+
+    if let Some(report) = linearity {
+        for (side, l) in [("G", &report.left), ("D", &report.right)] {
+            let Some(l) = l else { continue };
+            if l.pumping_db.abs() > PUMPING_LIMIT_DB {
+                symptoms.push(Symptom::Pumping { side, drift_db: l.pumping_db });
+            }
+            if l.slope < AGC_SLOPE_LIMIT {
+                symptoms.push(Symptom::GainRiding { side, slope: l.slope });
+            }
+        }
+    }
+    symptoms
+}
@@ -0,0 +1,303 @@
+// ============================================================
+//  environment.rs — Conditions de mesure et corrélations
+//
+//  Sur une étude de plusieurs jours, un score qui baisse ne dit
+//  pas si la pièce a changé ou seulement son ambiance : chauffage
+//  qui tourne, rue plus bruyante le soir, air plus sec. Chaque
+//  analyse garde donc son contexte (`Environment`) : heure, bruit
+//  de fond mesuré ([H]), température et humidité saisies ([$]).
+//
+//  Le panneau ["] rapproche le score de chaque grandeur sur tout
+//  l'historique : coefficient de Pearson et pente de la droite des
+//  moindres carrés. Une corrélation forte désigne une dérive
+//  d'ambiance plutôt qu'un changement de l'installation — indice,
+//  pas preuve, d'autant que l'heure est traitée comme une droite
+//  (UTC, comme `HistoryEntry::time`).
+//
+//  La température corrige aussi la vitesse du son de toutes les
+//  distances (`dsp::speed_of_sound_at`) : 5 °C de moins, c'est
+//  1 % de moins sur chaque distance. Valeurs de départ dans la
+//  section `[room]` du fichier de configuration :
+//
+//    [room]
+//    temperature_c = 17.5
+//    humidity_pct  = 60      # facultative
+//
+//  La même section donne les dimensions de la pièce
+//  (`roommodes.rs`).
+// ============================================================
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    app::HistoryEntry,
+    dsp,
+    roommodes::{RoomDimensions, DIMENSION_RANGE_M},
+    strings::tr,
+};
+
+/// Nombre minimal d'analyses renseignées pour calculer une corrélation.
+pub const MIN_POINTS: usize = 4;
+
+/// |r| au-delà duquel le score est jugé lié à la grandeur.
+pub const STRONG_R: f32 = 0.5;
+
+/// Longueur maximale de la saisie « température humidité dimensions ».
+pub const MAX_INPUT_LEN: usize = 32;
+
+/// Plages admises à la saisie.
+const TEMPERATURE_RANGE_C: (f32, f32) = (-20.0, 50.0);
+const HUMIDITY_RANGE_PCT: (f32, f32) = (0.0, 100.0);
+
+/// Conditions saisies par l'utilisateur, reportées sur chaque analyse
+/// jusqu'à la saisie suivante.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Ambient {
+    pub temperature_c: Option<f32>,
+    pub humidity_pct: Option<f32>,
+}
+
+impl Ambient {
+    /// Lit « 21.5 45 » (température °C puis humidité %), l'une ou l'autre
+    /// pouvant manquer (« 21.5 », « - 45 ») ; une saisie vide efface tout.
+    pub fn parse(text: &str) -> Result<Self> {
+        let fields: Vec<&str> = text.split_whitespace().collect();
+        let s = tr();
+        if fields.len() > 2 {
+            bail!(s.ambient_expected);
+        }
+        let value = |i: usize, (min, max): (f32, f32), label: &str| -> Result<Option<f32>> {
+            match fields.get(i).copied() {
+                None | Some("-") => Ok(None),
+                Some(field) => {
+                    let Ok(v) = field.replace(',', ".").parse::<f32>() else {
+                        bail!((s.ambient_unreadable)(label, field));
+                    };
+                    if !(min..=max).contains(&v) {
+                        bail!((s.ambient_out_of_range)(label, min, max, v));
+                    }
+                    Ok(Some(v))
+                }
+            }
+        };
+        Ok(Ambient {
+            temperature_c: value(0, TEMPERATURE_RANGE_C, s.environment_factors[2])?,
+            humidity_pct: value(1, HUMIDITY_RANGE_PCT, s.environment_factors[3])?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.temperature_c.is_none() && self.humidity_pct.is_none()
+    }
+
+    /// Vitesse du son dans la pièce (m/s) ; sans température, celle par
+    /// défaut, l'humidité seule n'y changeant presque rien.
+    pub fn speed_of_sound(&self) -> f32 {
+        self.temperature_c.map_or(dsp::DEFAULT_SPEED_OF_SOUND, |t| dsp::speed_of_sound_at(t, self.humidity_pct))
+    }
+}
+
+/// Saisie [$] : conditions, puis dimensions de la pièce facultatives —
+/// « 21.5 45 5.2x3.8x2.5 ». La saisie s'ouvre pré-remplie des valeurs
+/// courantes et les remplace toutes : un champ retiré est effacé.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RoomEntry {
+    pub ambient: Ambient,
+    pub dimensions: Option<RoomDimensions>,
+}
+
+impl RoomEntry {
+    pub fn parse(text: &str) -> Result<Self> {
+        let (dims, rest): (Vec<&str>, Vec<&str>) =
+            text.split_whitespace().partition(|field| field.contains(['x', 'X', '×']));
+        if dims.len() > 1 {
+            bail!(tr().ambient_expected);
+        }
+        Ok(RoomEntry {
+            ambient: Ambient::parse(&rest.join(" "))?,
+            dimensions: dims.first().map(|field| RoomDimensions::parse(field)).transpose()?,
+        })
+    }
+
+    /// Texte relu par `parse`, pour pré-remplir la saisie.
+    pub fn to_text(&self) -> String {
+        let mut fields: Vec<String> = Vec::new();
+        let (t, h) = (self.ambient.temperature_c, self.ambient.humidity_pct);
+        if t.is_some() || h.is_some() {
+            fields.push(t.map_or("-".into(), |t| format!("{}", t)));
+        }
+        if let Some(h) = h {
+            fields.push(format!("{}", h));
+        }
+        if let Some(d) = self.dimensions {
+            if fields.is_empty() {
+                fields.push("-".into());
+            }
+            fields.push(format!("{}x{}x{}", d.length_m, d.width_m, d.height_m));
+        }
+        fields.join(" ")
+    }
+}
+
+/// Section `[room]` du fichier de configuration : conditions de départ,
+/// remplacées par une saisie [$].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoomParams {
+    pub temperature_c: Option<f32>,
+    pub humidity_pct: Option<f32>,
+    /// Dimensions intérieures (m), toutes les trois ou aucune : modes
+    /// propres marqués sur le spectre (`roommodes.rs`).
+    pub length_m: Option<f32>,
+    pub width_m: Option<f32>,
+    pub height_m: Option<f32>,
+}
+
+impl RoomParams {
+    pub fn validate(&self) -> Result<(), String> {
+        let check = |value: Option<f32>, (min, max): (f32, f32), label: &str| match value {
+            Some(v) if !(min..=max).contains(&v) => Err((tr().room_out_of_range)(label, min, max, v)),
+            _ => Ok(()),
+        };
+        check(self.temperature_c, TEMPERATURE_RANGE_C, "temperature_c")?;
+        check(self.humidity_pct, HUMIDITY_RANGE_PCT, "humidity_pct")?;
+        check(self.length_m, DIMENSION_RANGE_M, "length_m")?;
+        check(self.width_m, DIMENSION_RANGE_M, "width_m")?;
+        check(self.height_m, DIMENSION_RANGE_M, "height_m")?;
+        let given = [self.length_m, self.width_m, self.height_m].iter().filter(|d| d.is_some()).count();
+        if given != 0 && given != 3 {
+            return Err(tr().room_dimensions_together.to_string());
+        }
+        Ok(())
+    }
+
+    /// Dimensions de la pièce, si les trois sont données.
+    pub fn dimensions(self) -> Option<RoomDimensions> {
+        Some(RoomDimensions { length_m: self.length_m?, width_m: self.width_m?, height_m: self.height_m? })
+    }
+
+    pub fn ambient(self) -> Ambient {
+        Ambient { temperature_c: self.temperature_c, humidity_pct: self.humidity_pct }
+    }
+}
+
+/// Contexte d'une analyse. Tout est facultatif : les sessions plus
+/// anciennes n'en ont pas, et seul le bruit mesuré est connu sans saisie.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Environment {
+    /// Date de l'analyse (secondes depuis 1970, UTC).
+    pub unix_secs: Option<u64>,
+    /// Bruit de fond large bande de la dernière mesure [H] (dBFS).
+    pub noise_dbfs: Option<f32>,
+    pub temperature_c: Option<f32>,
+    pub humidity_pct: Option<f32>,
+}
+
+impl Environment {
+    /// Contexte courant : date système, bruit de fond et conditions saisies.
+    pub fn now(noise_dbfs: Option<f32>, ambient: Ambient) -> Self {
+        let unix_secs = SystemTime::now().duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs());
+        Environment { unix_secs, noise_dbfs, temperature_c: ambient.temperature_c, humidity_pct: ambient.humidity_pct }
+    }
+}
+
+/// Grandeur d'environnement rapprochée du score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Factor {
+    NoiseFloor,
+    HourOfDay,
+    Temperature,
+    Humidity,
+}
+
+impl Factor {
+    pub const ALL: [Factor; 4] = [Factor::NoiseFloor, Factor::HourOfDay, Factor::Temperature, Factor::Humidity];
+
+    /// Valeur de la grandeur pour une analyse, si elle est connue.
+    pub fn value(self, entry: &HistoryEntry) -> Option<f32> {
+        let env = &entry.environment;
+        match self {
+            Factor::NoiseFloor => env.noise_dbfs,
+            // Heure décimale : date si connue, sinon l'heure affichée « HH:MM:SS »
+            Factor::HourOfDay => match env.unix_secs {
+                Some(secs) => Some((secs % 86_400) as f32 / 3_600.0),
+                None => hour_of(&entry.time),
+            },
+            Factor::Temperature => env.temperature_c,
+            Factor::Humidity => env.humidity_pct,
+        }
+    }
+
+    pub fn unit(self) -> &'static str {
+        match self {
+            Factor::NoiseFloor => "dB",
+            Factor::HourOfDay => "h",
+            Factor::Temperature => "°C",
+            Factor::Humidity => "%",
+        }
+    }
+}
+
+/// Heure décimale d'un « HH:MM:SS ».
+fn hour_of(time: &str) -> Option<f32> {
+    let mut parts = time.split(':').map(|p| p.parse::<u32>().ok());
+    let (h, m) = (parts.next()??, parts.next()??);
+    (h < 24 && m < 60).then(|| h as f32 + m as f32 / 60.0)
+}
+
+/// Lien entre le score et une grandeur sur l'historique.
+#[derive(Debug, Clone, Copy)]
+pub struct Correlation {
+    pub factor: Factor,
+    /// Analyses où la grandeur est connue.
+    pub points: usize,
+    /// Coefficient de Pearson (−1…1).
+    pub r: f32,
+    /// Pente : points de score par unité de la grandeur.
+    pub slope: f32,
+    /// Valeurs extrêmes rencontrées.
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Correlation {
+    pub fn is_strong(&self) -> bool {
+        self.r.abs() >= STRONG_R
+    }
+}
+
+/// Corrélation du score avec `factor`, `None` avec moins de `MIN_POINTS`
+/// analyses renseignées ou une grandeur constante.
+pub fn correlate(history: &[HistoryEntry], factor: Factor) -> Option<Correlation> {
+    let points: Vec<(f32, f32)> = history.iter().filter_map(|h| Some((factor.value(h)?, h.score as f32))).collect();
+    if points.len() < MIN_POINTS {
+        return None;
+    }
+    let n = points.len() as f32;
+    let mean_x = points.iter().map(|p| p.0).sum::<f32>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f32>() / n;
+    let sxx: f32 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    let syy: f32 = points.iter().map(|p| (p.1 - mean_y).powi(2)).sum();
+    let sxy: f32 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    if sxx <= 1e-9 {
+        return None;
+    }
+    // Score constant : aucun lien, quelle que soit la grandeur
+    let r = if syy > 0.0 { sxy / (sxx * syy).sqrt() } else { 0.0 };
+    Some(Correlation {
+        factor,
+        points: points.len(),
+        r,
+        slope: sxy / sxx,
+        min: points.iter().map(|p| p.0).fold(f32::INFINITY, f32::min),
+        max: points.iter().map(|p| p.0).fold(f32::NEG_INFINITY, f32::max),
+    })
+}
+
+/// Nombre d'analyses où `factor` est renseigné.
+pub fn count(history: &[HistoryEntry], factor: Factor) -> usize {
+    history.iter().filter(|h| factor.value(h).is_some()).count()
+}
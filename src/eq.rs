@@ -0,0 +1,146 @@
+// ============================================================
+//  eq.rs — Égalisation paramétrique de l'écart G/D
+//
+//  Ce que le placement ne corrige pas (bosses de la pièce d'un
+//  seul côté, enceintes d'écoute légèrement différentes) se
+//  corrige en logiciel : un petit jeu de filtres en cloche (PK),
+//  appliqués à l'enceinte droite, ramène sa courbe sur celle de
+//  la gauche.
+//
+//  Ajustement glouton : à chaque tour, le plus grand écart
+//  restant (courbe lissée au tiers d'octave) reçoit une cloche de
+//  même amplitude et de largeur égale à celle de la bosse, puis
+//  la réponse exacte du biquad est retranchée. Arrêt sous
+//  `TOLERANCE_DB` ou à `MAX_FILTERS` filtres.
+//
+//  Sortie au format `ParametricEq` d'Equalizer APO (et de Peace,
+//  qui relit les mêmes fichiers).
+// ============================================================
+
+use rustfft::num_complex::Complex;
+use std::{f32::consts::PI, fmt::Write as _};
+
+use crate::dsp;
+
+pub const EQ_APO_TXT: &str = "eq_right.txt";
+
+/// Nombre maximal de filtres proposés.
+pub const MAX_FILTERS: usize = 10;
+/// Écart résiduel jugé inaudible (dB).
+pub const TOLERANCE_DB: f32 = 1.0;
+
+/// Plage corrigée (Hz) : sous 40 Hz la mesure est trop bruitée, au-dessus de
+/// 16 kHz trop dépendante de la position du micro.
+const FIT_MIN_HZ: f32 = 40.0;
+const FIT_MAX_HZ: f32 = 16_000.0;
+/// Gains limites (dB) : les creux se remplissent mal, les boosts restent modestes.
+const MAX_BOOST_DB: f32 = 6.0;
+const MAX_CUT_DB: f32 = -12.0;
+const MIN_Q: f32 = 0.5;
+const MAX_Q: f32 = 8.0;
+/// Demi-largeur du lissage (octave) appliqué à l'écart avant ajustement.
+const SMOOTH_OCT: f32 = 1.0 / 6.0;
+
+/// Filtre en cloche (biquad RBJ « peaking »).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeakingFilter {
+    pub freq_hz: f32,
+    pub gain_db: f32,
+    pub q: f32,
+}
+
+impl PeakingFilter {
+    /// Gain du filtre à `freq_hz` (dB), à la fréquence d'échantillonnage `sample_rate`.
+    pub fn response_db(&self, freq_hz: f32, sample_rate: u32) -> f32 {
+        let a = 10f32.powf(self.gain_db / 40.0);
+        let w0 = 2.0 * PI * self.freq_hz / sample_rate as f32;
+        let alpha = w0.sin() / (2.0 * self.q);
+        let (b, den) = (
+            [1.0 + alpha * a, -2.0 * w0.cos(), 1.0 - alpha * a],
+            [1.0 + alpha / a, -2.0 * w0.cos(), 1.0 - alpha / a],
+        );
+        let z = Complex::from_polar(1.0, -2.0 * PI * freq_hz / sample_rate as f32);
+        let eval = |c: [f32; 3]| c[0] + c[1] * z + c[2] * z * z;
+        20.0 * (eval(b).norm() / eval(den).norm()).log10()
+    }
+}
+
+/// Filtres à appliquer à l'enceinte droite pour annuler `diff_db` (D − G,
+/// `NUM_BANDS` bandes log), du plus fort au plus faible, biquads calculés à
+/// la fréquence d'échantillonnage des mesures.
+pub fn fit(diff_db: &[f32], sample_rate: u32) -> Vec<PeakingFilter> {
+    let n = diff_db.len();
+    let freqs: Vec<f32> = (0..n).map(|i| dsp::band_center_freq(i, n)).collect();
+    let in_range: Vec<usize> = (0..n).filter(|&i| (FIT_MIN_HZ..=FIT_MAX_HZ).contains(&freqs[i])).collect();
+    if in_range.is_empty() {
+        return Vec::new();
+    }
+
+    // Correction visée = −écart, lissée
+    let mut residual: Vec<f32> = (0..n)
+        .map(|i| {
+            let near: Vec<f32> = (0..n)
+                .filter(|&j| (freqs[j] / freqs[i]).log2().abs() <= SMOOTH_OCT)
+                .map(|j| diff_db[j])
+                .collect();
+            -near.iter().sum::<f32>() / near.len() as f32
+        })
+        .collect();
+
+    let mut filters = Vec::new();
+    while filters.len() < MAX_FILTERS {
+        let Some(&peak) = in_range.iter().max_by(|&&a, &&b| residual[a].abs().total_cmp(&residual[b].abs())) else {
+            break;
+        };
+        let target = residual[peak];
+        if target.abs() < TOLERANCE_DB {
+            break;
+        }
+
+        // Largeur de la bosse à mi-hauteur (dB), de part et d'autre du pic
+        let half = |i: usize| residual[i].signum() == target.signum() && residual[i].abs() >= target.abs() / 2.0;
+        let mut lo = peak;
+        while lo > 0 && half(lo - 1) {
+            lo -= 1;
+        }
+        let mut hi = peak;
+        while hi + 1 < n && half(hi + 1) {
+            hi += 1;
+        }
+        let octaves = (freqs[hi] / freqs[lo]).log2().max(1.0 / 12.0);
+        let ratio = 2f32.powf(octaves);
+        let q = (ratio.sqrt() / (ratio - 1.0)).clamp(MIN_Q, MAX_Q);
+
+        // Un filtre bloqué à sa limite ne réduit plus l'écart : inutile d'insister
+        if filters.iter().any(|p: &PeakingFilter| p.freq_hz == freqs[peak]) {
+            break;
+        }
+        let filter = PeakingFilter { freq_hz: freqs[peak], gain_db: target.clamp(MAX_CUT_DB, MAX_BOOST_DB), q };
+        for (r, &f) in residual.iter_mut().zip(&freqs) {
+            *r -= filter.response_db(f, sample_rate);
+        }
+        filters.push(filter);
+    }
+    filters
+}
+
+/// Gain total de la chaîne à `freq_hz` (dB).
+fn total_db(filters: &[PeakingFilter], freq_hz: f32, sample_rate: u32) -> f32 {
+    filters.iter().map(|p| p.response_db(freq_hz, sample_rate)).sum()
+}
+
+/// Fichier de configuration Equalizer APO / Peace : préampli négatif égal au
+/// plus fort boost de la chaîne (pas d'écrêtage), filtres sur le canal droit.
+pub fn to_equalizer_apo(filters: &[PeakingFilter], sample_rate: u32) -> String {
+    let boost = (0..dsp::NUM_BANDS)
+        .map(|i| total_db(filters, dsp::band_center_freq(i, dsp::NUM_BANDS), sample_rate))
+        .fold(0.0f32, f32::max);
+    let mut out = String::from("# Speaker Align — égalisation de l'enceinte DROITE sur la GAUCHE\n");
+    out.push_str("Channel: R\n");
+    let _ = writeln!(out, "Preamp: {:.1} dB", -boost);
+    for (i, p) in filters.iter().enumerate() {
+        let _ = writeln!(out, "Filter {}: ON PK Fc {:.0} Hz Gain {:.1} dB Q {:.2}", i + 1, p.freq_hz, p.gain_db, p.q);
+    }
+    out.push_str("Channel: all\n");
+    out
+}
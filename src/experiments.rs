@@ -0,0 +1,75 @@
+// ============================================================
+//  experiments.rs — Journal « un changement à la fois »
+//
+//  Chaque modification physique (« toe-in droite 5° », « tapis
+//  devant le meuble ») est notée avec [/] juste avant de la
+//  faire. La dernière analyse sert de mesure « avant », la
+//  suivante de mesure « après » : l'effet mesuré de chaque
+//  changement isolé (score, délai, niveau) reste lisible sur
+//  toute la session et voyage avec elle dans le .spkalign.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::HistoryEntry;
+
+/// Longueur maximale d'une description (caractères).
+pub const MAX_DESCRIPTION_LEN: usize = 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Experiment {
+    pub description: String,
+    /// Analyse précédant le changement.
+    pub before: HistoryEntry,
+    /// Première analyse après le changement ; `None` tant qu'elle n'a pas eu lieu.
+    pub after: Option<HistoryEntry>,
+}
+
+/// Effet mesuré d'un changement. Délai et niveau comptent en écart absolu :
+/// négatif = les deux enceintes se rapprochent.
+#[derive(Debug, Clone, Copy)]
+pub struct ExperimentEffect {
+    pub score_delta: i32,
+    pub delay_delta_ms: f32,
+    pub level_delta_db: f32,
+}
+
+impl Experiment {
+    pub fn effect(&self) -> Option<ExperimentEffect> {
+        let (before, after) = (&self.before, self.after.as_ref()?);
+        Some(ExperimentEffect {
+            score_delta: after.score as i32 - before.score as i32,
+            delay_delta_ms: after.delay_ms.abs() - before.delay_ms.abs(),
+            level_delta_db: after.level_diff_db.abs() - before.level_diff_db.abs(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExperimentLog {
+    /// Changements dans l'ordre de saisie ; seul le dernier peut attendre sa mesure « après ».
+    pub entries: Vec<Experiment>,
+}
+
+impl ExperimentLog {
+    /// Note un changement mesuré à partir de `before` (dernière analyse).
+    /// Un changement encore en attente est remplacé : sans mesure entre
+    /// les deux, les effets ne seraient pas séparables.
+    pub fn start(&mut self, description: &str, before: HistoryEntry) {
+        if self.pending().is_some() {
+            self.entries.pop();
+        }
+        self.entries.push(Experiment { description: description.trim().to_string(), before, after: None });
+    }
+
+    /// Rattache une nouvelle analyse au changement en attente, s'il y en a un.
+    pub fn close(&mut self, after: &HistoryEntry) -> Option<&Experiment> {
+        let pending = self.entries.last_mut().filter(|e| e.after.is_none())?;
+        pending.after = Some(after.clone());
+        Some(pending)
+    }
+
+    pub fn pending(&self) -> Option<&Experiment> {
+        self.entries.last().filter(|e| e.after.is_none())
+    }
+}
@@ -0,0 +1,267 @@
+// ============================================================
+//  export.rs — Export de la réponse en fréquence
+//
+//  Écrit les spectres en bandes dans des formats lisibles par
+//  d'autres outils, pour poursuivre le travail d'égalisation :
+//    left.frd / right.frd — format REW (fréquence, dB, phase)
+//    diff.csv             — tableur : fréquence, G, D, D−G (notes
+//                           de l'analyse en lignes `# `)
+//    left_ir.wav / right_ir.wav — réponses impulsionnelles (WAV
+//                           32 bits flottant, pour un convolueur)
+//    eq_right.txt         — filtres Equalizer APO / Peace ramenant
+//                           la droite sur la gauche (`eq`)
+//    camilladsp.yml       — retard et gain d'alignement G/D pour
+//                           CamillaDSP (Linux), après analyse, et
+//                           passe-tout si le retard varie par octave
+//    allpass_left.wav / allpass_right.wav — ces passe-tout en FIR
+//    eq_baseline.txt, camilladsp_baseline.yml — les mêmes, tirés de
+//                           l'asymétrie acceptée ([%]) au lieu de la
+//                           dernière analyse
+//  En mode fenêtre d'écoute, ce sont les courbes moyennées qui
+//  sont exportées (celles affichées à l'écran).
+//
+//  Les nombres sont toujours écrits avec un point décimal et sans
+//  séparateur de milliers, quelle que soit la langue de l'interface
+//  (`format!` ne dépend d'aucune locale) : REW et les tableurs les
+//  relisent tels quels.
+// ============================================================
+
+use anyhow::{Context, Result, bail};
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    allpass::{self, AllpassFilter, PhaseCorrection},
+    app::AppState,
+    dsp, eq,
+    measurement::Measurement,
+    strings::tr,
+};
+
+pub const LEFT_FRD: &str = "left.frd";
+pub const RIGHT_FRD: &str = "right.frd";
+pub const DIFF_CSV: &str = "diff.csv";
+pub const LEFT_IR_WAV: &str = "left_ir.wav";
+pub const RIGHT_IR_WAV: &str = "right_ir.wav";
+pub const CAMILLADSP_YML: &str = "camilladsp.yml";
+pub const EQ_BASELINE_TXT: &str = "eq_baseline.txt";
+pub const CAMILLADSP_BASELINE_YML: &str = "camilladsp_baseline.yml";
+pub const ALLPASS_LEFT_WAV: &str = "allpass_left.wav";
+pub const ALLPASS_RIGHT_WAV: &str = "allpass_right.wav";
+
+/// Marge conservée avant le front du son direct dans les IR exportées.
+const IR_PRE_SECS: f32 = 0.005;
+/// Durée des IR exportées (son direct + réverbération).
+const IR_EXPORT_SECS: f32 = 1.0;
+
+/// Contenu .frd : en-tête commenté (titre, lignes `notes`) puis « fréquence
+/// dB phase » par ligne. La phase n'est pas mesurée par bande ; REW accepte
+/// une colonne à 0.
+pub fn to_frd(bands_db: &[f32], title: &str, notes: &[String]) -> String {
+    let n = bands_db.len();
+    let mut out = format!("* Speaker Align — {}\n", title);
+    for line in notes {
+        let _ = writeln!(out, "* {}", line);
+    }
+    out.push_str("* Freq(Hz) SPL(dB) Phase(deg)\n");
+    for (i, db) in bands_db.iter().enumerate() {
+        let _ = writeln!(out, "{:.2} {:.2} 0.0", dsp::band_center_freq(i, n), db);
+    }
+    out
+}
+
+/// Contenu CSV : lignes `notes` en commentaire (`# `), puis une ligne par
+/// bande avec les deux spectres et leur différence.
+pub fn to_csv(left_db: &[f32], right_db: &[f32], diff_db: &[f32], notes: &[String]) -> String {
+    let n = left_db.len();
+    let mut out = String::new();
+    for line in notes {
+        let _ = writeln!(out, "# {}", line);
+    }
+    out.push_str("frequency_hz,left_db,right_db,diff_db\n");
+    for (i, l) in left_db.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "{:.2},{:.2},{:.2},{:.2}",
+            dsp::band_center_freq(i, n),
+            l,
+            right_db.get(i).copied().unwrap_or(f32::NAN),
+            diff_db.get(i).copied().unwrap_or(f32::NAN),
+        );
+    }
+    out
+}
+
+/// Filtres et pipeline CamillaDSP (≥ 3.0) compensant l'écart G/D : le côté
+/// en avance est retardé de `delay_ms` (jamais d'avance possible), le côté le
+/// plus fort est atténué de `level_diff_db` (jamais de gain positif), puis
+/// les passe-tout de `phase` alignent le retard de chaque octave.
+/// Canaux 0 = gauche, 1 = droite ; la section `devices` reste à compléter.
+pub fn to_camilladsp(delay_ms: f32, level_diff_db: f32, phase: &PhaseCorrection) -> String {
+    // delay_ms > 0 : la droite arrive après la gauche → retarder la gauche
+    let delayed = if delay_ms > 0.0 { 0 } else { 1 };
+    // level_diff_db > 0 : la droite est plus forte → l'atténuer
+    let attenuated = if level_diff_db > 0.0 { 1 } else { 0 };
+
+    let mut out = String::new();
+    let _ = writeln!(out, "# Speaker Align — alignement G/D pour CamillaDSP 3.x");
+    let _ = writeln!(out, "# À fusionner dans la configuration existante (section `devices` non fournie).");
+    let _ = writeln!(out, "# CamillaDSP 2.x : remplacer `channels: [n]` par `channel: n`.");
+    let _ = writeln!(out, "# Retard D − G mesuré : {:.3} ms, écart de niveau D − G : {:.2} dB", delay_ms, level_diff_db);
+    let _ = writeln!(out, "filters:");
+    let _ = writeln!(out, "  align_delay:\n    type: Delay\n    parameters:");
+    let _ = writeln!(out, "      delay: {:.3}\n      unit: ms\n      subsample: true", delay_ms.abs());
+    let _ = writeln!(out, "  align_gain:\n    type: Gain\n    parameters:");
+    let _ = writeln!(out, "      gain: {:.2}\n      inverted: false", -level_diff_db.abs());
+    let sides = [("left", 0, &phase.left), ("right", 1, &phase.right)];
+    for (side, _, filters) in sides {
+        for (i, filter) in filters.iter().enumerate() {
+            let _ = writeln!(out, "  phase_{}_{}:\n    type: Biquad\n    parameters:", side, i + 1);
+            match *filter {
+                AllpassFilter::FirstOrder { freq_hz } => {
+                    let _ = writeln!(out, "      type: AllpassFO\n      freq: {:.1}", freq_hz);
+                }
+                AllpassFilter::SecondOrder { freq_hz, q } => {
+                    let _ = writeln!(out, "      type: Allpass\n      freq: {:.1}\n      q: {:.2}", freq_hz, q);
+                }
+            }
+        }
+    }
+    let _ = writeln!(out, "pipeline:");
+    let _ = writeln!(out, "  - type: Filter\n    channels: [{}]\n    names:\n      - align_delay", delayed);
+    let _ = writeln!(out, "  - type: Filter\n    channels: [{}]\n    names:\n      - align_gain", attenuated);
+    for (side, channel, filters) in sides.into_iter().filter(|(_, _, f)| !f.is_empty()) {
+        let _ = writeln!(out, "  - type: Filter\n    channels: [{}]\n    names:", channel);
+        for i in 0..filters.len() {
+            let _ = writeln!(out, "      - phase_{}_{}", side, i + 1);
+        }
+    }
+    out
+}
+
+/// Écrit `ir` en WAV mono 32 bits flottant.
+pub fn write_ir_wav(path: &Path, ir: &[f32], sample_rate: u32) -> Result<()> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(path, spec)
+        .with_context(|| (tr().cannot_create)(&path.display().to_string()))?;
+    for &s in ir {
+        writer.write_sample(s)?;
+    }
+    writer.finalize()?;
+    Ok(())
+}
+
+/// IR de G et D découpées sur la même fenêtre (le retard relatif est conservé)
+/// et normalisées par leur pic commun (l'écart de niveau aussi).
+pub fn trimmed_irs(left: Option<&Measurement>, right: Option<&Measurement>, onset_db: f32) -> (Option<Vec<f32>>, Option<Vec<f32>>) {
+    let ir_of = |m: &Measurement| {
+        m.ir.clone()
+            .unwrap_or_else(|| dsp::compute_impulse_response(&m.samples, &m.test_signal, &m.sweep, m.sample_rate))
+    };
+    let left = left.map(|m| (ir_of(m), m.sample_rate));
+    let right = right.map(|m| (ir_of(m), m.sample_rate));
+
+    let irs = [&left, &right];
+    let Some(sr) = irs.iter().find_map(|ir| ir.as_ref().map(|(_, sr)| *sr)) else {
+        return (None, None);
+    };
+    let onset = irs
+        .iter()
+        .filter_map(|ir| ir.as_ref().and_then(|(ir, sr)| dsp::ir_onset(ir, *sr, onset_db)).map(|o| o.index))
+        .min()
+        .unwrap_or(0);
+    let start = onset.saturating_sub((IR_PRE_SECS * sr as f32) as usize);
+    let len = (IR_EXPORT_SECS * sr as f32) as usize;
+    let peak = irs
+        .iter()
+        .filter_map(|ir| ir.as_ref())
+        .flat_map(|(ir, _)| ir.iter())
+        .fold(0.0f32, |m, v| m.max(v.abs()));
+    let gain = if peak > 0.0 { 1.0 / peak } else { 1.0 };
+
+    let trim = |ir: Option<(Vec<f32>, u32)>| {
+        ir.map(|(ir, _)| ir.iter().skip(start).take(len).map(|v| v * gain).collect())
+    };
+    (trim(left), trim(right))
+}
+
+/// Écrit les fichiers disponibles dans `dir` et renvoie leurs chemins.
+/// `diff.csv` n'est produit qu'une fois les deux enceintes capturées.
+pub fn export_all(state: &AppState, dir: &Path) -> Result<Vec<PathBuf>> {
+    let (left, right) = match &state.window_summary {
+        Some(w) => (Some(w.left_db.clone()), Some(w.right_db.clone())),
+        None => (
+            state.left.as_ref().map(|m| m.bands_db.clone()),
+            state.right.as_ref().map(|m| m.bands_db.clone()),
+        ),
+    };
+    if left.is_none() && right.is_none() {
+        bail!(tr().nothing_to_export);
+    }
+
+    let write = |name: &str, content: String| -> Result<PathBuf> {
+        let path = dir.join(name);
+        fs::write(&path, content).with_context(|| (tr().cannot_write)(&path.display().to_string()))?;
+        Ok(path)
+    };
+
+    let mut written = Vec::new();
+    let notes = state.current_notes().header_lines();
+    if let Some(l) = &left {
+        written.push(write(LEFT_FRD, to_frd(l, tr().frd_left_title, &notes))?);
+    }
+    if let Some(r) = &right {
+        written.push(write(RIGHT_FRD, to_frd(r, tr().frd_right_title, &notes))?);
+    }
+    if let (Some(l), Some(r)) = (&left, &right) {
+        let diff = state
+            .diff_db
+            .clone()
+            .unwrap_or_else(|| l.iter().zip(r.iter()).map(|(l, r)| r - l).collect());
+        written.push(write(DIFF_CSV, to_csv(l, r, &diff, &notes))?);
+        let sample_rate = state.left.as_ref().map_or(state.sample_rate, |m| m.sample_rate);
+        written.push(write(eq::EQ_APO_TXT, eq::to_equalizer_apo(&eq::fit(&diff, sample_rate), sample_rate))?);
+        // Retard et écart de niveau n'existent qu'après l'analyse [A]
+        if state.diff_db.is_some() {
+            let phase = state.band_delay.as_ref().map(allpass::fit).unwrap_or_default();
+            written.push(write(CAMILLADSP_YML, to_camilladsp(state.delay_ms, state.level_diff_db, &phase))?);
+            // Mêmes passe-tout en FIR, pour un convolueur
+            for (name, filters) in [(ALLPASS_LEFT_WAV, &phase.left), (ALLPASS_RIGHT_WAV, &phase.right)] {
+                if !filters.is_empty() {
+                    let path = dir.join(name);
+                    write_ir_wav(&path, &allpass::impulse_response(filters, sample_rate, allpass::FIR_TAPS), sample_rate)?;
+                    written.push(path);
+                }
+            }
+        }
+    }
+
+    // Correction complémentaire de l'asymétrie acceptée
+    if let Some(accepted) = &state.baseline {
+        written.push(write(EQ_BASELINE_TXT, eq::to_equalizer_apo(&eq::fit(&accepted.diff_db, state.sample_rate), state.sample_rate))?);
+        written.push(write(
+            CAMILLADSP_BASELINE_YML,
+            to_camilladsp(accepted.delay_ms, accepted.level_diff_db, &PhaseCorrection::default()),
+        )?);
+    }
+
+    // Réponses impulsionnelles (mesures brutes, hors mode fenêtre)
+    let (left_ir, right_ir) = trimmed_irs(state.left.as_ref(), state.right.as_ref(), state.analysis.onset_db);
+    let sample_rate = state.left.as_ref().or(state.right.as_ref()).map(|m| m.sample_rate);
+    for (name, ir) in [(LEFT_IR_WAV, left_ir), (RIGHT_IR_WAV, right_ir)] {
+        if let (Some(ir), Some(sr)) = (ir, sample_rate) {
+            let path = dir.join(name);
+            write_ir_wav(&path, &ir, sr)?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
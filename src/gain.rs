@@ -0,0 +1,111 @@
+// ============================================================
+//  gain.rs — Écart de niveau traduit en réglage de l'appareil
+//
+//  « Δ niveau = 1,4 dB » ne dit pas quoi tourner. Selon le
+//  matériel déclaré dans `[gain]`, l'écart mesuré devient :
+//    trim     — réglage de niveau par canal d'un ampli A/V, par
+//               pas de 0,5 dB : on baisse l'enceinte la plus forte ;
+//    balance  — crans du bouton de balance, vers l'enceinte la
+//               plus faible ;
+//    detents  — crans du gain d'entrée d'une enceinte active : on
+//               baisse la plus forte.
+//  Le nombre de pas est arrondi au plus proche : un écart de moins
+//  d'un demi-pas ne se corrige pas et n'est pas proposé. Le reste
+//  après réglage est affiché avec la suggestion.
+//
+//    [gain]
+//    control = "balance"
+//    step_db = 1.0     # absent = valeur usuelle de l'organe
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::strings::tr;
+
+/// Pas le plus fin et le plus grossier acceptés dans `[gain]` (dB).
+pub const MIN_STEP_DB: f32 = 0.1;
+pub const MAX_STEP_DB: f32 = 6.0;
+
+/// Organe de réglage du niveau disponible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GainControl {
+    /// Niveau par canal d'un ampli A/V (« trim », « niveau d'enceinte »).
+    #[default]
+    Trim,
+    /// Bouton de balance d'un ampli stéréo.
+    Balance,
+    /// Gain d'entrée cranté d'une enceinte active.
+    Detents,
+}
+
+impl GainControl {
+    /// Pas usuel de l'organe (dB).
+    pub fn default_step_db(self) -> f32 {
+        match self {
+            GainControl::Trim => 0.5,
+            GainControl::Balance | GainControl::Detents => 1.0,
+        }
+    }
+}
+
+/// Section `[gain]` du fichier de configuration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GainParams {
+    pub control: GainControl,
+    /// Effet d'un pas sur l'écart G/D (dB) ; absent = `default_step_db`.
+    pub step_db: Option<f32>,
+}
+
+impl GainParams {
+    pub fn step(&self) -> f32 {
+        self.step_db.unwrap_or_else(|| self.control.default_step_db())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let step = self.step();
+        if (MIN_STEP_DB..=MAX_STEP_DB).contains(&step) {
+            Ok(())
+        } else {
+            Err((tr().gain_step_range)(MIN_STEP_DB, MAX_STEP_DB))
+        }
+    }
+
+    /// Réglage ramenant l'écart `level_diff_db` (D − G) au plus près de 0 ;
+    /// `None` s'il tient dans un demi-pas.
+    pub fn suggest(&self, level_diff_db: f32) -> Option<GainAdjustment> {
+        let step = self.step();
+        let steps = (level_diff_db.abs() / step).round() as u32;
+        if steps == 0 {
+            return None;
+        }
+        let applied_db = steps as f32 * step;
+        Some(GainAdjustment {
+            control: self.control,
+            right_louder: level_diff_db > 0.0,
+            steps,
+            step_db: step,
+            residual_db: level_diff_db.abs() - applied_db,
+        })
+    }
+}
+
+/// Réglage proposé.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GainAdjustment {
+    pub control: GainControl,
+    /// Enceinte droite la plus forte : trim et gain d'entrée baissent la
+    /// droite, la balance tourne vers la gauche.
+    pub right_louder: bool,
+    pub steps: u32,
+    pub step_db: f32,
+    /// Écart restant après réglage (dB) : négatif si l'arrondi dépasse.
+    pub residual_db: f32,
+}
+
+impl GainAdjustment {
+    pub fn applied_db(&self) -> f32 {
+        self.steps as f32 * self.step_db
+    }
+}
@@ -0,0 +1,171 @@
+// ============================================================
+//  harmonics.rs — Distorsion harmonique extraite du sweep (Farina)
+//
+//  Dans un sweep logarithmique, l'harmonique k d'une fréquence
+//  sort de l'enceinte ln(k) / ln(f_stop / f_start) × durée avant
+//  que le sweep n'atteigne k fois cette fréquence. Après
+//  déconvolution, chaque ordre forme donc sa propre IR, en avance
+//  d'autant sur l'IR linéaire. Chaque IR est isolée par une
+//  fenêtre, puis, par octave de `dsp::OCTAVE_CENTERS` :
+//    THD(f) = √(Σ |H_k(k·f)|²) / |H_1(f)|,  k = 2 … 5
+//  Aucune capture supplémentaire : le sweep de la mesure suffit.
+//  Une harmonique hors de la plage du sweep n'est pas comptée ;
+//  une octave sans aucune harmonique mesurable n'a pas de valeur.
+//  Le bruit de fond tombe aussi dans les fenêtres des harmoniques :
+//  un sweep plus long ou moyenné abaisse le plancher du THD.
+// ============================================================
+
+use rustfft::{FftPlanner, num_complex::Complex};
+use serde::{Deserialize, Serialize};
+
+use crate::dsp::{self, OCTAVE_CENTERS, SweepParams};
+
+/// Ordre le plus élevé pris en compte (harmoniques 2 à `MAX_ORDER`).
+pub const MAX_ORDER: usize = 5;
+
+/// THD au-dessus duquel une octave est signalée (dB / fondamentale, 1 %).
+pub const THD_LIMIT_DB: f32 = -40.0;
+
+/// Marge avant le pic de chaque IR d'harmonique (pré-oscillations).
+const PRE_MS: f32 = 2.0;
+
+/// Part de l'écart entre deux ordres voisins gardée dans une fenêtre.
+const WINDOW_FILL: f32 = 0.8;
+
+/// Périodes de la limite basse d'une octave que la fenêtre doit contenir.
+const MIN_PERIODS: f32 = 4.0;
+
+/// THD d'une octave.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct OctaveThd {
+    pub freq_hz: f32,
+    /// Harmoniques 2 à `MAX_ORDER` / fondamentale (dB).
+    pub thd_db: f32,
+    /// Harmonique 2 seule (dB) : dominante d'un défaut asymétrique.
+    pub h2_db: f32,
+    /// Harmonique 3 seule (dB) : dominante d'un écrêtage symétrique.
+    pub h3_db: f32,
+}
+
+impl OctaveThd {
+    pub fn thd_pct(&self) -> f32 {
+        100.0 * 10f32.powf(self.thd_db / 20.0)
+    }
+}
+
+/// Avance (s) de l'IR de l'harmonique `order` sur l'IR linéaire.
+pub fn harmonic_lead_s(params: &SweepParams, duration_s: f32, order: usize) -> f32 {
+    duration_s * (order as f32).ln() / (params.f_stop_hz / params.f_start_hz).ln()
+}
+
+/// THD par octave d'une capture de sweep ; vide si l'IR est introuvable
+/// ou le sweep trop court pour séparer les harmoniques.
+pub fn sweep_thd(capture: &[f32], sweep: &[f32], params: &SweepParams, sample_rate: u32) -> Vec<OctaveThd> {
+    let full = dsp::deconvolve_sweep(capture, sweep, params, sample_rate);
+    let Some(peak) = full
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+        .filter(|(_, v)| v.abs() > 1e-9)
+        .map(|(i, _)| i)
+    else {
+        return Vec::new();
+    };
+
+    let sr = sample_rate as f32;
+    let duration_s = sweep.len() as f32 / sr;
+    let lead = |order: usize| (harmonic_lead_s(params, duration_s, order) * sr).round() as usize;
+
+    // Même fenêtre pour tous les ordres : la plus courte sépare les deux plus
+    // élevés, dont les IR sont les plus rapprochées
+    let pre = (PRE_MS / 1000.0 * sr) as usize;
+    let gap = lead(MAX_ORDER) - lead(MAX_ORDER - 1);
+    let len = ((gap as f32 * WINDOW_FILL) as usize).saturating_sub(pre);
+    if len < 64 || peak < lead(MAX_ORDER) + pre {
+        return Vec::new();
+    }
+
+    // Fondu d'entrée sur la marge, demi-Hann sur le dernier quart
+    let fade_out = len / 4;
+    let window: Vec<f32> = (0..pre + len)
+        .map(|i| {
+            if i < pre {
+                (i as f32 / pre as f32).min(1.0)
+            } else if i >= pre + len - fade_out {
+                let x = (i - (pre + len - fade_out)) as f32 / fade_out as f32;
+                0.5 * (1.0 + (std::f32::consts::PI * x).cos())
+            } else {
+                1.0
+            }
+        })
+        .collect();
+
+    let fft_len = (pre + len).next_power_of_two();
+    let fft = FftPlanner::<f32>::new().plan_fft_forward(fft_len);
+    let power_spectrum = |ir: &[f32], start: usize| -> Vec<f32> {
+        let mut buf: Vec<Complex<f32>> = ir[start..start + pre + len]
+            .iter()
+            .zip(&window)
+            .map(|(x, w)| Complex::new(x * w, 0.0))
+            .chain(std::iter::repeat_n(Complex::new(0.0, 0.0), fft_len - pre - len))
+            .collect();
+        fft.process(&mut buf);
+        buf[..fft_len / 2].iter().map(|c| c.norm_sqr()).collect()
+    };
+
+    // Le sweep déconvolué par lui-même donne la réponse propre de la méthode
+    // (filtre inverse non plat) : chaque ordre en est divisé
+    let reference = dsp::deconvolve_sweep(sweep, sweep, params, sample_rate);
+    let reference = power_spectrum(&reference, sweep.len() - 1 - pre);
+    // Spectre de puissance de l'IR de chaque ordre (indice 0 = fondamentale)
+    let spectra: Vec<Vec<f32>> = (1..=MAX_ORDER)
+        .map(|order| {
+            power_spectrum(&full, peak - lead(order) - pre)
+                .iter()
+                .zip(&reference)
+                .map(|(p, r)| if *r > 0.0 { p / r } else { 0.0 })
+                .collect()
+        })
+        .collect();
+
+    let bin_hz = sr / fft_len as f32;
+    // Puissance moyenne par bin. L'énergie de l'harmonique k d'une octave
+    // s'étale sur une bande k fois plus large : sa densité est multipliée par k
+    let band_power = |power: &[f32], lo: f32, hi: f32| -> f32 {
+        let k0 = (lo / bin_hz).ceil() as usize;
+        let k1 = ((hi / bin_hz).floor() as usize + 1).min(power.len());
+        match power.get(k0..k1) {
+            Some(p) if !p.is_empty() => p.iter().sum::<f32>() / p.len() as f32,
+            _ => 0.0,
+        }
+    };
+    let min_hz = MIN_PERIODS * sr / len as f32;
+    let max_hz = params.f_stop_hz.min(sr / 2.0);
+
+    OCTAVE_CENTERS
+        .iter()
+        .filter_map(|&fc| {
+            let (lo, hi) = (fc / 2f32.sqrt(), fc * 2f32.sqrt());
+            if lo < params.f_start_hz.max(min_hz) || 2.0 * hi > max_hz {
+                return None;
+            }
+            let fundamental = band_power(&spectra[0], lo, hi);
+            if fundamental <= 0.0 {
+                return None;
+            }
+            let orders: Vec<f32> = (2..=MAX_ORDER)
+                .map(|k| {
+                    let k = k as f32;
+                    if k * hi > max_hz { 0.0 } else { k * band_power(&spectra[k as usize - 1], k * lo, k * hi) }
+                })
+                .collect();
+            let to_db = |p: f32| if p > 0.0 { 10.0 * (p / fundamental).log10() } else { -100.0 };
+            Some(OctaveThd {
+                freq_hz: fc,
+                thd_db: to_db(orders.iter().sum()),
+                h2_db: to_db(orders[0]),
+                h3_db: to_db(orders[1]),
+            })
+        })
+        .collect()
+}
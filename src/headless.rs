@@ -0,0 +1,189 @@
+// ============================================================
+//  headless.rs — Mode sans interface (scripts, automatisation)
+//
+//  Exécute la séquence complète sans ratatui :
+//    [niveau auto] → capture GAUCHE → capture DROITE → analyse
+//  et produit un rapport sérialisable en JSON.
+// ============================================================
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::{path::Path, sync::mpsc};
+
+use crate::{
+    app::AppState,
+    audio::{self, Capture, CaptureLink, CaptureTiming, Channel, Progress},
+    calibration::MicCalibration,
+    config::Config,
+    drift,
+    dsp::{self, ScoreBreakdown, NUM_BANDS},
+    enhancements::Symptom,
+    levelcal,
+    measurement::Measurement,
+    pipeline::AnalysisOptions,
+    report::Response,
+    schema,
+    strings::tr,
+    target::TargetCurve,
+};
+
+/// Résultat d'une analyse complète, tel qu'imprimé par `--headless`.
+#[derive(Debug, Clone, Serialize)]
+pub struct AnalysisReport {
+    /// Version du format du rapport (`schema::REPORT_VERSION`).
+    pub schema_version: u32,
+    pub frequencies_hz: Vec<f32>,
+    pub left_db: Vec<f32>,
+    pub right_db: Vec<f32>,
+    pub diff_db: Vec<f32>,
+    pub delay_ms: f32,
+    pub level_diff_db: f32,
+    pub freq_tilt_db: f32,
+    pub left_distance_m: Option<f32>,
+    pub right_distance_m: Option<f32>,
+    /// Latence système mesurée par la boucle de référence (distances alors absolues).
+    pub latency_ms: Option<f32>,
+    pub score: Option<u32>,
+    /// Score séparé : placement (retard, réflexions) / réglage (niveau, spectre).
+    pub score_breakdown: Option<ScoreBreakdown>,
+    /// Profil de score (`[score]`) des deux valeurs précédentes.
+    pub score_profile: String,
+    /// Traitements audio du système suspectés (mesures alors peu fiables).
+    pub enhancements: Vec<Symptom>,
+    /// Courbe cible (`--target`) et écart moyen de G et D à elle (dB).
+    pub target: Option<String>,
+    pub target_deviation_db: Option<(f32, f32)>,
+}
+
+impl AnalysisReport {
+    pub fn from_state(state: &AppState) -> Self {
+        let bands = |m: &Option<Measurement>| m.as_ref().map(|m| m.bands_db.clone()).unwrap_or_default();
+        AnalysisReport {
+            schema_version: schema::REPORT_VERSION,
+            frequencies_hz: (0..NUM_BANDS).map(|i| dsp::band_center_freq(i, NUM_BANDS)).collect(),
+            left_db: bands(&state.left),
+            right_db: bands(&state.right),
+            diff_db: state.diff_db.clone().unwrap_or_default(),
+            delay_ms: state.delay_ms,
+            level_diff_db: state.level_diff_db,
+            freq_tilt_db: state.freq_tilt,
+            left_distance_m: state.left.as_ref().and_then(|m| m.dist_m),
+            right_distance_m: state.right.as_ref().and_then(|m| m.dist_m),
+            latency_ms: state.left.as_ref().and_then(|m| m.latency_ms),
+            score: state.score,
+            score_breakdown: state.score_breakdown(),
+            score_profile: state.scoring.profile.name().to_string(),
+            enhancements: state.enhancements.clone(),
+            target: state.target.as_ref().map(|t| t.name.clone()),
+            target_deviation_db: state.target_deviations(),
+        }
+    }
+
+    /// Courbes du rapport pour `report::write_png` (`--png`), sur la plage
+    /// d'analyse par défaut.
+    pub fn response(&self) -> Response<'_> {
+        Response {
+            left_db: Some(&self.left_db),
+            right_db: Some(&self.right_db),
+            diff_db: Some(&self.diff_db),
+            bands: AnalysisOptions::default().bands(),
+            notes: Vec::new(),
+        }
+    }
+
+    /// Résumé d'une ligne (notifications).
+    pub fn summary(&self) -> String {
+        match self.score {
+            Some(score) => (tr().headless_summary)(score, self.delay_ms, self.level_diff_db),
+            None => tr().headless_incomplete.to_string(),
+        }
+    }
+}
+
+/// Capture les deux enceintes l'une après l'autre puis lance l'analyse
+/// (sweep, niveau et boucle de référence de `config`, au taux
+/// `sample_rate`). Les messages de progression vont sur stderr pour laisser
+/// stdout au JSON.
+pub fn run(
+    pre_delay_secs: f32,
+    retry_default_config: bool,
+    config: &Config,
+    sample_rate: u32,
+    calibration: Option<&MicCalibration>,
+    target: Option<&TargetCurve>,
+    debug_dir: Option<&Path>,
+) -> Result<AnalysisReport> {
+    let mut state = AppState::new();
+    state.sample_rate = sample_rate;
+    state.target = target.cloned();
+    state.scoring = config.score;
+    state.set_ambient(config.room.ambient());
+    state.pre_delay_secs = pre_delay_secs;
+    state.debug_dir = debug_dir.map(Path::to_path_buf);
+    let (level, loopback) = (&config.level, config.loopback);
+    let timing = |capture_secs| CaptureTiming { sample_rate, capture_secs, pre_delay_secs, start_at: None };
+
+    // Calibration du niveau (`[level] auto`) : même amplitude pour les deux enceintes
+    let mut sweep = config.sweep;
+    if level.auto {
+        eprintln!("{}", tr().headless_level);
+        let burst = levelcal::burst(sample_rate, &sweep);
+        let (prog_tx, _prog_rx) = mpsc::channel::<Progress>();
+        let capture = audio::play_and_capture(
+            &burst,
+            Channel::LEFT,
+            timing(levelcal::BURST_SECS + 0.5),
+            retry_default_config,
+            loopback,
+            CaptureLink::progress_only(prog_tx),
+        )?;
+        let cal = levelcal::calibrate(&capture.mono, &burst, &sweep, level.target_dbfs, sample_rate)
+            .context(tr().level_no_burst)?;
+        sweep.amplitude = cal.amplitude;
+        eprintln!("{}", (tr().headless_sweep_level)(sweep.amplitude_dbfs(), cal.expected_dbfs));
+        if cal.limited {
+            eprintln!("{}", tr().headless_level_limited);
+        }
+    }
+    let sweep = &sweep;
+    state.sweep = *sweep;
+
+    for (channel, label) in [(Channel::LEFT, tr().left_name), (Channel::RIGHT, tr().right_name)] {
+        eprintln!("{}", (tr().headless_capture)(label));
+
+        let signal = dsp::generate_sweep(sample_rate, sweep);
+        let (mut captures, mut drifts) = (Vec::new(), Vec::new());
+        for _ in 0..sweep.averages.max(1) {
+            let (prog_tx, _prog_rx) = mpsc::channel::<Progress>();
+            let capture = audio::play_and_capture(
+                &signal,
+                channel,
+                timing(sweep.capture_secs()),
+                retry_default_config,
+                loopback,
+                CaptureLink::progress_only(prog_tx),
+            )?;
+            let (capture, ppm) = drift::correct(capture, &signal, sweep, sample_rate)?;
+            captures.push(capture);
+            drifts.push(ppm);
+        }
+        let capture = Capture::average(captures, &signal, sweep, sample_rate).context(tr().err_no_capture)?;
+        let mut m = Measurement::from_capture(channel, capture, signal, *sweep, sample_rate, calibration, state.analysis);
+        m.clock_drift_ppm = drift::mean(&drifts);
+        if let Some(ppm) = m.clock_drift_ppm {
+            eprintln!("{}", (tr().headless_drift)(ppm));
+        }
+
+        match channel {
+            Channel::LEFT => state.left = Some(m),
+            _ => state.right = Some(m),
+        }
+    }
+
+    state.analyze();
+    // Export des intermédiaires (--debug-dump) : compte rendu sur stderr
+    if let Some(msg) = state.error.as_ref().or(state.status.as_ref()) {
+        eprintln!("{}", msg);
+    }
+    Ok(AnalysisReport::from_state(&state))
+}
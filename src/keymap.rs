@@ -0,0 +1,224 @@
+// ============================================================
+//  keymap.rs — Touches réassignables
+//
+//  La section `[keys]` du fichier de configuration donne une autre
+//  touche à une action, par son nom :
+//
+//    [keys]
+//    quit = "a"          # AZERTY : [A] quitte…
+//    analyze = "q"       # … et [Q] analyse
+//    capture_left = "F7"
+//
+//  Touche : un caractère (lettres sans casse), `Tab`, `Ins`, `End`
+//  ou `F1`…`F12`. Les touches d'origine évitent F10 et F11, que la
+//  plupart des terminaux gardent pour eux (menu, plein écran).
+//  Une action réassignée perd sa touche d'origine, qui reste libre
+//  pour une autre ; deux actions ne partagent jamais une touche.
+//  Les chiffres (captures [1-8]), les touches du sweep et du délai
+//  pré-capture, les flèches, Entrée, Échap et Ctrl+C ne se
+//  réassignent pas ; les variantes Maj+F3 / Maj+F5 restent sur
+//  leur touche d'origine quand F3 / F5 sont réassignées.
+//
+//  `App::handle_key` ramène la touche pressée à la touche d'origine
+//  de son action (`translate`) avant la répartition habituelle ; la
+//  barre d'aide affiche les touches effectives (`relabel`).
+// ============================================================
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::strings::tr;
+
+/// Actions réassignables : nom dans `[keys]`, touche d'origine.
+const ACTIONS: &[(&str, KeyCode)] = &[
+    ("quit", KeyCode::Char('q')),
+    ("capture_left", KeyCode::Char('l')),
+    ("capture_right", KeyCode::Char('r')),
+    ("capture_pair", KeyCode::Tab),
+    ("analyze", KeyCode::Char('a')),
+    ("reset", KeyCode::Char('x')),
+    ("reference", KeyCode::Char('f')),
+    ("sub", KeyCode::Char('u')),
+    ("multisub", KeyCode::F(3)),
+    ("noise", KeyCode::Char('h')),
+    ("level", KeyCode::Char('j')),
+    ("bass", KeyCode::Char('b')),
+    ("polarity", KeyCode::Char('p')),
+    ("rub_buzz", KeyCode::Char('z')),
+    ("compression", KeyCode::Char('c')),
+    ("linearity", KeyCode::Char('g')),
+    ("wiring", KeyCode::Char('k')),
+    ("quick_check", KeyCode::Char('v')),
+    ("quick_stimulus", KeyCode::Char('n')),
+    ("quick_distance", KeyCode::Char('0')),
+    ("spatial_average", KeyCode::Char(';')),
+    ("watch", KeyCode::Char('d')),
+    ("dual", KeyCode::Char('?')),
+    ("nudge", KeyCode::F(2)),
+    ("onset", KeyCode::F(4)),
+    ("target", KeyCode::F(5)),
+    ("forum_diff", KeyCode::F(6)),
+    ("theme", KeyCode::F(7)),
+    ("metric_next", KeyCode::F(8)),
+    ("metric_fold", KeyCode::F(9)),
+    ("report", KeyCode::End),
+    ("png", KeyCode::Insert),
+    ("notes", KeyCode::F(12)),
+    ("schedule", KeyCode::Char('\\')),
+    ("note", KeyCode::Char('/')),
+    ("position", KeyCode::Char('#')),
+    ("seat", KeyCode::Char(':')),
+    ("baseline", KeyCode::Char('%')),
+    ("ambient", KeyCode::Char('$')),
+    ("calibration_checked", KeyCode::Char('_')),
+    ("environment", KeyCode::Char('"')),
+    ("overlay", KeyCode::Char('&')),
+    ("analysis_range", KeyCode::Char('m')),
+    ("smoothing", KeyCode::Char('~')),
+    ("fft_window", KeyCode::Char('^')),
+    ("gate", KeyCode::Char('9')),
+    ("swap", KeyCode::Char('|')),
+    ("mic_calibration", KeyCode::Char('\'')),
+    ("impulse", KeyCode::Char('i')),
+    ("regions", KeyCode::Char('y')),
+    ("trend", KeyCode::Char('`')),
+    ("listening_window", KeyCode::Char('w')),
+    ("expert", KeyCode::Char('!')),
+    ("language", KeyCode::Char('t')),
+    ("save", KeyCode::Char('s')),
+    ("open", KeyCode::Char('o')),
+    ("share", KeyCode::Char('@')),
+    ("export", KeyCode::Char('e')),
+];
+
+/// Caractères tenus par des touches fixes : captures, délai pré-capture,
+/// sweep, niveau visé.
+const RESERVED: &str = "12345678+=-[],.<>()*{} ";
+
+/// Section `[keys]` : nom d'action → touche.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KeyBindings(pub BTreeMap<String, String>);
+
+impl KeyBindings {
+    pub fn validate(&self) -> Result<(), String> {
+        KeyMap::new(self).map(|_| ())
+    }
+}
+
+/// Touches réassignées : (touche choisie, touche d'origine de l'action).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KeyMap {
+    bound: Vec<(KeyCode, KeyCode)>,
+}
+
+/// Lettres sans casse.
+fn normalize(code: KeyCode) -> KeyCode {
+    match code {
+        KeyCode::Char(c) => KeyCode::Char(c.to_ascii_lowercase()),
+        other => other,
+    }
+}
+
+/// « a », « Tab », « Ins » (ou « Insert »), « End », « F7 ».
+pub fn parse_key(text: &str) -> Option<KeyCode> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(normalize(KeyCode::Char(c))),
+        _ if text == "Tab" => Some(KeyCode::Tab),
+        _ if text == "Ins" || text == "Insert" => Some(KeyCode::Insert),
+        _ if text == "End" => Some(KeyCode::End),
+        _ => text.strip_prefix('F').and_then(|n| n.parse().ok()).filter(|n| (1..=12).contains(n)).map(KeyCode::F),
+    }
+}
+
+/// Nom affiché d'une touche, comme dans la barre d'aide.
+fn key_label(code: KeyCode) -> String {
+    match code {
+        KeyCode::Char(c) => c.to_uppercase().to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Insert => "Ins".to_string(),
+        KeyCode::End => "End".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+impl KeyMap {
+    /// Vérifie les noms d'actions, les touches et l'absence de doublon.
+    pub fn new(bindings: &KeyBindings) -> Result<Self, String> {
+        let mut bound = Vec::new();
+        for (action, key) in &bindings.0 {
+            let Some(&(_, default)) = ACTIONS.iter().find(|(name, _)| name == action) else {
+                return Err((tr().keys_unknown_action)(action));
+            };
+            let Some(code) = parse_key(key.trim()) else {
+                return Err((tr().keys_unreadable)(key));
+            };
+            if matches!(code, KeyCode::Char(c) if RESERVED.contains(c)) {
+                return Err((tr().keys_reserved)(key));
+            }
+            bound.push((code, default));
+        }
+        let map = KeyMap { bound };
+        // Touche effective de chaque action : deux fois la même = conflit
+        let mut seen: Vec<(KeyCode, &str)> = Vec::new();
+        for &(name, default) in ACTIONS {
+            let key = map.key_of(default);
+            if let Some((_, other)) = seen.iter().find(|(k, _)| *k == key) {
+                return Err((tr().keys_conflict)(&key_label(key), other, name));
+            }
+            seen.push((key, name));
+        }
+        Ok(map)
+    }
+
+    /// Touche effective de l'action dont la touche d'origine est `default`.
+    fn key_of(&self, default: KeyCode) -> KeyCode {
+        self.bound.iter().find(|(_, d)| *d == default).map_or(default, |(k, _)| *k)
+    }
+
+    /// Touche d'origine de l'action liée à `code` ; `None` pour la touche
+    /// d'origine d'une action réassignée (libérée). Seule la touche nue est
+    /// traduite : Ctrl+… (Ctrl+C quitte toujours), Maj+F3 ou Maj+F5 gardent
+    /// leur touche d'origine. Maj fait partie d'un caractère (lettres sans
+    /// casse).
+    pub fn translate(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<KeyCode> {
+        let plain = modifiers.is_empty() || matches!(code, KeyCode::Char(_)) && modifiers == KeyModifiers::SHIFT;
+        if !plain {
+            return Some(code);
+        }
+        let key = normalize(code);
+        if let Some((_, default)) = self.bound.iter().find(|(k, _)| *k == key) {
+            return Some(*default);
+        }
+        if self.bound.iter().any(|(_, d)| *d == key) {
+            return None;
+        }
+        Some(code)
+    }
+
+    /// Étiquette « [L] » ou « [S/O] » de la barre d'aide, touches d'origine
+    /// remplacées par les touches effectives.
+    pub fn label(&self, label: &str) -> String {
+        let Some(inner) = label.strip_prefix('[').and_then(|l| l.strip_suffix(']')) else {
+            return label.to_string();
+        };
+        let relabel = |part: &str| match parse_key(part) {
+            Some(code) if self.bound.iter().any(|(_, d)| *d == code) => key_label(self.key_of(code)),
+            _ => part.to_string(),
+        };
+        // « [/] » est une touche, « [S/O] » deux
+        if parse_key(inner).is_some() {
+            return format!("[{}]", relabel(inner));
+        }
+        let parts: Vec<String> = inner.split('/').map(relabel).collect();
+        format!("[{}]", parts.join("/"))
+    }
+
+    /// Entrées de la barre d'aide avec les touches effectives.
+    pub fn relabel(&self, entries: &[(&str, &'static str)]) -> Vec<(String, &'static str)> {
+        entries.iter().map(|&(key, desc)| (self.label(key), desc)).collect()
+    }
+}
@@ -0,0 +1,103 @@
+// ============================================================
+//  levelcal.rs — Calibration automatique du niveau de sortie
+//
+//  Une salve d'une seconde de bruit rose, jouée à l'amplitude
+//  courante du sweep sur l'enceinte gauche, donne le niveau capté
+//  pour ce réglage. L'amplitude du sweep est ensuite ajustée pour
+//  que la capture atteigne la cible (−20 dBFS RMS par défaut) :
+//  assez haut pour le rapport signal/bruit, assez loin de
+//  l'écrêtage. Gauche et droite sont ensuite mesurées avec la
+//  MÊME amplitude — sans quoi l'écart de niveau G/D disparaîtrait.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dsp::{self, SweepParams},
+    strings::tr,
+};
+
+/// Durée de la salve de bruit rose (s).
+pub const BURST_SECS: f32 = 1.0;
+
+/// Plage de cibles proposée par { } (dBFS RMS).
+pub const MIN_TARGET_DBFS: f32 = -40.0;
+pub const MAX_TARGET_DBFS: f32 = -6.0;
+
+/// Tranches de mesure du niveau capté (s) ; seules les plus fortes, qui
+/// tombent dans la salve, comptent.
+const BLOCK_SECS: f32 = 0.1;
+
+/// Réglage du niveau de mesure : `[level]` dans `speaker-align.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LevelParams {
+    /// Niveau RMS visé pendant le sweep, côté capture (dBFS).
+    pub target_dbfs: f32,
+    /// Calibre automatiquement avant la première capture de la session.
+    pub auto: bool,
+}
+
+impl Default for LevelParams {
+    fn default() -> Self {
+        LevelParams { target_dbfs: -20.0, auto: false }
+    }
+}
+
+impl LevelParams {
+    pub fn validate(&self) -> Result<(), String> {
+        if (MIN_TARGET_DBFS..=MAX_TARGET_DBFS).contains(&self.target_dbfs) {
+            Ok(())
+        } else {
+            Err((tr().level_target_range)(MIN_TARGET_DBFS, MAX_TARGET_DBFS))
+        }
+    }
+}
+
+/// Résultat d'une calibration.
+#[derive(Debug, Clone, Copy)]
+pub struct LevelCalibration {
+    /// Niveau capté pendant la salve (dBFS RMS).
+    pub burst_dbfs: f32,
+    /// Niveau attendu pendant le sweep à la nouvelle amplitude (dBFS RMS).
+    pub expected_dbfs: f32,
+    /// Nouvelle amplitude du sweep.
+    pub amplitude: f32,
+    /// Cible hors d'atteinte : amplitude bloquée à sa limite (volume de
+    /// l'ampli ou gain du micro à revoir).
+    pub limited: bool,
+}
+
+/// Salve de bruit rose à l'amplitude du sweep.
+pub fn burst(sample_rate: u32, sweep: &SweepParams) -> Vec<f32> {
+    dsp::generate_pink_noise(sample_rate, BURST_SECS, sweep.amplitude)
+}
+
+/// Nouvelle amplitude du sweep pour que sa capture atteigne `target_dbfs`.
+/// Le niveau du sweep se déduit de celui de la salve par le rapport de leurs
+/// RMS numériques (à réponse de la chaîne à peu près plate).
+pub fn calibrate(capture: &[f32], burst: &[f32], sweep: &SweepParams, target_dbfs: f32, sample_rate: u32) -> Option<LevelCalibration> {
+    let block = (BLOCK_SECS * sample_rate as f32) as usize;
+    let mut powers: Vec<f32> = capture.chunks_exact(block).map(|c| dsp::compute_rms(c).powi(2)).collect();
+    powers.sort_by(|a, b| b.total_cmp(a));
+    // Salve sans ses fondus : une tranche de moins de chaque côté
+    let keep = ((BURST_SECS / BLOCK_SECS) as usize).saturating_sub(2).max(1);
+    let loudest = &powers[..keep.min(powers.len())];
+    if loudest.is_empty() {
+        return None;
+    }
+    let db = |x: f32| 20.0 * x.max(1e-10).log10();
+    let burst_dbfs = db((loudest.iter().sum::<f32>() / loudest.len() as f32).sqrt());
+
+    let sweep_rms = dsp::compute_rms(&dsp::generate_sweep(sample_rate, sweep));
+    let sweep_dbfs = burst_dbfs + db(sweep_rms) - db(dsp::compute_rms(burst));
+
+    let wanted = sweep.amplitude * 10f32.powf((target_dbfs - sweep_dbfs) / 20.0);
+    let amplitude = SweepParams { amplitude: wanted, ..*sweep }.clamped().amplitude;
+    Some(LevelCalibration {
+        burst_dbfs,
+        expected_dbfs: sweep_dbfs + db(amplitude / sweep.amplitude),
+        amplitude,
+        limited: (amplitude - wanted).abs() > 1e-4,
+    })
+}
@@ -0,0 +1,136 @@
+// ============================================================
+//  Speaker Align — bibliothèque
+//
+//  Expose le traitement du signal et l'accès audio pour des
+//  outils externes (traitement par lots, scripts) :
+//    advice      — conseils tirés d'une analyse (texte des recommandations)
+//    assets      — données embarquées (courbes cibles, masques, calibrations)
+//    dsp         — sweep, FFT, bandes, IR, distance, GCC-PHAT, score
+//    audio       — lecture & capture via cpal
+//    mockaudio   — carte son simulée, pannes à la demande (tests, CI)
+//    resample    — conversion de taux d'échantillonnage (rubato)
+//    drift       — dérive d'horloge entre cartes de sortie et d'entrée
+//    calibration — fichier de calibration micro (UMIK-1 / .cal / .frd)
+//    levelcal    — calibration du niveau de sortie (bruit rose → amplitude)
+//    gain        — écart de niveau G/D en crans de l'appareil (trim, balance)
+//    config      — fichier de configuration TOML (paramètres du sweep)
+//    channelmap  — vérification du câblage (emplacement réel de FL / FR)
+//    checklist   — liste de vérification avant la première mesure
+//    measurement — mesure d'une enceinte (capture + grandeurs dérivées)
+//    banddelay   — retard G/D par octave (distance ou filtre / phase)
+//    allpass     — passe-tout corrigeant un retard G/D variable avec la fréquence
+//    sub         — intégration du caisson (retard / phase à la coupure)
+//    noise       — bruit de fond (capture de silence, SNR par bande)
+//    bass        — vérification du bass management (redirection vers le caisson)
+//    compression — compression / limiteur (même sweep à plusieurs niveaux)
+//    distortion  — rub & buzz / souffle d'évent (salves graves à fort niveau)
+//    harmonics   — distorsion harmonique (THD) tirée du sweep de mesure
+//    polarity    — polarité par haut-parleur (clics filtrés en bande)
+//    reflections — premières réflexions de l'IR (1–20 ms, surfaces probables)
+//    roommodes   — modes propres de la pièce (axiaux, tangentiels) d'après ses dimensions
+//    multichannel — comparaison de chaque enceinte (5.1 / 7.1) à une référence
+//    multisub    — gain, retard et polarité de 2 à 4 caissons (grave égal entre places)
+//    pipeline    — étapes de l'analyse, intermédiaires en cache, plage d'analyse
+//    monitor     — surveillance planifiée d'une installation fixe (alertes)
+//    webhook     — notification HTTP (POST JSON) en fin de mesure
+//    linearity   — linéarité du gain (paliers −30 → −10 dBFS)
+//    quickcheck  — vérification rapide (bi-ton / bruit blanc, 1 s)
+//    distance    — distances rapides (chirp de 0,3 s, placement grossier)
+//    enhancements — traitements audio du système (pompage, son spatial)
+//    watch       — suivi en direct (sweeps courts G / D alternés)
+//    nudge       — bips de guidage du suivi (écart codé en cadence et hauteur)
+//    dualnoise   — mesure simultanée G + D (bruits roses décorrélés, suivi de l'équilibre)
+//    listening   — fenêtre d'écoute (moyenne sur une grille de positions)
+//    mmm         — moyenne spatiale micro en mouvement (bruit rose périodique)
+//    placement   — contraintes de placement (déplacement, délai par canal)
+//    positions   — positions d'enceintes candidates (classement composite)
+//    seats       — places d'écoute nommées (placement ou anomalie d'une place)
+//    baseline    — asymétrie acceptée (correction électrique, conformité)
+//    session     — sauvegarde / chargement des mesures (.spkalign)
+//    schema      — versions des formats enregistrés, migrations au chargement
+//    debugdump   — intermédiaires DSP (.npy / CSV) pour le diagnostic
+//    overlay     — comparaison de deux analyses de l'historique (superposition)
+//    experiments — journal « un changement à la fois » (effet mesuré)
+//    environment — conditions de chaque analyse, corrélations avec le score
+//    notes       — notes et métadonnées des mesures (micro, enceintes, pièce)
+//    export      — réponse en fréquence en .frd (REW) et CSV
+//    report      — rapport HTML autonome (graphiques SVG, métriques, conseils)
+//    eq          — filtres en cloche ramenant la droite sur la gauche (Equalizer APO)
+//    target      — courbe cible (plate, salon, type Harman, .frd) et écart à la cible
+//    mask        — masque de tolérance de l'écart G/D (score, graphe)
+//    scoring     — profils de score (studio, hi-fi, home-cinéma) : poids et seuils des conseils
+//    strings     — textes de l'interface (français / anglais)
+//    keymap      — touches réassignables (section [keys], barre d'aide)
+//    theme       — couleurs de l'interface (contraste élevé, 16 couleurs, monochrome)
+//    script      — scripts de touches rejoués sans terminal (tests de bout en bout)
+//    schedule    — départ de capture à une seconde fixe de la minute
+//
+//  `app` et `ui` forment l'interface TUI utilisée par le binaire ;
+//  `headless` exécute la même séquence sans interface (sortie JSON).
+// ============================================================
+
+pub mod advice;
+pub mod allpass;
+pub mod app;
+pub mod assets;
+pub mod audio;
+pub mod banddelay;
+pub mod baseline;
+pub mod bass;
+pub mod calibration;
+pub mod channelmap;
+pub mod checklist;
+pub mod compression;
+pub mod config;
+pub mod debugdump;
+pub mod distance;
+pub mod distortion;
+pub mod drift;
+pub mod dsp;
+pub mod dualnoise;
+pub mod enhancements;
+pub mod environment;
+pub mod eq;
+pub mod experiments;
+pub mod export;
+pub mod gain;
+pub mod harmonics;
+pub mod headless;
+pub mod keymap;
+pub mod linearity;
+pub mod levelcal;
+pub mod listening;
+pub mod mask;
+pub mod measurement;
+pub mod mmm;
+pub mod mockaudio;
+pub mod monitor;
+pub mod multichannel;
+pub mod multisub;
+pub mod noise;
+pub mod notes;
+pub mod nudge;
+pub mod overlay;
+pub mod pipeline;
+pub mod placement;
+pub mod polarity;
+pub mod positions;
+pub mod quickcheck;
+pub mod reflections;
+pub mod reminders;
+pub mod report;
+pub mod resample;
+pub mod roommodes;
+pub mod schema;
+pub mod schedule;
+pub mod scoring;
+pub mod script;
+pub mod seats;
+pub mod session;
+pub mod strings;
+pub mod sub;
+pub mod target;
+pub mod theme;
+mod ui;
+pub mod watch;
+pub mod webhook;
@@ -0,0 +1,143 @@
+// ============================================================
+//  linearity.rs — Linéarité du gain par canal
+//
+//  Un bruit filtré est joué par paliers de −30 à −10 dBFS (pas de
+//  5 dB) dans une seule capture. Sur une chaîne linéaire, chaque
+//  palier de +5 dB en entrée donne +5 dB au micro. Les fonctions
+//  « dynamic volume » des AVR, les compresseurs de plugins ou
+//  l'égalisation adaptative d'un pilote modifient discrètement ce
+//  rapport sur un canal et faussent toutes les autres mesures.
+//  On rapporte la pente de la droite dB-entrée → dB-sortie et
+//  l'écart maximal d'un palier à cette droite idéale.
+// ============================================================
+
+use crate::dsp;
+
+/// Niveaux RMS joués (dBFS), du plus faible au plus fort.
+pub const STEPS_DBFS: [f32; 5] = [-30.0, -25.0, -20.0, -15.0, -10.0];
+
+/// Durée d'un palier, silence entre deux paliers.
+pub const STEP_SECS: f32 = 1.0;
+pub const GAP_SECS: f32 = 0.3;
+
+/// Bande du bruit de test (Hz).
+const NOISE_LO_HZ: f32 = 100.0;
+const NOISE_HI_HZ: f32 = 8_000.0;
+
+/// Tolérances : écart de pente (1.0 = linéaire) et écart d'un palier (dB).
+pub const SLOPE_TOLERANCE: f32 = 0.05;
+pub const DEVIATION_TOLERANCE_DB: f32 = 1.0;
+
+/// Résultat pour une enceinte.
+#[derive(Debug, Clone)]
+pub struct ChannelLinearity {
+    /// Niveau capté de chaque palier (dB relatifs au palier le plus faible).
+    pub output_db: Vec<f32>,
+    /// Pente de la régression dB-sortie / dB-entrée.
+    pub slope: f32,
+    /// Écart maximal d'un palier à la droite de pente 1 (dB).
+    pub max_deviation_db: f32,
+    /// Plus forte dérive de niveau à l'intérieur d'un palier (dB, dernier
+    /// tiers − premier tiers) : un bruit stationnaire ne varie pas, une
+    /// égalisation de sonie ou un AGC « pompe ».
+    pub pumping_db: f32,
+}
+
+impl ChannelLinearity {
+    pub fn is_linear(&self) -> bool {
+        (self.slope - 1.0).abs() <= SLOPE_TOLERANCE && self.max_deviation_db <= DEVIATION_TOLERANCE_DB
+    }
+}
+
+/// Résultat du test pour les deux enceintes.
+#[derive(Debug, Clone, Default)]
+pub struct LinearityReport {
+    pub left: Option<ChannelLinearity>,
+    pub right: Option<ChannelLinearity>,
+}
+
+/// Position (début, longueur) de chaque palier, en échantillons.
+pub fn segments(sample_rate: u32) -> Vec<(usize, usize)> {
+    let step = (STEP_SECS * sample_rate as f32) as usize;
+    let gap = (GAP_SECS * sample_rate as f32) as usize;
+    (0..STEPS_DBFS.len()).map(|i| (i * (step + gap), step)).collect()
+}
+
+/// Durée totale de la séquence, en secondes.
+pub fn sequence_secs() -> f32 {
+    STEPS_DBFS.len() as f32 * (STEP_SECS + GAP_SECS)
+}
+
+/// Séquence : le même bruit filtré, normalisé en RMS à chaque palier.
+pub fn generate_test_signal(sample_rate: u32) -> Vec<f32> {
+    let noise = dsp::generate_band_noise(sample_rate, STEP_SECS, NOISE_LO_HZ, NOISE_HI_HZ);
+    let rms = dsp::compute_rms(&noise);
+    let segs = segments(sample_rate);
+    let total = segs.last().map(|(s, l)| s + l).unwrap_or(0);
+    let mut out = vec![0.0f32; total];
+    if rms <= 0.0 {
+        return out;
+    }
+    for (&(start, len), &dbfs) in segs.iter().zip(STEPS_DBFS.iter()) {
+        let gain = 10f32.powf(dbfs / 20.0) / rms;
+        for (o, s) in out[start..start + len].iter_mut().zip(noise.iter()) {
+            *o = s * gain;
+        }
+    }
+    out
+}
+
+/// Linéarité à partir de la capture de `signal`.
+pub fn analyze(capture: &[f32], signal: &[f32], sample_rate: u32) -> Option<ChannelLinearity> {
+    let lag = dsp::xcorr_delay(signal, capture, capture.len())?.round() as usize;
+    // Ignore l'attaque et le fondu de chaque palier
+    let margin = (0.1 * sample_rate as f32) as usize;
+
+    let db = |samples: &[f32]| {
+        let rms = dsp::compute_rms(samples);
+        (rms > 0.0).then(|| 20.0 * rms.log10())
+    };
+    let mut pumping_db = 0.0f32;
+    let levels: Vec<f32> = segments(sample_rate)
+        .iter()
+        .map(|&(start, len)| {
+            let from = start + lag + margin;
+            let to = (start + lag + len - margin).min(capture.len());
+            if from >= to {
+                return None;
+            }
+            let third = (to - from) / 3;
+            if let (Some(first), Some(last)) = (db(&capture[from..from + third]), db(&capture[to - third..to])) {
+                if (last - first).abs() > pumping_db.abs() {
+                    pumping_db = last - first;
+                }
+            }
+            db(&capture[from..to])
+        })
+        .collect::<Option<_>>()?;
+
+    // Régression linéaire sortie = pente × entrée + b
+    let n = STEPS_DBFS.len() as f32;
+    let mean_in = STEPS_DBFS.iter().sum::<f32>() / n;
+    let mean_out = levels.iter().sum::<f32>() / n;
+    let (mut cov, mut var) = (0.0f32, 0.0f32);
+    for (x, y) in STEPS_DBFS.iter().zip(levels.iter()) {
+        cov += (x - mean_in) * (y - mean_out);
+        var += (x - mean_in) * (x - mean_in);
+    }
+    let slope = if var > 0.0 { cov / var } else { 0.0 };
+
+    // Écart à la droite idéale de pente 1 passant par le point moyen
+    let max_deviation_db = STEPS_DBFS
+        .iter()
+        .zip(levels.iter())
+        .map(|(x, y)| ((y - mean_out) - (x - mean_in)).abs())
+        .fold(0.0, f32::max);
+
+    Some(ChannelLinearity {
+        output_db: levels.iter().map(|l| l - levels[0]).collect(),
+        slope,
+        max_deviation_db,
+        pumping_db,
+    })
+}
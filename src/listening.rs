@@ -0,0 +1,208 @@
+// ============================================================
+//  listening.rs — Fenêtre d'écoute multi-positions
+//
+//  Une mesure en un seul point est dominée par les interférences
+//  locales (modes, réflexions). On mesure donc G/D sur une grille
+//  de positions micro autour du siège central, puis on combine :
+//    - moyenne (spectres moyennés en puissance, métriques en moyenne)
+//    - écart maximal de chaque métrique par rapport à cette moyenne
+//  Les recommandations se basent ensuite sur la fenêtre entière.
+// ============================================================
+
+use std::ops::Range;
+
+use crate::{dsp, scoring::ScoreWeights, strings::tr};
+
+/// Espacement entre deux positions voisines de la grille.
+pub const GRID_SPACING_CM: f32 = 30.0;
+
+/// Position sur la grille 3×3 centrée sur le siège d'écoute.
+/// `col` : -1 = gauche, +1 = droite ; `row` : -1 = avant (vers les enceintes), +1 = arrière.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridPosition {
+    pub col: i8,
+    pub row: i8,
+}
+
+/// Ordre de mesure : centre d'abord, puis la couronne dans le sens horaire.
+pub const GRID: [GridPosition; 9] = [
+    GridPosition { col: 0, row: 0 },
+    GridPosition { col: 0, row: -1 },
+    GridPosition { col: 1, row: -1 },
+    GridPosition { col: 1, row: 0 },
+    GridPosition { col: 1, row: 1 },
+    GridPosition { col: 0, row: 1 },
+    GridPosition { col: -1, row: 1 },
+    GridPosition { col: -1, row: 0 },
+    GridPosition { col: -1, row: -1 },
+];
+
+impl GridPosition {
+    /// Décalage (latéral, profondeur) par rapport au siège central, en cm.
+    pub fn offset_cm(&self) -> (f32, f32) {
+        (self.col as f32 * GRID_SPACING_CM, self.row as f32 * GRID_SPACING_CM)
+    }
+
+    /// Distance au siège central, en cm.
+    pub fn distance_cm(&self) -> f32 {
+        let (x, y) = self.offset_cm();
+        (x * x + y * y).sqrt()
+    }
+
+    /// Consigne de placement lisible, ex. « 30 cm à droite, 30 cm vers l'avant ».
+    pub fn placement_hint(&self) -> String {
+        let s = tr();
+        let (x, y) = self.offset_cm();
+        let mut parts = Vec::new();
+        if x != 0.0 {
+            parts.push((s.hint_side)(x.abs(), if x > 0.0 { s.right_word } else { s.left_word }));
+        }
+        if y < 0.0 {
+            parts.push((s.hint_forward)(-y));
+        } else if y > 0.0 {
+            parts.push((s.hint_backward)(y));
+        }
+        if parts.is_empty() {
+            s.hint_seat.to_string()
+        } else {
+            (s.hint_from_center)(&parts.join(", "), self.distance_cm())
+        }
+    }
+
+    pub fn label(&self) -> String {
+        let s = tr();
+        if self.col == 0 && self.row == 0 {
+            return s.pos_center.to_string();
+        }
+        let depth = match self.row {
+            -1 => s.pos_front,
+            1 => s.pos_back,
+            _ => "",
+        };
+        let side = match self.col {
+            -1 => s.left_word,
+            1 => s.right_word,
+            _ => "",
+        };
+        [depth, side].iter().filter(|s| !s.is_empty()).cloned().collect::<Vec<_>>().join("-")
+    }
+}
+
+/// Résultat de l'analyse G/D en une position.
+#[derive(Debug, Clone)]
+pub struct PointAnalysis {
+    pub left_db: Vec<f32>,
+    pub right_db: Vec<f32>,
+    pub delay_ms: f32,
+    pub level_diff_db: f32,
+    pub freq_tilt: f32,
+}
+
+/// Synthèse de la fenêtre d'écoute.
+#[derive(Debug, Clone)]
+pub struct WindowSummary {
+    pub positions: usize,
+    pub left_db: Vec<f32>,
+    pub right_db: Vec<f32>,
+    pub diff_db: Vec<f32>,
+    pub delay_ms: f32,
+    pub level_diff_db: f32,
+    pub freq_tilt: f32,
+    // Pire écart d'une position par rapport à la moyenne
+    pub worst_delay_ms: f32,
+    pub worst_level_db: f32,
+    pub worst_tilt_db: f32,
+    /// Écart moyen (sur les bandes) de la différence R−L de la pire position.
+    pub worst_spectral_db: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ListeningWindow {
+    pub points: Vec<(GridPosition, PointAnalysis)>,
+}
+
+impl ListeningWindow {
+    /// Prochaine position de la grille non encore mesurée.
+    pub fn next_position(&self) -> Option<GridPosition> {
+        GRID.iter().copied().find(|p| !self.is_done(*p))
+    }
+
+    pub fn is_done(&self, pos: GridPosition) -> bool {
+        self.points.iter().any(|(p, _)| *p == pos)
+    }
+
+    /// Enregistre (ou remplace) l'analyse d'une position.
+    pub fn record(&mut self, pos: GridPosition, analysis: PointAnalysis) {
+        self.points.retain(|(p, _)| *p != pos);
+        self.points.push((pos, analysis));
+    }
+
+    /// Moyenne et pire écart sur toutes les positions mesurées.
+    pub fn summary(&self) -> Option<WindowSummary> {
+        let n = self.points.len();
+        if n == 0 {
+            return None;
+        }
+        let analyses: Vec<&PointAnalysis> = self.points.iter().map(|(_, a)| a).collect();
+
+        // Moyenne en puissance des spectres (dB → puissance → dB)
+        let power_avg = |get: fn(&PointAnalysis) -> &Vec<f32>| -> Vec<f32> {
+            let bands = get(analyses[0]).len();
+            (0..bands)
+                .map(|b| {
+                    let p: f32 = analyses.iter().map(|a| 10f32.powf(get(a)[b] / 10.0)).sum::<f32>() / n as f32;
+                    if p > 0.0 { 10.0 * p.log10() } else { -100.0 }
+                })
+                .collect()
+        };
+        let left_db = power_avg(|a| &a.left_db);
+        let right_db = power_avg(|a| &a.right_db);
+        let diff_db: Vec<f32> = left_db.iter().zip(right_db.iter()).map(|(l, r)| r - l).collect();
+
+        let mean = |get: fn(&PointAnalysis) -> f32| -> f32 {
+            analyses.iter().map(|a| get(a)).sum::<f32>() / n as f32
+        };
+        let worst = |get: fn(&PointAnalysis) -> f32, avg: f32| -> f32 {
+            analyses.iter().map(|a| (get(a) - avg).abs()).fold(0.0, f32::max)
+        };
+
+        let delay_ms = mean(|a| a.delay_ms);
+        let level_diff_db = mean(|a| a.level_diff_db);
+        let freq_tilt = mean(|a| a.freq_tilt);
+
+        let worst_spectral_db = analyses
+            .iter()
+            .map(|a| {
+                let dev: f32 = a.left_db.iter().zip(a.right_db.iter()).zip(diff_db.iter())
+                    .map(|((l, r), d)| ((r - l) - d).abs())
+                    .sum();
+                dev / diff_db.len().max(1) as f32
+            })
+            .fold(0.0, f32::max);
+
+        Some(WindowSummary {
+            positions: n,
+            worst_delay_ms: worst(|a| a.delay_ms, delay_ms),
+            worst_level_db: worst(|a| a.level_diff_db, level_diff_db),
+            worst_tilt_db: worst(|a| a.freq_tilt, freq_tilt),
+            worst_spectral_db,
+            left_db,
+            right_db,
+            diff_db,
+            delay_ms,
+            level_diff_db,
+            freq_tilt,
+        })
+    }
+}
+
+impl WindowSummary {
+    /// Score composite calculé sur la moyenne de la fenêtre, bandes `range`
+    /// (`target_db` : courbe cible, `mask_db` : masque de tolérance, sur
+    /// toutes les bandes), pondéré par `weights`.
+    pub fn score(&self, range: Range<usize>, target_db: Option<&[f32]>, mask_db: Option<&[f32]>, weights: &ScoreWeights) -> u32 {
+        let target = target_db.map(|t| &t[range.clone()]);
+        let mask = mask_db.map(|m| &m[range.clone()]);
+        dsp::compute_score(&self.left_db[range.clone()], &self.right_db[range], self.delay_ms, self.level_diff_db, target, mask, weights)
+    }
+}
@@ -7,16 +7,279 @@
 //    rustfft  — FFT rapide O(n log n)
 //    ratatui  — interface TUI
 //    crossterm — terminal cross-platform
+//    clap     — arguments de ligne de commande
+//
+//  Le traitement du signal vit dans la bibliothèque (src/lib.rs).
 // ============================================================
 
-mod audio;
-mod dsp;
-mod ui;
-mod app;
+use anyhow::{Result, bail};
+use clap::{Parser, Subcommand};
+use speaker_align::{
+    app::{App, AppState},
+    assets,
+    audio::{self, Channel},
+    bass,
+    calibration::MicCalibration,
+    checklist::Checklist,
+    config::{self, Config},
+    headless,
+    keymap::KeyMap,
+    mockaudio::{self, MockAudio},
+    monitor,
+    multisub::{self, MultiSub},
+    report, script, session,
+    strings::{self, Lang},
+    target::TargetCurve,
+    theme::{self, ThemeName},
+    webhook::{self, Event},
+};
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[command(name = "speaker-align", version, about = "Calibration de placement stéréo par analyse comparative micro")]
+struct Cli {
+    /// Capture G puis D et analyse sans interface ; imprime le résultat en JSON sur stdout
+    #[arg(long)]
+    headless: bool,
+
+    /// Délai pré-capture en secondes (mode headless)
+    #[arg(long, default_value_t = 1.0)]
+    pre_delay: f32,
+
+    /// Si un périphérique est occupé (DAW, mode exclusif), réessayer une fois
+    /// avec sa config par défaut (format partagé du système)
+    #[arg(long)]
+    retry_default_config: bool,
+
+    /// Ferme les périphériques après chaque capture au lieu de garder les
+    /// flux ouverts entre les mesures (DAW ou autre application qui doit
+    /// s'en servir entre deux captures)
+    #[arg(long)]
+    release_devices: bool,
+
+    /// Taux d'échantillonnage de la session en Hz (44100, 48000, 96000…) ;
+    /// absent = le premier taux accepté par la sortie et le micro par défaut
+    #[arg(long, value_name = "HZ", value_parser = clap::value_parser!(u32).range(8_000..=384_000))]
+    sample_rate: Option<u32>,
+
+    /// Fichier de session utilisé par [S] (enregistrer) et [O] (ouvrir)
+    #[arg(long, default_value = session::DEFAULT_SESSION_PATH)]
+    session: PathBuf,
+
+    /// Fréquence de coupure configurée sur l'AVR (vérifiée par [B])
+    #[arg(long, default_value_t = bass::DEFAULT_CROSSOVER_HZ)]
+    crossover: f32,
+
+    /// Sortie du caisson pour [U] : nom (LFE, C…) ou indice
+    #[arg(long, default_value = "LFE", value_parser = parse_channel)]
+    sub_channel: Channel,
+
+    /// Sorties de 2 à 4 caissons à régler ensemble par [F3] : LFE,SL…
+    #[arg(long, value_delimiter = ',', value_parser = parse_channel)]
+    subs: Vec<Channel>,
+
+    /// Enceinte de référence des comparaisons multicanal : FL, FR, C, SL…
+    #[arg(long, default_value = "FL", value_parser = parse_channel)]
+    reference: Channel,
+
+    /// Fichier de calibration du micro (UMIK-1 .cal/.txt ou .frd), ou
+    /// calibration embarquée : builtin:<nom> (voir --list-assets)
+    #[arg(long, value_name = "FICHIER")]
+    mic_cal: Option<PathBuf>,
+
+    /// Courbe cible (.frd ou .txt « fréquence dB »), ou cible embarquée :
+    /// builtin:flat, builtin:house-curve, builtin:harman (voir --list-assets)
+    #[arg(long, value_name = "FICHIER")]
+    target: Option<PathBuf>,
+
+    /// Fichier de configuration TOML (paramètres du sweep) ; absent = valeurs par défaut
+    #[arg(long, default_value = config::DEFAULT_CONFIG_PATH)]
+    config: PathBuf,
+
+    /// Écrit les intermédiaires DSP (spectres, corrélation, IR brute) de
+    /// chaque analyse dans ce dossier, pour les rapports de bug
+    #[arg(long, value_name = "DOSSIER")]
+    debug_dump: Option<PathBuf>,
+
+    /// Écrit aussi les courbes G, D et D − G dans cette image PNG (mode headless)
+    #[arg(long, value_name = "FICHIER", requires = "headless")]
+    png: Option<PathBuf>,
+
+    /// Ouvre une session enregistrée ou partagée (.spkalign) au démarrage
+    #[arg(long, value_name = "FICHIER")]
+    import: Option<PathBuf>,
+
+    /// URL recevant le rapport JSON (POST) en fin de mesure, en mode headless
+    /// et en surveillance ; remplace `[webhook] url` de la configuration
+    #[arg(long, value_name = "URL")]
+    webhook: Option<String>,
+
+    /// Garde l'audio brut des captures dans le partage [@] (fichier bien plus gros)
+    #[arg(long)]
+    share_with_audio: bool,
+
+    /// Liste de vérification (micro, bruit, volume, traitements) à valider
+    /// avant la première mesure de la session
+    #[arg(long)]
+    checklist: bool,
 
-use anyhow::Result;
-use app::App;
+    /// Démarre en mode simple : captures, verdict et conseils (mode expert par [!])
+    #[arg(long)]
+    basic: bool,
+
+    /// Langue de l'interface : fr ou en (basculable par [T])
+    #[arg(long, default_value = "fr", value_parser = parse_lang)]
+    lang: Lang,
+
+    /// Couleurs : default, high-contrast, ansi16 (terminaux sans couleurs
+    /// 24 bits) ou mono (basculable par [F7])
+    #[arg(long, value_parser = parse_theme)]
+    theme: Option<ThemeName>,
+
+    /// Rejoue un script de touches sur l'interface, sans terminal, et vérifie
+    /// l'état obtenu (tests de bout en bout) ; code de sortie 1 en cas d'échec
+    #[arg(long, value_name = "FICHIER")]
+    script: Option<PathBuf>,
+
+    /// Liste les ressources embarquées (courbes cibles, masques, calibrations) et quitte
+    #[arg(long)]
+    list_assets: bool,
+
+    /// Carte son simulée au lieu des périphériques (tests, CI) ; pannes
+    /// séparées par des virgules : busy, hang, reject[:HZ], rate:HZ,
+    /// short[:FRACTION], drop[:N], clip, speed:N, inputs:N, skew:MS (retard
+    /// des callbacks d'entrée sur la sortie, en ms ; négatif = en avance)
+    #[arg(long, value_name = "PANNES", num_args = 0..=1, default_missing_value = "")]
+    mock_audio: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Surveillance : mesure quotidienne sans interface (section [monitor] de
+    /// la configuration), historique et alerte sous le score minimal
+    Monitor {
+        /// Une seule mesure, immédiate ; code de sortie 3 si alerte (cron, minuteur systemd)
+        #[arg(long)]
+        once: bool,
+    },
+}
+
+fn parse_channel(name: &str) -> std::result::Result<Channel, String> {
+    Channel::from_name(name).ok_or_else(|| (strings::tr().arg_channel_unknown)(name))
+}
+
+fn parse_lang(code: &str) -> std::result::Result<Lang, String> {
+    Lang::from_code(code).ok_or_else(|| (strings::tr().arg_lang_unknown)(code))
+}
+
+fn parse_theme(name: &str) -> std::result::Result<ThemeName, String> {
+    ThemeName::from_name(name).ok_or_else(|| (strings::tr().arg_theme_unknown)(name))
+}
+
+/// `--lang xx` ou `--lang=xx`, lu avant clap : les erreurs des autres
+/// arguments sont déjà dans la langue demandée.
+fn early_lang() -> Option<Lang> {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter().enumerate().find_map(|(i, arg)| match arg.strip_prefix("--lang")? {
+        "" => args.get(i + 1).and_then(|code| Lang::from_code(code)),
+        rest => rest.strip_prefix('=').and_then(Lang::from_code),
+    })
+}
 
 fn main() -> Result<()> {
-    App::run()
+    if let Some(lang) = early_lang() {
+        strings::set_lang(lang);
+    }
+    let cli = Cli::parse();
+    strings::set_lang(cli.lang);
+    if cli.list_assets {
+        for asset in assets::ASSETS {
+            println!("{:<12} {}{:<18} {}", asset.kind.name(), assets::BUILTIN_PREFIX, asset.name, asset.description);
+        }
+        return Ok(());
+    }
+    if let Some(spec) = &cli.mock_audio {
+        mockaudio::install(Some(MockAudio::parse(spec)?));
+    }
+    audio::set_keep_streams(!cli.release_devices);
+    let calibration = cli.mic_cal.as_deref().map(MicCalibration::load).transpose()?;
+    let target = cli.target.as_deref().map(TargetCurve::load).transpose()?;
+    let mut config = Config::load(&cli.config)?;
+    theme::set_theme(theme::initial(cli.theme, &config.theme));
+    theme::set_colors(&config.theme.colors).map_err(anyhow::Error::msg)?;
+    if cli.webhook.is_some() {
+        config.webhook.url = cli.webhook.clone();
+        config.webhook.validate().map_err(anyhow::Error::msg)?;
+    }
+    let webhook_url = config.webhook.url.as_deref();
+    let sample_rate = cli.sample_rate.unwrap_or_else(audio::negotiate_sample_rate);
+
+    if let Some(Command::Monitor { once }) = cli.command {
+        let alert = monitor::run(&config.monitor, webhook_url, once, || {
+            headless::run(cli.pre_delay, cli.retry_default_config, &config, sample_rate, calibration.as_ref(), target.as_ref(), cli.debug_dump.as_deref())
+        })?;
+        if alert {
+            std::process::exit(monitor::ALERT_EXIT_CODE);
+        }
+        return Ok(());
+    }
+
+    if cli.headless {
+        let report = headless::run(cli.pre_delay, cli.retry_default_config, &config, sample_rate, calibration.as_ref(), target.as_ref(), cli.debug_dump.as_deref())?;
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        if let Some(path) = &cli.png {
+            if report::write_png(&report.response(), path)? {
+                eprintln!("{}", (strings::tr().png_written)(&path.display().to_string()));
+            }
+        }
+        if let Some(url) = webhook_url {
+            webhook::notify(url, Event::Measurement, &report.summary(), &report);
+        }
+        return Ok(());
+    }
+
+    let mut state = AppState::new();
+    state.session_path = cli.session;
+    state.crossover_hz = cli.crossover;
+    state.sub_output = cli.sub_channel;
+    if !cli.subs.is_empty() {
+        if !(multisub::MIN_SUBS..=multisub::MAX_SUBS).contains(&cli.subs.len()) {
+            bail!((strings::tr().subs_count)(multisub::MIN_SUBS, multisub::MAX_SUBS, cli.subs.len()));
+        }
+        state.multisub = Some(MultiSub::new(cli.subs.clone()));
+    }
+    state.sample_rate = sample_rate;
+    state.reference = cli.reference;
+    state.calibration_file = calibration.clone();
+    state.calibration = calibration;
+    state.target_file = target.clone();
+    state.target = target;
+    state.retry_default_config = cli.retry_default_config;
+    state.sweep = config.sweep;
+    state.level = config.level;
+    state.gain = config.gain;
+    state.placement = config.placement;
+    state.scoring = config.score;
+    state.set_ambient(config.room.ambient());
+    state.set_room_dimensions(config.room.dimensions());
+    state.keys = KeyMap::new(&config.keys).map_err(anyhow::Error::msg)?;
+    state.reminder_params = config.reminders.clone();
+    state.loopback = config.loopback;
+    state.debug_dir = cli.debug_dump;
+    state.share_audio = cli.share_with_audio;
+    state.expert = !cli.basic;
+    state.checklist = cli.checklist.then(Checklist::default);
+    if let Some(path) = &cli.import {
+        state.import_session(path);
+    }
+    if let Some(path) = &cli.script {
+        let report = script::run_file(&mut state, path)?;
+        println!("{}", (strings::tr().script_ok)(report.commands, report.checks, report.quit));
+        return Ok(());
+    }
+    state.load_checks();
+    App::run(state)
 }
@@ -0,0 +1,48 @@
+// ============================================================
+//  mask.rs — Masque de tolérance de l'écart G/D
+//
+//  Un masque (ressource embarquée `standard`, `strict`) donne,
+//  par fréquence, l'écart G/D jugé sans conséquence : ±2 dB au
+//  médium, davantage aux extrêmes où la pièce domine. Il est
+//  ramené sur les bandes log du spectre avec la même
+//  interpolation que les calibrations micro.
+//
+//  Choisi ([Maj+F5]), il change le terme spectral du score : seul
+//  l'écart qui dépasse le masque est compté
+//  (`dsp::compute_score`), et le graphe trace sa limite sous la
+//  courbe Δ.
+// ============================================================
+
+use anyhow::{Context, Result};
+
+use crate::{
+    assets::{self, AssetKind},
+    calibration::MicCalibration,
+    dsp::NUM_BANDS,
+    strings::tr,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToleranceMask {
+    /// Nom de la ressource embarquée.
+    pub name: String,
+    /// Écart toléré (± dB) sur les `NUM_BANDS` bandes du spectre.
+    pub bands_db: Vec<f32>,
+}
+
+impl ToleranceMask {
+    /// Masque embarqué `name`.
+    pub fn builtin(name: &str) -> Result<Self> {
+        let asset = assets::find(AssetKind::ToleranceMask, name).with_context(|| {
+            let known: Vec<&str> = assets::list(AssetKind::ToleranceMask).map(|a| a.name).collect();
+            (tr().mask_unknown)(name, &known.join(", "))
+        })?;
+        let bands_db = MicCalibration { points: asset.points()?, name: String::new() }.band_corrections(NUM_BANDS);
+        Ok(ToleranceMask { name: asset.name.to_string(), bands_db })
+    }
+
+    /// Masques embarqués, dans l'ordre du registre.
+    pub fn all() -> Vec<Self> {
+        assets::list(AssetKind::ToleranceMask).filter_map(|a| Self::builtin(a.name).ok()).collect()
+    }
+}
@@ -0,0 +1,295 @@
+// ============================================================
+//  measurement.rs — Mesure d'une enceinte
+//
+//  Regroupe tout ce qui concerne une capture unique :
+//    - échantillons filtrés + signal de test joué
+//    - spectre en bandes log (dB)
+//    - réponse impulsionnelle et grandeurs dérivées
+//      (distance, EDT/C50/C80, IACC, temps de réverbération par octave,
+//      distorsion harmonique par octave)
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    audio::{Capture, Channel},
+    calibration::MicCalibration,
+    dsp::{self, ClarityMetrics, DecayTimes, SweepParams, Window, NUM_BANDS},
+    harmonics::{self, OctaveThd},
+    pipeline::AnalysisOptions,
+};
+
+/// Coupure du passe-haut appliqué à chaque capture : supprime le bruit de
+/// ronflement ambiant (ventilateurs PC, vibrations bureau) sans affecter la
+/// plage utile des enceintes.
+pub const HIGHPASS_HZ: f32 = 30.0;
+
+/// Durée d'IR gardée après le pic dans une session partagée (s).
+pub const SHARED_IR_SECS: f32 = 0.5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Measurement {
+    pub channel: Channel,
+    pub sample_rate: u32,
+
+    /// Capture mono filtrée (passe-haut `HIGHPASS_HZ`).
+    pub samples: Vec<f32>,
+    /// Signal de test joué pendant la capture (référence de déconvolution).
+    pub test_signal: Vec<f32>,
+    /// Paramètres du sweep joué (filtre inverse de la déconvolution).
+    #[serde(default)]
+    pub sweep: SweepParams,
+    /// Pistes « oreille gauche / oreille droite » (entrée stéréo à deux micros).
+    pub ears: Option<(Vec<f32>, Vec<f32>)>,
+    /// Piste de la boucle électrique de référence (`audio::Loopback`).
+    #[serde(default)]
+    pub loopback: Option<Vec<f32>>,
+
+    /// Spectre FFT moyenné de `samples` (amplitudes linéaires), gardé en
+    /// cache : les bandes se recalculent sans refaire la FFT.
+    #[serde(default)]
+    pub spectrum: Vec<f32>,
+    /// Spectre moyen en `NUM_BANDS` bandes logarithmiques (dB).
+    pub bands_db: Vec<f32>,
+
+    // Grandeurs dérivées de la réponse impulsionnelle (remplies par `analyze_ir`)
+    pub ir: Option<Vec<f32>>,
+    pub dist_m: Option<f32>,
+    /// Arrivées distinctes trouvées jusqu'au pic de l'IR (`dsp::Onset`) ;
+    /// plus d'une, le son direct est plus faible qu'une réflexion.
+    #[serde(default)]
+    pub onset_candidates: usize,
+    /// Latence système mesurée par la boucle (ms, hors pre_delay) ; présente,
+    /// elle est déjà retirée de `dist_m`, qui devient une distance absolue.
+    #[serde(default)]
+    pub latency_ms: Option<f32>,
+    /// Arrivée du signal dans la boucle (échantillons), tirée une fois de sa
+    /// déconvolution par `deconvolve` : relocaliser le front n'y revient pas.
+    #[serde(default)]
+    pub loopback_origin: Option<f32>,
+    /// Dérive d'horloge entre cartes corrigée avant l'analyse (ppm, `drift.rs`).
+    #[serde(default)]
+    pub clock_drift_ppm: Option<f32>,
+    pub clarity: Option<ClarityMetrics>,
+    pub iacc: Option<f32>,
+    /// EDT / T20 / T30 par octave (`dsp::OCTAVE_CENTERS`).
+    #[serde(default)]
+    pub rt60: Vec<DecayTimes>,
+    /// THD par octave, tiré des harmoniques du sweep (`harmonics.rs`).
+    #[serde(default)]
+    pub thd: Vec<OctaveThd>,
+    /// RMS de la capture, gardé quand l'audio brut est retiré (partage).
+    #[serde(default)]
+    pub capture_rms: Option<f32>,
+    /// Spectre fenêtré du dernier `rebuild_bands`, refait seulement quand
+    /// la fenêtre temporelle, le seuil du front ou la fenêtre FFT changent.
+    #[serde(skip)]
+    gated: Option<GatedSpectrum>,
+}
+
+/// Spectre raccordé à la réponse fenêtrée, et réglages qui l'ont donné ;
+/// `spectrum` vide : pas de fenêtre appliquée (aucune, pas d'IR ou pas
+/// d'audio).
+#[derive(Debug, Clone)]
+struct GatedSpectrum {
+    gate_ms: f32,
+    onset_db: f32,
+    window: Window,
+    spectrum: Option<Vec<f32>>,
+}
+
+impl Measurement {
+    /// Construit une mesure à partir d'une capture brute : filtre passe-haut,
+    /// FFT moyennée (fenêtre et lissage de `options`), découpage en bandes
+    /// puis correction de la réponse du micro.
+    pub fn from_capture(
+        channel: Channel,
+        capture: Capture,
+        test_signal: Vec<f32>,
+        sweep: SweepParams,
+        sample_rate: u32,
+        calibration: Option<&MicCalibration>,
+        options: AnalysisOptions,
+    ) -> Self {
+        let samples = dsp::highpass_filter(&capture.mono, HIGHPASS_HZ, sample_rate);
+
+        // Entrée à deux micros → conserve chaque oreille pour l'IACC
+        let ears = match capture.inputs.as_slice() {
+            [l, r] => Some((
+                dsp::highpass_filter(l, HIGHPASS_HZ, sample_rate),
+                dsp::highpass_filter(r, HIGHPASS_HZ, sample_rate),
+            )),
+            _ => None,
+        };
+
+        let mut m = Measurement {
+            channel,
+            sample_rate,
+            samples,
+            test_signal,
+            sweep,
+            ears,
+            loopback: capture.loopback,
+            spectrum: Vec::new(),
+            bands_db: Vec::new(),
+            ir: None,
+            dist_m: None,
+            onset_candidates: 0,
+            latency_ms: None,
+            loopback_origin: None,
+            clock_drift_ppm: None,
+            clarity: None,
+            iacc: None,
+            rt60: Vec::new(),
+            thd: Vec::new(),
+            capture_rms: None,
+            gated: None,
+        };
+        // La fenêtre temporelle part de l'IR : déconvolution avancée
+        if options.gate_ms > 0.0 {
+            m.deconvolve();
+        }
+        m.rebuild_bands(calibration, options);
+        m
+    }
+
+    /// Étape « spectre » : refait la FFT avec `window`. Sans audio (session
+    /// partagée), le spectre en cache est gardé tel quel.
+    pub fn rebuild_spectrum(&mut self, window: Window) {
+        if !self.samples.is_empty() {
+            self.spectrum = dsp::compute_fft(&self.samples, window);
+        }
+    }
+
+    /// Étape « bandes » : découpage du spectre en cache (FFT faite au besoin,
+    /// par exemple pour une session d'un format antérieur) puis correction
+    /// de la réponse du micro. Fenêtre temporelle et lissage (1/N d'octave)
+    /// portent sur une copie : le spectre en cache reste brut.
+    pub fn rebuild_bands(&mut self, calibration: Option<&MicCalibration>, options: AnalysisOptions) {
+        if self.spectrum.is_empty() {
+            self.spectrum = dsp::compute_fft(&self.samples, options.window);
+        }
+        self.update_gated(options);
+        let gated = self.gated.as_ref().and_then(|g| g.spectrum.as_deref());
+        let spectrum = dsp::smooth_spectrum(gated.unwrap_or(&self.spectrum), options.smoothing);
+        let mut bands = dsp::spectrum_to_bands(&spectrum, self.sample_rate, NUM_BANDS);
+        if let Some(cal) = calibration {
+            cal.apply(&mut bands);
+        }
+        self.bands_db = dsp::bands_to_db(&bands);
+    }
+
+    /// Vrai si le spectre courant vient de la fenêtre temporelle : faux
+    /// sans fenêtre, sans IR ou sans audio (session partagée).
+    pub fn is_gated(&self) -> bool {
+        self.gated.as_ref().is_some_and(|g| g.spectrum.is_some())
+    }
+
+    /// Refait le spectre fenêtré si les réglages qui le donnent ont changé.
+    fn update_gated(&mut self, options: AnalysisOptions) {
+        let same = |g: &GatedSpectrum| g.gate_ms == options.gate_ms && g.onset_db == options.onset_db && g.window == options.window;
+        if self.gated.as_ref().is_some_and(same) {
+            return;
+        }
+        let spectrum = self.gated_spectrum(options);
+        self.gated = Some(GatedSpectrum { gate_ms: options.gate_ms, onset_db: options.onset_db, window: options.window, spectrum });
+    }
+
+    /// Spectre raccordé à la réponse fenêtrée sur le son direct
+    /// (`options.gate_ms`) ; `None` sans fenêtre, sans IR ou sans audio (le
+    /// signal joué donne la forme du spectre).
+    fn gated_spectrum(&self, options: AnalysisOptions) -> Option<Vec<f32>> {
+        if options.gate_ms <= 0.0 || !self.has_audio() {
+            return None;
+        }
+        let sr = self.sample_rate;
+        let ir = self.ir.as_ref()?;
+        let gated = dsp::gated_response(ir, &self.samples, &self.test_signal, sr, options.onset_db, options.gate_ms, options.window)?;
+        Some(dsp::splice_gated(&self.spectrum, &gated, sr, options.gate_ms))
+    }
+
+    /// Calcule la réponse impulsionnelle et ses grandeurs dérivées.
+    /// `pre_delay_samples` est soustrait de la distance ; la latence système
+    /// reste incluse mais est identique pour toutes les enceintes. Avec une
+    /// boucle de référence, l'arrivée du signal dans la boucle sert d'origine.
+    /// Les déconvolutions (mesure et boucle) ne sont faites qu'une fois, par
+    /// le thread de capture : ensuite, seule la localisation du front est
+    /// refaite, selon `pre_delay_samples` et le seuil `onset_db`.
+    pub fn analyze_ir(&mut self, pre_delay_samples: usize, onset_db: f32) {
+        let sr = self.sample_rate;
+        self.deconvolve();
+        let Some(ir) = &self.ir else { return };
+        self.onset_candidates = dsp::ir_onset(ir, sr, onset_db).map_or(0, |o| o.candidates);
+
+        let origin = self.loopback_origin;
+        self.latency_ms = origin.map(|o| (o - pre_delay_samples as f32) / sr as f32 * 1000.0);
+        self.dist_m = match origin {
+            Some(origin) => dsp::distance_from_origin(ir, sr, origin, onset_db),
+            None => dsp::distance_from_ir(ir, sr, pre_delay_samples, onset_db),
+        };
+    }
+
+    /// Étape coûteuse de `analyze_ir`, faite une seule fois : IR, clarté,
+    /// RT60, THD, IACC et arrivée dans la boucle. Le thread de capture
+    /// l'appelle avant de rendre la mesure, l'analyse ne fait plus que la
+    /// localiser.
+    pub fn deconvolve(&mut self) {
+        let sr = self.sample_rate;
+        // Session d'une version sans `loopback_origin` : IR déjà là, boucle pas encore lue
+        if self.loopback_origin.is_none() {
+            self.loopback_origin = self.loopback.as_ref().and_then(|lb| dsp::loopback_arrival(lb, &self.test_signal, &self.sweep, sr));
+        }
+        if self.ir.is_some() {
+            return;
+        }
+        let ir = dsp::compute_impulse_response(&self.samples, &self.test_signal, &self.sweep, sr);
+        self.clarity = dsp::compute_clarity(&ir, sr);
+        self.rt60 = dsp::compute_rt60(&ir, sr);
+        self.thd = harmonics::sweep_thd(&self.samples, &self.test_signal, &self.sweep, sr);
+        self.iacc = self.ears.as_ref().and_then(|(l, r)| {
+            let ir_l = dsp::compute_impulse_response(l, &self.test_signal, &self.sweep, sr);
+            let ir_r = dsp::compute_impulse_response(r, &self.test_signal, &self.sweep, sr);
+            dsp::compute_iacc(&ir_l, &ir_r, sr)
+        });
+        self.ir = Some(ir);
+        // Un spectre fenêtré demandé avant l'IR n'a pas pu être calculé
+        self.gated = None;
+    }
+
+    /// Niveau RMS de la capture filtrée.
+    pub fn rms(&self) -> f32 {
+        self.capture_rms.unwrap_or_else(|| dsp::compute_rms(&self.samples))
+    }
+
+    /// Moyenne des octaves de `rt60` (`pick` : T30, T20 ou EDT).
+    pub fn mean_decay(&self, pick: fn(&DecayTimes) -> Option<f32>) -> Option<f32> {
+        let times: Vec<f32> = self.rt60.iter().filter_map(pick).collect();
+        (!times.is_empty()).then(|| times.iter().sum::<f32>() / times.len() as f32)
+    }
+
+    /// Octave la plus distordue.
+    pub fn worst_thd(&self) -> Option<&OctaveThd> {
+        self.thd.iter().max_by(|a, b| a.thd_db.total_cmp(&b.thd_db))
+    }
+
+    /// Faux pour une mesure partagée sans audio brut : rien à réanalyser.
+    pub fn has_audio(&self) -> bool {
+        !self.samples.is_empty()
+    }
+
+    /// Retire l'audio brut (capture, signal de test, pistes annexes) et ne
+    /// garde de l'IR que `SHARED_IR_SECS` après son pic : les indices, donc
+    /// distances et retards, sont inchangés. Bandes, spectre et grandeurs
+    /// dérivées restent.
+    pub fn strip_audio(&mut self) {
+        self.capture_rms = Some(self.rms());
+        self.samples = Vec::new();
+        self.test_signal = Vec::new();
+        self.ears = None;
+        self.loopback = None;
+        if let Some(ir) = self.ir.as_mut() {
+            let peak = ir.iter().enumerate().max_by(|a, b| a.1.abs().total_cmp(&b.1.abs())).map_or(0, |(i, _)| i);
+            ir.truncate(peak + (SHARED_IR_SECS * self.sample_rate as f32) as usize);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// ============================================================
+//  mmm.rs — Moyenne spatiale micro en mouvement (MMM)
+//
+//  Un sweep mesure un seul point : à quelques centimètres près,
+//  les creux et bosses de la pièce changent, et une différence
+//  G/D peut n'être qu'un accident de position. Ici chaque enceinte
+//  joue `MMM_SECS` de bruit rose périodique pendant que l'on
+//  promène lentement le micro autour de la place d'écoute (tête,
+//  ±30 cm). Le spectre de chaque période complète est accumulé en
+//  puissance (`dsp::SpectrumAccumulator`) : les accidents locaux
+//  se moyennent, la tonalité propre à l'enceinte reste. Le relevé
+//  compare G et D par zone spectrale.
+//
+//  Pas d'IR ni de délai : le signal n'est pas un sweep. La
+//  première seconde (latence, établissement dans la pièce) et la
+//  fin (fondu, queue) sont ignorées.
+// ============================================================
+
+use crate::{
+    calibration::MicCalibration,
+    dsp::{self, FFT_SIZE, NUM_BANDS, SpectrumAccumulator},
+};
+
+/// Durée du bruit joué sur chaque enceinte (s).
+pub const MMM_SECS: f32 = 25.0;
+
+/// Crête du bruit (pleine échelle = 1.0).
+pub const MMM_LEVEL: f32 = 0.5;
+
+/// Début de capture ignoré : latence du système et régime établi (s).
+const SETTLE_SECS: f32 = 1.0;
+
+/// Nombre minimal de périodes accumulées pour un résultat.
+const MIN_BLOCKS: usize = 20;
+
+/// Bruit joué sur une enceinte.
+pub fn generate_test_signal(sample_rate: u32) -> Vec<f32> {
+    dsp::generate_periodic_pink_noise(sample_rate, MMM_SECS, MMM_LEVEL)
+}
+
+/// Spectre moyen d'une enceinte.
+#[derive(Debug, Clone)]
+pub struct SpatialAverage {
+    /// Bandes log (dB), micro corrigé et lissage appliqué.
+    pub bands_db: Vec<f32>,
+    /// Périodes accumulées.
+    pub blocks: usize,
+}
+
+/// Spectre moyen de la capture ; `None` si trop peu de périodes.
+pub fn analyze(
+    capture: &[f32],
+    sample_rate: u32,
+    calibration: Option<&MicCalibration>,
+    smoothing: u32,
+) -> Option<SpatialAverage> {
+    let from = (SETTLE_SECS * sample_rate as f32) as usize;
+    // Le bruit s'arrête à MMM_SECS après le début de capture, plus la latence
+    let to = (((MMM_SECS - 0.2) * sample_rate as f32) as usize).min(capture.len());
+    let mut acc = SpectrumAccumulator::default();
+    for chunk in capture.get(from..to)?.chunks(FFT_SIZE / 4) {
+        acc.push(chunk);
+    }
+    if acc.blocks() < MIN_BLOCKS {
+        return None;
+    }
+    let spectrum = dsp::smooth_spectrum(&acc.spectrum(), smoothing);
+    let mut bands = dsp::spectrum_to_bands(&spectrum, sample_rate, NUM_BANDS);
+    if let Some(cal) = calibration {
+        cal.apply(&mut bands);
+    }
+    Some(SpatialAverage { bands_db: dsp::bands_to_db(&bands), blocks: acc.blocks() })
+}
+
+/// Résultat pour les deux enceintes.
+#[derive(Debug, Clone, Default)]
+pub struct MmmReport {
+    pub left: Option<SpatialAverage>,
+    pub right: Option<SpatialAverage>,
+}
+
+impl MmmReport {
+    /// D − G de chaque bande, niveaux alignés sur la moyenne (seule la
+    /// tonalité compte : l'écart de volume est mesuré par ailleurs).
+    pub fn diff_db(&self) -> Option<Vec<f32>> {
+        let (l, r) = (self.left.as_ref()?, self.right.as_ref()?);
+        let diff: Vec<f32> = r.bands_db.iter().zip(&l.bands_db).map(|(r, l)| r - l).collect();
+        let mean = diff.iter().sum::<f32>() / diff.len().max(1) as f32;
+        Some(diff.iter().map(|d| d - mean).collect())
+    }
+
+    /// D − G moyen de chaque zone de `dsp::SPECTRAL_REGIONS`.
+    pub fn region_diffs(&self) -> Option<Vec<f32>> {
+        self.diff_db().map(|d| dsp::region_means(&d))
+    }
+
+    /// |D − G| moyen sur toutes les bandes (dB).
+    pub fn mismatch_db(&self) -> Option<f32> {
+        self.diff_db().map(|d| d.iter().map(|v| v.abs()).sum::<f32>() / d.len().max(1) as f32)
+    }
+}
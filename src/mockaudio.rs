@@ -0,0 +1,207 @@
+// ============================================================
+//  mockaudio.rs — Carte son simulée (tests, CI sans matériel)
+//
+//  Installée (`install`, ou `--mock-audio` en ligne de commande),
+//  elle remplace cpal dans `audio::play_and_capture` et ses
+//  variantes. Seule l'ouverture des flux est simulée : les
+//  callbacks simulés passent par la même `StreamSlot` que les
+//  vrais (calage de l'entrée sur la sortie compris), et
+//  le chien de garde (entrée figée), le contrôle de longueur, le
+//  contrôle de niveau et la seconde tentative avec la config par
+//  défaut sont ceux du matériel.
+//
+//  Pièce : chaque sortie atteint le micro (entrée 0) avec son
+//  retard et son gain (`speakers`, indice = emplacement de
+//  sortie), sur un bruit de fond faible ; les autres entrées
+//  reçoivent la somme des sorties sans retard (boucle électrique).
+//  L'horloge simulée peut tourner plus vite que le temps réel
+//  (`speed`) pour des tests rapides ; les callbacks d'entrée
+//  peuvent être décalés sur ceux de la sortie (`skew`, ms).
+//
+//  Pannes à la demande (`Fault`), cumulables :
+//    busy      — périphérique tenu par une autre application ;
+//    hang      — pilote bloqué à l'ouverture (délai dépassé) ;
+//    reject    — config demandée refusée, seule la config par
+//                défaut s'ouvre (à `default_rate`) ;
+//    rate      — périphériques ouverts à un autre taux que celui
+//                de la session (conversion) ;
+//    short     — l'entrée cesse de livrer après une fraction de
+//                la capture (capture tronquée) ;
+//    drop      — un callback d'entrée sur `every` perdu ;
+//    clip      — entrée saturée.
+//  `--mock-audio "short:0.5,speed:8"` : pannes et réglages séparés
+//  par des virgules, chaîne vide = carte saine.
+// ============================================================
+
+use anyhow::{Result, bail};
+use rand::Rng;
+use std::sync::Mutex;
+
+use crate::{dsp, strings::tr};
+
+/// Noms affichés des périphériques simulés.
+pub const OUTPUT_NAME: &str = "Sortie simulée";
+pub const INPUT_NAME: &str = "Entrée simulée";
+
+/// Coupure basse des enceintes simulées (Hz).
+const SPEAKER_HIGHPASS_HZ: f32 = 80.0;
+
+/// Carte installée, lue à chaque ouverture de flux.
+static INSTALLED: Mutex<Option<MockAudio>> = Mutex::new(None);
+
+/// Panne simulée.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fault {
+    Busy,
+    Hang,
+    Reject { default_rate: u32 },
+    Rate { rate: u32 },
+    Short { fraction: f32 },
+    Drop { every: usize },
+    Clip,
+}
+
+/// Trajet d'une sortie jusqu'au micro.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MockSpeaker {
+    pub delay_ms: f32,
+    pub gain_db: f32,
+}
+
+/// Carte son simulée : canaux, pièce, vitesse de l'horloge et pannes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockAudio {
+    pub output_channels: u16,
+    pub input_channels: u16,
+    /// Sorties entendues par le micro, par emplacement ; au-delà, muettes.
+    pub speakers: Vec<MockSpeaker>,
+    /// Bruit de fond du micro (dBFS efficaces).
+    pub noise_dbfs: f32,
+    /// Vitesse de l'horloge simulée (1 = temps réel).
+    pub speed: f32,
+    /// Retard des callbacks d'entrée sur ceux de la sortie (ms, négatif :
+    /// en avance).
+    pub input_skew_ms: f32,
+    pub faults: Vec<Fault>,
+}
+
+impl Default for MockAudio {
+    /// Stéréo, enceintes à 1 m environ, droite 0,5 ms plus loin.
+    fn default() -> Self {
+        MockAudio {
+            output_channels: 2,
+            input_channels: 1,
+            speakers: vec![MockSpeaker { delay_ms: 3.0, gain_db: -12.0 }, MockSpeaker { delay_ms: 3.5, gain_db: -12.0 }],
+            noise_dbfs: -80.0,
+            speed: 1.0,
+            input_skew_ms: 0.0,
+            faults: Vec::new(),
+        }
+    }
+}
+
+impl MockAudio {
+    /// Lit « busy,short:0.5,speed:8 » (voir l'en-tête) ; vide = carte saine.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut mock = MockAudio::default();
+        for token in spec.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            let (name, value) = token.split_once(':').map_or((token, None), |(n, v)| (n, Some(v)));
+            let number = |default: Option<f32>| -> Result<f32> {
+                match (value, default) {
+                    (Some(v), _) => v.parse::<f32>().map_err(|_| anyhow::anyhow!((tr().mock_number)(name, v))),
+                    (None, Some(d)) => Ok(d),
+                    (None, None) => bail!((tr().mock_value)(name)),
+                }
+            };
+            let fault = match name {
+                "busy" => Fault::Busy,
+                "hang" => Fault::Hang,
+                "reject" => Fault::Reject { default_rate: number(Some(44_100.0))? as u32 },
+                "rate" => Fault::Rate { rate: number(None)? as u32 },
+                "short" => Fault::Short { fraction: number(Some(0.5))?.clamp(0.0, 1.0) },
+                "drop" => Fault::Drop { every: (number(Some(4.0))? as usize).max(1) },
+                "clip" => Fault::Clip,
+                "speed" => {
+                    mock.speed = number(None)?.max(0.1);
+                    continue;
+                }
+                "inputs" => {
+                    mock.input_channels = (number(None)? as u16).max(1);
+                    continue;
+                }
+                "skew" => {
+                    mock.input_skew_ms = number(None)?;
+                    continue;
+                }
+                _ => bail!((tr().mock_unknown)(name)),
+            };
+            mock.faults.push(fault);
+        }
+        Ok(mock)
+    }
+
+    /// Taux d'ouverture des périphériques pour une session à `rate` Hz.
+    pub fn open_rate(&self, rate: u32) -> u32 {
+        self.faults.iter().find_map(|f| if let Fault::Rate { rate } = *f { Some(rate) } else { None }).unwrap_or(rate)
+    }
+
+    /// Taux de la config par défaut, si la config demandée est refusée.
+    pub fn rejected(&self) -> Option<u32> {
+        self.faults.iter().find_map(|f| if let Fault::Reject { default_rate } = *f { Some(default_rate) } else { None })
+    }
+
+    /// Fraction de la capture livrée avant que l'entrée ne se taise.
+    pub fn short_fraction(&self) -> Option<f32> {
+        self.faults.iter().find_map(|f| if let Fault::Short { fraction } = *f { Some(fraction) } else { None })
+    }
+
+    /// Un callback d'entrée perdu sur `every`.
+    pub fn drop_every(&self) -> Option<usize> {
+        self.faults.iter().find_map(|f| if let Fault::Drop { every } = *f { Some(every) } else { None })
+    }
+
+    /// Ce que capteraient les entrées pendant que `output` (interleaved,
+    /// `out_channels` canaux, `rate` Hz) est joué après `lead` trames de
+    /// silence (la pause pré-capture, présente dans les captures comme sur
+    /// le matériel) : `frames` trames interleaved de `input_channels` canaux.
+    pub fn record(&self, output: &[f32], out_channels: usize, rate: u32, lead: usize, frames: usize) -> Vec<f32> {
+        let (outs, ins) = (out_channels.max(1), self.input_channels.max(1) as usize);
+        let played = output.len() / outs;
+        let sample = |frame: isize, ch: usize| {
+            usize::try_from(frame - lead as isize).ok().filter(|&f| f < played).map_or(0.0, |f| output[f * outs + ch])
+        };
+        let paths: Vec<(usize, isize, f32)> = self
+            .speakers
+            .iter()
+            .enumerate()
+            .take(outs)
+            .map(|(ch, s)| (ch, (s.delay_ms / 1000.0 * rate as f32).round() as isize, 10f32.powf(s.gain_db / 20.0)))
+            .collect();
+        let mic: Vec<f32> = (0..frames as isize)
+            .map(|n| paths.iter().map(|&(ch, delay, gain)| gain * sample(n - delay, ch)).sum())
+            .collect();
+        // Enceinte : coupure basse du 4e ordre, comme le `synth` des scripts
+        let mic = dsp::highpass_filter(&dsp::highpass_filter(&mic, SPEAKER_HIGHPASS_HZ, rate), SPEAKER_HIGHPASS_HZ, rate);
+        let noise = 10f32.powf(self.noise_dbfs / 20.0) * 3f32.sqrt();
+        let boost = if self.faults.contains(&Fault::Clip) { 100.0 } else { 1.0 };
+        let mut rng = rand::thread_rng();
+        let mut out = vec![0.0f32; frames * ins];
+        for (n, frame) in out.chunks_exact_mut(ins).enumerate() {
+            frame[0] = ((mic[n] + noise * rng.gen_range(-1.0..1.0)) * boost).clamp(-1.0, 1.0);
+            let direct: f32 = (0..outs).map(|ch| sample(n as isize, ch)).sum();
+            frame[1..].fill(direct.clamp(-1.0, 1.0));
+        }
+        out
+    }
+}
+
+/// Installe (`Some`) ou retire (`None`) la carte simulée pour toutes les
+/// captures suivantes du processus.
+pub fn install(mock: Option<MockAudio>) {
+    *INSTALLED.lock().unwrap_or_else(|e| e.into_inner()) = mock;
+}
+
+/// Carte simulée installée, s'il y en a une.
+pub fn installed() -> Option<MockAudio> {
+    INSTALLED.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
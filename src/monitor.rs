@@ -0,0 +1,280 @@
+// ============================================================
+//  monitor.rs — Surveillance d'une installation fixe
+//
+//  `speaker-align monitor` tourne en arrière-plan (service
+//  systemd, tmux…) et lance chaque jour, à l'heure `[monitor] at`,
+//  la même séquence que `--headless` avec le profil du fichier de
+//  configuration (sweep, niveau, boucle) et la calibration micro.
+//  Chaque mesure est ajoutée à un historique JSON Lines ; sous
+//  `min_score`, une alerte est levée :
+//    - stderr, et code de sortie `ALERT_EXIT_CODE` avec `--once`
+//      (cron, minuteur systemd : une mesure puis arrêt) ;
+//    - message MQTT (QoS 0) si un broker est configuré ;
+//    - événement « alert » du webhook (`webhook.rs`), qui reçoit
+//      sinon chaque mesure en « measurement ».
+//  Une mesure impossible (périphérique absent) est journalisée et
+//  retentée le lendemain ; avec `--once`, c'est une erreur.
+//
+//  L'heure est en UTC, comme les horodatages de l'historique : le
+//  programme n'embarque pas de base de fuseaux horaires.
+// ============================================================
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::OpenOptions,
+    io::{Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    headless::AnalysisReport,
+    schema,
+    strings::tr,
+    webhook::{self, Event},
+};
+
+/// Code de sortie de `monitor --once` quand le score est sous le seuil.
+pub const ALERT_EXIT_CODE: i32 = 3;
+
+/// Délai de connexion et d'envoi vers le broker MQTT.
+const MQTT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Section `[monitor]` du fichier de configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorParams {
+    /// Heure de la mesure quotidienne, « HH:MM » (UTC).
+    pub at: String,
+    /// Score sous lequel l'alerte est levée (0–100).
+    pub min_score: u32,
+    /// Historique des mesures, une ligne JSON par mesure.
+    pub history: PathBuf,
+    /// Broker MQTT des alertes, « hôte:port » ; absent = pas de MQTT.
+    pub mqtt_broker: Option<String>,
+    pub mqtt_topic: String,
+}
+
+impl Default for MonitorParams {
+    fn default() -> Self {
+        MonitorParams {
+            at: "03:00".into(),
+            min_score: 80,
+            history: PathBuf::from("monitor.jsonl"),
+            mqtt_broker: None,
+            mqtt_topic: "speaker-align/alert".into(),
+        }
+    }
+}
+
+impl MonitorParams {
+    /// Heure et minute de `at`.
+    pub fn schedule(&self) -> Result<(u64, u64), String> {
+        let parsed = self
+            .at
+            .split_once(':')
+            .and_then(|(h, m)| Some((h.trim().parse::<u64>().ok()?, m.trim().parse::<u64>().ok()?)));
+        match parsed {
+            Some((h, m)) if h < 24 && m < 60 => Ok((h, m)),
+            _ => Err((tr().monitor_at)(&self.at)),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        self.schedule()?;
+        if self.min_score > 100 {
+            return Err((tr().monitor_min_score)(self.min_score));
+        }
+        Ok(())
+    }
+}
+
+/// Ligne de l'historique (et charge utile des alertes).
+#[derive(Debug, Clone, Serialize)]
+pub struct MonitorRecord {
+    /// Version du format de la ligne (`schema::HISTORY_VERSION`).
+    pub schema_version: u32,
+    /// Horodatage UTC, « 2026-10-16T03:00:12Z ».
+    pub time: String,
+    /// `None` si l'analyse n'a pas abouti (signal absent…) : compte comme une alerte.
+    pub score: Option<u32>,
+    pub min_score: u32,
+    pub alert: bool,
+    pub report: AnalysisReport,
+}
+
+/// Boucle de surveillance : attend l'heure prévue, mesure avec `measure`,
+/// journalise, alerte. Ne rend la main qu'avec `once` (une mesure,
+/// immédiate) : `true` si l'alerte a été levée.
+pub fn run(
+    params: &MonitorParams,
+    webhook_url: Option<&str>,
+    once: bool,
+    mut measure: impl FnMut() -> Result<AnalysisReport>,
+) -> Result<bool> {
+    let (hour, minute) = params.schedule().map_err(|e| anyhow!(e))?;
+    loop {
+        if !once {
+            let wait = until_next(unix_secs(), hour, minute);
+            eprintln!("{}", (tr().monitor_next)(hour, minute, wait.as_secs() / 3600, wait.as_secs() % 3600 / 60));
+            thread::sleep(wait);
+        }
+        let report = match measure() {
+            Ok(report) => report,
+            Err(e) if !once => {
+                eprintln!("{}", (tr().monitor_failed)(&utc_timestamp(unix_secs()), &format!("{:#}", e)));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        let alert = record(params, webhook_url, report)?;
+        if once {
+            return Ok(alert);
+        }
+    }
+}
+
+/// Ajoute la mesure à l'historique, la notifie et lève l'alerte si besoin.
+fn record(params: &MonitorParams, webhook_url: Option<&str>, report: AnalysisReport) -> Result<bool> {
+    let score = report.score;
+    let alert = score.is_none_or(|s| s < params.min_score);
+    let summary = match (alert, score) {
+        (true, Some(s)) => (tr().monitor_alert_score)(s, params.min_score),
+        (true, None) => tr().monitor_alert_incomplete.to_string(),
+        (false, _) => report.summary(),
+    };
+    let record = MonitorRecord {
+        schema_version: schema::HISTORY_VERSION,
+        time: utc_timestamp(unix_secs()),
+        score,
+        min_score: params.min_score,
+        alert,
+        report,
+    };
+    let line = serde_json::to_string(&record)?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&params.history)
+        .with_context(|| (tr().monitor_history)(&params.history.display().to_string()))?;
+    writeln!(file, "{}", line)?;
+
+    match score {
+        Some(s) => eprintln!("{}", (tr().monitor_score)(&record.time, s, params.min_score)),
+        None => eprintln!("{}", (tr().monitor_incomplete)(&record.time)),
+    }
+    if let Some(url) = webhook_url {
+        let event = if alert { Event::Alert } else { Event::Measurement };
+        webhook::notify(url, event, &summary, &record);
+    }
+    if alert {
+        eprintln!("{}", tr().monitor_alert);
+        // L'alerte MQTT est un plus : son échec ne doit pas arrêter la surveillance
+        if let Some(broker) = &params.mqtt_broker {
+            if let Err(e) = mqtt_publish(broker, &params.mqtt_topic, line.as_bytes()) {
+                eprintln!("{}", (tr().mqtt_not_sent)(broker, &format!("{:#}", e)));
+            }
+        }
+    }
+    Ok(alert)
+}
+
+// ─── Horloge (UTC, sans dépendance) ───────────────────────────────────────────
+
+fn unix_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// Attente jusqu'à la prochaine occurrence de `hour:minute` UTC (demain si
+/// l'heure du jour est passée ou tombe maintenant).
+fn until_next(now: u64, hour: u64, minute: u64) -> Duration {
+    let target = hour * 3600 + minute * 60;
+    let today = now % 86_400;
+    let wait = if target > today { target - today } else { 86_400 - today + target };
+    Duration::from_secs(wait)
+}
+
+/// « AAAA-MM-JJTHH:MM:SSZ » (calendrier grégorien proleptique).
+fn utc_timestamp(secs: u64) -> String {
+    // Jours depuis 1970 → date civile, en ères de 400 ans (146 097 jours)
+    let days = secs / 86_400 + 719_468;
+    let era = days / 146_097;
+    let doe = days % 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    let t = secs % 86_400;
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, t / 3600, t % 3600 / 60, t % 60)
+}
+
+// ─── MQTT 3.1.1 minimal (CONNECT, PUBLISH QoS 0, DISCONNECT) ─────────────────
+
+fn mqtt_packet(kind: u8, body: &[u8]) -> Vec<u8> {
+    let mut packet = vec![kind];
+    // Longueur restante : 7 bits par octet, bit de poids fort = suite
+    let mut len = body.len();
+    loop {
+        let byte = (len % 128) as u8;
+        len /= 128;
+        packet.push(if len > 0 { byte | 0x80 } else { byte });
+        if len == 0 {
+            break;
+        }
+    }
+    packet.extend_from_slice(body);
+    packet
+}
+
+fn mqtt_string(out: &mut Vec<u8>, s: &[u8]) {
+    out.extend_from_slice(&(s.len() as u16).to_be_bytes());
+    out.extend_from_slice(s);
+}
+
+/// Publie `payload` sur `topic` (session propre, sans authentification).
+fn mqtt_publish(broker: &str, topic: &str, payload: &[u8]) -> Result<()> {
+    // Chaque adresse du broker est essayée avec un délai borné : un hôte
+    // qui ne répond pas ne doit pas bloquer la surveillance
+    let mut last_err = None;
+    let mut stream = None;
+    for addr in broker.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, MQTT_TIMEOUT) {
+            Ok(s) => {
+                stream = Some(s);
+                break;
+            }
+            Err(e) => last_err = Some(e),
+        }
+    }
+    let Some(mut stream) = stream else {
+        return Err(last_err.map_or_else(|| anyhow!((tr().mqtt_no_address)(broker)), Into::into));
+    };
+    stream.set_read_timeout(Some(MQTT_TIMEOUT))?;
+    stream.set_write_timeout(Some(MQTT_TIMEOUT))?;
+
+    // CONNECT : protocole « MQTT » niveau 4, clean session, keep-alive 60 s
+    let mut connect = Vec::new();
+    mqtt_string(&mut connect, b"MQTT");
+    connect.extend_from_slice(&[4, 0x02, 0, 60]);
+    mqtt_string(&mut connect, b"speaker-align");
+    stream.write_all(&mqtt_packet(0x10, &connect))?;
+
+    let mut connack = [0u8; 4];
+    stream.read_exact(&mut connack)?;
+    if connack[0] != 0x20 || connack[3] != 0 {
+        bail!((tr().mqtt_refused)(connack[3]));
+    }
+
+    let mut publish = Vec::new();
+    mqtt_string(&mut publish, topic.as_bytes());
+    publish.extend_from_slice(payload);
+    stream.write_all(&mqtt_packet(0x30, &publish))?;
+    stream.write_all(&mqtt_packet(0xE0, &[]))?;
+    Ok(())
+}
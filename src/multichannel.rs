@@ -0,0 +1,126 @@
+// ============================================================
+//  multichannel.rs — Comparaison d'enceintes deux à deux
+//
+//  Généralise l'analyse G/D à n'importe quelle paire : chaque
+//  enceinte capturée (FL, FR, C, SL, SR…) est comparée à une
+//  enceinte de référence choisie par l'utilisateur :
+//    délai (différence de distances), niveau (RMS), différence
+//    spectrale, inclinaison et score composite.
+//  L'analyse stéréo principale n'est que la paire (FL, FR).
+//
+//  Des comparaisons se déduisent les réglages à saisir dans
+//  l'ampli (`trims`) : gain de chaque enceinte pour égaler la
+//  référence, et retard pour que toutes arrivent ensemble. Changer
+//  de référence ([F]) recalcule tout depuis les mesures en place.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::{audio::Channel, dsp, measurement::Measurement, pipeline::AnalysisOptions, scoring::ScoreWeights};
+
+/// Comparaison d'une enceinte à la référence.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairResult {
+    pub channel: Channel,
+    pub dist_m: Option<f32>,
+    /// Retard de l'enceinte par rapport à la référence (ms, positif = plus loin).
+    pub delay_ms: f32,
+    /// Niveau de l'enceinte − niveau de la référence (dB).
+    pub level_diff_db: f32,
+    /// Différence spectrale enceinte − référence, par bande (dB).
+    pub diff_db: Vec<f32>,
+    pub freq_tilt: f32,
+    pub score: u32,
+}
+
+/// Compare `other` à `reference`. Les deux mesures doivent avoir été
+/// passées par `Measurement::analyze_ir` pour que le délai soit renseigné.
+/// Inclinaison et score ne portent que sur la plage d'analyse de `options` ;
+/// `target_db` (courbe cible sur les bandes du spectre) et `mask_db` (masque
+/// de tolérance, mêmes bandes) entrent au score, pondéré selon `weights`.
+pub fn compare(
+    reference: &Measurement,
+    other: &Measurement,
+    options: &AnalysisOptions,
+    target_db: Option<&[f32]>,
+    mask_db: Option<&[f32]>,
+    weights: &ScoreWeights,
+) -> PairResult {
+    // Différence de distances → annule pre_delay ET latence système
+    let delay_ms = match (reference.dist_m, other.dist_m) {
+        (Some(r), Some(o)) => (o - r) / dsp::speed_of_sound() * 1000.0,
+        _ => 0.0,
+    };
+
+    let ref_rms = reference.rms();
+    let other_rms = other.rms();
+    let level_diff_db = if ref_rms > 0.0 && other_rms > 0.0 {
+        20.0 * (other_rms / ref_rms).log10()
+    } else {
+        0.0
+    };
+
+    let ref_db = &reference.bands_db;
+    let other_db = &other.bands_db;
+    let diff_db = ref_db.iter().zip(other_db.iter()).map(|(r, o)| o - r).collect();
+    let range = options.bands();
+    let (ref_in, other_in) = (&ref_db[range.clone()], &other_db[range.clone()]);
+    let target_in = target_db.map(|t| &t[range.clone()]);
+    let mask_in = mask_db.map(|m| &m[range]);
+
+    PairResult {
+        channel: other.channel,
+        dist_m: other.dist_m,
+        delay_ms,
+        level_diff_db,
+        diff_db,
+        freq_tilt: dsp::compute_freq_tilt(ref_in, other_in),
+        score: dsp::compute_score(ref_in, other_in, delay_ms, level_diff_db, target_in, mask_in, weights),
+    }
+}
+
+/// Réglage d'une enceinte, relatif à la référence.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelTrim {
+    pub channel: Channel,
+    /// Gain à appliquer pour égaler le niveau de la référence (dB).
+    pub trim_db: f32,
+    /// Retard à appliquer pour arriver avec les autres (ms, ≥ 0 : la plus
+    /// lointaine est à zéro) ; `None` sans distance mesurée.
+    pub delay_ms: Option<f32>,
+}
+
+/// Réglages de la référence (en tête, gain nul) et de chaque enceinte de
+/// `pairs`. Les retards ne sont donnés que si la référence a une distance.
+pub fn trims(reference: Channel, reference_dist_m: Option<f32>, pairs: &[PairResult]) -> Vec<ChannelTrim> {
+    // Avance de chaque enceinte sur la référence (ms)
+    let lead = |p: &PairResult| p.dist_m.and(reference_dist_m).map(|_| -p.delay_ms);
+    let shift = pairs.iter().filter_map(lead).fold(0.0f32, f32::min);
+    let mut out = vec![ChannelTrim { channel: reference, trim_db: 0.0, delay_ms: reference_dist_m.map(|_| -shift) }];
+    out.extend(pairs.iter().map(|p| ChannelTrim {
+        channel: p.channel,
+        trim_db: -p.level_diff_db,
+        delay_ms: lead(p).map(|l| l - shift),
+    }));
+    out
+}
+
+/// Compare toutes les mesures à celle du canal `reference` (exclue du résultat).
+/// Vide si la référence n'a pas été capturée.
+pub fn compare_all<'a>(
+    reference: Channel,
+    measurements: impl IntoIterator<Item = &'a Measurement>,
+    options: &AnalysisOptions,
+    target_db: Option<&[f32]>,
+    mask_db: Option<&[f32]>,
+    weights: &ScoreWeights,
+) -> Vec<PairResult> {
+    let all: Vec<&Measurement> = measurements.into_iter().collect();
+    let Some(reference) = all.iter().find(|m| m.channel == reference) else {
+        return Vec::new();
+    };
+    all.iter()
+        .filter(|m| m.channel != reference.channel)
+        .map(|m| compare(reference, m, options, target_db, mask_db, weights))
+        .collect()
+}
@@ -0,0 +1,261 @@
+// ============================================================
+//  multisub.rs — Réglage de plusieurs caissons
+//
+//  Avec 2 à 4 caissons (`--subs LFE,SL…`), chaque place d'écoute
+//  est mesurée caisson par caisson ([F3] : un sweep par sortie,
+//  [Maj+F3] pour reprendre la dernière place). La réponse complexe
+//  de chacun est relevée de 20 à 120 Hz par DFT de l'IR, le temps
+//  compté depuis l'arrivée du signal dans la boucle de référence :
+//  la latence des buffers, propre à chaque capture, disparaît et
+//  les phases s'additionnent telles quelles. Sans boucle, pas de
+//  réglage — un retard au buffer près n'aurait aucun sens.
+//
+//  Le réglage cherché — gain, retard et polarité de chaque caisson,
+//  le premier servant de référence — rend le grave le plus égal
+//  possible d'une place à l'autre : critère = moyenne, sur les
+//  fréquences, de l'écart-type entre places du niveau de la somme
+//  (dB). Une baisse du niveau moyen est pénalisée, sans quoi des
+//  caissons qui s'annulent passeraient pour un grave « uniforme ».
+//
+//  Recherche : chaque caisson tour à tour parcourt toute sa grille,
+//  les autres restant fixés (descente par coordonnées). Exhaustive
+//  avec deux caissons ; quelques passes suffisent au-delà.
+// ============================================================
+
+use rustfft::num_complex::Complex;
+use std::f32::consts::PI;
+
+use crate::audio::Channel;
+
+/// Nombre de caissons pris en charge.
+pub const MIN_SUBS: usize = 2;
+pub const MAX_SUBS: usize = 4;
+
+/// Places mesurées nécessaires à l'optimisation.
+pub const MIN_SEATS: usize = 2;
+
+/// Plage et résolution de l'analyse (Hz, points par octave).
+const F_MIN_HZ: f32 = 20.0;
+const F_MAX_HZ: f32 = 120.0;
+const POINTS_PER_OCTAVE: f32 = 12.0;
+
+/// Durée de l'IR retenue après l'arrivée dans la boucle (s), fin en
+/// demi-Hann : assez pour les modes de la pièce à 20 Hz.
+const WINDOW_SECS: f32 = 0.5;
+
+/// Grille de recherche : atténuation (dB), retard relatif (ms).
+const MAX_ATTENUATION_DB: f32 = 6.0;
+const GAIN_STEP_DB: f32 = 1.0;
+const MAX_DELAY_MS: f32 = 10.0;
+const DELAY_STEP_MS: f32 = 0.25;
+
+/// Passes de la descente par coordonnées.
+const PASSES: usize = 4;
+
+/// Poids de la baisse du niveau moyen (dB) dans le critère.
+const LEVEL_WEIGHT: f32 = 0.5;
+
+/// Réponses d'une place d'écoute, une par caisson (aux `frequencies()`).
+#[derive(Debug, Clone)]
+pub struct SeatResponses {
+    pub label: String,
+    pub responses: Vec<Vec<Complex<f32>>>,
+}
+
+/// Réglage d'un caisson.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SubSetting {
+    pub gain_db: f32,
+    pub delay_ms: f32,
+    pub inverted: bool,
+}
+
+/// Meilleur réglage trouvé et son effet.
+#[derive(Debug, Clone)]
+pub struct MultiSubPlan {
+    pub settings: Vec<SubSetting>,
+    /// Écart moyen entre places, tous caissons à zéro puis réglés (dB).
+    pub spread_before_db: f32,
+    pub spread_after_db: f32,
+    /// Niveau moyen réglé − niveau moyen à zéro (dB).
+    pub level_change_db: f32,
+}
+
+/// Session multi-caissons : sorties, places mesurées, réglage proposé.
+#[derive(Debug, Clone, Default)]
+pub struct MultiSub {
+    pub outputs: Vec<Channel>,
+    pub seats: Vec<SeatResponses>,
+    /// Réponses de la place en cours de mesure.
+    pub pending: Vec<Vec<Complex<f32>>>,
+    pub plan: Option<MultiSubPlan>,
+}
+
+impl MultiSub {
+    pub fn new(outputs: Vec<Channel>) -> Self {
+        MultiSub { outputs, ..Default::default() }
+    }
+
+    /// Range la réponse du caisson suivant ; vrai quand la place est complète
+    /// (elle rejoint alors `seats` et le réglage est recalculé).
+    pub fn record(&mut self, response: Vec<Complex<f32>>) -> bool {
+        self.pending.push(response);
+        if self.pending.len() < self.outputs.len() {
+            return false;
+        }
+        let label = format!("{}", self.seats.len() + 1);
+        self.seats.push(SeatResponses { label, responses: std::mem::take(&mut self.pending) });
+        self.plan = optimize(&self.seats);
+        true
+    }
+
+    /// Oublie la place en cours, sinon la dernière place complète, pour la
+    /// reprendre ; vrai si une place complète a été retirée.
+    pub fn drop_last_seat(&mut self) -> bool {
+        if !self.pending.is_empty() {
+            self.pending.clear();
+            return false;
+        }
+        let dropped = self.seats.pop().is_some();
+        self.plan = optimize(&self.seats);
+        dropped
+    }
+}
+
+/// Fréquences analysées, au 1/12 d'octave de `F_MIN_HZ` à `F_MAX_HZ`.
+pub fn frequencies() -> Vec<f32> {
+    let count = ((F_MAX_HZ / F_MIN_HZ).log2() * POINTS_PER_OCTAVE) as usize + 1;
+    (0..count).map(|k| F_MIN_HZ * 2f32.powf(k as f32 / POINTS_PER_OCTAVE)).collect()
+}
+
+/// Réponse complexe de l'IR aux `frequencies()`, temps compté depuis
+/// `origin` (échantillons, sub-sample) : l'arrivée dans la boucle de
+/// référence (`Measurement::loopback_origin`).
+pub fn response(ir: &[f32], sample_rate: u32, origin: f32) -> Vec<Complex<f32>> {
+    let start = origin.max(0.0) as usize;
+    let window = ir.get(start..).unwrap_or_default();
+    let len = window.len().min((WINDOW_SECS * sample_rate as f32) as usize);
+    let rate = sample_rate as f32;
+    // Partie fractionnaire de l'origine, rendue par la phase
+    let offset = start as f32 - origin;
+    frequencies()
+        .into_iter()
+        .map(|freq| {
+            let mut sum = Complex::new(0.0f32, 0.0);
+            for (n, &v) in window[..len].iter().enumerate() {
+                let w = 0.5 + 0.5 * (PI * n as f32 / len as f32).cos();
+                let phase = -2.0 * PI * freq * (n as f32 + offset) / rate;
+                sum += Complex::from_polar(v * w, phase);
+            }
+            sum
+        })
+        .collect()
+}
+
+/// Facteur complexe d'un réglage à la fréquence `freq`.
+fn factor(setting: SubSetting, freq: f32) -> Complex<f32> {
+    let gain = 10f32.powf(setting.gain_db / 20.0) * if setting.inverted { -1.0 } else { 1.0 };
+    Complex::from_polar(gain, -2.0 * PI * freq * setting.delay_ms / 1000.0)
+}
+
+/// Écart moyen entre places et niveau moyen (dB) des sommes `sums[place][fréquence]`.
+fn spread_and_level(sums: &[Vec<Complex<f32>>]) -> (f32, f32) {
+    let points = sums.first().map_or(0, Vec::len);
+    let (mut spread, mut level) = (0.0, 0.0);
+    for k in 0..points {
+        let db: Vec<f32> = sums.iter().map(|s| 20.0 * s[k].norm().max(1e-9).log10()).collect();
+        let mean = db.iter().sum::<f32>() / db.len() as f32;
+        let var = db.iter().map(|d| (d - mean).powi(2)).sum::<f32>() / db.len() as f32;
+        spread += var.sqrt();
+        level += mean;
+    }
+    (spread / points.max(1) as f32, level / points.max(1) as f32)
+}
+
+/// Sommes des caissons réglés, sauf `skip`, par place et par fréquence.
+fn sums(seats: &[SeatResponses], settings: &[SubSetting], freqs: &[f32], skip: Option<usize>) -> Vec<Vec<Complex<f32>>> {
+    seats
+        .iter()
+        .map(|seat| {
+            freqs
+                .iter()
+                .enumerate()
+                .map(|(k, &f)| {
+                    seat.responses
+                        .iter()
+                        .zip(settings)
+                        .enumerate()
+                        .filter(|&(j, _)| Some(j) != skip)
+                        .map(|(_, (h, &s))| h[k] * factor(s, f))
+                        .sum()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Réglages possibles d'un caisson non référence.
+fn candidates() -> Vec<SubSetting> {
+    let gains = (MAX_ATTENUATION_DB / GAIN_STEP_DB) as i32;
+    let delays = (MAX_DELAY_MS / DELAY_STEP_MS) as i32;
+    let mut out = Vec::new();
+    for g in 0..=gains {
+        for d in -delays..=delays {
+            for inverted in [false, true] {
+                out.push(SubSetting { gain_db: 0.0 - g as f32 * GAIN_STEP_DB, delay_ms: d as f32 * DELAY_STEP_MS, inverted });
+            }
+        }
+    }
+    out
+}
+
+/// Cherche gain, retard et polarité de chaque caisson (le premier fixe) qui
+/// égalisent le grave entre les places. `None` sans assez de places ou de
+/// caissons.
+pub fn optimize(seats: &[SeatResponses]) -> Option<MultiSubPlan> {
+    let subs = seats.first()?.responses.len();
+    if seats.len() < MIN_SEATS || subs < MIN_SUBS {
+        return None;
+    }
+    let freqs = frequencies();
+    let mut settings = vec![SubSetting::default(); subs];
+    let (spread_before_db, level_before) = spread_and_level(&sums(seats, &settings, &freqs, None));
+    let cost = |(spread, level): (f32, f32)| spread + LEVEL_WEIGHT * (level_before - level).max(0.0);
+
+    let candidates = candidates();
+    for _ in 0..PASSES {
+        let mut changed = false;
+        for i in 1..subs {
+            // Somme des autres caissons, fixe pendant que `i` parcourt sa grille
+            let rest = sums(seats, &settings, &freqs, Some(i));
+            let eval = |s: SubSetting| {
+                let total: Vec<Vec<Complex<f32>>> = rest
+                    .iter()
+                    .zip(seats)
+                    .map(|(r, seat)| {
+                        r.iter().zip(&freqs).enumerate().map(|(k, (&o, &f))| o + seat.responses[i][k] * factor(s, f)).collect()
+                    })
+                    .collect();
+                cost(spread_and_level(&total))
+            };
+            let Some((best_cost, best)) = candidates.iter().map(|&s| (eval(s), s)).min_by(|a, b| a.0.total_cmp(&b.0)) else {
+                continue;
+            };
+            if best_cost < eval(settings[i]) - 1e-4 {
+                settings[i] = best;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let (spread_after_db, level_after) = spread_and_level(&sums(seats, &settings, &freqs, None));
+    // Retards ramenés au plus petit : aucun retard négatif à saisir
+    let earliest = settings.iter().map(|s| s.delay_ms).fold(f32::INFINITY, f32::min);
+    for s in &mut settings {
+        s.delay_ms -= earliest;
+    }
+    Some(MultiSubPlan { settings, spread_before_db, spread_after_db, level_change_db: level_after - level_before })
+}
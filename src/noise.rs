@@ -0,0 +1,78 @@
+// ============================================================
+//  noise.rs — Bruit de fond de la pièce
+//
+//  Quelques secondes captées sans signal de test donnent le
+//  spectre du bruit ambiant (ventilation, trafic, ordinateur),
+//  traité exactement comme une mesure : passe-haut, FFT moyennée,
+//  bandes, calibration micro. Bande par bande, l'écart entre une
+//  mesure et ce bruit (SNR) dit si la courbe affichée est celle de
+//  l'enceinte ou celle de la pièce.
+// ============================================================
+
+use crate::{
+    calibration::MicCalibration,
+    dsp::{self, Window, NUM_BANDS},
+    measurement::HIGHPASS_HZ,
+    pipeline::AnalysisOptions,
+};
+
+/// Durée de la capture de silence (s).
+pub const NOISE_SECS: f32 = 3.0;
+
+/// Écart minimal mesure / bruit pour qu'une bande soit fiable (dB).
+pub const MIN_BAND_SNR_DB: f32 = 10.0;
+
+#[derive(Debug, Clone)]
+pub struct NoiseFloor {
+    /// Capture filtrée (passe-haut), gardée pour refaire la FFT.
+    pub samples: Vec<f32>,
+    /// Spectre FFT moyenné (amplitudes linéaires), comme `Measurement::spectrum`.
+    pub spectrum: Vec<f32>,
+    /// Bruit en `NUM_BANDS` bandes (dB), comparable à `Measurement::bands_db`.
+    pub bands_db: Vec<f32>,
+    /// Niveau RMS large bande après passe-haut (dBFS). Sans la sensibilité du
+    /// micro, c'est l'estimation de niveau la plus directe disponible.
+    pub broadband_dbfs: f32,
+}
+
+impl NoiseFloor {
+    pub fn from_capture(capture: &[f32], sample_rate: u32, calibration: Option<&MicCalibration>, options: AnalysisOptions) -> Self {
+        let samples = dsp::highpass_filter(capture, HIGHPASS_HZ, sample_rate);
+        let rms = dsp::compute_rms(&samples);
+        let mut noise = NoiseFloor {
+            spectrum: dsp::compute_fft(&samples, options.window),
+            samples,
+            bands_db: Vec::new(),
+            broadband_dbfs: 20.0 * rms.max(1e-10).log10(),
+        };
+        noise.rebuild_bands(sample_rate, calibration, options);
+        noise
+    }
+
+    /// Étape « spectre » : même fenêtre que les mesures comparées.
+    pub fn rebuild_spectrum(&mut self, window: Window) {
+        self.spectrum = dsp::compute_fft(&self.samples, window);
+    }
+
+    /// Étape « bandes » depuis le spectre en cache (même chemin qu'une mesure).
+    pub fn rebuild_bands(&mut self, sample_rate: u32, calibration: Option<&MicCalibration>, options: AnalysisOptions) {
+        let spectrum = dsp::smooth_spectrum(&self.spectrum, options.smoothing);
+        let mut bands = dsp::spectrum_to_bands(&spectrum, sample_rate, NUM_BANDS);
+        if let Some(cal) = calibration {
+            cal.apply(&mut bands);
+        }
+        self.bands_db = dsp::bands_to_db(&bands);
+    }
+
+    /// Indices des bandes où `bands_db` dépasse le bruit de moins de
+    /// `MIN_BAND_SNR_DB`.
+    pub fn low_snr_bands(&self, bands_db: &[f32]) -> Vec<usize> {
+        bands_db
+            .iter()
+            .zip(&self.bands_db)
+            .enumerate()
+            .filter(|(_, (m, n))| *m - *n < MIN_BAND_SNR_DB)
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
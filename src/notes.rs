@@ -0,0 +1,122 @@
+// ============================================================
+//  notes.rs — Notes et métadonnées des mesures
+//
+//  Ce que le micro ne mesure pas : un texte libre, la position du
+//  micro, le modèle des enceintes et la pièce. Chaque analyse de
+//  l'historique garde les siennes (`HistoryEntry::notes`), avec la
+//  session et dans les exports (.frd, diff.csv, rapport HTML,
+//  image PNG, comparaison pour forum).
+//
+//  [F12] ouvre la saisie sur la dernière analyse (ou, avant la
+//  première, sur la prochaine) : Tab / ↑ ↓ changent de champ,
+//  PgPréc / PgSuiv ou un clic sur l'historique passent à une autre
+//  analyse, Entrée valide, Échap annule. Micro, enceintes et pièce
+//  de la dernière analyse sont reportés sur les suivantes jusqu'à
+//  la saisie d'après ; le texte libre ne vaut que pour une analyse.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::strings::tr;
+
+/// Longueur maximale d'un champ.
+pub const MAX_FIELD_LEN: usize = 60;
+
+/// Métadonnées d'une analyse ; champs vides = non renseignés.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MeasurementNotes {
+    pub text: String,
+    pub mic_position: String,
+    pub speakers: String,
+    pub room: String,
+}
+
+/// Champs de la saisie, dans l'ordre de Tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoteField {
+    #[default]
+    Text,
+    MicPosition,
+    Speakers,
+    Room,
+}
+
+impl NoteField {
+    pub const ALL: [NoteField; 4] = [NoteField::Text, NoteField::MicPosition, NoteField::Speakers, NoteField::Room];
+
+    pub fn label(self) -> &'static str {
+        tr().notes_fields[self as usize]
+    }
+
+    pub fn next(self) -> NoteField {
+        NoteField::ALL[(self as usize + 1) % NoteField::ALL.len()]
+    }
+
+    pub fn prev(self) -> NoteField {
+        NoteField::ALL[(self as usize + NoteField::ALL.len() - 1) % NoteField::ALL.len()]
+    }
+}
+
+impl MeasurementNotes {
+    pub fn get(&self, field: NoteField) -> &str {
+        match field {
+            NoteField::Text => &self.text,
+            NoteField::MicPosition => &self.mic_position,
+            NoteField::Speakers => &self.speakers,
+            NoteField::Room => &self.room,
+        }
+    }
+
+    pub fn get_mut(&mut self, field: NoteField) -> &mut String {
+        match field {
+            NoteField::Text => &mut self.text,
+            NoteField::MicPosition => &mut self.mic_position,
+            NoteField::Speakers => &mut self.speakers,
+            NoteField::Room => &mut self.room,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        NoteField::ALL.iter().all(|&f| self.get(f).trim().is_empty())
+    }
+
+    /// Champs renseignés : (champ, valeur sans espaces de bord).
+    pub fn filled(&self) -> Vec<(NoteField, &str)> {
+        NoteField::ALL.iter().map(|&f| (f, self.get(f).trim())).filter(|(_, v)| !v.is_empty()).collect()
+    }
+
+    /// Ce qui passe à l'analyse suivante : tout sauf le texte libre.
+    pub fn carried(&self) -> MeasurementNotes {
+        MeasurementNotes { text: String::new(), ..self.clone() }
+    }
+
+    /// Lignes « Micro : au centre, 1,2 m » (« Mic: … » en anglais) des
+    /// en-têtes d'export.
+    pub fn header_lines(&self) -> Vec<String> {
+        self.filled().into_iter().map(|(f, v)| (tr().label_value)(f.label(), v)).collect()
+    }
+}
+
+/// Saisie [F12] en cours.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NotesEditor {
+    pub notes: MeasurementNotes,
+    pub field: NoteField,
+    /// Analyse annotée (indice de l'historique) ; `None` = la prochaine.
+    pub entry: Option<usize>,
+}
+
+impl NotesEditor {
+    /// Ajoute `c` au champ courant, dans la limite de `MAX_FIELD_LEN`.
+    pub fn push(&mut self, c: char) {
+        let value = self.notes.get_mut(self.field);
+        if value.chars().count() < MAX_FIELD_LEN {
+            value.push(c);
+        }
+    }
+
+    pub fn pop(&mut self) {
+        self.notes.get_mut(self.field).pop();
+    }
+}
@@ -0,0 +1,69 @@
+// ============================================================
+//  nudge.rs — Bips de guidage du suivi en direct
+//
+//  Derrière une enceinte, l'écran est hors de vue : avec le
+//  guidage ([F2]), chaque lecture du suivi [D] est suivie d'une
+//  courte série de bips qui code son écart, comme un radar de
+//  recul :
+//    - cadence : plus l'écart est faible, plus les bips sont
+//      rapprochés ; son continu une fois aligné (`watch::ALIGNED_CM`) ;
+//    - hauteur : aiguë = avancer l'enceinte droite, grave = la
+//      reculer (le sens des conseils du panneau de suivi).
+//  Les bips sont joués dès la lecture connue, dans un passage à
+//  part qui précède la pause du sweep suivant : ils ne tombent
+//  jamais dans la fenêtre de capture, et n'attendent pas la fin
+//  de cette pause.
+// ============================================================
+
+use std::f32::consts::PI;
+
+use crate::watch::{self, WatchReading};
+
+/// Durée de la série de bips (s).
+pub const CLIP_SECS: f32 = 0.6;
+
+/// Silence entre les bips et le sweep (s) : le temps que la pièce se taise.
+const GAP_SECS: f32 = 0.15;
+
+/// Crête des bips, relative à celle du sweep.
+pub const RELATIVE_LEVEL: f32 = 0.5;
+
+/// Hauteurs : avancer, reculer, aligné (Hz).
+const CLOSER_HZ: f32 = 1_320.0;
+const FARTHER_HZ: f32 = 660.0;
+const ALIGNED_HZ: f32 = 880.0;
+
+const BEEP_SECS: f32 = 0.04;
+
+/// Intervalle entre deux bips par cm d'écart, et ses bornes (s).
+const INTERVAL_PER_CM: f32 = 0.04;
+const MIN_INTERVAL: f32 = 0.06;
+const MAX_INTERVAL: f32 = CLIP_SECS;
+
+/// Bips codant `reading` puis silence, joués seuls avant le sweep suivant ;
+/// vide sans lecture (premier passage du suivi).
+pub fn feedback(reading: Option<&WatchReading>, sample_rate: u32, peak: f32) -> Vec<f32> {
+    let Some(reading) = reading else { return Vec::new() };
+    let cm = reading.distance_cm();
+    let rate = sample_rate as f32;
+    let len = (CLIP_SECS * rate) as usize;
+    let mut clip = vec![0.0f32; len + (GAP_SECS * rate) as usize];
+    let (freq, interval) = if cm.abs() < watch::ALIGNED_CM {
+        (ALIGNED_HZ, None)
+    } else {
+        let freq = if cm > 0.0 { CLOSER_HZ } else { FARTHER_HZ };
+        (freq, Some((cm.abs() * INTERVAL_PER_CM).clamp(MIN_INTERVAL, MAX_INTERVAL)))
+    };
+    let beep = interval.map_or(len, |_| (BEEP_SECS * rate) as usize);
+    let period = interval.map_or(len, |i| (i * rate) as usize).max(beep);
+    let fade = (0.005 * rate) as usize;
+    for (i, v) in clip.iter_mut().take(len).enumerate() {
+        let pos = i % period;
+        if pos >= beep {
+            continue;
+        }
+        let env = (pos as f32 / fade as f32).min(1.0) * ((beep - pos) as f32 / fade as f32).min(1.0);
+        *v = peak * env * (2.0 * PI * freq * i as f32 / rate).sin();
+    }
+    clip
+}
@@ -0,0 +1,161 @@
+// ============================================================
+//  overlay.rs — Comparaison de deux analyses de l'historique
+//
+//  Chaque entrée de l'historique garde ses courbes G et D : deux
+//  analyses quelconques (même d'une session rouverte la semaine
+//  suivante) se superposent sur le spectre, A en pâle, B en vif.
+//  Le relevé chiffre ce qui a changé de A à B : score, écarts de
+//  délai et de niveau, symétrie spectrale (|D − G| moyen) et
+//  déplacement de chaque courbe.
+//
+//  [F6] écrit ce relevé en Markdown (`comparaison.md`, en anglais
+//  `comparison.md`) pour un fil d'entraide (forum, Discord) : un
+//  tableau avant / après (notes [F12] comprises), puis l'écart D − G par octave en barres
+//  ASCII dans un bloc de code, lisible tel quel même là où le
+//  Markdown n'est pas rendu. Le relevé est rédigé dans la langue
+//  de l'interface.
+// ============================================================
+
+use std::{fmt::Write as _, ops::Range};
+
+use crate::{app::HistoryEntry, dsp, notes::NoteField, strings::tr};
+
+/// Barres du graphique : dB par caractère, caractères au plus de chaque côté.
+const CHART_DB_PER_CHAR: f32 = 0.5;
+const CHART_HALF_WIDTH: usize = 12;
+
+/// Écarts entre deux analyses, sur les bandes de la plage d'analyse.
+#[derive(Debug, Clone, Copy)]
+pub struct OverlayDiff {
+    pub score_delta: i32,
+    /// Évolution des écarts absolus (négatif = G et D se rapprochent).
+    pub delay_delta_ms: f32,
+    pub level_delta_db: f32,
+    /// |D − G| moyen de A puis de B (dB).
+    pub mismatch_db: (f32, f32),
+    /// |B − A| moyen de la courbe gauche, puis de la droite (dB).
+    pub shift_db: (f32, f32),
+}
+
+fn mean_abs(a: &[f32], b: &[f32], bands: Range<usize>) -> f32 {
+    let n = bands.len().max(1);
+    bands.map(|i| (a[i] - b[i]).abs()).sum::<f32>() / n as f32
+}
+
+impl OverlayDiff {
+    /// `None` si l'une des entrées n'a pas de courbes.
+    pub fn between(a: &HistoryEntry, b: &HistoryEntry, bands: Range<usize>) -> Option<Self> {
+        if !a.has_curves() || !b.has_curves() || a.left_db.len() != b.left_db.len() {
+            return None;
+        }
+        let bands = bands.start.min(a.left_db.len())..bands.end.min(a.left_db.len());
+        Some(OverlayDiff {
+            score_delta: b.score as i32 - a.score as i32,
+            delay_delta_ms: b.delay_ms.abs() - a.delay_ms.abs(),
+            level_delta_db: b.level_diff_db.abs() - a.level_diff_db.abs(),
+            mismatch_db: (
+                mean_abs(&a.right_db, &a.left_db, bands.clone()),
+                mean_abs(&b.right_db, &b.left_db, bands.clone()),
+            ),
+            shift_db: (
+                mean_abs(&b.left_db, &a.left_db, bands.clone()),
+                mean_abs(&b.right_db, &a.right_db, bands),
+            ),
+        })
+    }
+}
+
+/// |D − G| moyen (dB) de l'octave centrée sur `center`, bandes de `bands`
+/// seulement ; `None` si l'octave tombe hors de la plage.
+fn octave_mismatch(entry: &HistoryEntry, center: f32, bands: Range<usize>) -> Option<f32> {
+    let n = entry.left_db.len();
+    let (lo, hi) = (center / 2f32.sqrt(), center * 2f32.sqrt());
+    let inside: Vec<usize> = bands.filter(|&i| (lo..hi).contains(&dsp::band_center_freq(i, n))).collect();
+    if inside.is_empty() {
+        return None;
+    }
+    Some(inside.iter().map(|&i| entry.right_db[i] - entry.left_db[i]).sum::<f32>() / inside.len() as f32)
+}
+
+/// Barre centrée : à gauche si |D − G| diminue de A à B, à droite s'il
+/// augmente.
+fn delta_bar(delta: f32) -> String {
+    let len = ((delta.abs() / CHART_DB_PER_CHAR).round() as usize).min(CHART_HALF_WIDTH);
+    let (left, right) = if delta < 0.0 { (len, 0) } else { (0, len) };
+    format!(
+        "{}{}|{}{}",
+        " ".repeat(CHART_HALF_WIDTH - left),
+        "#".repeat(left),
+        "#".repeat(right),
+        " ".repeat(CHART_HALF_WIDTH - right)
+    )
+}
+
+/// Relevé A → B en Markdown, à coller sur un forum. `ia` / `ib` : rangs
+/// dans l'historique (affichés à partir de 1). `None` si l'une des entrées
+/// n'a pas de courbes.
+pub fn forum_markdown(a: &HistoryEntry, ia: usize, b: &HistoryEntry, ib: usize, bands: Range<usize>) -> Option<String> {
+    let s = tr();
+    let d = OverlayDiff::between(a, b, bands.clone())?;
+    let bands = bands.start.min(a.left_db.len())..bands.end.min(a.left_db.len());
+    let mut out = String::new();
+    let _ = writeln!(out, "### Speaker Align — A #{} ({}) → B #{} ({})\n", ia + 1, a.time, ib + 1, b.time);
+    out.push_str("| | A | B | Δ |\n|---|---:|---:|---:|\n");
+    let _ = writeln!(out, "| Score | {} | {} | {:+} |", a.score, b.score, d.score_delta);
+    let _ = writeln!(
+        out,
+        "| {} | {:.2} | {:.2} | {:+.2} |",
+        s.forum_delay,
+        a.delay_ms.abs(),
+        b.delay_ms.abs(),
+        d.delay_delta_ms
+    );
+    let _ = writeln!(
+        out,
+        "| {} | {:.1} | {:.1} | {:+.1} |",
+        s.forum_level,
+        a.level_diff_db.abs(),
+        b.level_diff_db.abs(),
+        d.level_delta_db
+    );
+    let _ = writeln!(
+        out,
+        "| {} | {:.1} | {:.1} | {:+.1} |",
+        s.forum_mismatch,
+        d.mismatch_db.0,
+        d.mismatch_db.1,
+        d.mismatch_db.1 - d.mismatch_db.0
+    );
+    // Notes de l'une ou l'autre analyse ([F12]), côte à côte
+    for field in NoteField::ALL {
+        let (na, nb) = (a.notes.get(field).trim(), b.notes.get(field).trim());
+        if !na.is_empty() || !nb.is_empty() {
+            let cell = |text: &str| text.replace('|', "\\|");
+            let _ = writeln!(out, "| {} | {} | {} | |", field.label(), cell(na), cell(nb));
+        }
+    }
+    let _ = writeln!(out, "\n{}\n", (s.forum_shift)(d.shift_db.0, d.shift_db.1));
+
+    // Écart D − G par octave ; barre = évolution de |D − G|
+    out.push_str("```text\n");
+    let column = |run: &str| format!("{}-{} {}", s.right_short, s.left_short, run);
+    let _ = writeln!(
+        out,
+        "{:>6}  {:>7}  {:>7}  {:>w$}|{}",
+        "Hz",
+        column("A"),
+        column("B"),
+        s.forum_better,
+        s.forum_worse,
+        w = CHART_HALF_WIDTH
+    );
+    for &center in &dsp::OCTAVE_CENTERS {
+        let (Some(ma), Some(mb)) = (octave_mismatch(a, center, bands.clone()), octave_mismatch(b, center, bands.clone())) else {
+            continue;
+        };
+        let delta = mb.abs() - ma.abs();
+        let _ = writeln!(out, "{:>6.0}  {:>+7.1}  {:>+7.1}  {} {:+.1}", center, ma, mb, delta_bar(delta), delta);
+    }
+    let _ = writeln!(out, "```\n{}", (s.forum_legend)(CHART_DB_PER_CHAR));
+    Some(out)
+}
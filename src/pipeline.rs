@@ -0,0 +1,120 @@
+// ============================================================
+//  pipeline.rs — Étapes de l'analyse et intermédiaires en cache
+//
+//    capture ─► spectre (FFT fenêtrée)   ┐ coûteux : calculés une
+//            └► IR (déconvolution)       ┘ fois par capture et
+//                                          gardés dans `Measurement`
+//    spectre ─► bandes (fenêtre temporelle de l'IR, lissage,
+//               calibration micro…)
+//    IR ─► front et distance (seuil du front)
+//    bandes + IR ─► comparaison (délai, niveau, inclinaison et
+//                   score dans la plage d'analyse)
+//
+//  Changer une option d'analyse ne relance que les étapes en aval
+//  (`AppState::recompute`) : la bascule reste instantanée.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+use crate::dsp::{self, Window, NUM_BANDS};
+
+/// Étapes relançables depuis le cache, dans l'ordre du pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    /// Captures en mémoire → spectre (changement de fenêtre), puis bandes.
+    Spectrum,
+    /// Spectre en cache → bandes (puis comparaison).
+    Bands,
+    /// Bandes et IR en cache → délai, niveau, score.
+    Compare,
+}
+
+/// Plages d'analyse proposées par [M] (Hz) : tout le spectre, au-dessus
+/// des modes de la pièce, zone médium-aigu.
+pub const RANGE_PRESETS: [(f32, f32); 3] = [(20.0, 20_000.0), (200.0, 20_000.0), (500.0, 10_000.0)];
+
+/// Lissages proposés par [~] : 1/N d'octave, 0 = aucun (128 bandes brutes).
+pub const SMOOTHING_PRESETS: [u32; 6] = [0, 24, 12, 6, 3, 1];
+
+/// Seuils du front de l'IR proposés par [F4] (dB au-dessus du bruit de
+/// fond) : plus bas, un son direct faible est trouvé ; plus haut, le bruit
+/// d'une pièce agitée n'est pas pris pour un front.
+pub const ONSET_PRESETS_DB: [f32; 3] = [dsp::ONSET_THRESHOLD_DB, 12.0, 30.0];
+
+/// Fenêtres temporelles de l'IR proposées par [9] (ms, 0 = réponse
+/// complète) : plus courte, plus de réflexions écartées, mais raccord plus
+/// haut en fréquence (`dsp::gate_low_hz`).
+pub const GATE_PRESETS_MS: [f32; 4] = [0.0, 3.0, 5.0, 10.0];
+
+/// Options appliquées après les étapes coûteuses.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisOptions {
+    /// Bandes prises en compte par le score et l'inclinaison (Hz).
+    pub range_hz: (f32, f32),
+    /// Lissage des spectres en 1/N d'octave (0 = aucun), appliqué avant les
+    /// bandes : courbes tracées, différence et score en tiennent compte.
+    #[serde(default)]
+    pub smoothing: u32,
+    /// Fenêtre de la FFT des mesures et du bruit de fond.
+    #[serde(default)]
+    pub window: Window,
+    /// Seuil du front de l'IR (`dsp::ir_onset`), donc des distances.
+    #[serde(default = "default_onset_db")]
+    pub onset_db: f32,
+    /// Fenêtre temporelle de l'IR avant la FFT (ms, 0 = aucune) : au-dessus
+    /// de `dsp::gate_low_hz`, les courbes ne gardent que le son direct.
+    #[serde(default)]
+    pub gate_ms: f32,
+}
+
+fn default_onset_db() -> f32 {
+    dsp::ONSET_THRESHOLD_DB
+}
+
+impl Default for AnalysisOptions {
+    fn default() -> Self {
+        AnalysisOptions { range_hz: RANGE_PRESETS[0], smoothing: 0, window: Window::Hann, onset_db: dsp::ONSET_THRESHOLD_DB, gate_ms: 0.0 }
+    }
+}
+
+impl AnalysisOptions {
+    /// Plage suivante de `RANGE_PRESETS`.
+    pub fn next_range(&self) -> Self {
+        let i = RANGE_PRESETS.iter().position(|&r| r == self.range_hz).map_or(0, |i| i + 1);
+        AnalysisOptions { range_hz: RANGE_PRESETS[i % RANGE_PRESETS.len()], ..*self }
+    }
+
+    /// Lissage suivant de `SMOOTHING_PRESETS`.
+    pub fn next_smoothing(&self) -> Self {
+        let i = SMOOTHING_PRESETS.iter().position(|&n| n == self.smoothing).map_or(0, |i| i + 1);
+        AnalysisOptions { smoothing: SMOOTHING_PRESETS[i % SMOOTHING_PRESETS.len()], ..*self }
+    }
+
+    /// Fenêtre suivante de `Window::ALL`.
+    pub fn next_window(&self) -> Self {
+        AnalysisOptions { window: self.window.next(), ..*self }
+    }
+
+    /// Seuil du front suivant de `ONSET_PRESETS_DB`.
+    pub fn next_onset(&self) -> Self {
+        let i = ONSET_PRESETS_DB.iter().position(|&db| db == self.onset_db).map_or(0, |i| i + 1);
+        AnalysisOptions { onset_db: ONSET_PRESETS_DB[i % ONSET_PRESETS_DB.len()], ..*self }
+    }
+
+    /// Fenêtre temporelle suivante de `GATE_PRESETS_MS`.
+    pub fn next_gate(&self) -> Self {
+        let i = GATE_PRESETS_MS.iter().position(|&ms| ms == self.gate_ms).map_or(0, |i| i + 1);
+        AnalysisOptions { gate_ms: GATE_PRESETS_MS[i % GATE_PRESETS_MS.len()], ..*self }
+    }
+
+    /// Indices des bandes dont le centre tombe dans la plage (deux au moins,
+    /// pour que l'inclinaison garde un sens).
+    pub fn bands(&self) -> Range<usize> {
+        let (lo, hi) = self.range_hz;
+        let inside = |i: usize| (lo..=hi).contains(&dsp::band_center_freq(i, NUM_BANDS));
+        let start = (0..NUM_BANDS).find(|&i| inside(i)).unwrap_or(0).min(NUM_BANDS - 2);
+        let end = (0..NUM_BANDS).rev().find(|&i| inside(i)).map_or(NUM_BANDS, |i| i + 1).max(start + 2);
+        start..end
+    }
+}
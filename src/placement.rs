@@ -0,0 +1,148 @@
+// ============================================================
+//  placement.rs — Contraintes de placement et remède au retard
+//
+//  « Rapprocher l'enceinte droite de 12 cm » ne sert à rien si
+//  elle est collée au meuble TV. La section `[placement]` déclare
+//  ce que chaque enceinte peut faire vers l'auditeur :
+//    free    — avancer ou reculer ;
+//    forward — seulement avancer (mur derrière) ;
+//    back    — seulement reculer (meuble devant) ;
+//    fixed   — ni l'un ni l'autre : décalage latéral et pivot
+//              (toe-in) seulement, sans effet sur le retard ;
+//  et si l'ampli A/V ou le DSP offre un délai par canal. L'écart
+//  de distance est alors corrigé, dans cet ordre de préférence :
+//    1. en déplaçant l'enceinte droite (conseil historique) ;
+//    2. en déplaçant l'enceinte gauche dans l'autre sens ;
+//    3. en retardant électriquement l'enceinte la plus proche, le
+//       niveau restant au trim (`gain`).
+//  Déplacer une enceinte change aussi son niveau (1/r) : avec des
+//  distances absolues (boucle de référence), l'écart de niveau
+//  attendu après le déplacement est estimé.
+//
+//  Les distances au mètre ruban (`left_m`, `right_m`), facultatives,
+//  servent de garde-fou : une capture droite faite sous [L] inverse
+//  tous les signes, et les conseils enverraient dans le mauvais sens.
+//  Un retard de signe contraire aux distances déclarées (ou, avec la
+//  boucle, des distances mesurées qui collent mieux une fois
+//  échangées) fait suspecter l'inversion.
+//
+//    [placement]
+//    left  = "free"
+//    right = "back"        # ne peut pas avancer
+//    channel_delay = true  # délai / distance réglable par canal
+//    left_m  = 2.45        # enceinte → place d'écoute (m)
+//    right_m = 2.80
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::dsp;
+
+/// Vitesse du son (cm/ms), comme dans les conseils de distance.
+pub fn sound_cm_per_ms() -> f32 {
+    dsp::speed_of_sound() / 10.0
+}
+
+/// Retard en deçà duquel son signe ne prouve rien (ms, ≈ 10 cm).
+pub const SWAP_MIN_MS: f32 = 0.3;
+
+/// Avance exigée de l'appariement échangé sur l'appariement direct (m).
+const SWAP_MARGIN_M: f32 = 0.05;
+
+/// Déplacements possibles d'une enceinte vers ou loin de l'auditeur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mobility {
+    #[default]
+    Free,
+    Forward,
+    Back,
+    Fixed,
+}
+
+impl Mobility {
+    /// Déplacement autorisé : vers l'auditeur (`closer`) ou en s'éloignant.
+    pub fn allows(self, closer: bool) -> bool {
+        match self {
+            Mobility::Free => true,
+            Mobility::Forward => closer,
+            Mobility::Back => !closer,
+            Mobility::Fixed => false,
+        }
+    }
+}
+
+/// Section `[placement]` du fichier de configuration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlacementParams {
+    pub left: Mobility,
+    pub right: Mobility,
+    /// L'ampli / le DSP règle le délai (ou la distance) de chaque canal.
+    pub channel_delay: bool,
+    /// Distances déclarées enceinte → place d'écoute (m, mètre ruban).
+    pub left_m: Option<f32>,
+    pub right_m: Option<f32>,
+}
+
+/// Remède retenu pour l'écart de distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DistanceFix {
+    /// Déplacer une enceinte (`right` faux = la gauche) de `cm` vers
+    /// l'auditeur (`closer`) ou en l'éloignant ; `level_after_db` = écart
+    /// D − G attendu ensuite, si les distances sont absolues.
+    Move { right: bool, closer: bool, cm: f32, level_after_db: Option<f32> },
+    /// Retarder électriquement l'enceinte la plus proche de `ms`.
+    Delay { right: bool, ms: f32 },
+    /// Aucun remède permis par les contraintes.
+    Blocked,
+}
+
+impl PlacementParams {
+    /// Vrai si les captures G / D semblent inversées au vu des distances
+    /// déclarées : retard D − G `delay_ms` de signe contraire, ou distances
+    /// absolues `measured_m` (G, D) plus proches une fois échangées.
+    pub fn swap_suspected(&self, delay_ms: f32, measured_m: Option<(f32, f32)>) -> bool {
+        let (Some(left), Some(right)) = (self.left_m, self.right_m) else {
+            return false;
+        };
+        if let Some((ml, mr)) = measured_m {
+            let straight = (ml - left).abs() + (mr - right).abs();
+            let swapped = (ml - right).abs() + (mr - left).abs();
+            return swapped + SWAP_MARGIN_M < straight;
+        }
+        let expected_ms = (right - left) * 100.0 / sound_cm_per_ms();
+        expected_ms.abs() >= SWAP_MIN_MS && delay_ms.abs() >= SWAP_MIN_MS && expected_ms.signum() != delay_ms.signum()
+    }
+
+    /// Remède à un retard D − G `delay_ms` (> 0 : la droite est plus loin).
+    /// `distances_m` (G, D) absolues permettent d'estimer le niveau après
+    /// un déplacement.
+    pub fn solve(&self, delay_ms: f32, level_diff_db: f32, distances_m: Option<(f32, f32)>) -> DistanceFix {
+        let cm = delay_ms.abs() * sound_cm_per_ms();
+        let right_farther = delay_ms > 0.0;
+        // Niveau d'une enceinte déplacée de `from` à `to` mètres : +20·log(from / to)
+        let level_after = |right: bool, closer: bool| {
+            distances_m.and_then(|(l, r)| {
+                let from = if right { r } else { l };
+                let to = if closer { from - cm / 100.0 } else { from + cm / 100.0 };
+                (to > 0.0).then(|| {
+                    let change = 20.0 * (from / to).log10();
+                    if right { level_diff_db + change } else { level_diff_db - change }
+                })
+            })
+        };
+
+        // La droite rattrape la gauche, sinon la gauche rattrape la droite
+        for (right, closer, mobility) in [(true, right_farther, self.right), (false, !right_farther, self.left)] {
+            if mobility.allows(closer) {
+                return DistanceFix::Move { right, closer, cm, level_after_db: level_after(right, closer) };
+            }
+        }
+        if self.channel_delay {
+            // L'enceinte la plus proche attend l'autre
+            return DistanceFix::Delay { right: !right_farther, ms: delay_ms.abs() };
+        }
+        DistanceFix::Blocked
+    }
+}
@@ -0,0 +1,161 @@
+// ============================================================
+//  polarity.rs — Polarité par haut-parleur (clics filtrés en bande)
+//
+//  Un test de polarité large bande est dominé par le woofer : un
+//  tweeter câblé à l'envers à l'intérieur d'une enceinte passe
+//  inaperçu. On joue donc des clics à phase nulle limités à la bande
+//  de chaque haut-parleur, puis on lit le signe du pic de corrélation
+//  capture / clic dans chaque bande.
+//
+//  Le signe absolu dépend du filtre de l'enceinte (un filtre du 2e
+//  ordre inverse volontairement le tweeter) : ce qui compte est que
+//  GAUCHE et DROITE soient identiques, bande par bande.
+//
+//  Une enceinte entière câblée à l'envers (+ et − croisés) se lit
+//  sans test dédié : le sweep de chaque capture donne la réponse
+//  impulsionnelle, dont le pic principal change de signe. Le signe
+//  est vérifié à chaque analyse ([A]).
+// ============================================================
+
+use crate::dsp;
+
+/// Bandes testées (nom, bas, haut) en Hz, loin de la coupure du filtre.
+pub const DRIVER_BANDS: [(&str, f32, f32); 2] = [
+    ("Woofer", 200.0, 1_000.0),
+    ("Tweeter", 5_000.0, 12_000.0),
+];
+
+/// Durée d'un clic, nombre de répétitions par bande et espacement.
+pub const CLICK_SECS: f32 = 0.020;
+pub const CLICKS_PER_BAND: usize = 4;
+pub const CLICK_SPACING_SECS: f32 = 0.300;
+
+/// En dessous de cette marge entre pic positif et négatif, le signe est douteux.
+pub const MIN_CONFIDENCE: f32 = 0.2;
+
+/// Polarité mesurée dans une bande.
+#[derive(Debug, Clone, Copy)]
+pub struct BandPolarity {
+    pub driver: &'static str,
+    /// Vrai si le pic de corrélation est positif (polarité normale).
+    pub positive: bool,
+    /// Marge relative entre le pic retenu et le plus grand pic de signe opposé (0–1).
+    pub confidence: f32,
+}
+
+impl BandPolarity {
+    pub fn is_reliable(&self) -> bool {
+        self.confidence >= MIN_CONFIDENCE
+    }
+}
+
+/// Résultat du test pour les deux enceintes.
+#[derive(Debug, Clone, Default)]
+pub struct PolarityReport {
+    pub left: Option<Vec<BandPolarity>>,
+    pub right: Option<Vec<BandPolarity>>,
+}
+
+impl PolarityReport {
+    /// Haut-parleurs dont la polarité diffère entre GAUCHE et DROITE
+    /// (mesures fiables uniquement).
+    pub fn mismatched_drivers(&self) -> Vec<&'static str> {
+        let (Some(l), Some(r)) = (&self.left, &self.right) else {
+            return Vec::new();
+        };
+        l.iter()
+            .zip(r.iter())
+            .filter(|(a, b)| a.is_reliable() && b.is_reliable() && a.positive != b.positive)
+            .map(|(a, _)| a.driver)
+            .collect()
+    }
+}
+
+/// Début de chaque clic dans la séquence, par bande, en échantillons.
+fn click_starts(sample_rate: u32) -> Vec<Vec<usize>> {
+    let spacing = (CLICK_SPACING_SECS * sample_rate as f32) as usize;
+    (0..DRIVER_BANDS.len())
+        .map(|b| (0..CLICKS_PER_BAND).map(|k| (b * CLICKS_PER_BAND + k) * spacing).collect())
+        .collect()
+}
+
+/// Durée totale de la séquence de test, en secondes.
+pub fn sequence_secs() -> f32 {
+    (DRIVER_BANDS.len() * CLICKS_PER_BAND) as f32 * CLICK_SPACING_SECS
+}
+
+/// Séquence de test : `CLICKS_PER_BAND` clics par bande, bande après bande.
+pub fn generate_test_signal(sample_rate: u32) -> Vec<f32> {
+    let len = (sequence_secs() * sample_rate as f32) as usize;
+    let mut out = vec![0.0f32; len];
+    for (starts, &(_, lo, hi)) in click_starts(sample_rate).iter().zip(DRIVER_BANDS.iter()) {
+        let click = dsp::generate_band_click(sample_rate, CLICK_SECS, lo, hi);
+        for &start in starts {
+            for (o, c) in out[start..].iter_mut().zip(click.iter()) {
+                *o += c;
+            }
+        }
+    }
+    out
+}
+
+/// Polarité de chaque bande à partir de la capture de `signal`.
+pub fn analyze(capture: &[f32], signal: &[f32], sample_rate: u32) -> Option<Vec<BandPolarity>> {
+    let click_len = (CLICK_SECS * sample_rate as f32) as usize;
+
+    click_starts(sample_rate)
+        .iter()
+        .zip(DRIVER_BANDS.iter())
+        .map(|(starts, &(driver, _, _))| {
+            // Référence ne contenant que les clics de cette bande
+            let mut reference = vec![0.0f32; signal.len()];
+            for &start in starts {
+                let end = (start + click_len).min(signal.len());
+                reference[start..end].copy_from_slice(&signal[start..end]);
+            }
+            let (_, peak, opposite) = dsp::xcorr_signed_peak(&reference, capture, capture.len())?;
+            if peak == 0.0 {
+                return None;
+            }
+            Some(BandPolarity {
+                driver,
+                positive: peak > 0.0,
+                confidence: 1.0 - opposite / peak.abs(),
+            })
+        })
+        .collect()
+}
+
+// ─── Polarité d'une enceinte entière (pic principal de l'IR) ─────────────────
+
+/// Demi-fenêtre autour du pic principal où chercher le pic de signe opposé (ms).
+const IR_PEAK_WINDOW_MS: f32 = 0.5;
+
+/// Signe du pic principal de la réponse impulsionnelle d'une enceinte.
+#[derive(Debug, Clone, Copy)]
+pub struct SpeakerPolarity {
+    /// Vrai si le pic principal est positif (polarité normale).
+    pub positive: bool,
+    /// Marge relative entre le pic principal et le plus grand pic de signe
+    /// opposé à moins de `IR_PEAK_WINDOW_MS` (0–1).
+    pub confidence: f32,
+}
+
+impl SpeakerPolarity {
+    pub fn from_ir(ir: &[f32], sample_rate: u32) -> Option<Self> {
+        let (idx, &peak) = ir.iter().enumerate().max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))?;
+        if peak == 0.0 {
+            return None;
+        }
+        let half = (IR_PEAK_WINDOW_MS / 1000.0 * sample_rate as f32) as usize;
+        let opposite = ir[idx.saturating_sub(half)..(idx + half + 1).min(ir.len())]
+            .iter()
+            .filter(|v| v.signum() != peak.signum())
+            .fold(0.0f32, |m, v| m.max(v.abs()));
+        Some(SpeakerPolarity { positive: peak > 0.0, confidence: 1.0 - opposite / peak.abs() })
+    }
+
+    pub fn is_reliable(&self) -> bool {
+        self.confidence >= MIN_CONFIDENCE
+    }
+}
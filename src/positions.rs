@@ -0,0 +1,106 @@
+// ============================================================
+//  positions.rs — Comparaison de positions candidates
+//
+//  Chercher le bon emplacement des enceintes se fait par essais :
+//  on les pose, on mesure, on déplace, on remesure. Chaque
+//  analyse peut être marquée ([#]) comme position candidate ;
+//  les positions sont classées par une note composite :
+//    - score G/D (symétrie, comme le panneau principal) ;
+//    - régularité du grave (écart-type 40–250 Hz, modes de pièce) ;
+//    - réflexion précoce la plus forte (1–20 ms après le direct).
+//  Une position symétrique mais collée au mur perd ainsi face à
+//  une position un peu moins symétrique mais plus dégagée.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::{dsp, measurement::Measurement};
+
+/// Plage du grave évaluée (Hz).
+const BASS_RANGE_HZ: (f32, f32) = (40.0, 250.0);
+/// Écart-type du grave noté 0 (dB) ; 0 dB est noté 100.
+const BASS_SD_WORST_DB: f32 = 8.0;
+/// Fenêtre des réflexions précoces après le pic du son direct (ms).
+const REFLECTION_WINDOW_MS: (f32, f32) = (1.0, 20.0);
+/// Réflexion notée 100 à ce niveau sous le direct (dB), 0 au niveau du direct.
+const REFLECTION_BEST_DB: f32 = -20.0;
+
+/// Poids de la note composite : score, grave, réflexions.
+const WEIGHTS: (f32, f32, f32) = (0.5, 0.3, 0.2);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandidatePosition {
+    /// « P1 », « P2 »… dans l'ordre de marquage.
+    pub label: String,
+    pub time: String,
+    pub score: u32,
+    /// Écart-type du grave, moyenne G/D (dB).
+    pub bass_sd_db: f32,
+    /// Plus forte réflexion précoce des deux enceintes, relative au direct (dB).
+    pub reflection_db: Option<f32>,
+}
+
+impl CandidatePosition {
+    pub fn from_measurements(label: String, time: String, score: u32, left: &Measurement, right: &Measurement) -> Self {
+        let reflection_db = [left, right]
+            .iter()
+            .filter_map(|m| m.ir.as_ref().and_then(|ir| strongest_reflection_db(ir, m.sample_rate)))
+            .reduce(f32::max);
+        CandidatePosition {
+            label,
+            time,
+            score,
+            bass_sd_db: (bass_sd_db(&left.bands_db) + bass_sd_db(&right.bands_db)) / 2.0,
+            reflection_db,
+        }
+    }
+
+    /// Note composite 0–100 (plus haut = meilleure position).
+    pub fn composite(&self) -> f32 {
+        let bass = 100.0 * (1.0 - self.bass_sd_db / BASS_SD_WORST_DB).clamp(0.0, 1.0);
+        // Sans IR exploitable, la réflexion ne départage pas : note neutre
+        let reflection = self
+            .reflection_db
+            .map_or(50.0, |db| 100.0 * (db / REFLECTION_BEST_DB).clamp(0.0, 1.0));
+        let (ws, wb, wr) = WEIGHTS;
+        ws * self.score as f32 + wb * bass + wr * reflection
+    }
+}
+
+/// Écart-type des bandes (dB) dans `BASS_RANGE_HZ` : 0 = grave sans bosse ni creux.
+pub fn bass_sd_db(bands_db: &[f32]) -> f32 {
+    let n = bands_db.len();
+    let bass: Vec<f32> = (0..n)
+        .filter(|&i| {
+            let f = dsp::band_center_freq(i, n);
+            f >= BASS_RANGE_HZ.0 && f <= BASS_RANGE_HZ.1
+        })
+        .map(|i| bands_db[i])
+        .collect();
+    if bass.len() < 2 {
+        return 0.0;
+    }
+    let mean = bass.iter().sum::<f32>() / bass.len() as f32;
+    (bass.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / bass.len() as f32).sqrt()
+}
+
+/// Pic le plus fort de l'IR entre 1 et 20 ms après le son direct, relatif à
+/// lui (dB, ≤ 0).
+pub fn strongest_reflection_db(ir: &[f32], sample_rate: u32) -> Option<f32> {
+    let (peak_idx, peak) = ir.iter().enumerate().max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))?;
+    let ms = |t: f32| (t / 1000.0 * sample_rate as f32) as usize;
+    let from = peak_idx + ms(REFLECTION_WINDOW_MS.0);
+    let to = (peak_idx + ms(REFLECTION_WINDOW_MS.1)).min(ir.len());
+    if from >= to || peak.abs() <= 0.0 {
+        return None;
+    }
+    let reflection = ir[from..to].iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    Some(20.0 * (reflection / peak.abs()).max(1e-6).log10())
+}
+
+/// Indices de `positions` du meilleur au moins bon.
+pub fn ranking(positions: &[CandidatePosition]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..positions.len()).collect();
+    order.sort_by(|&a, &b| positions[b].composite().total_cmp(&positions[a].composite()));
+    order
+}
@@ -0,0 +1,168 @@
+// ============================================================
+//  quickcheck.rs — Vérification rapide avant mesure
+//
+//  Une seconde de stimulus sur chaque enceinte pour s'assurer
+//  que les graves ET les aigus arrivent bien du canal choisi
+//  avant de lancer un sweep complet : câble débranché, tweeter
+//  muet, caisson qui reprend les graves, mauvais périphérique…
+//
+//    - Bi-ton 60 Hz + 7 kHz : énergie à chaque fréquence pendant
+//      le stimulus, comparée au bruit ambiant à la même fréquence
+//      (fin de capture, stimulus éteint).
+//    - Bruit blanc : même comparaison sur les octaves 63 Hz,
+//      1 kHz et 8 kHz.
+// ============================================================
+
+use std::f32::consts::PI;
+
+use crate::{
+    dsp::{self, FFT_SIZE, OCTAVE_CENTERS},
+    strings::tr,
+};
+
+/// Durée du stimulus (s).
+pub const CHECK_SECS: f32 = 1.0;
+
+/// Fréquences du bi-ton (Hz) : graves sous la coupure d'une enceinte
+/// bibliothèque typique, aigus confiés au tweeter.
+pub const TONE_LO_HZ: f32 = 60.0;
+pub const TONE_HI_HZ: f32 = 7_000.0;
+
+/// Émergence minimale d'une bande au-dessus du bruit ambiant (dB).
+pub const MIN_SNR_DB: f32 = 10.0;
+
+/// Marge ignorée au début et à la fin du stimulus (s) : fondus, erreur
+/// de recalage sur un signal périodique.
+const EDGE_SECS: f32 = 0.1;
+/// Queue de réverbération ignorée avant la mesure du bruit ambiant (s).
+const DECAY_SECS: f32 = 0.2;
+
+/// Octaves vérifiées avec le bruit blanc (indices de `OCTAVE_CENTERS`).
+const NOISE_OCTAVES: [usize; 3] = [0, 4, 7];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Stimulus {
+    #[default]
+    DualTone,
+    WhiteNoise,
+}
+
+impl Stimulus {
+    pub fn label(self) -> &'static str {
+        match self {
+            Stimulus::DualTone => tr().stim_dual_tone,
+            Stimulus::WhiteNoise => tr().stim_white_noise,
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            Stimulus::DualTone => Stimulus::WhiteNoise,
+            Stimulus::WhiteNoise => Stimulus::DualTone,
+        }
+    }
+
+    pub fn generate(self, sample_rate: u32) -> Vec<f32> {
+        match self {
+            Stimulus::DualTone => dsp::generate_dual_tone(sample_rate, CHECK_SECS, TONE_LO_HZ, TONE_HI_HZ),
+            Stimulus::WhiteNoise => dsp::generate_white_noise(sample_rate, CHECK_SECS),
+        }
+    }
+}
+
+/// Émergence d'une bande pendant le stimulus.
+#[derive(Debug, Clone, Copy)]
+pub struct BandCheck {
+    pub freq_hz: f32,
+    /// Niveau pendant le stimulus moins niveau ambiant, même bande (dB).
+    pub snr_db: f32,
+    pub ok: bool,
+}
+
+/// Résultat pour une enceinte.
+#[derive(Debug, Clone)]
+pub struct ChannelQuickCheck {
+    pub bands: Vec<BandCheck>,
+}
+
+impl ChannelQuickCheck {
+    pub fn passed(&self) -> bool {
+        self.bands.iter().all(|b| b.ok)
+    }
+
+    /// Bandes absentes, pour le message d'erreur.
+    pub fn missing(&self) -> impl Iterator<Item = f32> + '_ {
+        self.bands.iter().filter(|b| !b.ok).map(|b| b.freq_hz)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct QuickCheckReport {
+    pub stimulus: Stimulus,
+    pub left: Option<ChannelQuickCheck>,
+    pub right: Option<ChannelQuickCheck>,
+}
+
+impl QuickCheckReport {
+    /// Vrai quand les deux enceintes ont passé la vérification.
+    pub fn passed(&self) -> bool {
+        [&self.left, &self.right].iter().all(|c| c.as_ref().is_some_and(|c| c.passed()))
+    }
+}
+
+/// Puissance moyenne à `freq` (DFT à une fréquence, fenêtre de Hann).
+fn tone_power(samples: &[f32], freq: f32, sample_rate: u32) -> f32 {
+    let n = samples.len();
+    let w = 2.0 * PI * freq / sample_rate as f32;
+    let (mut re, mut im, mut norm) = (0.0f32, 0.0f32, 0.0f32);
+    for (i, &x) in samples.iter().enumerate() {
+        let hann = 0.5 - 0.5 * (2.0 * PI * i as f32 / n as f32).cos();
+        re += x * hann * (w * i as f32).cos();
+        im -= x * hann * (w * i as f32).sin();
+        norm += hann;
+    }
+    if norm > 0.0 { (re * re + im * im) / (norm * norm) } else { 0.0 }
+}
+
+/// Compare chaque bande du stimulus capté au bruit ambiant qui le suit.
+/// `None` si la capture est trop courte pour isoler les deux fenêtres.
+pub fn analyze(capture: &[f32], signal: &[f32], stimulus: Stimulus, sample_rate: u32) -> Option<ChannelQuickCheck> {
+    let lag = dsp::xcorr_delay(signal, capture, capture.len())?.round() as usize;
+    let secs = |s: f32| (s * sample_rate as f32) as usize;
+
+    let on_from = lag + secs(EDGE_SECS);
+    let on_to = (lag + signal.len()).saturating_sub(secs(EDGE_SECS));
+    let off_from = lag + signal.len() + secs(DECAY_SECS);
+    // Fenêtres de même longueur : le bruit se compare à lui-même
+    let len = on_to.saturating_sub(on_from).min(capture.len().saturating_sub(off_from));
+    if len < FFT_SIZE {
+        return None;
+    }
+    let on = &capture[on_from..on_from + len];
+    let off = &capture[off_from..off_from + len];
+
+    let bands: Vec<(f32, f32)> = match stimulus {
+        Stimulus::DualTone => [TONE_LO_HZ, TONE_HI_HZ]
+            .iter()
+            .map(|&f| {
+                let ratio = tone_power(on, f, sample_rate) / tone_power(off, f, sample_rate).max(1e-20);
+                (f, 10.0 * ratio.max(1e-20).log10())
+            })
+            .collect(),
+        Stimulus::WhiteNoise => {
+            let on_db = dsp::octave_levels_db(on, sample_rate);
+            let off_db = dsp::octave_levels_db(off, sample_rate);
+            NOISE_OCTAVES
+                .iter()
+                .map(|&k| (OCTAVE_CENTERS[k], on_db[k] - off_db[k]))
+                .collect()
+        }
+    };
+
+    Some(ChannelQuickCheck {
+        bands: bands
+            .into_iter()
+            .map(|(freq_hz, snr_db)| BandCheck { freq_hz, snr_db, ok: snr_db >= MIN_SNR_DB })
+            .collect(),
+    })
+}
@@ -0,0 +1,238 @@
+// ============================================================
+//  reflections.rs — Premières réflexions
+//
+//  Les courbes G et D diffèrent souvent parce qu'une enceinte est
+//  plus près d'un mur que l'autre : ses premières réflexions
+//  arrivent plus tôt, ou plus fort, et creusent d'autres peignes
+//  dans sa réponse. Dans l'IR déconvoluée, chaque réflexion est
+//  un pic de l'enveloppe 1 à 20 ms après le son direct ; son
+//  retard donne la différence de trajet (retard × vitesse du son).
+//
+//  La surface probable vient de la géométrie d'une pièce type
+//  (sources images) : tweeter à 1 m et oreilles à 1,1 m du sol,
+//  plafond à 2,5 m, enceinte et auditeur à 1 m des murs latéraux,
+//  enceinte à 0,5 m du mur derrière elle. Deux surfaces aussi
+//  plausibles l'une que l'autre sont nommées toutes les deux :
+//  c'est une piste pour placer un absorbant, pas un relevé.
+//
+//  Les réflexions G et D sont appariées par trajet (à
+//  `PAIR_TOLERANCE_M` près), pas par surface devinée : sol et mur
+//  latéral ne sont qu'à quelques centimètres l'un de l'autre et la
+//  même réflexion peut être nommée différemment de chaque côté.
+//  Une réflexion plus forte de `ASYMMETRY_DB` d'un côté (ou
+//  présente d'un seul côté) devient une recommandation.
+// ============================================================
+
+use serde::Serialize;
+
+use crate::{dsp, strings::tr};
+
+/// Fenêtre de recherche après le pic du son direct (ms).
+const WINDOW_MS: (f32, f32) = (1.0, 20.0);
+/// Niveau minimal d'une réflexion par rapport au son direct (dB).
+pub const FLOOR_DB: f32 = -20.0;
+/// Deux pics plus proches que cela n'en font qu'un (ms).
+const MIN_SPACING_MS: f32 = 0.5;
+/// Réflexions gardées par enceinte, les plus fortes.
+pub const MAX_REFLECTIONS: usize = 3;
+/// Écart G/D d'une même réflexion signalé (dB).
+pub const ASYMMETRY_DB: f32 = 3.0;
+/// Réflexions G et D dont les trajets diffèrent de moins que cela : la
+/// même, venue de la même surface (m).
+const PAIR_TOLERANCE_M: f32 = 0.3;
+
+/// Distance d'écoute supposée sans distance absolue ni `[placement]` (m).
+pub const DEFAULT_LISTENING_M: f32 = 2.5;
+
+/// Géométrie de la pièce type (m).
+const SPEAKER_HEIGHT_M: f32 = 1.0;
+const EAR_HEIGHT_M: f32 = 1.1;
+const CEILING_HEIGHT_M: f32 = 2.5;
+const SIDE_WALL_M: f32 = 1.0;
+const FRONT_WALL_M: f32 = 0.5;
+/// Écart admis entre trajet mesuré et trajet type : relatif, avec un
+/// minimum absolu (m).
+const MATCH_RATIO: f32 = 0.25;
+const MATCH_MIN_M: f32 = 0.15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Surface {
+    Floor,
+    Ceiling,
+    SideWall,
+    /// Mur derrière l'enceinte.
+    FrontWall,
+}
+
+impl Surface {
+    pub const ALL: [Surface; 4] = [Surface::Floor, Surface::Ceiling, Surface::SideWall, Surface::FrontWall];
+
+    pub fn label(self) -> &'static str {
+        tr().surface_names[self as usize]
+    }
+
+    /// Allongement du trajet réfléchi par rapport au trajet direct
+    /// `direct_m`, dans la pièce type (m).
+    fn extra_path_m(self, direct_m: f32) -> f32 {
+        let image = |offset: f32| (direct_m * direct_m + offset * offset).sqrt() - direct_m;
+        match self {
+            Surface::Floor => image(SPEAKER_HEIGHT_M + EAR_HEIGHT_M),
+            Surface::Ceiling => image(2.0 * CEILING_HEIGHT_M - SPEAKER_HEIGHT_M - EAR_HEIGHT_M),
+            Surface::SideWall => image(2.0 * SIDE_WALL_M),
+            // Aller-retour derrière l'enceinte, quasiment dans l'axe
+            Surface::FrontWall => 2.0 * FRONT_WALL_M,
+        }
+    }
+}
+
+/// Une réflexion de l'IR.
+#[derive(Debug, Clone, Serialize)]
+pub struct Reflection {
+    /// Retard sur le son direct (ms).
+    pub delay_ms: f32,
+    /// Niveau de l'enveloppe par rapport au son direct (dB).
+    pub level_db: f32,
+    /// Différence de trajet avec le son direct (m).
+    pub path_m: f32,
+    /// Surfaces plausibles, la plus probable d'abord ; vide = aucune.
+    pub surfaces: Vec<Surface>,
+}
+
+impl Reflection {
+    pub fn surfaces_label(&self) -> String {
+        surfaces_label(&self.surfaces)
+    }
+}
+
+/// « sol », « sol ou mur latéral », « ? ».
+fn surfaces_label(surfaces: &[Surface]) -> String {
+    match surfaces {
+        [] => "?".to_string(),
+        surfaces => surfaces.iter().map(|s| s.label()).collect::<Vec<_>>().join(tr().or_word),
+    }
+}
+
+/// Surfaces dont le trajet type est proche de `path_m` (deux au plus).
+fn guess_surfaces(path_m: f32, direct_m: f32) -> Vec<Surface> {
+    let mut candidates: Vec<(f32, Surface)> = Surface::ALL
+        .iter()
+        .map(|&s| (s.extra_path_m(direct_m), s))
+        .map(|(expected, s)| ((path_m - expected).abs(), expected, s))
+        .filter(|&(gap, expected, _)| gap <= (expected * MATCH_RATIO).max(MATCH_MIN_M))
+        .map(|(gap, _, s)| (gap, s))
+        .collect();
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+    candidates.into_iter().take(2).map(|(_, s)| s).collect()
+}
+
+/// Réflexions les plus fortes de `ir` (au plus `MAX_REFLECTIONS`, dans
+/// l'ordre d'arrivée) ; `direct_m` : distance enceinte → micro servant à
+/// deviner les surfaces. `None` sans son direct.
+pub fn find(ir: &[f32], sample_rate: u32, onset_db: f32, direct_m: f32) -> Option<Vec<Reflection>> {
+    let window = dsp::direct_window(ir, sample_rate, onset_db)?;
+    let samples = |ms: f32| (ms / 1000.0 * sample_rate as f32) as usize;
+    let spacing = samples(MIN_SPACING_MS).max(1);
+    let end = (window.end + samples(WINDOW_MS.1) + spacing + 1).min(ir.len());
+    let env = dsp::envelope(&ir[window.onset..end]);
+    let direct_end = (window.end - window.onset).min(env.len() - 1);
+    let (peak, &peak_env) = env[..=direct_end].iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1))?;
+    if peak_env <= 0.0 {
+        return None;
+    }
+
+    let from = peak + samples(WINDOW_MS.0);
+    let to = (peak + samples(WINDOW_MS.1)).min(env.len().saturating_sub(spacing + 1));
+    let mut found: Vec<(usize, f32)> = (from.max(spacing)..to)
+        .filter(|&i| env[i - spacing..=i + spacing].iter().all(|&v| v <= env[i]))
+        .map(|i| (i, 20.0 * (env[i] / peak_env).log10()))
+        .filter(|&(_, db)| db >= FLOOR_DB)
+        .collect();
+    found.sort_by(|a, b| b.1.total_cmp(&a.1));
+    found.truncate(MAX_REFLECTIONS);
+    found.sort_by_key(|&(i, _)| i);
+
+    Some(
+        found
+            .into_iter()
+            .map(|(i, level_db)| {
+                let delay_ms = (i - peak) as f32 / sample_rate as f32 * 1000.0;
+                let path_m = delay_ms / 1000.0 * dsp::speed_of_sound();
+                Reflection { delay_ms, level_db, path_m, surfaces: guess_surfaces(path_m, direct_m) }
+            })
+            .collect(),
+    )
+}
+
+/// Même réflexion nettement plus forte d'un côté.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Asymmetry {
+    /// Surfaces plausibles et retard, pris du côté le plus fort.
+    pub surfaces: Vec<Surface>,
+    pub delay_ms: f32,
+    /// Niveau de la réflexion de chaque côté (dB) ; `None` = absente ou
+    /// sous `FLOOR_DB`.
+    pub left_db: Option<f32>,
+    pub right_db: Option<f32>,
+}
+
+impl Asymmetry {
+    /// Réflexion vue à gauche, à droite ou des deux côtés.
+    fn between(left: Option<&Reflection>, right: Option<&Reflection>) -> Option<Self> {
+        let (left_db, right_db) = (left.map(|r| r.level_db), right.map(|r| r.level_db));
+        let stronger = if right_db > left_db { right } else { left.or(right) }?;
+        Some(Asymmetry { surfaces: stronger.surfaces.clone(), delay_ms: stronger.delay_ms, left_db, right_db })
+    }
+
+    /// Écart D − G (dB), une réflexion absente comptant pour `FLOOR_DB`.
+    pub fn diff_db(&self) -> f32 {
+        self.right_db.unwrap_or(FLOOR_DB) - self.left_db.unwrap_or(FLOOR_DB)
+    }
+
+    pub fn surfaces_label(&self) -> String {
+        surfaces_label(&self.surfaces)
+    }
+}
+
+/// Premières réflexions des deux enceintes.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReflectionReport {
+    pub left: Vec<Reflection>,
+    pub right: Vec<Reflection>,
+}
+
+impl ReflectionReport {
+    /// Réflexions à surface connue plus fortes d'au moins `ASYMMETRY_DB`
+    /// d'un côté, par retard croissant. G et D sont appariées par trajet,
+    /// les plus proches d'abord, chaque réflexion une fois au plus.
+    pub fn asymmetries(&self) -> Vec<Asymmetry> {
+        let mut pairs: Vec<(f32, usize, usize)> = (0..self.left.len())
+            .flat_map(|l| (0..self.right.len()).map(move |r| (l, r)))
+            .map(|(l, r)| ((self.left[l].path_m - self.right[r].path_m).abs(), l, r))
+            .filter(|&(gap, _, _)| gap <= PAIR_TOLERANCE_M)
+            .collect();
+        pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut left_paired = vec![false; self.left.len()];
+        let mut right_paired = vec![false; self.right.len()];
+        let mut found = Vec::new();
+        for (_, l, r) in pairs {
+            if !left_paired[l] && !right_paired[r] {
+                left_paired[l] = true;
+                right_paired[r] = true;
+                found.extend(Asymmetry::between(Some(&self.left[l]), Some(&self.right[r])));
+            }
+        }
+        // Sans pendant de l'autre côté
+        for (l, _) in self.left.iter().zip(&left_paired).filter(|(_, &p)| !p) {
+            found.extend(Asymmetry::between(Some(l), None));
+        }
+        for (r, _) in self.right.iter().zip(&right_paired).filter(|(_, &p)| !p) {
+            found.extend(Asymmetry::between(None, Some(r)));
+        }
+
+        found.retain(|a| !a.surfaces.is_empty() && a.diff_db().abs() >= ASYMMETRY_DB);
+        found.sort_by(|a, b| a.delay_ms.total_cmp(&b.delay_ms));
+        found
+    }
+}
@@ -0,0 +1,215 @@
+// ============================================================
+//  reminders.rs — Âge des vérifications et rappels
+//
+//  Une calibration micro ou une latence de boucle valent pour un
+//  micro, une carte et une date : un fichier de calibration resté
+//  en place après un changement de micro, ou une boucle mesurée
+//  sur l'ancienne interface, faussent les mesures sans bruit.
+//
+//  Le journal `speaker-align-checks.json` (répertoire courant, ou
+//  `[reminders] log`) garde, d'une exécution à l'autre :
+//    - chaque calibration micro rencontrée (empreinte de ses points)
+//      avec la date de son premier chargement ou de sa dernière
+//      re-vérification [_], et l'entrée audio d'alors ;
+//    - la dernière latence de boucle mesurée, sa date et les
+//      périphériques de sortie / d'entrée.
+//  L'en-tête affiche leur âge ; au-delà de `mic_cal_days` /
+//  `loopback_days`, ou si les périphériques ont changé depuis
+//  (`on_device_change`), un rappel est affiché.
+// ============================================================
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{calibration::MicCalibration, strings::tr};
+
+/// Journal lu par défaut, dans le répertoire courant.
+pub const DEFAULT_LOG_PATH: &str = "speaker-align-checks.json";
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Section `[reminders]` du fichier de configuration. Une période de 0
+/// désactive le rappel correspondant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReminderParams {
+    /// Jours avant de re-vérifier la calibration micro.
+    pub mic_cal_days: u32,
+    /// Jours avant de remesurer la latence de la boucle.
+    pub loopback_days: u32,
+    /// Rappeler aussi quand les périphériques ont changé depuis.
+    pub on_device_change: bool,
+    /// Chemin du journal des vérifications.
+    pub log: PathBuf,
+}
+
+impl Default for ReminderParams {
+    fn default() -> Self {
+        ReminderParams { mic_cal_days: 180, loopback_days: 30, on_device_change: true, log: DEFAULT_LOG_PATH.into() }
+    }
+}
+
+/// Calibration micro rencontrée.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MicCalCheck {
+    pub name: String,
+    /// Empreinte des points : un fichier renommé reste reconnu, un
+    /// fichier modifié repart de zéro.
+    pub fingerprint: u64,
+    /// Premier chargement ou dernière re-vérification (secondes, UTC).
+    pub verified_unix: u64,
+    pub input_device: String,
+}
+
+/// Dernière latence de boucle mesurée.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoopbackCheck {
+    pub measured_unix: u64,
+    pub latency_ms: f32,
+    pub output_device: String,
+    pub input_device: String,
+}
+
+/// Contenu du journal des vérifications.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CheckLog {
+    pub mic_cals: Vec<MicCalCheck>,
+    pub loopback: Option<LoopbackCheck>,
+}
+
+/// Vérification à refaire.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reminder {
+    /// Calibration vérifiée il y a `days` jours.
+    MicCalStale { days: u64 },
+    /// Calibration vérifiée avec une autre entrée audio.
+    MicCalDeviceChanged,
+    /// Boucle configurée mais jamais mesurée.
+    LoopbackUnmeasured,
+    LoopbackStale { days: u64 },
+    LoopbackDeviceChanged,
+}
+
+impl Reminder {
+    pub fn is_mic_cal(self) -> bool {
+        matches!(self, Reminder::MicCalStale { .. } | Reminder::MicCalDeviceChanged)
+    }
+}
+
+/// Heure système (secondes depuis 1970, UTC).
+pub fn now_unix() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+/// Jours entiers écoulés depuis `since`.
+pub fn age_days(since: u64, now: u64) -> u64 {
+    now.saturating_sub(since) / SECS_PER_DAY
+}
+
+/// Empreinte FNV-1a des points d'une calibration (stable d'une version
+/// du compilateur à l'autre, contrairement au `Hasher` standard).
+pub fn fingerprint(cal: &MicCalibration) -> u64 {
+    cal.points
+        .iter()
+        .flat_map(|(f, db)| f.to_bits().to_le_bytes().into_iter().chain(db.to_bits().to_le_bytes()))
+        .fold(0xcbf2_9ce4_8422_2325, |h, b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+impl CheckLog {
+    /// Lit le journal ; absent = vide.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(CheckLog::default()),
+            Err(e) => return Err(e).with_context(|| (tr().cannot_read)(&path.display().to_string())),
+        };
+        serde_json::from_str(&text).with_context(|| (tr().checks_log_invalid)(&path.display().to_string()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).with_context(|| (tr().cannot_write)(&path.display().to_string()))
+    }
+
+    pub fn mic_cal(&self, cal: &MicCalibration) -> Option<&MicCalCheck> {
+        let print = fingerprint(cal);
+        self.mic_cals.iter().find(|c| c.fingerprint == print)
+    }
+
+    /// Note le chargement de `cal` : une calibration déjà connue garde sa
+    /// date. Vrai si le journal a changé.
+    pub fn note_mic_cal(&mut self, cal: &MicCalibration, input_device: &str, now: u64) -> bool {
+        if self.mic_cal(cal).is_some() {
+            return false;
+        }
+        self.verify_mic_cal(cal, input_device, now);
+        true
+    }
+
+    /// Calibration re-vérifiée maintenant, avec l'entrée courante.
+    pub fn verify_mic_cal(&mut self, cal: &MicCalibration, input_device: &str, now: u64) {
+        let print = fingerprint(cal);
+        self.mic_cals.retain(|c| c.fingerprint != print);
+        self.mic_cals.push(MicCalCheck {
+            name: cal.name.clone(),
+            fingerprint: print,
+            verified_unix: now,
+            input_device: input_device.to_string(),
+        });
+    }
+
+    pub fn note_loopback(&mut self, latency_ms: f32, output_device: &str, input_device: &str, now: u64) {
+        self.loopback = Some(LoopbackCheck {
+            measured_unix: now,
+            latency_ms,
+            output_device: output_device.to_string(),
+            input_device: input_device.to_string(),
+        });
+    }
+
+    /// Vérifications à refaire pour la calibration `cal` et, si une boucle
+    /// est configurée (`loopback`), pour sa latence.
+    pub fn reminders(
+        &self,
+        params: &ReminderParams,
+        cal: Option<&MicCalibration>,
+        loopback: bool,
+        devices: (&str, &str),
+        now: u64,
+    ) -> Vec<Reminder> {
+        let (output_device, input_device) = devices;
+        let mut due = Vec::new();
+        let expired = |since: u64, period: u32| period > 0 && age_days(since, now) >= period as u64;
+        if let Some(check) = cal.and_then(|c| self.mic_cal(c)) {
+            if expired(check.verified_unix, params.mic_cal_days) {
+                due.push(Reminder::MicCalStale { days: age_days(check.verified_unix, now) });
+            }
+            if params.on_device_change && check.input_device != input_device {
+                due.push(Reminder::MicCalDeviceChanged);
+            }
+        }
+        if loopback {
+            match &self.loopback {
+                None => due.push(Reminder::LoopbackUnmeasured),
+                Some(check) => {
+                    if expired(check.measured_unix, params.loopback_days) {
+                        due.push(Reminder::LoopbackStale { days: age_days(check.measured_unix, now) });
+                    }
+                    if params.on_device_change
+                        && (check.output_device != output_device || check.input_device != input_device)
+                    {
+                        due.push(Reminder::LoopbackDeviceChanged);
+                    }
+                }
+            }
+        }
+        due
+    }
+}
@@ -0,0 +1,375 @@
+// ============================================================
+//  report.rs — Rapport HTML autonome (partage avec un client)
+//
+//  [End] écrit `rapport.html` après une analyse : un seul fichier,
+//  sans ressource externe, lisible dans n'importe quel navigateur
+//  et joignable à un courriel :
+//    — en-tête : score, verdict, heure, périphériques, notes [F12] ;
+//    — métriques G/D (retard, niveau, pente, profil de score) et
+//      par enceinte (distance, C80, T30 moyen, pire THD, écart à
+//      la cible) ;
+//    — graphiques SVG (plotters) : réponses G/D, écart D − G sur
+//      la plage d'analyse, évolution du score de l'historique ;
+//    — recommandations telles que le panneau les affiche.
+//  Textes dans la langue de l'interface ; nombres au point
+//  décimal, comme les autres exports.
+//
+//  [Ins] (et `--png` en mode headless) trace les mêmes courbes
+//  G/D et D − G dans `reponse.png`, en haute résolution quelle que
+//  soit la taille du terminal : une image qui se colle telle quelle
+//  dans un forum, là où les tracés braille se déforment.
+// ============================================================
+
+use anyhow::Result;
+use plotters::{coord::Shift, prelude::*};
+use std::{f32::consts::SQRT_2, fmt::Write as _, ops::Range, path::Path, sync::Once};
+
+use crate::{
+    advice::{self, Advice},
+    app::{AppState, HistoryEntry},
+    dsp::{self, DecayTimes, NUM_BANDS},
+    harmonics::THD_LIMIT_DB,
+    measurement::Measurement,
+    strings::{format_hz, lang, tr},
+};
+
+/// Fichier écrit par [End], dans le dossier courant.
+pub const REPORT_PATH: &str = "rapport.html";
+
+/// Fichier écrit par [Ins], dans le dossier courant.
+pub const PNG_PATH: &str = "reponse.png";
+
+/// Taille des graphiques (pixels).
+const CHART_SIZE: (u32, u32) = (760, 320);
+/// Image PNG : deux panneaux de 1600 × 600, traits et textes doublés.
+const PNG_SIZE: (u32, u32) = (1600, 1200);
+const PNG_SCALE: u32 = 2;
+/// Hauteur d'une ligne de notes sous les graphiques de l'image (avant échelle).
+const NOTE_LINE_PX: u32 = 16;
+
+/// Police des graphiques, embarquée : le PNG ne dépend d'aucune police du
+/// système (ni fontconfig) et le rendu est le même partout.
+const CHART_FONT: &[u8] = include_bytes!("../assets/fonts/DejaVuSans.ttf");
+const CHART_FONT_NAME: &str = "sans-serif";
+
+/// Couleurs des courbes sur fond blanc : gauche, droite, écart / score.
+const LEFT_COLOR: RGBColor = RGBColor(0, 150, 90);
+const RIGHT_COLOR: RGBColor = RGBColor(220, 90, 40);
+const ACCENT_COLOR: RGBColor = RGBColor(90, 70, 200);
+
+/// Feuille de style de la page.
+const STYLE: &str = "body{font-family:sans-serif;max-width:800px;margin:2em auto;color:#222}\
+h1{margin-bottom:.2em}.sub{color:#666;margin-top:0}\
+.score{font-size:2em;font-weight:bold}\
+table{border-collapse:collapse;margin:.5em 0 1.5em}\
+td,th{border:1px solid #ccc;padding:.3em .8em;text-align:right}\
+td:first-child,th:first-child{text-align:left}\
+td.note{text-align:left}\
+.bad{color:#c0392b;font-weight:bold}\
+.reco div{white-space:pre-wrap}\
+footer{color:#999;font-size:.8em;margin-top:2em}";
+
+/// Enregistre `CHART_FONT` auprès de plotters, une fois, avant tout tracé :
+/// les textes des graphiques SVG et PNG s'y mesurent.
+fn register_chart_font() {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        let _ = plotters::style::register_font(CHART_FONT_NAME, FontStyle::Normal, CHART_FONT);
+    });
+}
+
+/// Échappe `&`, `<`, `>` et `"` pour le HTML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// « 125 », « 2k » : étiquettes de l'axe des fréquences.
+fn hz_label(hz: f32) -> String {
+    if hz >= 1000.0 { format!("{}k", (hz / 100.0).round() / 10.0) } else { format!("{:.0}", hz) }
+}
+
+/// Points (fréquence, dB) des bandes `bands` d'une courbe.
+fn band_points(db: &[f32], bands: Range<usize>) -> Vec<(f32, f32)> {
+    bands.filter_map(|b| db.get(b).map(|&v| (dsp::band_center_freq(b, NUM_BANDS), v))).collect()
+}
+
+/// Courbe nommée d'un graphique en fréquence : points (Hz, dB).
+struct Curve {
+    name: String,
+    color: RGBColor,
+    points: Vec<(f32, f32)>,
+}
+
+/// Courbes de la réponse : G et D (moyennes en mode fenêtre d'écoute, comme
+/// à l'écran), écart D − G, sur les bandes `bands` de la plage d'analyse.
+pub struct Response<'a> {
+    pub left_db: Option<&'a [f32]>,
+    pub right_db: Option<&'a [f32]>,
+    pub diff_db: Option<&'a [f32]>,
+    pub bands: Range<usize>,
+    /// Notes [F12] écrites sous les graphiques de l'image.
+    pub notes: Vec<String>,
+}
+
+impl<'a> Response<'a> {
+    pub fn from_state(state: &'a AppState) -> Self {
+        let (left_db, right_db) = match &state.window_summary {
+            Some(w) => (Some(w.left_db.as_slice()), Some(w.right_db.as_slice())),
+            None => (state.left.as_ref().map(|m| m.bands_db.as_slice()), state.right.as_ref().map(|m| m.bands_db.as_slice())),
+        };
+        Response {
+            left_db,
+            right_db,
+            diff_db: state.diff_db.as_deref(),
+            bands: state.analysis.bands(),
+            notes: state.current_notes().header_lines(),
+        }
+    }
+
+    /// Courbes G et D présentes, puis l'écart s'il existe.
+    fn curves(&self) -> (Vec<Curve>, Option<Curve>) {
+        let s = tr();
+        let curve = |name: &str, color, db: Option<&[f32]>| {
+            let points = band_points(db?, self.bands.clone());
+            (!points.is_empty()).then(|| Curve { name: name.to_string(), color, points })
+        };
+        let sides = [curve(s.left_name, LEFT_COLOR, self.left_db), curve(s.right_name, RIGHT_COLOR, self.right_db)];
+        let diff = curve(&format!("{} − {}", s.right_short, s.left_short), ACCENT_COLOR, self.diff_db);
+        (sides.into_iter().flatten().collect(), diff)
+    }
+}
+
+/// Valeur d'une ligne du tableau par enceinte.
+type SideValue = fn(&Measurement) -> Option<String>;
+
+/// Graphique en fréquence (axe logarithmique) de courbes nommées, en SVG.
+fn frequency_chart(caption: &str, curves: &[Curve]) -> Result<String> {
+    register_chart_font();
+    let mut svg = String::new();
+    {
+        let root = SVGBackend::with_string(&mut svg, CHART_SIZE).into_drawing_area();
+        root.fill(&WHITE)?;
+        draw_frequency(&root, caption, curves, 1)?;
+        root.present()?;
+    }
+    Ok(svg)
+}
+
+/// Trace `curves` sur `area`, tailles multipliées par `scale`.
+fn draw_frequency<DB: DrawingBackend>(area: &DrawingArea<DB, Shift>, caption: &str, curves: &[Curve], scale: u32) -> Result<()>
+where
+    DB::ErrorType: 'static,
+{
+    let points = curves.iter().flat_map(|c| &c.points);
+    let (lo_hz, hi_hz) = points.clone().fold((f32::MAX, f32::MIN), |(lo, hi), &(f, _)| (lo.min(f), hi.max(f)));
+    // Une seule bande (plage d'analyse réduite) : une demi-octave de part et
+    // d'autre, l'axe logarithmique ne tolère pas une plage nulle
+    let (lo_hz, hi_hz) = if hi_hz > lo_hz { (lo_hz, hi_hz) } else { (lo_hz / SQRT_2, hi_hz * SQRT_2) };
+    let (lo_db, hi_db) = points.fold((f32::MAX, f32::MIN), |(lo, hi), &(_, v)| (lo.min(v), hi.max(v)));
+    // Axe des dB arrondi à 5 dB, 10 dB au moins
+    let lo_db = (lo_db / 5.0).floor() * 5.0 - 5.0;
+    let hi_db = ((hi_db / 5.0).ceil() * 5.0 + 5.0).max(lo_db + 10.0);
+
+    let scaled = |size: u32| size * scale;
+    let mut chart = ChartBuilder::on(area)
+        .caption(caption, (CHART_FONT_NAME, scaled(18)))
+        .margin(scaled(10))
+        .x_label_area_size(scaled(30))
+        .y_label_area_size(scaled(45))
+        .build_cartesian_2d((lo_hz..hi_hz).log_scale(), lo_db..hi_db)?;
+    chart
+        .configure_mesh()
+        .x_desc("Hz")
+        .y_desc("dB")
+        .label_style((CHART_FONT_NAME, scaled(12)))
+        .x_label_formatter(&|&hz| hz_label(hz))
+        .draw()?;
+    for curve in curves {
+        let (color, width, dash) = (curve.color, scaled(2), scaled(16) as i32);
+        chart
+            .draw_series(LineSeries::new(curve.points.iter().copied(), color.stroke_width(width)))?
+            .label(curve.name.as_str())
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + dash, y)], color.stroke_width(width)));
+    }
+    chart
+        .configure_series_labels()
+        .label_font((CHART_FONT_NAME, scaled(12)))
+        .legend_area_size(scaled(30))
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+    Ok(())
+}
+
+/// Écrit les courbes de `response` dans l'image PNG `path` : réponses G/D
+/// en haut, écart D − G en bas (un seul panneau s'il manque l'un des deux).
+/// `false` sans aucune courbe à tracer.
+pub fn write_png(response: &Response, path: &Path) -> Result<bool> {
+    let s = tr();
+    let (sides, diff) = response.curves();
+    let mut charts: Vec<(&str, Vec<Curve>)> = Vec::new();
+    if !sides.is_empty() {
+        charts.push((s.report_response, sides));
+    }
+    if let Some(diff) = diff {
+        charts.push((s.report_diff, vec![diff]));
+    }
+    if charts.is_empty() {
+        return Ok(false);
+    }
+    register_chart_font();
+    let root = BitMapBackend::new(path, PNG_SIZE).into_drawing_area();
+    root.fill(&WHITE)?;
+    let notes_px = response.notes.len() as u32 * NOTE_LINE_PX * PNG_SCALE;
+    let (charts_area, notes_area) = root.split_vertically(PNG_SIZE.1 - notes_px);
+    for (area, (caption, curves)) in charts_area.split_evenly((charts.len(), 1)).iter().zip(&charts) {
+        draw_frequency(area, caption, curves, PNG_SCALE)?;
+    }
+    let font = (CHART_FONT_NAME, (12 * PNG_SCALE) as f64).into_font();
+    for (i, line) in response.notes.iter().enumerate() {
+        let y = (i as u32 * NOTE_LINE_PX * PNG_SCALE) as i32;
+        notes_area.draw(&Text::new(line.as_str(), (20 * PNG_SCALE as i32, y), font.clone()))?;
+    }
+    root.present()?;
+    Ok(true)
+}
+
+/// Score de chaque analyse de l'historique (numérotées depuis 1).
+fn history_chart(history: &[HistoryEntry]) -> Result<String> {
+    register_chart_font();
+    let mut svg = String::new();
+    {
+        let root = SVGBackend::with_string(&mut svg, CHART_SIZE).into_drawing_area();
+        root.fill(&WHITE)?;
+        let mut chart = ChartBuilder::on(&root)
+            .caption(tr().report_history, (CHART_FONT_NAME, 18))
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(45)
+            .build_cartesian_2d(1..history.len().max(2), 0u32..100u32)?;
+        chart.configure_mesh().x_desc("#").y_desc("/100").draw()?;
+        let points: Vec<(usize, u32)> = history.iter().enumerate().map(|(i, h)| (i + 1, h.score)).collect();
+        chart.draw_series(LineSeries::new(points.iter().copied(), ACCENT_COLOR.stroke_width(2)))?;
+        chart.draw_series(points.iter().map(|&p| Circle::new(p, 4, ACCENT_COLOR.filled())))?;
+        root.present()?;
+    }
+    Ok(svg)
+}
+
+/// Valeur d'une cellule, tiret si absente.
+fn cell(value: Option<String>) -> String {
+    value.unwrap_or_else(|| "—".to_string())
+}
+
+/// Page complète ; `None` avant la première analyse [A].
+pub fn to_html(state: &AppState) -> Result<Option<String>> {
+    let s = tr();
+    let Some(score) = state.score else {
+        return Ok(None);
+    };
+    let rating = if score >= 85 { s.rating_excellent } else if score >= 60 { s.rating_adjust } else { s.rating_fix };
+    let time = state.history.last().map_or("—", |h| h.time.as_str());
+
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"{}\"><head><meta charset=\"utf-8\"><title>{}</title><style>{}</style></head><body>\n",
+        lang().code(),
+        escape(s.report_title),
+        STYLE
+    );
+    let _ = writeln!(out, "<h1>{}</h1>", escape(s.report_title));
+    let _ = writeln!(
+        out,
+        "<p class=\"sub\">{}</p>",
+        escape(&(s.report_measured)(time, &state.out_device, &state.in_device))
+    );
+    let _ = writeln!(out, "<p class=\"score\">{}/100 — {}</p>", score, escape(rating));
+
+    // Notes de l'analyse ([F12])
+    let notes = state.current_notes().filled();
+    if !notes.is_empty() {
+        let _ = writeln!(out, "<h2>{}</h2>\n<table>", escape(s.report_notes));
+        for (field, value) in notes {
+            let _ = writeln!(out, "<tr><td>{}</td><td class=\"note\">{}</td></tr>", escape(field.label()), escape(value));
+        }
+        out.push_str("</table>\n");
+    }
+
+    // Écarts G/D
+    let w = state.weights();
+    let flag = |bad: bool| if bad { " class=\"bad\"" } else { "" };
+    let _ = writeln!(out, "<h2>{}</h2>\n<table>", escape(s.report_metrics));
+    for (label, value, bad) in [
+        (s.report_delay, format!("{:+.3} ms", state.delay_ms), w.delay_ms.severe(state.delay_ms)),
+        (s.report_level, format!("{:+.2} dB", state.level_diff_db), w.level_db.severe(state.level_diff_db)),
+        (s.report_tilt, format!("{:+.2} dB", state.freq_tilt), w.tilt_db.severe(state.freq_tilt)),
+        (s.report_profile, state.scoring.profile.name().to_string(), false),
+    ] {
+        let _ = writeln!(out, "<tr><td>{}</td><td{}>{}</td></tr>", escape(label), flag(bad), escape(&value));
+    }
+    out.push_str("</table>\n");
+
+    // Grandeurs de chaque enceinte
+    let sides = [state.left.as_ref(), state.right.as_ref()];
+    let deviations = state.target_deviations();
+    let _ = writeln!(
+        out,
+        "<h2>{}</h2>\n<table><tr><th></th><th>{}</th><th>{}</th></tr>",
+        escape(s.report_speakers),
+        escape(s.left_name),
+        escape(s.right_name)
+    );
+    let rows: [(&str, SideValue); 4] = [
+        (s.report_distance, |m| m.dist_m.map(|d| format!("{:.2} m", d))),
+        ("C80", |m| m.clarity.map(|c| format!("{:+.1} dB", c.c80_db))),
+        (s.report_t30, |m| m.mean_decay(|d: &DecayTimes| d.t30_s).map(|t| format!("{:.2} s", t))),
+        (s.report_thd, |m| m.worst_thd().map(|o| format!("{:.2} % ({})", o.thd_pct(), format_hz(o.freq_hz)))),
+    ];
+    for (label, value) in rows {
+        let _ = write!(out, "<tr><td>{}</td>", escape(label));
+        for m in sides {
+            let bad = label == s.report_thd && m.and_then(Measurement::worst_thd).is_some_and(|o| o.thd_db > THD_LIMIT_DB);
+            let _ = write!(out, "<td{}>{}</td>", flag(bad), escape(&cell(m.and_then(value))));
+        }
+        out.push_str("</tr>\n");
+    }
+    if let (Some(target), Some((l, r))) = (&state.target, deviations) {
+        let _ = writeln!(
+            out,
+            "<tr><td>{} ({})</td><td>±{:.1} dB</td><td>±{:.1} dB</td></tr>",
+            escape(s.report_target),
+            escape(&target.name),
+            l,
+            r
+        );
+    }
+    out.push_str("</table>\n");
+
+    // Courbes : celles de l'écran
+    let (sides, diff) = Response::from_state(state).curves();
+    if !sides.is_empty() {
+        let _ = writeln!(out, "<h2>{}</h2>\n{}", escape(s.report_response), frequency_chart(s.report_response, &sides)?);
+    }
+    if let Some(diff) = diff {
+        let _ = writeln!(out, "<h2>{}</h2>\n{}", escape(s.report_diff), frequency_chart(s.report_diff, &[diff])?);
+    }
+    if state.history.len() >= 2 {
+        let _ = writeln!(out, "<h2>{}</h2>\n{}", escape(s.report_history), history_chart(&state.history)?);
+    }
+
+    // Recommandations du panneau, ligne par ligne
+    let _ = writeln!(out, "<h2>{}</h2>\n<div class=\"reco\">", escape(s.reco_title.trim()));
+    for Advice { icon, text, .. } in advice::recommendations(state) {
+        let text = match icon {
+            Some((icon, _)) => format!("  {} {}", icon, text),
+            None => text,
+        };
+        if !text.trim().is_empty() {
+            let _ = writeln!(out, "<div>{}</div>", escape(text.trim_end()));
+        }
+    }
+    out.push_str("</div>\n");
+    let _ = writeln!(out, "<footer>{}</footer>\n</body></html>", escape(s.report_footer));
+    Ok(Some(out))
+}
@@ -0,0 +1,100 @@
+// ============================================================
+//  resample.rs — Conversion de taux d'échantillonnage
+//
+//  Les périphériques ne tournent pas tous à 48 kHz : 44,1 kHz
+//  (cartes grand public), 96 ou 192 kHz (interfaces de studio),
+//  parfois un taux différent en entrée et en sortie. L'analyse se
+//  fait au taux de la session (`AppState::sample_rate`) : le signal
+//  de test est converti au taux de la sortie avant la lecture, la
+//  capture au taux de la session après l'arrêt des flux.
+//
+//  Conversion synchrone par FFT (`rubato::FftFixedInOut`) : rapport
+//  exact entre taux entiers, bande passante quasi pleine, et retard
+//  du filtre retiré — un écart d'arrivée G/D reste exact.
+//
+//  `stretch_tracks` applique un rapport quelconque, proche de 1
+//  (sinc fenêtré, `rubato::SincFixedIn`) : correction de la dérive
+//  d'horloge entre cartes (drift.rs).
+// ============================================================
+
+use anyhow::Result;
+use rubato::{
+    FftFixedInOut, Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
+};
+
+/// Taille visée des blocs d'entrée (trames), ajustée par rubato au
+/// multiple du rapport des taux.
+const CHUNK_FRAMES: usize = 1024;
+
+/// Convertit des pistes de même longueur de `from` à `to` Hz ; la sortie
+/// dure autant que l'entrée (longueur · to / from, arrondie).
+pub fn resample_tracks(tracks: &[Vec<f32>], from: u32, to: u32) -> Result<Vec<Vec<f32>>> {
+    let len = tracks.first().map_or(0, Vec::len);
+    if from == to || len == 0 {
+        return Ok(tracks.to_vec());
+    }
+    let expected = (len as f64 * to as f64 / from as f64).round() as usize;
+    let mut resampler = FftFixedInOut::<f32>::new(from as usize, to as usize, CHUNK_FRAMES, tracks.len())?;
+    let delay = resampler.output_delay();
+    process_tracks(&mut resampler, tracks, expected, delay)
+}
+
+/// Étire des pistes de même longueur d'un facteur `ratio` (trames de sortie
+/// par trame d'entrée) ; identité si `ratio` vaut 1.
+pub fn stretch_tracks(tracks: &[Vec<f32>], ratio: f64) -> Result<Vec<Vec<f32>>> {
+    let len = tracks.first().map_or(0, Vec::len);
+    if ratio == 1.0 || len == 0 {
+        return Ok(tracks.to_vec());
+    }
+    let expected = (len as f64 * ratio).round() as usize;
+    let parameters = SincInterpolationParameters {
+        sinc_len: 256,
+        f_cutoff: 0.95,
+        oversampling_factor: 256,
+        interpolation: SincInterpolationType::Cubic,
+        window: WindowFunction::BlackmanHarris2,
+    };
+    let mut resampler = SincFixedIn::<f32>::new(ratio, 1.0, parameters, CHUNK_FRAMES, tracks.len())?;
+    // Le sinc démarre déjà centré sur le filtre (indice initial négatif) :
+    // aucun retard à retirer, quoi qu'annonce `output_delay()`
+    process_tracks(&mut resampler, tracks, expected, 0)
+}
+
+/// Passe les pistes dans `resampler` et garde `expected` trames après les
+/// `delay` premières (retard du filtre).
+fn process_tracks(
+    resampler: &mut impl Resampler<f32>,
+    tracks: &[Vec<f32>],
+    expected: usize,
+    delay: usize,
+) -> Result<Vec<Vec<f32>>> {
+    let len = tracks.first().map_or(0, Vec::len);
+
+    let mut out = vec![Vec::with_capacity(expected + delay + resampler.output_frames_max()); tracks.len()];
+    let mut pos = 0;
+    // Blocs complétés de zéros au-delà de la fin : la queue du filtre sort aussi
+    while out[0].len() < delay + expected {
+        let need = resampler.input_frames_next();
+        let block: Vec<Vec<f32>> = tracks
+            .iter()
+            .map(|t| {
+                let mut b = t.get(pos.min(len)..(pos + need).min(len)).unwrap_or(&[]).to_vec();
+                b.resize(need, 0.0);
+                b
+            })
+            .collect();
+        for (o, chunk) in out.iter_mut().zip(resampler.process(&block, None)?) {
+            o.extend(chunk);
+        }
+        pos += need;
+    }
+    Ok(out.into_iter().map(|t| t[delay..delay + expected].to_vec()).collect())
+}
+
+/// Convertit une piste de `from` à `to` Hz.
+pub fn resample(samples: &[f32], from: u32, to: u32) -> Result<Vec<f32>> {
+    if from == to {
+        return Ok(samples.to_vec());
+    }
+    Ok(resample_tracks(&[samples.to_vec()], from, to)?.pop().unwrap_or_default())
+}
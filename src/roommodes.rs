@@ -0,0 +1,129 @@
+// ============================================================
+//  roommodes.rs — Modes propres de la pièce
+//
+//  Dans le grave, une bosse ou un creux de 10 dB au micro vient
+//  le plus souvent d'un mode de la pièce, pas d'une enceinte : la
+//  pression y dépend surtout de la position du micro. Avec les
+//  dimensions de la pièce (section `[room]`), les fréquences des
+//  modes sont calculées et marquées sur le spectre :
+//
+//    f = c/2 · √((nx/L)² + (ny/l)² + (nz/h)²)
+//
+//  Modes axiaux (un seul indice non nul, entre deux murs
+//  opposés, les plus forts) et tangentiels (deux indices, entre
+//  quatre murs, environ 3 dB plus faibles) jusqu'à `MAX_MODE_HZ` ;
+//  les obliques, plus faibles encore, sont laissés de côté.
+//
+//    [room]
+//    length_m = 5.2
+//    width_m  = 3.8
+//    height_m = 2.5
+//
+//  Les dimensions se saisissent aussi avec [$] (« 5.2x3.8x2.5 »).
+//  Les modes sont calculés une fois par changement de dimensions
+//  ou de vitesse du son (`AppState::room_modes`), pas à chaque
+//  image.
+// ============================================================
+
+use anyhow::{Result, bail};
+use serde::Serialize;
+
+use crate::{dsp, strings::tr};
+
+/// Plus haute fréquence de mode calculée (Hz) : au-dessus, les modes
+/// trop serrés ne se distinguent plus les uns des autres.
+pub const MAX_MODE_HZ: f32 = 300.0;
+
+/// Dimensions admises (m).
+pub const DIMENSION_RANGE_M: (f32, f32) = (1.0, 50.0);
+
+/// Dimensions intérieures de la pièce (m).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RoomDimensions {
+    pub length_m: f32,
+    pub width_m: f32,
+    pub height_m: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ModeKind {
+    Axial,
+    Tangential,
+}
+
+/// Un mode propre.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RoomMode {
+    pub freq_hz: f32,
+    pub kind: ModeKind,
+    /// Indices (longueur, largeur, hauteur).
+    pub order: (u32, u32, u32),
+}
+
+impl RoomMode {
+    /// « 1-0-0 ».
+    pub fn label(&self) -> String {
+        format!("{}-{}-{}", self.order.0, self.order.1, self.order.2)
+    }
+}
+
+impl RoomDimensions {
+    /// Lit « 5.2x3.8x2.5 » (longueur, largeur, hauteur en m ; `x` ou `×`,
+    /// virgule décimale admise).
+    pub fn parse(field: &str) -> Result<Self> {
+        let dims: Vec<&str> = field.split(['x', 'X', '×']).collect();
+        let [length, width, height] = dims.as_slice() else {
+            bail!((tr().room_dimensions_format)(field));
+        };
+        let value = |text: &str| -> Result<f32> {
+            let Ok(v) = text.trim().replace(',', ".").parse::<f32>() else {
+                bail!((tr().room_dimensions_format)(field));
+            };
+            let (min, max) = DIMENSION_RANGE_M;
+            if !(min..=max).contains(&v) {
+                bail!((tr().room_dimension_range)(v, min, max));
+            }
+            Ok(v)
+        };
+        Ok(RoomDimensions { length_m: value(length)?, width_m: value(width)?, height_m: value(height)? })
+    }
+
+    /// Modes axiaux et tangentiels jusqu'à `max_hz`, par fréquence
+    /// croissante, à la vitesse du son courante.
+    pub fn modes(&self, max_hz: f32) -> Vec<RoomMode> {
+        let half_c = dsp::speed_of_sound() / 2.0;
+        let max_order = |dim: f32| (2.0 * max_hz * dim / dsp::speed_of_sound()).floor() as u32;
+        let mut modes = Vec::new();
+        for nx in 0..=max_order(self.length_m) {
+            for ny in 0..=max_order(self.width_m) {
+                for nz in 0..=max_order(self.height_m) {
+                    let kind = match [nx, ny, nz].iter().filter(|&&n| n > 0).count() {
+                        1 => ModeKind::Axial,
+                        2 => ModeKind::Tangential,
+                        _ => continue,
+                    };
+                    let term = |n: u32, dim: f32| (n as f32 / dim).powi(2);
+                    let freq_hz =
+                        half_c * (term(nx, self.length_m) + term(ny, self.width_m) + term(nz, self.height_m)).sqrt();
+                    if freq_hz <= max_hz {
+                        modes.push(RoomMode { freq_hz, kind, order: (nx, ny, nz) });
+                    }
+                }
+            }
+        }
+        modes.sort_by(|a, b| a.freq_hz.total_cmp(&b.freq_hz));
+        modes
+    }
+}
+
+/// Mode de `modes` le plus proche de `freq_hz`, s'il en est à moins d'un
+/// sixième d'octave.
+pub fn mode_near(modes: &[RoomMode], freq_hz: f32) -> Option<RoomMode> {
+    modes
+        .iter()
+        .map(|&m| ((m.freq_hz / freq_hz).log2().abs(), m))
+        .filter(|&(octaves, _)| octaves <= 1.0 / 6.0)
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, m)| m)
+}
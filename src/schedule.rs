@@ -0,0 +1,91 @@
+// ============================================================
+//  schedule.rs — Départ de capture à heure fixe
+//
+//  Pour caler une mesure sur un événement extérieur — un collègue
+//  qui tient le micro dans une autre pièce, un chronomètre —
+//  chaque capture peut attendre la seconde `:SS` de la minute
+//  (« départ à :30 », saisie [\]). L'attente a lieu flux ouverts
+//  et armés (`audio::CaptureTiming::start_at`) : le délai
+//  d'ouverture des pilotes ne décale pas le départ. La pause
+//  pré-capture ([+] [-], pas de 0,1 s) court ensuite. Les
+//  deux personnes règlent leur montre sur la même seconde : la
+//  minute importe peu, l'heure système (UTC) suffit.
+// ============================================================
+
+use anyhow::{Result, bail};
+use std::{
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::strings::tr;
+
+/// Longueur maximale de la saisie « :SS ».
+pub const MAX_INPUT_LEN: usize = 3;
+
+const MS_PER_MINUTE: u64 = 60_000;
+
+/// Pas d'attente entre deux vérifications de l'annulation.
+const WAIT_STEP: Duration = Duration::from_millis(50);
+
+/// Départ de chaque capture à une seconde donnée de la minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartSchedule {
+    /// Seconde de la minute (0–59).
+    pub second: u8,
+}
+
+impl StartSchedule {
+    /// Lit « :30 » ou « 30 » ; une saisie vide supprime le départ programmé.
+    pub fn parse(text: &str) -> Result<Option<Self>> {
+        let text = text.trim();
+        let digits = text.strip_prefix(':').unwrap_or(text);
+        if digits.is_empty() {
+            return Ok(None);
+        }
+        match digits.parse::<u8>() {
+            Ok(second) if second < 60 => Ok(Some(StartSchedule { second })),
+            _ => bail!((tr().schedule_second)(text)),
+        }
+    }
+
+    /// « :30 ».
+    pub fn label(self) -> String {
+        format!(":{:02}", self.second)
+    }
+
+    /// Attente avant le prochain départ depuis `now_ms` (ms depuis 1970) ;
+    /// nulle pile à l'heure.
+    pub fn wait_ms(self, now_ms: u64) -> u64 {
+        let target = self.second as u64 * 1000;
+        (target + MS_PER_MINUTE - now_ms % MS_PER_MINUTE) % MS_PER_MINUTE
+    }
+
+    /// Attente restante depuis l'heure système (s).
+    pub fn remaining_secs(self) -> f32 {
+        self.wait_ms(now_ms()) as f32 / 1000.0
+    }
+
+    /// Attend le prochain départ ; `on_progress` reçoit la fraction écoulée.
+    /// Faux si `stopped` est devenu vrai entre-temps.
+    pub fn wait(self, stopped: impl Fn() -> bool, mut on_progress: impl FnMut(f32)) -> bool {
+        let total = self.wait_ms(now_ms());
+        let deadline = SystemTime::now() + Duration::from_millis(total);
+        loop {
+            if stopped() {
+                return false;
+            }
+            let left = deadline.duration_since(SystemTime::now()).unwrap_or_default();
+            if left.is_zero() {
+                return true;
+            }
+            on_progress(1.0 - left.as_millis() as f32 / total.max(1) as f32);
+            thread::sleep(left.min(WAIT_STEP));
+        }
+    }
+}
+
+/// Heure système en millisecondes depuis 1970 (UTC).
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_millis() as u64)
+}
@@ -0,0 +1,99 @@
+// ============================================================
+//  schema.rs — Versions des formats enregistrés et migrations
+//
+//  Les sessions (.spkalign, brutes ou partagées), le rapport
+//  JSON de `--headless` et chaque ligne de l'historique de
+//  `monitor` portent un numéro de format. Au
+//  chargement, une session ancienne est d'abord lue comme JSON
+//  générique puis remise à niveau étape par étape (v1 → v2 →
+//  v3…) avant d'être désérialisée : les structures peuvent
+//  grandir sans rendre illisibles les fichiers existants. Un
+//  fichier plus récent que le programme est refusé plutôt que
+//  lu à moitié.
+//
+//  Règles pour faire évoluer un format :
+//    - champ ajouté : `#[serde(default)]`, sans nouvelle version ;
+//    - champ renommé, retypé ou supprimé : nouvelle version et
+//      migration `vN_to_vN+1` ajoutée à `SESSION_MIGRATIONS`.
+//  Les flottants sont écrits par serde_json au plus court
+//  aller-retour exact : un f32 relu est identique au bit près.
+//  Chaque mesure garde sa fréquence d'échantillonnage.
+// ============================================================
+
+use anyhow::{Result, anyhow, bail};
+use serde_json::{Map, Value, json};
+
+use crate::strings::tr;
+
+/// Version courante du format de session.
+pub const SESSION_VERSION: u32 = 3;
+
+/// Version du rapport JSON imprimé par `--headless`.
+pub const REPORT_VERSION: u32 = 1;
+
+/// Version des lignes de l'historique de `monitor` (JSONL).
+pub const HISTORY_VERSION: u32 = 1;
+
+type Migration = fn(&mut Map<String, Value>) -> Result<()>;
+
+/// `SESSION_MIGRATIONS[n]` passe de la version n + 1 à n + 2.
+const SESSION_MIGRATIONS: [Migration; 2] = [session_v1_to_v2, session_v2_to_v3];
+
+/// Remet une session au format courant ; renvoie aussi sa version d'origine.
+pub fn migrate_session(mut value: Value) -> Result<(Value, u32)> {
+    let object = value.as_object_mut().ok_or_else(|| anyhow!(tr().session_not_object))?;
+    let version = object
+        .get("version")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| anyhow!(tr().session_no_version))? as u32;
+    if version == 0 {
+        bail!(tr().session_version_zero);
+    }
+    if version > SESSION_VERSION {
+        bail!((tr().session_too_new)(version, SESSION_VERSION));
+    }
+    for migrate in &SESSION_MIGRATIONS[version as usize - 1..] {
+        migrate(object)?;
+    }
+    object.insert("version".into(), json!(SESSION_VERSION));
+    Ok((value, version))
+}
+
+/// v1 → v2 : `Channel` devient un indice de sortie (l'énumération
+/// `Left` / `Right` / `Sub(n)` disparaît) ; enceintes supplémentaires et
+/// référence des comparaisons apparaissent.
+fn session_v1_to_v2(session: &mut Map<String, Value>) -> Result<()> {
+    for key in ["left", "right"] {
+        if let Some(Value::Object(m)) = session.get_mut(key) {
+            let index = match m.get("channel") {
+                Some(Value::String(s)) if s == "Left" => 0,
+                Some(Value::String(s)) if s == "Right" => 1,
+                Some(Value::Object(sub)) => sub
+                    .get("Sub")
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| anyhow!((tr().session_v1_channel)(key)))?,
+                Some(Value::Number(n)) => n.as_u64().unwrap_or(0),
+                other => bail!((tr().label_value)(&(tr().session_v1_channel)(key), &format!("{:?}", other))),
+            };
+            m.insert("channel".into(), json!(index));
+        }
+    }
+    session.entry("others").or_insert(json!([]));
+    session.entry("reference").or_insert(json!(0));
+    Ok(())
+}
+
+/// v2 → v3 : journal des changements, positions candidates, réglages du
+/// sweep et courbes de l'historique (tous optionnels, valeurs vides).
+fn session_v2_to_v3(session: &mut Map<String, Value>) -> Result<()> {
+    session.entry("experiments").or_insert(json!({ "entries": [] }));
+    session.entry("positions").or_insert(json!([]));
+    session.entry("sweep").or_insert(Value::Null);
+    if let Some(Value::Array(history)) = session.get_mut("history") {
+        for entry in history.iter_mut().filter_map(Value::as_object_mut) {
+            entry.entry("left_db").or_insert(json!([]));
+            entry.entry("right_db").or_insert(json!([]));
+        }
+    }
+    Ok(())
+}
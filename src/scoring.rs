@@ -0,0 +1,178 @@
+// ============================================================
+//  scoring.rs — Profils de score et tolérances des conseils
+//
+//  0,1 ms d'écart G/D (3 cm) compte pour des moniteurs de studio
+//  écoutés à un mètre, pas pour un canapé à quatre mètres d'un
+//  ampli A/V qui règle la distance de chaque canal. La section
+//  `[score]` choisit un profil :
+//    nearfield-studio — écoute de proximité : temps et niveau
+//                       sévères, le moindre décalage se voit ;
+//    home-hifi        — chaîne stéréo de salon (défaut, valeurs
+//                       historiques) ;
+//    home-theater     — canapé, ampli A/V : retard toléré (le
+//                       délai par canal le corrige), niveau au dB.
+//  Le profil fixe le poids de chaque composante du score (spectre,
+//  niveau, temps, 100 points au total), l'écart qui la ramène à
+//  zéro, et les seuils des recommandations : sous `ok` rien n'est
+//  conseillé, au-delà de `bad` le conseil passe au rouge.
+//
+//    [score]
+//    profile = "nearfield-studio"
+//    spectrum = 40        # poids, optionnels (total de 100)
+//    level = 25
+//    time = 35
+//    delay_tolerance_ms = 0.05   # optionnels : seuils « ok »
+//    level_tolerance_db = 0.3
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::strings::tr;
+
+/// Profil d'écoute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Profile {
+    NearfieldStudio,
+    #[default]
+    HomeHifi,
+    HomeTheater,
+}
+
+impl Profile {
+    /// Nom du fichier de configuration.
+    pub fn name(self) -> &'static str {
+        match self {
+            Profile::NearfieldStudio => "nearfield-studio",
+            Profile::HomeHifi => "home-hifi",
+            Profile::HomeTheater => "home-theater",
+        }
+    }
+
+    pub fn weights(self) -> ScoreWeights {
+        match self {
+            Profile::NearfieldStudio => ScoreWeights {
+                spectrum: 40.0,
+                level: 25.0,
+                time: 35.0,
+                spectrum_zero_db: 15.0,
+                level_zero_db: 2.0,
+                time_zero_ms: 0.25,
+                delay_ms: Tolerance { ok: 0.02, bad: 0.1 },
+                level_db: Tolerance { ok: 0.2, bad: 1.0 },
+                tilt_db: Tolerance { ok: 0.5, bad: 2.0 },
+                c80_db: Tolerance { ok: 1.0, bad: 2.0 },
+            },
+            Profile::HomeHifi => ScoreWeights {
+                spectrum: 50.0,
+                level: 25.0,
+                time: 25.0,
+                spectrum_zero_db: 25.0,
+                level_zero_db: 5.0,
+                time_zero_ms: 2.5,
+                delay_ms: Tolerance { ok: 0.1, bad: 0.5 },
+                level_db: Tolerance { ok: 0.5, bad: 2.0 },
+                tilt_db: Tolerance { ok: 1.0, bad: 3.0 },
+                c80_db: Tolerance { ok: 2.0, bad: 4.0 },
+            },
+            Profile::HomeTheater => ScoreWeights {
+                spectrum: 50.0,
+                level: 30.0,
+                time: 20.0,
+                spectrum_zero_db: 30.0,
+                level_zero_db: 6.0,
+                time_zero_ms: 10.0,
+                delay_ms: Tolerance { ok: 1.0, bad: 3.0 },
+                level_db: Tolerance { ok: 1.0, bad: 3.0 },
+                tilt_db: Tolerance { ok: 1.5, bad: 4.0 },
+                c80_db: Tolerance { ok: 3.0, bad: 6.0 },
+            },
+        }
+    }
+}
+
+/// Seuils d'un écart (valeur absolue) : conseillé au-delà de `ok`, grave
+/// au-delà de `bad`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    pub ok: f32,
+    pub bad: f32,
+}
+
+impl Tolerance {
+    pub fn exceeded(self, value: f32) -> bool {
+        value.abs() > self.ok
+    }
+
+    pub fn severe(self, value: f32) -> bool {
+        value.abs() > self.bad
+    }
+}
+
+/// Poids et seuils résolus d'un profil.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreWeights {
+    /// Points de chaque composante (total 100).
+    pub spectrum: f32,
+    pub level: f32,
+    pub time: f32,
+    /// Écart qui ramène la composante à zéro : moyenne |D − G| par bande
+    /// (dB), |niveau| (dB), |retard| (ms).
+    pub spectrum_zero_db: f32,
+    pub level_zero_db: f32,
+    pub time_zero_ms: f32,
+    /// Seuils des recommandations.
+    pub delay_ms: Tolerance,
+    pub level_db: Tolerance,
+    pub tilt_db: Tolerance,
+    pub c80_db: Tolerance,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Profile::default().weights()
+    }
+}
+
+/// Section `[score]` du fichier de configuration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoreParams {
+    pub profile: Profile,
+    /// Poids remplaçant ceux du profil ; donnés, les trois font 100.
+    pub spectrum: Option<f32>,
+    pub level: Option<f32>,
+    pub time: Option<f32>,
+    /// Seuils « ok » remplaçant ceux du profil ; le seuil grave garde son
+    /// rapport au seuil « ok ».
+    pub delay_tolerance_ms: Option<f32>,
+    pub level_tolerance_db: Option<f32>,
+}
+
+impl ScoreParams {
+    pub fn weights(&self) -> ScoreWeights {
+        let mut w = self.profile.weights();
+        w.spectrum = self.spectrum.unwrap_or(w.spectrum);
+        w.level = self.level.unwrap_or(w.level);
+        w.time = self.time.unwrap_or(w.time);
+        let scale = |t: Tolerance, ok: Option<f32>| ok.map_or(t, |ok| Tolerance { ok, bad: ok * t.bad / t.ok });
+        w.delay_ms = scale(w.delay_ms, self.delay_tolerance_ms);
+        w.level_db = scale(w.level_db, self.level_tolerance_db);
+        w
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let w = self.weights();
+        if [w.spectrum, w.level, w.time].iter().any(|&p| p <= 0.0) {
+            return Err(tr().score_weights_positive.to_string());
+        }
+        let total = w.spectrum + w.level + w.time;
+        if (total - 100.0).abs() > 0.01 {
+            return Err((tr().score_weights_total)(total));
+        }
+        if w.delay_ms.ok <= 0.0 || w.level_db.ok <= 0.0 {
+            return Err(tr().score_tolerances_positive.to_string());
+        }
+        Ok(())
+    }
+}
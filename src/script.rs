@@ -0,0 +1,309 @@
+// ============================================================
+//  script.rs — Scripts de touches (tests de bout en bout)
+//
+//  `--script fichier` rejoue une suite de touches sur l'état de
+//  l'interface, sans terminal ni rendu, et vérifie l'état obtenu :
+//  la machine à états (capture → analyse → réinitialisation,
+//  saisies de texte, panneaux) se teste comme un utilisateur s'en
+//  sert. Chaque touche passe par `App::handle_key`, la même
+//  fonction que la boucle TUI.
+//
+//  Une commande par ligne ; une ligne commençant par `#` est un
+//  commentaire :
+//
+//    key l               touche : caractère, ou Enter, Esc, Backspace,
+//                        Tab, Delete, Left, Right, Up, Down, Space,
+//                        Insert, End, PageUp, PageDown, F1…F12 ;
+//                        préfixe « ctrl- » (key ctrl-c)
+//    type 21.5 45        chaque caractère du texte, comme des touches
+//    wait 10             dépile les messages audio jusqu'à la fin de la
+//                        capture en cours (délai maximal en s, 10 par défaut)
+//    synth FL 0.5 -2     capture simulée rendue par le thread audio, sans
+//                        carte son : sweep reçu retardé (ms) et atténué (dB)
+//    expect score >= 80  vérification de l'état (voir `Field`)
+//
+//  Opérateurs : == != < <= > >= et ~ (le texte contient).
+//  Le script s'arrête à la première vérification fausse, avec le
+//  numéro de ligne ; [Q] l'arrête aussi (lignes suivantes ignorées).
+// ============================================================
+
+use anyhow::{Context, Result, anyhow, bail};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::{
+    path::Path,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    app::{App, AppState, AudioMsg, Step},
+    audio::{Capture, Channel},
+    dsp,
+    measurement::Measurement,
+};
+
+/// Délai d'attente par défaut de `wait` (secondes).
+const DEFAULT_WAIT_SECS: f32 = 10.0;
+
+/// Coupure basse de l'enceinte simulée par `synth`.
+const SYNTH_HIGHPASS_HZ: f32 = 80.0;
+
+/// Pas de dépilement des messages audio pendant `wait`.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Bilan d'un script exécuté jusqu'au bout (ou jusqu'à [Q]).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScriptReport {
+    /// Commandes exécutées.
+    pub commands: usize,
+    /// Vérifications `expect` passées.
+    pub checks: usize,
+    /// [Q] reçue avant la fin du script.
+    pub quit: bool,
+}
+
+/// Grandeur de l'état vérifiable par `expect`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    /// Étape de la machine à états (`Idle`, `Results`…).
+    Step,
+    Score,
+    DelayMs,
+    LevelDiffDb,
+    /// Nombre d'analyses dans l'historique.
+    History,
+    /// Mesures gauche / droite présentes (`true` / `false`).
+    Left,
+    Right,
+    /// Message d'erreur et d'état (`none` = aucun).
+    Error,
+    Status,
+}
+
+impl Field {
+    fn parse(name: &str) -> Option<Field> {
+        Some(match name {
+            "step" => Field::Step,
+            "score" => Field::Score,
+            "delay_ms" => Field::DelayMs,
+            "level_diff_db" => Field::LevelDiffDb,
+            "history" => Field::History,
+            "left" => Field::Left,
+            "right" => Field::Right,
+            "error" => Field::Error,
+            "status" => Field::Status,
+            _ => return None,
+        })
+    }
+
+    /// Valeur courante : nombre si la grandeur est numérique, sinon texte.
+    fn value(self, state: &AppState) -> Value {
+        match self {
+            Field::Step => Value::Text(format!("{:?}", state.step)),
+            Field::Score => state.score.map_or(Value::Text("none".into()), |s| Value::Number(s as f64)),
+            Field::DelayMs => Value::Number(state.delay_ms as f64),
+            Field::LevelDiffDb => Value::Number(state.level_diff_db as f64),
+            Field::History => Value::Number(state.history.len() as f64),
+            Field::Left => Value::Text(state.left.is_some().to_string()),
+            Field::Right => Value::Text(state.right.is_some().to_string()),
+            Field::Error => Value::Text(state.error.clone().unwrap_or_else(|| "none".into())),
+            Field::Status => Value::Text(state.status.clone().unwrap_or_else(|| "none".into())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Number(f64),
+    Text(String),
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Text(t) => write!(f, "« {} »", t),
+        }
+    }
+}
+
+/// Compare `actual` à `expected` (texte du script) selon `op`.
+fn compare(actual: &Value, op: &str, expected: &str) -> Result<bool> {
+    Ok(match (actual, op) {
+        (Value::Text(t), "~") => t.contains(expected),
+        (Value::Text(t), "==") => t == expected,
+        (Value::Text(t), "!=") => t != expected,
+        // Grandeur présente comparée à son absence
+        (Value::Number(_), "==" | "!=") if expected == "none" => op == "!=",
+        (Value::Number(n), _) => {
+            let e: f64 = expected.parse().with_context(|| format!("nombre attendu : {}", expected))?;
+            match op {
+                "==" => n == &e,
+                "!=" => n != &e,
+                "<" => *n < e,
+                "<=" => *n <= e,
+                ">" => *n > e,
+                ">=" => *n >= e,
+                _ => bail!("opérateur inconnu pour un nombre : {}", op),
+            }
+        }
+        (Value::Text(_), _) => bail!("opérateur inconnu pour un texte : {}", op),
+    })
+}
+
+/// Touche nommée du script.
+fn parse_key(name: &str) -> Result<KeyEvent> {
+    let (modifiers, name) = match name.strip_prefix("ctrl-") {
+        Some(rest) => (KeyModifiers::CONTROL, rest),
+        None => (KeyModifiers::NONE, name),
+    };
+    let code = match name {
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Backspace" => KeyCode::Backspace,
+        "Tab" => KeyCode::Tab,
+        "Delete" => KeyCode::Delete,
+        "Insert" => KeyCode::Insert,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Space" => KeyCode::Char(' '),
+        _ => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => match name.strip_prefix('F').and_then(|n| n.parse().ok()) {
+                    Some(n) => KeyCode::F(n),
+                    None => bail!("touche inconnue : {}", name),
+                },
+            }
+        }
+    };
+    Ok(KeyEvent::new(code, modifiers))
+}
+
+/// Capture simulée du sweep de la session sur `channel`, retardée de
+/// `delay_ms` et atténuée de `gain_db`, remise comme le ferait le thread
+/// audio (`AudioMsg::Measured`). L'enceinte simulée n'a pas de sub-grave
+/// (passe-haut 2e ordre à `SYNTH_HIGHPASS_HZ`), comme une vraie.
+fn synth_capture(state: &mut AppState, channel: Channel, delay_ms: f32, gain_db: f32) {
+    let sr = state.sample_rate;
+    let signal = dsp::generate_sweep(sr, &state.sweep);
+    let start = ((state.pre_delay_secs + delay_ms / 1000.0) * sr as f32).round() as usize;
+    let len = ((state.pre_delay_secs + state.sweep.capture_secs()) * sr as f32) as usize;
+    let gain = 10f32.powf(gain_db / 20.0);
+    let mut mono = vec![0.0; len];
+    for (o, s) in mono.iter_mut().skip(start).zip(&signal) {
+        *o = gain * s;
+    }
+    let mono = dsp::highpass_filter(&dsp::highpass_filter(&mono, SYNTH_HIGHPASS_HZ, sr), SYNTH_HIGHPASS_HZ, sr);
+    let capture = Capture { mono, inputs: Vec::new(), loopback: None };
+    let mut m = Measurement::from_capture(channel, capture, signal, state.sweep, sr, state.calibration.as_ref(), state.analysis);
+    m.deconvolve();
+
+    let (tx, rx) = mpsc::channel();
+    let _ = tx.send(AudioMsg::Measured(Box::new(m)));
+    state.step = match channel {
+        Channel::LEFT => Step::CapturingLeft,
+        Channel::RIGHT => Step::CapturingRight,
+        other => Step::CapturingChannel(other),
+    };
+    state.audio_rx = Some(rx);
+    state.poll_audio();
+}
+
+/// Dépile les messages audio jusqu'à la fin de la capture, au plus `secs`.
+fn wait_capture(state: &mut AppState, secs: f32) -> Result<()> {
+    let deadline = Instant::now() + Duration::from_secs_f32(secs);
+    loop {
+        state.poll_audio();
+        if !state.step.is_capturing() {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            bail!("capture toujours en cours après {} s ({:?})", secs, state.step);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Exécute une ligne ; faux = [Q] reçue.
+fn run_line(state: &mut AppState, line: &str, report: &mut ScriptReport) -> Result<bool> {
+    let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let rest = rest.trim();
+    let args: Vec<&str> = rest.split_whitespace().collect();
+    match command {
+        "key" => {
+            let [name] = args[..] else { bail!("key : une touche attendue") };
+            let key = parse_key(name)?;
+            let running = App::handle_key(state, key);
+            state.poll_audio();
+            return Ok(running);
+        }
+        "type" => {
+            for c in rest.chars() {
+                if !App::handle_key(state, KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)) {
+                    return Ok(false);
+                }
+            }
+        }
+        "wait" => {
+            let secs = match args[..] {
+                [] => DEFAULT_WAIT_SECS,
+                [s] => s.parse().with_context(|| format!("wait : durée illisible : {}", s))?,
+                _ => bail!("wait : une durée au plus"),
+            };
+            wait_capture(state, secs)?;
+        }
+        "synth" => {
+            let [channel, delay, gain] = args[..] else { bail!("synth : canal, retard (ms) et gain (dB) attendus") };
+            let channel = Channel::from_name(channel).ok_or_else(|| anyhow!("synth : canal inconnu : {}", channel))?;
+            let delay_ms: f32 = delay.parse().with_context(|| format!("synth : retard illisible : {}", delay))?;
+            let gain_db: f32 = gain.parse().with_context(|| format!("synth : gain illisible : {}", gain))?;
+            synth_capture(state, channel, delay_ms, gain_db);
+        }
+        "expect" => {
+            let mut parts = rest.splitn(3, char::is_whitespace);
+            let (Some(name), Some(op), Some(expected)) = (parts.next(), parts.next(), parts.next()) else {
+                bail!("expect : grandeur, opérateur et valeur attendus");
+            };
+            let field = Field::parse(name).ok_or_else(|| anyhow!("expect : grandeur inconnue : {}", name))?;
+            let actual = field.value(state);
+            if !compare(&actual, op, expected.trim())? {
+                bail!("{} vaut {}, attendu {} {}", name, actual, op, expected.trim());
+            }
+            report.checks += 1;
+        }
+        _ => bail!("commande inconnue : {}", command),
+    }
+    Ok(true)
+}
+
+/// Exécute le texte d'un script sur `state`.
+pub fn run(state: &mut AppState, script: &str) -> Result<ScriptReport> {
+    let mut report = ScriptReport::default();
+    for (i, line) in script.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let running = run_line(state, line, &mut report).with_context(|| format!("ligne {} : {}", i + 1, line))?;
+        report.commands += 1;
+        if !running {
+            report.quit = true;
+            break;
+        }
+    }
+    Ok(report)
+}
+
+/// Exécute le script `path` (`--script`).
+pub fn run_file(state: &mut AppState, path: &Path) -> Result<ScriptReport> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("Impossible de lire le script {}", path.display()))?;
+    run(state, &text).with_context(|| format!("Script {}", path.display()))
+}
@@ -0,0 +1,146 @@
+// ============================================================
+//  seats.rs — Mesures à plusieurs places d'écoute nommées
+//
+//  Un canapé a plusieurs places : un écart G/D mesuré à une seule
+//  d'entre elles peut venir du placement des enceintes (il se
+//  retrouve partout) ou de la place elle-même (creux de mode,
+//  accoudoir, mur proche). Chaque analyse peut être rangée sous
+//  un nom de place ([:], « canapé gauche », « canapé centre ») ;
+//  sur l'ensemble des places, pour le retard, le niveau et
+//  chaque zone spectrale :
+//    - placement : hors tolérance à TOUTES les places, même sens ;
+//    - anomalie  : hors tolérance à une seule place, les autres
+//                  dans la tolérance — ne pas déplacer les
+//                  enceintes pour elle.
+//  La dispersion de D − G d'une place à l'autre (écart-type par
+//  bande) dit aussi quelles fréquences dépendent de la place.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::dsp::{self, SPECTRAL_REGIONS};
+
+/// Longueur maximale d'un nom de place (caractères).
+pub const MAX_NAME_LEN: usize = 24;
+
+/// Tolérances au-delà desquelles une place est « hors tolérance ».
+pub const DELAY_LIMIT_MS: f32 = 0.2;
+pub const LEVEL_LIMIT_DB: f32 = 0.5;
+pub const REGION_LIMIT_DB: f32 = 2.0;
+
+/// Analyse G/D rangée sous un nom de place.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Seat {
+    pub name: String,
+    pub time: String,
+    pub score: u32,
+    pub delay_ms: f32,
+    pub level_diff_db: f32,
+    pub left_db: Vec<f32>,
+    pub right_db: Vec<f32>,
+}
+
+impl Seat {
+    pub fn diff_db(&self) -> Vec<f32> {
+        self.right_db.iter().zip(&self.left_db).map(|(r, l)| r - l).collect()
+    }
+}
+
+/// Grandeur comparée d'une place à l'autre.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Delay,
+    Level,
+    /// Indice dans `dsp::SPECTRAL_REGIONS`.
+    Region(usize),
+}
+
+impl Metric {
+    fn limit(self) -> f32 {
+        match self {
+            Metric::Delay => DELAY_LIMIT_MS,
+            Metric::Level => LEVEL_LIMIT_DB,
+            Metric::Region(_) => REGION_LIMIT_DB,
+        }
+    }
+}
+
+/// Conclusion sur une grandeur.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Finding {
+    /// Même écart à toutes les places : moyenne et écart-type.
+    Placement { metric: Metric, mean: f32, sd: f32 },
+    /// Écart à une seule place (indice dans la liste) : sa valeur.
+    SeatAnomaly { metric: Metric, seat: usize, value: f32 },
+}
+
+/// Dispersion d'une place à l'autre.
+#[derive(Debug, Clone)]
+pub struct SeatStats {
+    /// D − G moyen et écart-type de chaque bande (dB).
+    pub diff_mean_db: Vec<f32>,
+    pub diff_sd_db: Vec<f32>,
+    /// Écart-type moyen de D − G dans chaque zone de `SPECTRAL_REGIONS`.
+    pub region_sd_db: Vec<f32>,
+    pub findings: Vec<Finding>,
+}
+
+fn mean_sd(values: &[f32]) -> (f32, f32) {
+    let n = values.len().max(1) as f32;
+    let mean = values.iter().sum::<f32>() / n;
+    (mean, (values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / n).sqrt())
+}
+
+fn classify(metric: Metric, values: &[f32]) -> Option<Finding> {
+    let limit = metric.limit();
+    let (mean, sd) = mean_sd(values);
+    let outside: Vec<usize> = (0..values.len()).filter(|&i| values[i].abs() > limit).collect();
+    if outside.len() == values.len() && values.iter().all(|v| v.signum() == mean.signum()) {
+        return Some(Finding::Placement { metric, mean, sd });
+    }
+    match outside.as_slice() {
+        &[seat] => Some(Finding::SeatAnomaly { metric, seat, value: values[seat] }),
+        _ => None,
+    }
+}
+
+/// Statistiques sur les places ; `None` sous deux places.
+pub fn stats(seats: &[Seat]) -> Option<SeatStats> {
+    if seats.len() < 2 {
+        return None;
+    }
+    let diffs: Vec<Vec<f32>> = seats.iter().map(Seat::diff_db).collect();
+    let bands = diffs.iter().map(Vec::len).min().unwrap_or(0);
+    let (diff_mean_db, diff_sd_db): (Vec<f32>, Vec<f32>) = (0..bands)
+        .map(|b| mean_sd(&diffs.iter().map(|d| d[b]).collect::<Vec<f32>>()))
+        .unzip();
+
+    let column = |get: fn(&Seat) -> f32| seats.iter().map(get).collect::<Vec<f32>>();
+    let mut findings: Vec<Finding> = [
+        classify(Metric::Delay, &column(|s| s.delay_ms)),
+        classify(Metric::Level, &column(|s| s.level_diff_db)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    let regions: Vec<Vec<f32>> = diffs.iter().map(|d| dsp::region_means(d)).collect();
+    findings.extend(
+        (0..SPECTRAL_REGIONS.len())
+            .filter_map(|k| classify(Metric::Region(k), &regions.iter().map(|r| r[k]).collect::<Vec<f32>>())),
+    );
+
+    Some(SeatStats {
+        region_sd_db: dsp::region_means(&diff_sd_db),
+        diff_mean_db,
+        diff_sd_db,
+        findings,
+    })
+}
+
+/// Range `seat` dans `seats`, en remplaçant une place du même nom.
+pub fn record(seats: &mut Vec<Seat>, seat: Seat) {
+    match seats.iter_mut().find(|s| s.name == seat.name) {
+        Some(existing) => *existing = seat,
+        None => seats.push(seat),
+    }
+}
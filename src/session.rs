@@ -0,0 +1,181 @@
+// ============================================================
+//  session.rs — Sauvegarde / chargement de session (.spkalign)
+//
+//  Sérialise (JSON via serde) les captures, les spectres en bandes,
+//  les résultats d'analyse, l'historique, le journal des
+//  changements, les positions candidates, les places d'écoute
+//  nommées et l'asymétrie acceptée, pour reprendre le réglage du
+//  placement plus tard sans tout recapturer.
+//
+//  Partage ([@]) : le même contenu compressé en gzip, réglages du
+//  sweep compris, sans l'audio brut (capture, signal de test) par
+//  défaut — quelques centaines de ko à joindre sur un forum.
+//  `load` reconnaît les deux formes ; `--import` ouvre un partage.
+// ============================================================
+
+use anyhow::{Context, Result};
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::Read,
+    path::Path,
+};
+
+use crate::{
+    app::{AppState, HistoryEntry, Step},
+    audio::Channel,
+    baseline::AcceptedAsymmetry,
+    dsp::SweepParams,
+    experiments::ExperimentLog,
+    notes::MeasurementNotes,
+    positions::CandidatePosition,
+    schema,
+    seats::Seat,
+    measurement::Measurement,
+    strings::tr,
+};
+
+/// Chemin utilisé par défaut par les touches [S] / [O].
+pub const DEFAULT_SESSION_PATH: &str = "session.spkalign";
+
+/// Fichier écrit par [@] (session partagée).
+pub const SHARED_SESSION_PATH: &str = "shared.spkalign";
+
+/// En-tête gzip : distingue un partage d'une session JSON brute.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Version du format ; incrémentée à chaque changement incompatible
+/// (migrations dans `schema`).
+pub use crate::schema::SESSION_VERSION;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub version: u32,
+    pub left: Option<Measurement>,
+    pub right: Option<Measurement>,
+    /// Enceintes supplémentaires (C, SL, SR…) et référence des comparaisons.
+    pub others: Vec<Measurement>,
+    pub reference: Channel,
+    pub diff_db: Option<Vec<f32>>,
+    pub delay_ms: f32,
+    pub level_diff_db: f32,
+    pub freq_tilt: f32,
+    pub score: Option<u32>,
+    pub history: Vec<HistoryEntry>,
+    /// Absent des sessions antérieures au journal : vide.
+    #[serde(default)]
+    pub experiments: ExperimentLog,
+    #[serde(default)]
+    pub positions: Vec<CandidatePosition>,
+    #[serde(default)]
+    pub seats: Vec<Seat>,
+    #[serde(default)]
+    pub baseline: Option<AcceptedAsymmetry>,
+    /// Notes de la prochaine analyse (micro, enceintes, pièce reportés).
+    #[serde(default)]
+    pub notes: MeasurementNotes,
+    pub pre_delay_secs: f32,
+    /// Réglages du sweep au moment de l'enregistrement.
+    #[serde(default)]
+    pub sweep: Option<SweepParams>,
+    /// Version d'origine d'un fichier migré au chargement (jamais écrite).
+    #[serde(skip)]
+    pub migrated_from: Option<u32>,
+}
+
+impl Session {
+    pub fn from_state(state: &AppState) -> Self {
+        Session {
+            version: SESSION_VERSION,
+            left: state.left.clone(),
+            right: state.right.clone(),
+            others: state.others.clone(),
+            reference: state.reference,
+            diff_db: state.diff_db.clone(),
+            delay_ms: state.delay_ms,
+            level_diff_db: state.level_diff_db,
+            freq_tilt: state.freq_tilt,
+            score: state.score,
+            history: state.history.clone(),
+            experiments: state.experiments.clone(),
+            positions: state.positions.clone(),
+            seats: state.seats.clone(),
+            baseline: state.baseline.clone(),
+            notes: state.notes.clone(),
+            pre_delay_secs: state.pre_delay_secs,
+            sweep: Some(state.sweep),
+            migrated_from: None,
+        }
+    }
+
+    /// Restaure la session dans l'état de l'application.
+    pub fn apply(self, state: &mut AppState) {
+        state.left = self.left;
+        state.right = self.right;
+        state.others = self.others;
+        state.reference = self.reference;
+        state.diff_db = self.diff_db;
+        state.delay_ms = self.delay_ms;
+        state.level_diff_db = self.level_diff_db;
+        state.freq_tilt = self.freq_tilt;
+        state.score = self.score;
+        state.history = self.history;
+        state.experiments = self.experiments;
+        state.positions = self.positions;
+        state.seats = self.seats;
+        state.baseline = self.baseline;
+        state.notes = self.notes;
+        state.pre_delay_secs = self.pre_delay_secs;
+        if let Some(sweep) = self.sweep {
+            state.sweep = sweep;
+        }
+        state.step = if state.score.is_some() { Step::Results } else { Step::Idle };
+        if !state.others.is_empty() {
+            state.compare_speakers();
+        }
+    }
+}
+
+pub fn save(state: &AppState, path: &Path) -> Result<()> {
+    let json = serde_json::to_string(&Session::from_state(state))?;
+    fs::write(path, json)
+        .with_context(|| (tr().cannot_write)(&path.display().to_string()))
+}
+
+/// Écrit la session compressée pour la partager ; sans `with_audio`, les
+/// mesures perdent leur audio brut (`Measurement::strip_audio`). Renvoie la
+/// taille du fichier (octets).
+pub fn save_shared(state: &AppState, path: &Path, with_audio: bool) -> Result<usize> {
+    let mut session = Session::from_state(state);
+    if !with_audio {
+        let all = session.left.iter_mut().chain(session.right.iter_mut()).chain(session.others.iter_mut());
+        all.for_each(Measurement::strip_audio);
+    }
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    serde_json::to_writer(&mut encoder, &session)?;
+    let bytes = encoder.finish()?;
+    fs::write(path, &bytes)
+        .with_context(|| (tr().cannot_write)(&path.display().to_string()))?;
+    Ok(bytes.len())
+}
+
+pub fn load(path: &Path) -> Result<Session> {
+    let bytes = fs::read(path)
+        .with_context(|| (tr().session_unreadable)(&path.display().to_string()))?;
+    let json = if bytes.starts_with(&GZIP_MAGIC) {
+        let mut json = String::new();
+        GzDecoder::new(bytes.as_slice())
+            .read_to_string(&mut json)
+            .with_context(|| (tr().session_shared_unreadable)(&path.display().to_string()))?;
+        json
+    } else {
+        String::from_utf8(bytes).with_context(|| (tr().session_invalid)(&path.display().to_string()))?
+    };
+    let invalid = || (tr().session_invalid)(&path.display().to_string());
+    let value: serde_json::Value = serde_json::from_str(&json).with_context(invalid)?;
+    let (value, version) = schema::migrate_session(value).with_context(invalid)?;
+    let mut session: Session = serde_json::from_value(value).with_context(invalid)?;
+    session.migrated_from = (version != SESSION_VERSION).then_some(version);
+    Ok(session)
+}
@@ -0,0 +1,2219 @@
+// ============================================================
+//  strings.rs — Textes de l'interface (français / anglais)
+//
+//  Tous les textes affichés par ui.rs, la barre d'état, les
+//  erreurs (audio, configuration, fichiers) et les messages du
+//  mode headless et de la surveillance passent par une table
+//  `Strings`, une par langue.
+//  La langue est globale (le thread audio formate aussi ses
+//  erreurs) : choisie par `--lang`, basculée par [T].
+//  Les textes paramétrés sont des fonctions de formatage ; leurs
+//  nombres passent par `format_num` et voisins, qui appliquent le
+//  séparateur décimal de la langue.
+// ============================================================
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    Fr,
+    En,
+}
+
+impl Lang {
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::Fr => "fr",
+            Lang::En => "en",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Lang> {
+        match code.trim().to_lowercase().as_str() {
+            "fr" => Some(Lang::Fr),
+            "en" => Some(Lang::En),
+            _ => None,
+        }
+    }
+
+    pub fn toggle(self) -> Lang {
+        match self {
+            Lang::Fr => Lang::En,
+            Lang::En => Lang::Fr,
+        }
+    }
+
+    /// Séparateur décimal des nombres affichés. Les fichiers exportés gardent
+    /// toujours le point : ils sont relus par d'autres outils.
+    pub fn decimal_separator(self) -> char {
+        match self {
+            Lang::Fr => ',',
+            Lang::En => '.',
+        }
+    }
+
+    pub fn strings(self) -> &'static Strings {
+        match self {
+            Lang::Fr => &FR,
+            Lang::En => &EN,
+        }
+    }
+}
+
+static LANG: AtomicU8 = AtomicU8::new(0);
+
+/// Langue courante.
+pub fn lang() -> Lang {
+    match LANG.load(Ordering::Relaxed) {
+        1 => Lang::En,
+        _ => Lang::Fr,
+    }
+}
+
+pub fn set_lang(lang: Lang) {
+    LANG.store(lang as u8, Ordering::Relaxed);
+}
+
+/// Table de textes de la langue courante.
+pub fn tr() -> &'static Strings {
+    lang().strings()
+}
+
+/// « 40 Hz », « 2,5 kHz », « 20 kHz » : fréquence dans le texte.
+pub fn format_hz(hz: f32) -> String {
+    if hz >= 1000.0 {
+        format!("{} kHz", localized(format!("{}", (hz / 100.0).round() / 10.0)))
+    } else {
+        format!("{:.0} Hz", hz)
+    }
+}
+
+/// Nombre à `decimals` décimales avec le séparateur de la langue
+/// (« 1,25 » en français). Réservé aux textes affichés : les fichiers
+/// exportés formatent eux-mêmes avec le point.
+pub fn format_num(value: impl Into<f64>, decimals: usize) -> String {
+    localized(format!("{:.*}", decimals, value.into()))
+}
+
+/// Comme [`format_num`], toujours signé (« +0,5 », « -3,0 »).
+pub fn format_signed(value: impl Into<f64>, decimals: usize) -> String {
+    localized(format!("{:+.*}", decimals, value.into()))
+}
+
+/// Nombre sans arrondi (« 5,25 ») pour les valeurs saisies telles quelles.
+pub fn format_short(value: f32) -> String {
+    localized(value.to_string())
+}
+
+fn localized(text: String) -> String {
+    match lang().decimal_separator() {
+        '.' => text,
+        sep => text.replace('.', &sep.to_string()),
+    }
+}
+
+pub struct Strings {
+    // ─── En-tête ───
+    pub ready: &'static str,
+    pub subtitle: &'static str,
+    pub output_label: &'static str,
+    pub input_label: &'static str,
+    pub mic_calibrated: fn(&str, usize) -> String,
+    pub mic_uncalibrated: &'static str,
+
+    // ─── Délai pré-capture ───
+    pub delay_title: &'static str,
+
+    // ─── Boutons de capture ───
+    pub capturing: fn(&str, f32) -> String,
+    pub left_title: &'static str,
+    pub left_done: &'static str,
+    pub left_prompt: &'static str,
+    pub left_note: &'static str,
+    pub right_title: &'static str,
+    pub right_done: &'static str,
+    pub right_prompt: &'static str,
+    pub right_note: &'static str,
+
+    // ─── Progression ───
+    pub capture_left: &'static str,
+    pub capture_right: &'static str,
+    pub capture_pair: &'static str,
+    pub capture_channel: fn(&str) -> String,
+    pub capture_sub: &'static str,
+    pub bass_test: &'static str,
+    pub capture_noise: &'static str,
+    pub capture_level: &'static str,
+    pub probe_capture: fn(&str) -> String,
+    pub pause: fn(f32) -> String,
+    pub capture_cancel_hint: &'static str,
+    pub phase_pre_delay: &'static str,
+    pub phase_playing: &'static str,
+    pub phase_tail: &'static str,
+    pub phase_averaging: &'static str,
+    pub phase_deconvolving: &'static str,
+    pub phase_scheduled: &'static str,
+    /// Départ programmé (« :30 ») et attente restante (s).
+    pub scheduled_start: fn(&str, f32) -> String,
+    pub schedule_prompt: &'static str,
+    pub schedule_off: &'static str,
+    pub window_hint: fn(usize, usize) -> String,
+    pub window_then_analyze: &'static str,
+    pub window_then_capture: &'static str,
+    pub both_captured: &'static str,
+    pub analyze_key: &'static str,
+    pub idle_hint: &'static str,
+
+    // ─── Côtés ───
+    /// Initiales (« G » / « D »).
+    pub left_short: &'static str,
+    pub right_short: &'static str,
+    /// Majuscules, pour les libellés de capture.
+    pub left_upper: &'static str,
+    pub right_upper: &'static str,
+    /// Légendes des courbes.
+    pub left_name: &'static str,
+    pub right_name: &'static str,
+    /// Minuscules, dans une phrase.
+    pub left_word: &'static str,
+    pub region_names: [&'static str; 5],
+    pub reco_region: fn(&str, &str, f32) -> String,
+    pub right_word: &'static str,
+    pub measuring: &'static str,
+
+    // ─── Spectre et réponse impulsionnelle ───
+    pub spectrum_title: &'static str,
+    /// Titre du spectre lissé : dénominateur N du 1/N d'octave.
+    pub spectrum_title_smoothed: fn(u32) -> String,
+    /// Légende des modes de la pièce sur le spectre.
+    pub room_modes_legend: &'static str,
+    /// Mode proche du curseur : indices, fréquence (Hz).
+    pub room_mode_near: fn(&str, f32) -> String,
+    /// Fenêtre temporelle (ms) et fréquence de raccord (Hz).
+    pub spectrum_gated: fn(f32, f32) -> String,
+    /// Fenêtre temporelle choisie [9] : aucune, durée (ms) et fréquence de
+    /// raccord (Hz), ou durée sans effet (mesures sans audio).
+    pub gate_off: &'static str,
+    pub gate_on: fn(f32, f32) -> String,
+    pub gate_unavailable: fn(f32) -> String,
+    /// Noms des fenêtres FFT, dans l'ordre de `dsp::Window::ALL`.
+    pub window_names: [&'static str; 4],
+    pub overlay_a_left: &'static str,
+    pub overlay_a_right: &'static str,
+    pub overlay_b_left: &'static str,
+    pub overlay_b_right: &'static str,
+    pub overlay_title: fn(usize, &str, usize, &str) -> String,
+    pub overlay_keys: &'static str,
+    pub overlay_no_curves: &'static str,
+    pub overlay_score: &'static str,
+    pub overlay_delay: &'static str,
+    pub overlay_level: &'static str,
+    pub overlay_mismatch: &'static str,
+    pub overlay_shift: fn(f32, f32) -> String,
+    /// Relevé Markdown de la comparaison ([F6]) : fichier, lignes du
+    /// tableau, graphique par octave, messages.
+    pub forum_file: &'static str,
+    pub forum_delay: &'static str,
+    pub forum_level: &'static str,
+    pub forum_mismatch: &'static str,
+    pub forum_shift: fn(f32, f32) -> String,
+    pub forum_better: &'static str,
+    pub forum_worse: &'static str,
+    pub forum_legend: fn(f32) -> String,
+    pub compare_needs_two: &'static str,
+    pub forum_no_curves: &'static str,
+    pub forum_written: fn(usize, usize, &str) -> String,
+    pub write_failed: fn(&str, &str) -> String,
+    pub spectrum_empty: &'static str,
+    pub ir_title: &'static str,
+    pub band_delay_title: &'static str,
+    pub band_delay_constant: fn(f32) -> String,
+    pub band_delay_dispersed: fn(f32) -> String,
+    pub reco_dispersion: fn(f32) -> String,
+    /// Passe-tout proposés (« G 1 kHz 1er ordre, D 2 kHz Q 1.5 »).
+    pub reco_allpass: fn(&str) -> String,
+    pub allpass_first_order: &'static str,
+    pub ir_empty: &'static str,
+
+    // ─── Fenêtre d'écoute ───
+    pub grid_title: fn(usize, usize) -> String,
+    pub grid_speakers: &'static str,
+    pub grid_seat: &'static str,
+    pub grid_spacing: fn(f32) -> String,
+    pub grid_complete: &'static str,
+    pub pos_center: &'static str,
+    pub pos_front: &'static str,
+    pub pos_back: &'static str,
+    pub hint_side: fn(f32, &str) -> String,
+    pub hint_forward: fn(f32) -> String,
+    pub hint_backward: fn(f32) -> String,
+    pub hint_seat: &'static str,
+    pub hint_from_center: fn(&str, f32) -> String,
+
+    // ─── Multicanal ───
+    pub speakers_title: fn(&str) -> String,
+    pub col_channel: &'static str,
+    pub col_distance: &'static str,
+    pub col_delay: &'static str,
+    pub col_level: &'static str,
+    pub col_score: &'static str,
+    pub col_trim: &'static str,
+    pub col_set_delay: &'static str,
+    pub speakers_reference: &'static str,
+    pub speakers_pending: &'static str,
+    pub speakers_trims_hint: fn(&str) -> String,
+
+    // ─── Caisson ───
+    pub sub_title: fn(&str) -> String,
+    pub sub_need_left: &'static str,
+    pub sub_unavailable: &'static str,
+    pub sub_delay_sub: &'static str,
+    pub sub_delay_mains: &'static str,
+    pub avr_decrease: fn(f32) -> String,
+    pub avr_increase: fn(f32) -> String,
+    pub sub_arrival: fn(f32) -> String,
+    pub sub_phase_at: fn(f32) -> String,
+    pub sub_summation: fn(f32) -> String,
+    pub sub_action: fn(&str) -> String,
+    pub multisub_title: fn(usize) -> String,
+    pub multisub_capture: fn(&str, usize, usize) -> String,
+    pub multisub_hint: &'static str,
+    pub multisub_spread: fn(f32, f32, f32) -> String,
+    pub multisub_setting: fn(&str, f32, f32, bool) -> String,
+
+    // ─── Bass management ───
+    pub bass_title: &'static str,
+    pub route_main: &'static str,
+    pub route_sub: &'static str,
+    pub route_absent: &'static str,
+    pub bass_ok: fn(f32, f32) -> String,
+    pub bass_mismatch: fn(f32, f32) -> String,
+    pub bass_none: fn(f32) -> String,
+
+    // ─── Polarité ───
+    pub polarity_title: &'static str,
+    pub pol_uncertain: &'static str,
+    pub pol_normal: &'static str,
+    pub pol_inverted: &'static str,
+    pub pol_ok: &'static str,
+    pub pol_mismatch: fn(&str) -> String,
+
+    // ─── Rub & buzz ───
+    pub rub_title: &'static str,
+    pub rub_ok: &'static str,
+    pub rub_item: fn(&str, f32, f32) -> String,
+    pub rub_suspect: fn(&str) -> String,
+
+    // ─── Compression ───
+    pub compression_title: fn(f32, f32) -> String,
+    pub compression_ok: &'static str,
+    pub compression_bad: fn(&str) -> String,
+
+    // ─── Linéarité ───
+    pub linearity_title: fn(f32, f32) -> String,
+    pub linearity_row: fn(f32, f32) -> String,
+    pub linearity_ok: &'static str,
+    pub linearity_bad: &'static str,
+
+    // ─── Traitements du système ───
+    pub enh_title: &'static str,
+    pub enh_pumping: fn(&str, f32) -> String,
+    pub enh_gain_riding: fn(&str, f32) -> String,
+    pub enh_crossfeed: fn(&str, f32, f32, bool) -> String,
+    pub enh_hint_windows: &'static str,
+    pub enh_hint_other: &'static str,
+
+    // ─── Câblage des canaux ───
+    pub map_title: &'static str,
+    pub map_ok: &'static str,
+    pub map_applied: fn(usize, usize) -> String,
+    pub map_uncertain: &'static str,
+    pub map_next: &'static str,
+
+    // ─── Vérification rapide ───
+    pub quick_title: fn(&str) -> String,
+    pub stim_dual_tone: &'static str,
+    pub stim_white_noise: &'static str,
+    pub quick_ok: &'static str,
+    pub quick_missing: fn(&str, &str) -> String,
+
+    // ─── Distances rapides ───
+    pub distance_title: &'static str,
+    /// Distance gauche, droite (m).
+    pub distance_values: fn(f32, f32) -> String,
+    /// Retard D − G (ms), Δ distance (cm).
+    pub distance_reading: fn(f32, f32) -> String,
+    pub distance_relative: &'static str,
+
+    // ─── Moyenne spatiale (micro en mouvement) ───
+    pub mmm_title: &'static str,
+    pub mmm_blocks: fn(usize) -> String,
+    pub mmm_diff: &'static str,
+    pub mmm_mismatch: fn(f32) -> String,
+
+    // ─── Bruit de fond ───
+    pub noise_name: &'static str,
+    pub noise_title: fn(f32) -> String,
+    pub noise_snr_ok: fn(&str, f32) -> String,
+    pub noise_snr_low: fn(&str, usize, f32, &str) -> String,
+
+    // ─── Suivi en direct ───
+    pub watch_title: &'static str,
+    pub watch_capture: fn(&str) -> String,
+    pub watch_reading: fn(f32, f32) -> String,
+    pub watch_trend: &'static str,
+    pub watch_aligned: &'static str,
+    pub watch_nudge: &'static str,
+    pub dual_title: &'static str,
+    pub dual_capture: &'static str,
+    /// Δ niveau D − G (dB), Δ distance (cm).
+    pub dual_reading: fn(f32, f32) -> String,
+    pub dual_regions: &'static str,
+    pub dual_trend: &'static str,
+    pub dual_balanced: &'static str,
+    /// Côté le plus fort (« droite »), écart (dB).
+    pub dual_louder: fn(&str, f32) -> String,
+
+    // ─── Tests G/D ───
+    pub test_polarity: &'static str,
+    pub test_rub_buzz: &'static str,
+    pub test_compression: &'static str,
+    pub test_linearity: &'static str,
+    pub test_quick: &'static str,
+    pub test_distance: &'static str,
+    pub test_mmm: &'static str,
+
+    // ─── Score et métriques ───
+    pub score_title: &'static str,
+    pub score_title_range: fn(&str, &str) -> String,
+    pub profile_studio: &'static str,
+    pub profile_theater: &'static str,
+    pub rating_excellent: &'static str,
+    pub rating_adjust: &'static str,
+    pub rating_fix: &'static str,
+    /// Parts du score (`dsp::ScoreBreakdown`), suivies du nombre de points.
+    pub score_placement: &'static str,
+    pub score_electrical: &'static str,
+    pub distances: &'static str,
+    pub distances_need_sweep: &'static str,
+    pub target_label: &'static str,
+    pub target_name: &'static str,
+    pub mask_name: &'static str,
+    pub distances_latency: fn(f32) -> String,
+    pub distances_drift: fn(f32) -> String,
+    pub iacc_need: &'static str,
+    pub meter_delay: &'static str,
+    pub meter_level: &'static str,
+    pub meter_spectrum: &'static str,
+    pub window_summary: fn(usize) -> String,
+    pub run_analysis: &'static str,
+    pub clarity_title: &'static str,
+    pub reverb_title: &'static str,
+    pub thd_title: &'static str,
+    /// Arbre des métriques : noms courts des domaines repliés, octave la
+    /// plus distordue, légende des touches.
+    pub metric_clarity: &'static str,
+    pub metric_reverb: &'static str,
+    pub metric_thd: &'static str,
+    pub metrics_thd_at: fn(&str) -> String,
+    pub metrics_next: &'static str,
+    pub metrics_fold: &'static str,
+    /// Rapport HTML ([End]) : titres des sections, lignes des tableaux.
+    pub report_title: &'static str,
+    pub report_measured: fn(&str, &str, &str) -> String,
+    pub report_metrics: &'static str,
+    pub report_speakers: &'static str,
+    pub report_response: &'static str,
+    pub report_diff: &'static str,
+    pub report_history: &'static str,
+    pub report_delay: &'static str,
+    pub report_level: &'static str,
+    pub report_tilt: &'static str,
+    pub report_profile: &'static str,
+    pub report_distance: &'static str,
+    pub report_t30: &'static str,
+    pub report_thd: &'static str,
+    pub report_target: &'static str,
+    pub report_footer: &'static str,
+    pub report_notes: &'static str,
+
+    // ─── Recommandations ───
+    pub reco_title: &'static str,
+    pub reco_pending: &'static str,
+    /// Part du score la plus faible : ce qu'il faut faire en premier.
+    pub reco_bucket_placement: &'static str,
+    pub reco_bucket_electrical: &'static str,
+    pub checklist_title: &'static str,
+    /// Points de `checklist::ITEMS` et mesure qui les vérifie.
+    pub checklist_items: [(&'static str, &'static str); 4],
+    pub checklist_auto: &'static str,
+    pub checklist_problem: &'static str,
+    pub checklist_hint: &'static str,
+    pub verdict_title: &'static str,
+    pub verdict_pending: &'static str,
+    pub verdict_good: &'static str,
+    pub verdict_adjust: &'static str,
+    pub verdict_fix: &'static str,
+    pub reco_closer: &'static str,
+    pub reco_farther: &'static str,
+    /// Même conseil pour la gauche, quand `[placement]` bloque la droite.
+    pub reco_left_closer: &'static str,
+    pub reco_left_farther: &'static str,
+    /// Côté, délai (ms), distance équivalente (cm).
+    pub reco_channel_delay: fn(&str, f32, f32) -> String,
+    /// Écart D − G attendu après le déplacement (dB).
+    pub reco_level_after: fn(f32) -> String,
+    pub reco_distance_blocked: &'static str,
+    pub reco_distance_blocked_hint: &'static str,
+    pub reco_too_loud: &'static str,
+    pub reco_too_quiet: &'static str,
+    pub reco_level: fn(f32) -> String,
+    /// Réglage dans les unités de l'appareil (`gain.rs`) : côté, pas, dB appliqués.
+    pub reco_gain_trim: fn(&str, u32, f32) -> String,
+    pub reco_gain_balance: fn(&str, u32, f32) -> String,
+    pub reco_gain_detents: fn(&str, u32, f32) -> String,
+    pub reco_gain_residual: fn(f32) -> String,
+    pub reco_too_bright: &'static str,
+    pub reco_too_dull: &'static str,
+    pub reco_clarity: fn(&str) -> String,
+    /// Réflexions (surfaces), côté où elle est plus forte, écart (dB).
+    pub reco_reflection: fn(&str, &str, f32) -> String,
+    /// Surfaces de `reflections::Surface`, dans l'ordre de `Surface::ALL`.
+    pub surface_names: [&'static str; 4],
+    /// « sol ou mur latéral ».
+    pub or_word: &'static str,
+    pub reflections_label: &'static str,
+    pub reco_sign_mismatch: fn(&str) -> String,
+    pub reco_sign_mismatch_hint: &'static str,
+    pub reco_swapped: &'static str,
+    pub reco_swapped_hint: &'static str,
+    /// Âge des vérifications (jours), dans l'en-tête.
+    pub age_mic_cal: fn(u64) -> String,
+    pub age_loopback: fn(u64) -> String,
+    pub age_loopback_never: &'static str,
+    /// Rappels de re-vérification (jours écoulés).
+    pub reminder_mic_stale: fn(u64) -> String,
+    pub reminder_mic_device: &'static str,
+    pub reminder_mic_hint: &'static str,
+    pub reminder_loopback_unmeasured: &'static str,
+    pub reminder_loopback_stale: fn(u64) -> String,
+    pub reminder_loopback_device: &'static str,
+    pub reminder_loopback_hint: &'static str,
+    pub reco_reversed: fn(&str) -> String,
+    pub reco_reversed_hint: &'static str,
+    pub reco_reversed_both: &'static str,
+    pub reco_weak_direct: fn(&str) -> String,
+    pub reco_weak_direct_hint: &'static str,
+    pub reco_optimal: &'static str,
+    pub reco_symmetric: &'static str,
+
+    // ─── Historique ───
+    pub history_title: &'static str,
+    pub history_trend_title: &'static str,
+    pub experiments_title: &'static str,
+    pub positions_title: fn(usize) -> String,
+    pub positions_header: &'static str,
+    pub experiment_prompt: &'static str,
+    pub experiment_hint: &'static str,
+    pub experiment_pending: &'static str,
+    pub seats_title: fn(usize) -> String,
+    pub seats_header: &'static str,
+    /// Écart-type de D − G d'une place à l'autre, par zone.
+    pub seats_spread: &'static str,
+    pub seat_prompt: &'static str,
+    /// Nombre d'analyses de l'historique.
+    pub environment_title: fn(usize) -> String,
+    pub environment_current: &'static str,
+    pub environment_none: &'static str,
+    /// Grandeurs dans l'ordre de `environment::Factor::ALL`.
+    pub environment_factors: [&'static str; 4],
+    /// Analyses renseignées, minimum requis.
+    pub environment_missing: fn(usize, usize) -> String,
+    pub environment_linked: &'static str,
+    pub environment_unlinked: &'static str,
+    pub environment_prompt: &'static str,
+    /// Dimensions de la pièce (m) dans le panneau.
+    pub environment_room: fn(f32, f32, f32) -> String,
+    /// Saisie [$] refusée : forme attendue, champ (nom, texte) illisible,
+    /// champ (nom, min, max, valeur) hors plage, dimensions.
+    pub ambient_expected: &'static str,
+    pub ambient_unreadable: fn(&str, &str) -> String,
+    pub ambient_out_of_range: fn(&str, f32, f32, f32) -> String,
+    pub room_dimensions_format: fn(&str) -> String,
+    /// Dimension lue, plage admise (m).
+    pub room_dimension_range: fn(f32, f32, f32) -> String,
+    pub ambient_error: fn(&str) -> String,
+    /// Saisie [$] acceptée : conditions effacées, ou température et
+    /// humidité (déjà formatées) avec la vitesse du son (m/s) ; puis les
+    /// dimensions (m) et le nombre de modes calculés.
+    pub ambient_cleared: &'static str,
+    pub ambient_noted: fn(&str, &str, f32) -> String,
+    pub room_dimensions_noted: fn(f32, f32, f32, usize) -> String,
+    /// Notes [F12] : titre (heure de l'analyse, `None` = la prochaine),
+    /// champs dans l'ordre de `notes::NoteField::ALL`, aide de la saisie.
+    pub notes_title: fn(Option<&str>) -> String,
+    pub notes_fields: [&'static str; 4],
+    pub notes_hint: &'static str,
+    pub notes_saved: fn(&str) -> String,
+    pub notes_saved_next: &'static str,
+    /// Grandeurs comparées d'une place à l'autre (retard, niveau).
+    pub seat_delay: &'static str,
+    pub seat_level: &'static str,
+    /// Grandeur, nombre de places.
+    pub reco_seat_placement: fn(&str, usize) -> String,
+    /// Grandeur, nom de la place.
+    pub reco_seat_anomaly: fn(&str, &str) -> String,
+    pub baseline_title: &'static str,
+    pub baseline_prompt: &'static str,
+    /// Score, heure de l'acceptation.
+    pub baseline_accepted: fn(u32, &str) -> String,
+    pub baseline_correction: &'static str,
+    /// Côté retardé, retard (ms).
+    pub baseline_delay: fn(&str, f32) -> String,
+    /// Nombre de filtres d'égalisation (droite).
+    pub baseline_eq: fn(usize) -> String,
+    pub baseline_none: &'static str,
+    /// Contrôles conformes, contrôles au total.
+    pub baseline_checks: fn(usize, usize) -> String,
+    pub baseline_compliant: &'static str,
+    /// Grandeur, écart accepté, écart courant, unité.
+    pub baseline_deviation: fn(&str, f32, f32, &str) -> String,
+    pub history_empty: &'static str,
+
+    // ─── Messages d'état ───
+    /// « Libellé : valeur » (« Label: value » en anglais).
+    pub label_value: fn(&str, &str) -> String,
+    /// Nombre minimal et maximal de sorties de --subs.
+    pub multisub_needs_subs: fn(usize, usize) -> String,
+    /// Places mesurées, écart entre places avant → après le réglage (dB).
+    pub multisub_planned: fn(usize, f32, f32) -> String,
+    /// Places mesurées, minimum requis.
+    pub multisub_next_seat: fn(usize, usize) -> String,
+    pub multisub_needs_loopback: &'static str,
+    pub multisub_no_arrival: &'static str,
+    /// Places gardées.
+    pub multisub_dropped: fn(usize) -> String,
+    pub checklist_first: &'static str,
+    pub checklist_incomplete: &'static str,
+    pub checklist_done: &'static str,
+    /// Enceinte (« gauche »), durée de la promenade (s).
+    pub mmm_walk: fn(&str, f32) -> String,
+    pub watch_stopped: &'static str,
+    pub nudge_on: &'static str,
+    pub nudge_off: &'static str,
+    pub dual_stopped: &'static str,
+    pub dual_too_short: &'static str,
+    pub chain_done: &'static str,
+    pub err_no_capture: &'static str,
+    pub level_no_burst: &'static str,
+    /// Salve captée, sweep réglé, capture attendue (dBFS).
+    pub level_set: fn(f32, f32, f32) -> String,
+    pub level_limited: &'static str,
+    pub bass_not_detected: &'static str,
+    /// Nom du test.
+    pub test_not_detected: fn(&str) -> String,
+    pub wiring_not_detected: &'static str,
+    pub sub_no_arrival: &'static str,
+    pub baseline_compliant_check: &'static str,
+    pub baseline_outside_check: &'static str,
+    /// Écart de score, de |retard| (ms) et de |niveau| (dB).
+    pub experiment_effect: fn(i32, f32, f32) -> String,
+    /// Fichiers écrits, répertoire.
+    pub debug_written: fn(usize, &str) -> String,
+    /// Plage d'analyse, bornes (Hz).
+    pub range_set: fn(f32, f32) -> String,
+    pub calibration_set: fn(&str) -> String,
+    pub calibration_none: &'static str,
+    pub target_set: fn(&str) -> String,
+    pub target_none: &'static str,
+    pub mask_set: fn(&str) -> String,
+    pub mask_none: &'static str,
+    /// Nombre de vérifications à refaire.
+    pub checks_due: fn(usize) -> String,
+    pub checks_log_error: fn(&str) -> String,
+    pub calibration_nothing_to_verify: &'static str,
+    pub calibration_verified: fn(&str) -> String,
+    pub window_set: fn(&str) -> String,
+    /// Seuil du front au-dessus du bruit de fond (dB).
+    pub onset_set: fn(f32) -> String,
+    pub smoothing_off: &'static str,
+    /// Lissage en 1/n octave.
+    pub smoothing_set: fn(u32) -> String,
+    pub captures_swapped: &'static str,
+    pub experiment_needs_before: &'static str,
+    /// Description du changement.
+    pub experiment_noted: fn(&str) -> String,
+    pub seat_needs_analysis: &'static str,
+    /// Nom de la place, places enregistrées.
+    pub seat_recorded: fn(&str, usize) -> String,
+    /// Seconde de départ (« :30 »).
+    pub schedule_set: fn(&str) -> String,
+    pub schedule_cleared: &'static str,
+    pub schedule_error: fn(&str) -> String,
+    pub baseline_dropped: &'static str,
+    pub baseline_needs_analysis: &'static str,
+    /// Raison de l'asymétrie acceptée.
+    pub baseline_noted: fn(&str) -> String,
+    pub report_needs_analysis: &'static str,
+    pub report_charts_failed: fn(&str) -> String,
+    pub report_written: fn(&str) -> String,
+    pub png_written: fn(&str) -> String,
+    pub png_no_curves: &'static str,
+    pub position_needs_analysis: &'static str,
+    /// Nom de la position, note composite, rang, positions notées.
+    pub position_marked: fn(&str, f32, usize, usize) -> String,
+    pub session_saved: fn(&str) -> String,
+    pub session_loaded: fn(&str) -> String,
+    /// Chemin, version lue, version courante.
+    pub session_migrated: fn(&str, u32, u32) -> String,
+    /// Chemin, taille (ko), audio brut inclus.
+    pub session_shared: fn(&str, usize, bool) -> String,
+    /// Fichiers écrits, séparés par des virgules.
+    pub exported: fn(&str) -> String,
+    pub theme_set: fn(&str) -> String,
+    pub mode_expert: &'static str,
+    pub mode_basic: &'static str,
+    /// Erreur d'ouverture d'une carte déjà prise.
+    pub err_busy_hint: fn(&str) -> String,
+
+    // ─── Aide clavier ───
+    pub help: &'static [(&'static str, &'static str)],
+    /// Aide du mode simple.
+    pub help_basic: &'static [(&'static str, &'static str)],
+
+    // ─── Fichier de configuration ───
+    pub theme_color_unknown: fn(&str) -> String,
+    pub theme_color_unreadable: fn(&str, &str) -> String,
+    pub config_unreadable: fn(&str) -> String,
+    pub config_invalid: fn(&str) -> String,
+    /// Bornes : durée (s), début, fin (Hz), amplitude, moyennes, silence G → D (s).
+    pub sweep_out_of_range: fn(f32, f32, f32, f32, f32, u32, f32, f32) -> String,
+    pub level_target_range: fn(f32, f32) -> String,
+    pub gain_step_range: fn(f32, f32) -> String,
+    pub score_weights_positive: &'static str,
+    /// Somme des poids lue.
+    pub score_weights_total: fn(f32) -> String,
+    pub score_tolerances_positive: &'static str,
+    /// Champ, min, max, valeur.
+    pub room_out_of_range: fn(&str, f32, f32, f32) -> String,
+    pub room_dimensions_together: &'static str,
+    pub keys_unknown_action: fn(&str) -> String,
+    pub keys_unreadable: fn(&str) -> String,
+    pub keys_reserved: fn(&str) -> String,
+    /// Touche, première action, seconde action.
+    pub keys_conflict: fn(&str, &str, &str) -> String,
+    pub monitor_at: fn(&str) -> String,
+    pub monitor_min_score: fn(u32) -> String,
+    pub webhook_url: fn(&str) -> String,
+    /// Saisie [\\] refusée : texte lu.
+    pub schedule_second: fn(&str) -> String,
+
+    // ─── Erreurs audio ───
+    pub err_no_output: &'static str,
+    pub err_output_format: &'static str,
+    pub err_too_few_channels: fn(u16, usize) -> String,
+    pub err_loopback_input: fn(usize, usize) -> String,
+    pub err_no_input: &'static str,
+    pub err_input_format: &'static str,
+    pub err_no_samples: &'static str,
+    pub err_capture_armed: &'static str,
+    pub err_truncated: fn(usize, usize, f32, usize) -> String,
+    pub err_open_failed: fn(&str, &str) -> String,
+    pub err_default_config: fn(&str, &str, u32) -> String,
+    pub err_device_busy: fn(&str, &str) -> String,
+    pub err_timeout: fn(f32) -> String,
+    pub err_clipped: fn(usize, f32) -> String,
+    pub err_too_quiet: fn(f32, f32) -> String,
+    pub err_cancelled: &'static str,
+    pub capture_stopping: &'static str,
+    pub err_overrun: fn(usize) -> String,
+    pub err_output_stream: fn(&str) -> String,
+    pub err_input_stream: fn(&str) -> String,
+    pub device_unknown: &'static str,
+    pub device_none: &'static str,
+
+    // ─── Ligne de commande, fichiers et surveillance ───
+    /// Fichiers : chemin en cause (la cause suit).
+    pub cannot_read: fn(&str) -> String,
+    pub cannot_write: fn(&str) -> String,
+    pub cannot_create: fn(&str) -> String,
+    pub cannot_open: fn(&str) -> String,
+    pub nothing_to_export: &'static str,
+    /// Titres des fichiers .frd ([E]).
+    pub frd_left_title: &'static str,
+    pub frd_right_title: &'static str,
+    pub session_unreadable: fn(&str) -> String,
+    pub session_shared_unreadable: fn(&str) -> String,
+    pub session_invalid: fn(&str) -> String,
+    pub session_not_object: &'static str,
+    pub session_no_version: &'static str,
+    pub session_version_zero: &'static str,
+    /// Version lue, version courante.
+    pub session_too_new: fn(u32, u32) -> String,
+    /// Clé de la mesure v1 (« left »).
+    pub session_v1_channel: fn(&str) -> String,
+    pub calibration_unreadable: fn(&str) -> String,
+    pub calibration_invalid: fn(&str) -> String,
+    /// Nom demandé, noms disponibles.
+    pub calibration_unknown: fn(&str, &str) -> String,
+    pub calibration_too_few_points: &'static str,
+    pub target_unreadable: fn(&str) -> String,
+    pub target_invalid: fn(&str) -> String,
+    pub target_unknown: fn(&str, &str) -> String,
+    pub mask_unknown: fn(&str, &str) -> String,
+    pub asset_invalid: fn(&str) -> String,
+    pub checks_log_invalid: fn(&str) -> String,
+    /// Arguments : valeur lue.
+    pub arg_channel_unknown: fn(&str) -> String,
+    pub arg_lang_unknown: fn(&str) -> String,
+    pub arg_theme_unknown: fn(&str) -> String,
+    /// --mock-audio : panne, valeur lue.
+    pub mock_number: fn(&str, &str) -> String,
+    pub mock_value: fn(&str) -> String,
+    pub mock_unknown: fn(&str) -> String,
+    /// --headless : progression sur stderr, puis résumé d'une ligne.
+    pub headless_level: &'static str,
+    /// Sweep réglé, capture attendue (dBFS).
+    pub headless_sweep_level: fn(f32, f32) -> String,
+    pub headless_level_limited: &'static str,
+    pub headless_capture: fn(&str) -> String,
+    pub headless_drift: fn(f32) -> String,
+    /// Score, retard (ms), écart de niveau (dB).
+    pub headless_summary: fn(u32, f32, f32) -> String,
+    pub headless_incomplete: &'static str,
+    /// Surveillance : heure et minute UTC, attente en heures et minutes.
+    pub monitor_next: fn(u64, u64, u64, u64) -> String,
+    /// Horodatage, erreur.
+    pub monitor_failed: fn(&str, &str) -> String,
+    /// Score, seuil.
+    pub monitor_alert_score: fn(u32, u32) -> String,
+    pub monitor_alert_incomplete: &'static str,
+    /// Horodatage, score, seuil.
+    pub monitor_score: fn(&str, u32, u32) -> String,
+    pub monitor_incomplete: fn(&str) -> String,
+    pub monitor_alert: &'static str,
+    pub monitor_history: fn(&str) -> String,
+    /// Broker, erreur.
+    pub mqtt_not_sent: fn(&str, &str) -> String,
+    pub mqtt_no_address: fn(&str) -> String,
+    pub mqtt_refused: fn(u8) -> String,
+    pub webhook_not_object: &'static str,
+    /// URL, erreur.
+    pub webhook_not_sent: fn(&str, &str) -> String,
+    /// Minimum, maximum, sorties lues.
+    pub subs_count: fn(usize, usize, usize) -> String,
+    /// Commandes, vérifications, arrêt par [Q].
+    pub script_ok: fn(usize, usize, bool) -> String,
+}
+
+pub static FR: Strings = Strings {
+    ready: "● PRÊT",
+    subtitle: "  Calibration de placement stéréo par analyse comparative micro",
+    output_label: "  Sortie : ",
+    input_label: "   Entrée : ",
+    mic_calibrated: |name, n| format!("   ✓ Micro calibré : {} ({} pts)", name, n),
+    mic_uncalibrated: "   Micro non calibré",
+
+    delay_title: " ◈ Délai pré-capture  —  SWEEP SINUS : durée, début → fin, crête ",
+
+    capturing: |phase, p| format!("  ◉ {}… {:.0}%", phase, p),
+    left_title: " L  ENCEINTE GAUCHE ",
+    left_done: "  ✓ Capturé — Appuyer sur [L] pour recapturer",
+    left_prompt: "  [L] Capturer l'enceinte GAUCHE (référence)",
+    left_note: "  Signal test lu sur le canal GAUCHE uniquement",
+    right_title: " R  ENCEINTE DROITE ",
+    right_done: "  ✓ Capturé — Appuyer sur [R] pour recapturer",
+    right_prompt: "  [R] Capturer l'enceinte DROITE (à aligner)",
+    right_note: "  Signal test lu sur le canal DROIT uniquement",
+
+    capture_left: "Capture GAUCHE",
+    capture_right: "Capture DROITE",
+    capture_pair: "Capture GAUCHE puis DROITE",
+    capture_channel: |ch| format!("Capture {}", ch),
+    capture_sub: "Capture CAISSON",
+    bass_test: "Test bass management",
+    capture_noise: "Bruit de fond (silence)",
+    capture_level: "Niveau (bruit rose)",
+    probe_capture: |side| format!("Câblage — micro contre {}", side),
+    pause: |s| format!("Pause {}s…", format_num(s, 1)),
+    capture_cancel_hint: "[Échap] arrêter",
+    phase_pre_delay: "Pause",
+    phase_playing: "Lecture",
+    phase_tail: "Queue",
+    phase_averaging: "Moyenne",
+    phase_deconvolving: "Déconvolution",
+    phase_scheduled: "Attente du départ",
+    scheduled_start: |at, secs| format!("Départ à {} dans {}s…", at, format_num(secs, 1)),
+    schedule_prompt: "Départ à la seconde (:SS, vide = aussitôt) : ",
+    schedule_off: "aussitôt",
+    window_hint: |done, total| format!("  ▦ Fenêtre d'écoute {}/{} — placez le micro : ", done, total),
+    window_then_analyze: "  puis [A] Analyser",
+    window_then_capture: "  puis capturez (L) et (R)",
+    both_captured: "  ⚡ Les deux enceintes sont capturées — ",
+    analyze_key: "[A] Analyser",
+    idle_hint: "  Placez le micro au point d'écoute, puis capturez l'enceinte GAUCHE (L) puis DROITE (R)",
+
+    left_short: "G",
+    right_short: "D",
+    left_upper: "GAUCHE",
+    right_upper: "DROITE",
+    left_name: "Gauche",
+    right_name: "Droite",
+    left_word: "gauche",
+    region_names: ["Sub-grave", "Grave", "Médium", "Présence", "Brillance"],
+    reco_region: |region, side, db| format!("Zone {} plus énergique à {} (+{} dB)", region.to_lowercase(), side, format_num(db, 1)),
+    right_word: "droite",
+    measuring: "  Mesure en cours…",
+
+    spectrum_title: " Réponse en fréquence (dB) ",
+    spectrum_title_smoothed: |n| format!(" Réponse en fréquence (dB) — lissage 1/{} oct ", n),
+    spectrum_gated: |ms, hz| format!("· fenêtre {:.0} ms (> {:.0} Hz) ", ms, hz),
+    gate_off: "Fenêtre temporelle : aucune (réponse complète, pièce comprise)",
+    gate_on: |ms, hz| format!("Fenêtre temporelle : {:.0} ms — son direct seul au-dessus de {:.0} Hz", ms, hz),
+    gate_unavailable: |ms| format!("Fenêtre temporelle : {:.0} ms, sans effet sur ces mesures (pas d'audio ni d'IR)", ms),
+    room_modes_legend: " ┊ modes axiaux  ┴ tangentiels ",
+    room_mode_near: |order, hz| format!(" mode {} ({:.0} Hz) ", order, hz),
+    window_names: ["Hann", "Blackman-Harris", "flat-top", "rectangulaire"],
+    overlay_a_left: "A gauche",
+    overlay_a_right: "A droite",
+    overlay_b_left: "B gauche",
+    overlay_b_right: "B droite",
+    overlay_title: |a, ta, b, tb| format!(" Comparaison A #{} ({}) → B #{} ({}) ", a, ta, b, tb),
+    overlay_keys: " ← → A · ↑ ↓ B · F6 forum · & fermer ",
+    overlay_no_curves: "  Courbes absentes de cette entrée (session antérieure)",
+    overlay_score: "  Score      ",
+    overlay_delay: "  |Délai|    ",
+    overlay_level: "  |Niveau|   ",
+    overlay_mismatch: "  |D − G|    ",
+    overlay_shift: |l, r| format!("   courbes déplacées : G {} dB · D {} dB", format_num(l, 1), format_num(r, 1)),
+    forum_file: "comparaison.md",
+    forum_delay: "Retard G/D (ms)",
+    forum_level: "Écart de niveau (dB)",
+    forum_mismatch: "\\|D − G\\| moyen (dB)",
+    forum_shift: |l, r| format!("Courbes déplacées de A à B : gauche {:.1} dB, droite {:.1} dB en moyenne.", l, r),
+    forum_better: "mieux <",
+    forum_worse: "> pire",
+    forum_legend: |db| format!("(# = {} dB, D − G en dB, moyenne par octave)", db),
+    compare_needs_two: "Comparaison : il faut au moins deux analyses dans l'historique",
+    forum_no_curves: "Comparaison : analyses sans courbes (session antérieure)",
+    forum_written: |a, b, path| format!("Comparaison #{} → #{} écrite : {} (à coller sur un forum)", a, b, path),
+    write_failed: |path, e| format!("Impossible d'écrire {} : {}", path, e),
+    spectrum_empty: "  Capturez les deux enceintes pour afficher leur réponse en fréquence",
+    ir_title: " Réponse impulsionnelle (enveloppe dB) ",
+    band_delay_title: " Retard D − G par octave (ms) ",
+    band_delay_constant: |spread| format!(" ✓ Retard constant (±{} ms) : écart de distance ", format_num(spread / 2.0, 2)),
+    band_delay_dispersed: |spread| format!(" ⚠ Retard variable ({} ms d'écart) : filtre / phase, pas distance ", format_num(spread, 2)),
+    reco_dispersion: |spread| {
+        format!("Retard variable selon la fréquence ({} ms) : vérifier coupure, filtres et polarité avant de déplacer", format_num(spread, 2))
+    },
+    reco_allpass: |filters| format!("    Sinon, passe-tout : {} — [E] camilladsp.yml, allpass_*.wav", filters),
+    allpass_first_order: "1er ordre",
+    ir_empty: "  Analysez une mesure [A] pour afficher sa réponse impulsionnelle",
+
+    grid_title: |done, total| format!(" Positions micro {}/{} ", done, total),
+    grid_speakers: " enceintes ↑ ",
+    grid_seat: " siège",
+    grid_spacing: |cm| format!("  pas de grille : {:.0} cm", cm),
+    grid_complete: "  ✓ Grille complète",
+    pos_center: "centre",
+    pos_front: "avant",
+    pos_back: "arrière",
+    hint_side: |cm, side| format!("{:.0} cm à {}", cm, side),
+    hint_forward: |cm| format!("{:.0} cm vers l'avant", cm),
+    hint_backward: |cm| format!("{:.0} cm vers l'arrière", cm),
+    hint_seat: "au siège central",
+    hint_from_center: |parts, cm| format!("{} ({:.0} cm du centre)", parts, cm),
+
+    speakers_title: |reference| format!(" Enceintes — référence {}  [F] changer ", reference),
+    col_channel: "Canal",
+    col_distance: "Distance",
+    col_delay: "Délai",
+    col_level: "Niveau",
+    col_score: "Score",
+    col_trim: "Gain",
+    col_set_delay: "Retard",
+    speakers_reference: "  référence",
+    speakers_pending: "  capturé — [A] pour comparer",
+    speakers_trims_hint: |reference| format!("  Gain, Retard : réglages de l'ampli pour égaler {} et arriver ensemble", reference),
+
+    sub_title: |output| format!(" Caisson (sortie {}) ↔ principales ", output),
+    sub_need_left: "  Capturez l'enceinte GAUCHE (L) pour aligner le caisson",
+    sub_unavailable: "  Alignement indisponible",
+    sub_delay_sub: "Retarder le caisson",
+    sub_delay_mains: "Retarder les principales",
+    avr_decrease: |m| format!("  AVR : diminuer la distance du caisson de {} m", format_num(m, 2)),
+    avr_increase: |m| format!("  AVR : augmenter la distance du caisson de {} m", format_num(m, 2)),
+    sub_arrival: |ms| format!("  Arrivée caisson {} ms", format_signed(ms, 1)),
+    sub_phase_at: |hz| format!("   Phase à {:.0} Hz : ", hz),
+    sub_summation: |db| format!("  (sommation {} dB)", format_signed(db, 1)),
+    sub_action: |target| format!("  ▶ {} de ", target),
+    multisub_title: |seats| format!(" Caissons multiples ({} place(s) mesurée(s)) ", seats),
+    multisub_capture: |output, n, total| format!("Caisson {} ({}/{})", output, n, total),
+    multisub_hint: "  [F3] à chaque place d'écoute : les caissons sont mesurés un à un (2 places au moins) ; [Maj+F3] la reprend",
+    multisub_spread: |before, after, level| {
+        format!("  Écart entre places {} dB → {} dB   (niveau moyen {} dB)", format_num(before, 1), format_num(after, 1), format_signed(level, 1))
+    },
+    multisub_setting: |output, gain, delay, inverted| {
+        format!(
+            "  {:<4} gain {:+.0} dB   retard {} ms   polarité {}",
+            output,
+            gain,
+            format_num(delay, 2),
+            if inverted { "inversée" } else { "normale" }
+        )
+    },
+
+    bass_title: " Bass management ",
+    route_main: "enceinte principale",
+    route_sub: "caisson",
+    route_absent: "absente",
+    bass_ok: |hz, conf| format!("  ✓ Coupure mesurée ≈ {:.0} Hz (configurée : {:.0} Hz)", hz, conf),
+    bass_mismatch: |hz, conf| format!("  ⚠ Coupure mesurée ≈ {:.0} Hz — configurée : {:.0} Hz", hz, conf),
+    bass_none: |conf| format!("  ⚠ Aucune bande redirigée vers le caisson (configurée : {:.0} Hz)", conf),
+
+    polarity_title: " Polarité par haut-parleur ",
+    pol_uncertain: "? incertain",
+    pol_normal: "+ normale",
+    pol_inverted: "− inversée",
+    pol_ok: "  ✓ Polarités identiques G/D sur chaque haut-parleur",
+    pol_mismatch: |drivers| format!("  ⚠ {} câblé(s) en opposition entre G et D — vérifier le câblage interne", drivers),
+
+    rub_title: " Rub & buzz — résidu / fondamentale ",
+    rub_ok: "  ✓ Aucun frottement ni souffle asymétrique détecté",
+    rub_item: |side, hz, db| format!("{} à {:.0} Hz (+{:.0} dB)", side, hz, db),
+    rub_suspect: |list| format!("  ⚠ Distorsion anormale : {} — défaut mécanique probable", list),
+
+    compression_title: |lo, hi| format!(" Compression ({:.0} → {:.0} dB) par octave ", lo, hi),
+    compression_ok: "  ✓ Réponse identique à tous les niveaux",
+    compression_bad: |list| format!("  ⚠ Compression : {} — limiteur ou ampli saturé", list),
+
+    linearity_title: |lo, hi| format!(" Linéarité du gain ({:.0} → {:.0} dBFS) ", lo, hi),
+    linearity_row: |slope, dev| format!("pente {}  écart max {} dB   ", format_num(slope, 2), format_num(dev, 1)),
+    linearity_ok: "  ✓ Gain linéaire sur les deux canaux",
+    linearity_bad: "  ⚠ Gain non linéaire — désactiver « dynamic volume » / compresseurs avant de mesurer",
+
+    enh_title: " ⚠ Traitements audio du système détectés — mesures faussées ",
+    enh_pumping: |side, db| format!("  {} : le niveau dérive de {} dB sur un palier stable — égalisation de sonie / AGC", side, format_signed(db, 1)),
+    enh_gain_riding: |side, slope| format!("  {} : pente de gain {} — niveau régulé (AGC, « dynamic volume »)", side, format_num(slope, 2)),
+    enh_crossfeed: |side, ms, db, inv| {
+        let pol = if inv { ", inversée" } else { "" };
+        format!("  {} : seconde arrivée à {} ms ({:+.0} dB{}) — son spatial / virtual surround", side, format_num(ms, 2), db, pol)
+    },
+    enh_hint_windows: "  → Paramètres son › Propriétés du périphérique : désactiver « Améliorations audio » et « Son spatial »",
+    enh_hint_other: "  → Désactiver égaliseurs, son spatial et effets du pilote (PipeWire/PulseAudio, Dolby, DTS…)",
+
+    map_title: " Câblage des canaux — niveau par emplacement (dB) ",
+    map_ok: "  ✓ Ordre standard : FL sur l'emplacement 1, FR sur 2",
+    map_applied: |l, r| format!("  ⚠ FL sur l'emplacement {}, FR sur {} — correspondance appliquée aux captures", l, r),
+    map_uncertain: "  ⚠ Emplacement incertain — coller le micro à l'enceinte et relancer [K]",
+    map_next: "  Placez le micro contre l'enceinte DROITE puis [K]",
+
+    quick_title: |stim| format!(" Vérification rapide — {} — émergence (dB) ", stim),
+    stim_dual_tone: "bi-ton 60 Hz + 7 kHz",
+    stim_white_noise: "bruit blanc",
+    quick_ok: "  ✓ Graves et aigus reçus des deux enceintes — mesure complète possible",
+    quick_missing: |side, freqs| format!("  ⚠ {} : rien reçu à {} — vérifier câble, tweeter / woofer, périphérique", side, freqs),
+
+    distance_title: " Distances rapides — chirp 0,3 s, sans analyse ",
+    distance_values: |l, r| format!("  G {} m   D {} m", format_num(l, 2), format_num(r, 2)),
+    distance_reading: |ms, cm| format!("  Δ retard D − G {} ms   Δ distance {} cm", format_signed(ms, 2), format_signed(cm, 1)),
+    distance_relative: "   (latence du système incluse : seul l'écart compte)",
+
+    mmm_title: " Moyenne spatiale — micro en mouvement ",
+    mmm_blocks: |n| format!("{} périodes", n),
+    mmm_diff: "  D − G  ",
+    mmm_mismatch: |db| format!("  Écart de tonalité moyen |D − G| = {} dB (volume mis de côté)", format_num(db, 1)),
+
+    noise_name: "Bruit",
+    noise_title: |dbfs| format!(" Bruit de fond — {} dBFS RMS large bande ", format_num(dbfs, 1)),
+    noise_snr_ok: |side, db| format!("  ✓ {} : au moins {:.0} dB au-dessus du bruit dans toutes les bandes", side, db),
+    noise_snr_low: |side, n, db, freqs| {
+        format!("  ⚠ {} : {} bandes à moins de {:.0} dB du bruit ({}) — courbe peu fiable, allonger le sweep", side, n, db, freqs)
+    },
+
+    watch_title: " Suivi en direct — [D] pour arrêter ",
+    watch_capture: |side| format!("Suivi en direct {}", side),
+    watch_reading: |cm, db| format!("  Δ distance {} cm   Δ niveau {} dB", format_signed(cm, 1), format_signed(db, 1)),
+    watch_trend: "  Tendance (cm) ",
+    watch_aligned: "  ✓ Distances égales — enceintes alignées",
+    watch_nudge: "   ♪ guidage sonore [F2]",
+    dual_title: " Suivi simultané G + D (bruits décorrélés) — [?] pour arrêter ",
+    dual_capture: "Suivi simultané GAUCHE + DROITE",
+    dual_reading: |db, cm| format!("  Δ niveau D − G {} dB   Δ distance {} cm", format_signed(db, 1), format_signed(cm, 1)),
+    dual_regions: "  D − G  ",
+    dual_trend: "  Tendance (dB) ",
+    dual_balanced: "  ✓ Niveaux équilibrés",
+    dual_louder: |side, db| format!("  ⚖ Enceinte {} plus forte de {} dB : la baisser, ou monter l'autre", side, format_num(db, 1)),
+
+    test_polarity: "Polarité",
+    test_rub_buzz: "Rub & buzz",
+    test_compression: "Compression",
+    test_linearity: "Linéarité",
+    test_quick: "Vérification rapide",
+    test_distance: "Distances rapides",
+    test_mmm: "Moyenne spatiale",
+
+    score_title: " Score & Métriques ",
+    score_title_range: |lo, hi| format!(" Score & Métriques — {} à {} ", lo, hi),
+    profile_studio: "studio de proximité",
+    profile_theater: "home-cinéma",
+    rating_excellent: "EXCELLENT",
+    rating_adjust: "AJUSTABLE",
+    rating_fix: "À CORRIGER",
+    score_placement: "   placement ",
+    score_electrical: "  réglage ",
+    distances: "  Distances  ",
+    distances_need_sweep: "  Distances  — sweep requis",
+    target_label: "  Cible      ",
+    target_name: "Cible",
+    mask_name: "Masque",
+    distances_latency: |ms| format!("  absolues (boucle, latence {} ms)", format_num(ms, 1)),
+    distances_drift: |ppm| format!("  · dérive d'horloge {:+.0} ppm corrigée", ppm),
+    iacc_need: "  IACC       — deux micros requis",
+    meter_delay: "Délai",
+    meter_level: "Niveau",
+    meter_spectrum: "Spectre",
+    window_summary: |n| format!("  Fenêtre {} pos — écart max ", n),
+    run_analysis: "  Lancez l'analyse [A]",
+    clarity_title: " Clarté  EDT · C50 · C80 ",
+    reverb_title: " Réverbération par octave (s) ",
+    thd_title: " Distorsion harmonique par octave (THD %, H2–H5 du sweep) ",
+    metric_clarity: "Clarté",
+    metric_reverb: "Réverbération",
+    metric_thd: "Distorsion",
+    metrics_thd_at: |hz| format!("au pire à {}", hz),
+    metrics_next: "domaine",
+    metrics_fold: "détail",
+    report_title: "Rapport d'alignement des enceintes",
+    report_measured: |time, out, input| format!("Analyse de {} — sortie {}, entrée {}", time, out, input),
+    report_metrics: "Métriques",
+    report_speakers: "Par enceinte",
+    report_response: "Réponse en fréquence",
+    report_diff: "Écart droite − gauche",
+    report_history: "Évolution du score",
+    report_delay: "Retard D − G",
+    report_level: "Écart de niveau D − G",
+    report_tilt: "Écart de pente spectrale",
+    report_profile: "Profil de score",
+    report_distance: "Distance",
+    report_t30: "T30 moyen",
+    report_thd: "THD, pire octave",
+    report_target: "Écart à la cible",
+    report_footer: "Généré par Speaker Align",
+    report_notes: "Notes",
+
+    reco_title: " Recommandations ",
+    reco_pending: "  Résultats disponibles après analyse",
+    reco_bucket_placement: "Surtout une affaire de placement (retard, réflexions) : déplacer les enceintes",
+    reco_bucket_electrical: "Surtout une affaire de réglage (niveau, spectre) : trim ou égaliseur, sans déplacer les meubles",
+    checklist_title: " Avant de mesurer ",
+    checklist_items: [
+        ("Micro à hauteur d'oreille, à la place d'écoute", ""),
+        ("Pièce silencieuse", "[H] mesure le bruit"),
+        ("Volume d'écoute réglé", "[J] règle le niveau"),
+        ("Améliorations audio du système désactivées", "[G] les détecte"),
+    ],
+    checklist_auto: "vérifié",
+    checklist_problem: "problème détecté",
+    checklist_hint: "  [1-4] cocher · Entrée valider · Échap fermer",
+    verdict_title: " Verdict ",
+    verdict_pending: "  Mesurez la gauche [L], puis la droite [R], puis analysez [A].",
+    verdict_good: "  Vos enceintes sont bien placées : rien à changer.",
+    verdict_adjust: "  Presque : suivez les conseils ci-dessous, puis remesurez.",
+    verdict_fix: "  Placement à revoir : suivez les conseils ci-dessous, puis remesurez.",
+    reco_closer: "Rapprocher l'enceinte droite",
+    reco_farther: "Éloigner l'enceinte droite",
+    reco_left_closer: "Rapprocher l'enceinte gauche",
+    reco_left_farther: "Éloigner l'enceinte gauche",
+    reco_channel_delay: |side, ms, cm| {
+        format!("Retarder l'enceinte {} de {} ms dans l'ampli / le DSP (réglage de distance −{:.0} cm)", side, format_num(ms, 2), cm)
+    },
+    reco_level_after: |db| format!("    ΔL attendu après déplacement ≈ {} dB", format_signed(db, 1)),
+    reco_distance_blocked: "Écart de distance impossible à corriger avec les contraintes [placement]",
+    reco_distance_blocked_hint: "    Autorisez un déplacement ou déclarez channel_delay = true",
+    reco_too_loud: "Son droit trop fort — éloigner ou désaxer",
+    reco_too_quiet: "Son droit trop faible — rapprocher ou orienter",
+    reco_level: |db| format!("    Δ niveau = {} dB", format_num(db, 1)),
+    reco_gain_trim: |side, n, db| format!("    Ampli : niveau de l'enceinte {} −{} dB ({} pas)", side, format_num(db, 1), n),
+    reco_gain_balance: |side, n, db| format!("    Balance : {} cran{} vers la {} (≈ {} dB)", n, if n > 1 { "s" } else { "" }, side, format_num(db, 1)),
+    reco_gain_detents: |side, n, db| format!("    Gain d'entrée de l'enceinte {} : −{} cran{} (≈ {} dB)", side, n, if n > 1 { "s" } else { "" }, format_num(db, 1)),
+    reco_gain_residual: |db| format!(", reste {} dB", format_num(db, 1)),
+    reco_too_bright: "Trop d'aigus à droite — désaxer (toe-out)",
+    reco_too_dull: "Manque d'aigus à droite — orienter (toe-in)",
+    reco_clarity: |side| format!("Clarté plus faible à {} — traiter les réflexions proches", side),
+    reco_reflection: |surface, side, db| {
+        format!("Réflexion ({}) plus forte de {} dB à {} — absorbant au point de réflexion", surface, format_num(db, 1), side)
+    },
+    surface_names: ["sol", "plafond", "mur latéral", "mur derrière l'enceinte"],
+    or_word: " ou ",
+    reflections_label: "Réfl.",
+    reco_sign_mismatch: |side| format!("Enceinte {} plus proche mais moins forte — orientation ou obstacle, pas la distance", side),
+    reco_sign_mismatch_hint: "    Vérifier le toe-in et dégager l'axe avant de toucher au volume",
+    reco_swapped: "Gauche et droite semblent inversées : le retard contredit les distances déclarées",
+    reco_swapped_hint: "    [|] échanger les captures, ou remesurer chaque enceinte sous sa touche",
+    age_mic_cal: |d| format!("cal. micro : {} j", d),
+    age_loopback: |d| format!("boucle : {} j", d),
+    age_loopback_never: "boucle : jamais mesurée",
+    reminder_mic_stale: |d| format!("Calibration micro vérifiée il y a {} jours : la contrôler", d),
+    reminder_mic_device: "Calibration micro vérifiée avec une autre entrée audio : est-ce le même micro ?",
+    reminder_mic_hint: "    [_] une fois contrôlée (fichier du bon micro, même numéro de série)",
+    reminder_loopback_unmeasured: "Boucle de référence configurée mais jamais mesurée",
+    reminder_loopback_stale: |d| format!("Latence de la boucle mesurée il y a {} jours", d),
+    reminder_loopback_device: "Latence de la boucle mesurée avec d'autres périphériques",
+    reminder_loopback_hint: "    remesurer : capture [L] ou [R] avec la boucle branchée",
+    reco_reversed: |side| format!("Enceinte {} câblée en polarité inversée", side),
+    reco_reversed_hint: "    Croiser + et − à une extrémité de son câble (ampli ou enceinte)",
+    reco_reversed_both: "  Polarité inversée des deux côtés (ampli, DSP ?) — sans effet sur l'image stéréo",
+    reco_weak_direct: |side| format!("Enceinte {} : une réflexion dépasse le son direct — distance prise sur la première arrivée", side),
+    reco_weak_direct_hint: "    Orienter l'enceinte vers le micro, dégager le bureau ; [F4] règle le seuil du front",
+    reco_optimal: "  ✓ Placement optimal atteint !",
+    reco_symmetric: "  Les deux enceintes sont symétriquement alignées.",
+
+    history_title: " Historique ",
+    history_trend_title: " Historique — tendance ",
+    experiments_title: " Changements (un à la fois) ",
+    positions_title: |n| format!(" Positions candidates ({}) ", n),
+    positions_header: "      pos.   note  score     grave σ réflexion",
+    experiment_prompt: "Changement : ",
+    experiment_hint: "   Entrée valider · Échap annuler",
+    experiment_pending: "mesurer puis analyser [A]…",
+    seats_title: |n| format!(" Places d'écoute ({}) ", n),
+    seats_header: "      place                  score      Δt        ΔL",
+    seats_spread: "  Dispersion D − G : ",
+    seat_prompt: "Place : ",
+    environment_title: |n| format!(" Environnement ({} analyses) ", n),
+    environment_current: "  Conditions notées [$] : ",
+    environment_none: "aucune (bruit de fond [H] et heure seulement)",
+    environment_factors: ["Bruit de fond", "Heure (UTC)", "Température", "Humidité"],
+    environment_missing: |n, min| format!("{} analyse(s) renseignée(s), {} nécessaires", n, min),
+    environment_linked: "  score lié : dérive d'ambiance ?",
+    environment_unlinked: "  sans lien net",
+    environment_prompt: "Température °C, humidité %, pièce L×l×h m : ",
+    environment_room: |l, w, h| format!(" · pièce {} × {} × {} m", format_short(l), format_short(w), format_short(h)),
+    ambient_expected: "saisie attendue : « température humidité longueur×largeur×hauteur », par ex. 21.5 45 5.2x3.8x2.5",
+    ambient_unreadable: |label, field| format!("{} illisible : « {} »", label, field),
+    ambient_out_of_range: |label, min, max, v| {
+        format!("{} hors plage ({} à {}) : {}", label, format_short(min), format_short(max), format_short(v))
+    },
+    room_dimensions_format: |field| format!("dimensions illisibles : « {} » (longueur×largeur×hauteur en m, par ex. 5.2x3.8x2.5)", field),
+    room_dimension_range: |v, min, max| format!("dimension hors plage ({} à {} m) : {}", format_short(min), format_short(max), format_short(v)),
+    ambient_error: |e| format!("Conditions : {}", e),
+    ambient_cleared: "Conditions effacées : les analyses suivantes n'en auront pas",
+    ambient_noted: |t, h, c| format!("Conditions notées pour les analyses suivantes : {} · {} — vitesse du son {} m/s", t, h, format_num(c, 1)),
+    room_dimensions_noted: |l, w, h, n| format!(" · pièce {} × {} × {} m, {} modes calculés", l, w, h, n),
+    notes_title: |time| match time {
+        Some(time) => format!(" Notes de l'analyse de {} ", time),
+        None => " Notes de la prochaine analyse ".to_string(),
+    },
+    notes_fields: ["Note", "Micro", "Enceintes", "Pièce"],
+    notes_hint: "  Tab champ suivant · PgPréc/PgSuiv ou clic sur l'historique : autre analyse · Entrée valider · Échap annuler",
+    notes_saved: |time| format!("Notes de l'analyse de {} enregistrées", time),
+    notes_saved_next: "Notes enregistrées pour la prochaine analyse",
+    seat_delay: "Retard",
+    seat_level: "Niveau",
+    reco_seat_placement: |metric, n| format!("{} : même écart aux {} places — placement des enceintes", metric, n),
+    reco_seat_anomaly: |metric, name| {
+        format!("{} : écart à la seule place « {} » — propre à cette place, ne pas déplacer les enceintes", metric, name)
+    },
+    baseline_title: " Asymétrie acceptée ",
+    baseline_prompt: "Raison : ",
+    baseline_accepted: |score, time| format!("  score {} accepté à {}", score, time),
+    baseline_correction: "  Correction : ",
+    baseline_delay: |side, ms| format!("retarder {} de {} ms", side, format_num(ms, 2)),
+    baseline_eq: |n| format!("{} filtre(s) EQ à droite", n),
+    baseline_none: "aucune",
+    baseline_checks: |ok, n| format!("  Conformité : {} / {} analyse(s) conforme(s)", ok, n),
+    baseline_compliant: "  ✓ dernière analyse dans l'écart accepté",
+    baseline_deviation: |metric, accepted, current, unit| {
+        format!("  ✕ {} : {} {} au lieu de {} accepté", metric, format_signed(current, 2), unit, format_signed(accepted, 2))
+    },
+    history_empty: "  Aucune mesure",
+
+    label_value: |label, value| format!("{} : {}", label, value),
+    multisub_needs_subs: |min, max| format!("Plusieurs caissons : lancez avec --subs LFE,SL… ({} à {} sorties)", min, max),
+    multisub_planned: |n, before, after| {
+        format!("Place {} mesurée : écart entre places {} dB → {} dB avec le réglage proposé", n, format_num(before, 1), format_num(after, 1))
+    },
+    multisub_next_seat: |n, min| format!("Place {} mesurée : déplacez le micro et [F3] à une autre place ({} au moins)", n, min),
+    multisub_needs_loopback: "Plusieurs caissons : déclarez la boucle de référence ([loopback]) pour dater chaque capture",
+    multisub_no_arrival: "Signal absent de la boucle de référence : place abandonnée, vérifiez le câble puis [F3]",
+    multisub_dropped: |n| format!("Dernière place retirée ({} place(s) gardée(s)) : [F3] pour la reprendre", n),
+    checklist_first: "Avant la première mesure : cochez la liste [1-4] puis validez [Entrée]",
+    checklist_incomplete: "Liste incomplète : cochez chaque point [1-4] ou vérifiez-le par une mesure",
+    checklist_done: "Liste validée — [L] pour mesurer la gauche",
+    mmm_walk: |side, secs| format!("Enceinte {} : promenez lentement le micro autour de la place d'écoute ({:.0} s)", side.to_lowercase(), secs),
+    watch_stopped: "Suivi en direct arrêté",
+    nudge_on: "Guidage sonore activé : bips rapprochés = presque aligné, aigu = avancer la droite, grave = la reculer",
+    nudge_off: "Guidage sonore coupé",
+    dual_stopped: "Suivi simultané G + D arrêté",
+    dual_too_short: "Suivi simultané : capture trop courte pour séparer G et D",
+    chain_done: "Mesure enchaînée G → D terminée : [A] pour analyser",
+    err_no_capture: "aucune capture",
+    level_no_burst: "Niveau : salve de bruit rose non captée",
+    level_set: |burst, sweep, expected| {
+        format!("Niveau : salve captée à {} dBFS → sweep à {} dBFS (capture attendue {} dBFS)", format_num(burst, 1), format_signed(sweep, 1), format_num(expected, 1))
+    },
+    level_limited: " — cible hors d'atteinte : régler le volume de l'ampli ou le gain du micro",
+    bass_not_detected: "Test bass management : salves non détectées dans la capture",
+    test_not_detected: |test| format!("Test {} : signal non détecté dans la capture", test),
+    wiring_not_detected: "Câblage : salves non détectées dans la capture",
+    sub_no_arrival: "Caisson : arrivée du son direct introuvable dans l'IR",
+    baseline_compliant_check: "Conforme à l'asymétrie acceptée",
+    baseline_outside_check: "Hors de l'asymétrie acceptée : voir le panneau",
+    experiment_effect: |score, delay, level| {
+        format!("Effet du changement : score {:+}, |Δt| {} ms, |ΔL| {} dB", score, format_signed(delay, 2), format_signed(level, 1))
+    },
+    debug_written: |n, dir| format!("Intermédiaires DSP : {} fichiers dans {}", n, dir),
+    range_set: |lo, hi| format!("Plage d'analyse : {:.0}–{:.0} Hz", lo, hi),
+    calibration_set: |name| format!("Calibration micro : {}", name),
+    calibration_none: "Calibration micro : aucune (micro supposé plat)",
+    target_set: |name| format!("Courbe cible : {}", name),
+    target_none: "Courbe cible : aucune",
+    mask_set: |name| format!("Masque de tolérance G/D : {}", name),
+    mask_none: "Masque de tolérance G/D : aucun",
+    checks_due: |n| format!("Rappel : {} vérification{} à refaire, voir les conseils", n, if n > 1 { "s" } else { "" }),
+    checks_log_error: |e| format!("Journal des vérifications : {}", e),
+    calibration_nothing_to_verify: "Aucune calibration micro à vérifier (['] pour en choisir une)",
+    calibration_verified: |name| format!("Calibration micro {} notée comme vérifiée aujourd'hui", name),
+    window_set: |name| format!("Fenêtre FFT : {}", name),
+    onset_set: |db| format!("Seuil du front de l'IR : {:.0} dB au-dessus du bruit de fond", db),
+    smoothing_off: "Lissage : aucun (bandes brutes)",
+    smoothing_set: |n| format!("Lissage : 1/{} octave", n),
+    captures_swapped: "Captures gauche et droite échangées",
+    experiment_needs_before: "Analysez d'abord [A] : la mesure « avant » le changement manque",
+    experiment_noted: |text| format!("Changement noté : « {} » — mesurez puis analysez [A] pour voir son effet", text),
+    seat_needs_analysis: "Analysez d'abord [A] la place à enregistrer",
+    seat_recorded: |name, n| format!("Place « {} » enregistrée ({} place{})", name, n, if n > 1 { "s" } else { "" }),
+    schedule_set: |at| format!("Départ programmé : chaque capture démarre à {} (heure système)", at),
+    schedule_cleared: "Départ programmé supprimé : les captures démarrent aussitôt",
+    schedule_error: |e| format!("Départ programmé : {}", e),
+    baseline_dropped: "Asymétrie acceptée abandonnée : retour à la symétrie comme objectif",
+    baseline_needs_analysis: "Analysez d'abord [A] : l'asymétrie à accepter manque",
+    baseline_noted: |reason| format!("Asymétrie acceptée : « {} » — correction électrique exportée par [E]", reason),
+    report_needs_analysis: "Rapport : lancez d'abord l'analyse [A]",
+    report_charts_failed: |e| format!("Rapport : graphiques impossibles ({})", e),
+    report_written: |path| format!("Rapport écrit : {} (à ouvrir dans un navigateur)", path),
+    png_written: |path| format!("Graphique écrit : {}", path),
+    png_no_curves: "PNG : aucune courbe, capturez d'abord [L] / [R]",
+    position_needs_analysis: "Analysez d'abord [A] la position à noter",
+    position_marked: |label, score, rank, n| format!("Position {} notée : {:.0}/100, rang {} sur {}", label, score, rank, n),
+    session_saved: |path| format!("Session enregistrée : {}", path),
+    session_loaded: |path| format!("Session chargée : {}", path),
+    session_migrated: |path, from, to| format!("Session chargée : {} (format v{} mis à jour en v{})", path, from, to),
+    session_shared: |path, kb, audio| {
+        format!("Partage écrit : {} ({} ko, {})", path, kb, if audio { "avec audio brut" } else { "sans audio brut" })
+    },
+    exported: |names| format!("Exporté : {}", names),
+    theme_set: |name| format!("Thème : {}", name),
+    mode_expert: "Mode expert : tous les panneaux",
+    mode_basic: "Mode simple : captures, verdict et conseils",
+    err_busy_hint: |e| format!("{} — fermez l'autre application ou relancez avec --retry-default-config", e),
+    help: &[
+        ("[L]", "Capturer gauche"),
+        ("[R]", "Capturer droite"),
+        ("[Tab]", "G puis D"),
+        ("[A]", "Analyser"),
+        ("[+/-]", "Délai pré-capture"),
+        ("[\\]", "Départ à :SS"),
+        ("[?]", "G + D simultanés"),
+        ("[F2]", "Bips de guidage"),
+        ("[F3]", "Caissons multiples"),
+        ("[Maj+F3]", "Reprendre la dernière place"),
+        ("[F4]", "Seuil du front"),
+        ("[F5]", "Courbe cible"),
+        ("[Maj+F5]", "Masque de tolérance G/D"),
+        ("[1-8]", "Canal FL…SBR"),
+        ("[F]", "Référence"),
+        ("[U]", "Caisson"),
+        ("[B]", "Bass management"),
+        ("[P]", "Polarité"),
+        ("[Z]", "Rub & buzz"),
+        ("[C]", "Compression"),
+        ("[G]", "Linéarité"),
+        ("[K]", "Câblage"),
+        ("[V/N]", "Vérif. rapide"),
+        ("[0]", "Distances rapides"),
+        ("[;]", "Moyenne spatiale"),
+        ("[D]", "Suivi en direct"),
+        ("[H]", "Bruit de fond"),
+        ("[J]", "Niveau auto"),
+        ("[/]", "Noter un changement"),
+        ("[#]", "Position candidate"),
+        ("[:]", "Place d'écoute"),
+        ("[%]", "Accepter l'asymétrie"),
+        ("[$]", "Température / humidité / pièce"),
+        ("[_]", "Calibration vérifiée"),
+        ("[\"]", "Environnement"),
+        ("[&]", "Comparer A/B"),
+        ("[F6]", "A/B pour un forum"),
+        ("[F7]", "Thème"),
+        ("[F8]", "Domaine de mesures"),
+        ("[F9]", "Détail des mesures"),
+        ("[End]", "Rapport HTML"),
+        ("[Ins]", "Image PNG"),
+        ("[F12]", "Notes"),
+        ("[←→]", "Curseur du spectre"),
+        ("[Maj+↑↓]", "Zoom du spectre"),
+        ("[M]", "Plage d'analyse"),
+        ("[~]", "Lissage"),
+        ("[^]", "Fenêtre FFT"),
+        ("[9]", "Fenêtre temporelle"),
+        ("[|]", "Échanger G / D"),
+        ("[']", "Calibration micro"),
+        ("[I]", "IR"),
+        ("[Y]", "Zones"),
+        ("[`]", "Tendance"),
+        ("[W]", "Fenêtre d'écoute"),
+        ("[S/O]", "Session"),
+        ("[E]", "Exporter"),
+        ("[T]", "English"),
+        ("[X]", "Réinitialiser"),
+        ("[!]", "Mode simple"),
+        ("[Q]", "Quitter"),
+    ],
+    help_basic: &[
+        ("[L]", "Capturer gauche"),
+        ("[R]", "Capturer droite"),
+        ("[A]", "Analyser"),
+        ("[T]", "English"),
+        ("[!]", "Mode expert"),
+        ("[Q]", "Quitter"),
+    ],
+
+    theme_color_unknown: |field| format!("[theme.colors] : teinte « {} » inconnue (green, orange, cyan, red, yellow, purple, gray, white, border…)", field),
+    theme_color_unreadable: |field, text| {
+        format!("[theme.colors] {} = « {} » : couleur illisible (#rrggbb, nom comme lightgreen, ou index 0-255)", field, text)
+    },
+
+    config_unreadable: |path| format!("Impossible de lire la configuration {}", path),
+    config_invalid: |path| format!("Fichier de configuration invalide : {}", path),
+    sweep_out_of_range: |d_min, d_max, start, stop, amp, averages, gap_min, gap_max| {
+        format!(
+            "sweep hors plage : durée {}–{} s, début ≥ {} Hz, fin ≤ {} Hz, fin ≥ 2 × début, amplitude {}–1.0, moyennes 1–{}, silence G → D {}–{} s",
+            d_min, d_max, start, stop, amp, averages, gap_min, gap_max
+        )
+    },
+    level_target_range: |min, max| format!("level.target_dbfs hors plage : {} à {} dBFS", min, max),
+    gain_step_range: |min, max| format!("gain.step_db hors plage : {} à {} dB", min, max),
+    score_weights_positive: "score : spectrum, level et time doivent être positifs",
+    score_weights_total: |total| format!("score : spectrum + level + time = {} (100 attendu)", total),
+    score_tolerances_positive: "score : delay_tolerance_ms et level_tolerance_db doivent être positifs",
+    room_out_of_range: |field, min, max, v| format!("room : {} hors plage ({} à {}) : {}", field, min, max, v),
+    room_dimensions_together: "room : length_m, width_m et height_m vont ensemble",
+    keys_unknown_action: |action| format!("keys : action « {} » inconnue", action),
+    keys_unreadable: |key| format!("keys : touche « {} » illisible (caractère, Tab, Ins, End ou F1…F12)", key),
+    keys_reserved: |key| format!("keys : « {} » est une touche fixe (captures 1-8, sweep, délai)", key),
+    keys_conflict: |key, a, b| format!("keys : « {} » affectée à la fois à {} et à {}", key, a, b),
+    monitor_at: |at| format!("[monitor] at = « {} » : heure attendue au format HH:MM", at),
+    monitor_min_score: |score| format!("[monitor] min_score = {} : attendu entre 0 et 100", score),
+    webhook_url: |url| format!("[webhook] url = « {} » : URL http:// ou https:// attendue", url),
+    schedule_second: |text| format!("seconde de la minute attendue (0 à 59), par ex. :30 — lu « {} »", text),
+    err_no_output: "Aucune sortie audio disponible",
+    err_output_format: "Aucun format de sortie stéréo utilisable (taux et format d'échantillon)",
+    err_too_few_channels: |n, ch| format!("La sortie ne propose que {} canaux : impossible de jouer sur le canal {}", n, ch),
+    err_loopback_input: |input, n| format!("Entrée de boucle {} absente : l'entrée n'a que {} canaux (indices à partir de 0)", input, n),
+    err_no_input: "Aucun microphone disponible. Branchez un micro et réessayez.",
+    err_input_format: "Aucun format d'entrée mono utilisable (taux et format d'échantillon)",
+    err_no_samples: "Aucun échantillon capturé. Vérifiez que le microphone est actif.",
+    err_capture_armed: "Une autre capture utilise déjà les flux audio : attendez sa fin.",
+    err_truncated: |received, expected, pct, required| {
+        format!(
+            "Capture tronquée : {} échantillons reçus sur {} attendus ({:.0} %, minimum {}). \
+             Le flux micro a été interrompu — recommencez la mesure.",
+            received, expected, pct, required
+        )
+    },
+    err_open_failed: |device, detail| format!("Ouverture de « {} » impossible : {}", device, detail),
+    err_default_config: |device, format, rate| {
+        format!("Config par défaut de « {} » inutilisable ({}, {} Hz ; format F32, I32, I16 ou U16 requis)", device, format, rate)
+    },
+    err_device_busy: |device, detail| {
+        format!(
+            "Périphérique « {} » occupé par une autre application (DAW, lecteur en mode exclusif) : {}",
+            device, detail
+        )
+    },
+    err_timeout: |secs| {
+        format!("Les périphériques audio ne répondent pas après {:.0} s (occupés par une autre application ?)", secs)
+    },
+    err_clipped: |n, db| {
+        format!(
+            "Entrée micro saturée ({} échantillons à pleine échelle) : baissez le gain du micro d'au moins {:.0} dB \
+             (ou le volume de l'ampli) puis recommencez.",
+            n, db
+        )
+    },
+    err_too_quiet: |rms, db| {
+        format!(
+            "Capture trop faible ({:.0} dBFS RMS) : montez le gain du micro d'environ {:.0} dB \
+             (ou le volume de l'ampli) et vérifiez que le bon micro est sélectionné.",
+            rms, db
+        )
+    },
+    err_cancelled: "Capture annulée",
+    capture_stopping: "Arrêt de la capture…",
+    err_overrun: |n| format!("Capture interrompue : {} échantillons perdus (système trop chargé), recommencez", n),
+    err_output_stream: |e| format!("Erreur sortie audio : {}", e),
+    err_input_stream: |e| format!("Erreur entrée audio : {}", e),
+    device_unknown: "Inconnu",
+    device_none: "Aucun",
+    cannot_read: |path| format!("Impossible de lire {}", path),
+    cannot_write: |path| format!("Impossible d'écrire {}", path),
+    cannot_create: |path| format!("Impossible de créer {}", path),
+    cannot_open: |path| format!("Impossible d'ouvrir {}", path),
+    nothing_to_export: "Rien à exporter : aucune enceinte capturée",
+    frd_left_title: "enceinte GAUCHE",
+    frd_right_title: "enceinte DROITE",
+    session_unreadable: |path| format!("Impossible de lire la session {}", path),
+    session_shared_unreadable: |path| format!("Session partagée illisible : {}", path),
+    session_invalid: |path| format!("Fichier de session invalide : {}", path),
+    session_not_object: "la session n'est pas un objet JSON",
+    session_no_version: "numéro de version absent",
+    session_version_zero: "version de session invalide : 0",
+    session_too_new: |v, current| {
+        format!("session au format v{}, créée par une version plus récente de speaker-align (format lu : v{})", v, current)
+    },
+    session_v1_channel: |key| format!("canal v1 inconnu dans « {} »", key),
+    calibration_unreadable: |path| format!("Lecture du fichier de calibration {}", path),
+    calibration_invalid: |path| format!("Fichier de calibration invalide : {}", path),
+    calibration_unknown: |name, known| format!("Calibration embarquée inconnue : {} (disponibles : {})", name, known),
+    calibration_too_few_points: "au moins deux points fréquence / dB sont nécessaires",
+    target_unreadable: |path| format!("Lecture de la courbe cible {}", path),
+    target_invalid: |path| format!("Courbe cible invalide : {}", path),
+    target_unknown: |name, known| format!("Courbe cible embarquée inconnue : {} (disponibles : {})", name, known),
+    mask_unknown: |name, known| format!("Masque de tolérance embarqué inconnu : {} (disponibles : {})", name, known),
+    asset_invalid: |name| format!("Ressource {} invalide", name),
+    checks_log_invalid: |path| format!("Journal des vérifications invalide : {}", path),
+    arg_channel_unknown: |name| format!("canal inconnu : {} (FL, FR, C, LFE, SL, SR, SBL, SBR ou indice)", name),
+    arg_lang_unknown: |code| format!("langue inconnue : {} (fr ou en)", code),
+    arg_theme_unknown: |name| format!("thème inconnu : {} (default, high-contrast, ansi16 ou mono)", name),
+    mock_number: |name, value| format!("--mock-audio : nombre attendu après « {}: », lu « {} »", name, value),
+    mock_value: |name| format!("--mock-audio : valeur attendue, par ex. « {}:0.5 »", name),
+    mock_unknown: |name| format!("--mock-audio : « {} » inconnu (busy, hang, reject, rate, short, drop, clip, speed, inputs, skew)", name),
+    headless_level: "Calibration du niveau…",
+    headless_sweep_level: |sweep, expected| format!("Sweep à {} dBFS (capture attendue {} dBFS)", format_signed(sweep, 1), format_num(expected, 1)),
+    headless_level_limited: "Cible de niveau hors d'atteinte : régler le volume de l'ampli ou le gain du micro",
+    headless_capture: |side| format!("Capture {}…", side.to_uppercase()),
+    headless_drift: |ppm| format!("Dérive d'horloge corrigée : {} ppm", format_signed(ppm, 1)),
+    headless_summary: |score, delay, level| format!("Speaker Align — score {}/100, délai {} ms, niveau {} dB", score, format_signed(delay, 2), format_signed(level, 1)),
+    headless_incomplete: "Speaker Align — analyse incomplète",
+    monitor_next: |h, m, wait_h, wait_min| format!("Prochaine mesure à {:02}:{:02} UTC (dans {} h {:02} min)", h, m, wait_h, wait_min),
+    monitor_failed: |time, e| format!("{} — mesure impossible : {}", time, e),
+    monitor_alert_score: |score, min| format!("ALERTE — Speaker Align : score {}/100 sous le seuil de {}", score, min),
+    monitor_alert_incomplete: "ALERTE — Speaker Align : analyse incomplète",
+    monitor_score: |time, score, min| format!("{} — score {} (seuil {})", time, score, min),
+    monitor_incomplete: |time| format!("{} — analyse incomplète", time),
+    monitor_alert: "ALERTE : installation hors tolérance",
+    monitor_history: |path| format!("Impossible d'ouvrir l'historique {}", path),
+    mqtt_not_sent: |broker, e| format!("Alerte MQTT non envoyée ({}) : {}", broker, e),
+    mqtt_no_address: |broker| format!("adresse du broker introuvable : {}", broker),
+    mqtt_refused: |code| format!("connexion refusée par le broker (code {})", code),
+    webhook_not_object: "document à notifier non objet",
+    webhook_not_sent: |url, e| format!("Webhook non envoyé ({}) : {}", url, e),
+    subs_count: |min, max, n| format!("--subs : {} à {} sorties attendues, {} lue(s)", min, max, n),
+    script_ok: |commands, checks, quit| {
+        format!("Script OK : {} commande(s), {} vérification(s){}", commands, checks, if quit { ", arrêté par [Q]" } else { "" })
+    },
+};
+
+pub static EN: Strings = Strings {
+    ready: "● READY",
+    subtitle: "  Stereo placement calibration by comparative microphone analysis",
+    output_label: "  Output: ",
+    input_label: "   Input: ",
+    mic_calibrated: |name, n| format!("   ✓ Mic calibrated: {} ({} pts)", name, n),
+    mic_uncalibrated: "   Mic not calibrated",
+
+    delay_title: " ◈ Pre-capture delay  —  SINE SWEEP: duration, start → stop, peak ",
+
+    capturing: |phase, p| format!("  ◉ {}… {:.0}%", phase, p),
+    left_title: " L  LEFT SPEAKER ",
+    left_done: "  ✓ Captured — press [L] to capture again",
+    left_prompt: "  [L] Capture the LEFT speaker (reference)",
+    left_note: "  Test signal played on the LEFT channel only",
+    right_title: " R  RIGHT SPEAKER ",
+    right_done: "  ✓ Captured — press [R] to capture again",
+    right_prompt: "  [R] Capture the RIGHT speaker (to align)",
+    right_note: "  Test signal played on the RIGHT channel only",
+
+    capture_left: "Capturing LEFT",
+    capture_right: "Capturing RIGHT",
+    capture_pair: "Capturing LEFT then RIGHT",
+    capture_channel: |ch| format!("Capturing {}", ch),
+    capture_sub: "Capturing SUB",
+    bass_test: "Bass management test",
+    capture_noise: "Background noise (silence)",
+    capture_level: "Level (pink noise)",
+    probe_capture: |side| format!("Channel map — mic against {}", side),
+    pause: |s| format!("Waiting {:.1}s…", s),
+    capture_cancel_hint: "[Esc] stop",
+    phase_pre_delay: "Waiting",
+    phase_playing: "Playing",
+    phase_tail: "Tail",
+    phase_averaging: "Averaging",
+    phase_deconvolving: "Deconvolving",
+    phase_scheduled: "Waiting for start",
+    scheduled_start: |at, secs| format!("Starting at {} in {:.1}s…", at, secs),
+    schedule_prompt: "Start at second (:SS, empty = now): ",
+    schedule_off: "now",
+    window_hint: |done, total| format!("  ▦ Listening window {}/{} — place the mic: ", done, total),
+    window_then_analyze: "  then [A] Analyse",
+    window_then_capture: "  then capture (L) and (R)",
+    both_captured: "  ⚡ Both speakers are captured — ",
+    analyze_key: "[A] Analyse",
+    idle_hint: "  Put the mic at the listening position, then capture the LEFT (L) and RIGHT (R) speakers",
+
+    left_short: "L",
+    right_short: "R",
+    left_upper: "LEFT",
+    right_upper: "RIGHT",
+    left_name: "Left",
+    right_name: "Right",
+    left_word: "left",
+    region_names: ["Sub-bass", "Bass", "Midrange", "Presence", "Brilliance"],
+    reco_region: |region, side, db| format!("More {}-region energy on the {} (+{:.1} dB)", region.to_lowercase(), side, db),
+    right_word: "right",
+    measuring: "  Measuring…",
+
+    spectrum_title: " Frequency response (dB) ",
+    spectrum_title_smoothed: |n| format!(" Frequency response (dB) — 1/{} oct smoothing ", n),
+    spectrum_gated: |ms, hz| format!("· {:.0} ms gate (> {:.0} Hz) ", ms, hz),
+    gate_off: "Time gate: none (full response, room included)",
+    gate_on: |ms, hz| format!("Time gate: {:.0} ms — direct sound only above {:.0} Hz", ms, hz),
+    gate_unavailable: |ms| format!("Time gate: {:.0} ms, no effect on these measurements (no audio or IR)", ms),
+    room_modes_legend: " ┊ axial modes  ┴ tangential ",
+    room_mode_near: |order, hz| format!(" mode {} ({:.0} Hz) ", order, hz),
+    window_names: ["Hann", "Blackman-Harris", "flat-top", "rectangular"],
+    overlay_a_left: "A left",
+    overlay_a_right: "A right",
+    overlay_b_left: "B left",
+    overlay_b_right: "B right",
+    overlay_title: |a, ta, b, tb| format!(" Compare A #{} ({}) → B #{} ({}) ", a, ta, b, tb),
+    overlay_keys: " ← → A · ↑ ↓ B · F6 forum · & close ",
+    overlay_no_curves: "  No curves stored for this entry (older session)",
+    overlay_score: "  Score      ",
+    overlay_delay: "  |Delay|    ",
+    overlay_level: "  |Level|    ",
+    overlay_mismatch: "  |R − L|    ",
+    overlay_shift: |l, r| format!("   curves moved: L {:.1} dB · R {:.1} dB", l, r),
+    forum_file: "comparison.md",
+    forum_delay: "L/R delay (ms)",
+    forum_level: "Level difference (dB)",
+    forum_mismatch: "Mean \\|R − L\\| (dB)",
+    forum_shift: |l, r| format!("Curves moved from A to B: left {:.1} dB, right {:.1} dB on average.", l, r),
+    forum_better: "better <",
+    forum_worse: "> worse",
+    forum_legend: |db| format!("(# = {} dB, R − L in dB, octave average)", db),
+    compare_needs_two: "Comparison: at least two analyses are needed in the history",
+    forum_no_curves: "Comparison: analyses without curves (older session)",
+    forum_written: |a, b, path| format!("Comparison #{} → #{} written: {} (ready to paste on a forum)", a, b, path),
+    write_failed: |path, e| format!("Cannot write {}: {}", path, e),
+    spectrum_empty: "  Capture both speakers to display their frequency response",
+    ir_title: " Impulse response (dB envelope) ",
+    band_delay_title: " R − L delay per octave (ms) ",
+    band_delay_constant: |spread| format!(" ✓ Constant delay (±{:.2} ms): distance mismatch ", spread / 2.0),
+    band_delay_dispersed: |spread| format!(" ⚠ Frequency-dependent delay ({:.2} ms spread): filter / phase, not distance ", spread),
+    reco_dispersion: |spread| {
+        format!("Delay varies with frequency ({:.2} ms): check crossover, filters and polarity before moving", spread)
+    },
+    reco_allpass: |filters| format!("    Otherwise, all-pass: {} — [E] camilladsp.yml, allpass_*.wav", filters),
+    allpass_first_order: "1st order",
+    ir_empty: "  Analyse a measurement [A] to display its impulse response",
+
+    grid_title: |done, total| format!(" Mic positions {}/{} ", done, total),
+    grid_speakers: " speakers ↑ ",
+    grid_seat: " seat",
+    grid_spacing: |cm| format!("  grid spacing: {:.0} cm", cm),
+    grid_complete: "  ✓ Grid complete",
+    pos_center: "center",
+    pos_front: "front",
+    pos_back: "back",
+    hint_side: |cm, side| format!("{:.0} cm to the {}", cm, side),
+    hint_forward: |cm| format!("{:.0} cm forward", cm),
+    hint_backward: |cm| format!("{:.0} cm back", cm),
+    hint_seat: "at the central seat",
+    hint_from_center: |parts, cm| format!("{} ({:.0} cm from the center)", parts, cm),
+
+    speakers_title: |reference| format!(" Speakers — reference {}  [F] change ", reference),
+    col_channel: "Chan",
+    col_distance: "Distance",
+    col_delay: "Delay",
+    col_level: "Level",
+    col_score: "Score",
+    col_trim: "Trim",
+    col_set_delay: "Set delay",
+    speakers_reference: "  reference",
+    speakers_pending: "  captured — [A] to compare",
+    speakers_trims_hint: |reference| format!("  Trim, Set delay: amp settings to match {} and arrive together", reference),
+
+    sub_title: |output| format!(" Subwoofer (output {}) ↔ mains ", output),
+    sub_need_left: "  Capture the LEFT speaker (L) to align the subwoofer",
+    sub_unavailable: "  Alignment unavailable",
+    sub_delay_sub: "Delay the subwoofer",
+    sub_delay_mains: "Delay the mains",
+    avr_decrease: |m| format!("  AVR: decrease the subwoofer distance by {:.2} m", m),
+    avr_increase: |m| format!("  AVR: increase the subwoofer distance by {:.2} m", m),
+    sub_arrival: |ms| format!("  Subwoofer arrival {:+.1} ms", ms),
+    sub_phase_at: |hz| format!("   Phase at {:.0} Hz: ", hz),
+    sub_summation: |db| format!("  (summation {:+.1} dB)", db),
+    sub_action: |target| format!("  ▶ {} by ", target),
+    multisub_title: |seats| format!(" Multiple subwoofers ({} seat(s) measured) ", seats),
+    multisub_capture: |output, n, total| format!("Subwoofer {} ({}/{})", output, n, total),
+    multisub_hint: "  [F3] at each listening seat: the subwoofers are measured one by one (2 seats at least); [Shift+F3] redoes it",
+    multisub_spread: |before, after, level| {
+        format!("  Seat-to-seat spread {:.1} dB → {:.1} dB   (mean level {:+.1} dB)", before, after, level)
+    },
+    multisub_setting: |output, gain, delay, inverted| {
+        format!(
+            "  {:<4} gain {:+.0} dB   delay {:.2} ms   polarity {}",
+            output,
+            gain,
+            delay,
+            if inverted { "inverted" } else { "normal" }
+        )
+    },
+
+    bass_title: " Bass management ",
+    route_main: "main speaker",
+    route_sub: "subwoofer",
+    route_absent: "missing",
+    bass_ok: |hz, conf| format!("  ✓ Measured crossover ≈ {:.0} Hz (configured: {:.0} Hz)", hz, conf),
+    bass_mismatch: |hz, conf| format!("  ⚠ Measured crossover ≈ {:.0} Hz — configured: {:.0} Hz", hz, conf),
+    bass_none: |conf| format!("  ⚠ No band redirected to the subwoofer (configured: {:.0} Hz)", conf),
+
+    polarity_title: " Polarity per driver ",
+    pol_uncertain: "? uncertain",
+    pol_normal: "+ normal",
+    pol_inverted: "− inverted",
+    pol_ok: "  ✓ Same L/R polarity on every driver",
+    pol_mismatch: |drivers| format!("  ⚠ {} wired in opposite polarity between L and R — check the internal wiring", drivers),
+
+    rub_title: " Rub & buzz — residue / fundamental ",
+    rub_ok: "  ✓ No asymmetric rubbing or port chuffing detected",
+    rub_item: |side, hz, db| format!("{} at {:.0} Hz (+{:.0} dB)", side, hz, db),
+    rub_suspect: |list| format!("  ⚠ Abnormal distortion: {} — likely mechanical fault", list),
+
+    compression_title: |lo, hi| format!(" Compression ({:.0} → {:.0} dB) per octave ", lo, hi),
+    compression_ok: "  ✓ Identical response at every level",
+    compression_bad: |list| format!("  ⚠ Compression: {} — limiter or clipping amplifier", list),
+
+    linearity_title: |lo, hi| format!(" Gain linearity ({:.0} → {:.0} dBFS) ", lo, hi),
+    linearity_row: |slope, dev| format!("slope {:.2}  max deviation {:.1} dB   ", slope, dev),
+    linearity_ok: "  ✓ Linear gain on both channels",
+    linearity_bad: "  ⚠ Non-linear gain — disable “dynamic volume” / compressors before measuring",
+
+    enh_title: " ⚠ System audio processing detected — measurements unreliable ",
+    enh_pumping: |side, db| format!("  {}: level drifts {:+.1} dB during a steady step — loudness equalization / AGC", side, db),
+    enh_gain_riding: |side, slope| format!("  {}: gain slope {:.2} — level is being regulated (AGC, “dynamic volume”)", side, slope),
+    enh_crossfeed: |side, ms, db, inv| {
+        let pol = if inv { ", inverted" } else { "" };
+        format!("  {}: second arrival at {:.2} ms ({:+.0} dB{}) — spatial sound / virtual surround", side, ms, db, pol)
+    },
+    enh_hint_windows: "  → Sound settings › Device properties: turn off “Audio enhancements” and “Spatial sound”",
+    enh_hint_other: "  → Disable equalizers, spatial sound and driver effects (PipeWire/PulseAudio, Dolby, DTS…)",
+
+    map_title: " Channel map — level per slot (dB) ",
+    map_ok: "  ✓ Standard order: FL on slot 1, FR on slot 2",
+    map_applied: |l, r| format!("  ⚠ FL on slot {}, FR on slot {} — mapping applied to captures", l, r),
+    map_uncertain: "  ⚠ Uncertain slot — put the mic right against the speaker and rerun [K]",
+    map_next: "  Place the mic against the RIGHT speaker, then press [K]",
+
+    quick_title: |stim| format!(" Quick check — {} — margin over ambient (dB) ", stim),
+    stim_dual_tone: "two-tone 60 Hz + 7 kHz",
+    stim_white_noise: "white noise",
+    quick_ok: "  ✓ Lows and highs received from both speakers — ready for a full measurement",
+    quick_missing: |side, freqs| format!("  ⚠ {}: nothing received at {} — check cable, tweeter / woofer, device", side, freqs),
+
+    distance_title: " Quick distances — 0.3 s chirp, no analysis ",
+    distance_values: |l, r| format!("  L {:.2} m   R {:.2} m", l, r),
+    distance_reading: |ms, cm| format!("  R − L delay {:+.2} ms   Δ distance {:+.1} cm", ms, cm),
+    distance_relative: "   (system latency included: only the gap counts)",
+
+    mmm_title: " Spatial average — moving mic ",
+    mmm_blocks: |n| format!("{} periods", n),
+    mmm_diff: "  R − L  ",
+    mmm_mismatch: |db| format!("  Mean tonal difference |R − L| = {:.1} dB (volume left aside)", db),
+
+    noise_name: "Noise",
+    noise_title: |dbfs| format!(" Background noise — {:.1} dBFS RMS broadband ", dbfs),
+    noise_snr_ok: |side, db| format!("  ✓ {}: at least {:.0} dB above the noise in every band", side, db),
+    noise_snr_low: |side, n, db, freqs| {
+        format!("  ⚠ {}: {} bands less than {:.0} dB above the noise ({}) — unreliable curve, use a longer sweep", side, n, db, freqs)
+    },
+
+    watch_title: " Live watch — [D] to stop ",
+    watch_capture: |side| format!("Live watch {}", side),
+    watch_reading: |cm, db| format!("  Δ distance {:+.1} cm   Δ level {:+.1} dB", cm, db),
+    watch_trend: "  Trend (cm) ",
+    watch_aligned: "  ✓ Equal distances — speakers aligned",
+    watch_nudge: "   ♪ audio guidance [F2]",
+    dual_title: " Simultaneous L + R watch (uncorrelated noise) — [?] to stop ",
+    dual_capture: "Simultaneous watch LEFT + RIGHT",
+    dual_reading: |db, cm| format!("  Δ level R − L {:+.1} dB   Δ distance {:+.1} cm", db, cm),
+    dual_regions: "  R − L  ",
+    dual_trend: "  Trend (dB) ",
+    dual_balanced: "  ✓ Levels balanced",
+    dual_louder: |side, db| format!("  ⚖ The {} speaker is louder by {:.1} dB: turn it down, or the other one up", side, db),
+
+    test_polarity: "Polarity",
+    test_rub_buzz: "Rub & buzz",
+    test_compression: "Compression",
+    test_linearity: "Linearity",
+    test_quick: "Quick check",
+    test_distance: "Quick distances",
+    test_mmm: "Spatial average",
+
+    score_title: " Score & Metrics ",
+    score_title_range: |lo, hi| format!(" Score & Metrics — {} to {} ", lo, hi),
+    profile_studio: "nearfield studio",
+    profile_theater: "home theater",
+    rating_excellent: "EXCELLENT",
+    rating_adjust: "ADJUSTABLE",
+    rating_fix: "NEEDS FIXING",
+    score_placement: "   placement ",
+    score_electrical: "  tuning ",
+    distances: "  Distances  ",
+    distances_need_sweep: "  Distances  — sweep required",
+    target_label: "  Target     ",
+    target_name: "Target",
+    mask_name: "Mask",
+    distances_latency: |ms| format!("  absolute (loopback, latency {:.1} ms)", ms),
+    distances_drift: |ppm| format!("  · clock drift {:+.0} ppm corrected", ppm),
+    iacc_need: "  IACC       — two mics required",
+    meter_delay: "Delay",
+    meter_level: "Level",
+    meter_spectrum: "Spectrum",
+    window_summary: |n| format!("  Window {} pos — max deviation ", n),
+    run_analysis: "  Run the analysis [A]",
+    clarity_title: " Clarity  EDT · C50 · C80 ",
+    reverb_title: " Reverberation per octave (s) ",
+    thd_title: " Harmonic distortion per octave (THD %, sweep H2–H5) ",
+    metric_clarity: "Clarity",
+    metric_reverb: "Reverberation",
+    metric_thd: "Distortion",
+    metrics_thd_at: |hz| format!("worst at {}", hz),
+    metrics_next: "domain",
+    metrics_fold: "detail",
+    report_title: "Speaker alignment report",
+    report_measured: |time, out, input| format!("Analysis at {} — output {}, input {}", time, out, input),
+    report_metrics: "Metrics",
+    report_speakers: "Per speaker",
+    report_response: "Frequency response",
+    report_diff: "Right − left difference",
+    report_history: "Score history",
+    report_delay: "Delay R − L",
+    report_level: "Level difference R − L",
+    report_tilt: "Spectral tilt difference",
+    report_profile: "Score profile",
+    report_distance: "Distance",
+    report_t30: "Mean T30",
+    report_thd: "THD, worst octave",
+    report_target: "Deviation from target",
+    report_footer: "Generated by Speaker Align",
+    report_notes: "Notes",
+
+    reco_title: " Recommendations ",
+    reco_pending: "  Results available after analysis",
+    reco_bucket_placement: "Mostly a placement issue (delay, reflections): move the speakers",
+    reco_bucket_electrical: "Mostly a tuning issue (level, spectrum): trim or EQ, no need to move furniture",
+    checklist_title: " Before measuring ",
+    checklist_items: [
+        ("Mic at ear height, at the listening position", ""),
+        ("Room quiet", "[H] measures the noise"),
+        ("Listening volume set", "[J] sets the level"),
+        ("System audio enhancements turned off", "[G] detects them"),
+    ],
+    checklist_auto: "verified",
+    checklist_problem: "problem detected",
+    checklist_hint: "  [1-4] tick · Enter confirm · Esc close",
+    verdict_title: " Verdict ",
+    verdict_pending: "  Measure the left [L], then the right [R], then analyze [A].",
+    verdict_good: "  Your speakers are well placed: nothing to change.",
+    verdict_adjust: "  Almost there: follow the advice below, then measure again.",
+    verdict_fix: "  Placement needs work: follow the advice below, then measure again.",
+    reco_closer: "Move the right speaker closer",
+    reco_farther: "Move the right speaker further away",
+    reco_left_closer: "Move the left speaker closer",
+    reco_left_farther: "Move the left speaker further away",
+    reco_channel_delay: |side, ms, cm| {
+        format!("Delay the {} speaker by {:.2} ms in the AVR / DSP (distance setting −{:.0} cm)", side, ms, cm)
+    },
+    reco_level_after: |db| format!("    Expected ΔL after the move ≈ {:+.1} dB", db),
+    reco_distance_blocked: "Distance offset cannot be fixed within the [placement] constraints",
+    reco_distance_blocked_hint: "    Allow a move or declare channel_delay = true",
+    reco_too_loud: "Right side too loud — move away or toe out",
+    reco_too_quiet: "Right side too quiet — move closer or toe in",
+    reco_level: |db| format!("    Δ level = {:.1} dB", db),
+    reco_gain_trim: |side, n, db| format!("    AVR: {} speaker level −{:.1} dB ({} step{})", side, db, n, if n > 1 { "s" } else { "" }),
+    reco_gain_balance: |side, n, db| format!("    Balance: {} notch{} toward the {} (≈ {:.1} dB)", n, if n > 1 { "es" } else { "" }, side, db),
+    reco_gain_detents: |side, n, db| format!("    Input gain of the {} speaker: −{} detent{} (≈ {:.1} dB)", side, n, if n > 1 { "s" } else { "" }, db),
+    reco_gain_residual: |db| format!(", {:.1} dB left", db),
+    reco_too_bright: "Too much treble on the right — toe out",
+    reco_too_dull: "Not enough treble on the right — toe in",
+    reco_clarity: |side| format!("Lower clarity on the {} — treat nearby reflections", side),
+    reco_reflection: |surface, side, db| {
+        format!("Reflection ({}) {:.1} dB stronger on the {} — absorber at the reflection point", surface, db, side)
+    },
+    surface_names: ["floor", "ceiling", "side wall", "wall behind the speaker"],
+    or_word: " or ",
+    reflections_label: "Refl.",
+    reco_sign_mismatch: |side| format!("The {} speaker is closer but quieter — aim or obstruction, not distance", side),
+    reco_sign_mismatch_hint: "    Check the toe-in and clear the path before touching the volume",
+    reco_swapped: "Left and right look swapped: the delay contradicts the declared distances",
+    reco_swapped_hint: "    [|] swap the captures, or measure each speaker again under its own key",
+    age_mic_cal: |d| format!("mic cal: {} d", d),
+    age_loopback: |d| format!("loopback: {} d", d),
+    age_loopback_never: "loopback: never measured",
+    reminder_mic_stale: |d| format!("Mic calibration verified {} days ago: check it", d),
+    reminder_mic_device: "Mic calibration verified with another audio input: is it the same mic?",
+    reminder_mic_hint: "    [_] once checked (file of the right mic, same serial number)",
+    reminder_loopback_unmeasured: "Reference loopback configured but never measured",
+    reminder_loopback_stale: |d| format!("Loopback latency measured {} days ago", d),
+    reminder_loopback_device: "Loopback latency measured with other devices",
+    reminder_loopback_hint: "    measure again: capture [L] or [R] with the loopback connected",
+    reco_reversed: |side| format!("The {} speaker is wired in reverse polarity", side),
+    reco_reversed_hint: "    Swap + and − at one end of its cable (amplifier or speaker)",
+    reco_reversed_both: "  Both sides in reverse polarity (amplifier, DSP?) — no effect on the stereo image",
+    reco_weak_direct: |side| format!("The {} speaker: a reflection is louder than the direct sound — distance taken from the first arrival", side),
+    reco_weak_direct_hint: "    Aim the speaker at the mic, clear the desk; [F4] sets the onset threshold",
+    reco_optimal: "  ✓ Optimal placement reached!",
+    reco_symmetric: "  Both speakers are symmetrically aligned.",
+
+    history_title: " History ",
+    history_trend_title: " History — trend ",
+    experiments_title: " Changes (one at a time) ",
+    positions_title: |n| format!(" Candidate positions ({}) ", n),
+    positions_header: "      pos.  total  score      bass σ  reflect.",
+    experiment_prompt: "Change: ",
+    experiment_hint: "   Enter confirm · Esc cancel",
+    experiment_pending: "measure, then analyze [A]…",
+    seats_title: |n| format!(" Listening seats ({}) ", n),
+    seats_header: "      seat                   score      Δt        ΔL",
+    seats_spread: "  R − L spread: ",
+    seat_prompt: "Seat: ",
+    environment_title: |n| format!(" Environment ({} analyses) ", n),
+    environment_current: "  Current conditions [$]: ",
+    environment_none: "none (noise floor [H] and time only)",
+    environment_factors: ["Noise floor", "Time (UTC)", "Temperature", "Humidity"],
+    environment_missing: |n, min| format!("{} analysis(es) with data, {} needed", n, min),
+    environment_linked: "  score follows it: environmental drift?",
+    environment_unlinked: "  no clear link",
+    environment_prompt: "Temperature °C, humidity %, room L×W×H m: ",
+    environment_room: |l, w, h| format!(" · room {} × {} × {} m", l, w, h),
+    ambient_expected: "expected: « temperature humidity length×width×height », e.g. 21.5 45 5.2x3.8x2.5",
+    ambient_unreadable: |label, field| format!("{} unreadable: « {} »", label, field),
+    ambient_out_of_range: |label, min, max, v| format!("{} out of range ({} to {}): {}", label, min, max, v),
+    room_dimensions_format: |field| format!("unreadable dimensions: « {} » (length×width×height in m, e.g. 5.2x3.8x2.5)", field),
+    room_dimension_range: |v, min, max| format!("dimension out of range ({} to {} m): {}", min, max, v),
+    ambient_error: |e| format!("Conditions: {}", e),
+    ambient_cleared: "Conditions cleared: the next analyses will have none",
+    ambient_noted: |t, h, c| format!("Conditions noted for the next analyses: {} · {} — speed of sound {:.1} m/s", t, h, c),
+    room_dimensions_noted: |l, w, h, n| format!(" · room {} × {} × {} m, {} modes computed", l, w, h, n),
+    notes_title: |time| match time {
+        Some(time) => format!(" Notes of the {} analysis ", time),
+        None => " Notes for the next analysis ".to_string(),
+    },
+    notes_fields: ["Note", "Mic", "Speakers", "Room"],
+    notes_hint: "  Tab next field · PgUp/PgDn or click the history: other analysis · Enter confirm · Esc cancel",
+    notes_saved: |time| format!("Notes of the {} analysis saved", time),
+    notes_saved_next: "Notes saved for the next analysis",
+    seat_delay: "Delay",
+    seat_level: "Level",
+    reco_seat_placement: |metric, n| format!("{}: same offset at all {} seats — speaker placement", metric, n),
+    reco_seat_anomaly: |metric, name| {
+        format!("{}: offset only at the \"{}\" seat — specific to that seat, don't move the speakers", metric, name)
+    },
+    baseline_title: " Accepted asymmetry ",
+    baseline_prompt: "Reason: ",
+    baseline_accepted: |score, time| format!("  score {} accepted at {}", score, time),
+    baseline_correction: "  Correction: ",
+    baseline_delay: |side, ms| format!("delay {} by {:.2} ms", side, ms),
+    baseline_eq: |n| format!("{} EQ filter(s) on the right", n),
+    baseline_none: "none",
+    baseline_checks: |ok, n| format!("  Compliance: {} / {} analyses compliant", ok, n),
+    baseline_compliant: "  ✓ latest analysis within the accepted offset",
+    baseline_deviation: |metric, accepted, current, unit| {
+        format!("  ✕ {}: {:+.2} {} instead of {:+.2} accepted", metric, current, unit, accepted)
+    },
+    history_empty: "  No measurements",
+
+    label_value: |label, value| format!("{}: {}", label, value),
+    multisub_needs_subs: |min, max| format!("Several subwoofers: start with --subs LFE,SL… ({} to {} outputs)", min, max),
+    multisub_planned: |n, before, after| {
+        format!("Seat {} measured: spread between seats {:.1} dB → {:.1} dB with the proposed settings", n, before, after)
+    },
+    multisub_next_seat: |n, min| format!("Seat {} measured: move the mic and press [F3] at another seat ({} at least)", n, min),
+    multisub_needs_loopback: "Several subwoofers: declare the reference loopback ([loopback]) to time each capture",
+    multisub_no_arrival: "No signal in the reference loopback: seat discarded, check the cable then press [F3]",
+    multisub_dropped: |n| format!("Last seat removed ({} seat(s) kept): press [F3] to measure it again", n),
+    checklist_first: "Before the first measurement: tick the list [1-4] then confirm [Enter]",
+    checklist_incomplete: "List incomplete: tick every item [1-4] or check it with a measurement",
+    checklist_done: "List confirmed — [L] to measure the left speaker",
+    mmm_walk: |side, secs| format!("{} speaker: slowly move the mic around the listening seat ({:.0} s)", side, secs),
+    watch_stopped: "Live tracking stopped",
+    nudge_on: "Audio guidance on: closer beeps = nearly aligned, high = move the right speaker forward, low = move it back",
+    nudge_off: "Audio guidance off",
+    dual_stopped: "Simultaneous L + R tracking stopped",
+    dual_too_short: "Simultaneous tracking: capture too short to separate L and R",
+    chain_done: "Chained L → R measurement done: [A] to analyse",
+    err_no_capture: "no capture",
+    level_no_burst: "Level: pink noise burst not captured",
+    level_set: |burst, sweep, expected| {
+        format!("Level: burst captured at {:.1} dBFS → sweep at {:+.1} dBFS (expected capture {:.1} dBFS)", burst, sweep, expected)
+    },
+    level_limited: " — target out of reach: adjust the amplifier volume or the mic gain",
+    bass_not_detected: "Bass management test: bursts not found in the capture",
+    test_not_detected: |test| format!("{} test: signal not found in the capture", test),
+    wiring_not_detected: "Wiring: bursts not found in the capture",
+    sub_no_arrival: "Subwoofer: direct sound arrival not found in the IR",
+    baseline_compliant_check: "Within the accepted asymmetry",
+    baseline_outside_check: "Outside the accepted asymmetry: see the panel",
+    experiment_effect: |score, delay, level| format!("Effect of the change: score {:+}, |Δt| {:+.2} ms, |ΔL| {:+.1} dB", score, delay, level),
+    debug_written: |n, dir| format!("DSP intermediates: {} files in {}", n, dir),
+    range_set: |lo, hi| format!("Analysis range: {:.0}–{:.0} Hz", lo, hi),
+    calibration_set: |name| format!("Mic calibration: {}", name),
+    calibration_none: "Mic calibration: none (mic assumed flat)",
+    target_set: |name| format!("Target curve: {}", name),
+    target_none: "Target curve: none",
+    mask_set: |name| format!("L/R tolerance mask: {}", name),
+    mask_none: "L/R tolerance mask: none",
+    checks_due: |n| format!("Reminder: {} check{} to redo, see the advice", n, if n > 1 { "s" } else { "" }),
+    checks_log_error: |e| format!("Check log: {}", e),
+    calibration_nothing_to_verify: "No mic calibration to verify (['] to choose one)",
+    calibration_verified: |name| format!("Mic calibration {} marked as verified today", name),
+    window_set: |name| format!("FFT window: {}", name),
+    onset_set: |db| format!("IR onset threshold: {:.0} dB above the noise floor", db),
+    smoothing_off: "Smoothing: none (raw bands)",
+    smoothing_set: |n| format!("Smoothing: 1/{} octave", n),
+    captures_swapped: "Left and right captures swapped",
+    experiment_needs_before: "Analyse first [A]: the \"before\" measurement of the change is missing",
+    experiment_noted: |text| format!("Change noted: \"{}\" — measure then analyse [A] to see its effect", text),
+    seat_needs_analysis: "Analyse first [A] the seat to record",
+    seat_recorded: |name, n| format!("Seat \"{}\" recorded ({} seat{})", name, n, if n > 1 { "s" } else { "" }),
+    schedule_set: |at| format!("Scheduled start: every capture starts at {} (system clock)", at),
+    schedule_cleared: "Scheduled start removed: captures start immediately",
+    schedule_error: |e| format!("Scheduled start: {}", e),
+    baseline_dropped: "Accepted asymmetry dropped: symmetry is the goal again",
+    baseline_needs_analysis: "Analyse first [A]: the asymmetry to accept is missing",
+    baseline_noted: |reason| format!("Asymmetry accepted: \"{}\" — electrical correction exported by [E]", reason),
+    report_needs_analysis: "Report: run the analysis [A] first",
+    report_charts_failed: |e| format!("Report: charts failed ({})", e),
+    report_written: |path| format!("Report written: {} (open it in a browser)", path),
+    png_written: |path| format!("Chart written: {}", path),
+    png_no_curves: "PNG: no curve, capture [L] / [R] first",
+    position_needs_analysis: "Analyse first [A] the position to mark",
+    position_marked: |label, score, rank, n| format!("Position {} marked: {:.0}/100, rank {} of {}", label, score, rank, n),
+    session_saved: |path| format!("Session saved: {}", path),
+    session_loaded: |path| format!("Session loaded: {}", path),
+    session_migrated: |path, from, to| format!("Session loaded: {} (format v{} upgraded to v{})", path, from, to),
+    session_shared: |path, kb, audio| {
+        format!("Share written: {} ({} KB, {})", path, kb, if audio { "with raw audio" } else { "without raw audio" })
+    },
+    exported: |names| format!("Exported: {}", names),
+    theme_set: |name| format!("Theme: {}", name),
+    mode_expert: "Expert mode: all panels",
+    mode_basic: "Basic mode: captures, verdict and advice",
+    err_busy_hint: |e| format!("{} — close the other application or restart with --retry-default-config", e),
+    help: &[
+        ("[L]", "Capture left"),
+        ("[R]", "Capture right"),
+        ("[Tab]", "L then R"),
+        ("[A]", "Analyse"),
+        ("[+/-]", "Pre-capture delay"),
+        ("[\\]", "Start at :SS"),
+        ("[?]", "L + R at once"),
+        ("[F2]", "Guidance beeps"),
+        ("[F3]", "Multiple subs"),
+        ("[Shift+F3]", "Redo the last seat"),
+        ("[F4]", "Onset threshold"),
+        ("[F5]", "Target curve"),
+        ("[Shift+F5]", "L/R tolerance mask"),
+        ("[1-8]", "Channel FL…SBR"),
+        ("[F]", "Reference"),
+        ("[U]", "Subwoofer"),
+        ("[B]", "Bass management"),
+        ("[P]", "Polarity"),
+        ("[Z]", "Rub & buzz"),
+        ("[C]", "Compression"),
+        ("[G]", "Linearity"),
+        ("[K]", "Channel map"),
+        ("[V/N]", "Quick check"),
+        ("[0]", "Quick distances"),
+        ("[;]", "Spatial average"),
+        ("[D]", "Live watch"),
+        ("[H]", "Background noise"),
+        ("[J]", "Auto level"),
+        ("[/]", "Log a change"),
+        ("[#]", "Candidate position"),
+        ("[:]", "Listening seat"),
+        ("[%]", "Accept asymmetry"),
+        ("[$]", "Temperature / humidity / room"),
+        ("[_]", "Calibration checked"),
+        ("[\"]", "Environment"),
+        ("[&]", "Compare A/B"),
+        ("[F6]", "A/B for a forum"),
+        ("[F7]", "Theme"),
+        ("[F8]", "Metric domain"),
+        ("[F9]", "Metric detail"),
+        ("[End]", "HTML report"),
+        ("[Ins]", "PNG chart"),
+        ("[F12]", "Notes"),
+        ("[←→]", "Spectrum cursor"),
+        ("[Shift+↑↓]", "Spectrum zoom"),
+        ("[M]", "Analysis range"),
+        ("[~]", "Smoothing"),
+        ("[^]", "FFT window"),
+        ("[9]", "Time gate"),
+        ("[|]", "Swap L / R"),
+        ("[']", "Mic calibration"),
+        ("[I]", "IR"),
+        ("[Y]", "Regions"),
+        ("[`]", "Trend"),
+        ("[W]", "Listening window"),
+        ("[S/O]", "Session"),
+        ("[E]", "Export"),
+        ("[T]", "Français"),
+        ("[X]", "Reset"),
+        ("[!]", "Basic mode"),
+        ("[Q]", "Quit"),
+    ],
+    help_basic: &[
+        ("[L]", "Capture left"),
+        ("[R]", "Capture right"),
+        ("[A]", "Analyse"),
+        ("[T]", "Français"),
+        ("[!]", "Expert mode"),
+        ("[Q]", "Quit"),
+    ],
+
+    theme_color_unknown: |field| format!("[theme.colors]: unknown colour \"{}\" (green, orange, cyan, red, yellow, purple, gray, white, border…)", field),
+    theme_color_unreadable: |field, text| {
+        format!("[theme.colors] {} = \"{}\": unreadable colour (#rrggbb, a name such as lightgreen, or an index 0-255)", field, text)
+    },
+
+    config_unreadable: |path| format!("Cannot read the configuration {}", path),
+    config_invalid: |path| format!("Invalid configuration file: {}", path),
+    sweep_out_of_range: |d_min, d_max, start, stop, amp, averages, gap_min, gap_max| {
+        format!(
+            "sweep out of range: duration {}–{} s, start ≥ {} Hz, stop ≤ {} Hz, stop ≥ 2 × start, amplitude {}–1.0, averages 1–{}, L → R silence {}–{} s",
+            d_min, d_max, start, stop, amp, averages, gap_min, gap_max
+        )
+    },
+    level_target_range: |min, max| format!("level.target_dbfs out of range: {} to {} dBFS", min, max),
+    gain_step_range: |min, max| format!("gain.step_db out of range: {} to {} dB", min, max),
+    score_weights_positive: "score: spectrum, level and time must be positive",
+    score_weights_total: |total| format!("score: spectrum + level + time = {} (100 expected)", total),
+    score_tolerances_positive: "score: delay_tolerance_ms and level_tolerance_db must be positive",
+    room_out_of_range: |field, min, max, v| format!("room: {} out of range ({} to {}): {}", field, min, max, v),
+    room_dimensions_together: "room: length_m, width_m and height_m go together",
+    keys_unknown_action: |action| format!("keys: unknown action \"{}\"", action),
+    keys_unreadable: |key| format!("keys: unreadable key \"{}\" (a character, Tab, Ins, End or F1…F12)", key),
+    keys_reserved: |key| format!("keys: \"{}\" is a fixed key (captures 1-8, sweep, delay)", key),
+    keys_conflict: |key, a, b| format!("keys: \"{}\" bound to both {} and {}", key, a, b),
+    monitor_at: |at| format!("[monitor] at = \"{}\": time expected as HH:MM", at),
+    monitor_min_score: |score| format!("[monitor] min_score = {}: expected between 0 and 100", score),
+    webhook_url: |url| format!("[webhook] url = \"{}\": http:// or https:// URL expected", url),
+    schedule_second: |text| format!("second of the minute expected (0 to 59), e.g. :30 — read \"{}\"", text),
+    err_no_output: "No audio output available",
+    err_output_format: "No usable stereo output format found (sample rate and format)",
+    err_too_few_channels: |n, ch| format!("The output only has {} channels: cannot play on channel {}", n, ch),
+    err_loopback_input: |input, n| format!("Loopback input {} missing: the input only has {} channels (0-based indices)", input, n),
+    err_no_input: "No microphone available. Plug in a microphone and try again.",
+    err_input_format: "No usable mono input format found (sample rate and format)",
+    err_no_samples: "No samples captured. Check that the microphone is active.",
+    err_capture_armed: "Another capture is already using the audio streams: wait for it to finish.",
+    err_truncated: |received, expected, pct, required| {
+        format!(
+            "Truncated capture: {} samples received out of {} expected ({:.0} %, minimum {}). \
+             The microphone stream was interrupted — run the measurement again.",
+            received, expected, pct, required
+        )
+    },
+    err_open_failed: |device, detail| format!("Cannot open “{}”: {}", device, detail),
+    err_default_config: |device, format, rate| {
+        format!("Default config of “{}” is unusable ({}, {} Hz; F32, I32, I16 or U16 format required)", device, format, rate)
+    },
+    err_device_busy: |device, detail| {
+        format!("Device “{}” is in use by another application (DAW, exclusive-mode player): {}", device, detail)
+    },
+    err_timeout: |secs| format!("Audio devices not responding after {:.0} s (in use by another application?)", secs),
+    err_clipped: |n, db| {
+        format!(
+            "Microphone input clipped ({} samples at full scale): lower the mic gain by at least {:.0} dB \
+             (or the amplifier volume) and run it again.",
+            n, db
+        )
+    },
+    err_too_quiet: |rms, db| {
+        format!(
+            "Capture too quiet ({:.0} dBFS RMS): raise the mic gain by about {:.0} dB \
+             (or the amplifier volume) and check that the right microphone is selected.",
+            rms, db
+        )
+    },
+    err_cancelled: "Capture cancelled",
+    capture_stopping: "Stopping the capture…",
+    err_overrun: |n| format!("Capture interrupted: {} samples lost (system overloaded), try again", n),
+    err_output_stream: |e| format!("Audio output error: {}", e),
+    err_input_stream: |e| format!("Audio input error: {}", e),
+    device_unknown: "Unknown",
+    device_none: "None",
+    cannot_read: |path| format!("Cannot read {}", path),
+    cannot_write: |path| format!("Cannot write {}", path),
+    cannot_create: |path| format!("Cannot create {}", path),
+    cannot_open: |path| format!("Cannot open {}", path),
+    nothing_to_export: "Nothing to export: no speaker captured",
+    frd_left_title: "LEFT speaker",
+    frd_right_title: "RIGHT speaker",
+    session_unreadable: |path| format!("Cannot read the session {}", path),
+    session_shared_unreadable: |path| format!("Unreadable shared session: {}", path),
+    session_invalid: |path| format!("Invalid session file: {}", path),
+    session_not_object: "the session is not a JSON object",
+    session_no_version: "version number missing",
+    session_version_zero: "invalid session version: 0",
+    session_too_new: |v, current| {
+        format!("session in format v{}, created by a newer version of speaker-align (supported format: v{})", v, current)
+    },
+    session_v1_channel: |key| format!("unknown v1 channel in \"{}\"", key),
+    calibration_unreadable: |path| format!("Reading the calibration file {}", path),
+    calibration_invalid: |path| format!("Invalid calibration file: {}", path),
+    calibration_unknown: |name, known| format!("Unknown built-in calibration: {} (available: {})", name, known),
+    calibration_too_few_points: "at least two frequency / dB points are required",
+    target_unreadable: |path| format!("Reading the target curve {}", path),
+    target_invalid: |path| format!("Invalid target curve: {}", path),
+    target_unknown: |name, known| format!("Unknown built-in target curve: {} (available: {})", name, known),
+    mask_unknown: |name, known| format!("Unknown built-in tolerance mask: {} (available: {})", name, known),
+    asset_invalid: |name| format!("Invalid resource {}", name),
+    checks_log_invalid: |path| format!("Invalid check log: {}", path),
+    arg_channel_unknown: |name| format!("unknown channel: {} (FL, FR, C, LFE, SL, SR, SBL, SBR or index)", name),
+    arg_lang_unknown: |code| format!("unknown language: {} (fr or en)", code),
+    arg_theme_unknown: |name| format!("unknown theme: {} (default, high-contrast, ansi16 or mono)", name),
+    mock_number: |name, value| format!("--mock-audio: number expected after \"{}:\", got \"{}\"", name, value),
+    mock_value: |name| format!("--mock-audio: value expected, e.g. \"{}:0.5\"", name),
+    mock_unknown: |name| format!("--mock-audio: unknown \"{}\" (busy, hang, reject, rate, short, drop, clip, speed, inputs, skew)", name),
+    headless_level: "Level calibration…",
+    headless_sweep_level: |sweep, expected| format!("Sweep at {:+.1} dBFS (expected capture {:.1} dBFS)", sweep, expected),
+    headless_level_limited: "Level target out of reach: adjust the amplifier volume or the mic gain",
+    headless_capture: |side| format!("Capturing {}…", side.to_uppercase()),
+    headless_drift: |ppm| format!("Clock drift corrected: {:+.1} ppm", ppm),
+    headless_summary: |score, delay, level| format!("Speaker Align — score {}/100, delay {:+.2} ms, level {:+.1} dB", score, delay, level),
+    headless_incomplete: "Speaker Align — incomplete analysis",
+    monitor_next: |h, m, wait_h, wait_min| format!("Next measurement at {:02}:{:02} UTC (in {} h {:02} min)", h, m, wait_h, wait_min),
+    monitor_failed: |time, e| format!("{} — measurement failed: {}", time, e),
+    monitor_alert_score: |score, min| format!("ALERT — Speaker Align: score {}/100 below the threshold of {}", score, min),
+    monitor_alert_incomplete: "ALERT — Speaker Align: incomplete analysis",
+    monitor_score: |time, score, min| format!("{} — score {} (threshold {})", time, score, min),
+    monitor_incomplete: |time| format!("{} — incomplete analysis", time),
+    monitor_alert: "ALERT: installation out of tolerance",
+    monitor_history: |path| format!("Cannot open the history {}", path),
+    mqtt_not_sent: |broker, e| format!("MQTT alert not sent ({}): {}", broker, e),
+    mqtt_no_address: |broker| format!("broker address not found: {}", broker),
+    mqtt_refused: |code| format!("connection refused by the broker (code {})", code),
+    webhook_not_object: "document to notify is not an object",
+    webhook_not_sent: |url, e| format!("Webhook not sent ({}): {}", url, e),
+    subs_count: |min, max, n| format!("--subs: {} to {} outputs expected, {} given", min, max, n),
+    script_ok: |commands, checks, quit| {
+        format!("Script OK: {} command(s), {} check(s){}", commands, checks, if quit { ", stopped by [Q]" } else { "" })
+    },
+};
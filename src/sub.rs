@@ -0,0 +1,110 @@
+// ============================================================
+//  sub.rs — Intégration du caisson de basses
+//
+//  Le caisson (canal LFE par défaut ou sortie choisie) est mesuré au sweep
+//  comme une enceinte, puis comparé à l'enceinte principale GAUCHE
+//  à la fréquence de coupure :
+//    - retard d'arrivée (front de l'IR) caisson − principales
+//    - différence de phase à la coupure (DFT de l'IR à fc)
+//  On en déduit le retard à appliquer au caisson pour que les deux
+//  sources soient en phase à fc, exprimé en ms (miniDSP) et en
+//  distance à corriger dans l'AVR.
+//
+//  Les deux IR partent du début de lecture et subissent la même
+//  latence système : leurs phases sont directement comparables.
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+
+use crate::{dsp, measurement::Measurement};
+
+/// Durée de l'IR prise en compte pour la phase à la coupure.
+const PHASE_WINDOW_SECS: f32 = 0.3;
+/// Marge conservée avant le pic de l'IR (front de montée du caisson).
+const PEAK_MARGIN_SECS: f32 = 0.005;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SubAlignment {
+    pub crossover_hz: f32,
+    /// Arrivée du caisson − arrivée des principales (ms, positif = caisson en retard).
+    pub arrival_diff_ms: f32,
+    /// Phase caisson − principales à la coupure, ramenée dans [-180°, 180°].
+    pub phase_deg: f32,
+    /// Retard à AJOUTER au caisson (ms) ; négatif = retarder les principales.
+    pub correction_ms: f32,
+}
+
+impl SubAlignment {
+    /// Correction équivalente en distance : dans un AVR, retarder le caisson
+    /// revient à DIMINUER sa distance réglée de cette valeur (m).
+    pub fn distance_correction_m(&self) -> f32 {
+        self.correction_ms / 1000.0 * dsp::speed_of_sound()
+    }
+
+    /// Perte de sommation à la coupure avant correction (dB, niveaux égaux supposés).
+    pub fn summation_loss_db(&self) -> f32 {
+        let half = (self.phase_deg.to_radians() / 2.0).cos().abs();
+        if half > 1e-6 { 20.0 * half.log10() } else { -60.0 }
+    }
+}
+
+/// Phase (radians) de l'IR à la fréquence `freq`, sur une fenêtre en demi-Hann
+/// calée juste avant le pic de l'IR : la pondération est la même pour les deux
+/// sources quel que soit leur retard, seule la référence de temps est absolue.
+fn phase_at(ir: &[f32], freq: f32, sample_rate: u32) -> Option<f32> {
+    let peak = ir
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+        .map(|(i, _)| i)?;
+    let start = peak.saturating_sub((PEAK_MARGIN_SECS * sample_rate as f32) as usize);
+    let len = ((PHASE_WINDOW_SECS * sample_rate as f32) as usize).min(ir.len().saturating_sub(start));
+    if len < 2 {
+        return None;
+    }
+    let (mut re, mut im) = (0.0f32, 0.0f32);
+    for (n, &v) in ir[start..start + len].iter().enumerate() {
+        let w = 0.5 + 0.5 * (PI * n as f32 / (len - 1) as f32).cos();
+        let t = (start + n) as f32 / sample_rate as f32;
+        re += v * w * (2.0 * PI * freq * t).cos();
+        im -= v * w * (2.0 * PI * freq * t).sin();
+    }
+    (re != 0.0 || im != 0.0).then(|| im.atan2(re))
+}
+
+/// Compare le caisson et l'enceinte principale à la fréquence `crossover_hz`
+/// (`onset_db` : seuil du front de l'IR).
+pub fn align(sub: &Measurement, mains: &Measurement, crossover_hz: f32, pre_delay_samples: usize, onset_db: f32) -> Option<SubAlignment> {
+    let sr = sub.sample_rate;
+    let ir_of = |m: &Measurement| {
+        m.ir.clone()
+            .unwrap_or_else(|| dsp::compute_impulse_response(&m.samples, &m.test_signal, &m.sweep, sr))
+    };
+    let ir_sub = ir_of(sub);
+    let ir_main = ir_of(mains);
+
+    let arrival_diff_ms = (dsp::distance_from_ir(&ir_sub, sr, pre_delay_samples, onset_db)?
+        - dsp::distance_from_ir(&ir_main, sr, pre_delay_samples, onset_db)?)
+        / dsp::speed_of_sound()
+        * 1000.0;
+
+    let phase_sub = phase_at(&ir_sub, crossover_hz, sr)?;
+    let phase_main = phase_at(&ir_main, crossover_hz, sr)?;
+    let dphi = (phase_sub - phase_main + PI).rem_euclid(2.0 * PI) - PI;
+
+    // Retarder le caisson de d tourne sa phase de −2π·fc·d : d = Δφ / (2π·fc) + k / fc.
+    // Parmi les solutions, on garde la plus proche de la compensation du retard
+    // d'arrivée (évite de caler le caisson une période à côté).
+    let period_ms = 1000.0 / crossover_hz;
+    let base_ms = dphi / (2.0 * PI * crossover_hz) * 1000.0;
+    let k = ((-arrival_diff_ms - base_ms) / period_ms).round();
+    let correction_ms = base_ms + k * period_ms;
+
+    Some(SubAlignment {
+        crossover_hz,
+        arrival_diff_ms,
+        phase_deg: dphi.to_degrees(),
+        correction_ms,
+    })
+}
@@ -0,0 +1,60 @@
+// ============================================================
+//  target.rs — Courbe cible
+//
+//  Une courbe cible (plate, courbe de salon, type Harman, ou
+//  fichier .frd / .txt « fréquence  dB ») décrit la réponse visée
+//  pour chaque enceinte. Elle est ramenée sur les bandes log du
+//  spectre avec la même interpolation que les calibrations micro.
+//
+//  Seule la forme compte : l'écart d'une mesure à la cible est pris
+//  après alignement de leurs niveaux moyens sur la plage d'analyse
+//  (`dsp::target_deviation`). L'écart moyen de G et D à la cible
+//  entre alors dans le score (`dsp::compute_score`).
+// ============================================================
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::{
+    assets::{self, AssetKind},
+    calibration::MicCalibration,
+    dsp::NUM_BANDS,
+    strings::tr,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetCurve {
+    /// Nom affiché : fichier ou ressource embarquée.
+    pub name: String,
+    /// Réponse visée sur les `NUM_BANDS` bandes du spectre (dB relatifs).
+    pub bands_db: Vec<f32>,
+}
+
+impl TargetCurve {
+    /// Lit une courbe cible sur disque, ou une cible embarquée (`builtin:<nom>`).
+    pub fn load(path: &Path) -> Result<Self> {
+        if let Some(name) = path.to_str().and_then(assets::builtin_name) {
+            return Self::builtin(name);
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| (tr().target_unreadable)(&path.display().to_string()))?;
+        let curve = MicCalibration::parse(&text).with_context(|| (tr().target_invalid)(&path.display().to_string()))?;
+        let name = path.file_name().map_or_else(|| path.display().to_string(), |n| n.to_string_lossy().into_owned());
+        Ok(Self::from_points(name, curve.points))
+    }
+
+    /// Cible embarquée `name`.
+    pub fn builtin(name: &str) -> Result<Self> {
+        let asset = assets::find(AssetKind::TargetCurve, name).with_context(|| {
+            let known: Vec<&str> = assets::list(AssetKind::TargetCurve).map(|a| a.name).collect();
+            (tr().target_unknown)(name, &known.join(", "))
+        })?;
+        Ok(Self::from_points(asset.name.to_string(), asset.points()?))
+    }
+
+    /// Points (fréquence Hz, dB) triés, interpolés sur les bandes du spectre.
+    fn from_points(name: String, points: Vec<(f32, f32)>) -> Self {
+        let bands_db = MicCalibration { points, name: String::new() }.band_corrections(NUM_BANDS);
+        TargetCurve { name, bands_db }
+    }
+}
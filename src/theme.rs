@@ -0,0 +1,332 @@
+// ============================================================
+//  theme.rs — Couleurs de l'interface
+//
+//  Toutes les couleurs de `ui.rs` viennent du thème courant
+//  (`theme()`), global comme la langue :
+//    default       — palette d'origine (couleurs 24 bits) ;
+//    high-contrast — textes blancs, gris clairs, teintes
+//                    Okabe-Ito (vert bleuté / vermillon, orange /
+//                    bleu ciel) distinguées par les daltoniens ;
+//    ansi16        — les 16 couleurs nommées du terminal, pour les
+//                    terminaux sans couleurs 24 bits ;
+//    mono          — blanc, gris et fond du terminal seulement ;
+//                    les courbes de droite se tracent en demi-blocs
+//                    et leurs étiquettes en italique, la couleur ne
+//                    séparant plus G de D.
+//  Choix : `[theme] name = "…"`, `--theme`, [F7] en cours de
+//  session ; `NO_COLOR` (variable d'environnement) impose `mono`
+//  quand ni l'option ni le fichier n'en choisissent un.
+//
+//  `[theme.colors]` remplace des teintes, par nom de champ de
+//  `Theme`, dans tous les thèmes (et donc après [F7]) :
+//
+//    [theme.colors]
+//    green  = "#00c896"     # 24 bits
+//    orange = "lightred"    # couleur nommée du terminal
+//    gray   = "245"         # index de la palette 256 couleurs
+// ============================================================
+
+use ratatui::{
+    style::{Color, Modifier},
+    symbols::Marker,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    sync::{
+        OnceLock,
+        atomic::{AtomicU8, Ordering},
+    },
+};
+
+use crate::strings::tr;
+
+/// Thèmes disponibles, dans l'ordre de [F7].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    #[default]
+    Default,
+    HighContrast,
+    Ansi16,
+    Mono,
+}
+
+impl ThemeName {
+    pub const ALL: [ThemeName; 4] = [ThemeName::Default, ThemeName::HighContrast, ThemeName::Ansi16, ThemeName::Mono];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemeName::Default => "default",
+            ThemeName::HighContrast => "high-contrast",
+            ThemeName::Ansi16 => "ansi16",
+            ThemeName::Mono => "mono",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ThemeName> {
+        ThemeName::ALL.into_iter().find(|t| t.name() == name.trim().to_lowercase())
+    }
+
+    pub fn next(self) -> ThemeName {
+        ThemeName::ALL[(self as usize + 1) % ThemeName::ALL.len()]
+    }
+
+    pub fn theme(self) -> &'static Theme {
+        match self {
+            ThemeName::Default => &DEFAULT,
+            ThemeName::HighContrast => &HIGH_CONTRAST,
+            ThemeName::Ansi16 => &ANSI16,
+            ThemeName::Mono => &MONO,
+        }
+    }
+}
+
+/// Section `[theme]` du fichier de configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeParams {
+    /// Absent : `default`, ou `mono` si `NO_COLOR` est défini.
+    pub name: Option<ThemeName>,
+    /// Teintes remplacées (`[theme.colors]`) : nom de champ de `Theme` →
+    /// « #rrggbb », nom de couleur ou index 0-255.
+    pub colors: BTreeMap<String, String>,
+}
+
+impl ThemeParams {
+    pub fn validate(&self) -> Result<(), String> {
+        DEFAULT.with_colors(&self.colors).map(|_| ())
+    }
+}
+
+/// Couleurs de l'interface. Les teintes gardent le nom de la palette
+/// d'origine (`green` = gauche et bon score, `orange` = droite…).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub green: Color,
+    pub orange: Color,
+    pub cyan: Color,
+    pub red: Color,
+    pub yellow: Color,
+    pub purple: Color,
+    /// Textes secondaires, étiquettes.
+    pub gray: Color,
+    pub white: Color,
+    /// Bordures des panneaux et séparateurs de la barre d'aide.
+    pub border: Color,
+    /// Panneau de capture gauche : texte capturé, bordure (vide /
+    /// capturé), fond.
+    pub left_done: Color,
+    pub left_border: Color,
+    pub left_border_done: Color,
+    pub left_bg: Color,
+    pub right_done: Color,
+    pub right_border: Color,
+    pub right_border_done: Color,
+    pub right_bg: Color,
+    /// Partie vide de la jauge de progression.
+    pub gauge_bg: Color,
+    /// Curseur du spectre.
+    pub cursor: Color,
+    /// Courbes de l'analyse A dans la comparaison [&].
+    pub overlay_left: Color,
+    pub overlay_right: Color,
+    /// Fonds des zones spectrales (`dsp::SPECTRAL_REGIONS`), assez sombres
+    /// pour laisser lisibles les courbes tracées par-dessus.
+    pub regions: [Color; 5],
+    /// Tracé des courbes de droite et attribut de leurs étiquettes : ce
+    /// qui distingue D de G quand les couleurs ne le font pas (`mono`).
+    pub right_marker: Marker,
+    pub right_modifier: Modifier,
+}
+
+impl Theme {
+    /// Copie du thème aux teintes de `colors` remplacées ; erreur pour un
+    /// nom de champ inconnu ou une couleur illisible.
+    pub fn with_colors(&self, colors: &BTreeMap<String, String>) -> Result<Theme, String> {
+        let mut theme = *self;
+        for (field, text) in colors {
+            let slot = match field.as_str() {
+                "green" => &mut theme.green,
+                "orange" => &mut theme.orange,
+                "cyan" => &mut theme.cyan,
+                "red" => &mut theme.red,
+                "yellow" => &mut theme.yellow,
+                "purple" => &mut theme.purple,
+                "gray" => &mut theme.gray,
+                "white" => &mut theme.white,
+                "border" => &mut theme.border,
+                "left_done" => &mut theme.left_done,
+                "left_border" => &mut theme.left_border,
+                "left_border_done" => &mut theme.left_border_done,
+                "left_bg" => &mut theme.left_bg,
+                "right_done" => &mut theme.right_done,
+                "right_border" => &mut theme.right_border,
+                "right_border_done" => &mut theme.right_border_done,
+                "right_bg" => &mut theme.right_bg,
+                "gauge_bg" => &mut theme.gauge_bg,
+                "cursor" => &mut theme.cursor,
+                "overlay_left" => &mut theme.overlay_left,
+                "overlay_right" => &mut theme.overlay_right,
+                _ => return Err((tr().theme_color_unknown)(field)),
+            };
+            *slot = text.trim().parse().map_err(|_| (tr().theme_color_unreadable)(field, text))?;
+        }
+        Ok(theme)
+    }
+}
+
+const DEFAULT: Theme = Theme {
+    green: Color::Rgb(0, 255, 135),
+    orange: Color::Rgb(255, 107, 53),
+    cyan: Color::Rgb(0, 204, 255),
+    red: Color::Rgb(255, 45, 85),
+    yellow: Color::Rgb(255, 214, 10),
+    purple: Color::Rgb(168, 85, 247),
+    gray: Color::Rgb(80, 80, 100),
+    white: Color::Rgb(220, 220, 230),
+    border: Color::Rgb(35, 35, 55),
+    left_done: Color::Rgb(0, 120, 70),
+    left_border: Color::Rgb(0, 60, 35),
+    left_border_done: Color::Rgb(0, 100, 60),
+    left_bg: Color::Rgb(0, 12, 8),
+    right_done: Color::Rgb(160, 70, 30),
+    right_border: Color::Rgb(70, 35, 15),
+    right_border_done: Color::Rgb(120, 55, 20),
+    right_bg: Color::Rgb(10, 6, 3),
+    gauge_bg: Color::Rgb(10, 10, 20),
+    cursor: Color::Rgb(90, 90, 120),
+    overlay_left: Color::Rgb(40, 120, 80),
+    overlay_right: Color::Rgb(130, 65, 40),
+    regions: [
+        Color::Rgb(34, 18, 44),
+        Color::Rgb(16, 24, 48),
+        Color::Rgb(14, 36, 26),
+        Color::Rgb(42, 36, 12),
+        Color::Rgb(44, 24, 14),
+    ],
+    right_marker: Marker::Braille,
+    right_modifier: Modifier::empty(),
+};
+
+const HIGH_CONTRAST: Theme = Theme {
+    green: Color::Rgb(0, 200, 150),
+    orange: Color::Rgb(230, 159, 0),
+    cyan: Color::Rgb(86, 180, 233),
+    red: Color::Rgb(240, 90, 20),
+    yellow: Color::Rgb(240, 228, 66),
+    purple: Color::Rgb(204, 121, 167),
+    gray: Color::Rgb(175, 175, 185),
+    white: Color::Rgb(255, 255, 255),
+    border: Color::Rgb(120, 120, 140),
+    left_done: Color::Rgb(0, 160, 120),
+    left_border: Color::Rgb(0, 110, 80),
+    left_border_done: Color::Rgb(0, 200, 150),
+    left_bg: Color::Reset,
+    right_done: Color::Rgb(190, 130, 0),
+    right_border: Color::Rgb(140, 95, 0),
+    right_border_done: Color::Rgb(230, 159, 0),
+    right_bg: Color::Reset,
+    gauge_bg: Color::Rgb(40, 40, 50),
+    cursor: Color::Rgb(220, 220, 230),
+    overlay_left: Color::Rgb(0, 120, 90),
+    overlay_right: Color::Rgb(150, 105, 0),
+    regions: DEFAULT.regions,
+    right_marker: Marker::Braille,
+    right_modifier: Modifier::empty(),
+};
+
+const ANSI16: Theme = Theme {
+    green: Color::LightGreen,
+    orange: Color::LightRed,
+    cyan: Color::LightCyan,
+    red: Color::Red,
+    yellow: Color::Yellow,
+    purple: Color::LightMagenta,
+    gray: Color::DarkGray,
+    white: Color::White,
+    border: Color::DarkGray,
+    left_done: Color::Green,
+    left_border: Color::DarkGray,
+    left_border_done: Color::Green,
+    left_bg: Color::Reset,
+    right_done: Color::LightRed,
+    right_border: Color::DarkGray,
+    right_border_done: Color::LightRed,
+    right_bg: Color::Reset,
+    gauge_bg: Color::Reset,
+    cursor: Color::Gray,
+    overlay_left: Color::Green,
+    overlay_right: Color::Red,
+    regions: [Color::Reset; 5],
+    right_marker: Marker::Braille,
+    right_modifier: Modifier::empty(),
+};
+
+const MONO: Theme = Theme {
+    green: Color::White,
+    orange: Color::White,
+    cyan: Color::White,
+    red: Color::White,
+    yellow: Color::White,
+    purple: Color::White,
+    gray: Color::Gray,
+    white: Color::White,
+    border: Color::DarkGray,
+    left_done: Color::Gray,
+    left_border: Color::DarkGray,
+    left_border_done: Color::Gray,
+    left_bg: Color::Reset,
+    right_done: Color::Gray,
+    right_border: Color::DarkGray,
+    right_border_done: Color::Gray,
+    right_bg: Color::Reset,
+    gauge_bg: Color::DarkGray,
+    cursor: Color::Gray,
+    overlay_left: Color::DarkGray,
+    overlay_right: Color::DarkGray,
+    regions: [Color::Reset; 5],
+    right_marker: Marker::HalfBlock,
+    right_modifier: Modifier::ITALIC,
+};
+
+static THEME: AtomicU8 = AtomicU8::new(0);
+/// Thèmes aux teintes de `[theme.colors]`, dans l'ordre de `ThemeName::ALL`.
+static CUSTOM: OnceLock<[Theme; 4]> = OnceLock::new();
+
+/// Thème choisi.
+pub fn theme_name() -> ThemeName {
+    ThemeName::ALL.get(THEME.load(Ordering::Relaxed) as usize).copied().unwrap_or_default()
+}
+
+pub fn set_theme(name: ThemeName) {
+    THEME.store(name as u8, Ordering::Relaxed);
+}
+
+/// Couleurs du thème courant.
+pub fn theme() -> &'static Theme {
+    let name = theme_name();
+    CUSTOM.get().map_or(name.theme(), |themes| &themes[name as usize])
+}
+
+/// Applique `[theme.colors]` à tous les thèmes, une fois au démarrage
+/// (les appels suivants sont sans effet).
+pub fn set_colors(colors: &BTreeMap<String, String>) -> Result<(), String> {
+    if colors.is_empty() {
+        return Ok(());
+    }
+    let mut themes = ThemeName::ALL.map(|name| *name.theme());
+    for theme in &mut themes {
+        *theme = theme.with_colors(colors)?;
+    }
+    let _ = CUSTOM.set(themes);
+    Ok(())
+}
+
+/// Thème de départ : option, sinon fichier, sinon `mono` si `NO_COLOR` est
+/// défini (non vide), sinon `default`.
+pub fn initial(cli: Option<ThemeName>, config: &ThemeParams) -> ThemeName {
+    cli.or(config.name).unwrap_or_else(|| {
+        if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) { ThemeName::Mono } else { ThemeName::Default }
+    })
+}
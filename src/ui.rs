@@ -10,44 +10,226 @@
 //    - Score ring (en ASCII), métriques, recommandations
 //    - Historique des mesures
 //    - Aide clavier en bas
+//    - Zones cliquables à la souris (`HitMap`)
 // ============================================================
 
+use crossterm::event::KeyCode;
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     symbols,
     text::{Line, Span},
     widgets::{
-        Axis, Block, Borders, Chart, Dataset, Gauge, GraphType, List, ListItem, Paragraph, Wrap,
+        block::Title, Axis, Block, Borders, Chart, Dataset, Gauge, GraphType, List, ListItem, Paragraph, Wrap,
     },
 };
 
 use crate::{
-    app::{AppState, Step},
-    dsp::NUM_BANDS,
+    advice::{self, Tone, REGION_RECO_DB},
+    app::{AppState, Fold, HistoryEntry, MetricDomain, SpectrumView, Step},
+    audio::{Channel, Phase},
+    bass::BassRoute,
+    baseline::Deviation,
+    channelmap::SlotProbe,
+    checklist::ITEMS,
+    enhancements::Symptom,
+    environment::{self, Factor},
+    compression::{ChannelCompression, COMPRESSION_LIMIT_DB, LEVELS_DB},
+    distortion::{BurstDistortion, RESIDUE_LIMIT_DB},
+    dsp::{self, ClarityMetrics, DecayTimes, Window, NUM_BANDS, OCTAVE_CENTERS},
+    dualnoise,
+    gain::GainControl,
+    keymap,
+    harmonics::THD_LIMIT_DB,
+    linearity::{ChannelLinearity, STEPS_DBFS},
+    quickcheck::ChannelQuickCheck,
+    reminders,
+    roommodes::{self, ModeKind, RoomMode},
+    listening::{GridPosition, GRID, GRID_SPACING_CM},
+    measurement::Measurement,
+    mmm::SpatialAverage,
+    pipeline::AnalysisOptions,
+    polarity::{BandPolarity, DRIVER_BANDS},
+    overlay::OverlayDiff,
+    positions,
+    noise,
+    notes::{MeasurementNotes, NoteField},
+    scoring::Profile,
+    seats::{self, Metric},
+    strings::{format_hz, format_num, format_signed, tr},
+    theme::theme,
+    watch,
 };
 
-// ─── Palette ──────────────────────────────────────────────────────────────────
+/// Fonction de rendu d'un panneau secondaire.
+type PanelFn = fn(&mut Frame, Rect, &AppState);
 
-const GREEN: Color = Color::Rgb(0, 255, 135);
-const ORANGE: Color = Color::Rgb(255, 107, 53);
-const CYAN: Color = Color::Rgb(0, 204, 255);
-const RED: Color = Color::Rgb(255, 45, 85);
-const YELLOW: Color = Color::Rgb(255, 214, 10);
-const PURPLE: Color = Color::Rgb(168, 85, 247);
-const DARK: Color = Color::Rgb(20, 20, 35);
-const GRAY: Color = Color::Rgb(80, 80, 100);
-const WHITE: Color = Color::Rgb(220, 220, 230);
+/// Initiale d'un côté telle que renvoyée par les rapports (« G » / « D »),
+/// dans la langue courante.
+fn side_label(side: &str) -> &'static str {
+    if side == "G" { tr().left_short } else { tr().right_short }
+}
+
+/// Titre du panneau de score, avec la plage d'analyse si elle est réduite
+/// et le profil `[score]` s'il n'est pas celui par défaut.
+fn score_title(state: &AppState) -> String {
+    let s = tr();
+    let title = match state.analysis.range_hz {
+        range if range == AnalysisOptions::default().range_hz => s.score_title.to_string(),
+        (lo, hi) => (s.score_title_range)(&format_hz(lo), &format_hz(hi)),
+    };
+    let profile = match state.scoring.profile {
+        Profile::HomeHifi => return title,
+        Profile::NearfieldStudio => s.profile_studio,
+        Profile::HomeTheater => s.profile_theater,
+    };
+    format!("{}· {} ", title, profile)
+}
 
 fn score_color(score: u32) -> Color {
-    if score >= 85 { GREEN } else if score >= 60 { YELLOW } else { RED }
+    if score >= 85 { theme().green } else if score >= 60 { theme().yellow } else { theme().red }
+}
+
+/// « placement 90  réglage 74 », chaque part dans la couleur de sa note.
+fn score_split_spans(state: &AppState) -> Vec<Span<'static>> {
+    let s = tr();
+    let Some(split) = state.score_breakdown() else { return Vec::new() };
+    vec![
+        Span::styled(s.score_placement, Style::default().fg(theme().gray)),
+        Span::styled(split.placement.to_string(), Style::default().fg(score_color(split.placement))),
+        Span::styled(s.score_electrical, Style::default().fg(theme().gray)),
+        Span::styled(split.electrical.to_string(), Style::default().fg(score_color(split.electrical))),
+    ]
+}
+
+// ─── Zones cliquables ─────────────────────────────────────────────────────────
+
+/// Action d'un clic gauche.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Click {
+    /// Même effet que la touche (boutons de capture, [-] [+], aide).
+    Key(KeyCode),
+    /// Entrée de l'historique (indice dans `AppState::history`).
+    History(usize),
+    /// Bande du spectre sous le clic (curseur).
+    Band(usize),
+    /// En-tête d'un domaine du panneau de résultats (sélection, détail).
+    Metric(MetricDomain),
+}
+
+/// Zones cliquables du dernier rendu, relevées par `draw` sur les mêmes
+/// rectangles que les widgets : le clic tombe là où le texte est affiché.
+#[derive(Debug, Clone, Default)]
+pub struct HitMap {
+    zones: Vec<(Rect, Click)>,
+}
+
+impl HitMap {
+    /// Action sous la cellule (`column`, `row`) ; la zone la plus récente
+    /// l'emporte.
+    pub fn at(&self, column: u16, row: u16) -> Option<Click> {
+        self.zones
+            .iter()
+            .rev()
+            .find(|(r, _)| column >= r.x && column < r.right() && row >= r.y && row < r.bottom())
+            .map(|&(_, click)| click)
+    }
+
+    fn add(&mut self, area: Rect, click: Click) {
+        if area.width > 0 && area.height > 0 {
+            self.zones.push((area, click));
+        }
+    }
+
+    /// Boutons de capture, aux cadres rendus par `draw_capture_controls`.
+    fn capture_buttons(&mut self, [left, right]: [Rect; 2]) {
+        self.add(left, Click::Key(KeyCode::Char('l')));
+        self.add(right, Click::Key(KeyCode::Char('r')));
+    }
+
+    /// Étiquettes « [x] » d'une ligne de texte commençant en (`x`, `y`),
+    /// sans dépasser `right`.
+    fn key_labels(&mut self, mut x: u16, y: u16, right: u16, spans: &[Span]) {
+        for span in spans {
+            let text = span.content.as_ref();
+            let label = text.trim();
+            if let Some(code) = label_key(label) {
+                let start = x + (text.len() - text.trim_start().len()) as u16;
+                let width = (Span::raw(label).width() as u16).min(right.saturating_sub(start));
+                self.add(Rect::new(start, y, width, 1), Click::Key(code));
+            }
+            x = x.saturating_add(span.width() as u16);
+        }
+    }
+
+    /// Entrées de la barre d'aide (touche et description), disposées comme
+    /// `draw_help`.
+    fn help(&mut self, area: Rect, entries: &[(String, &str)]) {
+        let mut x = area.x;
+        let y = area.y + 1;
+        for (key, desc) in entries {
+            let width = Span::raw(format!(" {} {} ", key, desc)).width() as u16;
+            if let Some(code) = label_key(key) {
+                let width = width.min(area.right().saturating_sub(x));
+                self.add(Rect::new(x, y, width, 1), Click::Key(code));
+            }
+            x = x.saturating_add(width + 3);
+        }
+    }
+
+    /// Colonnes du tracé du spectre, chacune vers sa bande.
+    fn spectrum(&mut self, area: Rect, view: SpectrumView) {
+        let plot = spectrum_plot_area(area, view);
+        for column in plot.left()..plot.right() {
+            self.add(Rect::new(column, plot.y, 1, plot.height), Click::Band(band_at_column(plot, view, column)));
+        }
+    }
+
+    /// Lignes de `draw_history` : la plus récente en haut.
+    fn history(&mut self, area: Rect, len: usize) {
+        let inner = Rect::new(area.x + 1, area.y + 1, area.width.saturating_sub(2), area.height.saturating_sub(2));
+        for row in 0..len.min(HISTORY_ROWS).min(inner.height as usize) {
+            self.add(Rect::new(inner.x, inner.y + row as u16, inner.width, 1), Click::History(len - 1 - row));
+        }
+    }
+
+    /// Colonnes de la tendance de `draw_history` : chacune vers la dernière
+    /// analyse de son groupe.
+    fn history_trend(&mut self, area: Rect, len: usize) {
+        let plot = trend_plot_area(area);
+        for (column, bucket) in trend_buckets(len, plot.width as usize).into_iter().enumerate() {
+            self.add(Rect::new(plot.x + column as u16, plot.y, 1, plot.height), Click::History(bucket.end - 1));
+        }
+    }
+
+    /// En-têtes visibles de l'arbre des métriques (`inner` : intérieur du
+    /// cadre, `scroll` : lignes défilées).
+    fn metric_headers(&mut self, inner: Rect, scroll: usize, headers: &[(usize, MetricDomain)]) {
+        for &(line, domain) in headers {
+            if let Some(row) = line.checked_sub(scroll).filter(|&r| r < inner.height as usize) {
+                self.add(Rect::new(inner.x, inner.y + row as u16, inner.width, 1), Click::Metric(domain));
+            }
+        }
+    }
+}
+
+/// Touche d'une étiquette « [L] », « [Tab] », « [F2] » ; `None` pour les
+/// étiquettes à plusieurs touches (« [+/-] », « [1-8] », « [ ] »).
+fn label_key(label: &str) -> Option<KeyCode> {
+    let inner = label.strip_prefix('[')?.strip_suffix(']')?;
+    keymap::parse_key(inner).filter(|&code| code != KeyCode::Char(' '))
 }
 
 // ─── Point d'entrée du rendu ──────────────────────────────────────────────────
 
-pub fn draw(f: &mut Frame, state: &AppState) {
+/// Dessine l'écran et renvoie ses zones cliquables.
+pub fn draw(f: &mut Frame, state: &AppState) -> HitMap {
     let area = f.area();
+    if !state.expert {
+        return draw_basic(f, area, state);
+    }
+    let mut hits = HitMap::default();
 
     // Layout principal vertical
     let chunks = Layout::default()
@@ -65,7 +247,11 @@ pub fn draw(f: &mut Frame, state: &AppState) {
 
     draw_header(f, chunks[0], state);
     draw_delay_control(f, chunks[1], state);
-    draw_capture_controls(f, chunks[2], state);
+    if state.schedule_input.is_none() {
+        let inner = chunks[1].inner(Margin::new(1, 1));
+        hits.key_labels(inner.x, inner.y, inner.right(), &delay_spans(state));
+    }
+    hits.capture_buttons(draw_capture_controls(f, chunks[2], state));
     draw_progress(f, chunks[3], state);
 
     // Zone centrale : spectre à gauche, résultats à droite
@@ -74,49 +260,259 @@ pub fn draw(f: &mut Frame, state: &AppState) {
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(chunks[4]);
 
-    draw_spectrum(f, center[0], state);
-    draw_results_panel(f, center[1], state);
+    // Rapports de tests (bass management, polarité) empilés sous le spectre ;
+    // l'alerte « traitements du système » passe en premier
+    let mut reports: Vec<(u16, PanelFn)> = Vec::new();
+    if state.checklist.as_ref().is_some_and(|c| c.open) {
+        reports.push((ITEMS.len() as u16 + 3, draw_checklist));
+    }
+    if state.overlay.is_some() {
+        reports.push((7, draw_overlay));
+    }
+    if !state.enhancements.is_empty() {
+        reports.push((state.enhancements.len() as u16 + 3, draw_enhancements));
+    }
+    if !state.experiments.entries.is_empty() || state.experiment_input.is_some() {
+        let rows = state.experiments.entries.len().min(EXPERIMENT_ROWS) + state.experiment_input.is_some() as usize;
+        reports.push((rows as u16 + 2, draw_experiments));
+    }
+    if !state.positions.is_empty() {
+        reports.push((state.positions.len().min(POSITION_ROWS) as u16 + 3, draw_positions));
+    }
+    if state.baseline.is_some() || state.baseline_input.is_some() {
+        reports.push((baseline_rows(state) as u16 + 2, draw_baseline));
+    }
+    if !state.seats.is_empty() || state.seat_input.is_some() {
+        let rows = state.seats.len().min(SEAT_ROWS)
+            + (state.seats.len() >= 2) as usize
+            + state.seat_input.is_some() as usize;
+        reports.push((rows as u16 + 3, draw_seats));
+    }
+    if state.show_environment || state.ambient_input.is_some() {
+        reports.push((Factor::ALL.len() as u16 + 3 + state.ambient_input.is_some() as u16, draw_environment));
+    }
+    if let Some((_, notes)) = shown_notes(state) {
+        let rows = if state.notes_input.is_some() { NoteField::ALL.len() + 1 } else { notes.filled().len() };
+        reports.push((rows as u16 + 2, draw_notes));
+    }
+    if state.watch.is_some() {
+        reports.push((5, draw_watch));
+    }
+    if state.dual.is_some() {
+        reports.push((6, draw_dual));
+    }
+    if state.noise.is_some() {
+        reports.push((4, draw_noise_floor));
+    }
+    if state.show_ir {
+        reports.push((12, draw_impulse_response));
+        if state.band_delay.is_some() {
+            reports.push((10, draw_band_delay));
+        }
+    }
+    if !state.others.is_empty() {
+        reports.push((state.captured_channels().len() as u16 + 3 + u16::from(!state.pairs.is_empty()), draw_speakers));
+    }
+    if state.sub.is_some() {
+        reports.push((6, draw_sub_alignment));
+    }
+    if let Some(multisub) = &state.multisub {
+        reports.push((multisub.outputs.len() as u16 + 3, draw_multisub));
+    }
+    if state.bass_report.is_some() {
+        reports.push((8, draw_bass_report));
+    }
+    if state.polarity.is_some() {
+        reports.push((6, draw_polarity_report));
+    }
+    if state.rub_buzz.is_some() {
+        reports.push((5, draw_rub_buzz_report));
+    }
+    if state.compression.is_some() {
+        reports.push((5, draw_compression_report));
+    }
+    if state.linearity.is_some() {
+        reports.push((5, draw_linearity_report));
+    }
+    if state.channel_probe.is_some() {
+        reports.push((5, draw_channel_map));
+    }
+    if state.quick_check.is_some() {
+        reports.push((5, draw_quick_check));
+    }
+    if state.quick_distance.is_some() {
+        reports.push((5, draw_quick_distance));
+    }
+    if state.mmm.is_some() {
+        reports.push((5, draw_mmm_report));
+    }
+    let spectrum_rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            std::iter::once(Constraint::Min(8))
+                .chain(reports.iter().map(|(h, _)| Constraint::Length(*h)))
+                .collect::<Vec<_>>(),
+        )
+        .split(center[0]);
+    for (i, (_, draw_report)) in reports.iter().enumerate() {
+        draw_report(f, spectrum_rows[i + 1], state);
+    }
+    let spectrum_area = spectrum_rows[0];
+
+    // Mode fenêtre d'écoute : schéma de la grille micro à droite du spectre
+    if state.window.is_some() {
+        let spectrum_cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(30), Constraint::Length(30)])
+            .split(spectrum_area);
+        draw_spectrum(f, spectrum_cols[0], state);
+        hits.spectrum(spectrum_cols[0], state.spectrum_view);
+        draw_window_grid(f, spectrum_cols[1], state);
+    } else {
+        draw_spectrum(f, spectrum_area, state);
+        hits.spectrum(spectrum_area, state.spectrum_view);
+    }
+    draw_results_panel(f, center[1], state, &mut hits);
+
+    let help = state.keys.relabel(tr().help);
+    draw_help(f, chunks[5], &help);
+    hits.help(chunks[5], &help);
+    hits
+}
+
+// ─── Mode simple ──────────────────────────────────────────────────────────────
+
+/// Mode simple ([!]) : captures, verdict en clair et conseils, sans spectre ni
+/// métriques. Les touches du mode expert restent actives.
+fn draw_basic(f: &mut Frame, area: Rect, state: &AppState) -> HitMap {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(4), // Header
+            Constraint::Length(5), // Capture controls
+            Constraint::Length(3), // Progress / status bar
+            Constraint::Length(6), // Verdict
+            Constraint::Min(8),    // Recommandations
+            Constraint::Length(3), // Keyboard help
+        ])
+        .split(area);
+
+    draw_header(f, chunks[0], state);
+    let buttons = draw_capture_controls(f, chunks[1], state);
+    draw_progress(f, chunks[2], state);
+    draw_verdict(f, chunks[3], state);
+    let mut hits = HitMap::default();
+    hits.capture_buttons(buttons);
+
+    // Liste de vérification et alerte « traitements du système » (qui fausse
+    // toute mesure) au-dessus des conseils
+    let mut panels: Vec<(u16, PanelFn)> = Vec::new();
+    if state.checklist.as_ref().is_some_and(|c| c.open) {
+        panels.push((ITEMS.len() as u16 + 3, draw_checklist));
+    }
+    if !state.enhancements.is_empty() {
+        panels.push((state.enhancements.len() as u16 + 3, draw_enhancements));
+    }
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            panels
+                .iter()
+                .map(|(h, _)| Constraint::Length(*h))
+                .chain(std::iter::once(Constraint::Min(5)))
+                .collect::<Vec<_>>(),
+        )
+        .split(chunks[4]);
+    for (i, (_, draw_panel)) in panels.iter().enumerate() {
+        draw_panel(f, rows[i], state);
+    }
+    draw_recommendations(f, rows[panels.len()], state);
+    let help = state.keys.relabel(tr().help_basic);
+    draw_help(f, chunks[5], &help);
+    hits.help(chunks[5], &help);
+    hits
+}
+
+/// Score et verdict en une phrase (mode simple).
+fn draw_verdict(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.verdict_title, Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+
+    let Some(score) = state.score else {
+        let para = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(Span::styled(s.verdict_pending, Style::default().fg(theme().white))),
+        ])
+        .block(block);
+        f.render_widget(para, area);
+        return;
+    };
 
-    draw_help(f, chunks[5], state);
+    let col = score_color(score);
+    let (rating, advice) = if score >= 85 {
+        (s.rating_excellent, s.verdict_good)
+    } else if score >= 60 {
+        (s.rating_adjust, s.verdict_adjust)
+    } else {
+        (s.rating_fix, s.verdict_fix)
+    };
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(format!("  {} / 100   ", score), Style::default().fg(col).add_modifier(Modifier::BOLD)),
+            Span::styled(rating, Style::default().fg(col).add_modifier(Modifier::BOLD)),
+        ]),
+        Line::from(score_split_spans(state)),
+        Line::from(Span::styled(advice, Style::default().fg(theme().white))),
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
 }
 
 // ─── En-tête ──────────────────────────────────────────────────────────────────
 
 fn draw_header(f: &mut Frame, area: Rect, state: &AppState) {
-    let mic_dot = if state.step == Step::CapturingLeft
-        || state.step == Step::CapturingRight
-    {
-        Span::styled("◉ REC", Style::default().fg(RED).add_modifier(Modifier::BOLD))
+    let s = tr();
+    let mic_dot = if state.step.is_capturing() {
+        Span::styled("◉ REC", Style::default().fg(theme().red).add_modifier(Modifier::BOLD))
     } else {
-        Span::styled("● PRÊT", Style::default().fg(GREEN))
+        Span::styled(s.ready, Style::default().fg(theme().green))
     };
 
     let title = Line::from(vec![
         Span::styled(
             "  Speaker Align  ",
             Style::default()
-                .fg(WHITE)
+                .fg(theme().white)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw("  "),
         mic_dot,
+        check_ages(state),
     ]);
 
     let subtitle = Line::from(vec![Span::styled(
-        "  Calibration de placement stéréo par analyse comparative micro",
-        Style::default().fg(GRAY),
+        s.subtitle,
+        Style::default().fg(theme().gray),
     )]);
 
     let device_line = Line::from(vec![
-        Span::styled("  Sortie : ", Style::default().fg(GRAY)),
-        Span::styled(&state.out_device, Style::default().fg(CYAN)),
-        Span::styled("   Entrée : ", Style::default().fg(GRAY)),
-        Span::styled(&state.in_device, Style::default().fg(CYAN)),
+        Span::styled(s.output_label, Style::default().fg(theme().gray)),
+        Span::styled(&state.out_device, Style::default().fg(theme().cyan)),
+        Span::styled(s.input_label, Style::default().fg(theme().gray)),
+        Span::styled(&state.in_device, Style::default().fg(theme().cyan)),
+        match &state.calibration {
+            Some(cal) => Span::styled((s.mic_calibrated)(&cal.name, cal.points.len()), Style::default().fg(theme().green)),
+            None => Span::styled(s.mic_uncalibrated, Style::default().fg(theme().gray)),
+        },
     ]);
 
     let block = Block::default()
         .borders(Borders::BOTTOM)
-        .border_style(Style::default().fg(Color::Rgb(40, 40, 60)));
+        .border_style(Style::default().fg(theme().border));
 
     let para = Paragraph::new(vec![title, subtitle, device_line])
         .block(block)
@@ -125,151 +521,245 @@ fn draw_header(f: &mut Frame, area: Rect, state: &AppState) {
     f.render_widget(para, area);
 }
 
+/// Âge de la calibration micro et de la latence de boucle, en jaune si
+/// une vérification est à refaire.
+fn check_ages(state: &AppState) -> Span<'static> {
+    let s = tr();
+    let now = reminders::now_unix();
+    let mut parts = Vec::new();
+    if let Some(check) = state.calibration.as_ref().and_then(|c| state.checks.mic_cal(c)) {
+        parts.push((s.age_mic_cal)(reminders::age_days(check.verified_unix, now)));
+    }
+    if state.loopback.is_some() {
+        parts.push(match &state.checks.loopback {
+            Some(check) => (s.age_loopback)(reminders::age_days(check.measured_unix, now)),
+            None => s.age_loopback_never.to_string(),
+        });
+    }
+    if parts.is_empty() {
+        return Span::raw("");
+    }
+    let color = if state.reminders().is_empty() { theme().gray } else { theme().yellow };
+    Span::styled(format!("   {}", parts.join(" · ")), Style::default().fg(color))
+}
+
 // ─── Contrôle du délai pré-capture ───────────────────────────────────────────
 
 fn draw_delay_control(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(Span::styled(" ◈ SWEEP SINUS 20 Hz → 20 kHz  —  Délai pré-capture ", Style::default().fg(GRAY)))
-        .border_style(Style::default().fg(Color::Rgb(35, 35, 50)));
-
-    let content = Line::from(vec![
-        Span::styled("  [-] ", Style::default().fg(CYAN).add_modifier(Modifier::BOLD)),
-        Span::styled(
-            format!("{:.1} s", state.pre_delay_secs),
-            Style::default().fg(WHITE).add_modifier(Modifier::BOLD),
-        ),
-        Span::styled(" [+]  ", Style::default().fg(CYAN).add_modifier(Modifier::BOLD)),
-        Span::styled(
-            "laisser le temps au bruit transitoire de se dissiper avant la capture",
-            Style::default().fg(GRAY),
-        ),
-    ]);
+        .title(Span::styled(s.delay_title, Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+
+    if let Some(input) = &state.schedule_input {
+        let content = Line::from(vec![
+            Span::styled(format!("  ✎ {}", s.schedule_prompt), Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", input), Style::default().fg(theme().white)),
+            Span::styled(s.experiment_hint, Style::default().fg(theme().gray)),
+        ]);
+        f.render_widget(Paragraph::new(content).block(block), area);
+        return;
+    }
+    f.render_widget(Paragraph::new(Line::from(delay_spans(state))).block(block), area);
+}
 
-    f.render_widget(Paragraph::new(content).block(block), area);
+/// Ligne de réglages de `draw_delay_control`, dont les étiquettes [-] [+]
+/// [\] sont aussi cliquables.
+fn delay_spans(state: &AppState) -> Vec<Span<'static>> {
+    let s = tr();
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD));
+    let value = |v: String| Span::styled(v, Style::default().fg(theme().white).add_modifier(Modifier::BOLD));
+    let sweep = &state.sweep;
+    let mut spans = vec![
+        key("  [-] "),
+        value(format!("{} s", format_num(state.pre_delay_secs, 1))),
+        key(" [+]"),
+        key("   [\\] "),
+        value(state.start_schedule.map_or_else(|| s.schedule_off.to_string(), |at| at.label())),
+        Span::styled("   │   ", Style::default().fg(theme().gray)),
+        key("[ ] "),
+        value(format!("{:.0} s", sweep.duration_s)),
+        key("   , . "),
+        value(format_hz(sweep.f_start_hz)),
+        Span::styled(" → ", Style::default().fg(theme().gray)),
+        key("< > "),
+        value(format_hz(sweep.f_stop_hz)),
+        key("   ( ) "),
+        value(format!("{} dBFS", format_signed(sweep.amplitude_dbfs(), 1))),
+        key("   * "),
+        value(format!("×{}", sweep.averages)),
+        key("   { } "),
+        value(format!("→ {:.0} dBFS", state.level.target_dbfs)),
+    ];
+    if state.level_cal.is_some_and(|c| c.limited) {
+        spans.push(Span::styled(" ⚠", Style::default().fg(theme().orange).add_modifier(Modifier::BOLD)));
+    }
+    spans
 }
 
 // ─── Boutons de capture ───────────────────────────────────────────────────────
 
-fn draw_capture_controls(f: &mut Frame, area: Rect, state: &AppState) {
+/// Cadres gauche et droit ; renvoie leurs rectangles (zones cliquables).
+fn draw_capture_controls(f: &mut Frame, area: Rect, state: &AppState) -> [Rect; 2] {
+    let s = tr();
     let cols = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
     // ── Gauche ──
-    let left_done = state.left_db.is_some();
-    let capturing_left = state.step == Step::CapturingLeft;
-    let left_color = if capturing_left { GREEN } else if left_done { Color::Rgb(0, 120, 70) } else { GREEN };
+    let left_done = state.left.is_some();
+    let capturing_left = matches!(state.step, Step::CapturingLeft | Step::CapturingPair | Step::CapturingDual);
+    let left_color = if capturing_left { theme().green } else if left_done { theme().left_done } else { theme().green };
 
     let left_status = if capturing_left {
-        format!("  ◉ Capture en cours… {:.0}%", state.progress * 100.0)
+        (s.capturing)(&phase_label(state), state.progress.fraction * 100.0)
     } else if left_done {
-        "  ✓ Capturé — Appuyer sur [L] pour recapturer".to_string()
+        s.left_done.to_string()
     } else {
-        "  [L] Capturer l'enceinte GAUCHE (référence)".to_string()
+        s.left_prompt.to_string()
     };
 
     let left_block = Block::default()
         .borders(Borders::ALL)
-        .title(Span::styled(" L  ENCEINTE GAUCHE ", Style::default().fg(GREEN).add_modifier(Modifier::BOLD)))
-        .border_style(Style::default().fg(if left_done { Color::Rgb(0, 100, 60) } else { Color::Rgb(0, 60, 35) }))
-        .style(Style::default().bg(Color::Rgb(0, 12, 8)));
+        .title(Span::styled(s.left_title, Style::default().fg(theme().green).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(if left_done { theme().left_border_done } else { theme().left_border }))
+        .style(Style::default().bg(theme().left_bg));
 
     let left_lines = vec![
         Line::from(Span::styled(left_status, Style::default().fg(left_color).add_modifier(Modifier::BOLD))),
-        Line::from(Span::styled("  Signal test lu sur le canal GAUCHE uniquement", Style::default().fg(GRAY))),
+        Line::from(Span::styled(s.left_note, Style::default().fg(theme().gray))),
     ];
     f.render_widget(Paragraph::new(left_lines).block(left_block), cols[0]);
 
     // ── Droite ──
-    let right_done = state.right_db.is_some();
-    let capturing_right = state.step == Step::CapturingRight;
-    let right_color = if capturing_right { ORANGE } else if right_done { Color::Rgb(160, 70, 30) } else { ORANGE };
+    let right_done = state.right.is_some();
+    let capturing_right = matches!(state.step, Step::CapturingRight | Step::CapturingPair | Step::CapturingDual);
+    let right_color = if capturing_right { theme().orange } else if right_done { theme().right_done } else { theme().orange };
 
     let right_status = if capturing_right {
-        format!("  ◉ Capture en cours… {:.0}%", state.progress * 100.0)
+        (s.capturing)(&phase_label(state), state.progress.fraction * 100.0)
     } else if right_done {
-        "  ✓ Capturé — Appuyer sur [R] pour recapturer".to_string()
+        s.right_done.to_string()
     } else {
-        "  [R] Capturer l'enceinte DROITE (à aligner)".to_string()
+        s.right_prompt.to_string()
     };
 
     let right_block = Block::default()
         .borders(Borders::ALL)
-        .title(Span::styled(" R  ENCEINTE DROITE ", Style::default().fg(ORANGE).add_modifier(Modifier::BOLD)))
-        .border_style(Style::default().fg(if right_done { Color::Rgb(120, 55, 20) } else { Color::Rgb(70, 35, 15) }))
-        .style(Style::default().bg(Color::Rgb(10, 6, 3)));
+        .title(Span::styled(
+            s.right_title,
+            Style::default().fg(theme().orange).add_modifier(Modifier::BOLD | theme().right_modifier),
+        ))
+        .border_style(Style::default().fg(if right_done { theme().right_border_done } else { theme().right_border }))
+        .style(Style::default().bg(theme().right_bg));
 
     let right_lines = vec![
         Line::from(Span::styled(right_status, Style::default().fg(right_color).add_modifier(Modifier::BOLD))),
-        Line::from(Span::styled("  Signal test lu sur le canal DROIT uniquement", Style::default().fg(GRAY))),
+        Line::from(Span::styled(s.right_note, Style::default().fg(theme().gray))),
     ];
     f.render_widget(Paragraph::new(right_lines).block(right_block), cols[1]);
+    [cols[0], cols[1]]
 }
 
 // ─── Barre de progression / erreur ───────────────────────────────────────────
 
+/// Étape en cours, avec le cycle quand plusieurs sweeps sont moyennés.
+fn phase_label(state: &AppState) -> String {
+    match state.cycle {
+        (i, n) if n > 1 => format!("{} {}/{}", state.progress.phase.label(), i, n),
+        _ => state.progress.phase.label().to_string(),
+    }
+}
+
 fn draw_progress(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
     if let Some(err) = &state.error {
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(RED));
+            .border_style(Style::default().fg(theme().red));
         let para = Paragraph::new(Span::styled(
             format!(" ⚠ {}", err),
-            Style::default().fg(RED),
+            Style::default().fg(theme().red),
         ))
         .block(block);
         f.render_widget(para, area);
         return;
     }
 
-    let is_capturing = matches!(state.step, Step::CapturingLeft | Step::CapturingRight);
-
-    if is_capturing {
-        let label = if state.step == Step::CapturingLeft {
-            "Capture GAUCHE"
-        } else {
-            "Capture DROITE"
+    if state.step.is_capturing() {
+        let (label, color) = match state.step {
+            Step::CapturingLeft => (s.capture_left.to_string(), theme().green),
+            Step::CapturingPair => (s.capture_pair.to_string(), theme().green),
+            Step::CapturingChannel(ch) => ((s.capture_channel)(&ch.name()), theme().cyan),
+            Step::CapturingSub => (s.capture_sub.to_string(), theme().purple),
+            Step::CapturingSubs(index) => {
+                let outputs = state.multisub.as_ref().map_or(&[][..], |m| &m.outputs[..]);
+                let name = outputs.get(index).map(|c| c.name()).unwrap_or_default();
+                ((s.multisub_capture)(&name, index + 1, outputs.len()), theme().purple)
+            }
+            Step::CapturingBass => (s.bass_test.to_string(), theme().purple),
+            Step::CapturingNoise => (s.capture_noise.to_string(), theme().gray),
+            Step::CalibratingLevel(_) => (s.capture_level.to_string(), theme().purple),
+            Step::ProbingChannels(Channel::LEFT) => ((s.probe_capture)(s.left_upper), theme().cyan),
+            Step::ProbingChannels(_) => ((s.probe_capture)(s.right_upper), theme().cyan),
+            Step::CapturingTest(test, Channel::LEFT) => (format!("{} {}", test.label(), s.left_upper), theme().cyan),
+            Step::CapturingTest(test, _) => (format!("{} {}", test.label(), s.right_upper), theme().cyan),
+            Step::Watching(Channel::LEFT) => ((s.watch_capture)(s.left_upper), theme().yellow),
+            Step::Watching(_) => ((s.watch_capture)(s.right_upper), theme().yellow),
+            Step::CapturingDual => (s.dual_capture.to_string(), theme().yellow),
+            _ => (s.capture_right.to_string(), theme().orange),
         };
-        let color = if state.step == Step::CapturingLeft { GREEN } else { ORANGE };
 
-        let gauge_label = if state.progress < 0.01 && state.pre_delay_secs > 0.0 {
-            format!("Pause {:.1}s…", state.pre_delay_secs)
-        } else {
-            format!("{:.0}%", state.progress * 100.0)
+        let gauge_label = match state.progress.phase {
+            Phase::PreDelay => (s.pause)(state.pre_delay_secs * (1.0 - state.progress.fraction)),
+            Phase::Scheduled => match state.start_schedule {
+                Some(at) => (s.scheduled_start)(&at.label(), at.remaining_secs()),
+                None => phase_label(state),
+            },
+            _ => format!("{} · {:.0}%", phase_label(state), state.progress.fraction * 100.0),
         };
 
         let gauge = Gauge::default()
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(Span::styled(format!(" {} ", label), Style::default().fg(color)))
+                    .title(Span::styled(format!(" {} · {} ", label, s.capture_cancel_hint), Style::default().fg(color)))
                     .border_style(Style::default().fg(color)),
             )
-            .gauge_style(Style::default().fg(color).bg(Color::Rgb(10, 10, 20)))
-            .ratio(state.progress as f64)
+            .gauge_style(Style::default().fg(color).bg(theme().gauge_bg))
+            .ratio(state.progress.fraction as f64)
             .label(gauge_label);
 
         f.render_widget(gauge, area);
     } else {
         // Affiche les actions disponibles
-        let ready_for_analyze = state.left_db.is_some() && state.right_db.is_some();
-        let hint = if ready_for_analyze {
+        let ready_for_analyze = state.can_analyze();
+        let hint = if let Some(status) = &state.status {
+            Line::from(Span::styled(format!("  ✓ {}", status), Style::default().fg(theme().green)))
+        } else if let Some(pos) = state.window_pos {
+            let done = state.window.as_ref().map(|w| w.points.len()).unwrap_or(0);
+            Line::from(vec![
+                Span::styled((s.window_hint)(done, GRID.len()), Style::default().fg(theme().gray)),
+                Span::styled(pos.label(), Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    if ready_for_analyze { s.window_then_analyze } else { s.window_then_capture },
+                    Style::default().fg(theme().gray),
+                ),
+            ])
+        } else if ready_for_analyze {
             Line::from(vec![
-                Span::styled("  ⚡ Les deux enceintes sont capturées — ", Style::default().fg(GRAY)),
-                Span::styled("[A] Analyser", Style::default().fg(CYAN).add_modifier(Modifier::BOLD)),
+                Span::styled(s.both_captured, Style::default().fg(theme().gray)),
+                Span::styled(s.analyze_key, Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
             ])
         } else {
-            Line::from(Span::styled(
-                "  Placez le micro au point d'écoute, puis capturez l'enceinte GAUCHE (L) puis DROITE (R)",
-                Style::default().fg(GRAY),
-            ))
+            Line::from(Span::styled(s.idle_hint, Style::default().fg(theme().gray)))
         };
 
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Rgb(35, 35, 50)));
+            .border_style(Style::default().fg(theme().border));
 
         f.render_widget(Paragraph::new(hint).block(block), area);
     }
@@ -277,22 +767,138 @@ fn draw_progress(f: &mut Frame, area: Rect, state: &AppState) {
 
 // ─── Visualisation spectrale ──────────────────────────────────────────────────
 
+/// Étiquettes par axe du spectre.
+const SPECTRUM_LABELS: usize = 5;
+
+/// « 200 », « 1.2k » : étiquette compacte de l'axe des fréquences.
+fn compact_hz(hz: f32) -> String {
+    if hz >= 1000.0 { format!("{}k", (hz / 100.0).round() / 10.0) } else { format!("{:.0}", hz) }
+}
+
+/// Étiquettes des axes pour la vue `view`. `Chart` espace ses étiquettes
+/// régulièrement : chacune porte la fréquence de la bande à sa place
+/// (`band_center_freq`), et les dB vont du plancher à 0.
+fn spectrum_labels(view: SpectrumView) -> (Vec<String>, Vec<String>) {
+    let (lo, hi) = view.bands;
+    let last = SPECTRUM_LABELS - 1;
+    let x = (0..SPECTRUM_LABELS)
+        .map(|k| compact_hz(dsp::band_center_freq(lo + ((hi - lo) * k + last / 2) / last, NUM_BANDS)))
+        .collect();
+    let y = (0..SPECTRUM_LABELS).map(|k| format!("{:.0}", view.floor_db - view.floor_db * k as f32 / last as f32)).collect();
+    (x, y)
+}
+
+/// Tracé du spectre dans son cadre, découpé comme le fait `Chart` :
+/// étiquettes dB et axe à gauche, étiquettes Hz et axe dessous.
+fn spectrum_plot_area(area: Rect, view: SpectrumView) -> Rect {
+    let inner = area.inner(Margin::new(1, 1));
+    let (x_labels, y_labels) = spectrum_labels(view);
+    let first_x = x_labels.first().map_or(0, |l| l.len().saturating_sub(1));
+    let widest = y_labels.iter().map(String::len).max().unwrap_or(0).max(first_x) as u16;
+    let left = widest.min(inner.width / 3) + 1;
+    Rect::new(inner.x + left, inner.y, inner.width.saturating_sub(left), inner.height.saturating_sub(2))
+}
+
+/// Bande tracée dans la colonne `column` du tracé `plot`.
+fn band_at_column(plot: Rect, view: SpectrumView, column: u16) -> usize {
+    let (lo, hi) = view.bands;
+    let ratio = (column - plot.x) as f64 / plot.width.saturating_sub(1).max(1) as f64;
+    (lo + (ratio * (hi - lo) as f64).round() as usize).min(hi)
+}
+
+/// Colonne de la bande `band` dans le tracé `plot` (inverse de `band_at_column`).
+fn column_of_band(plot: Rect, view: SpectrumView, band: usize) -> u16 {
+    let (lo, hi) = view.bands;
+    let ratio = (band - lo) as f64 / (hi - lo).max(1) as f64;
+    plot.x + (ratio * plot.width.saturating_sub(1) as f64).round() as u16
+}
+
+/// Curseur du spectre : trait vertical sur les cellules vides de la colonne
+/// de `band`, et un point sur chaque courbe lue (`points` : dB et couleur).
+fn draw_spectrum_cursor(f: &mut Frame, plot: Rect, view: SpectrumView, band: usize, points: &[(f64, Color)]) {
+    if plot.width == 0 || plot.height == 0 || !view.contains(band) {
+        return;
+    }
+    let column = column_of_band(plot, view, band);
+    let buf = f.buffer_mut();
+    for row in plot.top()..plot.bottom() {
+        let cell = &mut buf[(column, row)];
+        if matches!(cell.symbol(), " " | "\u{2800}") {
+            cell.set_symbol("│").set_fg(theme().cursor);
+        }
+    }
+    let floor = view.floor_db as f64;
+    for &(db, color) in points {
+        let ratio = (db - floor) / -floor;
+        let rise = (ratio.clamp(0.0, 1.0) * (plot.height - 1) as f64).round() as u16;
+        buf[(column, plot.bottom() - 1 - rise)].set_symbol("●").set_fg(color);
+    }
+}
+
+/// Abscisse du graphique de spectre (échelle log, en indices de bande).
+fn freq_to_x(freq: f32) -> f64 {
+    let log_min = (20f32).log10() as f64;
+    let log_max = (20_000f32).log10() as f64;
+    let ratio = ((freq as f64).log10() - log_min) / (log_max - log_min);
+    ratio * (NUM_BANDS - 1) as f64
+}
+
 fn draw_spectrum(f: &mut Frame, area: Rect, state: &AppState) {
-    let block = Block::default()
+    let s = tr();
+    let mut title = match state.analysis.smoothing {
+        0 => s.spectrum_title.to_string(),
+        n => (s.spectrum_title_smoothed)(n),
+    };
+    if state.analysis.window != Window::default() {
+        title.push_str(&format!("· {} ", s.window_names[state.analysis.window.index()]));
+    }
+    if state.left.iter().chain(&state.right).any(Measurement::is_gated) {
+        title.push_str(&(s.spectrum_gated)(state.analysis.gate_ms, dsp::gate_low_hz(state.analysis.gate_ms)));
+    }
+    let view = state.spectrum_view;
+    if view != SpectrumView::default() {
+        let (lo, hi) = view.bands;
+        title.push_str(&format!(
+            "· {}–{} · {:.0} dB ",
+            format_hz(dsp::band_center_freq(lo, NUM_BANDS)),
+            format_hz(dsp::band_center_freq(hi, NUM_BANDS)),
+            view.floor_db
+        ));
+    }
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .title(Span::styled(
-            " Réponse en fréquence (dB) ",
-            Style::default().fg(GRAY).add_modifier(Modifier::BOLD),
+            title,
+            Style::default().fg(theme().gray).add_modifier(Modifier::BOLD),
         ))
-        .border_style(Style::default().fg(Color::Rgb(35, 35, 55)));
+        .border_style(Style::default().fg(theme().border));
+    // Légende des zones sur la bordure basse
+    if state.show_regions {
+        block = block.title_bottom(Line::from(
+            s.region_names
+                .iter()
+                .zip(theme().regions)
+                .map(|(name, color)| Span::styled(format!(" {} ", name), Style::default().fg(theme().white).bg(color)))
+                .collect::<Vec<_>>(),
+        ));
+    }
 
-    if state.left_db.is_none() && state.right_db.is_none() {
+    // Légende des modes de la pièce, à droite de la bordure basse
+    let room_modes = &state.room_modes;
+    if !room_modes.is_empty() {
+        block = block.title_bottom(Line::from(Span::styled(s.room_modes_legend, Style::default().fg(theme().purple))).right_aligned());
+    }
+
+    // Comparaison [&] : A (pâle) et B (vif) remplacent les mesures courantes
+    let overlay = state
+        .overlay
+        .map(|(a, b)| (&state.history[a], &state.history[b]))
+        .filter(|(a, b)| a.has_curves() && b.has_curves());
+
+    if state.left.is_none() && state.right.is_none() && state.noise.is_none() && overlay.is_none() {
         let para = Paragraph::new(vec![
             Line::from(""),
-            Line::from(Span::styled(
-                "  Capturez les deux enceintes pour afficher leur réponse en fréquence",
-                Style::default().fg(GRAY),
-            )),
+            Line::from(Span::styled(s.spectrum_empty, Style::default().fg(theme().gray))),
         ])
         .block(block);
         f.render_widget(para, area);
@@ -304,190 +910,1630 @@ fn draw_spectrum(f: &mut Frame, area: Rect, state: &AppState) {
     // et que la courbe plus faible descend d'autant de dB qu'elle l'est vraiment.
     let ref_db: f32 = {
         let mut m = f32::NEG_INFINITY;
-        if let Some(l) = &state.left_db {
+        if let Some(l) = state.left.as_ref().map(|m| &m.bands_db) {
             for &v in l { if v > m { m = v; } }
         }
-        if let Some(r) = &state.right_db {
+        if let Some(r) = state.right.as_ref().map(|m| &m.bands_db) {
             for &v in r { if v > m { m = v; } }
         }
+        // Bruit seul : sa courbe sert de référence (toujours sous les mesures sinon)
+        if let Some(n) = state.noise.as_ref().map(|n| &n.bands_db) {
+            for &v in n { if v > m { m = v; } }
+        }
+        if let Some((a, b)) = overlay {
+            for &v in a.left_db.iter().chain(&a.right_db).chain(&b.left_db).chain(&b.right_db) {
+                if v > m { m = v; }
+            }
+        }
         if m.is_infinite() || m < -80.0 { 0.0 } else { m }
     };
 
-    // Convertit les bandes en points (x, y) normalisés par rapport au pic global
+    // Convertit les bandes de la vue en points (x, y) normalisés par rapport
+    // au pic global
+    let floor = view.floor_db;
     let make_data = |bands: &[f32]| -> Vec<(f64, f64)> {
         bands
             .iter()
             .enumerate()
-            .map(|(i, &db)| (i as f64, (db - ref_db).max(-80.0) as f64))
+            .filter(|&(i, _)| view.contains(i))
+            .map(|(i, &db)| (i as f64, (db - ref_db).max(floor) as f64))
             .collect()
     };
 
     // Pré-alloue les données pour garantir leur durée de vie >= datasets
-    let left_data: Vec<(f64, f64)> = state.left_db.as_deref()
-        .map(make_data).unwrap_or_default();
-    let right_data: Vec<(f64, f64)> = state.right_db.as_deref()
-        .map(make_data).unwrap_or_default();
+    // En mode fenêtre d'écoute, on trace la moyenne des positions mesurées
+    let left_data: Vec<(f64, f64)> = match (&state.window_summary, overlay) {
+        (_, Some((_, b))) => make_data(&b.left_db),
+        (Some(w), None) => make_data(&w.left_db),
+        (None, None) => state.left.as_ref().map(|m| make_data(&m.bands_db)).unwrap_or_default(),
+    };
+    let right_data: Vec<(f64, f64)> = match (&state.window_summary, overlay) {
+        (_, Some((_, b))) => make_data(&b.right_db),
+        (Some(w), None) => make_data(&w.right_db),
+        (None, None) => state.right.as_ref().map(|m| make_data(&m.bands_db)).unwrap_or_default(),
+    };
+    let overlay_data = overlay.map(|(a, _)| (make_data(&a.left_db), make_data(&a.right_db)));
     // La diff R-L est déjà relative, on la clamp juste sur la plage affichable
     let diff_data: Vec<(f64, f64)> = state.diff_db.as_deref()
         .map(|bands| bands.iter().enumerate()
-            .map(|(i, &db)| (i as f64, db.clamp(-80.0, 0.0) as f64))
+            .filter(|&(i, _)| view.contains(i))
+            .map(|(i, &db)| (i as f64, db.clamp(floor, 0.0) as f64))
             .collect())
         .unwrap_or_default();
 
+    // Limite du masque de tolérance, sous la courbe Δ (mêmes bornes)
+    let mask_data: Vec<(f64, f64)> = match (state.mask_db(), &state.diff_db, overlay) {
+        (Some(mask), Some(_), None) => mask
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| view.contains(i))
+            .map(|(i, &tol)| (i as f64, (-tol).clamp(floor, 0.0) as f64))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let noise_data: Vec<(f64, f64)> = state.noise.as_ref().map(|n| make_data(&n.bands_db)).unwrap_or_default();
+    // Courbe cible, calée sur le niveau moyen de G et D dans la plage
+    // d'analyse : l'écart de chaque courbe à elle se lit directement
+    let curves = match &state.window_summary {
+        Some(w) => Some((&w.left_db, &w.right_db)),
+        None => state.left.as_ref().zip(state.right.as_ref()).map(|(l, r)| (&l.bands_db, &r.bands_db)),
+    };
+    let target_data: Vec<(f64, f64)> = match (state.target_db(), curves, overlay) {
+        (Some(target), Some((left, right)), None) => {
+            let range = state.analysis.bands();
+            let offset = range.clone().map(|i| (left[i] + right[i]) / 2.0 - target[i]).sum::<f32>() / range.len() as f32;
+            make_data(&target.iter().map(|t| t + offset).collect::<Vec<f32>>())
+        }
+        _ => Vec::new(),
+    };
+    // Zones : barres verticales pleine hauteur, serrées pour couvrir chaque
+    // colonne, rognées à la vue
+    let (view_lo, view_hi) = (view.bands.0 as f64, view.bands.1 as f64);
+    let region_data: Vec<Vec<(f64, f64)>> = if state.show_regions {
+        dsp::SPECTRAL_REGIONS
+            .iter()
+            .map(|&(lo, hi)| {
+                let (x0, x1) = (freq_to_x(lo).max(view_lo), freq_to_x(hi).min(view_hi));
+                if x0 > x1 {
+                    return Vec::new();
+                }
+                let step = 0.05 * (view_hi - view_lo) / (NUM_BANDS - 1) as f64;
+                let steps = ((x1 - x0) / step).ceil() as usize;
+                (0..=steps).map(|k| ((x0 + k as f64 * step).min(x1), floor as f64)).collect()
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // Curseur : fréquence, G, D et Δ de la bande lus en clair dans le titre
+    let cursor_points: Vec<(f64, Color)> = state
+        .spectrum_cursor
+        .map(|band| {
+            [(&left_data, theme().green), (&right_data, theme().orange)]
+                .into_iter()
+                .filter_map(|(data, color)| band_value(data, band).map(|db| (db, color)))
+                .collect()
+        })
+        .unwrap_or_default();
+    if let Some(band) = state.spectrum_cursor {
+        let mode = roommodes::mode_near(&state.room_modes, dsp::band_center_freq(band, NUM_BANDS));
+        block = block.title(Title::from(cursor_readout(band, &left_data, &right_data, mode)).alignment(Alignment::Right));
+    }
+
     let mut datasets: Vec<Dataset> = Vec::new();
 
-    if state.left_db.is_some() {
+    // Zones au fond (sans nom : la légende est sur la bordure)
+    for (data, color) in region_data.iter().zip(theme().regions) {
+        datasets.push(
+            Dataset::default()
+                .marker(symbols::Marker::Block)
+                .graph_type(GraphType::Bar)
+                .style(Style::default().fg(color))
+                .data(data),
+        );
+    }
+
+    // Bruit de fond en premier : les mesures se tracent par-dessus
+    if state.noise.is_some() {
         datasets.push(
             Dataset::default()
-                .name("Gauche")
+                .name(s.noise_name)
                 .marker(symbols::Marker::Braille)
                 .graph_type(GraphType::Line)
-                .style(Style::default().fg(GREEN))
-                .data(&left_data),
+                .style(Style::default().fg(theme().gray))
+                .data(&noise_data),
         );
     }
-    if state.right_db.is_some() {
+    // Analyse A de la comparaison, sous B
+    if let Some((a_left, a_right)) = &overlay_data {
+        for (name, data, color) in [
+            (s.overlay_a_left, a_left, theme().overlay_left),
+            (s.overlay_a_right, a_right, theme().overlay_right),
+        ] {
+            datasets.push(
+                Dataset::default()
+                    .name(name)
+                    .marker(symbols::Marker::Dot)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(color))
+                    .data(data),
+            );
+        }
+    }
+    if state.left.is_some() || overlay.is_some() {
         datasets.push(
             Dataset::default()
-                .name("Droite")
+                .name(if overlay.is_some() { s.overlay_b_left } else { s.left_name })
                 .marker(symbols::Marker::Braille)
                 .graph_type(GraphType::Line)
-                .style(Style::default().fg(ORANGE))
+                .style(Style::default().fg(theme().green))
+                .data(&left_data),
+        );
+    }
+    if state.right.is_some() || overlay.is_some() {
+        datasets.push(
+            Dataset::default()
+                .name(if overlay.is_some() { s.overlay_b_right } else { s.right_name })
+                .marker(theme().right_marker)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(theme().orange).add_modifier(theme().right_modifier))
                 .data(&right_data),
         );
     }
-    if state.diff_db.is_some() {
+    if !target_data.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .name(s.target_name)
+                .marker(symbols::Marker::Dot)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(theme().cyan))
+                .data(&target_data),
+        );
+    }
+    if !mask_data.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .name(s.mask_name)
+                .marker(symbols::Marker::Dot)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(theme().gray))
+                .data(&mask_data),
+        );
+    }
+    if state.diff_db.is_some() && overlay.is_none() {
         datasets.push(
             Dataset::default()
                 .name("Δ Diff")
                 .marker(symbols::Marker::Dot)
                 .graph_type(GraphType::Line)
-                .style(Style::default().fg(RED))
+                .style(Style::default().fg(theme().red))
                 .data(&diff_data),
         );
     }
 
-    // Étiquettes de l'axe X (fréquences)
-    let freq_labels: Vec<(f64, String)> = [20usize, 50, 100, 500, 1000, 5000, 10000, 20000]
-        .iter()
-        .map(|&f| {
-            let log_min = (20f32).log10() as f64;
-            let log_max = (20_000f32).log10() as f64;
-            let ratio = ((f as f64).log10() - log_min) / (log_max - log_min);
-            let idx = (ratio * (NUM_BANDS - 1) as f64) as f64;
-            let label = if f >= 1000 {
-                format!("{}k", f / 1000)
-            } else {
-                format!("{}", f)
-            };
-            (idx, label)
-        })
-        .collect();
-
-    let x_labels: Vec<Span> = freq_labels
-        .iter()
-        .map(|(_, l)| Span::styled(l.clone(), Style::default().fg(GRAY)))
-        .collect();
+    // Étiquettes des axes, recalculées pour la vue
+    let (x_labels, y_labels) = spectrum_labels(view);
+    let styled = |labels: Vec<String>| -> Vec<Span> {
+        labels.into_iter().map(|l| Span::styled(l, Style::default().fg(theme().gray))).collect()
+    };
 
     let chart = Chart::new(datasets)
         .block(block)
         .x_axis(
             Axis::default()
-                .title(Span::styled("Hz", Style::default().fg(GRAY)))
-                .style(Style::default().fg(GRAY))
-                .labels(x_labels)
-                .bounds([0.0, (NUM_BANDS - 1) as f64]),
+                .title(Span::styled("Hz", Style::default().fg(theme().gray)))
+                .style(Style::default().fg(theme().gray))
+                .labels(styled(x_labels))
+                .bounds([view_lo, view_hi]),
         )
         .y_axis(
             Axis::default()
-                .title(Span::styled("dB", Style::default().fg(GRAY)))
-                .style(Style::default().fg(GRAY))
-                .labels(vec![
-                    Span::styled("-80", Style::default().fg(GRAY)),
-                    Span::styled("-60", Style::default().fg(GRAY)),
-                    Span::styled("-40", Style::default().fg(GRAY)),
-                    Span::styled("-20", Style::default().fg(GRAY)),
-                    Span::styled("0", Style::default().fg(GRAY)),
-                ])
-                .bounds([-80.0, 0.0]),
+                .title(Span::styled("dB", Style::default().fg(theme().gray)))
+                .style(Style::default().fg(theme().gray))
+                .labels(styled(y_labels))
+                .bounds([floor as f64, 0.0]),
         );
 
     f.render_widget(chart, area);
+    draw_room_modes(f, spectrum_plot_area(area, view), view, room_modes);
+    if let Some(band) = state.spectrum_cursor {
+        draw_spectrum_cursor(f, spectrum_plot_area(area, view), view, band, &cursor_points);
+    }
 }
 
-// ─── Panneau de résultats ─────────────────────────────────────────────────────
+/// Niveau tracé de la bande `band` (les points ne couvrent que la vue).
+fn band_value(data: &[(f64, f64)], band: usize) -> Option<f64> {
+    data.iter().find(|p| p.0 as usize == band).map(|p| p.1)
+}
 
-fn draw_results_panel(f: &mut Frame, area: Rect, state: &AppState) {
-    let rows = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(7),  // Score + métriques
-            Constraint::Min(5),     // Recommandations
-            Constraint::Length(6),  // Historique
-        ])
-        .split(area);
+/// Modes de la pièce : repère sur l'axe des fréquences, et pointillés sur
+/// les cellules vides pour les axiaux, les plus marqués.
+fn draw_room_modes(f: &mut Frame, plot: Rect, view: SpectrumView, modes: &[RoomMode]) {
+    if plot.width == 0 || plot.height == 0 {
+        return;
+    }
+    let (lo, hi) = (view.bands.0 as f64, view.bands.1 as f64);
+    let buf = f.buffer_mut();
+    for mode in modes {
+        let x = freq_to_x(mode.freq_hz);
+        if x < lo || x > hi {
+            continue;
+        }
+        let column = plot.x + ((x - lo) / (hi - lo).max(1.0) * plot.width.saturating_sub(1) as f64).round() as u16;
+        let color = match mode.kind {
+            ModeKind::Axial => theme().purple,
+            ModeKind::Tangential => theme().gray,
+        };
+        if mode.kind == ModeKind::Axial {
+            for row in plot.top()..plot.bottom() {
+                let cell = &mut buf[(column, row)];
+                if matches!(cell.symbol(), " " | "\u{2800}") {
+                    cell.set_symbol("┊").set_fg(color);
+                }
+            }
+        }
+        // Axe des fréquences juste sous le tracé
+        if buf.area.contains((column, plot.bottom()).into()) {
+            buf[(column, plot.bottom())].set_symbol("┴").set_fg(color);
+        }
+    }
+}
 
-    draw_score_metrics(f, rows[0], state);
-    draw_recommendations(f, rows[1], state);
-    draw_history(f, rows[2], state);
+/// « 1.2 kHz  G −12.3  D −14.1  Δ −1.8 dB » à la bande `band` du curseur,
+/// valeurs telles que tracées (dB sous le pic), et le mode de la pièce
+/// proche s'il y en a un.
+fn cursor_readout(band: usize, left: &[(f64, f64)], right: &[(f64, f64)], mode: Option<RoomMode>) -> Line<'static> {
+    let s = tr();
+    let value = |data: &[(f64, f64)]| band_value(data, band);
+    let number = |v: Option<f64>| v.map_or_else(|| "—".to_string(), |v| format_signed(v, 1));
+    let (l, r) = (value(left), value(right));
+    let mut spans = vec![
+        Span::styled(
+            format!(" ⌖ {} ", format_hz(dsp::band_center_freq(band, NUM_BANDS))),
+            Style::default().fg(theme().white).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(format!(" {} {}", s.left_short, number(l)), Style::default().fg(theme().green)),
+        Span::styled(format!("  {} {}", s.right_short, number(r)), Style::default().fg(theme().orange)),
+        Span::styled(format!("  Δ {} dB ", number(l.zip(r).map(|(l, r)| r - l))), Style::default().fg(theme().red)),
+    ];
+    if let Some(mode) = mode {
+        spans.push(Span::styled((s.room_mode_near)(&mode.label(), mode.freq_hz), Style::default().fg(theme().purple)));
+    }
+    Line::from(spans)
 }
 
-fn draw_score_metrics(f: &mut Frame, area: Rect, state: &AppState) {
+// ─── Réponse impulsionnelle ───────────────────────────────────────────────────
+
+/// Étendue affichée autour du son direct (ms).
+const IR_VIEW_PRE_MS: f64 = 5.0;
+const IR_VIEW_POST_MS: f64 = 100.0;
+/// Plancher de l'enveloppe affichée (dB).
+const IR_FLOOR_DB: f64 = -60.0;
+
+fn draw_impulse_response(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(Span::styled(" Score & Métriques ", Style::default().fg(GRAY)))
-        .border_style(Style::default().fg(Color::Rgb(35, 35, 55)));
-
-    if let Some(score) = state.score {
-        let col = score_color(score);
-        let rating = if score >= 85 { "EXCELLENT" } else if score >= 60 { "AJUSTABLE" } else { "À CORRIGER" };
+        .title(Span::styled(
+            s.ir_title,
+            Style::default().fg(theme().gray).add_modifier(Modifier::BOLD),
+        ))
+        .border_style(Style::default().fg(theme().border));
 
-        let dist_line = match (state.left_dist_m, state.right_dist_m) {
-            (Some(l), Some(r)) => Line::from(vec![
-                Span::styled("  Distances  ", Style::default().fg(GRAY)),
-                Span::styled("G ", Style::default().fg(GREEN).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("{:.2} m", l), Style::default().fg(GREEN)),
-                Span::styled("  D ", Style::default().fg(ORANGE).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("{:.2} m", r), Style::default().fg(ORANGE)),
-            ]),
-            _ => Line::from(Span::styled(
-                "  Distances  — sweep requis",
-                Style::default().fg(GRAY),
-            )),
-        };
+    let irs = [
+        state.left.as_ref().and_then(|m| m.ir.as_deref().map(|ir| (ir, m.sample_rate))),
+        state.right.as_ref().and_then(|m| m.ir.as_deref().map(|ir| (ir, m.sample_rate))),
+    ];
+    // Origine commune = front le plus précoce : le retard G/D reste visible
+    let Some(origin) = irs.iter().flatten().filter_map(|(ir, sr)| dsp::ir_onset(ir, *sr, state.analysis.onset_db)).map(|o| o.index).min() else {
+        let para = Paragraph::new(Span::styled(s.ir_empty, Style::default().fg(theme().gray))).block(block);
+        f.render_widget(para, area);
+        return;
+    };
+    let peak = irs
+        .iter()
+        .flatten()
+        .flat_map(|(ir, _)| ir.iter())
+        .fold(0.0f32, |m, v| m.max(v.abs()));
+
+    // Un point par échantillon, relatif au pic commun
+    let envelope = |ir: &[f32], sr: u32| -> Vec<(f64, f64)> {
+        let per_ms = sr as f64 / 1000.0;
+        let from = origin.saturating_sub((IR_VIEW_PRE_MS * per_ms) as usize);
+        let to = (origin + (IR_VIEW_POST_MS * per_ms) as usize).min(ir.len());
+        (from..to)
+            .map(|i| {
+                let db = 20.0 * ((ir[i].abs() / peak).max(1e-6) as f64).log10();
+                ((i as f64 - origin as f64) / per_ms, db.max(IR_FLOOR_DB))
+            })
+            .collect()
+    };
+    let left_data = irs[0].map(|(ir, sr)| envelope(ir, sr)).unwrap_or_default();
+    let right_data = irs[1].map(|(ir, sr)| envelope(ir, sr)).unwrap_or_default();
+
+    let mut datasets = Vec::new();
+    let left = (s.left_name, symbols::Marker::Braille, Style::default().fg(theme().green), &left_data);
+    let right = (s.right_name, theme().right_marker, Style::default().fg(theme().orange).add_modifier(theme().right_modifier), &right_data);
+    for (name, marker, style, data) in [left, right] {
+        if !data.is_empty() {
+            datasets.push(Dataset::default().name(name).marker(marker).graph_type(GraphType::Line).style(style).data(data));
+        }
+    }
+
+    let label = |text: String| Span::styled(text, Style::default().fg(theme().gray));
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .title(label("ms".into()))
+                .style(Style::default().fg(theme().gray))
+                .labels(vec![
+                    label(format!("{}", -IR_VIEW_PRE_MS)),
+                    label("0".into()),
+                    label(format!("{}", IR_VIEW_POST_MS / 2.0)),
+                    label(format!("{}", IR_VIEW_POST_MS)),
+                ])
+                .bounds([-IR_VIEW_PRE_MS, IR_VIEW_POST_MS]),
+        )
+        .y_axis(
+            Axis::default()
+                .title(label("dB".into()))
+                .style(Style::default().fg(theme().gray))
+                .labels(vec![label(format!("{}", IR_FLOOR_DB)), label("-30".into()), label("0".into())])
+                .bounds([IR_FLOOR_DB, 0.0]),
+        );
+    f.render_widget(chart, area);
+}
+
+// ─── Retard par octave ────────────────────────────────────────────────────────
+
+fn draw_band_delay(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(report) = &state.band_delay else { return };
+
+    let spread = report.spread_ms();
+    let (verdict, color) = if report.frequency_dependent() {
+        ((s.band_delay_dispersed)(spread), theme().yellow)
+    } else {
+        ((s.band_delay_constant)(spread), theme().green)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.band_delay_title, Style::default().fg(theme().gray).add_modifier(Modifier::BOLD)))
+        .title_bottom(Span::styled(verdict, Style::default().fg(color)))
+        .border_style(Style::default().fg(theme().border));
+
+    let points: Vec<(f64, f64)> = report
+        .bands
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| b.delay_ms.map(|d| (i as f64, d as f64)))
+        .collect();
+    let last = report.bands.len().saturating_sub(1) as f64;
+    let broadband = [(0.0, report.broadband_ms as f64), (last, report.broadband_ms as f64)];
+
+    // Échelle symétrique autour de zéro, au moins ±0,5 ms
+    let extent = points
+        .iter()
+        .map(|p| p.1.abs())
+        .chain(std::iter::once(report.broadband_ms.abs() as f64))
+        .fold(0.5f64, f64::max)
+        * 1.2;
+
+    let datasets = vec![
+        Dataset::default()
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(theme().gray))
+            .data(&broadband),
+        Dataset::default()
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(theme().cyan))
+            .data(&points),
+    ];
+
+    let label = |t: String| Span::styled(t, Style::default().fg(theme().gray));
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .title(label("Hz".into()))
+                .style(Style::default().fg(theme().gray))
+                .labels(report.bands.iter().map(|b| label(format_hz(b.freq_hz).replace(" Hz", "").replace(" kHz", "k"))).collect::<Vec<_>>())
+                .bounds([0.0, last]),
+        )
+        .y_axis(
+            Axis::default()
+                .title(label("ms".into()))
+                .style(Style::default().fg(theme().gray))
+                .labels(vec![label(format_signed(-extent, 2)), label("0".into()), label(format_signed(extent, 2))])
+                .bounds([-extent, extent]),
+        );
+    f.render_widget(chart, area);
+}
+
+// ─── Guidage du micro (fenêtre d'écoute) ─────────────────────────────────────
+
+fn draw_window_grid(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(window) = &state.window else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(
+            (s.grid_title)(window.points.len(), GRID.len()),
+            Style::default().fg(theme().gray),
+        ))
+        .border_style(Style::default().fg(theme().border));
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled(format!("   {}", s.left_short), Style::default().fg(theme().green).add_modifier(Modifier::BOLD)),
+        Span::styled(s.grid_speakers, Style::default().fg(theme().gray)),
+        Span::styled(s.right_short, Style::default().fg(theme().orange).add_modifier(Modifier::BOLD)),
+    ])];
+
+    // Grille 3×3 : ligne -1 = avant (vers les enceintes)
+    for row in -1i8..=1 {
+        let mut spans = vec![Span::raw("  ")];
+        for col in -1i8..=1 {
+            let pos = GridPosition { col, row };
+            let (cell, style) = if state.window_pos == Some(pos) {
+                ("[▶]", Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD))
+            } else if window.is_done(pos) {
+                ("[✓]", Style::default().fg(theme().green))
+            } else {
+                ("[ ]", Style::default().fg(theme().gray))
+            };
+            spans.push(Span::styled(cell, style));
+            spans.push(Span::raw(if col < 1 { format!("{:^4}", "") } else { String::new() }));
+        }
+        if row == 0 {
+            spans.push(Span::styled(s.grid_seat, Style::default().fg(theme().gray)));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(Span::styled(
+        (s.grid_spacing)(GRID_SPACING_CM),
+        Style::default().fg(theme().gray),
+    )));
+    lines.push(Line::from(""));
+
+    match state.window_pos {
+        Some(pos) => {
+            lines.push(Line::from(vec![
+                Span::styled("  ▶ ", Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(pos.label(), Style::default().fg(theme().white).add_modifier(Modifier::BOLD)),
+            ]));
+            lines.push(Line::from(Span::styled(
+                format!("  {}", pos.placement_hint()),
+                Style::default().fg(theme().gray),
+            )));
+        }
+        None => lines.push(Line::from(Span::styled(
+            s.grid_complete,
+            Style::default().fg(theme().green).add_modifier(Modifier::BOLD),
+        ))),
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block).wrap(Wrap { trim: false }), area);
+}
+
+// ─── Comparaison multicanal ───────────────────────────────────────────────────
+
+fn draw_speakers(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(
+            (s.speakers_title)(&state.reference.name()),
+            Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD),
+        ))
+        .border_style(Style::default().fg(theme().border));
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "  {:<5}{:>9}{:>10}{:>10}{:>8}{:>10}{:>10}",
+            s.col_channel, s.col_distance, s.col_delay, s.col_level, s.col_score, s.col_trim, s.col_set_delay
+        ),
+        Style::default().fg(theme().gray),
+    ))];
+
+    // Réglages à saisir dans l'ampli, relatifs à la référence
+    let trims = state.channel_trims();
+    let trim_spans = |ch: Channel| match trims.iter().find(|t| t.channel == ch) {
+        Some(t) => vec![
+            Span::styled(format!("{:>8}dB", format_signed(t.trim_db, 1)), Style::default().fg(theme().cyan)),
+            Span::styled(
+                t.delay_ms.map(|d| format!("{:>8}ms", format_num(d, 2))).unwrap_or_else(|| format!("{:>10}", "—")),
+                Style::default().fg(theme().cyan),
+            ),
+        ],
+        None => Vec::new(),
+    };
+
+    for ch in state.captured_channels() {
+        let spans = if ch == state.reference {
+            let dist = state
+                .left
+                .iter()
+                .chain(state.right.iter())
+                .chain(state.others.iter())
+                .find(|m| m.channel == ch)
+                .and_then(|m| m.dist_m);
+            vec![
+                Span::styled(format!("  {:<5}", ch.name()), Style::default().fg(theme().white).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    dist.map(|d| format!("{:>8}m", format_num(d, 2))).unwrap_or_else(|| format!("{:>9}", "—")),
+                    Style::default().fg(theme().gray),
+                ),
+                Span::styled(format!("{:<28}", s.speakers_reference), Style::default().fg(theme().cyan)),
+            ]
+            .into_iter()
+            .chain(trim_spans(ch))
+            .collect()
+        } else if let Some(p) = state.pairs.iter().find(|p| p.channel == ch) {
+            vec![
+                Span::styled(format!("  {:<5}", ch.name()), Style::default().fg(theme().white).add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    p.dist_m.map(|d| format!("{:>8}m", format_num(d, 2))).unwrap_or_else(|| format!("{:>9}", "—")),
+                    Style::default().fg(theme().gray),
+                ),
+                Span::styled(format!("{:>8}ms", format_signed(p.delay_ms, 2)), Style::default().fg(theme().gray)),
+                Span::styled(format!("{:>8}dB", format_signed(p.level_diff_db, 1)), Style::default().fg(theme().gray)),
+                Span::styled(format!("{:>8}", p.score), Style::default().fg(score_color(p.score)).add_modifier(Modifier::BOLD)),
+            ]
+            .into_iter()
+            .chain(trim_spans(ch))
+            .collect()
+        } else {
+            vec![
+                Span::styled(format!("  {:<5}", ch.name()), Style::default().fg(theme().white).add_modifier(Modifier::BOLD)),
+                Span::styled(s.speakers_pending, Style::default().fg(theme().gray)),
+            ]
+        };
+        lines.push(Line::from(spans));
+    }
+    if !trims.is_empty() {
+        lines.push(Line::from(Span::styled((s.speakers_trims_hint)(&state.reference.name()), Style::default().fg(theme().gray))));
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Intégration du caisson ───────────────────────────────────────────────────
+
+fn draw_sub_alignment(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(
+            (s.sub_title)(&state.sub_output.name()),
+            Style::default().fg(theme().purple).add_modifier(Modifier::BOLD),
+        ))
+        .border_style(Style::default().fg(theme().border));
+
+    let Some(a) = &state.sub_alignment else {
+        let hint = if state.left.is_none() { s.sub_need_left } else { s.sub_unavailable };
+        f.render_widget(Paragraph::new(Span::styled(hint, Style::default().fg(theme().gray))).block(block), area);
+        return;
+    };
+
+    let phase_color = if a.phase_deg.abs() <= 45.0 { theme().green } else if a.phase_deg.abs() <= 90.0 { theme().yellow } else { theme().red };
+    let (target, amount) = if a.correction_ms >= 0.0 {
+        (s.sub_delay_sub, a.correction_ms)
+    } else {
+        (s.sub_delay_mains, -a.correction_ms)
+    };
+    let dist = a.distance_correction_m();
+    let avr_hint = if dist >= 0.0 { (s.avr_decrease)(dist) } else { (s.avr_increase)(-dist) };
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled((s.sub_arrival)(a.arrival_diff_ms), Style::default().fg(theme().white)),
+            Span::styled((s.sub_phase_at)(a.crossover_hz), Style::default().fg(theme().gray)),
+            Span::styled(format!("{:+.0}°", a.phase_deg), Style::default().fg(phase_color).add_modifier(Modifier::BOLD)),
+            Span::styled((s.sub_summation)(a.summation_loss_db()), Style::default().fg(theme().gray)),
+        ]),
+        Line::from(vec![
+            Span::styled((s.sub_action)(target), Style::default().fg(theme().white)),
+            Span::styled(format!("{} ms", format_num(amount, 2)), Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(" (miniDSP)", Style::default().fg(theme().gray)),
+        ]),
+        Line::from(Span::styled(avr_hint, Style::default().fg(theme().cyan))),
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Caissons multiples : écart entre places avant / après, réglage de chacun.
+fn draw_multisub(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(multisub) = &state.multisub else { return };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled((s.multisub_title)(multisub.seats.len()), Style::default().fg(theme().purple).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().border));
+
+    let Some(plan) = &multisub.plan else {
+        f.render_widget(Paragraph::new(Span::styled(s.multisub_hint, Style::default().fg(theme().gray))).block(block), area);
+        return;
+    };
+    let improved = plan.spread_after_db < plan.spread_before_db;
+    let mut lines = vec![Line::from(Span::styled(
+        (s.multisub_spread)(plan.spread_before_db, plan.spread_after_db, plan.level_change_db),
+        Style::default().fg(if improved { theme().green } else { theme().white }),
+    ))];
+    for (output, setting) in multisub.outputs.iter().zip(&plan.settings) {
+        lines.push(Line::from(Span::styled(
+            (s.multisub_setting)(&output.name(), setting.gain_db, setting.delay_ms, setting.inverted),
+            Style::default().fg(theme().cyan),
+        )));
+    }
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Bass management ──────────────────────────────────────────────────────────
+
+fn draw_bass_report(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(report) = &state.bass_report else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.bass_title, Style::default().fg(theme().purple).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().border));
+
+    let mut lines: Vec<Line> = report
+        .bands
+        .iter()
+        .map(|b| {
+            let (route, color) = match b.route {
+                BassRoute::Main => (s.route_main, theme().green),
+                BassRoute::Sub => (s.route_sub, theme().purple),
+                BassRoute::Absent => (s.route_absent, theme().red),
+            };
+            Line::from(vec![
+                Span::styled(format!("  {:>6} Hz", format_num(b.center_hz, 1)), Style::default().fg(theme().white)),
+                Span::styled(format!("  {:>6} dB", format_signed(b.level_db, 1)), Style::default().fg(theme().gray)),
+                Span::styled(format!("  Δt {:>6} ms  ", format_signed(b.delay_ms, 1)), Style::default().fg(theme().gray)),
+                Span::styled(route, Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            ])
+        })
+        .collect();
+
+    let (verdict, color) = match report.crossover_hz {
+        Some(hz) if report.matches_configuration() => ((s.bass_ok)(hz, report.configured_hz), theme().green),
+        Some(hz) => ((s.bass_mismatch)(hz, report.configured_hz), theme().yellow),
+        None => ((s.bass_none)(report.configured_hz), theme().yellow),
+    };
+    lines.push(Line::from(Span::styled(verdict, Style::default().fg(color))));
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Polarité par haut-parleur ────────────────────────────────────────────────
+
+fn draw_polarity_report(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(report) = &state.polarity else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.polarity_title, Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().border));
+
+    let cell = |bands: &Option<Vec<BandPolarity>>, i: usize| -> Span<'static> {
+        match bands.as_ref().and_then(|b| b.get(i)) {
+            Some(b) if !b.is_reliable() => Span::styled(format!("{:<14}", s.pol_uncertain), Style::default().fg(theme().yellow)),
+            Some(b) if b.positive => Span::styled(format!("{:<14}", s.pol_normal), Style::default().fg(theme().green)),
+            Some(_) => Span::styled(format!("{:<14}", s.pol_inverted), Style::default().fg(theme().orange)),
+            None => Span::styled(format!("{:<14}", "—"), Style::default().fg(theme().gray)),
+        }
+    };
+
+    let mut lines: Vec<Line> = DRIVER_BANDS
+        .iter()
+        .enumerate()
+        .map(|(i, (driver, lo, hi))| {
+            Line::from(vec![
+                Span::styled(format!("  {:<8}", driver), Style::default().fg(theme().white)),
+                Span::styled(format!("{:>5.0}–{:<6.0}Hz  ", lo, hi), Style::default().fg(theme().gray)),
+                Span::styled(format!("{} ", s.left_short), Style::default().fg(theme().green)),
+                cell(&report.left, i),
+                Span::styled(format!("{} ", s.right_short), Style::default().fg(theme().orange)),
+                cell(&report.right, i),
+            ])
+        })
+        .collect();
+
+    let mismatched = report.mismatched_drivers();
+    let verdict = if report.left.is_none() || report.right.is_none() {
+        Line::from(Span::styled(s.measuring, Style::default().fg(theme().gray)))
+    } else if mismatched.is_empty() {
+        Line::from(Span::styled(s.pol_ok, Style::default().fg(theme().green)))
+    } else {
+        Line::from(Span::styled(
+            (s.pol_mismatch)(&mismatched.join(", ")),
+            Style::default().fg(theme().red).add_modifier(Modifier::BOLD),
+        ))
+    };
+    lines.push(verdict);
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Rub & buzz ───────────────────────────────────────────────────────────────
+
+fn draw_rub_buzz_report(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(report) = &state.rub_buzz else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.rub_title, Style::default().fg(theme().yellow).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().border));
+
+    let row = |label: &'static str, color: Color, bursts: &Option<Vec<BurstDistortion>>| -> Line<'static> {
+        let mut spans = vec![Span::styled(format!("  {} ", label), Style::default().fg(color).add_modifier(Modifier::BOLD))];
+        match bursts {
+            Some(bursts) => spans.extend(bursts.iter().map(|b| {
+                let c = if b.residue_db > RESIDUE_LIMIT_DB { theme().yellow } else { theme().gray };
+                Span::styled(format!("{:>4.0}Hz {:>4.0}dB  ", b.freq_hz, b.residue_db), Style::default().fg(c))
+            })),
+            None => spans.push(Span::styled("—", Style::default().fg(theme().gray))),
+        }
+        Line::from(spans)
+    };
+
+    let suspects = report.suspects();
+    let verdict = if report.left.is_none() || report.right.is_none() {
+        Line::from(Span::styled(s.measuring, Style::default().fg(theme().gray)))
+    } else if suspects.is_empty() {
+        Line::from(Span::styled(s.rub_ok, Style::default().fg(theme().green)))
+    } else {
+        let list: Vec<String> = suspects
+            .iter()
+            .map(|(freq, side, diff)| (s.rub_item)(side_label(side), *freq, *diff))
+            .collect();
+        Line::from(Span::styled(
+            (s.rub_suspect)(&list.join(", ")),
+            Style::default().fg(theme().red).add_modifier(Modifier::BOLD),
+        ))
+    };
+
+    let lines = vec![
+        row(s.left_short, theme().green, &report.left),
+        row(s.right_short, theme().orange, &report.right),
+        verdict,
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Compression ──────────────────────────────────────────────────────────────
+
+fn draw_compression_report(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(report) = &state.compression else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(
+            (s.compression_title)(LEVELS_DB[0], LEVELS_DB[LEVELS_DB.len() - 1]),
+            Style::default().fg(theme().yellow).add_modifier(Modifier::BOLD),
+        ))
+        .border_style(Style::default().fg(theme().border));
+
+    let row = |label: &'static str, color: Color, c: &Option<ChannelCompression>| -> Line<'static> {
+        let mut spans = vec![Span::styled(format!("  {} ", label), Style::default().fg(color).add_modifier(Modifier::BOLD))];
+        match c {
+            Some(c) => spans.extend(c.per_octave_db.iter().zip(OCTAVE_CENTERS.iter()).map(|(db, fc)| {
+                let col = if *db < -COMPRESSION_LIMIT_DB { theme().red } else { theme().gray };
+                let freq = if *fc >= 1000.0 { format!("{:.0}k", fc / 1000.0) } else { format!("{:.0}", fc) };
+                Span::styled(format!("{:>3}:{:>5} ", freq, format_signed(*db, 1)), Style::default().fg(col))
+            })),
+            None => spans.push(Span::styled("—", Style::default().fg(theme().gray))),
+        }
+        Line::from(spans)
+    };
+
+    let compressed = report.compressed_octaves();
+    let verdict = if report.left.is_none() || report.right.is_none() {
+        Line::from(Span::styled(s.measuring, Style::default().fg(theme().gray)))
+    } else if compressed.is_empty() {
+        Line::from(Span::styled(s.compression_ok, Style::default().fg(theme().green)))
+    } else {
+        let list: Vec<String> = compressed
+            .iter()
+            .map(|(fc, side, db)| format!("{} {:.0} Hz ({} dB)", side_label(side), fc, format_signed(*db, 1)))
+            .collect();
+        Line::from(Span::styled(
+            (s.compression_bad)(&list.join(", ")),
+            Style::default().fg(theme().red).add_modifier(Modifier::BOLD),
+        ))
+    };
+
+    let lines = vec![
+        row(s.left_short, theme().green, &report.left),
+        row(s.right_short, theme().orange, &report.right),
+        verdict,
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Linéarité du gain ────────────────────────────────────────────────────────
+
+fn draw_linearity_report(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(report) = &state.linearity else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(
+            (s.linearity_title)(STEPS_DBFS[0], STEPS_DBFS[STEPS_DBFS.len() - 1]),
+            Style::default().fg(theme().yellow).add_modifier(Modifier::BOLD),
+        ))
+        .border_style(Style::default().fg(theme().border));
+
+    let row = |label: &'static str, color: Color, l: &Option<ChannelLinearity>| -> Line<'static> {
+        let mut spans = vec![Span::styled(format!("  {} ", label), Style::default().fg(color).add_modifier(Modifier::BOLD))];
+        match l {
+            Some(l) => {
+                let verdict_color = if l.is_linear() { theme().green } else { theme().red };
+                spans.push(Span::styled(
+                    (s.linearity_row)(l.slope, l.max_deviation_db),
+                    Style::default().fg(verdict_color),
+                ));
+                spans.extend(l.output_db.iter().map(|db| Span::styled(format!("{:>5} ", format_signed(*db, 1)), Style::default().fg(theme().gray))));
+            }
+            None => spans.push(Span::styled("—", Style::default().fg(theme().gray))),
+        }
+        Line::from(spans)
+    };
+
+    let verdict = match (&report.left, &report.right) {
+        (Some(l), Some(r)) if l.is_linear() && r.is_linear() => {
+            Line::from(Span::styled(s.linearity_ok, Style::default().fg(theme().green)))
+        }
+        (Some(_), Some(_)) => Line::from(Span::styled(
+            s.linearity_bad,
+            Style::default().fg(theme().red).add_modifier(Modifier::BOLD),
+        )),
+        _ => Line::from(Span::styled(s.measuring, Style::default().fg(theme().gray))),
+    };
+
+    let lines = vec![
+        row(s.left_short, theme().green, &report.left),
+        row(s.right_short, theme().orange, &report.right),
+        verdict,
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Traitements du système ───────────────────────────────────────────────────
+
+fn draw_enhancements(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.enh_title, Style::default().fg(theme().red).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().red));
+
+    let mut lines: Vec<Line> = state
+        .enhancements
+        .iter()
+        .map(|symptom| {
+            let text = match *symptom {
+                Symptom::Pumping { side, drift_db } => (s.enh_pumping)(side_label(side), drift_db),
+                Symptom::GainRiding { side, slope } => (s.enh_gain_riding)(side_label(side), slope),
+                Symptom::Crossfeed { side, delay_ms, level_db, inverted } => {
+                    (s.enh_crossfeed)(side_label(side), delay_ms, level_db, inverted)
+                }
+            };
+            Line::from(Span::styled(text, Style::default().fg(theme().yellow)))
+        })
+        .collect();
+    let hint = if cfg!(windows) { s.enh_hint_windows } else { s.enh_hint_other };
+    lines.push(Line::from(Span::styled(hint, Style::default().fg(theme().white))));
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Câblage des canaux ───────────────────────────────────────────────────────
+
+fn draw_channel_map(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(report) = &state.channel_probe else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.map_title, Style::default().fg(theme().yellow).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().border));
+
+    // Emplacements numérotés à partir de 1, comme les touches [1-8]
+    let row = |label: &'static str, color: Color, probe: &Option<SlotProbe>| -> Line<'static> {
+        let mut spans = vec![Span::styled(format!("  {} ", label), Style::default().fg(color).add_modifier(Modifier::BOLD))];
+        match probe {
+            Some(p) => spans.extend(p.levels_db.iter().enumerate().map(|(k, db)| {
+                let c = if p.slot == Some(k) { color } else { theme().gray };
+                Span::styled(format!("{}:{:>+4.0}  ", k + 1, db), Style::default().fg(c))
+            })),
+            None => spans.push(Span::styled("—", Style::default().fg(theme().gray))),
+        }
+        Line::from(spans)
+    };
+
+    let verdict = match (&report.left, &report.right, report.map()) {
+        (_, _, Some(map)) if map.is_identity() => Line::from(Span::styled(s.map_ok, Style::default().fg(theme().green))),
+        (_, _, Some(map)) => Line::from(Span::styled(
+            (s.map_applied)(map.left + 1, map.right + 1),
+            Style::default().fg(theme().yellow).add_modifier(Modifier::BOLD),
+        )),
+        (Some(_), None, _) if !state.step.is_capturing() => Line::from(Span::styled(s.map_next, Style::default().fg(theme().cyan))),
+        (Some(_), Some(_), None) => Line::from(Span::styled(s.map_uncertain, Style::default().fg(theme().red))),
+        _ => Line::from(Span::styled(s.measuring, Style::default().fg(theme().gray))),
+    };
+
+    let lines = vec![
+        row(s.left_short, theme().green, &report.left),
+        row(s.right_short, theme().orange, &report.right),
+        verdict,
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Vérification rapide ──────────────────────────────────────────────────────
+
+fn draw_quick_check(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(report) = &state.quick_check else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(
+            (s.quick_title)(report.stimulus.label()),
+            Style::default().fg(theme().yellow).add_modifier(Modifier::BOLD),
+        ))
+        .border_style(Style::default().fg(theme().border));
+
+    let row = |label: &'static str, color: Color, check: &Option<ChannelQuickCheck>| -> Line<'static> {
+        let mut spans = vec![Span::styled(format!("  {} ", label), Style::default().fg(color).add_modifier(Modifier::BOLD))];
+        match check {
+            Some(c) => spans.extend(c.bands.iter().map(|b| {
+                let (mark, c) = if b.ok { ("✓", theme().green) } else { ("✗", theme().red) };
+                Span::styled(format!("{} {:>+4.0} {}   ", format_hz(b.freq_hz), b.snr_db, mark), Style::default().fg(c))
+            })),
+            None => spans.push(Span::styled("—", Style::default().fg(theme().gray))),
+        }
+        Line::from(spans)
+    };
+
+    // Première enceinte en défaut, avec les bandes manquantes
+    let failed = [("G", &report.left), ("D", &report.right)]
+        .into_iter()
+        .find_map(|(side, c)| c.as_ref().filter(|c| !c.passed()).map(|c| (side, c)));
+    let verdict = match failed {
+        Some((side, c)) => {
+            let freqs: Vec<String> = c.missing().map(format_hz).collect();
+            Line::from(Span::styled(
+                (s.quick_missing)(side_label(side), &freqs.join(", ")),
+                Style::default().fg(theme().red).add_modifier(Modifier::BOLD),
+            ))
+        }
+        None if report.passed() => Line::from(Span::styled(s.quick_ok, Style::default().fg(theme().green))),
+        None => Line::from(Span::styled(s.measuring, Style::default().fg(theme().gray))),
+    };
+
+    let lines = vec![
+        row(s.left_short, theme().green, &report.left),
+        row(s.right_short, theme().orange, &report.right),
+        verdict,
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Distances rapides ────────────────────────────────────────────────────────
+
+fn draw_quick_distance(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(report) = &state.quick_distance else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.distance_title, Style::default().fg(theme().yellow).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().border));
+
+    let (Some(left), Some(right), Some(ms), Some(cm)) = (report.left, report.right, report.delay_ms(), report.distance_cm()) else {
+        f.render_widget(Paragraph::new(Span::styled(s.measuring, Style::default().fg(theme().gray))).block(block), area);
+        return;
+    };
+
+    let mut values = vec![Span::styled((s.distance_values)(left, right), Style::default().fg(theme().white))];
+    if !report.absolute {
+        values.push(Span::styled(s.distance_relative, Style::default().fg(theme().gray)));
+    }
+    let aligned = cm.abs() < watch::ALIGNED_CM;
+    let reading = Line::from(Span::styled(
+        (s.distance_reading)(ms, cm),
+        Style::default().fg(if aligned { theme().green } else { theme().yellow }).add_modifier(Modifier::BOLD),
+    ));
+    let action = if aligned {
+        Line::from(Span::styled(s.watch_aligned, Style::default().fg(theme().green)))
+    } else {
+        let (icon, text) = if cm > 0.0 { ("↗", s.reco_closer) } else { ("↙", s.reco_farther) };
+        Line::from(Span::styled(format!("  {} {} ({} cm)", icon, text, format_num(cm.abs(), 1)), Style::default().fg(theme().orange)))
+    };
+
+    f.render_widget(Paragraph::new(vec![Line::from(values), reading, action]).block(block), area);
+}
+
+// ─── Moyenne spatiale (micro en mouvement) ────────────────────────────────────
+
+fn draw_mmm_report(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(report) = &state.mmm else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.mmm_title, Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().border));
+
+    let side = |label: &'static str, color: Color, avg: &Option<SpatialAverage>| -> Vec<Span<'static>> {
+        vec![
+            Span::styled(format!("  {} ", label), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            match avg {
+                Some(a) => Span::styled((s.mmm_blocks)(a.blocks), Style::default().fg(theme().white)),
+                None => Span::styled("—", Style::default().fg(theme().gray)),
+            },
+        ]
+    };
+    let mut counts = side(s.left_short, theme().green, &report.left);
+    counts.extend(side(s.right_short, theme().orange, &report.right));
+
+    let lines = match (report.region_diffs(), report.mismatch_db()) {
+        (Some(regions), Some(mismatch)) => {
+            let mut diff = vec![Span::styled(s.mmm_diff, Style::default().fg(theme().gray))];
+            diff.extend(regions.iter().zip(s.region_names).map(|(&db, name)| {
+                let color = if db.abs() > REGION_RECO_DB { theme().yellow } else { theme().white };
+                Span::styled(format!("{} {}   ", name, format_signed(db, 1)), Style::default().fg(color))
+            }));
+            vec![
+                Line::from(counts),
+                Line::from(diff),
+                Line::from(Span::styled((s.mmm_mismatch)(mismatch), Style::default().fg(theme().white))),
+            ]
+        }
+        _ => vec![Line::from(counts), Line::from(Span::styled(s.measuring, Style::default().fg(theme().gray)))],
+    };
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Bruit de fond ────────────────────────────────────────────────────────────
+
+/// Bandes consécutives regroupées en plages : « 20 Hz–40 Hz, 16 kHz ».
+fn band_runs(indices: &[usize]) -> String {
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for &i in indices {
+        match runs.last_mut() {
+            Some((_, end)) if *end + 1 == i => *end = i,
+            _ => runs.push((i, i)),
+        }
+    }
+    let hz = |i: usize| format_hz(dsp::band_center_freq(i, NUM_BANDS));
+    runs.iter()
+        .map(|&(a, b)| if a == b { hz(a) } else { format!("{}–{}", hz(a), hz(b)) })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn draw_noise_floor(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(noise) = &state.noise else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(
+            (s.noise_title)(noise.broadband_dbfs),
+            Style::default().fg(theme().gray).add_modifier(Modifier::BOLD),
+        ))
+        .border_style(Style::default().fg(theme().border));
+
+    let row = |side: &str, m: &Option<Measurement>| -> Line<'static> {
+        let Some(m) = m else {
+            return Line::from(Span::styled(format!("  {} —", side), Style::default().fg(theme().gray)));
+        };
+        let low = noise.low_snr_bands(&m.bands_db);
+        if low.is_empty() {
+            Line::from(Span::styled((s.noise_snr_ok)(side, noise::MIN_BAND_SNR_DB), Style::default().fg(theme().green)))
+        } else {
+            Line::from(Span::styled(
+                (s.noise_snr_low)(side, low.len(), noise::MIN_BAND_SNR_DB, &band_runs(&low)),
+                Style::default().fg(theme().yellow),
+            ))
+        }
+    };
+
+    let lines = vec![row(s.left_short, &state.left), row(s.right_short, &state.right)];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Suivi simultané G + D ────────────────────────────────────────────────────
+
+fn draw_dual(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(dual) = &state.dual else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.dual_title, Style::default().fg(theme().yellow).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().border));
+
+    let (Some(latest), Some(regions)) = (dual.readings.last(), dual.region_diffs()) else {
+        f.render_widget(Paragraph::new(Span::styled(s.measuring, Style::default().fg(theme().gray))).block(block), area);
+        return;
+    };
+
+    let balanced = latest.level_diff_db.abs() < dualnoise::BALANCED_DB;
+    let reading = Line::from(Span::styled(
+        (s.dual_reading)(latest.level_diff_db, latest.distance_cm()),
+        Style::default().fg(if balanced { theme().green } else { theme().yellow }).add_modifier(Modifier::BOLD),
+    ));
+
+    let mut diff = vec![Span::styled(s.dual_regions, Style::default().fg(theme().gray))];
+    diff.extend(regions.iter().zip(s.region_names).map(|(&db, name)| {
+        let color = if db.abs() > REGION_RECO_DB { theme().yellow } else { theme().white };
+        Span::styled(format!("{} {}   ", name, format_signed(db, 1)), Style::default().fg(color))
+    }));
+
+    // Lectures précédentes, la plus récente à droite
+    let trend: Vec<String> = dual.readings.iter().map(|r| format_signed(r.level_diff_db, 1)).collect();
+    let trend = Line::from(vec![
+        Span::styled(s.dual_trend, Style::default().fg(theme().gray)),
+        Span::styled(trend.join(" → "), Style::default().fg(theme().white)),
+    ]);
+
+    let action = if balanced {
+        Line::from(Span::styled(s.dual_balanced, Style::default().fg(theme().green)))
+    } else {
+        let side = if latest.level_diff_db > 0.0 { s.right_word } else { s.left_word };
+        Line::from(Span::styled((s.dual_louder)(side, latest.level_diff_db.abs()), Style::default().fg(theme().orange)))
+    };
+
+    f.render_widget(Paragraph::new(vec![reading, Line::from(diff), trend, action]).block(block), area);
+}
+
+// ─── Suivi en direct ──────────────────────────────────────────────────────────
+
+fn draw_watch(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(watch) = &state.watch else { return };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.watch_title, Style::default().fg(theme().yellow).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().border));
+
+    let Some(latest) = watch.latest() else {
+        f.render_widget(Paragraph::new(Span::styled(s.measuring, Style::default().fg(theme().gray))).block(block), area);
+        return;
+    };
+
+    let cm = latest.distance_cm();
+    let aligned = cm.abs() < watch::ALIGNED_CM;
+    let reading = Line::from(Span::styled(
+        (s.watch_reading)(cm, latest.level_diff_db),
+        Style::default().fg(if aligned { theme().green } else { theme().yellow }).add_modifier(Modifier::BOLD),
+    ));
+
+    // Lectures précédentes, la plus récente à droite
+    let trend: Vec<String> = watch.readings.iter().map(|r| format_signed(r.distance_cm(), 1)).collect();
+    let mut trend = vec![
+        Span::styled(s.watch_trend, Style::default().fg(theme().gray)),
+        Span::styled(trend.join(" → "), Style::default().fg(theme().white)),
+    ];
+    if state.nudge {
+        trend.push(Span::styled(s.watch_nudge, Style::default().fg(theme().cyan)));
+    }
+    let trend = Line::from(trend);
+
+    let action = if aligned {
+        Line::from(Span::styled(s.watch_aligned, Style::default().fg(theme().green)))
+    } else {
+        let (icon, text) = if cm > 0.0 { ("↗", s.reco_closer) } else { ("↙", s.reco_farther) };
+        Line::from(Span::styled(format!("  {} {} ({} cm)", icon, text, format_num(cm.abs(), 1)), Style::default().fg(theme().orange)))
+    };
+
+    f.render_widget(Paragraph::new(vec![reading, trend, action]).block(block), area);
+}
+
+// ─── Panneau de résultats ─────────────────────────────────────────────────────
+
+/// Panneau de droite : arbre des métriques, recommandations, historique.
+/// L'arbre prend la hauteur de ses domaines dépliés, dans la limite de ce
+/// que laissent les recommandations et l'historique ; au-delà, il défile
+/// jusqu'au domaine sélectionné ([F8]).
+fn draw_results_panel(f: &mut Frame, area: Rect, state: &AppState, hits: &mut HitMap) {
+    let s = tr();
+    let shown = state.metric_domains();
+    let mut lines = Vec::new();
+    let mut headers = Vec::new();
+    for &domain in &shown {
+        headers.push((lines.len(), domain));
+        lines.extend(metric_lines(state, domain));
+    }
+    // Au moins la moitié du panneau, même si les recommandations et
+    // l'historique doivent rétrécir (terminal de 24 lignes)
+    let fits = (lines.len() as u16 + 2).min(area.height.saturating_sub(11).max(area.height / 2));
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(fits), // Arbre des métriques
+            Constraint::Min(5),       // Recommandations
+            Constraint::Length(6),    // Historique
+        ])
+        .split(area);
+
+    let inner = rows[0].inner(Margin { horizontal: 1, vertical: 1 });
+    let focus_at = headers.iter().find(|(_, d)| *d == state.metrics.focus).map_or(0, |&(i, _)| i);
+    let scroll = focus_at.min(lines.len().saturating_sub(inner.height as usize));
+    let overflow = lines.len() > inner.height as usize;
+    let hint = format!(
+        " {} {} · {} {}{} ",
+        state.keys.label("[F8]"),
+        s.metrics_next,
+        state.keys.label("[F9]"),
+        s.metrics_fold,
+        if overflow { " ↕" } else { "" }
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(score_title(state), Style::default().fg(theme().gray)))
+        .title_bottom(Span::styled(hint, Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+    f.render_widget(Paragraph::new(lines).block(block).scroll((scroll as u16, 0)), rows[0]);
+    hits.metric_headers(inner, scroll, &headers);
+
+    draw_recommendations(f, rows[1], state);
+    draw_history(f, rows[2], state);
+    if state.show_trend {
+        hits.history_trend(rows[2], state.history.len());
+    } else {
+        hits.history(rows[2], state.history.len());
+    }
+}
+
+/// Lignes d'un domaine à son niveau de détail : en-tête (avec le résumé
+/// quand le domaine est replié), puis les valeurs.
+fn metric_lines(state: &AppState, domain: MetricDomain) -> Vec<Line<'static>> {
+    let s = tr();
+    let fold = state.metrics.fold(domain);
+    let focused = state.metrics.focus == domain;
+    let marker = Span::styled(
+        if fold == Fold::Summary { "▸ " } else { "▾ " },
+        if focused { Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(theme().gray) },
+    );
+    let title = |text: &'static str| {
+        let style = Style::default().fg(if focused { theme().white } else { theme().gray });
+        Span::styled(text.trim().to_string(), if focused { style.add_modifier(Modifier::BOLD) } else { style })
+    };
+
+    let (mut header, details) = match domain {
+        MetricDomain::Score => (score_header(state), score_metric_lines(state)),
+        MetricDomain::Clarity => match fold {
+            Fold::Summary => (vec![title(s.metric_clarity)], Vec::new()),
+            _ => (vec![title(s.clarity_title)], clarity_lines(state)),
+        },
+        MetricDomain::Reverb => match fold {
+            Fold::Summary | Fold::Domain => (vec![title(s.metric_reverb)], reverb_mean_lines(state)),
+            Fold::Bands => (vec![title(s.reverb_title)], reverb_lines(state)),
+        },
+        MetricDomain::Distortion => match fold {
+            Fold::Summary | Fold::Domain => (vec![title(s.metric_thd)], thd_worst_lines(state)),
+            Fold::Bands => (vec![title(s.thd_title)], harmonic_distortion_lines(state)),
+        },
+    };
+    header.insert(0, marker);
+    if fold != Fold::Summary {
+        return std::iter::once(Line::from(header)).chain(details).collect();
+    }
+    // Replié : le résumé suit le nom du domaine
+    header.extend(match domain {
+        MetricDomain::Score => Vec::new(),
+        MetricDomain::Clarity => side_summary(state, |m| m.clarity.map(|c| format!("C80 {} dB", format_signed(c.c80_db, 1))), None),
+        MetricDomain::Reverb => side_summary(state, |m| m.mean_decay(|d| d.t30_s).map(|t| format!("T30 {} s", format_num(t, 2))), None),
+        MetricDomain::Distortion => side_summary(
+            state,
+            |m| m.worst_thd().map(|o| format!("THD {} %", format_num(o.thd_pct(), 2))),
+            Some(|m: &Measurement| m.worst_thd().is_some_and(|o| o.thd_db > THD_LIMIT_DB)),
+        ),
+    });
+    vec![Line::from(header)]
+}
+
+/// « G … D … » d'un résumé replié ; `alarm` met la valeur d'un côté en rouge.
+fn side_summary(
+    state: &AppState,
+    value: impl Fn(&Measurement) -> Option<String>,
+    alarm: Option<fn(&Measurement) -> bool>,
+) -> Vec<Span<'static>> {
+    let s = tr();
+    let mut spans = Vec::new();
+    for (side, color, m) in [(s.left_short, theme().green, &state.left), (s.right_short, theme().orange, &state.right)] {
+        spans.push(Span::styled(format!("  {} ", side), Style::default().fg(color).add_modifier(Modifier::BOLD)));
+        spans.push(match m.as_ref().and_then(&value) {
+            Some(text) => {
+                let red = m.as_ref().zip(alarm).is_some_and(|(m, alarm)| alarm(m));
+                Span::styled(text, Style::default().fg(if red { theme().red } else { theme().white }))
+            }
+            None => Span::styled("—", Style::default().fg(theme().gray)),
+        });
+    }
+    spans
+}
+
+/// Ligne du score : note, verdict, répartition ; invite avant l'analyse.
+fn score_header(state: &AppState) -> Vec<Span<'static>> {
+    let s = tr();
+    let Some(score) = state.score else {
+        return vec![Span::styled(s.run_analysis.trim().to_string(), Style::default().fg(theme().gray))];
+    };
+    let col = score_color(score);
+    let rating = if score >= 85 { s.rating_excellent } else if score >= 60 { s.rating_adjust } else { s.rating_fix };
+    let mut spans = vec![
+        Span::styled(format!("{:>3}/100 ", score), Style::default().fg(col).add_modifier(Modifier::BOLD)),
+        Span::styled(rating, Style::default().fg(col)),
+    ];
+    spans.extend(score_split_spans(state));
+    spans
+}
 
-        let lines = vec![
+/// Distances, IACC, écart à la cible, retard / niveau / pente, fenêtre
+/// d'écoute.
+fn score_metric_lines(state: &AppState) -> Vec<Line<'static>> {
+    let s = tr();
+    if state.score.is_none() {
+        return Vec::new();
+    }
+    // Plus forte dérive d'horloge corrigée des deux mesures
+    let drift = state
+        .left
+        .iter()
+        .chain(&state.right)
+        .filter_map(|m| m.clock_drift_ppm)
+        .max_by(|a, b| a.abs().total_cmp(&b.abs()));
+    let dist_line = match (state.left.as_ref().and_then(|m| m.dist_m), state.right.as_ref().and_then(|m| m.dist_m)) {
+        (Some(l), Some(r)) => Line::from(vec![
+            Span::styled(s.distances, Style::default().fg(theme().gray)),
+            Span::styled(format!("{} ", s.left_short), Style::default().fg(theme().green).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{} m", format_num(l, 2)), Style::default().fg(theme().green)),
+            Span::styled(format!("  {} ", s.right_short), Style::default().fg(theme().orange).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{} m", format_num(r, 2)), Style::default().fg(theme().orange)),
+            Span::styled(
+                state.left.as_ref().and_then(|m| m.latency_ms).map(s.distances_latency).unwrap_or_default(),
+                Style::default().fg(theme().gray),
+            ),
+            Span::styled(drift.map(s.distances_drift).unwrap_or_default(), Style::default().fg(theme().gray)),
+        ]),
+        _ => Line::from(Span::styled(s.distances_need_sweep, Style::default().fg(theme().gray))),
+    };
+
+    // IACC : qualité spatiale (entrée à deux micros uniquement)
+    let iacc_line = match (state.left.as_ref().and_then(|m| m.iacc), state.right.as_ref().and_then(|m| m.iacc)) {
+        (None, None) => Line::from(Span::styled(s.iacc_need, Style::default().fg(theme().gray))),
+        (l, r) => {
+            let fmt = |v: Option<f32>| v.map(|v| format_num(v, 2)).unwrap_or_else(|| "—".into());
             Line::from(vec![
+                Span::styled("  IACC       ", Style::default().fg(theme().gray)),
+                Span::styled(format!("{} ", s.left_short), Style::default().fg(theme().green).add_modifier(Modifier::BOLD)),
+                Span::styled(fmt(l), Style::default().fg(theme().green)),
+                Span::styled(format!("  {} ", s.right_short), Style::default().fg(theme().orange).add_modifier(Modifier::BOLD)),
+                Span::styled(fmt(r), Style::default().fg(theme().orange)),
+            ])
+        }
+    };
+
+    let mut lines = vec![dist_line, iacc_line];
+    // Écart moyen de chaque enceinte à la courbe cible (niveaux alignés)
+    if let (Some(target), Some((l, r))) = (&state.target, state.target_deviations()) {
+        lines.push(Line::from(vec![
+            Span::styled(s.target_label, Style::default().fg(theme().gray)),
+            Span::styled(format!("{} ", s.left_short), Style::default().fg(theme().green).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("±{} dB", format_num(l, 1)), Style::default().fg(theme().green)),
+            Span::styled(format!("  {} ", s.right_short), Style::default().fg(theme().orange).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("±{} dB", format_num(r, 1)), Style::default().fg(theme().orange)),
+            Span::styled(format!("  ({})", target.name), Style::default().fg(theme().gray)),
+        ]));
+    }
+    let w = state.weights();
+    lines.extend([
+        meter_line_delay(s.meter_delay, state.delay_ms, 5.0, 2.0 * w.delay_ms.ok, theme().cyan),
+        meter_line(s.meter_level, state.level_diff_db, "dB", 10.0, w.level_db.ok, theme().orange),
+        meter_line(s.meter_spectrum, state.freq_tilt, "dB", 10.0, w.tilt_db.ok, theme().purple),
+    ]);
+
+    // Fenêtre d'écoute : pire écart d'une position par rapport à la moyenne
+    if let Some(w) = &state.window_summary {
+        lines.push(Line::from(vec![
+            Span::styled((s.window_summary)(w.positions), Style::default().fg(theme().gray)),
+            Span::styled(
+                format!("Δt ±{} ms  ΔL ±{} dB  ΔS ±{} dB", format_num(w.worst_delay_ms, 2), format_num(w.worst_level_db, 1), format_num(w.worst_spectral_db, 1)),
+                Style::default().fg(theme().white),
+            ),
+        ]));
+    }
+
+    lines
+}
+
+/// EDT / C50 / C80 de chaque côté.
+fn clarity_lines(state: &AppState) -> Vec<Line<'static>> {
+    let s = tr();
+    let clarity_line = |name: &'static str, color: Color, c: Option<ClarityMetrics>| -> Line<'static> {
+        match c {
+            Some(c) => Line::from(vec![
+                Span::styled(format!("  {} ", name), Style::default().fg(color).add_modifier(Modifier::BOLD)),
                 Span::styled(
-                    format!("  {:>3}/100 ", score),
-                    Style::default().fg(col).add_modifier(Modifier::BOLD),
+                    format!("EDT {} s   C50 {} dB   C80 {} dB", format_num(c.edt_s, 2), format_signed(c.c50_db, 1), format_signed(c.c80_db, 1)),
+                    Style::default().fg(theme().white),
                 ),
-                Span::styled(rating, Style::default().fg(col)),
             ]),
-            dist_line,
-            meter_line_delay("Délai", state.delay_ms, 5.0, 0.2, CYAN),
-            meter_line("Niveau", state.level_diff_db, "dB", 10.0, 0.5, ORANGE),
-            meter_line("Spectre", state.freq_tilt, "dB", 10.0, 1.0, PURPLE),
+            None => Line::from(vec![
+                Span::styled(format!("  {} ", name), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled("—", Style::default().fg(theme().gray)),
+            ]),
+        }
+    };
+
+    let mut lines = vec![
+        clarity_line(s.left_short, theme().green, state.left.as_ref().and_then(|m| m.clarity)),
+        clarity_line(s.right_short, theme().orange, state.right.as_ref().and_then(|m| m.clarity)),
+    ];
+    // Premières réflexions : retard, trajet en plus, niveau, surface probable
+    if let Some(report) = &state.reflections {
+        for (name, color, found) in [(s.left_short, theme().green, &report.left), (s.right_short, theme().orange, &report.right)] {
+            let mut spans = vec![
+                Span::styled(format!("  {} ", name), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{} ", s.reflections_label), Style::default().fg(theme().gray)),
+            ];
+            if found.is_empty() {
+                spans.push(Span::styled("—", Style::default().fg(theme().gray)));
+            }
+            for (i, r) in found.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::styled(" · ", Style::default().fg(theme().gray)));
+                }
+                spans.push(Span::styled(
+                    format!("{} ms (+{} m) {:+.0} dB ", format_num(r.delay_ms, 1), format_num(r.path_m, 2), r.level_db),
+                    Style::default().fg(theme().white),
+                ));
+                spans.push(Span::styled(r.surfaces_label(), Style::default().fg(theme().cyan)));
+            }
+            lines.push(Line::from(spans));
+        }
+    }
+    lines
+}
+
+// ─── Temps de réverbération par octave ────────────────────────────────────────
+
+/// T30 / T20 / EDT moyens de chaque côté.
+fn reverb_mean_lines(state: &AppState) -> Vec<Line<'static>> {
+    let s = tr();
+    let fmt = |t: Option<f32>| t.map_or("—".to_string(), |t| format!("{} s", format_num(t, 2)));
+    [(s.left_short, theme().green, &state.left), (s.right_short, theme().orange, &state.right)]
+        .into_iter()
+        .map(|(side, color, m)| {
+            let mut spans = vec![Span::styled(format!("  {} ", side), Style::default().fg(color).add_modifier(Modifier::BOLD))];
+            for (metric, pick) in [
+                ("T30", (|d: &DecayTimes| d.t30_s) as fn(&DecayTimes) -> Option<f32>),
+                ("T20", |d| d.t20_s),
+                ("EDT", |d| d.edt_s),
+            ] {
+                spans.push(Span::styled(format!("{} ", metric), Style::default().fg(theme().gray)));
+                spans.push(Span::styled(
+                    format!("{:<8}", fmt(m.as_ref().and_then(|m| m.mean_decay(pick)))),
+                    Style::default().fg(theme().white),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// T30 / T20 / EDT par octave.
+fn reverb_lines(state: &AppState) -> Vec<Line<'static>> {
+    let s = tr();
+    let mut header = vec![Span::styled("        ", Style::default())];
+    header.extend(OCTAVE_CENTERS.iter().map(|&fc| {
+        let label = if fc >= 1000.0 { format!("{}k", fc / 1000.0) } else { format!("{}", fc) };
+        Span::styled(format!("{:>5}", label), Style::default().fg(theme().gray))
+    }));
+
+    let row = |side: &'static str, metric: &'static str, color: Color, m: &Option<Measurement>, pick: fn(&DecayTimes) -> Option<f32>| {
+        let mut spans = vec![
+            Span::styled(format!("  {} ", side), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{:<4}", metric), Style::default().fg(theme().gray)),
         ];
+        let times = m.as_ref().map(|m| m.rt60.as_slice()).unwrap_or_default();
+        spans.extend((0..OCTAVE_CENTERS.len()).map(|i| match times.get(i).and_then(pick) {
+            Some(t) => Span::styled(format!("{:>5}", format_num(t, 2)), Style::default().fg(theme().white)),
+            None => Span::styled(format!("{:>5}", "—"), Style::default().fg(theme().gray)),
+        }));
+        Line::from(spans)
+    };
 
-        f.render_widget(Paragraph::new(lines).block(block), area);
-    } else {
-        let para = Paragraph::new(vec![
-            Line::from(""),
-            Line::from(Span::styled(
-                "  Lancez l'analyse [A]",
-                Style::default().fg(GRAY),
-            )),
-        ])
-        .block(block);
-        f.render_widget(para, area);
+    let mut lines = vec![Line::from(header)];
+    for (metric, pick) in [
+        ("T30", (|d: &DecayTimes| d.t30_s) as fn(&DecayTimes) -> Option<f32>),
+        ("T20", |d| d.t20_s),
+        ("EDT", |d| d.edt_s),
+    ] {
+        lines.push(row(s.left_short, metric, theme().green, &state.left, pick));
+        lines.push(row(s.right_short, metric, theme().orange, &state.right, pick));
     }
+    lines
+}
+
+// ─── Distorsion harmonique par octave (sweep) ─────────────────────────────────
+
+/// Octave la plus distordue de chaque côté.
+fn thd_worst_lines(state: &AppState) -> Vec<Line<'static>> {
+    let s = tr();
+    [(s.left_short, theme().green, &state.left), (s.right_short, theme().orange, &state.right)]
+        .into_iter()
+        .map(|(side, color, m)| {
+            let mut spans = vec![Span::styled(format!("  {} ", side), Style::default().fg(color).add_modifier(Modifier::BOLD))];
+            spans.extend(match m.as_ref().and_then(Measurement::worst_thd) {
+                Some(o) => vec![
+                    Span::styled(
+                        format!("{} % ", format_num(o.thd_pct(), 2)),
+                        Style::default().fg(if o.thd_db > THD_LIMIT_DB { theme().red } else { theme().white }),
+                    ),
+                    Span::styled((s.metrics_thd_at)(&format_hz(o.freq_hz)), Style::default().fg(theme().gray)),
+                ],
+                None => vec![Span::styled("—", Style::default().fg(theme().gray))],
+            });
+            Line::from(spans)
+        })
+        .collect()
+}
+
+fn harmonic_distortion_lines(state: &AppState) -> Vec<Line<'static>> {
+    let s = tr();
+    let mut header = vec![Span::styled("    ", Style::default())];
+    header.extend(OCTAVE_CENTERS.iter().map(|&fc| {
+        let label = if fc >= 1000.0 { format!("{}k", fc / 1000.0) } else { format!("{}", fc) };
+        Span::styled(format!("{:>6}", label), Style::default().fg(theme().gray))
+    }));
+
+    // Octave au-dessus de `THD_LIMIT_DB` en rouge ; absente (hors plage du
+    // sweep, fenêtre trop courte) en tiret
+    let row = |side: &'static str, color: Color, m: &Option<Measurement>| {
+        let mut spans = vec![Span::styled(format!("  {} ", side), Style::default().fg(color).add_modifier(Modifier::BOLD))];
+        let thd = m.as_ref().map(|m| m.thd.as_slice()).unwrap_or_default();
+        spans.extend(OCTAVE_CENTERS.iter().map(|&fc| match thd.iter().find(|o| o.freq_hz == fc) {
+            Some(o) => Span::styled(
+                format!("{:>6}", format_num(o.thd_pct(), 2)),
+                Style::default().fg(if o.thd_db > THD_LIMIT_DB { theme().red } else { theme().white }),
+            ),
+            None => Span::styled(format!("{:>6}", "—"), Style::default().fg(theme().gray)),
+        }));
+        Line::from(spans)
+    };
+
+    vec![
+        Line::from(header),
+        row(s.left_short, theme().green, &state.left),
+        row(s.right_short, theme().orange, &state.right),
+    ]
 }
 
 fn meter_line(label: &str, value: f32, unit: &str, max: f32, tolerance: f32, color: Color) -> Line<'static> {
     let is_good = value.abs() <= tolerance;
     let is_ok = value.abs() <= tolerance * 2.0;
-    let status_color = if is_good { GREEN } else if is_ok { YELLOW } else { RED };
+    let status_color = if is_good { theme().green } else if is_ok { theme().yellow } else { theme().red };
     let sign = if value >= 0.0 { "+" } else { "" };
     let bar_len = 12usize;
     let filled = ((value.abs() / max).min(1.0) * bar_len as f32) as usize;
@@ -496,11 +2542,11 @@ fn meter_line(label: &str, value: f32, unit: &str, max: f32, tolerance: f32, col
     Line::from(vec![
         Span::styled(
             format!("  {:<8}", label),
-            Style::default().fg(GRAY),
+            Style::default().fg(theme().gray),
         ),
         Span::styled(bar, Style::default().fg(color)),
         Span::styled(
-            format!(" {}{:.2} {}", sign, value, unit),
+            format!(" {}{} {}", sign, format_num(value, 2), unit),
             Style::default().fg(status_color).add_modifier(Modifier::BOLD),
         ),
     ])
@@ -509,7 +2555,7 @@ fn meter_line(label: &str, value: f32, unit: &str, max: f32, tolerance: f32, col
 fn meter_line_delay(label: &str, value: f32, max: f32, tolerance: f32, color: Color) -> Line<'static> {
     let is_good = value.abs() <= tolerance;
     let is_ok = value.abs() <= tolerance * 2.0;
-    let status_color = if is_good { GREEN } else if is_ok { YELLOW } else { RED };
+    let status_color = if is_good { theme().green } else if is_ok { theme().yellow } else { theme().red };
     let sign = if value >= 0.0 { "+" } else { "" };
     let bar_len = 12usize;
     let filled = ((value.abs() / max).min(1.0) * bar_len as f32) as usize;
@@ -518,11 +2564,11 @@ fn meter_line_delay(label: &str, value: f32, max: f32, tolerance: f32, color: Co
     Line::from(vec![
         Span::styled(
             format!("  {:<8}", label),
-            Style::default().fg(GRAY),
+            Style::default().fg(theme().gray),
         ),
         Span::styled(bar, Style::default().fg(color)),
         Span::styled(
-            format!(" {}{:.3} ms", sign, value),
+            format!(" {}{} ms", sign, format_num(value, 3)),
             Style::default().fg(status_color).add_modifier(Modifier::BOLD),
         ),
     ])
@@ -531,112 +2577,555 @@ fn meter_line_delay(label: &str, value: f32, max: f32, tolerance: f32, color: Co
 fn draw_recommendations(f: &mut Frame, area: Rect, state: &AppState) {
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(Span::styled(" Recommandations ", Style::default().fg(GRAY)))
-        .border_style(Style::default().fg(Color::Rgb(35, 35, 55)));
+        .title(Span::styled(tr().reco_title, Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+    f.render_widget(Paragraph::new(recommendation_lines(state)).block(block).wrap(Wrap { trim: true }), area);
+}
+
+/// Conseils de `advice` colorés selon le thème, icônes en gras.
+fn recommendation_lines(state: &AppState) -> Vec<Line<'static>> {
+    let color = |tone: Tone| match tone {
+        Tone::Severe => theme().red,
+        Tone::Warning => theme().yellow,
+        Tone::Action => theme().cyan,
+        Tone::Good => theme().green,
+        Tone::Text => theme().white,
+        Tone::Note => theme().gray,
+    };
+    advice::recommendations(state)
+        .into_iter()
+        .map(|line| {
+            let text = Style::default().fg(color(line.tone));
+            match line.icon {
+                Some((icon, tone)) => Line::from(vec![
+                    Span::styled(format!("  {} ", icon), Style::default().fg(color(tone)).add_modifier(Modifier::BOLD)),
+                    Span::styled(line.text, text),
+                ]),
+                None if line.tone == Tone::Good => Line::from(Span::styled(line.text, text.add_modifier(Modifier::BOLD))),
+                None => Line::from(Span::styled(line.text, text)),
+            }
+        })
+        .collect()
+}
+
+// ─── Liste de vérification ────────────────────────────────────────────────────
+
+fn draw_checklist(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some(list) = &state.checklist else { return };
+    let evidence = state.checklist_evidence();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.checklist_title, Style::default().fg(theme().yellow).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme().border));
 
-    if state.score.is_none() {
-        let para = Paragraph::new(Span::styled(
-            "  Résultats disponibles après analyse",
-            Style::default().fg(GRAY),
-        ))
-        .block(block);
+    let mut lines: Vec<Line> = ITEMS
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let (label, how) = s.checklist_items[i];
+            let tick = if list.ticked[i] { "✓" } else { "☐" };
+            // Un problème détecté reste affiché même coché à la main
+            let (mark, color, note) = match item.auto(&evidence) {
+                Some(true) => ("✓", theme().green, s.checklist_auto),
+                Some(false) => (tick, theme().red, s.checklist_problem),
+                None if list.ticked[i] => (tick, theme().green, ""),
+                None => (tick, theme().gray, how),
+            };
+            Line::from(vec![
+                Span::styled(format!("  {} ", i + 1), Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{} ", mark), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled(label, Style::default().fg(theme().white)),
+                Span::styled(
+                    if note.is_empty() { String::new() } else { format!("  — {}", note) },
+                    Style::default().fg(if color == theme().red { theme().red } else { theme().gray }),
+                ),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(Span::styled(s.checklist_hint, Style::default().fg(theme().gray))));
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Comparaison de deux analyses ─────────────────────────────────────────────
+
+fn draw_overlay(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some((ia, ib)) = state.overlay else { return };
+    let (a, b) = (&state.history[ia], &state.history[ib]);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled((s.overlay_title)(ia + 1, &a.time, ib + 1, &b.time), Style::default().fg(theme().gray)))
+        .title_bottom(Span::styled(s.overlay_keys, Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+
+    let Some(d) = OverlayDiff::between(a, b, state.analysis.bands()) else {
+        let para = Paragraph::new(Span::styled(s.overlay_no_curves, Style::default().fg(theme().gray))).block(block);
         f.render_widget(para, area);
         return;
-    }
+    };
+
+    // Vert si l'écart se réduit de A à B, rouge s'il grandit
+    let trend = |delta: f32, better_when_lower: bool| {
+        let improved = if better_when_lower { delta < 0.0 } else { delta > 0.0 };
+        if delta.abs() < 1e-3 { theme().gray } else if improved { theme().green } else { theme().red }
+    };
+    let row = |label: &'static str, from: String, to: String, delta: String, color: Color| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(theme().gray)),
+            Span::styled(format!("{} → {}", from, to), Style::default().fg(theme().white)),
+            Span::styled(format!("  ({})", delta), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        ])
+    };
+    let lines = vec![
+        row(s.overlay_score, a.score.to_string(), b.score.to_string(), format!("{:+}", d.score_delta), trend(d.score_delta as f32, false)),
+        row(
+            s.overlay_delay,
+            format_num(a.delay_ms.abs(), 2),
+            format!("{} ms", format_num(b.delay_ms.abs(), 2)),
+            format_signed(d.delay_delta_ms, 2),
+            trend(d.delay_delta_ms, true),
+        ),
+        row(
+            s.overlay_level,
+            format_num(a.level_diff_db.abs(), 1),
+            format!("{} dB", format_num(b.level_diff_db.abs(), 1)),
+            format_signed(d.level_delta_db, 1),
+            trend(d.level_delta_db, true),
+        ),
+        Line::from(vec![
+            Span::styled(s.overlay_mismatch, Style::default().fg(theme().gray)),
+            Span::styled(format!("{} → {} dB", format_num(d.mismatch_db.0, 1), format_num(d.mismatch_db.1, 1)), Style::default().fg(theme().white)),
+            Span::styled(
+                (s.overlay_shift)(d.shift_db.0, d.shift_db.1),
+                Style::default().fg(theme().gray),
+            ),
+        ]),
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Positions candidates ──────────────────────────────────────────────────────
 
-    let mut guides: Vec<Line> = Vec::new();
+/// Positions affichées (les mieux classées).
+const POSITION_ROWS: usize = 5;
 
-    if state.delay_ms.abs() > 0.1 {
-        let icon = if state.delay_ms > 0.0 { "↗" } else { "↙" };
-        let action = if state.delay_ms > 0.0 {
-            "Rapprocher l'enceinte droite"
+fn draw_positions(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled((s.positions_title)(state.positions.len()), Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+
+    let mut lines = vec![Line::from(Span::styled(s.positions_header, Style::default().fg(theme().gray)))];
+    for (rank, &i) in positions::ranking(&state.positions).iter().take(POSITION_ROWS).enumerate() {
+        let p = &state.positions[i];
+        let composite = p.composite();
+        let reflection = p.reflection_db.map_or("—".to_string(), |db| format!("{:.0} dB", db));
+        let style = if rank == 0 {
+            Style::default().fg(theme().green).add_modifier(Modifier::BOLD)
         } else {
-            "Éloigner l'enceinte droite"
+            Style::default().fg(theme().white)
         };
-        // delay_ms * 34.3 cm/ms = distance en cm  (vitesse du son ≈ 343 m/s)
-        let dist_cm = state.delay_ms.abs() * 34.3;
-        let sev = if state.delay_ms.abs() > 0.5 { RED } else { YELLOW };
-        guides.push(Line::from(vec![
-            Span::styled(format!("  {} ", icon), Style::default().fg(sev).add_modifier(Modifier::BOLD)),
-            Span::styled(action.to_string(), Style::default().fg(WHITE)),
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:>2}. {:<5}", rank + 1, p.label), style),
+            Span::styled(format!("{:>6.0}", composite), style),
+            Span::styled(format!("{:>7}", p.score), Style::default().fg(score_color(p.score))),
+            Span::styled(format!("{:>9} dB", format_num(p.bass_sd_db, 1)), Style::default().fg(theme().gray)),
+            Span::styled(format!("{:>10}", reflection), Style::default().fg(theme().gray)),
+            Span::styled(format!("   {}", p.time), Style::default().fg(theme().gray)),
         ]));
-        let dist_label = if dist_cm < 1.0 {
-            format!("    Δ distance ≈ {:.1} mm", dist_cm * 10.0)
-        } else {
-            format!("    Δ distance ≈ {:.1} cm", dist_cm)
-        };
-        guides.push(Line::from(Span::styled(
-            dist_label,
-            Style::default().fg(GRAY),
-        )));
     }
 
-    if state.level_diff_db.abs() > 0.5 {
-        let icon = if state.level_diff_db > 0.0 { "🔉" } else { "🔊" };
-        let action = if state.level_diff_db > 0.0 {
-            "Son droit trop fort — éloigner ou désaxer"
-        } else {
-            "Son droit trop faible — rapprocher ou orienter"
-        };
-        let sev = if state.level_diff_db.abs() > 2.0 { RED } else { YELLOW };
-        guides.push(Line::from(vec![
-            Span::styled(format!("  {} ", icon), Style::default().fg(sev)),
-            Span::styled(action.to_string(), Style::default().fg(WHITE)),
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Places d'écoute ──────────────────────────────────────────────────────────
+
+/// Places affichées (les plus anciennes d'abord).
+const SEAT_ROWS: usize = 5;
+
+fn draw_seats(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled((s.seats_title)(state.seats.len()), Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+
+    let mut lines = vec![Line::from(Span::styled(s.seats_header, Style::default().fg(theme().gray)))];
+    for (i, seat) in state.seats.iter().take(SEAT_ROWS).enumerate() {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:>2}. {:<24}", i + 1, seat.name), Style::default().fg(theme().white)),
+            Span::styled(format!("{:>5}", seat.score), Style::default().fg(score_color(seat.score))),
+            Span::styled(format!("{:>8} ms", format_signed(seat.delay_ms, 2)), Style::default().fg(theme().gray)),
+            Span::styled(format!("{:>7} dB", format_signed(seat.level_diff_db, 1)), Style::default().fg(theme().gray)),
         ]));
-        guides.push(Line::from(Span::styled(
-            format!("    Δ niveau = {:.1} dB", state.level_diff_db.abs()),
-            Style::default().fg(GRAY),
-        )));
     }
 
-    if state.freq_tilt.abs() > 1.0 {
-        let icon = if state.freq_tilt > 0.0 { "◑" } else { "◐" };
-        let action = if state.freq_tilt > 0.0 {
-            "Trop d'aigus à droite — désaxer (toe-out)"
+    // Bandes de variance : ce qui change d'une place à l'autre
+    if let Some(stats) = seats::stats(&state.seats) {
+        let mut spans = vec![Span::styled(s.seats_spread, Style::default().fg(theme().gray))];
+        for (k, sd) in stats.region_sd_db.iter().enumerate() {
+            let color = if *sd > seats::REGION_LIMIT_DB { theme().yellow } else { theme().gray };
+            spans.push(Span::styled(format!("{} ±{}  ", s.region_names[k], format_num(*sd, 1)), Style::default().fg(color)));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    if let Some(input) = &state.seat_input {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  ✎ {}", s.seat_prompt), Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", input), Style::default().fg(theme().white)),
+            Span::styled(s.experiment_hint, Style::default().fg(theme().gray)),
+        ]));
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Environnement ────────────────────────────────────────────────────────────
+
+fn draw_environment(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled((s.environment_title)(state.history.len()), Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+
+    let ambient = &state.ambient;
+    let mut current = if ambient.is_empty() {
+        s.environment_none.to_string()
+    } else {
+        format!(
+            "{} · {} · c = {} m/s",
+            ambient.temperature_c.map_or("—".into(), |t| format!("{} °C", format_num(t, 1))),
+            ambient.humidity_pct.map_or("—".into(), |h| format!("{:.0} %", h)),
+            format_num(ambient.speed_of_sound(), 1)
+        )
+    };
+    if let Some(d) = state.room_dimensions {
+        current += &(s.environment_room)(d.length_m, d.width_m, d.height_m);
+    }
+    let mut lines = vec![Line::from(vec![
+        Span::styled(s.environment_current, Style::default().fg(theme().gray)),
+        Span::styled(current, Style::default().fg(theme().white)),
+    ])];
+
+    // Une ligne par grandeur : r, pente et plage, ou ce qui manque
+    for (k, factor) in Factor::ALL.into_iter().enumerate() {
+        let mut spans = vec![Span::styled(format!("  {:<14}", s.environment_factors[k]), Style::default().fg(theme().white))];
+        match environment::correlate(&state.history, factor) {
+            Some(c) => {
+                let color = if c.is_strong() { theme().yellow } else { theme().gray };
+                spans.push(Span::styled(format!("r {:>5}", format_signed(c.r, 2)), Style::default().fg(color).add_modifier(Modifier::BOLD)));
+                spans.push(Span::styled(
+                    format!(
+                        "  {} pts/{}  ({}…{} {}, n={})",
+                        format_signed(c.slope, 1),
+                        factor.unit(),
+                        format_num(c.min, 1),
+                        format_num(c.max, 1),
+                        factor.unit(),
+                        c.points
+                    ),
+                    Style::default().fg(theme().gray),
+                ));
+                let verdict = if c.is_strong() { s.environment_linked } else { s.environment_unlinked };
+                spans.push(Span::styled(verdict, Style::default().fg(color)));
+            }
+            None => spans.push(Span::styled(
+                (s.environment_missing)(environment::count(&state.history, factor), environment::MIN_POINTS),
+                Style::default().fg(theme().gray),
+            )),
+        }
+        lines.push(Line::from(spans));
+    }
+
+    if let Some(input) = &state.ambient_input {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  ✎ {}", s.environment_prompt), Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", input), Style::default().fg(theme().white)),
+            Span::styled(s.experiment_hint, Style::default().fg(theme().gray)),
+        ]));
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Notes ────────────────────────────────────────────────────────────────────
+
+/// Notes affichées : la saisie en cours, sinon celles de la dernière analyse
+/// (ou de la prochaine, avant toute analyse) ; heure de l'analyse visée.
+fn shown_notes(state: &AppState) -> Option<(Option<&str>, &MeasurementNotes)> {
+    let time = |entry: Option<usize>| entry.and_then(|i| state.history.get(i)).map(|h| h.time.as_str());
+    if let Some(editor) = &state.notes_input {
+        return Some((time(editor.entry), &editor.notes));
+    }
+    let notes = state.current_notes();
+    (!notes.is_empty()).then_some((state.history.last().map(|h| h.time.as_str()), notes))
+}
+
+fn draw_notes(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let Some((time, notes)) = shown_notes(state) else { return };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled((s.notes_title)(time), Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+
+    let editing = state.notes_input.as_ref().map(|e| e.field);
+    let mut lines = Vec::new();
+    for field in NoteField::ALL {
+        let value = notes.get(field);
+        if editing.is_none() && value.trim().is_empty() {
+            continue;
+        }
+        let current = editing == Some(field);
+        let label_style = if current {
+            Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)
         } else {
-            "Manque d'aigus à droite — orienter (toe-in)"
+            Style::default().fg(theme().gray)
         };
-        let sev = if state.freq_tilt.abs() > 3.0 { RED } else { YELLOW };
-        guides.push(Line::from(vec![
-            Span::styled(format!("  {} ", icon), Style::default().fg(sev)),
-            Span::styled(action.to_string(), Style::default().fg(WHITE)),
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} {:<10}", if current { "✎" } else { " " }, field.label()), label_style),
+            Span::styled(format!("{}{}", value, if current { "█" } else { "" }), Style::default().fg(theme().white)),
         ]));
     }
+    if editing.is_some() {
+        lines.push(Line::from(Span::styled(s.notes_hint, Style::default().fg(theme().gray))));
+    }
 
-    if guides.is_empty() {
-        guides.push(Line::from(""));
-        guides.push(Line::from(Span::styled(
-            "  ✓ Placement optimal atteint !",
-            Style::default().fg(GREEN).add_modifier(Modifier::BOLD),
-        )));
-        guides.push(Line::from(Span::styled(
-            "  Les deux enceintes sont symétriquement alignées.",
-            Style::default().fg(GRAY),
-        )));
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Asymétrie acceptée ───────────────────────────────────────────────────────
+
+/// Écarts de la dernière analyse au-delà de l'asymétrie acceptée.
+fn baseline_deviations(state: &AppState) -> Vec<Deviation> {
+    match (&state.baseline, &state.diff_db) {
+        (Some(b), Some(diff)) => b.deviations(state.delay_ms, state.level_diff_db, diff),
+        _ => Vec::new(),
+    }
+}
+
+fn baseline_rows(state: &AppState) -> usize {
+    let panel = match &state.baseline {
+        // Raison, correction, conformité, puis un écart par ligne
+        Some(b) => 3 + if b.checks.is_empty() { 0 } else { baseline_deviations(state).len().max(1) },
+        None => 0,
+    };
+    panel + state.baseline_input.is_some() as usize
+}
+
+fn draw_baseline(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.baseline_title, Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+
+    let mut lines = Vec::new();
+    if let Some(b) = &state.baseline {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  « {} »", b.reason), Style::default().fg(theme().white).add_modifier(Modifier::BOLD)),
+            Span::styled((s.baseline_accepted)(b.score, &b.time), Style::default().fg(theme().gray)),
+        ]));
+
+        // Correction électrique du reste accepté
+        let correction = b.correction(&state.gain, state.sample_rate);
+        let mut parts: Vec<String> = Vec::new();
+        if let Some((right, ms)) = correction.delay {
+            parts.push((s.baseline_delay)(if right { s.right_word } else { s.left_word }, ms));
+        }
+        if let Some(adj) = correction.gain {
+            let (louder, quieter) =
+                if adj.right_louder { (s.right_word, s.left_word) } else { (s.left_word, s.right_word) };
+            parts.push(match adj.control {
+                GainControl::Trim => (s.reco_gain_trim)(louder, adj.steps, adj.applied_db()),
+                GainControl::Balance => (s.reco_gain_balance)(quieter, adj.steps, adj.applied_db()),
+                GainControl::Detents => (s.reco_gain_detents)(louder, adj.steps, adj.applied_db()),
+            });
+        }
+        if !correction.eq.is_empty() {
+            parts.push((s.baseline_eq)(correction.eq.len()));
+        }
+        let text = if parts.is_empty() { s.baseline_none.to_string() } else { parts.join(" · ") };
+        lines.push(Line::from(vec![
+            Span::styled(s.baseline_correction, Style::default().fg(theme().gray)),
+            Span::styled(text, Style::default().fg(theme().cyan)),
+        ]));
+
+        // Suivi des analyses faites depuis l'acceptation
+        let compliant = b.checks.iter().filter(|c| c.compliant).count();
+        lines.push(Line::from(Span::styled((s.baseline_checks)(compliant, b.checks.len()), Style::default().fg(theme().gray))));
+        if !b.checks.is_empty() {
+            let deviations = baseline_deviations(state);
+            if deviations.is_empty() {
+                lines.push(Line::from(Span::styled(s.baseline_compliant, Style::default().fg(theme().green))));
+            }
+            for d in deviations {
+                let metric = match d.metric {
+                    Metric::Delay => s.seat_delay,
+                    Metric::Level => s.seat_level,
+                    Metric::Region(k) => s.region_names[k],
+                };
+                lines.push(Line::from(Span::styled(
+                    (s.baseline_deviation)(metric, d.accepted, d.current, if d.metric == Metric::Delay { "ms" } else { "dB" }),
+                    Style::default().fg(theme().red),
+                )));
+            }
+        }
+    }
+
+    if let Some(input) = &state.baseline_input {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  ✎ {}", s.baseline_prompt), Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", input), Style::default().fg(theme().white)),
+            Span::styled(s.experiment_hint, Style::default().fg(theme().gray)),
+        ]));
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+// ─── Journal des changements ─────────────────────────────────────────────────
+
+/// Changements affichés (les plus récents).
+const EXPERIMENT_ROWS: usize = 3;
+
+fn draw_experiments(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(s.experiments_title, Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
+
+    let entries = &state.experiments.entries;
+    let mut lines: Vec<Line> = entries
+        .iter()
+        .enumerate()
+        .skip(entries.len().saturating_sub(EXPERIMENT_ROWS))
+        .map(|(i, e)| {
+            let mut spans = vec![Span::styled(format!("  {}. {}  ", i + 1, e.description), Style::default().fg(theme().white))];
+            match (e.effect(), &e.after) {
+                (Some(effect), Some(after)) => {
+                    let col = match effect.score_delta {
+                        d if d > 0 => theme().green,
+                        d if d < 0 => theme().red,
+                        _ => theme().gray,
+                    };
+                    spans.push(Span::styled(
+                        format!("{} → {} pts ({:+})", e.before.score, after.score, effect.score_delta),
+                        Style::default().fg(col).add_modifier(Modifier::BOLD),
+                    ));
+                    spans.push(Span::styled(
+                        format!("  |Δt| {} ms  |ΔL| {} dB", format_signed(effect.delay_delta_ms, 2), format_signed(effect.level_delta_db, 1)),
+                        Style::default().fg(theme().gray),
+                    ));
+                }
+                _ => spans.push(Span::styled(s.experiment_pending, Style::default().fg(theme().yellow))),
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    if let Some(input) = &state.experiment_input {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  ✎ {}", s.experiment_prompt), Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", input), Style::default().fg(theme().white)),
+            Span::styled(s.experiment_hint, Style::default().fg(theme().gray)),
+        ]));
     }
 
-    f.render_widget(Paragraph::new(guides).block(block).wrap(Wrap { trim: true }), area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Entrées affichées par `draw_history`.
+const HISTORY_ROWS: usize = 4;
+
+/// Tendance [`] : largeur de l'étiquette et de la dernière valeur.
+const TREND_LABEL: u16 = 6;
+const TREND_VALUE: u16 = 10;
+
+/// Huit hauteurs de barre, de la plus basse à la plus haute.
+const TREND_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Zone des barres de la tendance : entre les étiquettes et les valeurs,
+/// sur les trois lignes.
+fn trend_plot_area(area: Rect) -> Rect {
+    let inner = area.inner(Margin::new(1, 1));
+    let width = inner.width.saturating_sub(TREND_LABEL + TREND_VALUE);
+    Rect::new(inner.x + TREND_LABEL, inner.y, width, inner.height.min(3))
+}
+
+/// Analyses de chaque colonne : une par analyse, ou des groupes
+/// consécutifs quand l'historique dépasse la largeur.
+fn trend_buckets(len: usize, width: usize) -> Vec<std::ops::Range<usize>> {
+    let columns = len.min(width);
+    (0..columns).map(|c| c * len / columns..(c + 1) * len / columns).collect()
+}
+
+/// Moyenne de chaque groupe d'analyses.
+fn bucket_means(values: &[f32], buckets: &[std::ops::Range<usize>]) -> Vec<f32> {
+    buckets.iter().map(|b| values[b.clone()].iter().sum::<f32>() / b.len() as f32).collect()
+}
+
+/// Une ligne de la tendance : barres entre `lo` et `hi`, puis la dernière
+/// valeur.
+fn trend_line(label: &str, means: &[f32], (lo, hi): (f32, f32), color: impl Fn(f32) -> Color, last: String) -> Line<'static> {
+    let mut spans = vec![Span::styled(format!("{:<w$}", label, w = TREND_LABEL as usize), Style::default().fg(theme().gray))];
+    spans.extend(means.iter().map(|&v| {
+        let level = if hi > lo { ((v - lo) / (hi - lo) * 7.0).round() as usize } else { 3 };
+        Span::styled(TREND_BARS[level.min(7)].to_string(), Style::default().fg(color(v)))
+    }));
+    spans.push(Span::styled(format!("{:>w$}", last, w = TREND_VALUE as usize), Style::default().fg(theme().white)));
+    Line::from(spans)
+}
+
+/// Score, |Δt| et |ΔL| de toutes les analyses, la plus récente à droite :
+/// la convergence des essais se lit d'un coup d'œil (score qui monte,
+/// écarts qui descendent). Les écarts partent de zéro, le score de son
+/// minimum.
+fn history_trend_lines(area: Rect, history: &[HistoryEntry]) -> Vec<Line<'static>> {
+    let Some(last) = history.last() else { return Vec::new() };
+    let buckets = trend_buckets(history.len(), trend_plot_area(area).width as usize);
+    let scores = bucket_means(&history.iter().map(|h| h.score as f32).collect::<Vec<_>>(), &buckets);
+    let delays = bucket_means(&history.iter().map(|h| h.delay_ms.abs()).collect::<Vec<_>>(), &buckets);
+    let levels = bucket_means(&history.iter().map(|h| h.level_diff_db.abs()).collect::<Vec<_>>(), &buckets);
+    let max = |v: &[f32]| v.iter().copied().fold(0.0, f32::max);
+    let min = |v: &[f32]| v.iter().copied().fold(f32::INFINITY, f32::min);
+    vec![
+        trend_line("Score", &scores, (min(&scores), max(&scores)), |v| score_color(v.round() as u32), format!("{} pts", last.score)),
+        trend_line("|Δt|", &delays, (0.0, max(&delays)), |_| theme().cyan, format!("{} ms", format_signed(last.delay_ms, 1))),
+        trend_line("|ΔL|", &levels, (0.0, max(&levels)), |_| theme().purple, format!("{} dB", format_signed(last.level_diff_db, 1))),
+    ]
 }
 
 fn draw_history(f: &mut Frame, area: Rect, state: &AppState) {
+    let s = tr();
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(Span::styled(" Historique ", Style::default().fg(GRAY)))
-        .border_style(Style::default().fg(Color::Rgb(35, 35, 55)));
+        .title(Span::styled(if state.show_trend { s.history_trend_title } else { s.history_title }, Style::default().fg(theme().gray)))
+        .border_style(Style::default().fg(theme().border));
 
     if state.history.is_empty() {
-        let para = Paragraph::new(Span::styled("  Aucune mesure", Style::default().fg(GRAY)))
+        let para = Paragraph::new(Span::styled(s.history_empty, Style::default().fg(theme().gray)))
             .block(block);
         f.render_widget(para, area);
         return;
     }
 
+    if state.show_trend {
+        f.render_widget(Paragraph::new(history_trend_lines(area, &state.history)).block(block), area);
+        return;
+    }
+
     let items: Vec<ListItem> = state
         .history
         .iter()
         .enumerate()
         .rev()
-        .take(4)
+        .take(HISTORY_ROWS)
         .map(|(i, h)| {
             let col = score_color(h.score);
             let is_last = i == state.history.len() - 1;
@@ -652,9 +3141,13 @@ fn draw_history(f: &mut Frame, area: Rect, state: &AppState) {
                     Style::default().fg(col).add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!(" pts  Δt={:.1}ms  ΔL={:.1}dB  {}{}",
-                        h.delay_ms, h.level_diff_db, h.time, trend),
-                    Style::default().fg(if is_last { WHITE } else { GRAY }),
+                    format!(" pts  Δt={}ms  ΔL={}dB  {}{}",
+                        format_num(h.delay_ms, 1), format_num(h.level_diff_db, 1), h.time, trend),
+                    Style::default().fg(if is_last { theme().white } else { theme().gray }),
+                ),
+                Span::styled(
+                    if h.notes.text.is_empty() { String::new() } else { format!("  ✎ {}", h.notes.text) },
+                    Style::default().fg(theme().gray),
                 ),
             ]))
         })
@@ -666,23 +3159,14 @@ fn draw_history(f: &mut Frame, area: Rect, state: &AppState) {
 
 // ─── Aide clavier ─────────────────────────────────────────────────────────────
 
-fn draw_help(f: &mut Frame, area: Rect, state: &AppState) {
-    let items: Vec<(&str, &str)> = vec![
-        ("[L]", "Capturer gauche"),
-        ("[R]", "Capturer droite"),
-        ("[A]", "Analyser"),
-        ("[+/-]", "Délai pré-capture"),
-        ("[X]", "Réinitialiser"),
-        ("[Q]", "Quitter"),
-    ];
-
-    let spans: Vec<Span> = items
+fn draw_help(f: &mut Frame, area: Rect, entries: &[(String, &str)]) {
+    let spans: Vec<Span> = entries
         .iter()
         .flat_map(|(key, desc)| {
             vec![
-                Span::styled(format!(" {} ", key), Style::default().fg(CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("{} ", desc), Style::default().fg(GRAY)),
-                Span::styled(" │ ", Style::default().fg(Color::Rgb(40, 40, 55))),
+                Span::styled(format!(" {} ", key), Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{} ", desc), Style::default().fg(theme().gray)),
+                Span::styled(" │ ", Style::default().fg(theme().border)),
             ]
         })
         .collect();
@@ -690,7 +3174,7 @@ fn draw_help(f: &mut Frame, area: Rect, state: &AppState) {
     let line = Line::from(spans);
     let block = Block::default()
         .borders(Borders::TOP)
-        .border_style(Style::default().fg(Color::Rgb(35, 35, 50)));
+        .border_style(Style::default().fg(theme().border));
 
     f.render_widget(Paragraph::new(line).block(block), area);
 }
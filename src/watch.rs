@@ -0,0 +1,92 @@
+// ============================================================
+//  watch.rs — Suivi en direct (captures G / D alternées)
+//
+//  Pour déplacer une enceinte en regardant l'écart converger :
+//  des sweeps courts sont joués en boucle, une fois à gauche,
+//  une fois à droite, et chaque nouvelle capture est comparée à
+//  la dernière de l'autre côté. Le délai et l'écart de niveau
+//  sont donc rafraîchis à chaque capture, sans passer par
+//  l'analyse complète ni l'historique.
+// ============================================================
+
+use crate::{
+    audio::Channel,
+    dsp::{self, SweepParams},
+    measurement::Measurement,
+    multichannel,
+    pipeline::AnalysisOptions,
+    scoring::ScoreWeights,
+};
+
+/// Durée du sweep de suivi (s) : assez court pour un rafraîchissement
+/// toutes les quelques secondes, assez long pour une IR exploitable.
+pub const WATCH_SWEEP_SECS: f32 = 1.0;
+
+/// Nombre de lectures gardées pour la tendance.
+pub const HISTORY_LEN: usize = 12;
+
+/// Écart de distance sous lequel les enceintes sont jugées alignées (cm).
+pub const ALIGNED_CM: f32 = 1.0;
+
+/// Une comparaison G / D du suivi.
+#[derive(Debug, Clone, Copy)]
+pub struct WatchReading {
+    /// Retard de la droite sur la gauche (ms), comme `AppState::delay_ms`.
+    pub delay_ms: f32,
+    pub level_diff_db: f32,
+}
+
+impl WatchReading {
+    /// Écart de distance correspondant (cm, positif = droite plus loin).
+    pub fn distance_cm(&self) -> f32 {
+        self.delay_ms / 1000.0 * dsp::speed_of_sound() * 100.0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WatchState {
+    /// Dernière capture de chaque côté.
+    pub left: Option<Measurement>,
+    pub right: Option<Measurement>,
+    /// Enceinte de la prochaine capture.
+    pub next: Channel,
+    /// Lectures successives, la plus récente en dernier.
+    pub readings: Vec<WatchReading>,
+}
+
+impl Default for WatchState {
+    fn default() -> Self {
+        WatchState { left: None, right: None, next: Channel::LEFT, readings: Vec::new() }
+    }
+}
+
+impl WatchState {
+    /// Range la capture, passe à l'autre enceinte et, si les deux côtés sont
+    /// connus, ajoute une lecture. `m` doit avoir son IR analysée.
+    pub fn record(&mut self, m: Measurement, options: &AnalysisOptions) -> Option<WatchReading> {
+        if m.channel == Channel::LEFT {
+            self.left = Some(m);
+            self.next = Channel::RIGHT;
+        } else {
+            self.right = Some(m);
+            self.next = Channel::LEFT;
+        }
+        let (left, right) = (self.left.as_ref()?, self.right.as_ref()?);
+        let pair = multichannel::compare(left, right, options, None, None, &ScoreWeights::default());
+        let reading = WatchReading { delay_ms: pair.delay_ms, level_diff_db: pair.level_diff_db };
+        self.readings.push(reading);
+        if self.readings.len() > HISTORY_LEN {
+            self.readings.remove(0);
+        }
+        Some(reading)
+    }
+
+    pub fn latest(&self) -> Option<&WatchReading> {
+        self.readings.last()
+    }
+}
+
+/// Sweep de suivi : bande et amplitude de `base`, durée courte, sans moyenne.
+pub fn sweep(base: &SweepParams) -> SweepParams {
+    SweepParams { duration_s: WATCH_SWEEP_SECS, averages: 1, ..*base }.clamped()
+}
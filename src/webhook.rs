@@ -0,0 +1,79 @@
+// ============================================================
+//  webhook.rs — Notification HTTP en fin de mesure
+//
+//  En mode headless et en surveillance, le document JSON produit
+//  (rapport d'analyse, ligne d'historique) est envoyé par POST à
+//  l'URL configurée : Home Assistant, Node-RED, n8n ou un webhook
+//  Slack / Discord reçoivent la mesure sans script intermédiaire.
+//  Trois champs sont ajoutés au document :
+//    event   — « measurement » ou « alert » ;
+//    text    — résumé d'une ligne (lu par Slack, Mattermost) ;
+//    content — le même résumé (lu par Discord).
+//  Un échec d'envoi est signalé sur stderr sans interrompre la
+//  mesure : le webhook est une commodité, pas un prérequis.
+// ============================================================
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::time::Duration;
+
+use crate::strings::tr;
+
+/// Délai maximal d'un envoi (connexion, TLS, réponse).
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Section `[webhook]` du fichier de configuration (`--webhook` la remplace).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookParams {
+    /// URL http(s) recevant le POST ; absente = pas de notification.
+    pub url: Option<String>,
+}
+
+impl WebhookParams {
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.url {
+            Some(url) if !url.starts_with("http://") && !url.starts_with("https://") => {
+                Err((tr().webhook_url)(url))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Type de notification (champ `event`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Measurement,
+    Alert,
+}
+
+impl Event {
+    fn name(self) -> &'static str {
+        match self {
+            Event::Measurement => "measurement",
+            Event::Alert => "alert",
+        }
+    }
+}
+
+/// Envoie `document` (objet JSON) complété de `event`, `text` et `content`.
+pub fn post(url: &str, event: Event, summary: &str, document: &impl Serialize) -> Result<()> {
+    let mut body = serde_json::to_value(document)?;
+    let object = body.as_object_mut().ok_or_else(|| anyhow!(tr().webhook_not_object))?;
+    object.insert("event".into(), json!(event.name()));
+    object.insert("text".into(), Value::String(summary.into()));
+    object.insert("content".into(), Value::String(summary.into()));
+
+    let agent: ureq::Agent = ureq::Agent::config_builder().timeout_global(Some(TIMEOUT)).build().into();
+    agent.post(url).send_json(&body)?;
+    Ok(())
+}
+
+/// Comme `post`, erreur signalée sur stderr (la mesure, elle, a abouti).
+pub fn notify(url: &str, event: Event, summary: &str, document: &impl Serialize) {
+    if let Err(e) = post(url, event, summary, document) {
+        eprintln!("{}", (tr().webhook_not_sent)(url, &format!("{:#}", e)));
+    }
+}
@@ -0,0 +1,38 @@
+// ============================================================
+//  common — Aides partagées par les tests d'intégration
+//
+//  La langue (`strings::set_lang`) est globale au processus et
+//  les tests d'un même fichier tournent en parallèle : un test
+//  qui en change la prend d'abord (`lock_lang`), jusqu'à la fin,
+//  et la langue d'avant revient quand il la rend.
+// ============================================================
+
+use speaker_align::strings::{self, Lang};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+static LANG_LOCK: Mutex<()> = Mutex::new(());
+
+/// Langue réservée au test en cours ; rétablie à la libération.
+pub struct LangLock {
+    previous: Lang,
+    _held: MutexGuard<'static, ()>,
+}
+
+impl LangLock {
+    pub fn set(&self, lang: Lang) {
+        strings::set_lang(lang);
+    }
+}
+
+impl Drop for LangLock {
+    fn drop(&mut self) {
+        // Verrou encore tenu : les champs ne sont libérés qu'après
+        strings::set_lang(self.previous);
+    }
+}
+
+/// Attend que la langue soit libre (un test en échec ne la bloque pas).
+pub fn lock_lang() -> LangLock {
+    let held = LANG_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    LangLock { previous: strings::lang(), _held: held }
+}
@@ -0,0 +1,46 @@
+// ============================================================
+//  Distances rapides : chirp retardé d'un nombre connu
+//  d'échantillons, distances et retard D − G retrouvés
+// ============================================================
+
+use speaker_align::{
+    audio::Capture,
+    distance::{self, DistanceReport},
+    dsp,
+};
+
+const RATE: u32 = 48_000;
+const PRE_DELAY: usize = 4_800;
+
+/// Capture du chirp arrivé `delay` échantillons après la pause.
+fn capture(chirp: &[f32], delay: usize) -> Capture {
+    let mut mono = vec![0.0; PRE_DELAY + delay];
+    mono.extend(chirp.iter().map(|s| 0.5 * s));
+    mono.resize(mono.len() + RATE as usize / 5, 0.0);
+    Capture { mono, inputs: Vec::new(), loopback: None }
+}
+
+fn distance_of(chirp: &[f32], delay: usize) -> f32 {
+    let (m, absolute) = distance::analyze(&capture(chirp, delay), chirp, RATE, PRE_DELAY, dsp::ONSET_THRESHOLD_DB)
+        .expect("front d'onde trouvé");
+    assert!(!absolute, "pas de piste de boucle");
+    m
+}
+
+#[test]
+fn delayed_chirp_gives_distance_and_delay() {
+    let chirp = distance::generate_test_signal(RATE);
+    let metres = |samples: usize| samples as f32 / RATE as f32 * dsp::speed_of_sound();
+
+    // 5 ms et 6 ms : environ 1,72 m et 2,06 m
+    let (left_delay, right_delay) = (240, 288);
+    let left = distance_of(&chirp, left_delay);
+    let right = distance_of(&chirp, right_delay);
+    assert!((left - metres(left_delay)).abs() < 0.01, "gauche {left} m");
+    assert!((right - metres(right_delay)).abs() < 0.01, "droite {right} m");
+
+    let report = DistanceReport { left: Some(left), right: Some(right), absolute: false };
+    let delay_ms = report.delay_ms().unwrap();
+    assert!((delay_ms - 1.0).abs() < 0.02, "retard D − G {delay_ms} ms");
+    assert!((report.distance_cm().unwrap() - metres(48) * 100.0).abs() < 1.0);
+}
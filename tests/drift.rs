@@ -0,0 +1,84 @@
+// ============================================================
+//  Dérive d'horloge : sweep étiré d'un rapport connu, retrouvé
+//  avec et sans boucle, IR recalée après correction
+// ============================================================
+
+use speaker_align::{
+    audio::Capture,
+    dsp::{self, SweepParams},
+    drift::{self, MIN_CORRECTION_SAMPLES},
+    resample,
+};
+
+const RATE: u32 = 48_000;
+/// Pause avant le sweep (2 s, la dérive s'y accumule déjà) et trajet
+/// acoustique (échantillons).
+const LEAD: usize = 96_000;
+const TRAVEL: usize = 144;
+
+fn sweep_params() -> SweepParams {
+    SweepParams { duration_s: 4.0, ..SweepParams::default() }
+}
+
+/// Ce que capteraient la boucle (signal seul) et le micro (retardé, grave
+/// coupé) si l'horloge d'entrée avançait de `ppm`.
+fn drifted_capture(ppm: f32, loopback: bool) -> Capture {
+    let sweep = dsp::generate_sweep(RATE, &sweep_params());
+    let at = |delay: usize| {
+        let mut track = vec![0.0f32; delay];
+        track.extend_from_slice(&sweep);
+        track.resize(LEAD + sweep.len() + RATE as usize / 2, 0.0);
+        track
+    };
+    let mic = dsp::highpass_filter(&at(LEAD + TRAVEL), 80.0, RATE);
+    let tracks = resample::stretch_tracks(&[mic, at(LEAD)], 1.0 + ppm as f64 * 1e-6).expect("étirement");
+    let [mono, reference]: [Vec<f32>; 2] = tracks.try_into().unwrap();
+    Capture { mono, inputs: Vec::new(), loopback: loopback.then_some(reference) }
+}
+
+/// Pic de l'IR (sub-sample).
+fn ir_peak(capture: &Capture) -> f32 {
+    let sweep = dsp::generate_sweep(RATE, &sweep_params());
+    let ir: Vec<f32> = dsp::compute_impulse_response(&capture.mono, &sweep, &sweep_params(), RATE).iter().map(|v| v.abs()).collect();
+    let best = (1..ir.len() - 1).max_by(|&a, &b| ir[a].total_cmp(&ir[b])).unwrap();
+    let (a, b, c) = (ir[best - 1], ir[best], ir[best + 1]);
+    best as f32 + 0.5 * (a - c) / (a - 2.0 * b + c)
+}
+
+#[test]
+fn drift_is_estimated_with_and_without_loopback() {
+    let sweep = dsp::generate_sweep(RATE, &sweep_params());
+    for ppm in [50.0f32, -50.0] {
+        let capture = drifted_capture(ppm, true);
+        let acoustic = drift::estimate_ppm(&capture.mono, &sweep, &sweep_params(), RATE, false).expect("sans boucle");
+        let electrical = drift::estimate_ppm(capture.loopback.as_ref().unwrap(), &sweep, &sweep_params(), RATE, true).expect("boucle");
+        assert!((acoustic - ppm).abs() < 3.0, "{} ppm estimé sans boucle pour {}", acoustic, ppm);
+        assert!((electrical - ppm).abs() < 2.0, "{} ppm estimé sur la boucle pour {}", electrical, ppm);
+    }
+}
+
+#[test]
+fn corrected_capture_puts_the_ir_peak_back() {
+    let reference = ir_peak(&drifted_capture(0.0, false));
+    for loopback in [false, true] {
+        let capture = drifted_capture(50.0, loopback);
+        // 50 ppm : le pic arrive ~5 échantillons trop tard
+        let drifted = ir_peak(&capture);
+        assert!(drifted - reference > 3.0, "{} / {}", drifted, reference);
+        let (corrected, ppm) = drift::correct(capture, &dsp::generate_sweep(RATE, &sweep_params()), &sweep_params(), RATE).unwrap();
+        let ppm = ppm.expect("dérive corrigée");
+        assert!((ppm - 50.0).abs() < 3.0, "{} ppm (boucle : {})", ppm, loopback);
+        assert!((ir_peak(&corrected) - reference).abs() < 0.5, "pic {} au lieu de {} (étiré : {})", ir_peak(&corrected), reference, drifted);
+    }
+}
+
+#[test]
+fn negligible_drift_is_left_alone() {
+    // 1 ppm : moins d'un échantillon d'écart sur la capture
+    let capture = drifted_capture(1.0, true);
+    assert!(1e-6 * (capture.mono.len() as f32) < MIN_CORRECTION_SAMPLES);
+    let original = capture.mono.clone();
+    let (capture, ppm) = drift::correct(capture, &dsp::generate_sweep(RATE, &sweep_params()), &sweep_params(), RATE).unwrap();
+    assert_eq!(ppm, None);
+    assert_eq!(capture.mono, original);
+}
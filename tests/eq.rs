@@ -0,0 +1,68 @@
+// ============================================================
+//  Égalisation à la fréquence de la session : écart G/D d'une
+//  capture 44,1 kHz ramenée à 48 kHz, filtres calculés au taux
+//  des mesures
+// ============================================================
+
+use speaker_align::{
+    dsp::{self, Window, NUM_BANDS},
+    eq::{self, PeakingFilter},
+    resample,
+};
+
+/// Niveaux (dB) des `NUM_BANDS` bandes d'une capture, lissées au 1/6
+/// d'octave comme à l'analyse.
+fn bands_db(samples: &[f32], sample_rate: u32) -> Vec<f32> {
+    let spectrum = dsp::smooth_spectrum(&dsp::compute_fft(samples, Window::Hann), 6);
+    dsp::bands_to_db(&dsp::spectrum_to_bands(&spectrum, sample_rate, NUM_BANDS))
+}
+
+/// Écart D − G par bande.
+fn diff_db(left: &[f32], right: &[f32], sample_rate: u32) -> Vec<f32> {
+    bands_db(right, sample_rate).iter().zip(bands_db(left, sample_rate)).map(|(r, l)| r - l).collect()
+}
+
+fn in_fit_range(i: usize) -> bool {
+    (40.0..=16_000.0).contains(&dsp::band_center_freq(i, NUM_BANDS))
+}
+
+#[test]
+fn resampling_44k1_to_48k_keeps_the_band_gap_and_its_filters() {
+    // Droite = gauche + bosse de +6 dB vers 12 kHz, capturées à 44,1 kHz
+    let left = dsp::generate_white_noise(44_100, 20.0);
+    let bump = dsp::bandpass_biquad(&left, 12_000.0, 1.4, 44_100);
+    let right: Vec<f32> = left.iter().zip(&bump).map(|(l, b)| l + b).collect();
+    let native = diff_db(&left, &right, 44_100);
+
+    let convert = |x: &[f32]| resample::resample(x, 44_100, 48_000).expect("conversion");
+    let converted = diff_db(&convert(&left), &convert(&right), 48_000);
+    for (i, (a, b)) in converted.iter().zip(&native).enumerate() {
+        if in_fit_range(i) {
+            assert!((a - b).abs() < 0.25, "{} Hz : {} dB au lieu de {}", dsp::band_center_freq(i, NUM_BANDS), a, b);
+        }
+    }
+
+    // Mêmes filtres, chacun calculé au taux de ses mesures
+    let (at_44k1, at_48k) = (eq::fit(&native, 44_100), eq::fit(&converted, 48_000));
+    assert!(!at_44k1.is_empty());
+    assert_eq!(at_44k1.len(), at_48k.len(), "{:?} / {:?}", at_44k1, at_48k);
+    for (a, b) in at_44k1.iter().zip(&at_48k) {
+        assert!((a.freq_hz / b.freq_hz).log2().abs() < 0.1, "{:?} / {:?}", a, b);
+        assert!((a.gain_db - b.gain_db).abs() < 0.5, "{:?} / {:?}", a, b);
+    }
+}
+
+#[test]
+fn filters_cancel_the_gap_at_the_measurement_rate() {
+    // Bosse de +6 dB à 12 kHz sur la droite, mesurée à 44,1 kHz
+    let bump = PeakingFilter { freq_hz: 12_000.0, gain_db: 6.0, q: 1.4 };
+    let diff: Vec<f32> = (0..NUM_BANDS).map(|i| bump.response_db(dsp::band_center_freq(i, NUM_BANDS), 44_100)).collect();
+
+    let filters = eq::fit(&diff, 44_100);
+    assert!(!filters.is_empty());
+    for (i, d) in diff.iter().enumerate().filter(|&(i, _)| in_fit_range(i)) {
+        let f = dsp::band_center_freq(i, NUM_BANDS);
+        let residual = d + filters.iter().map(|p| p.response_db(f, 44_100)).sum::<f32>();
+        assert!(residual.abs() < 1.5, "{} Hz : reste {} dB", f, residual);
+    }
+}
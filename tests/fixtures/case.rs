@@ -0,0 +1,219 @@
+// ============================================================
+//  case.rs — Captures de référence (non-régression)
+//
+//  Les signaux synthétiques ne disent pas tout : réverbération,
+//  bruit de fond, micro bon marché, pilote qui décale le flux.
+//  `fixtures/manifest.toml` décrit des paires de captures réelles
+//  (left.wav / right.wav, une par enceinte, micro seul) et les
+//  résultats attendus, à une tolérance près :
+//
+//    [[case]]
+//    name = "salon-canape"
+//    url = "https://exemple.org/fixtures/salon-canape"  # facultatif
+//    pre_delay_secs = 0.5
+//    sweep = { duration_s = 3.0, f_start_hz = 20.0, f_stop_hz = 20000.0,
+//              amplitude = 0.7, averages = 1 }
+//    [case.expect]
+//    delay_ms      = { value = 0.42, tol = 0.05 }
+//    level_diff_db = { value = -1.2, tol = 0.3 }
+//
+//  Les WAV sont rangés sous fixtures/<name>/ : versionnés pour
+//  les cas courts, sinon téléchargés à la demande depuis
+//  `<url>/left.wav` et `<url>/right.wav`. Le signal joué est
+//  régénéré depuis `sweep`, au taux des WAV (les deux du même
+//  taux) ; l'analyse est celle de `headless`.
+//  Le test (main.rs) saute les cas absents, et les télécharge
+//  avec SPEAKER_ALIGN_FETCH_FIXTURES=1.
+// ============================================================
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use speaker_align::{
+    app::AppState,
+    audio::{Capture, Channel},
+    drift,
+    dsp::{self, SweepParams},
+    headless::AnalysisReport,
+    measurement::Measurement,
+};
+
+/// Dossier des cas, relatif à la racine du dépôt.
+pub const FIXTURES_DIR: &str = "fixtures";
+pub const MANIFEST: &str = "manifest.toml";
+
+/// Variable d'environnement qui autorise le téléchargement des cas absents.
+pub const FETCH_ENV: &str = "SPEAKER_ALIGN_FETCH_FIXTURES";
+
+/// Captures d'un cas, une par enceinte.
+pub const CAPTURE_FILES: [(Channel, &str); 2] = [(Channel::LEFT, "left.wav"), (Channel::RIGHT, "right.wav")];
+
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Valeur attendue et écart toléré.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Tolerance {
+    pub value: f32,
+    pub tol: f32,
+}
+
+impl Tolerance {
+    pub fn accepts(&self, measured: f32) -> bool {
+        (measured - self.value).abs() <= self.tol
+    }
+}
+
+/// Résultats attendus ; une grandeur absente n'est pas vérifiée.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Expectation {
+    pub delay_ms: Option<Tolerance>,
+    pub level_diff_db: Option<Tolerance>,
+    pub left_distance_m: Option<Tolerance>,
+    pub right_distance_m: Option<Tolerance>,
+    /// Score minimal.
+    pub min_score: Option<u32>,
+}
+
+impl Expectation {
+    /// Écarts du rapport aux valeurs attendues ; vide = conforme.
+    pub fn check(&self, report: &AnalysisReport) -> Vec<String> {
+        let mut failures = Vec::new();
+        let mut compare = |label: &str, expected: Option<Tolerance>, measured: Option<f32>| {
+            let Some(expected) = expected else { return };
+            match measured {
+                Some(m) if expected.accepts(m) => {}
+                Some(m) => failures.push(format!("{} : {:.3} mesuré, {:.3} ± {:.3} attendu", label, m, expected.value, expected.tol)),
+                None => failures.push(format!("{} : non mesuré, {:.3} ± {:.3} attendu", label, expected.value, expected.tol)),
+            }
+        };
+        compare("delay_ms", self.delay_ms, Some(report.delay_ms));
+        compare("level_diff_db", self.level_diff_db, Some(report.level_diff_db));
+        compare("left_distance_m", self.left_distance_m, report.left_distance_m);
+        compare("right_distance_m", self.right_distance_m, report.right_distance_m);
+        if let Some(min) = self.min_score {
+            match report.score {
+                Some(score) if score >= min => {}
+                score => failures.push(format!("score : {:?}, au moins {} attendu", score, min)),
+            }
+        }
+        failures
+    }
+}
+
+/// Une paire de captures et ce qu'elle doit donner.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Case {
+    pub name: String,
+    /// Adresse du dossier contenant left.wav et right.wav.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Sweep joué lors de la capture.
+    #[serde(default)]
+    pub sweep: SweepParams,
+    /// Pause pré-capture de l'enregistrement.
+    #[serde(default)]
+    pub pre_delay_secs: f32,
+    #[serde(default)]
+    pub expect: Expectation,
+}
+
+impl Case {
+    pub fn dir(&self, root: &Path) -> PathBuf {
+        root.join(&self.name)
+    }
+
+    /// Les deux captures sont sur le disque.
+    pub fn is_present(&self, root: &Path) -> bool {
+        CAPTURE_FILES.iter().all(|(_, file)| self.dir(root).join(file).is_file())
+    }
+
+    /// Télécharge les captures absentes depuis `url`.
+    pub fn fetch(&self, root: &Path) -> Result<()> {
+        let Some(url) = &self.url else {
+            bail!("{} : captures absentes et aucune adresse de téléchargement", self.name);
+        };
+        let dir = self.dir(root);
+        fs::create_dir_all(&dir).with_context(|| format!("Impossible de créer {}", dir.display()))?;
+        let agent: ureq::Agent = ureq::Agent::config_builder().timeout_global(Some(DOWNLOAD_TIMEOUT)).build().into();
+        for (_, file) in CAPTURE_FILES {
+            let path = dir.join(file);
+            if path.is_file() {
+                continue;
+            }
+            let source = format!("{}/{}", url.trim_end_matches('/'), file);
+            let response = agent.get(&source).call().with_context(|| format!("Téléchargement de {}", source))?;
+            // Fichier partiel écarté : un cas n'est présent qu'une fois complet
+            let partial = path.with_extension("part");
+            let mut out = fs::File::create(&partial).with_context(|| format!("Impossible de créer {}", partial.display()))?;
+            std::io::copy(&mut response.into_body().into_reader(), &mut out)
+                .with_context(|| format!("Téléchargement de {}", source))?;
+            fs::rename(&partial, &path)?;
+        }
+        Ok(())
+    }
+
+    /// Analyse la paire de captures comme une mesure G / D.
+    pub fn run(&self, root: &Path) -> Result<AnalysisReport> {
+        let mut state = AppState::new();
+        state.sweep = self.sweep;
+        state.pre_delay_secs = self.pre_delay_secs;
+        let mut signal: Option<(u32, Vec<f32>)> = None;
+        for (channel, file) in CAPTURE_FILES {
+            let path = self.dir(root).join(file);
+            let (mono, rate) = read_wav(&path)?;
+            let signal = match &signal {
+                Some((r, _)) if *r != rate => bail!("{} : {} Hz, {} Hz attendu (taux de l'autre capture)", path.display(), rate, r),
+                Some((_, s)) => s.clone(),
+                None => signal.insert((rate, dsp::generate_sweep(rate, &self.sweep))).1.clone(),
+            };
+            state.sample_rate = rate;
+            let capture = Capture { mono, inputs: Vec::new(), loopback: None };
+            let (capture, clock_drift_ppm) = drift::correct(capture, &signal, &self.sweep, rate)?;
+            let mut m = Measurement::from_capture(channel, capture, signal, self.sweep, rate, None, state.analysis);
+            m.clock_drift_ppm = clock_drift_ppm;
+            match channel {
+                Channel::LEFT => state.left = Some(m),
+                _ => state.right = Some(m),
+            }
+        }
+        state.analyze();
+        Ok(AnalysisReport::from_state(&state))
+    }
+}
+
+/// Contenu de `fixtures/manifest.toml`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "case")]
+    pub cases: Vec<Case>,
+}
+
+impl Manifest {
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(MANIFEST);
+        let text = fs::read_to_string(&path).with_context(|| format!("Impossible de lire {}", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("{} invalide", path.display()))
+    }
+}
+
+/// Première piste d'un WAV (entier ou flottant), en f32 pleine échelle = 1.0,
+/// et son taux d'échantillonnage.
+pub fn read_wav(path: &Path) -> Result<(Vec<f32>, u32)> {
+    let mut reader = hound::WavReader::open(path).with_context(|| format!("Impossible d'ouvrir {}", path.display()))?;
+    let spec = reader.spec();
+    let channels = spec.channels.max(1) as usize;
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().collect::<Result<_, _>>()?,
+        hound::SampleFormat::Int => {
+            let scale = 2f32.powi(spec.bits_per_sample as i32 - 1);
+            reader.samples::<i32>().map(|s| s.map(|s| s as f32 / scale)).collect::<Result<_, _>>()?
+        }
+    };
+    Ok((samples.into_iter().step_by(channels).collect(), spec.sample_rate))
+}
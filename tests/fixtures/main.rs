@@ -0,0 +1,117 @@
+// ============================================================
+//  Non-régression sur captures de référence (fixtures/manifest.toml)
+//
+//  Les cas versionnés sont toujours analysés ; les cas absents du
+//  disque sont sautés, sauf avec
+//  SPEAKER_ALIGN_FETCH_FIXTURES=1 qui les télécharge d'abord.
+//  Un cas synthétique écrit à la volée vérifie le mécanisme
+//  lui-même (lecture WAV, analyse, tolérances).
+// ============================================================
+
+use std::path::{Path, PathBuf};
+
+mod case;
+
+use case::{Case, Expectation, Manifest, Tolerance, CAPTURE_FILES, FETCH_ENV, FIXTURES_DIR};
+use rand::Rng;
+use speaker_align::dsp::{self, SweepParams, SAMPLE_RATE};
+
+fn fixtures_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURES_DIR)
+}
+
+#[test]
+fn reference_captures_stay_within_tolerance() {
+    let root = fixtures_root();
+    let manifest = Manifest::load(&root).expect("manifeste lisible");
+    let fetch = std::env::var_os(FETCH_ENV).is_some();
+
+    let (mut failures, mut analyzed) = (Vec::new(), 0);
+    for case in &manifest.cases {
+        if !case.is_present(&root) {
+            if !fetch {
+                eprintln!("{} : captures absentes, cas sauté ({}=1 pour les télécharger)", case.name, FETCH_ENV);
+                continue;
+            }
+            case.fetch(&root).unwrap_or_else(|e| panic!("{:#}", e));
+        }
+        let report = case.run(&root).unwrap_or_else(|e| panic!("{} : {:#}", case.name, e));
+        failures.extend(case.expect.check(&report).into_iter().map(|f| format!("{} — {}", case.name, f)));
+        analyzed += 1;
+    }
+    // Les cas versionnés sont toujours là : un manifeste sans eux ne vérifie rien
+    assert!(analyzed > 0, "aucun cas analysé dans {}", root.display());
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Passe-haut du 1er ordre (coupure `freq_hz`), appliqué sur place.
+fn high_pass(x: &mut [f32], freq_hz: f32) {
+    let rc = 1.0 / (2.0 * std::f32::consts::PI * freq_hz);
+    let a = rc / (rc + 1.0 / SAMPLE_RATE as f32);
+    let (mut x1, mut y1) = (0.0f32, 0.0f32);
+    for v in x.iter_mut() {
+        let y = a * (y1 + *v - x1);
+        (x1, y1) = (*v, y);
+        *v = y;
+    }
+}
+
+/// Capture simulée : enceinte sans sub-grave (passe-haut 2e ordre à 80 Hz),
+/// retard de propagation, bruit de fond. Pas de réflexion : le cas vérifie
+/// le mécanisme des fixtures, les captures réelles éprouvent la détection
+/// du front (`dsp::ir_onset`).
+fn simulated_capture(sweep: &[f32], pre_delay_secs: f32, capture_secs: f32, delay_samples: usize, gain: f32) -> Vec<f32> {
+    let mut rng = rand::thread_rng();
+    let len = ((pre_delay_secs + capture_secs) * SAMPLE_RATE as f32) as usize;
+    let start = (pre_delay_secs * SAMPLE_RATE as f32) as usize + delay_samples;
+    let mut out: Vec<f32> = (0..len).map(|_| rng.gen_range(-1e-3..1e-3)).collect();
+    for (i, &s) in sweep.iter().enumerate() {
+        if let Some(o) = out.get_mut(start + i) {
+            *o += gain * s;
+        }
+    }
+    high_pass(&mut out, 80.0);
+    high_pass(&mut out, 80.0);
+    out
+}
+
+fn write_wav(path: &Path, samples: &[f32]) {
+    let spec = hound::WavSpec { channels: 1, sample_rate: SAMPLE_RATE, bits_per_sample: 16, sample_format: hound::SampleFormat::Int };
+    let mut writer = hound::WavWriter::create(path, spec).unwrap();
+    for &s in samples {
+        writer.write_sample((s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16).unwrap();
+    }
+    writer.finalize().unwrap();
+}
+
+#[test]
+fn synthetic_case_round_trips() {
+    let root = std::env::temp_dir().join(format!("speaker-align-fixtures-{}", std::process::id()));
+    let sweep = SweepParams { duration_s: 2.0, ..SweepParams::default() };
+    let pre_delay_secs = 0.2;
+    // Droite 24 échantillons (0,5 ms) plus loin et 2 dB plus basse
+    let right_gain = 10f32.powf(-2.0 / 20.0);
+    let case = Case {
+        name: "synthetique".into(),
+        url: None,
+        sweep,
+        pre_delay_secs,
+        expect: Expectation {
+            delay_ms: Some(Tolerance { value: 0.5, tol: 0.05 }),
+            level_diff_db: Some(Tolerance { value: -2.0, tol: 0.5 }),
+            ..Expectation::default()
+        },
+    };
+
+    let signal = dsp::generate_sweep(SAMPLE_RATE, &sweep);
+    std::fs::create_dir_all(case.dir(&root)).unwrap();
+    for ((_, file), (delay, gain)) in CAPTURE_FILES.iter().zip([(96, 0.5), (120, 0.5 * right_gain)]) {
+        write_wav(&case.dir(&root).join(file), &simulated_capture(&signal, pre_delay_secs, sweep.capture_secs(), delay, gain));
+    }
+    assert!(case.is_present(&root));
+
+    let report = case.run(&root).unwrap();
+    let _ = std::fs::remove_dir_all(&root);
+    let failures = case.expect.check(&report);
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,73 @@
+// ============================================================
+//  Touches réassignables et touches d'export
+//
+//  Rapport et image ont des touches que les terminaux laissent
+//  passer (Fin, Inser) ; F10 / F11 restent assignables par
+//  `[keys]`. `--png` n'a de sens qu'en mode headless.
+//  Maj+F3 / Maj+F5 survivent à la réassignation de F3 / F5.
+// ============================================================
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use speaker_align::keymap::{parse_key, KeyBindings, KeyMap};
+use std::process::Command;
+
+fn bindings(pairs: &[(&str, &str)]) -> KeyBindings {
+    KeyBindings(pairs.iter().map(|&(a, k)| (a.to_string(), k.to_string())).collect())
+}
+
+#[test]
+fn export_keys_are_not_function_keys() {
+    assert_eq!(parse_key("End"), Some(KeyCode::End));
+    assert_eq!(parse_key("Ins"), Some(KeyCode::Insert));
+    assert_eq!(parse_key("Insert"), Some(KeyCode::Insert));
+    assert_eq!(parse_key("F13"), None);
+
+    let map = KeyMap::default();
+    assert_eq!(map.label("[End]"), "[End]");
+    assert_eq!(map.label("[Ins]"), "[Ins]");
+    assert_eq!(map.translate(KeyCode::End, KeyModifiers::NONE), Some(KeyCode::End));
+}
+
+#[test]
+fn export_keys_can_move_back_to_function_keys() {
+    let map = KeyMap::new(&bindings(&[("report", "F10"), ("png", "F11")])).unwrap();
+    assert_eq!(map.translate(KeyCode::F(11), KeyModifiers::NONE), Some(KeyCode::Insert));
+    assert_eq!(map.translate(KeyCode::F(10), KeyModifiers::NONE), Some(KeyCode::End));
+    // Touches d'origine libérées
+    assert_eq!(map.translate(KeyCode::Insert, KeyModifiers::NONE), None);
+    assert_eq!(map.label("[Ins]"), "[F11]");
+    assert_eq!(map.label("[End]"), "[F10]");
+}
+
+#[test]
+fn export_keys_conflict_with_actions() {
+    assert!(KeyMap::new(&bindings(&[("quit", "End")])).is_err());
+    assert!(KeyMap::new(&bindings(&[("quit", "End"), ("report", "F10")])).is_ok());
+}
+
+#[test]
+fn png_requires_headless() {
+    let output = Command::new(env!("CARGO_BIN_EXE_speaker-align")).args(["--png", "reponse.png"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--headless"));
+}
+
+#[test]
+fn shifted_original_keys_survive_a_rebind() {
+    let map = KeyMap::new(&bindings(&[("multisub", "F10"), ("target", "F11")])).unwrap();
+    assert_eq!(map.translate(KeyCode::F(10), KeyModifiers::NONE), Some(KeyCode::F(3)));
+    // F3 seule est libérée, Maj+F3 retire toujours une place
+    assert_eq!(map.translate(KeyCode::F(3), KeyModifiers::NONE), None);
+    assert_eq!(map.translate(KeyCode::F(3), KeyModifiers::SHIFT), Some(KeyCode::F(3)));
+    assert_eq!(map.translate(KeyCode::F(5), KeyModifiers::SHIFT), Some(KeyCode::F(5)));
+    assert_eq!(map.translate(KeyCode::F(10), KeyModifiers::SHIFT), Some(KeyCode::F(10)));
+
+    // F3 donnée à une autre action : Maj+F3 ne la déclenche pas
+    let map = KeyMap::new(&bindings(&[("multisub", "F10"), ("quit", "F3")])).unwrap();
+    assert_eq!(map.translate(KeyCode::F(3), KeyModifiers::NONE), Some(KeyCode::Char('q')));
+    assert_eq!(map.translate(KeyCode::F(3), KeyModifiers::SHIFT), Some(KeyCode::F(3)));
+    // Lettres sans casse : Maj+A quitte, Maj+Q analyse
+    let map = KeyMap::new(&bindings(&[("quit", "a"), ("analyze", "q")])).unwrap();
+    assert_eq!(map.translate(KeyCode::Char('A'), KeyModifiers::SHIFT), Some(KeyCode::Char('q')));
+    assert_eq!(map.translate(KeyCode::Char('Q'), KeyModifiers::SHIFT), Some(KeyCode::Char('a')));
+}
@@ -0,0 +1,48 @@
+// ============================================================
+//  Masque de tolérance : ressources embarquées ramenées sur les
+//  bandes, écart toléré retiré du terme spectral du score
+// ============================================================
+
+use speaker_align::{
+    dsp::{self, NUM_BANDS},
+    mask::ToleranceMask,
+    scoring::ScoreWeights,
+};
+
+fn band_at(freq: f32) -> usize {
+    (0..NUM_BANDS).min_by(|&a, &b| {
+        let d = |i| (dsp::band_center_freq(i, NUM_BANDS) / freq).log2().abs();
+        d(a).total_cmp(&d(b))
+    }).unwrap()
+}
+
+#[test]
+fn builtin_masks_cover_every_band() {
+    let masks = ToleranceMask::all();
+    assert_eq!(masks.iter().map(|m| m.name.as_str()).collect::<Vec<_>>(), ["standard", "strict"]);
+    let standard = &masks[0];
+    assert_eq!(standard.bands_db.len(), NUM_BANDS);
+    assert!((standard.bands_db[band_at(1_000.0)] - 2.0).abs() < 0.1);
+    assert!(standard.bands_db[0] > 5.0, "plus large dans le grave");
+    assert!(ToleranceMask::builtin("inconnu").is_err());
+}
+
+#[test]
+fn gap_inside_the_mask_costs_no_spectral_points() {
+    let weights = ScoreWeights::default();
+    let left = vec![-20.0f32; NUM_BANDS];
+    // Droite 1,5 dB plus haute partout : hors tolérance nulle, dans ±2 dB
+    let right: Vec<f32> = left.iter().map(|l| l + 1.5).collect();
+    let standard = ToleranceMask::builtin("standard").unwrap();
+
+    let plain = dsp::compute_score(&left, &right, 0.0, 0.0, None, None, &weights);
+    let masked = dsp::compute_score(&left, &right, 0.0, 0.0, None, Some(&standard.bands_db), &weights);
+    let identical = dsp::compute_score(&left, &left, 0.0, 0.0, None, None, &weights);
+    assert!(plain < masked, "{} / {}", plain, masked);
+    assert_eq!(masked, identical);
+
+    // Au-delà du masque, seul l'excédent compte
+    let wide: Vec<f32> = left.iter().map(|l| l + 8.0).collect();
+    let over = dsp::compute_score(&left, &wide, 0.0, 0.0, None, Some(&standard.bands_db), &weights);
+    assert!(over < masked && over > dsp::compute_score(&left, &wide, 0.0, 0.0, None, None, &weights));
+}
@@ -0,0 +1,226 @@
+// ============================================================
+//  Carte simulée : chemins d'erreur de la capture sans matériel
+//
+//  Chaque panne de `mockaudio::Fault` traverse le vrai chemin de
+//  `audio::play_and_capture` (thread audio, file, chien de garde,
+//  contrôles de longueur et de niveau). Le départ programmé est
+//  attendu carte ouverte. La carte est globale au processus : les
+//  tests la prennent tour à tour.
+// ============================================================
+
+use speaker_align::{
+    audio::{self, AudioError, Capture, CaptureLink, CaptureTiming, Channel, Phase},
+    dsp::{self, SweepParams},
+    measurement::Measurement,
+    mockaudio::{self, Fault, MockAudio},
+    pipeline::AnalysisOptions,
+    schedule::StartSchedule,
+    strings,
+};
+use std::{
+    sync::{Mutex, MutexGuard, mpsc},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+const RATE: u32 = 48_000;
+const PRE_DELAY_SECS: f32 = 0.1;
+
+static CARD: Mutex<()> = Mutex::new(());
+
+/// Installe une carte rapide avec les pannes `faults`, pour la durée du test.
+fn card(faults: Vec<Fault>) -> MutexGuard<'static, ()> {
+    let guard = CARD.lock().unwrap_or_else(|e| e.into_inner());
+    mockaudio::install(Some(MockAudio { speed: 8.0, faults, ..MockAudio::default() }));
+    guard
+}
+
+fn sweep() -> SweepParams {
+    SweepParams { duration_s: 1.0, ..SweepParams::default() }
+}
+
+fn timing() -> CaptureTiming {
+    CaptureTiming { sample_rate: RATE, capture_secs: sweep().capture_secs(), pre_delay_secs: PRE_DELAY_SECS, start_at: None }
+}
+
+fn capture(channel: Channel, retry_default_config: bool) -> anyhow::Result<Capture> {
+    let signal = dsp::generate_sweep(RATE, &sweep());
+    let (tx, _rx) = mpsc::channel();
+    audio::play_and_capture(&signal, channel, timing(), retry_default_config, None, CaptureLink::progress_only(tx))
+}
+
+fn expected_len() -> usize {
+    (timing().capture_secs * RATE as f32).round() as usize
+}
+
+fn audio_error(result: anyhow::Result<Capture>) -> Option<AudioError> {
+    result.err()?.downcast_ref::<AudioError>().cloned()
+}
+
+#[test]
+fn healthy_card_keeps_the_room_delay() {
+    let _card = card(Vec::new());
+    let pre = (PRE_DELAY_SECS * RATE as f32) as usize;
+    let distance = |channel| {
+        let c = capture(channel, false).unwrap_or_else(|e| panic!("{:#}", e));
+        assert_eq!(c.mono.len(), expected_len());
+        let signal = dsp::generate_sweep(RATE, &sweep());
+        let mut m = Measurement::from_capture(channel, c, signal, sweep(), RATE, None, AnalysisOptions::default());
+        m.analyze_ir(pre, dsp::ONSET_THRESHOLD_DB);
+        m.dist_m.expect("distance")
+    };
+    // Droite 0,5 ms plus loin : 17 cm
+    let diff = distance(Channel::RIGHT) - distance(Channel::LEFT);
+    assert!((diff - 0.1715).abs() < 0.01, "{}", diff);
+}
+
+#[test]
+fn input_callback_phase_does_not_move_the_capture() {
+    let _card = card(Vec::new());
+    let signal = dsp::generate_sweep(RATE, &sweep());
+    // Entrée partie 7,3 ms après la sortie, puis 4 ms avant : sans calage,
+    // 2,5 m et 1,4 m d'écart
+    let distances = [0.0f32, 7.3, -4.0].map(|skew| {
+        mockaudio::install(Some(MockAudio { speed: 8.0, input_skew_ms: skew, ..MockAudio::default() }));
+        let c = capture(Channel::LEFT, false).unwrap_or_else(|e| panic!("{:#}", e));
+        assert_eq!(c.mono.len(), expected_len());
+        let mut m = Measurement::from_capture(Channel::LEFT, c, signal.clone(), sweep(), RATE, None, AnalysisOptions::default());
+        m.analyze_ir(0, dsp::ONSET_THRESHOLD_DB);
+        m.dist_m.expect("distance")
+    });
+    let shifts = distances.map(|d| d - distances[0]);
+    assert!(shifts.iter().all(|d| d.abs() < 0.01), "{:?}", distances);
+}
+
+#[test]
+fn second_capture_is_refused_while_one_is_armed() {
+    let _card = card(Vec::new());
+    let signal = dsp::generate_sweep(RATE, &sweep());
+    let (progress, rx) = mpsc::channel();
+    let first = thread::spawn(move || {
+        audio::play_and_capture(&signal, Channel::LEFT, timing(), false, None, CaptureLink::progress_only(progress))
+    });
+    // Première étape signalée : flux ouverts, capture armée
+    rx.recv_timeout(Duration::from_secs(5)).expect("première capture armée");
+    let err = capture(Channel::RIGHT, false).unwrap_err();
+    assert_eq!(err.to_string(), strings::tr().err_capture_armed);
+    first.join().unwrap().unwrap_or_else(|e| panic!("{:#}", e));
+    // Première capture rendue : la suivante passe
+    capture(Channel::RIGHT, false).unwrap_or_else(|e| panic!("{:#}", e));
+}
+
+#[test]
+fn busy_device_is_identified() {
+    let _card = card(vec![Fault::Busy]);
+    assert!(matches!(audio_error(capture(Channel::LEFT, false)), Some(AudioError::DeviceBusy { .. })));
+}
+
+#[test]
+fn rejected_config_needs_the_retry() {
+    let _card = card(vec![Fault::Reject { default_rate: 44_100 }]);
+    assert!(capture(Channel::LEFT, false).is_err());
+    // Config par défaut à 44,1 kHz, capture rendue au taux de la session
+    let c = capture(Channel::LEFT, true).unwrap_or_else(|e| panic!("{:#}", e));
+    assert!(c.mono.len().abs_diff(expected_len()) <= 2, "{}", c.mono.len());
+}
+
+#[test]
+fn other_device_rate_is_converted() {
+    let _card = card(vec![Fault::Rate { rate: 96_000 }]);
+    let c = capture(Channel::LEFT, false).unwrap_or_else(|e| panic!("{:#}", e));
+    assert!(c.mono.len().abs_diff(expected_len()) <= 2, "{}", c.mono.len());
+}
+
+#[test]
+fn stalled_input_is_truncated() {
+    let _card = card(vec![Fault::Short { fraction: 0.5 }]);
+    let err = capture(Channel::LEFT, false).unwrap_err();
+    assert!(err.downcast_ref::<AudioError>().is_none());
+    // Le chien de garde arrête la capture à la moitié ; le contrôle de
+    // longueur la refuse
+    assert!(err.to_string().contains(&(expected_len() / 2).to_string()), "{}", err);
+}
+
+#[test]
+fn dropped_callbacks_count_against_the_length() {
+    // Un callback sur 50 perdu : 98 %, capture acceptée
+    let _card = card(vec![Fault::Drop { every: 50 }]);
+    let c = capture(Channel::LEFT, false).unwrap_or_else(|e| panic!("{:#}", e));
+    assert!(c.mono.len() < expected_len() && c.mono.len() * 100 >= expected_len() * 95, "{}", c.mono.len());
+    // Un sur 10 : 90 %, sous le minimum
+    mockaudio::install(Some(MockAudio { speed: 8.0, faults: vec![Fault::Drop { every: 10 }], ..MockAudio::default() }));
+    let err = capture(Channel::LEFT, false).unwrap_err();
+    assert!(err.downcast_ref::<AudioError>().is_none(), "{}", err);
+}
+
+#[test]
+fn clipping_is_rejected() {
+    let _card = card(vec![Fault::Clip]);
+    assert!(matches!(audio_error(capture(Channel::LEFT, false)), Some(AudioError::Clipped { .. })));
+}
+
+#[test]
+fn hanging_driver_times_out() {
+    let _card = card(vec![Fault::Hang]);
+    assert!(matches!(audio_error(capture(Channel::LEFT, false)), Some(AudioError::Timeout { .. })));
+}
+
+#[test]
+fn cancel_stops_the_capture() {
+    let _card = card(Vec::new());
+    let signal = dsp::generate_sweep(RATE, &sweep());
+    let (progress, _rx) = mpsc::channel();
+    let (cancel_tx, cancel_rx) = mpsc::channel();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(150));
+        let _ = cancel_tx.send(());
+    });
+    let link = CaptureLink { progress, cancel: Some(&cancel_rx) };
+    let result = audio::play_and_capture(&signal, Channel::LEFT, timing(), false, None, link);
+    assert_eq!(audio_error(result), Some(AudioError::Cancelled));
+}
+
+/// Départ programmé `secs` secondes après maintenant (à la seconde près).
+fn start_in(secs: u64) -> StartSchedule {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    StartSchedule { second: ((now + secs) % 60) as u8 }
+}
+
+/// Capture au départ programmé ; renvoie aussi les étapes signalées.
+fn scheduled_capture(start_at: StartSchedule) -> (anyhow::Result<Capture>, Vec<Phase>) {
+    let signal = dsp::generate_sweep(RATE, &sweep());
+    let (tx, rx) = mpsc::channel();
+    let timing = CaptureTiming { start_at: Some(start_at), ..timing() };
+    let result = audio::play_and_capture(&signal, Channel::LEFT, timing, false, None, CaptureLink::progress_only(tx));
+    (result, rx.try_iter().map(|p| p.phase).collect())
+}
+
+#[test]
+fn scheduled_start_waits_with_the_capture_armed() {
+    let _card = card(Vec::new());
+    let (result, phases) = scheduled_capture(start_in(2));
+    assert_eq!(result.unwrap().mono.len(), expected_len());
+    assert_eq!(phases.first(), Some(&Phase::Scheduled));
+    assert!(phases.contains(&Phase::Playing));
+}
+
+#[test]
+fn busy_card_fails_before_the_scheduled_start() {
+    let _card = card(vec![Fault::Busy]);
+    let begin = Instant::now();
+    let (result, phases) = scheduled_capture(start_in(30));
+    assert!(matches!(audio_error(result), Some(AudioError::DeviceBusy { .. })));
+    assert!(begin.elapsed() < Duration::from_secs(5));
+    assert!(!phases.contains(&Phase::Scheduled));
+}
+
+#[test]
+fn fault_spec_parses() {
+    let mock = MockAudio::parse("busy, short:0.25,drop,speed:4,skew:-2.5").unwrap();
+    assert_eq!(mock.faults, vec![Fault::Busy, Fault::Short { fraction: 0.25 }, Fault::Drop { every: 4 }]);
+    assert_eq!(mock.speed, 4.0);
+    assert_eq!(mock.input_skew_ms, -2.5);
+    assert_eq!(MockAudio::parse("").unwrap(), MockAudio::default());
+    assert!(MockAudio::parse("meltdown").is_err());
+    assert!(MockAudio::parse("rate").is_err());
+}
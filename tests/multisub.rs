@@ -0,0 +1,98 @@
+// ============================================================
+//  Plusieurs caissons : réglage retrouvé sur des réponses de
+//  retard et de polarité connus, origine des temps prise dans
+//  la boucle, reprise d'une place
+// ============================================================
+
+use rustfft::num_complex::Complex;
+use std::f32::consts::PI;
+
+use speaker_align::{
+    audio::Channel,
+    multisub::{self, MultiSub, SeatResponses},
+};
+
+/// Réponse en champ libre : gain `sign`, arrivée après `delay_ms`.
+fn arrival(delay_ms: f32, sign: f32) -> Vec<Complex<f32>> {
+    multisub::frequencies()
+        .into_iter()
+        .map(|f| Complex::from_polar(sign, -2.0 * PI * f * delay_ms / 1000.0))
+        .collect()
+}
+
+/// Deux places ; le second caisson arrive 1 ms plus tard à la seconde, et il
+/// est câblé inversé avec 2 ms de retard de traitement.
+fn seats() -> Vec<SeatResponses> {
+    vec![
+        SeatResponses { label: "1".into(), responses: vec![arrival(0.0, 1.0), arrival(2.0, -1.0)] },
+        SeatResponses { label: "2".into(), responses: vec![arrival(0.0, 1.0), arrival(3.0, -1.0)] },
+    ]
+}
+
+#[test]
+fn optimize_finds_known_delay_and_polarity() {
+    let plan = multisub::optimize(&seats()).expect("deux places, deux caissons");
+    let (first, second) = (plan.settings[0], plan.settings[1]);
+
+    // Arrivées symétriques autour du premier caisson : le second avance de
+    // 2,5 ms, soit le premier retardé de 2,5 ms après normalisation
+    // (le gain du second reste libre : à tout gain, les deux places sont égales)
+    assert!(second.inverted && !first.inverted, "{:?}", plan.settings);
+    assert_eq!(first.gain_db, 0.0);
+    assert!((first.delay_ms - 2.5).abs() <= 0.25, "{:?}", plan.settings);
+    assert_eq!(second.delay_ms, 0.0);
+
+    assert!(plan.spread_after_db < 0.1, "écart réglé {} dB", plan.spread_after_db);
+    assert!(plan.spread_before_db > 1.0, "écart initial {} dB", plan.spread_before_db);
+    assert!(plan.level_change_db > 0.0, "{} dB", plan.level_change_db);
+}
+
+#[test]
+fn response_counts_time_from_the_loopback_arrival() {
+    let rate = 48_000;
+    // Même trajet acoustique (48 échantillons), latences différentes
+    let ir_at = |origin: usize| {
+        let mut ir = vec![0.0f32; origin + rate as usize];
+        ir[origin + 48] = 1.0;
+        ir
+    };
+    let early = multisub::response(&ir_at(1000), rate, 1000.0);
+    let late = multisub::response(&ir_at(2333), rate, 2333.0);
+    for (a, b) in early.iter().zip(&late) {
+        assert!((a - b).norm() < 1e-4, "{} / {}", a, b);
+    }
+
+    // Origine fractionnaire : le demi-échantillon passe dans la phase
+    let half = multisub::response(&ir_at(1000), rate, 999.5);
+    for ((h, e), f) in half.iter().zip(&early).zip(multisub::frequencies()) {
+        let shift = Complex::from_polar(1.0, -2.0 * PI * f * 0.5 / rate as f32);
+        assert!((h - e * shift).norm() < 1e-4, "{} Hz : {} / {}", f, h, e);
+    }
+}
+
+#[test]
+fn last_seat_can_be_dropped_and_measured_again() {
+    let mut session = MultiSub::new(vec![Channel::LFE, Channel(4)]);
+    for seat in seats() {
+        for response in seat.responses {
+            session.record(response);
+        }
+    }
+    assert_eq!(session.seats.len(), 2);
+    assert!(session.plan.is_some());
+
+    // Place interrompue après le premier caisson : seule elle est oubliée
+    session.record(arrival(0.0, 1.0));
+    assert!(!session.drop_last_seat());
+    assert!(session.pending.is_empty());
+    assert_eq!(session.seats.len(), 2);
+
+    assert!(session.drop_last_seat());
+    assert_eq!(session.seats.len(), 1);
+    assert!(session.plan.is_none(), "une place ne suffit plus");
+
+    let retake = seats().remove(1);
+    session.record(retake.responses[0].clone());
+    assert!(session.record(retake.responses[1].clone()));
+    assert!(session.plan.is_some());
+}
@@ -0,0 +1,69 @@
+// ============================================================
+//  Notes [F12] : n'importe quelle analyse, reprises dans les exports
+//
+//  PgPréc passe la saisie sur l'analyse précédente sans toucher à
+//  ce qui est reporté sur les suivantes ; les notes figurent en
+//  tête de diff.csv et dans la comparaison pour forum, avec des
+//  libellés dans la langue de l'interface.
+// ============================================================
+
+use speaker_align::{
+    app::{AppState, HistoryEntry},
+    dsp::{SweepParams, NUM_BANDS},
+    export,
+    notes::MeasurementNotes,
+    overlay, script,
+    strings::Lang,
+};
+
+mod common;
+
+fn notes(mic_position: &str, room: &str) -> MeasurementNotes {
+    MeasurementNotes { mic_position: mic_position.to_string(), room: room.to_string(), ..Default::default() }
+}
+
+#[test]
+fn page_up_annotates_an_older_analysis() {
+    let mut state = AppState::new();
+    state.sweep = SweepParams { duration_s: 2.0, ..SweepParams::default() };
+    state.pre_delay_secs = 0.2;
+    let steps = "synth FL 2 0\nsynth FR 2.5 -2\nkey a\nkey a\nexpect history == 2\n\
+                 key F12\nkey PageUp\nkey Tab\ntype centre\nkey Enter";
+    script::run(&mut state, steps).unwrap_or_else(|e| panic!("{:#}", e));
+
+    assert_eq!(state.history[0].notes.mic_position, "centre");
+    assert!(state.history[1].notes.is_empty());
+    // La dernière analyse n'a pas changé : rien de nouveau à reporter
+    assert!(state.notes.is_empty());
+    assert!(state.notes_input.is_none());
+}
+
+#[test]
+fn exports_carry_the_notes() {
+    let lang = common::lock_lang();
+    lang.set(Lang::En);
+    let lines = notes("centre", "salon | 4x5 m").header_lines();
+    let entry = |score, notes| HistoryEntry {
+        score,
+        delay_ms: 0.0,
+        level_diff_db: 0.0,
+        time: "12:00:00".to_string(),
+        left_db: vec![0.0; NUM_BANDS],
+        right_db: vec![0.0; NUM_BANDS],
+        environment: Default::default(),
+        notes,
+    };
+    let markdown = overlay::forum_markdown(&entry(70, notes("centre", "")), 0, &entry(80, notes("1 m", "")), 1, 0..NUM_BANDS);
+    lang.set(Lang::Fr);
+    let french = notes("centre", "").header_lines();
+
+    assert_eq!(lines, ["Mic: centre", "Room: salon | 4x5 m"]);
+    assert_eq!(french, ["Micro : centre"]);
+
+    let csv = export::to_csv(&[0.0; 2], &[1.0; 2], &[1.0; 2], &lines);
+    assert!(csv.starts_with("# Mic: centre\n# Room: salon | 4x5 m\nfrequency_hz,"), "{}", csv);
+
+    let markdown = markdown.unwrap();
+    assert!(markdown.contains("| Mic | centre | 1 m | |"), "{}", markdown);
+    assert!(!markdown.contains("| Room"), "{}", markdown);
+}
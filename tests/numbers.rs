@@ -0,0 +1,39 @@
+// ============================================================
+//  Séparateur décimal de la langue
+//
+//  Les nombres prennent la virgule en français dès leur mise en
+//  forme ; adresses et noms de fichiers insérés dans les messages
+//  gardent leurs points.
+// ============================================================
+
+use speaker_align::strings::{format_hz, format_num, format_short, format_signed, tr, Lang};
+
+mod common;
+
+#[test]
+fn numbers_follow_the_language_and_text_is_left_alone() {
+    let lang = common::lock_lang();
+    let texts = |l| {
+        lang.set(l);
+        let s = tr();
+        vec![
+            format_num(1.25f32, 2),
+            format_signed(-3.0f32, 1),
+            format_short(5.25),
+            format_hz(2500.0),
+            (s.pause)(1.5),
+            (s.report_written)("rapport-v2.1.html"),
+            (s.mqtt_no_address)("192.168.1.2:1883"),
+        ]
+    };
+    let fr = texts(Lang::Fr);
+    let en = texts(Lang::En);
+
+    assert_eq!(fr[..4], ["1,25", "-3,0", "5,25", "2,5 kHz"]);
+    assert_eq!(en[..4], ["1.25", "-3.0", "5.25", "2.5 kHz"]);
+    assert!(fr[4].contains("1,5") && en[4].contains("1.5"), "{} / {}", fr[4], en[4]);
+    for (fr, en) in fr[5..].iter().zip(&en[5..]) {
+        assert!(fr.contains("rapport-v2.1.html") || fr.contains("192.168.1.2:1883"), "{}", fr);
+        assert!(en.contains("rapport-v2.1.html") || en.contains("192.168.1.2:1883"), "{}", en);
+    }
+}
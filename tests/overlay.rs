@@ -0,0 +1,54 @@
+// ============================================================
+//  Comparaison A → B en Markdown ([F6])
+//
+//  Le relevé suit la langue de l'interface : aucun libellé
+//  français ne reste dans la version anglaise.
+// ============================================================
+
+use speaker_align::{
+    app::HistoryEntry,
+    dsp::NUM_BANDS,
+    overlay,
+    strings::{tr, Lang},
+};
+
+mod common;
+
+fn entry(score: u32, delay_ms: f32, right_offset_db: f32) -> HistoryEntry {
+    HistoryEntry {
+        score,
+        delay_ms,
+        level_diff_db: right_offset_db,
+        time: "12:00:00".to_string(),
+        left_db: vec![0.0; NUM_BANDS],
+        right_db: vec![right_offset_db; NUM_BANDS],
+        environment: Default::default(),
+        notes: Default::default(),
+    }
+}
+
+#[test]
+fn forum_markdown_follows_the_language() {
+    let (a, b) = (entry(62, 0.8, -3.0), entry(88, 0.1, -1.0));
+    let lang = common::lock_lang();
+    let markdown = |l| {
+        lang.set(l);
+        overlay::forum_markdown(&a, 0, &b, 1, 0..NUM_BANDS).unwrap()
+    };
+
+    let fr = markdown(Lang::Fr);
+    assert!(fr.contains("| Score | 62 | 88 | +26 |"), "{}", fr);
+    assert!(fr.contains("Retard G/D (ms) | 0.80 | 0.10 | -0.70 |"), "{}", fr);
+    assert!(fr.contains("D-G A") && fr.contains("mieux <|> pire"), "{}", fr);
+    assert_eq!(tr().forum_file, "comparaison.md");
+
+    let en = markdown(Lang::En);
+    assert!(en.contains("L/R delay (ms) | 0.80 | 0.10 | -0.70 |"), "{}", en);
+    assert!(en.contains("Mean \\|R − L\\| (dB) | 3.0 | 1.0 | -2.0 |"), "{}", en);
+    assert!(en.contains("R-L A") && en.contains("better <|> worse"), "{}", en);
+    assert!(en.contains("Curves moved from A to B: left 0.0 dB, right 2.0 dB"), "{}", en);
+    for french in ["Retard", "Écart", "moyen", "Courbes", "mieux", "pire", "moyenne", "D − G"] {
+        assert!(!en.contains(french), "« {} » dans :\n{}", french, en);
+    }
+    assert_eq!(Lang::En.strings().forum_file, "comparison.md");
+}
@@ -0,0 +1,71 @@
+// ============================================================
+//  Premières réflexions : écho synthétique de niveau et de
+//  retard connus, appariement G/D par trajet
+// ============================================================
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use speaker_align::{
+    dsp,
+    reflections::{self, Reflection, ReflectionReport, Surface, FLOOR_DB},
+};
+
+const RATE: u32 = 48_000;
+const DIRECT_M: f32 = 2.5;
+
+/// Impulsion brève (fenêtre de Hann de 8 échantillons) ajoutée en `at`.
+fn add_pulse(ir: &mut [f32], at: usize, gain: f32) {
+    for k in 0..8 {
+        let w = 0.5 - 0.5 * (2.0 * std::f32::consts::PI * (k as f32 + 0.5) / 8.0).cos();
+        ir[at + k] += gain * w;
+    }
+}
+
+fn reflection(path_m: f32, level_db: f32, surfaces: Vec<Surface>) -> Reflection {
+    Reflection { delay_ms: path_m / dsp::speed_of_sound() * 1000.0, level_db, path_m, surfaces }
+}
+
+#[test]
+fn echo_at_known_delay_and_level() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut ir: Vec<f32> = (0..RATE as usize / 10).map(|_| rng.gen_range(-1e-5..1e-5)).collect();
+    // Écho à −6 dB, 107 échantillons (2,23 ms, 0,76 m) après le son direct :
+    // le rebond sur le sol de la pièce type, à 2,5 m
+    let direct = RATE as usize / 50;
+    add_pulse(&mut ir, direct, 1.0);
+    add_pulse(&mut ir, direct + 107, 0.5);
+
+    let found = reflections::find(&ir, RATE, dsp::ONSET_THRESHOLD_DB, DIRECT_M).expect("son direct trouvé");
+    assert_eq!(found.len(), 1, "{:?}", found);
+    let echo = &found[0];
+    assert!((echo.delay_ms - 107.0 / 48.0).abs() < 0.05, "retard {} ms", echo.delay_ms);
+    assert!((echo.level_db + 6.0).abs() < 0.5, "niveau {} dB", echo.level_db);
+    assert!((echo.path_m - 0.765).abs() < 0.02, "trajet {} m", echo.path_m);
+    assert_eq!(echo.surfaces, vec![Surface::Floor, Surface::SideWall]);
+}
+
+#[test]
+fn nearby_paths_pair_across_sides() {
+    // Même réflexion, nommée « sol » à gauche et « mur latéral » à droite :
+    // appariée par trajet, pas d'écart signalé
+    let report = ReflectionReport {
+        left: vec![reflection(0.765, -6.0, vec![Surface::Floor, Surface::SideWall])],
+        right: vec![reflection(0.70, -6.5, vec![Surface::SideWall, Surface::Floor])],
+    };
+    assert!(report.asymmetries().is_empty(), "{:?}", report.asymmetries());
+
+    // Plus forte de 4 dB à droite, et un mur derrière l'enceinte droite seul
+    let report = ReflectionReport {
+        left: vec![reflection(0.765, -6.0, vec![Surface::Floor, Surface::SideWall])],
+        right: vec![
+            reflection(0.70, -2.0, vec![Surface::SideWall, Surface::Floor]),
+            reflection(1.0, -8.0, vec![Surface::FrontWall]),
+        ],
+    };
+    let asymmetries = report.asymmetries();
+    assert_eq!(asymmetries.len(), 2, "{:?}", asymmetries);
+    assert_eq!(asymmetries[0].surfaces, vec![Surface::SideWall, Surface::Floor]);
+    assert!((asymmetries[0].diff_db() - 4.0).abs() < 1e-4);
+    assert_eq!(asymmetries[1].surfaces, vec![Surface::FrontWall]);
+    assert_eq!(asymmetries[1].left_db, None);
+    assert!((asymmetries[1].diff_db() - (-8.0 - FLOOR_DB)).abs() < 1e-4);
+}
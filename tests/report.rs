@@ -0,0 +1,70 @@
+// ============================================================
+//  Rapport HTML et image PNG
+//
+//  Page produite après une analyse de captures simulées : textes
+//  échappés, sections présentes, conseils repris du panneau ; et
+//  tracé d'une seule bande, dont la plage de fréquences est nulle,
+//  notes [F12] en pied d'image.
+// ============================================================
+
+use speaker_align::{
+    advice,
+    app::AppState,
+    dsp::{SweepParams, NUM_BANDS},
+    report::{self, Response},
+    script,
+    strings::tr,
+};
+
+/// Analyse de deux captures simulées, droite 0,5 ms plus loin et 2 dB plus
+/// basse.
+fn analyzed() -> AppState {
+    let mut state = AppState::new();
+    state.sweep = SweepParams { duration_s: 2.0, ..SweepParams::default() };
+    state.pre_delay_secs = 0.2;
+    script::run(&mut state, "synth FL 2 0\nsynth FR 2.5 -2\nkey a\nexpect score != none").unwrap_or_else(|e| panic!("{:#}", e));
+    state
+}
+
+#[test]
+fn html_needs_an_analysis() {
+    assert!(report::to_html(&AppState::new()).unwrap().is_none());
+}
+
+#[test]
+fn html_escapes_device_names() {
+    let mut state = analyzed();
+    state.out_device = "Carte <USB> & \"pro\"".to_string();
+    let html = report::to_html(&state).unwrap().unwrap();
+    assert!(html.contains("Carte &lt;USB&gt; &amp; &quot;pro&quot;"), "{}", html);
+    assert!(!html.contains("<USB>"));
+}
+
+#[test]
+fn html_has_sections_charts_and_advice() {
+    let state = analyzed();
+    let html = report::to_html(&state).unwrap().unwrap();
+    let s = tr();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.trim_end().ends_with("</html>"));
+    assert!(html.contains(&format!("{}/100", state.score.unwrap())));
+    for section in [s.report_metrics, s.report_speakers, s.report_response, s.report_diff] {
+        assert!(html.contains(&format!("<h2>{}</h2>", section)), "{}", section);
+    }
+    assert_eq!(html.matches("<svg").count(), 2);
+    // Chaque conseil du panneau figure dans le rapport
+    for line in advice::recommendations(&state).iter().filter(|l| !l.text.trim().is_empty()) {
+        let text = line.text.trim().replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;");
+        assert!(html.contains(&text), "{}", line.text);
+    }
+}
+
+#[test]
+fn png_of_a_single_band() {
+    let db = vec![-3.0; NUM_BANDS];
+    let response = Response { left_db: Some(&db), right_db: None, diff_db: None, bands: 10..11, notes: vec!["Micro : centre".into()] };
+    let path = std::env::temp_dir().join(format!("speaker-align-{}.png", std::process::id()));
+    let written = report::write_png(&response, &path);
+    let _ = std::fs::remove_file(&path);
+    assert!(written.unwrap());
+}
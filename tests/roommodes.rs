@@ -0,0 +1,48 @@
+// ============================================================
+//  Modes de la pièce : fréquences d'une pièce connue, saisie
+//  des dimensions
+// ============================================================
+
+use speaker_align::{
+    dsp,
+    environment::RoomEntry,
+    roommodes::{self, ModeKind, RoomDimensions, MAX_MODE_HZ},
+};
+
+const ROOM: RoomDimensions = RoomDimensions { length_m: 5.2, width_m: 3.8, height_m: 2.5 };
+
+#[test]
+fn axial_modes_of_a_known_room() {
+    assert_eq!(dsp::speed_of_sound(), 343.0);
+    let modes = ROOM.modes(MAX_MODE_HZ);
+
+    // 343 / (2 × 5,2) ≈ 33 Hz : premier mode, axial sur la longueur
+    let first = modes[0];
+    assert_eq!((first.order, first.kind), ((1, 0, 0), ModeKind::Axial));
+    assert!((first.freq_hz - 32.98).abs() < 0.05, "1-0-0 à {} Hz", first.freq_hz);
+
+    let tangential = modes.iter().find(|m| m.order == (1, 1, 0)).expect("mode 1-1-0");
+    assert_eq!(tangential.kind, ModeKind::Tangential);
+    assert!(modes.iter().all(|m| m.order.0.min(m.order.1).min(m.order.2) == 0), "pas de mode oblique");
+    assert!(modes.windows(2).all(|w| w[0].freq_hz <= w[1].freq_hz));
+    assert!(modes.iter().all(|m| m.freq_hz <= MAX_MODE_HZ));
+
+    assert_eq!(roommodes::mode_near(&modes, 34.0).map(|m| m.label()), Some("1-0-0".to_string()));
+    assert!(roommodes::mode_near(&modes, 20.0).is_none());
+}
+
+#[test]
+fn room_entry_reads_conditions_and_dimensions() {
+    let entry = RoomEntry::parse("21.5 45 5,2x3.8×2.5").unwrap();
+    assert_eq!(entry.ambient.temperature_c, Some(21.5));
+    assert_eq!(entry.dimensions, Some(ROOM));
+    assert_eq!(RoomEntry::parse(&entry.to_text()).unwrap(), entry);
+
+    let only_room = RoomEntry::parse("- 5.2x3.8x2.5").unwrap();
+    assert!(only_room.ambient.is_empty());
+    assert_eq!(RoomEntry::parse(&only_room.to_text()).unwrap(), only_room);
+
+    assert!(RoomEntry::parse("5.2x3.8").is_err());
+    assert!(RoomEntry::parse("5.2x3.8x0.2").is_err());
+    assert!(RoomEntry::parse("1x2x3 4x5x6").is_err());
+}
@@ -0,0 +1,92 @@
+// ============================================================
+//  Scripts de touches : machine à états sans terminal ni carte son
+//
+//  Les captures sont simulées (`synth`) : le reste du parcours —
+//  touches, messages du thread audio, analyse, réinitialisation,
+//  saisies de texte — est celui de l'interface.
+// ============================================================
+
+use speaker_align::{app::AppState, dsp::SweepParams, script};
+
+/// État de départ : sweep court, pour des tests rapides.
+fn state() -> AppState {
+    let mut state = AppState::new();
+    state.sweep = SweepParams { duration_s: 2.0, ..SweepParams::default() };
+    state.pre_delay_secs = 0.2;
+    state
+}
+
+#[test]
+fn capture_analyze_reset() {
+    let mut state = state();
+    let report = script::run(
+        &mut state,
+        "
+        # Latence de 2 ms, droite 0,5 ms plus loin et 2 dB plus basse
+        synth FL 2 0
+        expect left == true
+        expect step == Idle
+        synth FR 2.5 -2
+        key a
+        expect history == 1
+        expect delay_ms > 0.45
+        expect delay_ms < 0.55
+        expect level_diff_db > -2.5
+        expect level_diff_db < -1.5
+        expect score != none
+        key x
+        expect left == false
+        expect right == false
+        expect score == none
+        expect history == 1
+        ",
+    )
+    .unwrap_or_else(|e| panic!("{:#}", e));
+    assert_eq!(report.checks, 12);
+    assert!(!report.quit);
+}
+
+#[test]
+fn text_entry_routes_keys() {
+    let mut state = state();
+    script::run(
+        &mut state,
+        "
+        key $
+        type 21.5 45
+        key Enter
+        expect status ~ 21,5 °C
+        key $
+        type chaud
+        key Enter
+        expect error ~ illisible
+        ",
+    )
+    .unwrap_or_else(|e| panic!("{:#}", e));
+    assert_eq!(state.ambient.humidity_pct, Some(45.0));
+}
+
+#[test]
+fn analyze_needs_both_sides() {
+    let mut state = state();
+    script::run(&mut state, "synth FL 2 0\nkey a\nexpect history == 0\nexpect score == none")
+        .unwrap_or_else(|e| panic!("{:#}", e));
+}
+
+#[test]
+fn quit_stops_the_script() {
+    let mut state = state();
+    let report = script::run(&mut state, "key q\nexpect step == Results").unwrap();
+    assert!(report.quit);
+    assert_eq!((report.commands, report.checks), (1, 0));
+}
+
+#[test]
+fn failed_expectation_names_the_line() {
+    let mut state = state();
+    let err = script::run(&mut state, "key x\n\nexpect history == 3").unwrap_err();
+    let message = format!("{:#}", err);
+    assert!(message.contains("ligne 3"), "{}", message);
+    assert!(script::run(&mut state, "key nope").is_err());
+    assert!(script::run(&mut state, "frobnicate").is_err());
+}
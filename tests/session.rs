@@ -0,0 +1,74 @@
+// ============================================================
+//  Sessions : fichiers v1 et v2 (fixtures/sessions) remis au
+//  format courant au chargement sans perdre les mesures ;
+//  version plus récente que le programme refusée
+// ============================================================
+
+use std::path::{Path, PathBuf};
+
+use speaker_align::{
+    audio::Channel,
+    session::{self, SESSION_VERSION},
+    strings::tr,
+};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/sessions").join(name)
+}
+
+#[test]
+fn v1_session_is_migrated_with_its_measurements() {
+    let s = session::load(&fixture("v1.spkalign")).expect("session v1");
+    assert_eq!(s.version, SESSION_VERSION);
+    assert_eq!(s.migrated_from, Some(1));
+
+    // `Left` / `Right` deviennent des indices de sortie
+    let (left, right) = (s.left.expect("gauche"), s.right.expect("droite"));
+    assert_eq!((left.channel, right.channel), (Channel::LEFT, Channel::RIGHT));
+    assert_eq!(left.samples, [0.0, 0.25, -0.5, 0.125]);
+    assert_eq!(left.bands_db, [-12.5, -10.0, -9.75]);
+    assert_eq!(left.dist_m, Some(2.41));
+    assert_eq!(left.clarity.map(|c| c.c80_db), Some(7.25));
+    assert_eq!(right.ir.as_deref(), Some(&[0.0, 0.75, 1.0][..]));
+    assert_eq!(right.iacc, Some(0.62));
+
+    assert!(s.others.is_empty());
+    assert_eq!(s.reference, Channel::LEFT);
+    assert_eq!(s.score, Some(87));
+    assert_eq!(s.history.len(), 1);
+    assert!(!s.history[0].has_curves());
+    assert!(s.experiments.entries.is_empty() && s.positions.is_empty() && s.sweep.is_none());
+}
+
+#[test]
+fn v2_session_is_migrated_with_its_measurements() {
+    let s = session::load(&fixture("v2.spkalign")).expect("session v2");
+    assert_eq!(s.version, SESSION_VERSION);
+    assert_eq!(s.migrated_from, Some(2));
+
+    let (left, right) = (s.left.expect("gauche"), s.right.expect("droite"));
+    assert_eq!((left.channel, right.channel), (Channel::LEFT, Channel::RIGHT));
+    // Chaque mesure garde sa fréquence d'échantillonnage
+    assert_eq!((left.sample_rate, right.sample_rate), (48_000, 44_100));
+    assert_eq!(right.samples, [0.0, 0.5, -0.25, 0.0625]);
+    assert_eq!(s.others.len(), 1);
+    assert_eq!(s.others[0].channel, Channel(2));
+    assert_eq!(s.others[0].bands_db, [-15.0, -14.25, -12.0]);
+
+    assert_eq!(s.diff_db.as_deref(), Some(&[-0.5, -0.5, 0.25][..]));
+    let scores: Vec<u32> = s.history.iter().map(|h| h.score).collect();
+    assert_eq!(scores, [82, 87]);
+    assert!(s.history.iter().all(|h| h.left_db.is_empty() && h.right_db.is_empty()));
+}
+
+#[test]
+fn newer_session_is_rejected() {
+    let json = std::fs::read_to_string(fixture("v2.spkalign")).unwrap();
+    let newer = json.replacen("\"version\":2", &format!("\"version\":{}", SESSION_VERSION + 1), 1);
+    let path = std::env::temp_dir().join(format!("speaker-align-session-{}.spkalign", std::process::id()));
+    std::fs::write(&path, newer).unwrap();
+    let err = session::load(&path).expect_err("version future refusée");
+    std::fs::remove_file(&path).ok();
+    let chain = format!("{:#}", err);
+    assert!(chain.contains(&(tr().session_too_new)(SESSION_VERSION + 1, SESSION_VERSION)), "{}", chain);
+}
@@ -0,0 +1,66 @@
+// ============================================================
+//  Langue des messages hors panneaux
+//
+//  Barre d'état, erreurs du fichier de configuration, de la
+//  saisie et des arguments : en anglais, aucun texte français
+//  ne reste, même quand --lang suit l'argument fautif.
+// ============================================================
+
+use speaker_align::{
+    app::AppState,
+    environment::RoomParams,
+    keymap::{KeyBindings, KeyMap},
+    mockaudio::MockAudio,
+    monitor::MonitorParams,
+    schedule::StartSchedule,
+    script,
+    strings::Lang,
+    webhook::WebhookParams,
+};
+
+mod common;
+
+#[test]
+fn messages_follow_the_language() {
+    let lang = common::lock_lang();
+    let messages = |l| {
+        lang.set(l);
+        let mut state = AppState::new();
+        script::run(&mut state, "key !").unwrap();
+        let bindings = KeyBindings([("quit".to_string(), "F42".to_string())].into());
+        vec![
+            state.status.unwrap_or_default(),
+            KeyMap::new(&bindings).err().unwrap_or_default(),
+            RoomParams { length_m: Some(4.0), ..RoomParams::default() }.validate().unwrap_err(),
+            RoomParams { temperature_c: Some(90.0), ..RoomParams::default() }.validate().unwrap_err(),
+            MonitorParams { at: "25:00".into(), ..MonitorParams::default() }.validate().unwrap_err(),
+            WebhookParams { url: Some("ftp://x".into()) }.validate().unwrap_err(),
+            StartSchedule::parse(":75").unwrap_err().to_string(),
+            MockAudio::parse("meltdown").unwrap_err().to_string(),
+            MockAudio::parse("rate").unwrap_err().to_string(),
+            MockAudio::parse("rate:vite").unwrap_err().to_string(),
+        ]
+    };
+    let fr = messages(Lang::Fr);
+    let en = messages(Lang::En);
+
+    for (fr, en) in fr.iter().zip(&en) {
+        assert!(!en.is_empty() && fr != en, "{} / {}", fr, en);
+        for french in [" : ", "é", "à", "attendu", "hors plage", "touche"] {
+            assert!(!en.contains(french), "« {} » dans : {}", french, en);
+        }
+    }
+}
+
+#[test]
+fn argument_errors_follow_the_language() {
+    let stderr = |args: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_speaker-align")).args(args).output().unwrap();
+        assert!(!output.status.success());
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    };
+    assert!(stderr(&["--sub-channel", "XX"]).contains("canal inconnu : XX"));
+    let en = stderr(&["--sub-channel", "XX", "--lang", "en"]);
+    assert!(en.contains("unknown channel: XX"), "{}", en);
+    assert!(stderr(&["--lang=en", "--theme", "neon"]).contains("unknown theme: neon"));
+}
@@ -0,0 +1,64 @@
+// ============================================================
+//  Thèmes : G et D toujours distincts, teintes de [theme.colors]
+// ============================================================
+
+use ratatui::{
+    style::{Color, Modifier},
+    symbols::Marker,
+};
+use speaker_align::{
+    config::Config,
+    theme::{ThemeName, ThemeParams},
+};
+use std::collections::BTreeMap;
+
+fn colors(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs.iter().map(|&(f, c)| (f.to_string(), c.to_string())).collect()
+}
+
+#[test]
+fn every_theme_tells_left_from_right() {
+    for name in ThemeName::ALL {
+        let t = name.theme();
+        // Courbe de gauche : `green` en braille, sans attribut
+        let left = (t.green, Marker::Braille, Modifier::empty());
+        let right = (t.orange, t.right_marker, t.right_modifier);
+        assert_ne!(left, right, "{}", name.name());
+    }
+    let mono = ThemeName::Mono.theme();
+    assert_eq!(mono.green, mono.orange);
+    assert_ne!(mono.right_marker, Marker::Braille);
+}
+
+#[test]
+fn colors_override_named_fields() {
+    let base = ThemeName::Default.theme();
+    let t = base.with_colors(&colors(&[("orange", "#e69f00"), ("green", "lightred"), ("gray", "245")])).unwrap();
+    assert_eq!(t.orange, Color::Rgb(230, 159, 0));
+    assert_eq!(t.green, Color::LightRed);
+    assert_eq!(t.gray, Color::Indexed(245));
+    assert_eq!(t.cyan, base.cyan);
+}
+
+#[test]
+fn colors_reject_unknown_fields_and_values() {
+    let base = ThemeName::Default.theme();
+    assert!(base.with_colors(&colors(&[("magenta", "#ffffff")])).is_err());
+    assert!(base.with_colors(&colors(&[("orange", "orangé")])).is_err());
+    assert!(ThemeParams { name: None, colors: colors(&[("regions", "red")]) }.validate().is_err());
+}
+
+#[test]
+fn config_reads_theme_colors() {
+    let path = std::env::temp_dir().join(format!("speaker-align-theme-{}.toml", std::process::id()));
+    std::fs::write(&path, "[theme]\nname = \"mono\"\n[theme.colors]\norange = \"#e69f00\"\n").unwrap();
+    let config = Config::load(&path);
+    std::fs::write(&path, "[theme.colors]\norange = \"pas une couleur\"\n").unwrap();
+    let invalid = Config::load(&path);
+    let _ = std::fs::remove_file(&path);
+
+    let config = config.unwrap();
+    assert_eq!(config.theme.name, Some(ThemeName::Mono));
+    assert_eq!(config.theme.colors.get("orange").map(String::as_str), Some("#e69f00"));
+    assert!(invalid.is_err());
+}